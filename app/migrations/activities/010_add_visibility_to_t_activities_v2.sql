@@ -0,0 +1,3 @@
+-- Add visibility column to activities
+-- Existing activities are kept private
+ALTER TABLE t_activities_v2 ADD COLUMN visibility TEXT NOT NULL DEFAULT 'private';
@@ -0,0 +1,9 @@
+-- Add the bounding box of the activity's track
+-- Existing activities have no bounds until their data is replaced
+ALTER TABLE t_activities_v2 ADD COLUMN min_lat FLOAT;
+ALTER TABLE t_activities_v2 ADD COLUMN min_lon FLOAT;
+ALTER TABLE t_activities_v2 ADD COLUMN max_lat FLOAT;
+ALTER TABLE t_activities_v2 ADD COLUMN max_lon FLOAT;
+
+CREATE INDEX IF NOT EXISTS t_activities_v2_bounds_idx
+ON t_activities_v2(user_id, min_lat, max_lat, min_lon, max_lon);
@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS t_activities (
+    rowid BIGSERIAL PRIMARY KEY,
+    id TEXT NOT NULL UNIQUE,
+    user_id TEXT NOT NULL,
+    name TEXT,
+    -- TIMESTAMPTZ only keeps the instant, the offset of the activity is stored alongside
+    start_time TIMESTAMPTZ NOT NULL,
+    start_time_offset INTEGER NOT NULL,
+    duration DOUBLE PRECISION,
+    sport TEXT NOT NULL,
+    natural_key TEXT NOT NULL,
+    rpe SMALLINT,
+    workout_type TEXT,
+    nutrition JSONB,
+    feedback TEXT,
+    visibility TEXT NOT NULL DEFAULT 'private'
+);
+
+CREATE INDEX IF NOT EXISTS t_activities_natural_key_idx
+ON t_activities(natural_key);
+
+CREATE INDEX IF NOT EXISTS t_activities_user_id_start_time_idx
+ON t_activities(user_id, start_time);
+
+CREATE TABLE IF NOT EXISTS t_activities_metrics (
+    rowid BIGSERIAL PRIMARY KEY,
+    metric TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE IF NOT EXISTS t_activities_metrics_values (
+    activity_rowid BIGINT NOT NULL REFERENCES t_activities(rowid) ON DELETE CASCADE,
+    metric_rowid BIGINT NOT NULL REFERENCES t_activities_metrics(rowid) ON DELETE CASCADE,
+    value DOUBLE PRECISION,
+    UNIQUE (activity_rowid, metric_rowid)
+);
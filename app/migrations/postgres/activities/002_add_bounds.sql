@@ -0,0 +1,9 @@
+-- Add the bounding box of the activity's track
+-- Existing activities have no bounds until their data is replaced
+ALTER TABLE t_activities ADD COLUMN min_lat DOUBLE PRECISION;
+ALTER TABLE t_activities ADD COLUMN min_lon DOUBLE PRECISION;
+ALTER TABLE t_activities ADD COLUMN max_lat DOUBLE PRECISION;
+ALTER TABLE t_activities ADD COLUMN max_lon DOUBLE PRECISION;
+
+CREATE INDEX IF NOT EXISTS t_activities_bounds_idx
+ON t_activities(user_id, min_lat, max_lat, min_lon, max_lon);
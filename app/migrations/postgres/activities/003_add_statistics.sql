@@ -0,0 +1,34 @@
+-- Store the statistics of the activity, used to summarize it without parsing its raw file
+ALTER TABLE t_activities ADD COLUMN statistics JSONB;
+
+-- Backfill existing activities from their cached metric values, for the metrics that are read
+-- as is from the statistics. Statistics whose metric was never computed stay missing until the
+-- activity's data is replaced.
+UPDATE t_activities
+SET statistics = backfill.statistics
+FROM (
+    SELECT
+        t_activities_metrics_values.activity_rowid,
+        jsonb_object_agg(
+            CASE t_activities_metrics.metric
+                WHEN 'duration' THEN 'Duration'
+                WHEN 'calories' THEN 'Calories'
+                WHEN 'elevation' THEN 'Elevation'
+                WHEN 'distance' THEN 'Distance'
+                WHEN 'normalized-power' THEN 'NormalizedPower'
+                WHEN 'hrv' THEN 'HeartRateVariability'
+                WHEN 'respiration-rate' THEN 'RespirationRate'
+                WHEN 'removed-gps-points' THEN 'RemovedGpsPoints'
+            END,
+            t_activities_metrics_values.value
+        ) AS statistics
+    FROM t_activities_metrics_values
+    JOIN t_activities_metrics ON t_activities_metrics.rowid = t_activities_metrics_values.metric_rowid
+    WHERE t_activities_metrics_values.value IS NOT NULL
+    AND t_activities_metrics.metric IN (
+        'duration', 'calories', 'elevation', 'distance', 'normalized-power', 'hrv',
+        'respiration-rate', 'removed-gps-points'
+    )
+    GROUP BY t_activities_metrics_values.activity_rowid
+) AS backfill
+WHERE t_activities.rowid = backfill.activity_rowid;
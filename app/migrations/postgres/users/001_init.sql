@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS t_users (
+    email TEXT NOT NULL UNIQUE,
+    user_id TEXT NOT NULL
+);
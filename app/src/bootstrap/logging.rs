@@ -0,0 +1,213 @@
+use std::fmt;
+
+use serde_json::{Map, Value, json};
+use tracing::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_subscriber::{
+    fmt::{FmtContext, FormatEvent, FormatFields, MakeWriter, format::Writer},
+    registry::LookupSpan,
+};
+
+use crate::config::{BaseConfig, LogFormat};
+
+/// Install the global subscriber writing logs to stdout, using the level and format of `config`.
+/// `default_level` is used when the configuration does not set one.
+pub fn init_tracing(config: &BaseConfig, default_level: Level) {
+    let subscriber = build_subscriber(
+        config.log_level.unwrap_or(default_level),
+        config.log_format,
+        std::io::stdout,
+    );
+    if let Err(err) = tracing::subscriber::set_global_default(subscriber) {
+        tracing::error!("Error while setting up tracing subscriber: {err:?}");
+    };
+}
+
+/// Build a subscriber writing events up to `level` to `writer`, in the given `format`.
+pub fn build_subscriber<W>(
+    level: Level,
+    format: LogFormat,
+    writer: W,
+) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(writer);
+
+    match format {
+        LogFormat::Pretty => Box::new(
+            builder
+                .compact()
+                .with_file(true)
+                .with_line_number(true)
+                .with_thread_ids(true)
+                .with_target(false)
+                .finish(),
+        ),
+        LogFormat::Json => Box::new(builder.event_format(JsonFormat).finish()),
+    }
+}
+
+/// Write each event as a single line JSON object, with its level, location, spans and fields.
+struct JsonFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+
+        let mut fields = JsonFields::default();
+        event.record(&mut fields);
+
+        let spans = ctx
+            .event_scope()
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| span.name())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let line = json!({
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "level": metadata.level().as_str(),
+            "target": metadata.target(),
+            "file": metadata.file(),
+            "line": metadata.line(),
+            "spans": spans,
+            "fields": fields.0,
+        });
+        writeln!(writer, "{line}")
+    }
+}
+
+#[derive(Default)]
+struct JsonFields(Map<String, Value>);
+
+impl Visit for JsonFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), json!(format!("{value:?}")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    /// Writer keeping everything written to it, to inspect the formatted logs.
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'writer> MakeWriter<'writer> for BufferWriter {
+        type Writer = Self;
+
+        fn make_writer(&'writer self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl BufferWriter {
+        fn content(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_build_subscriber_with_each_option() {
+        for format in [LogFormat::Pretty, LogFormat::Json] {
+            for level in [
+                Level::ERROR,
+                Level::WARN,
+                Level::INFO,
+                Level::DEBUG,
+                Level::TRACE,
+            ] {
+                let writer = BufferWriter::default();
+                let subscriber = build_subscriber(level, format, writer.clone());
+
+                tracing::subscriber::with_default(subscriber, || {
+                    tracing::error!(count = 3, "an error");
+                    tracing::trace!("a trace");
+                });
+
+                let content = writer.content();
+                assert!(content.contains("an error"));
+                assert_eq!(content.contains("a trace"), level == Level::TRACE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_format_writes_one_object_per_event() {
+        let writer = BufferWriter::default();
+        let subscriber = build_subscriber(Level::INFO, LogFormat::Json, writer.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("upload");
+            let _guard = span.enter();
+            tracing::info!(activity = "abc", size = 12, "parsed");
+        });
+
+        let content = writer.content();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1);
+
+        let line: Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["spans"], json!(["upload"]));
+        assert_eq!(
+            line["fields"],
+            json!({"message": "parsed", "activity": "abc", "size": 12})
+        );
+    }
+}
@@ -1,2 +1,118 @@
+pub mod logging;
 pub mod multi_user;
 pub mod single_user;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::{config::BaseConfig, outbound::fs::FilesystemRawDataRepository};
+
+/// Create the SQLite databases and raw data directories if missing, failing early if one of them
+/// is not writable. Returns the SQLite databases directory and the raw data repository.
+async fn prepare_data_dirs(
+    config: &BaseConfig,
+) -> anyhow::Result<(PathBuf, FilesystemRawDataRepository)> {
+    let db_dir = config.sqlite_db_dir();
+    let raw_data_dir = config.raw_data_dir();
+    for dir in [&db_dir, &raw_data_dir] {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Unable to create data directory {}", dir.display()))?;
+        check_writable(dir).await?;
+    }
+
+    Ok((db_dir, FilesystemRawDataRepository::new(raw_data_dir)))
+}
+
+async fn check_writable(dir: &Path) -> anyhow::Result<()> {
+    let probe = dir.join(".write_check");
+    tokio::fs::write(&probe, b"")
+        .await
+        .with_context(|| format!("Data directory {} is not writable", dir.display()))?;
+    tokio::fs::remove_file(&probe).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use cookie::SameSite;
+
+    use crate::{
+        config::{LogFormat, TrustedProxy},
+        domain::models::activity::MaxGpsSpeeds,
+        outbound::sqlite::preferences::SqlitePreferencesRepository,
+    };
+
+    use super::*;
+
+    fn config(activities_data_path: &Path, sqlite_db_path: Option<&Path>) -> BaseConfig {
+        BaseConfig {
+            server_port: 0,
+            bind_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            allow_origin: "http://localhost:5173".to_string(),
+            activities_data_path: activities_data_path.to_string_lossy().to_string(),
+            sqlite_db_path: sqlite_db_path.map(|path| path.to_string_lossy().to_string()),
+            max_concurrent_uploads: 1,
+            upload_queue_size: 0,
+            database_url: None,
+            log_level: None,
+            log_format: LogFormat::Pretty,
+            near_duplicate_window: None,
+            trusted_proxy: TrustedProxy::None,
+            cookie_secure: true,
+            cookie_same_site: SameSite::Strict,
+            cookie_http_only: true,
+            cookie_domain: None,
+            retain_unknown_fit_messages: false,
+            max_gps_speeds: MaxGpsSpeeds::default(),
+            default_page_size: None,
+            max_page_size: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prepare_data_dirs_creates_directories() {
+        let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
+        let data_path = tmp_dir.path().join("data");
+
+        let (db_dir, _) = prepare_data_dirs(&config(&data_path, None)).await.unwrap();
+
+        assert_eq!(db_dir, data_path.join("db/"));
+        assert!(db_dir.is_dir());
+        assert!(data_path.join("activities/").is_dir());
+
+        let db = db_dir.join("preferences.db");
+        SqlitePreferencesRepository::new(&format!("sqlite:{}", db.to_string_lossy()))
+            .await
+            .unwrap();
+        assert!(db.is_file());
+    }
+
+    #[tokio::test]
+    async fn test_prepare_data_dirs_with_sqlite_db_path() {
+        let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
+        let data_path = tmp_dir.path().join("data");
+        let sqlite_path = tmp_dir.path().join("sqlite");
+
+        let (db_dir, _) = prepare_data_dirs(&config(&data_path, Some(&sqlite_path)))
+            .await
+            .unwrap();
+
+        assert_eq!(db_dir, sqlite_path);
+        assert!(sqlite_path.is_dir());
+        assert!(!data_path.join("db/").exists());
+    }
+
+    #[tokio::test]
+    async fn test_prepare_data_dirs_fails_when_not_a_directory() {
+        let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
+        let data_path = tmp_dir.path().join("data");
+        std::fs::write(&data_path, b"").unwrap();
+
+        assert!(prepare_data_dirs(&config(&data_path, None)).await.is_err());
+    }
+}
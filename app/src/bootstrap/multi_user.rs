@@ -1,14 +1,22 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{sync::Arc, time::Duration};
 
 use anyhow::{Ok, anyhow};
+use chrono::TimeDelta;
 use tokio::sync::Mutex;
+use tracing::Level;
 
 use crate::{
+    bootstrap::{logging::init_tracing, prepare_data_dirs},
     config::{AppMode, BaseConfig, MultiUserConfig, StdEnvironment},
-    domain::services::{
-        activity::ActivityService, preferences::PreferencesService, training::TrainingService,
+    domain::{
+        models::activity::NearDuplicateWindow,
+        ports::activity::ActivityRepository,
+        services::{
+            activity::ActivityService, preferences::PreferencesService, training::TrainingService,
+        },
     },
     inbound::{
+        auth::email_based::user::UserRepository,
         http::{
             AuthLinkService, HttpServer, SMTPEmailProvider, SessionService,
             SqliteAuthLinkRepository, SqliteSessionRepository, SqliteUserRepository, UserService,
@@ -23,52 +31,120 @@ use crate::{
         },
     },
 };
+#[cfg(feature = "postgres")]
+use crate::{
+    inbound::http::PostgresUserRepository, outbound::postgres::activity::PostgresActivityRepository,
+};
+
+pub type MultiUserServer<AR, UR> = HttpServer<
+    ActivityService<AR, FilesystemRawDataRepository>,
+    Parser,
+    TrainingService<SqliteTrainingRepository, ActivityService<AR, FilesystemRawDataRepository>>,
+    UserService<
+        AuthLinkService<SqliteAuthLinkRepository, SMTPEmailProvider>,
+        UR,
+        SessionService<SqliteSessionRepository>,
+    >,
+    PreferencesService<SqlitePreferencesRepository>,
+>;
 
 pub async fn bootstrap_multi_user(
     mode_config: MultiUserConfig,
     mode: AppMode,
 ) -> anyhow::Result<
-    HttpServer<
-        ActivityService<
-            SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-            FilesystemRawDataRepository,
-        >,
-        Parser,
-        TrainingService<
-            SqliteTrainingRepository,
-            ActivityService<
-                SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-                FilesystemRawDataRepository,
-            >,
-        >,
-        UserService<
-            AuthLinkService<SqliteAuthLinkRepository, SMTPEmailProvider>,
-            SqliteUserRepository,
-            SessionService<SqliteSessionRepository>,
-        >,
-        PreferencesService<SqlitePreferencesRepository>,
+    MultiUserServer<
+        SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
+        SqliteUserRepository,
     >,
 > {
-    tracing::info!("Starting multi-user app");
-    // start tracing
-    let subscriber = tracing_subscriber::fmt()
-        .compact()
-        .with_max_level(tracing::Level::DEBUG)
-        .with_file(true)
-        .with_line_number(true)
-        .with_thread_ids(true)
-        .with_target(false)
-        .finish();
-    if let Err(err) = tracing::subscriber::set_global_default(subscriber) {
-        tracing::error!("Error while setting up tracing subscriber: {err:?}");
-    };
+    let config = prepare()?;
+    let (db_dir, raw_data_repository) = prepare_data_dirs(&config).await?;
+    let parser = Parser::default()
+        .with_unknown_messages_retained(config.retain_unknown_fit_messages)
+        .with_max_gps_speeds(config.max_gps_speeds.clone());
+
+    let activity_db = db_dir.clone().join("activities.db");
+    let activity_repository = SqliteActivityRepository::new(
+        &format!("sqlite:{}", activity_db.to_string_lossy()),
+        raw_data_repository.clone(),
+        parser.clone(),
+    )
+    .await?;
+
+    let user_db = db_dir.clone().join("user.db");
+    let user_repository =
+        SqliteUserRepository::new(&format!("sqlite:{}", user_db.to_string_lossy())).await?;
+
+    build_server(
+        mode,
+        mode_config,
+        config,
+        activity_repository,
+        raw_data_repository,
+        user_repository,
+        parser,
+    )
+    .await
+}
+
+/// Same as [bootstrap_multi_user], with activities and users stored in the Postgres database at
+/// `database_url`.
+#[cfg(feature = "postgres")]
+pub async fn bootstrap_multi_user_postgres(
+    mode_config: MultiUserConfig,
+    mode: AppMode,
+    database_url: &str,
+) -> anyhow::Result<
+    MultiUserServer<
+        PostgresActivityRepository<FilesystemRawDataRepository, Parser>,
+        PostgresUserRepository,
+    >,
+> {
+    let config = prepare()?;
+    let (_, raw_data_repository) = prepare_data_dirs(&config).await?;
+    let parser = Parser::default()
+        .with_unknown_messages_retained(config.retain_unknown_fit_messages)
+        .with_max_gps_speeds(config.max_gps_speeds.clone());
+
+    let activity_repository =
+        PostgresActivityRepository::new(database_url, raw_data_repository.clone(), parser.clone())
+            .await?;
+    let user_repository = PostgresUserRepository::new(database_url).await?;
+
+    build_server(
+        mode,
+        mode_config,
+        config,
+        activity_repository,
+        raw_data_repository,
+        user_repository,
+        parser,
+    )
+    .await
+}
 
+/// Load the configuration and set up tracing.
+fn prepare() -> anyhow::Result<BaseConfig> {
     let config = BaseConfig::from_env(&StdEnvironment {}).map_err(|err| anyhow!(err))?;
+    init_tracing(&config, Level::DEBUG);
+    tracing::info!("Starting multi-user app");
+
+    Ok(config)
+}
 
-    let (activity_service, parser, training_metrics_service) =
-        build_activity_service(&config).await?;
+async fn build_server<AR: ActivityRepository, UR: UserRepository>(
+    mode: AppMode,
+    mode_config: MultiUserConfig,
+    config: BaseConfig,
+    activity_repository: AR,
+    raw_data_repository: FilesystemRawDataRepository,
+    user_repository: UR,
+    parser: Parser,
+) -> anyhow::Result<MultiUserServer<AR, UR>> {
+    let (activity_service, training_metrics_service) =
+        build_activity_service(&config, activity_repository, raw_data_repository).await?;
 
-    let user_service = build_user_service(&config, &mode_config).await?;
+    let user_service = build_user_service(&config, &mode_config, user_repository).await?;
 
     let preferences_service = build_preferences_service(&config).await?;
 
@@ -97,47 +173,20 @@ fn build_mailer(config: &MultiUserConfig) -> anyhow::Result<SMTPEmailProvider> {
     Ok(mailer)
 }
 
-async fn build_activity_service(
+async fn build_activity_service<AR: ActivityRepository>(
     config: &BaseConfig,
+    activity_repository: AR,
+    raw_data_repository: FilesystemRawDataRepository,
 ) -> anyhow::Result<(
-    ActivityService<
-        SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-        FilesystemRawDataRepository,
-    >,
-    Parser,
+    ActivityService<AR, FilesystemRawDataRepository>,
     Arc<
-        TrainingService<
-            SqliteTrainingRepository,
-            ActivityService<
-                SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-                FilesystemRawDataRepository,
-            >,
-        >,
+        TrainingService<SqliteTrainingRepository, ActivityService<AR, FilesystemRawDataRepository>>,
     >,
 )> {
-    let root_path = PathBuf::from(config.activities_data_path.clone());
-    let db_dir = root_path.clone().join("db/");
-    if !db_dir.exists() {
-        tokio::fs::create_dir_all(&db_dir).await?;
-    }
-    let raw_data_dir = root_path.clone().join("activities/");
-    if !raw_data_dir.exists() {
-        tokio::fs::create_dir_all(&raw_data_dir).await?;
-    }
-
-    let parser = Parser {};
+    let db_dir = config.sqlite_db_dir();
 
-    let raw_data_repository = FilesystemRawDataRepository::new(raw_data_dir);
-
-    let activity_db = db_dir.clone().join("activities.db");
-    let activity_repository = SqliteActivityRepository::new(
-        &format!("sqlite:{}", activity_db.to_string_lossy()),
-        raw_data_repository.clone(),
-        parser.clone(),
-    )
-    .await?;
-
-    let activity_service = ActivityService::new(activity_repository.clone(), raw_data_repository);
+    let activity_service = ActivityService::new(activity_repository, raw_data_repository)
+        .with_near_duplicate_window(config.near_duplicate_window.map(NearDuplicateWindow::new));
 
     let trainin_metrics_db = db_dir.clone().join("training_metrics.db");
     let training_metrics_repository =
@@ -149,40 +198,38 @@ async fn build_activity_service(
         activity_service.clone(),
     ));
 
-    anyhow::Ok((activity_service, parser, training_metrics_service))
+    anyhow::Ok((activity_service, training_metrics_service))
 }
 
-async fn build_user_service(
+async fn build_user_service<UR: UserRepository>(
     config: &BaseConfig,
     mode_config: &MultiUserConfig,
+    user_repository: UR,
 ) -> anyhow::Result<
     UserService<
         AuthLinkService<SqliteAuthLinkRepository, SMTPEmailProvider>,
-        SqliteUserRepository,
+        UR,
         SessionService<SqliteSessionRepository>,
     >,
 > {
-    let root_path = PathBuf::from(config.activities_data_path.clone());
-    let db_dir = root_path.clone().join("db/");
-    if !db_dir.exists() {
-        tokio::fs::create_dir_all(&db_dir).await?;
-    }
+    let db_dir = config.sqlite_db_dir();
 
     let auth_db = db_dir.clone().join("auth_link.db");
     let auth_link_repository = Arc::new(Mutex::new(
         SqliteAuthLinkRepository::new(&format!("sqlite:{}", auth_db.to_string_lossy())).await?,
     ));
     let mail_provider = Arc::new(build_mailer(mode_config)?);
-    let auth_link_service = Arc::new(Mutex::new(AuthLinkService::new(
-        auth_link_repository,
-        mail_provider,
-    )));
-
-    let user_db = db_dir.clone().join("user.db");
-    let user_repository = Arc::new(Mutex::new(
-        SqliteUserRepository::new(&format!("sqlite:{}", user_db.to_string_lossy())).await?,
+    let auth_link_service = Arc::new(Mutex::new(
+        AuthLinkService::new(auth_link_repository, mail_provider)
+            .with_link_ttl(TimeDelta::minutes(mode_config.auth_link_ttl_minutes as i64))
+            .with_mail_retries(
+                mode_config.mailer_retries,
+                Duration::from_millis(mode_config.mailer_retry_delay_ms as u64),
+            ),
     ));
 
+    let user_repository = Arc::new(Mutex::new(user_repository));
+
     let session_db = db_dir.clone().join("session.db");
     let session_repository = Arc::new(Mutex::new(
         SqliteSessionRepository::new(&format!("sqlite:{}", session_db.to_string_lossy())).await?,
@@ -196,11 +243,7 @@ async fn build_user_service(
 async fn build_preferences_service(
     config: &BaseConfig,
 ) -> anyhow::Result<PreferencesService<SqlitePreferencesRepository>> {
-    let root_path = PathBuf::from(config.activities_data_path.clone());
-    let db_dir = root_path.clone().join("db/");
-    if !db_dir.exists() {
-        tokio::fs::create_dir_all(&db_dir).await?;
-    }
+    let db_dir = config.sqlite_db_dir();
 
     let preferences_db = db_dir.clone().join("preferences.db");
     let preferences_repository =
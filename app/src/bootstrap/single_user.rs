@@ -1,11 +1,19 @@
 use std::{path::PathBuf, sync::Arc};
 
 use anyhow::anyhow;
+use tracing::Level;
 
+#[cfg(feature = "postgres")]
+use crate::outbound::postgres::activity::PostgresActivityRepository;
 use crate::{
+    bootstrap::{logging::init_tracing, prepare_data_dirs},
     config::{AppMode, BaseConfig, SingleUserConfig, StdEnvironment},
-    domain::services::{
-        activity::ActivityService, preferences::PreferencesService, training::TrainingService,
+    domain::{
+        models::activity::NearDuplicateWindow,
+        ports::activity::ActivityRepository,
+        services::{
+            activity::ActivityService, preferences::PreferencesService, training::TrainingService,
+        },
     },
     inbound::{
         http::{DisabledUserService, HttpServer},
@@ -20,55 +28,23 @@ use crate::{
     },
 };
 
+pub type SingleUserServer<AR> = HttpServer<
+    ActivityService<AR, FilesystemRawDataRepository>,
+    Parser,
+    TrainingService<SqliteTrainingRepository, ActivityService<AR, FilesystemRawDataRepository>>,
+    DisabledUserService,
+    PreferencesService<SqlitePreferencesRepository>,
+>;
+
 pub async fn bootstrap_single_user(
     _mode_config: SingleUserConfig,
     mode: AppMode,
-) -> anyhow::Result<
-    HttpServer<
-        ActivityService<
-            SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-            FilesystemRawDataRepository,
-        >,
-        Parser,
-        TrainingService<
-            SqliteTrainingRepository,
-            ActivityService<
-                SqliteActivityRepository<FilesystemRawDataRepository, Parser>,
-                FilesystemRawDataRepository,
-            >,
-        >,
-        DisabledUserService,
-        PreferencesService<SqlitePreferencesRepository>,
-    >,
-> {
-    tracing::info!("Starting multi-user app");
-    // start tracing
-    let subscriber = tracing_subscriber::fmt()
-        .compact()
-        .with_max_level(tracing::Level::INFO)
-        .with_file(true)
-        .with_line_number(true)
-        .with_thread_ids(true)
-        .with_target(false)
-        .finish();
-    if let Err(err) = tracing::subscriber::set_global_default(subscriber) {
-        tracing::error!("Error while setting up tracing subscriber: {err:?}");
-    };
-
-    let config = BaseConfig::from_env(&StdEnvironment {}).map_err(|err| anyhow!(err))?;
-    let root_path = PathBuf::from(config.activities_data_path.clone());
-    let db_dir = root_path.clone().join("db/");
-    if !db_dir.exists() {
-        tokio::fs::create_dir_all(&db_dir).await?;
-    }
-    let raw_data_dir = root_path.clone().join("activities/");
-    if !raw_data_dir.exists() {
-        tokio::fs::create_dir_all(&raw_data_dir).await?;
-    }
-
-    let parser = Parser {};
-
-    let raw_data_repository = FilesystemRawDataRepository::new(raw_data_dir);
+) -> anyhow::Result<SingleUserServer<SqliteActivityRepository<FilesystemRawDataRepository, Parser>>>
+{
+    let (config, db_dir, raw_data_repository) = prepare().await?;
+    let parser = Parser::default()
+        .with_unknown_messages_retained(config.retain_unknown_fit_messages)
+        .with_max_gps_speeds(config.max_gps_speeds.clone());
 
     let activity_db = db_dir.clone().join("activities.db");
     let activity_repository = SqliteActivityRepository::new(
@@ -77,7 +53,69 @@ pub async fn bootstrap_single_user(
         parser.clone(),
     )
     .await?;
-    let activity_service = ActivityService::new(activity_repository.clone(), raw_data_repository);
+
+    build_server(
+        mode,
+        config,
+        db_dir,
+        activity_repository,
+        raw_data_repository,
+        parser,
+    )
+    .await
+}
+
+/// Same as [bootstrap_single_user], with activities stored in the Postgres database at
+/// `database_url`.
+#[cfg(feature = "postgres")]
+pub async fn bootstrap_single_user_postgres(
+    _mode_config: SingleUserConfig,
+    mode: AppMode,
+    database_url: &str,
+) -> anyhow::Result<SingleUserServer<PostgresActivityRepository<FilesystemRawDataRepository, Parser>>>
+{
+    let (config, db_dir, raw_data_repository) = prepare().await?;
+    let parser = Parser::default()
+        .with_unknown_messages_retained(config.retain_unknown_fit_messages)
+        .with_max_gps_speeds(config.max_gps_speeds.clone());
+
+    let activity_repository =
+        PostgresActivityRepository::new(database_url, raw_data_repository.clone(), parser.clone())
+            .await?;
+
+    build_server(
+        mode,
+        config,
+        db_dir,
+        activity_repository,
+        raw_data_repository,
+        parser,
+    )
+    .await
+}
+
+/// Load the configuration, set up tracing and create the data directories. Returns the
+/// configuration, the SQLite databases directory and the raw data repository.
+async fn prepare() -> anyhow::Result<(BaseConfig, PathBuf, FilesystemRawDataRepository)> {
+    let config = BaseConfig::from_env(&StdEnvironment {}).map_err(|err| anyhow!(err))?;
+    init_tracing(&config, Level::INFO);
+    tracing::info!("Starting single-user app");
+
+    let (db_dir, raw_data_repository) = prepare_data_dirs(&config).await?;
+
+    Ok((config, db_dir, raw_data_repository))
+}
+
+async fn build_server<AR: ActivityRepository>(
+    mode: AppMode,
+    config: BaseConfig,
+    db_dir: PathBuf,
+    activity_repository: AR,
+    raw_data_repository: FilesystemRawDataRepository,
+    parser: Parser,
+) -> anyhow::Result<SingleUserServer<AR>> {
+    let activity_service = ActivityService::new(activity_repository.clone(), raw_data_repository)
+        .with_near_duplicate_window(config.near_duplicate_window.map(NearDuplicateWindow::new));
 
     let trainin_metrics_db = db_dir.clone().join("training_metrics.db");
     let training_metrics_repository =
@@ -109,13 +147,7 @@ pub async fn bootstrap_single_user(
 async fn build_preferences_service(
     config: &BaseConfig,
 ) -> anyhow::Result<PreferencesService<SqlitePreferencesRepository>> {
-    let root_path = PathBuf::from(config.activities_data_path.clone());
-    let db_dir = root_path.clone().join("db/");
-    if !db_dir.exists() {
-        tokio::fs::create_dir_all(&db_dir).await?;
-    }
-
-    let preferences_db = db_dir.clone().join("preferences.db");
+    let preferences_db = config.sqlite_db_dir().join("preferences.db");
     let preferences_repository =
         SqlitePreferencesRepository::new(&format!("sqlite:{}", preferences_db.to_string_lossy()))
             .await?;
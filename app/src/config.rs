@@ -1,13 +1,43 @@
 use std::{
+    collections::HashMap,
     env::{self},
     fs,
-    path::Path,
+    net::{IpAddr, Ipv4Addr},
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
+use axum::http::Uri;
+use cookie::SameSite;
+use thiserror::Error;
+use tracing::Level;
+
+use crate::domain::models::activity::{DEFAULT_MAX_GPS_SPEEDS, MaxGpsSpeeds};
+
 // Base config keys
 const SERVER_PORT_KEY: &str = "SERVER_PORT";
+const SERVER_BIND_ADDRESS_KEY: &str = "SERVER_BIND_ADDRESS";
 const ALLOW_ORIGIN_KEY: &str = "ALLOW_ORIGIN";
 const ACTIVITIES_DATA_PATH_KEY: &str = "ACTIVITIES_DATA_PATH";
+const SQLITE_DB_PATH_KEY: &str = "SQLITE_DB_PATH";
+const MAX_CONCURRENT_UPLOADS_KEY: &str = "MAX_CONCURRENT_UPLOADS";
+const UPLOAD_QUEUE_SIZE_KEY: &str = "UPLOAD_QUEUE_SIZE";
+const DATABASE_URL_KEY: &str = "DATABASE_URL";
+const LOG_LEVEL_KEY: &str = "LOG_LEVEL";
+const LOG_FORMAT_KEY: &str = "LOG_FORMAT";
+const NEAR_DUPLICATE_WINDOW_KEY: &str = "NEAR_DUPLICATE_WINDOW_SECONDS";
+const TRUSTED_PROXY_HOPS_KEY: &str = "TRUSTED_PROXY_HOPS";
+const COOKIE_SECURE_KEY: &str = "COOKIE_SECURE";
+const COOKIE_SAME_SITE_KEY: &str = "COOKIE_SAME_SITE";
+const COOKIE_HTTP_ONLY_KEY: &str = "COOKIE_HTTP_ONLY";
+const COOKIE_DOMAIN_KEY: &str = "COOKIE_DOMAIN";
+const RETAIN_UNKNOWN_FIT_MESSAGES_KEY: &str = "RETAIN_UNKNOWN_FIT_MESSAGES";
+const DEFAULT_PAGE_SIZE_KEY: &str = "DEFAULT_PAGE_SIZE";
+const MAX_PAGE_SIZE_KEY: &str = "MAX_PAGE_SIZE";
+const MAX_GPS_SPEEDS_KEY: &str = "MAX_GPS_SPEEDS";
+
+pub const DEFAULT_MAX_CONCURRENT_UPLOADS: usize = 4;
+pub const DEFAULT_UPLOAD_QUEUE_SIZE: usize = 16;
 
 // Single user related keys
 const SINGLE_USER_PASSWORD_KEY: &str = "ACTIVITIES_SINGLE_USER_PASSWORD";
@@ -18,31 +48,461 @@ const MULTI_USER_MAILER_USERNAME_KEY: &str = "ACTIVITIES_MAILER_USERNAME";
 const MULTI_USER_MAILER_PASSWORD_KEY: &str = "ACTIVITIES_MAILER_PASSWORD";
 const MULTI_USER_MAILER_RELAY_KEY: &str = "ACTIVITIES_MAILER_RELAY";
 const MULTI_USER_MAILER_DOMAIN_KEY: &str = "ACTIVITIES_MAILER_DOMAIN";
+const MULTI_USER_AUTH_LINK_TTL_KEY: &str = "ACTIVITIES_AUTH_LINK_TTL_MINUTES";
+const MULTI_USER_MAILER_RETRIES_KEY: &str = "ACTIVITIES_MAILER_RETRIES";
+const MULTI_USER_MAILER_RETRY_DELAY_KEY: &str = "ACTIVITIES_MAILER_RETRY_DELAY_MS";
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+pub const DEFAULT_AUTH_LINK_TTL_MINUTES: usize = 15;
+pub const DEFAULT_MAILER_RETRIES: usize = 2;
+pub const DEFAULT_MAILER_RETRY_DELAY_MS: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct BaseConfig {
-    pub server_port: String,
+    /// Port to listen on, between 1 and 65535.
+    pub server_port: u16,
+    /// Address of the interface to listen on, defaults to all interfaces (`0.0.0.0`).
+    pub bind_address: IpAddr,
+    /// Origin allowed to make cross-origin requests, e.g. `https://activities.example.com`.
     pub allow_origin: String,
     pub activities_data_path: String,
+    /// Directory of the SQLite databases, `db/` under `activities_data_path` if not set. See
+    /// [BaseConfig::sqlite_db_dir].
+    pub sqlite_db_path: Option<String>,
+    /// Number of uploaded files parsed at the same time, defaults to
+    /// [DEFAULT_MAX_CONCURRENT_UPLOADS].
+    pub max_concurrent_uploads: usize,
+    /// Number of uploads waiting for a parsing slot before new uploads are rejected, defaults to
+    /// [DEFAULT_UPLOAD_QUEUE_SIZE].
+    pub upload_queue_size: usize,
+    /// URL of the database storing activities and users, they are stored in SQLite files under
+    /// `activities_data_path` if not set. See [BaseConfig::database_backend].
+    pub database_url: Option<String>,
+    /// Most verbose level of the logs (`error`, `warn`, `info`, `debug` or `trace`). Defaults to
+    /// `info` in single-user mode and `debug` in multi-user mode if not set.
+    pub log_level: Option<Level>,
+    pub log_format: LogFormat,
+    /// Maximum number of seconds between the start of two activities with a similar duration and
+    /// distance for an upload to be rejected as a possible duplicate. Disabled if not set.
+    pub near_duplicate_window: Option<usize>,
+    /// Reverse proxies whose forwarding headers are used to find the client IP, none by default.
+    pub trusted_proxy: TrustedProxy,
+    /// Whether session cookies are only sent over HTTPS, defaults to `true`.
+    pub cookie_secure: bool,
+    /// `SameSite` attribute of session cookies (`Strict`, `Lax` or `None`), defaults to `Strict`.
+    /// `None` is only accepted with [BaseConfig::cookie_secure], as browsers reject it otherwise.
+    pub cookie_same_site: SameSite,
+    /// Whether session cookies are hidden from scripts, defaults to `true`.
+    pub cookie_http_only: bool,
+    /// Domain of session cookies, restricted to the host of the request if not set.
+    pub cookie_domain: Option<String>,
+    /// Whether the raw bytes of the .FIT messages unknown to the app are kept on the parsed
+    /// activities, defaults to `false`.
+    pub retain_unknown_fit_messages: bool,
+    /// Speeds, in m/s, above which GPS fixes are removed as spikes, by sport category, e.g.
+    /// `Running:10,Cycling:30`. Categories not set use [DEFAULT_MAX_GPS_SPEEDS].
+    pub max_gps_speeds: MaxGpsSpeeds,
+    /// Number of items returned by paginated list endpoints (e.g. activities) when a request has
+    /// no `limit`, all items if not set.
+    pub default_page_size: Option<usize>,
+    /// Upper bound of the `limit` of paginated list endpoints requests, unbounded if not set.
+    pub max_page_size: Option<usize>,
 }
 
 impl BaseConfig {
-    pub fn from_env<T: Environment>(env: &T) -> Result<BaseConfig, String> {
-        let server_port = load_env(env, SERVER_PORT_KEY)
+    pub fn from_env<T: Environment>(env: &T) -> Result<BaseConfig, ConfigError> {
+        let mut problems = Problems::default();
+
+        let server_port = problems
+            .check(load_port(env, SERVER_PORT_KEY))
+            .unwrap_or_default();
+        let bind_address = match load_env(env, SERVER_BIND_ADDRESS_KEY).as_string() {
+            Some(address) => problems
+                .check(
+                    address
+                        .trim()
+                        .parse::<IpAddr>()
+                        .map_err(|_| format!("Invalid {SERVER_BIND_ADDRESS_KEY}: {address}")),
+                )
+                .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            None => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        };
+        let allow_origin = problems
+            .check(load_origin(env, ALLOW_ORIGIN_KEY))
+            .unwrap_or_default();
+        let activities_data_path = problems
+            .check(
+                load_env(env, ACTIVITIES_DATA_PATH_KEY)
+                    .as_string()
+                    .ok_or_else(|| format!("Invalid or missing {ACTIVITIES_DATA_PATH_KEY}")),
+            )
+            .unwrap_or_default();
+        let sqlite_db_path = load_env(env, SQLITE_DB_PATH_KEY)
             .as_string()
-            .ok_or_else(|| format!("Invalid or missing {SERVER_PORT_KEY}"))?;
-        let allow_origin = load_env(env, ALLOW_ORIGIN_KEY)
+            .map(|path| path.trim().to_string());
+        let max_concurrent_uploads = problems
+            .check(load_usize_or(
+                env,
+                MAX_CONCURRENT_UPLOADS_KEY,
+                DEFAULT_MAX_CONCURRENT_UPLOADS,
+            ))
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_UPLOADS);
+        if max_concurrent_uploads == 0 {
+            problems.push(format!("{MAX_CONCURRENT_UPLOADS_KEY} must be at least 1"));
+        }
+        let upload_queue_size = problems
+            .check(load_usize_or(
+                env,
+                UPLOAD_QUEUE_SIZE_KEY,
+                DEFAULT_UPLOAD_QUEUE_SIZE,
+            ))
+            .unwrap_or(DEFAULT_UPLOAD_QUEUE_SIZE);
+        let database_url = load_env(env, DATABASE_URL_KEY)
             .as_string()
-            .ok_or_else(|| format!("Invalid or missing {ALLOW_ORIGIN_KEY}"))?;
-        let activities_data_path = load_env(env, ACTIVITIES_DATA_PATH_KEY)
+            .map(|url| url.trim().to_string());
+        let log_level = match load_env(env, LOG_LEVEL_KEY).as_string() {
+            Some(level) => problems.check(
+                level
+                    .trim()
+                    .parse::<Level>()
+                    .map_err(|_| format!("Invalid {LOG_LEVEL_KEY}: {level}")),
+            ),
+            None => None,
+        };
+        let log_format = match load_env(env, LOG_FORMAT_KEY).as_string() {
+            Some(format) => problems
+                .check(
+                    format
+                        .parse::<LogFormat>()
+                        .map_err(|_| format!("Invalid {LOG_FORMAT_KEY}: {format}")),
+                )
+                .unwrap_or_default(),
+            None => LogFormat::default(),
+        };
+        let near_duplicate_window = match load_env(env, NEAR_DUPLICATE_WINDOW_KEY).as_string() {
+            Some(window) => problems.check(
+                window
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid {NEAR_DUPLICATE_WINDOW_KEY}: {window}")),
+            ),
+            None => None,
+        };
+        let trusted_proxy = match problems
+            .check(load_usize_or(env, TRUSTED_PROXY_HOPS_KEY, 0))
+            .unwrap_or_default()
+        {
+            0 => TrustedProxy::None,
+            hops => TrustedProxy::Hops(hops),
+        };
+        let cookie_secure = problems
+            .check(load_bool_or(env, COOKIE_SECURE_KEY, true))
+            .unwrap_or(true);
+        let cookie_same_site = match load_env(env, COOKIE_SAME_SITE_KEY).as_string() {
+            Some(same_site) => problems
+                .check(
+                    parse_same_site(&same_site)
+                        .ok_or_else(|| format!("Invalid {COOKIE_SAME_SITE_KEY}: {same_site}")),
+                )
+                .unwrap_or(SameSite::Strict),
+            None => SameSite::Strict,
+        };
+        if cookie_same_site == SameSite::None && !cookie_secure {
+            problems.push(format!(
+                "{COOKIE_SAME_SITE_KEY}=None requires {COOKIE_SECURE_KEY} to be true"
+            ));
+        }
+        let cookie_http_only = problems
+            .check(load_bool_or(env, COOKIE_HTTP_ONLY_KEY, true))
+            .unwrap_or(true);
+        let cookie_domain = load_env(env, COOKIE_DOMAIN_KEY)
             .as_string()
-            .ok_or_else(|| format!("Invalid or missing {ACTIVITIES_DATA_PATH_KEY}"))?;
+            .map(|domain| domain.trim().to_string());
+        let retain_unknown_fit_messages = problems
+            .check(load_bool_or(env, RETAIN_UNKNOWN_FIT_MESSAGES_KEY, false))
+            .unwrap_or_default();
+        let max_gps_speeds = match load_env(env, MAX_GPS_SPEEDS_KEY).as_string() {
+            Some(speeds) => problems
+                .check(
+                    parse_max_gps_speeds(&speeds)
+                        .ok_or_else(|| format!("Invalid {MAX_GPS_SPEEDS_KEY}: {speeds}")),
+                )
+                .unwrap_or_default(),
+            None => MaxGpsSpeeds::default(),
+        };
+        let default_page_size = problems
+            .check(load_optional_usize(env, DEFAULT_PAGE_SIZE_KEY))
+            .flatten();
+        let max_page_size = problems
+            .check(load_optional_usize(env, MAX_PAGE_SIZE_KEY))
+            .flatten();
+        if max_page_size == Some(0) {
+            problems.push(format!("{MAX_PAGE_SIZE_KEY} must be at least 1"));
+        } else if let (Some(default), Some(max)) = (default_page_size, max_page_size)
+            && default > max
+        {
+            problems.push(format!(
+                "{DEFAULT_PAGE_SIZE_KEY} must not be greater than {MAX_PAGE_SIZE_KEY}"
+            ));
+        }
 
-        Ok(BaseConfig {
+        let config = BaseConfig {
             server_port,
+            bind_address,
             allow_origin,
             activities_data_path,
-        })
+            sqlite_db_path,
+            max_concurrent_uploads,
+            upload_queue_size,
+            database_url,
+            log_level,
+            log_format,
+            near_duplicate_window,
+            trusted_proxy,
+            cookie_secure,
+            cookie_same_site,
+            cookie_http_only,
+            cookie_domain,
+            retain_unknown_fit_messages,
+            max_gps_speeds,
+            default_page_size,
+            max_page_size,
+        };
+        // Fail early on an unsupported database URL
+        problems.check(config.database_backend());
+
+        problems.into_result(config)
+    }
+
+    /// Directory of the SQLite databases, [BaseConfig::sqlite_db_path] or `db/` under
+    /// [BaseConfig::activities_data_path].
+    pub fn sqlite_db_dir(&self) -> PathBuf {
+        match &self.sqlite_db_path {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(&self.activities_data_path).join("db/"),
+        }
+    }
+
+    /// Directory of the raw files of the activities, `activities/` under
+    /// [BaseConfig::activities_data_path].
+    pub fn raw_data_dir(&self) -> PathBuf {
+        PathBuf::from(&self.activities_data_path).join("activities/")
+    }
+
+    /// Backend selected by the scheme of [BaseConfig::database_url].
+    pub fn database_backend(&self) -> Result<DatabaseBackend, String> {
+        let Some(url) = &self.database_url else {
+            return Ok(DatabaseBackend::Sqlite);
+        };
+
+        match url.split_once("://").map(|(scheme, _)| scheme) {
+            #[cfg(feature = "postgres")]
+            Some("postgres" | "postgresql") => Ok(DatabaseBackend::Postgres(url.clone())),
+            #[cfg(not(feature = "postgres"))]
+            Some("postgres" | "postgresql") => Err(format!(
+                "{DATABASE_URL_KEY} requires the application to be built with the `postgres` feature"
+            )),
+            _ => Err(format!("Unsupported {DATABASE_URL_KEY} scheme: {url}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// One SQLite file per repository, under the activities data path.
+    Sqlite,
+    #[cfg(feature = "postgres")]
+    Postgres(String),
+}
+
+/// Format of the logs written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human readable, one line per event
+    #[default]
+    Pretty,
+    /// One JSON object per event, for log collectors
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Reverse proxies in front of the application, used to find the IP of the client behind them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrustedProxy {
+    /// The peer of the connection is the client, `X-Forwarded-For` and `Forwarded` are ignored
+    #[default]
+    None,
+    /// Number of proxies appending the address of their peer to `X-Forwarded-For` or `Forwarded`
+    Hops(usize),
+}
+
+/// Whole configuration of the application, see [Config::from_env].
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base: BaseConfig,
+    pub mode: AppMode,
+}
+
+impl Config {
+    /// Load and validate the [BaseConfig] and the [AppMode], reporting the problems of both at
+    /// once.
+    pub fn from_env<T: Environment>(env: &T) -> Result<Config, ConfigError> {
+        match (BaseConfig::from_env(env), AppMode::try_from_env(env)) {
+            (Ok(base), Ok(mode)) => Ok(Config { base, mode }),
+            (Err(err), Ok(_)) | (Ok(_), Err(err)) => Err(err),
+            (Err(mut base_err), Err(mode_err)) => {
+                base_err.problems.extend(mode_err.problems);
+                Err(base_err)
+            }
+        }
+    }
+}
+
+/// Every problem found in the configuration, one message per invalid environment variable.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid configuration: {}", .problems.join("; "))]
+pub struct ConfigError {
+    problems: Vec<String>,
+}
+
+impl ConfigError {
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+}
+
+impl From<String> for ConfigError {
+    fn from(problem: String) -> Self {
+        Self {
+            problems: vec![problem],
+        }
+    }
+}
+
+/// Problems found while loading a configuration, to report all of them instead of the first one.
+#[derive(Debug, Default)]
+struct Problems(Vec<String>);
+
+impl Problems {
+    /// Value of `result`, its error being recorded as a problem.
+    fn check<V>(&mut self, result: Result<V, String>) -> Option<V> {
+        result.map_err(|problem| self.push(problem)).ok()
+    }
+
+    fn push(&mut self, problem: String) {
+        self.0.push(problem);
+    }
+
+    fn into_result<V>(self, value: V) -> Result<V, ConfigError> {
+        if self.0.is_empty() {
+            Ok(value)
+        } else {
+            Err(ConfigError { problems: self.0 })
+        }
+    }
+}
+
+fn load_port<T: Environment>(env: &T, key: &str) -> Result<u16, String> {
+    let value = load_env(env, key)
+        .as_string()
+        .ok_or_else(|| format!("Invalid or missing {key}"))?;
+    match value.trim().parse::<u16>() {
+        Ok(port) if port > 0 => Ok(port),
+        _ => Err(format!(
+            "Invalid {key}: {value}, expected a port between 1 and 65535"
+        )),
+    }
+}
+
+/// Load an origin, made of a scheme and a host with an optional port (e.g.
+/// `https://example.com:8080`), without any path.
+fn load_origin<T: Environment>(env: &T, key: &str) -> Result<String, String> {
+    let value = load_env(env, key)
+        .as_string()
+        .ok_or_else(|| format!("Invalid or missing {key}"))?;
+    let is_origin = value
+        .parse::<Uri>()
+        .is_ok_and(|uri| uri.scheme().is_some() && uri.authority().is_some() && uri.path() == "/")
+        && !value.ends_with('/');
+    if !is_origin {
+        return Err(format!(
+            "Invalid {key}: {value}, expected an origin like https://example.com"
+        ));
+    }
+    Ok(value)
+}
+
+fn load_usize_or<T: Environment>(env: &T, key: &str, default: usize) -> Result<usize, String> {
+    match load_env(env, key).as_string() {
+        Some(value) => value
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid {key}: {value}")),
+        None => Ok(default),
+    }
+}
+
+fn load_optional_usize<T: Environment>(env: &T, key: &str) -> Result<Option<usize>, String> {
+    match load_env(env, key).as_string() {
+        Some(value) => value
+            .trim()
+            .parse::<usize>()
+            .map(Some)
+            .map_err(|_| format!("Invalid {key}: {value}")),
+        None => Ok(None),
+    }
+}
+
+fn load_bool_or<T: Environment>(env: &T, key: &str, default: bool) -> Result<bool, String> {
+    match load_env(env, key).as_string() {
+        Some(value) => match value.trim().to_lowercase().as_str() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(format!("Invalid {key}: {value}")),
+        },
+        None => Ok(default),
+    }
+}
+
+/// Parse comma separated `Category:speed` pairs, e.g. `Running:10,Cycling:30`. Categories are
+/// case insensitive and speeds must be strictly positive.
+fn parse_max_gps_speeds(value: &str) -> Option<MaxGpsSpeeds> {
+    let mut speeds = HashMap::new();
+    for pair in value
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+    {
+        let (category, speed) = pair.split_once(':')?;
+        let category = DEFAULT_MAX_GPS_SPEEDS
+            .iter()
+            .map(|(category, _)| *category)
+            .find(|known| known.to_string().eq_ignore_ascii_case(category.trim()))?;
+        let speed = speed.trim().parse::<f64>().ok()?;
+        if !speed.is_finite() || speed <= 0. {
+            return None;
+        }
+        speeds.insert(category, speed);
+    }
+    Some(MaxGpsSpeeds::new(speeds))
+}
+
+fn parse_same_site(value: &str) -> Option<SameSite> {
+    match value.trim().to_lowercase().as_str() {
+        "strict" => Some(SameSite::Strict),
+        "lax" => Some(SameSite::Lax),
+        "none" => Some(SameSite::None),
+        _ => None,
     }
 }
 
@@ -53,7 +513,7 @@ pub enum AppMode {
 }
 
 impl AppMode {
-    pub fn try_from_env<T: Environment>(env: &T) -> Result<AppMode, String> {
+    pub fn try_from_env<T: Environment>(env: &T) -> Result<AppMode, ConfigError> {
         match MultiUserConfig::try_from_env(env) {
             Ok(Some(config)) => return Ok(AppMode::MultiUser(config)),
             Err(err) => return Err(err),
@@ -84,9 +544,18 @@ pub struct MultiUserConfig {
     pub mailer_password: String,
     pub mailer_relay: String,
     pub mailer_domain: String,
+    /// Validity duration of the magic links sent by email, in minutes, defaults to
+    /// [DEFAULT_AUTH_LINK_TTL_MINUTES].
+    pub auth_link_ttl_minutes: usize,
+    /// Number of times sending an email is retried after a failure, defaults to
+    /// [DEFAULT_MAILER_RETRIES].
+    pub mailer_retries: usize,
+    /// Delay before the first retry of sending an email, in milliseconds, doubled for each
+    /// following retry. Defaults to [DEFAULT_MAILER_RETRY_DELAY_MS].
+    pub mailer_retry_delay_ms: usize,
 }
 impl MultiUserConfig {
-    pub fn try_from_env<T: Environment>(env: &T) -> Result<Option<MultiUserConfig>, String> {
+    pub fn try_from_env<T: Environment>(env: &T) -> Result<Option<MultiUserConfig>, ConfigError> {
         let mailer_from = load_env(env, MULTI_USER_MAILER_FROM_KEY).as_string();
         let mailer_username = load_env(env, MULTI_USER_MAILER_USERNAME_KEY).as_string();
         let mailer_password = load_env(env, MULTI_USER_MAILER_PASSWORD_KEY).as_string();
@@ -107,13 +576,43 @@ impl MultiUserConfig {
                 Some(mailer_password),
                 Some(mailer_relay),
                 Some(mailer_domain),
-            ] => Ok(Some(MultiUserConfig {
-                mailer_from,
-                mailer_username,
-                mailer_password,
-                mailer_relay,
-                mailer_domain,
-            })),
+            ] => {
+                let mut problems = Problems::default();
+                let auth_link_ttl_minutes = problems
+                    .check(load_usize_or(
+                        env,
+                        MULTI_USER_AUTH_LINK_TTL_KEY,
+                        DEFAULT_AUTH_LINK_TTL_MINUTES,
+                    ))
+                    .unwrap_or(DEFAULT_AUTH_LINK_TTL_MINUTES);
+                if auth_link_ttl_minutes == 0 {
+                    problems.push(format!("{MULTI_USER_AUTH_LINK_TTL_KEY} must be at least 1"));
+                }
+                let mailer_retries = problems
+                    .check(load_usize_or(
+                        env,
+                        MULTI_USER_MAILER_RETRIES_KEY,
+                        DEFAULT_MAILER_RETRIES,
+                    ))
+                    .unwrap_or(DEFAULT_MAILER_RETRIES);
+                let mailer_retry_delay_ms = problems
+                    .check(load_usize_or(
+                        env,
+                        MULTI_USER_MAILER_RETRY_DELAY_KEY,
+                        DEFAULT_MAILER_RETRY_DELAY_MS,
+                    ))
+                    .unwrap_or(DEFAULT_MAILER_RETRY_DELAY_MS);
+                problems.into_result(Some(MultiUserConfig {
+                    mailer_from,
+                    mailer_username,
+                    mailer_password,
+                    mailer_relay,
+                    mailer_domain,
+                    auth_link_ttl_minutes,
+                    mailer_retries,
+                    mailer_retry_delay_ms,
+                }))
+            }
             [
                 mailer_from,
                 mailer_username,
@@ -141,7 +640,8 @@ impl MultiUserConfig {
                 Err(format!(
                     "Invalid multi-user configuration, missing or invalid environment variables: {}",
                     errs.join(", ")
-                ))
+                )
+                .into())
             }
         }
     }
@@ -203,21 +703,26 @@ fn load_env<T: Environment>(env: &T, key: &str) -> EnvironmentVariable {
 mod test_config {
     use std::{collections::HashMap, io::Write};
 
+    use crate::domain::models::activity::Sport;
+
     use super::*;
 
     #[test]
     fn test_std_environment() {
         let env = StdEnvironment {};
 
-        std::assert_matches!(
+        assert!(matches!(
             env.get_var("unset-key"),
             EnvironmentVariable::NotSetOrInvalid
-        );
+        ));
 
         unsafe {
             std::env::set_var("test-key", "test-value");
         }
-        std::assert_matches!(env.get_var("test-key"), EnvironmentVariable::Set(_));
+        assert!(matches!(
+            env.get_var("test-key"),
+            EnvironmentVariable::Set(_)
+        ));
     }
 
     #[derive(Debug, Clone, Default)]
@@ -309,90 +814,838 @@ mod test_config {
         assert_eq!(
             BaseConfig::from_env(&env).unwrap(),
             BaseConfig {
-                server_port: "3000".to_string(),
+                server_port: 3000,
+                bind_address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
                 allow_origin: "http://localhost:5173".to_string(),
                 activities_data_path: "/tmp/activities".to_string(),
+                sqlite_db_path: None,
+                max_concurrent_uploads: DEFAULT_MAX_CONCURRENT_UPLOADS,
+                upload_queue_size: DEFAULT_UPLOAD_QUEUE_SIZE,
+                database_url: None,
+                log_level: None,
+                log_format: LogFormat::Pretty,
+                near_duplicate_window: None,
+                trusted_proxy: TrustedProxy::None,
+                cookie_secure: true,
+                cookie_same_site: SameSite::Strict,
+                cookie_http_only: true,
+                cookie_domain: None,
+                retain_unknown_fit_messages: false,
+                max_gps_speeds: MaxGpsSpeeds::default(),
+                default_page_size: None,
+                max_page_size: None,
             }
         );
     }
 
     #[test]
-    fn test_base_config_from_env_missing_required_value() {
+    fn test_base_config_from_env_with_log_options() {
         let mut env = MockEnvironment::default();
         env.set_var(
             SERVER_PORT_KEY,
             EnvironmentVariable::Set("3000".to_string()),
         );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
         env.set_var(
             ACTIVITIES_DATA_PATH_KEY,
             EnvironmentVariable::Set("/tmp/activities".to_string()),
         );
+        env.set_var(LOG_LEVEL_KEY, EnvironmentVariable::Set("warn".to_string()));
+        env.set_var(LOG_FORMAT_KEY, EnvironmentVariable::Set("JSON".to_string()));
+
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert_eq!(config.log_level, Some(Level::WARN));
+        assert_eq!(config.log_format, LogFormat::Json);
+
+        env.set_var(LOG_LEVEL_KEY, EnvironmentVariable::Set("loud".to_string()));
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!("Invalid {LOG_LEVEL_KEY}: loud")))
+        );
 
+        env.set_var(LOG_LEVEL_KEY, EnvironmentVariable::Set("info".to_string()));
+        env.set_var(LOG_FORMAT_KEY, EnvironmentVariable::Set("xml".to_string()));
         assert_eq!(
             BaseConfig::from_env(&env),
-            Err(format!("Invalid or missing {ALLOW_ORIGIN_KEY}"))
+            Err(ConfigError::from(format!("Invalid {LOG_FORMAT_KEY}: xml")))
         );
     }
 
     #[test]
-    fn test_single_user_config_try_from_env_with_password() {
+    fn test_base_config_from_env_with_near_duplicate_window() {
         let mut env = MockEnvironment::default();
         env.set_var(
-            SINGLE_USER_PASSWORD_KEY,
-            EnvironmentVariable::Set("secret".to_string()),
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            NEAR_DUPLICATE_WINDOW_KEY,
+            EnvironmentVariable::Set("300".to_string()),
         );
 
         assert_eq!(
-            SingleUserConfig::try_from_env(&env).unwrap().password,
-            Some("secret".to_string())
+            BaseConfig::from_env(&env).unwrap().near_duplicate_window,
+            Some(300)
+        );
+
+        env.set_var(
+            NEAR_DUPLICATE_WINDOW_KEY,
+            EnvironmentVariable::Set("5 minutes".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {NEAR_DUPLICATE_WINDOW_KEY}: 5 minutes"
+            )))
         );
     }
 
     #[test]
-    fn test_single_user_config_try_from_env_without_password() {
-        let env = MockEnvironment::default();
+    fn test_base_config_from_env_with_trusted_proxy() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            TRUSTED_PROXY_HOPS_KEY,
+            EnvironmentVariable::Set("2".to_string()),
+        );
 
-        assert_eq!(SingleUserConfig::try_from_env(&env).unwrap().password, None);
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().trusted_proxy,
+            TrustedProxy::Hops(2)
+        );
+
+        env.set_var(
+            TRUSTED_PROXY_HOPS_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().trusted_proxy,
+            TrustedProxy::None
+        );
+
+        env.set_var(
+            TRUSTED_PROXY_HOPS_KEY,
+            EnvironmentVariable::Set("all".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {TRUSTED_PROXY_HOPS_KEY}: all"
+            )))
+        );
     }
 
     #[test]
-    fn test_multi_user_config_try_from_env_none_when_unset() {
-        let env = MockEnvironment::default();
+    fn test_base_config_from_env_with_cookie_options() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            COOKIE_SECURE_KEY,
+            EnvironmentVariable::Set("false".to_string()),
+        );
+        env.set_var(
+            COOKIE_SAME_SITE_KEY,
+            EnvironmentVariable::Set("lax".to_string()),
+        );
+        env.set_var(
+            COOKIE_HTTP_ONLY_KEY,
+            EnvironmentVariable::Set("False".to_string()),
+        );
+        env.set_var(
+            COOKIE_DOMAIN_KEY,
+            EnvironmentVariable::Set("example.com".to_string()),
+        );
+
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert!(!config.cookie_secure);
+        assert_eq!(config.cookie_same_site, SameSite::Lax);
+        assert!(!config.cookie_http_only);
+        assert_eq!(config.cookie_domain, Some("example.com".to_string()));
+
+        env.set_var(
+            COOKIE_SAME_SITE_KEY,
+            EnvironmentVariable::Set("None".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "{COOKIE_SAME_SITE_KEY}=None requires {COOKIE_SECURE_KEY} to be true"
+            )))
+        );
+
+        env.set_var(
+            COOKIE_SECURE_KEY,
+            EnvironmentVariable::Set("true".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().cookie_same_site,
+            SameSite::None
+        );
+
+        env.set_var(
+            COOKIE_SAME_SITE_KEY,
+            EnvironmentVariable::Set("sometimes".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {COOKIE_SAME_SITE_KEY}: sometimes"
+            )))
+        );
 
-        assert_eq!(MultiUserConfig::try_from_env(&env).unwrap().is_none(), true);
+        env.set_var(
+            COOKIE_SAME_SITE_KEY,
+            EnvironmentVariable::Set("strict".to_string()),
+        );
+        env.set_var(
+            COOKIE_SECURE_KEY,
+            EnvironmentVariable::Set("yes".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {COOKIE_SECURE_KEY}: yes"
+            )))
+        );
     }
 
     #[test]
-    fn test_multi_user_config_try_from_env_ok_when_all_values_set() {
+    fn test_base_config_data_dirs() {
         let mut env = MockEnvironment::default();
         env.set_var(
-            MULTI_USER_MAILER_FROM_KEY,
-            EnvironmentVariable::Set("noreply@example.com".to_string()),
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
         );
         env.set_var(
-            MULTI_USER_MAILER_USERNAME_KEY,
-            EnvironmentVariable::Set("mailer-user".to_string()),
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
         );
         env.set_var(
-            MULTI_USER_MAILER_PASSWORD_KEY,
-            EnvironmentVariable::Set("mailer-password".to_string()),
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert_eq!(config.sqlite_db_path, None);
+        assert_eq!(config.sqlite_db_dir(), PathBuf::from("/tmp/activities/db/"));
+        assert_eq!(
+            config.raw_data_dir(),
+            PathBuf::from("/tmp/activities/activities/")
         );
+
         env.set_var(
-            MULTI_USER_MAILER_RELAY_KEY,
-            EnvironmentVariable::Set("smtp.example.com".to_string()),
+            SQLITE_DB_PATH_KEY,
+            EnvironmentVariable::Set("/var/lib/activities".to_string()),
+        );
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert_eq!(config.sqlite_db_dir(), PathBuf::from("/var/lib/activities"));
+        assert_eq!(
+            config.raw_data_dir(),
+            PathBuf::from("/tmp/activities/activities/")
         );
+    }
+
+    #[test]
+    fn test_base_config_from_env_with_page_sizes() {
+        let mut env = MockEnvironment::default();
         env.set_var(
-            MULTI_USER_MAILER_DOMAIN_KEY,
-            EnvironmentVariable::Set("example.com".to_string()),
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            DEFAULT_PAGE_SIZE_KEY,
+            EnvironmentVariable::Set("20".to_string()),
+        );
+        env.set_var(
+            MAX_PAGE_SIZE_KEY,
+            EnvironmentVariable::Set("100".to_string()),
         );
 
-        let config = MultiUserConfig::try_from_env(&env).unwrap().unwrap();
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert_eq!(config.default_page_size, Some(20));
+        assert_eq!(config.max_page_size, Some(100));
+
+        env.set_var(
+            MAX_PAGE_SIZE_KEY,
+            EnvironmentVariable::Set("10".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "{DEFAULT_PAGE_SIZE_KEY} must not be greater than {MAX_PAGE_SIZE_KEY}"
+            )))
+        );
+
+        env.set_var(MAX_PAGE_SIZE_KEY, EnvironmentVariable::Set("0".to_string()));
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "{MAX_PAGE_SIZE_KEY} must be at least 1"
+            )))
+        );
+
+        env.set_var(
+            MAX_PAGE_SIZE_KEY,
+            EnvironmentVariable::Set("all".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {MAX_PAGE_SIZE_KEY}: all"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_base_config_from_env_with_retain_unknown_fit_messages() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            RETAIN_UNKNOWN_FIT_MESSAGES_KEY,
+            EnvironmentVariable::Set("true".to_string()),
+        );
+
+        assert!(
+            BaseConfig::from_env(&env)
+                .unwrap()
+                .retain_unknown_fit_messages
+        );
+    }
+
+    #[test]
+    fn test_base_config_from_env_with_max_gps_speeds() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            MAX_GPS_SPEEDS_KEY,
+            EnvironmentVariable::Set("running:10, Cycling:30.5".to_string()),
+        );
+
+        let speeds = BaseConfig::from_env(&env).unwrap().max_gps_speeds;
+
+        assert_eq!(speeds.for_sport(&Sport::Running), 10.);
+        assert_eq!(speeds.for_sport(&Sport::Cycling), 30.5);
+        assert_eq!(
+            speeds.for_sport(&Sport::Swimming),
+            MaxGpsSpeeds::default().for_sport(&Sport::Swimming)
+        );
+    }
+
+    #[test]
+    fn test_parse_max_gps_speeds_invalid() {
+        assert_eq!(parse_max_gps_speeds(""), Some(MaxGpsSpeeds::default()));
+        assert_eq!(parse_max_gps_speeds("Running"), None);
+        assert_eq!(parse_max_gps_speeds("Unknown:10"), None);
+        assert_eq!(parse_max_gps_speeds("Running:fast"), None);
+        assert_eq!(parse_max_gps_speeds("Running:0"), None);
+        assert_eq!(parse_max_gps_speeds("Running:-5"), None);
+    }
+
+    #[test]
+    fn test_base_config_database_backend() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().database_backend(),
+            Ok(DatabaseBackend::Sqlite)
+        );
+
+        env.set_var(
+            DATABASE_URL_KEY,
+            EnvironmentVariable::Set("mysql://localhost/activities".to_string()),
+        );
+        assert!(BaseConfig::from_env(&env).is_err());
+
+        env.set_var(
+            DATABASE_URL_KEY,
+            EnvironmentVariable::Set("postgres://localhost/activities".to_string()),
+        );
+        #[cfg(feature = "postgres")]
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().database_backend(),
+            Ok(DatabaseBackend::Postgres(
+                "postgres://localhost/activities".to_string()
+            ))
+        );
+        #[cfg(not(feature = "postgres"))]
+        assert!(BaseConfig::from_env(&env).is_err());
+    }
+
+    #[test]
+    fn test_base_config_from_env_with_upload_limits() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        env.set_var(
+            MAX_CONCURRENT_UPLOADS_KEY,
+            EnvironmentVariable::Set("2".to_string()),
+        );
+        env.set_var(
+            UPLOAD_QUEUE_SIZE_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+
+        let config = BaseConfig::from_env(&env).unwrap();
+        assert_eq!(config.max_concurrent_uploads, 2);
+        assert_eq!(config.upload_queue_size, 0);
+
+        env.set_var(
+            MAX_CONCURRENT_UPLOADS_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        assert!(BaseConfig::from_env(&env).is_err());
+
+        env.set_var(
+            MAX_CONCURRENT_UPLOADS_KEY,
+            EnvironmentVariable::Set("many".to_string()),
+        );
+        assert!(BaseConfig::from_env(&env).is_err());
+    }
+
+    #[test]
+    fn test_base_config_from_env_with_bind_address() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            SERVER_BIND_ADDRESS_KEY,
+            EnvironmentVariable::Set("127.0.0.1".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        assert_eq!(
+            BaseConfig::from_env(&env).unwrap().bind_address,
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        );
+    }
+
+    #[test]
+    fn test_base_config_from_env_invalid_bind_address() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            SERVER_BIND_ADDRESS_KEY,
+            EnvironmentVariable::Set("localhost".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid {SERVER_BIND_ADDRESS_KEY}: localhost"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_base_config_from_env_missing_required_value() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        assert_eq!(
+            BaseConfig::from_env(&env),
+            Err(ConfigError::from(format!(
+                "Invalid or missing {ALLOW_ORIGIN_KEY}"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_base_config_from_env_invalid_port() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        for port in ["0", "65536", "http"] {
+            env.set_var(SERVER_PORT_KEY, EnvironmentVariable::Set(port.to_string()));
+            assert_eq!(
+                BaseConfig::from_env(&env),
+                Err(ConfigError::from(format!(
+                    "Invalid {SERVER_PORT_KEY}: {port}, expected a port between 1 and 65535"
+                )))
+            );
+        }
+
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("65535".to_string()),
+        );
+        assert_eq!(BaseConfig::from_env(&env).unwrap().server_port, 65535);
+    }
+
+    #[test]
+    fn test_base_config_from_env_invalid_origin() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+
+        for origin in [
+            "localhost:5173",
+            "http://localhost:5173/app",
+            "http://",
+            "*",
+        ] {
+            env.set_var(
+                ALLOW_ORIGIN_KEY,
+                EnvironmentVariable::Set(origin.to_string()),
+            );
+            assert_eq!(
+                BaseConfig::from_env(&env),
+                Err(ConfigError::from(format!(
+                    "Invalid {ALLOW_ORIGIN_KEY}: {origin}, expected an origin like https://example.com"
+                ))),
+                "{origin}"
+            );
+        }
+
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("https://activities.example.com".to_string()),
+        );
+        assert!(BaseConfig::from_env(&env).is_ok());
+    }
+
+    #[test]
+    fn test_base_config_from_env_lists_all_problems() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("70000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("not an origin".to_string()),
+        );
+        env.set_var(
+            MAX_CONCURRENT_UPLOADS_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        env.set_var(LOG_LEVEL_KEY, EnvironmentVariable::Set("loud".to_string()));
+
+        let err = BaseConfig::from_env(&env).unwrap_err();
+
+        let keys = [
+            SERVER_PORT_KEY,
+            ALLOW_ORIGIN_KEY,
+            ACTIVITIES_DATA_PATH_KEY,
+            MAX_CONCURRENT_UPLOADS_KEY,
+            LOG_LEVEL_KEY,
+        ];
+        assert_eq!(err.problems().len(), keys.len());
+        for key in keys {
+            assert!(
+                err.problems().iter().any(|problem| problem.contains(key)),
+                "{key} not in {err}"
+            );
+            assert!(err.to_string().contains(key));
+        }
+    }
+
+    #[test]
+    fn test_config_from_env_lists_base_and_mode_problems() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("3000".to_string()),
+        );
+        env.set_var(
+            ALLOW_ORIGIN_KEY,
+            EnvironmentVariable::Set("http://localhost:5173".to_string()),
+        );
+        env.set_var(
+            ACTIVITIES_DATA_PATH_KEY,
+            EnvironmentVariable::Set("/tmp/activities".to_string()),
+        );
+        for key in [
+            MULTI_USER_MAILER_FROM_KEY,
+            MULTI_USER_MAILER_USERNAME_KEY,
+            MULTI_USER_MAILER_PASSWORD_KEY,
+            MULTI_USER_MAILER_RELAY_KEY,
+            MULTI_USER_MAILER_DOMAIN_KEY,
+        ] {
+            env.set_var(key, EnvironmentVariable::Set("value".to_string()));
+        }
+
+        let config = Config::from_env(&env).unwrap();
+        assert_eq!(config.base.server_port, 3000);
+        assert!(matches!(config.mode, AppMode::MultiUser(_)));
+
+        env.set_var(
+            SERVER_PORT_KEY,
+            EnvironmentVariable::Set("port".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_AUTH_LINK_TTL_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_RETRY_DELAY_KEY,
+            EnvironmentVariable::Set("soon".to_string()),
+        );
+
+        let err = Config::from_env(&env).unwrap_err();
+        assert_eq!(
+            err.problems(),
+            [
+                format!("Invalid {SERVER_PORT_KEY}: port, expected a port between 1 and 65535"),
+                format!("{MULTI_USER_AUTH_LINK_TTL_KEY} must be at least 1"),
+                format!("Invalid {MULTI_USER_MAILER_RETRY_DELAY_KEY}: soon"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_single_user_config_try_from_env_with_password() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            SINGLE_USER_PASSWORD_KEY,
+            EnvironmentVariable::Set("secret".to_string()),
+        );
+
+        assert_eq!(
+            SingleUserConfig::try_from_env(&env).unwrap().password,
+            Some("secret".to_string())
+        );
+    }
+
+    #[test]
+    fn test_single_user_config_try_from_env_without_password() {
+        let env = MockEnvironment::default();
+
+        assert_eq!(SingleUserConfig::try_from_env(&env).unwrap().password, None);
+    }
+
+    #[test]
+    fn test_multi_user_config_try_from_env_none_when_unset() {
+        let env = MockEnvironment::default();
+
+        assert!(MultiUserConfig::try_from_env(&env).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_multi_user_config_try_from_env_ok_when_all_values_set() {
+        let mut env = MockEnvironment::default();
+        env.set_var(
+            MULTI_USER_MAILER_FROM_KEY,
+            EnvironmentVariable::Set("noreply@example.com".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_USERNAME_KEY,
+            EnvironmentVariable::Set("mailer-user".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_PASSWORD_KEY,
+            EnvironmentVariable::Set("mailer-password".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_RELAY_KEY,
+            EnvironmentVariable::Set("smtp.example.com".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_DOMAIN_KEY,
+            EnvironmentVariable::Set("example.com".to_string()),
+        );
+
+        let config = MultiUserConfig::try_from_env(&env).unwrap().unwrap();
 
         assert_eq!(config.mailer_from, "noreply@example.com");
         assert_eq!(config.mailer_username, "mailer-user");
         assert_eq!(config.mailer_password, "mailer-password");
         assert_eq!(config.mailer_relay, "smtp.example.com");
         assert_eq!(config.mailer_domain, "example.com");
+        assert_eq!(config.auth_link_ttl_minutes, DEFAULT_AUTH_LINK_TTL_MINUTES);
+        assert_eq!(config.mailer_retries, DEFAULT_MAILER_RETRIES);
+        assert_eq!(config.mailer_retry_delay_ms, DEFAULT_MAILER_RETRY_DELAY_MS);
+    }
+
+    #[test]
+    fn test_multi_user_config_try_from_env_with_auth_link_ttl() {
+        let mut env = MockEnvironment::default();
+        for key in [
+            MULTI_USER_MAILER_FROM_KEY,
+            MULTI_USER_MAILER_USERNAME_KEY,
+            MULTI_USER_MAILER_PASSWORD_KEY,
+            MULTI_USER_MAILER_RELAY_KEY,
+            MULTI_USER_MAILER_DOMAIN_KEY,
+        ] {
+            env.set_var(key, EnvironmentVariable::Set("value".to_string()));
+        }
+        env.set_var(
+            MULTI_USER_AUTH_LINK_TTL_KEY,
+            EnvironmentVariable::Set("60".to_string()),
+        );
+
+        let config = MultiUserConfig::try_from_env(&env).unwrap().unwrap();
+        assert_eq!(config.auth_link_ttl_minutes, 60);
+
+        env.set_var(
+            MULTI_USER_AUTH_LINK_TTL_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        assert!(MultiUserConfig::try_from_env(&env).is_err());
+
+        env.set_var(
+            MULTI_USER_AUTH_LINK_TTL_KEY,
+            EnvironmentVariable::Set("soon".to_string()),
+        );
+        assert!(MultiUserConfig::try_from_env(&env).is_err());
+    }
+
+    #[test]
+    fn test_multi_user_config_try_from_env_with_mailer_retries() {
+        let mut env = MockEnvironment::default();
+        for key in [
+            MULTI_USER_MAILER_FROM_KEY,
+            MULTI_USER_MAILER_USERNAME_KEY,
+            MULTI_USER_MAILER_PASSWORD_KEY,
+            MULTI_USER_MAILER_RELAY_KEY,
+            MULTI_USER_MAILER_DOMAIN_KEY,
+        ] {
+            env.set_var(key, EnvironmentVariable::Set("value".to_string()));
+        }
+        env.set_var(
+            MULTI_USER_MAILER_RETRIES_KEY,
+            EnvironmentVariable::Set("0".to_string()),
+        );
+        env.set_var(
+            MULTI_USER_MAILER_RETRY_DELAY_KEY,
+            EnvironmentVariable::Set("2000".to_string()),
+        );
+
+        let config = MultiUserConfig::try_from_env(&env).unwrap().unwrap();
+        assert_eq!(config.mailer_retries, 0);
+        assert_eq!(config.mailer_retry_delay_ms, 2000);
+
+        env.set_var(
+            MULTI_USER_MAILER_RETRIES_KEY,
+            EnvironmentVariable::Set("many".to_string()),
+        );
+        assert_eq!(
+            MultiUserConfig::try_from_env(&env).unwrap_err().problems(),
+            [format!("Invalid {MULTI_USER_MAILER_RETRIES_KEY}: many")]
+        );
     }
 
     #[test]
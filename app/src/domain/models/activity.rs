@@ -1,12 +1,12 @@
 use std::{
-    collections::{HashMap, hash_map::Iter},
+    collections::{BTreeMap, HashMap, hash_map::Iter},
     fmt::{self},
     hash::Hash,
-    ops::Div,
+    ops::{Div, RangeInclusive},
     str::FromStr,
 };
 
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, Timelike};
 use derive_more::{AsRef, Constructor, Display, From, Into};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -44,6 +44,7 @@ pub struct Activity {
     workout_type: Option<WorkoutType>,
     nutrition: Option<ActivityNutrition>,
     feedback: Option<ActivityFeedback>,
+    visibility: ActivityVisibility,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -60,6 +61,7 @@ impl Activity {
         workout_type: Option<WorkoutType>,
         nutrition: Option<ActivityNutrition>,
         feedback: Option<ActivityFeedback>,
+        visibility: ActivityVisibility,
     ) -> Self {
         Self {
             id,
@@ -72,6 +74,7 @@ impl Activity {
             workout_type,
             nutrition,
             feedback,
+            visibility,
         }
     }
 
@@ -94,6 +97,22 @@ impl Activity {
             workout_type: WorkoutType::empty(),
             nutrition: ActivityNutrition::empty(),
             feedback: ActivityFeedback::empty(),
+            visibility: ActivityVisibility::default(),
+        }
+    }
+
+    pub fn with_visibility(self, visibility: ActivityVisibility) -> Self {
+        Self { visibility, ..self }
+    }
+
+    pub fn with_name(self, name: Option<ActivityName>) -> Self {
+        Self { name, ..self }
+    }
+
+    pub fn with_workout_type(self, workout_type: Option<WorkoutType>) -> Self {
+        Self {
+            workout_type,
+            ..self
         }
     }
 
@@ -149,17 +168,57 @@ impl Activity {
     pub fn feedback(&self) -> &Option<ActivityFeedback> {
         &self.feedback
     }
+
+    pub fn visibility(&self) -> &ActivityVisibility {
+        &self.visibility
+    }
 }
 
 /// [Activity] enriched with data parsed from the raw file ([ActivityTimeseries], [ActivityStatistics]).
-#[derive(Clone, Debug, Constructor)]
+#[derive(Clone, Debug)]
 pub struct ActivityWithParsedData {
     activity: Activity,
     timeseries: ActivityTimeseries,
     statistics: ActivityStatistics,
+    sessions: Vec<SessionSummary>,
+    unknown_messages: UnknownMessages,
+    beat_intervals: BeatIntervals,
 }
 
 impl ActivityWithParsedData {
+    pub fn new(
+        activity: Activity,
+        timeseries: ActivityTimeseries,
+        statistics: ActivityStatistics,
+    ) -> Self {
+        Self {
+            activity,
+            timeseries,
+            statistics,
+            sessions: Vec::new(),
+            unknown_messages: UnknownMessages::default(),
+            beat_intervals: BeatIntervals::default(),
+        }
+    }
+
+    pub fn with_sessions(self, sessions: Vec<SessionSummary>) -> Self {
+        Self { sessions, ..self }
+    }
+
+    pub fn with_unknown_messages(self, unknown_messages: UnknownMessages) -> Self {
+        Self {
+            unknown_messages,
+            ..self
+        }
+    }
+
+    pub fn with_beat_intervals(self, beat_intervals: BeatIntervals) -> Self {
+        Self {
+            beat_intervals,
+            ..self
+        }
+    }
+
     pub fn activity(&self) -> &Activity {
         &self.activity
     }
@@ -212,20 +271,125 @@ impl ActivityWithParsedData {
         &self.activity.feedback
     }
 
+    pub fn visibility(&self) -> &ActivityVisibility {
+        &self.activity.visibility
+    }
+
     pub fn timeseries(&self) -> &ActivityTimeseries {
         &self.timeseries
     }
 
+    pub fn sessions(&self) -> &[SessionSummary] {
+        &self.sessions
+    }
+
+    /// Messages of the raw file unknown to the app, only retained if the parser is configured to.
+    pub fn unknown_messages(&self) -> &UnknownMessages {
+        &self.unknown_messages
+    }
+
+    pub fn beat_intervals(&self) -> &BeatIntervals {
+        &self.beat_intervals
+    }
+
+    /// Area covered by the activity's track, `None` if it has no position.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::from_positions(&self.timeseries.positions())
+    }
+
+    /// Whether the activity's track is a commute between the athlete's places, see
+    /// [CommuteLocations::matches].
+    pub fn is_commute(&self, locations: &CommuteLocations) -> bool {
+        locations.matches(&self.timeseries.positions())
+    }
+
+    /// Fill the missing values of `metric` with the ones from `other`, both activities being
+    /// aligned on their absolute timestamps. See [ActivityTimeseries::merge_metric].
+    pub fn merge_metric_from(
+        self,
+        other: &ActivityWithParsedData,
+        metric: &TimeseriesMetric,
+    ) -> Self {
+        let timeseries = self.timeseries.merge_metric(
+            self.start_time(),
+            other.timeseries(),
+            other.start_time(),
+            metric,
+        );
+        Self { timeseries, ..self }
+    }
+
     pub fn active_duration(&self) -> ActivityDuration {
         self.timeseries.active_time.duration().map_or_else(
             || self.activity.duration,
             |duration| ActivityDuration::from(duration as f64),
         )
     }
+
+    /// Keep only the part of the activity between `start` meters from its beginning and `end`
+    /// meters from its end, e.g. to remove a warmup rollout. The start time, duration and
+    /// distance are updated accordingly, while the statistics and sessions that cannot be
+    /// recomputed from the timeseries (calories, elevation, etc.) are dropped.
+    pub fn trim_by_distance(self, start: f64, end: f64) -> Result<Self, TrimByDistanceError> {
+        let window = self.timeseries.distance_window(start, end)?;
+        let time = self.timeseries.time.values();
+        let offset = time[*window.start()];
+        let duration = (time[*window.end()] - offset) as f64;
+        let distance = self
+            .timeseries
+            .metrics
+            .iter()
+            .find(|metric| metric.metric() == &TimeseriesMetric::Distance)
+            .map(|metric| {
+                let at = |idx: usize| metric.values()[idx].as_ref().map_or(0., f64::from);
+                at(*window.end()) - at(*window.start())
+            })
+            .unwrap_or_default();
+
+        let activity = Activity {
+            start_time: ActivityStartTime::new(
+                *self.start_time().datetime() + chrono::TimeDelta::seconds(offset as i64),
+            ),
+            duration: ActivityDuration::from(duration),
+            ..self.activity
+        };
+        let statistics = ActivityStatistics::new(HashMap::from([
+            (ActivityStatistic::Duration, duration),
+            (ActivityStatistic::Distance, distance),
+        ]));
+
+        Ok(Self {
+            activity,
+            timeseries: self.timeseries.slice(window),
+            statistics,
+            sessions: Vec::new(),
+            unknown_messages: UnknownMessages::default(),
+            beat_intervals: BeatIntervals::default(),
+        })
+    }
+
+    /// Reverse the order of the points of the activity, e.g. to repair a track recorded with
+    /// reversed timestamps, see [ActivityTimeseries::reverse]. Its start time, duration and
+    /// statistics are unchanged.
+    pub fn reverse(self) -> Result<Self, ReverseTimeseriesError> {
+        Ok(Self {
+            timeseries: self.timeseries.reverse()?,
+            ..self
+        })
+    }
+
+    /// Duration spent moving, using the threshold of the activity's sport. `None` if the activity
+    /// has no speed data.
+    pub fn moving_duration(&self, thresholds: &MovingSpeedThresholds) -> Option<ActivityDuration> {
+        self.timeseries
+            .moving_duration(thresholds.for_sport(self.sport()))
+            .map(|duration| ActivityDuration::from(duration as f64))
+    }
 }
 
 /// Technical ID of an [Activity].
-#[derive(Clone, Debug, Display, PartialEq, Eq, PartialOrd, Ord, AsRef, Hash)]
+#[derive(Clone, Debug, Display, PartialEq, Eq, PartialOrd, Ord, AsRef, Hash, Deserialize)]
+#[serde(transparent)]
 pub struct ActivityId(String);
 
 impl ActivityId {
@@ -244,6 +408,12 @@ impl Default for ActivityId {
     }
 }
 
+impl From<String> for ActivityId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Constructor)]
 pub struct ActivityName(String);
 
@@ -372,6 +542,8 @@ pub enum WorkoutType {
     LongRun,
     Race,
     CrossTraining,
+    /// Going to or coming back from work, see [CommuteLocations]
+    Commute,
 }
 
 impl WorkoutType {
@@ -391,8 +563,9 @@ impl FromStr for WorkoutType {
             "long_run" | "longrun" => Ok(WorkoutType::LongRun),
             "race" => Ok(WorkoutType::Race),
             "cross_training" => Ok(WorkoutType::CrossTraining),
+            "commute" => Ok(WorkoutType::Commute),
             _ => Err(format!(
-                "Invalid training type: '{}'. Must be one of: easy, tempo, intervals, long_run, race",
+                "Invalid training type: '{}'. Must be one of: easy, tempo, intervals, long_run, race, cross_training, commute",
                 s
             )),
         }
@@ -408,6 +581,7 @@ impl fmt::Display for WorkoutType {
             WorkoutType::LongRun => "long_run",
             WorkoutType::Race => "race",
             WorkoutType::CrossTraining => "cross_training",
+            WorkoutType::Commute => "commute",
         };
         write!(f, "{}", s)
     }
@@ -504,6 +678,46 @@ impl ActivityFeedback {
     }
 }
 
+/// Who can see an activity besides its owner.
+#[derive(Clone, Debug, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityVisibility {
+    /// Only visible to its owner
+    #[default]
+    Private,
+    /// Not listed anywhere, but may be shared with others
+    Unlisted,
+    /// Visible to anyone, including unauthenticated users
+    Public,
+}
+
+impl FromStr for ActivityVisibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "private" => Ok(ActivityVisibility::Private),
+            "unlisted" => Ok(ActivityVisibility::Unlisted),
+            "public" => Ok(ActivityVisibility::Public),
+            _ => Err(format!(
+                "Invalid visibility: '{}'. Must be one of: private, unlisted, public",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ActivityVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ActivityVisibility::Private => "private",
+            ActivityVisibility::Unlisted => "unlisted",
+            ActivityVisibility::Public => "public",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Clone, Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ActivityNaturalKey(String);
 
@@ -513,6 +727,136 @@ impl From<&str> for ActivityNaturalKey {
     }
 }
 
+/// Maximum relative difference between the durations, and between the distances, of two near
+/// duplicate activities.
+pub const NEAR_DUPLICATE_MAX_DIFFERENCE: f64 = 0.1;
+
+/// Maximum number of seconds between the start of two activities for them to be considered near
+/// duplicates, e.g. the same ride recorded by a watch and a phone. Unlike activities with the same
+/// [ActivityNaturalKey], their content differs and they may legitimately both be kept.
+#[derive(Clone, Copy, Debug, PartialEq, Constructor)]
+pub struct NearDuplicateWindow(usize);
+
+impl NearDuplicateWindow {
+    pub fn seconds(&self) -> usize {
+        self.0
+    }
+
+    /// Whether two activities of the same user, with their distances if known, start within the
+    /// window and have durations and distances differing by at most
+    /// [NEAR_DUPLICATE_MAX_DIFFERENCE]. Distances are only compared when both are known.
+    pub fn matches(
+        &self,
+        (activity, distance): (&Activity, Option<f64>),
+        (other, other_distance): (&Activity, Option<f64>),
+    ) -> bool {
+        let similar = |a: f64, b: f64| (a - b).abs() <= NEAR_DUPLICATE_MAX_DIFFERENCE * a.max(b);
+
+        let gap = (activity.start_time().datetime().timestamp()
+            - other.start_time().datetime().timestamp())
+        .unsigned_abs();
+
+        activity.user() == other.user()
+            && gap <= self.0 as u64
+            && similar(*activity.duration().as_f64(), *other.duration().as_f64())
+            && match (distance, other_distance) {
+                (Some(distance), Some(other_distance)) => similar(distance, other_distance),
+                _ => true,
+            }
+    }
+}
+
+/// Geographic area delimited by its southwest and northeast corners, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid bounding box ({min_lat}, {min_lon}) - ({max_lat}, {max_lon})")]
+pub struct InvalidBoundingBox {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl BoundingBox {
+    /// Latitudes must be within [-90, 90], longitudes within [-180, 180] and the minimums cannot
+    /// be greater than the maximums.
+    pub fn new(
+        min_lat: f64,
+        min_lon: f64,
+        max_lat: f64,
+        max_lon: f64,
+    ) -> Result<Self, InvalidBoundingBox> {
+        let latitudes = -90.0..=90.0;
+        let longitudes = -180.0..=180.0;
+        if !(latitudes.contains(&min_lat)
+            && latitudes.contains(&max_lat)
+            && longitudes.contains(&min_lon)
+            && longitudes.contains(&max_lon)
+            && min_lat <= max_lat
+            && min_lon <= max_lon)
+        {
+            return Err(InvalidBoundingBox {
+                min_lat,
+                min_lon,
+                max_lat,
+                max_lon,
+            });
+        }
+
+        Ok(Self {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        })
+    }
+
+    /// Smallest box containing all the `(latitude, longitude)` positions, `None` if there is no
+    /// valid position.
+    pub fn from_positions(positions: &[(f64, f64)]) -> Option<Self> {
+        positions
+            .iter()
+            .filter_map(|(lat, lon)| Self::new(*lat, *lon, *lat, *lon).ok())
+            .reduce(|acc, point| Self {
+                min_lat: acc.min_lat.min(point.min_lat),
+                min_lon: acc.min_lon.min(point.min_lon),
+                max_lat: acc.max_lat.max(point.max_lat),
+                max_lon: acc.max_lon.max(point.max_lon),
+            })
+    }
+
+    pub fn min_lat(&self) -> f64 {
+        self.min_lat
+    }
+
+    pub fn min_lon(&self) -> f64 {
+        self.min_lon
+    }
+
+    pub fn max_lat(&self) -> f64 {
+        self.max_lat
+    }
+
+    pub fn max_lon(&self) -> f64 {
+        self.max_lon
+    }
+
+    /// Whether both boxes share at least one point, touching edges included.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min_lat <= other.max_lat
+            && other.min_lat <= self.max_lat
+            && self.min_lon <= other.max_lon
+            && other.min_lon <= self.max_lon
+    }
+}
+
 #[derive(Clone, Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash, From, Into, Copy)]
 pub struct ActivityStartTime(DateTime<FixedOffset>);
 
@@ -530,6 +874,98 @@ impl ActivityStartTime {
     }
 }
 
+/// Title given to an activity without one, e.g. "Morning Run" or "Lunch Ride", from its sport and
+/// the hour it started at in its own timezone:
+/// - night: before 5h and from 22h,
+/// - morning: from 5h,
+/// - lunch: from 11h,
+/// - afternoon: from 14h,
+/// - evening: from 18h.
+pub fn default_title(sport: &Sport, local_start: &ActivityStartTime) -> String {
+    let time_of_day = match local_start.datetime().hour() {
+        5..=10 => "Morning",
+        11..=13 => "Lunch",
+        14..=17 => "Afternoon",
+        18..=21 => "Evening",
+        _ => "Night",
+    };
+    format!("{time_of_day} {}", sport_title(sport))
+}
+
+/// Short name of a sport to use in a title.
+fn sport_title(sport: &Sport) -> String {
+    match sport {
+        Sport::Hiking | Sport::Mountaineering => "Hike".to_string(),
+        Sport::Hiit => "HIIT".to_string(),
+        Sport::Other => "Activity".to_string(),
+        _ => match sport.category() {
+            Some(SportCategory::Running) => "Run".to_string(),
+            Some(SportCategory::Cycling) => "Ride".to_string(),
+            Some(SportCategory::Swimming) => "Swim".to_string(),
+            Some(SportCategory::Walking) => "Walk".to_string(),
+            Some(SportCategory::Rowing) => "Row".to_string(),
+            // Split the variant name in words, e.g. "Strength Training"
+            _ => sport.to_string().chars().enumerate().fold(
+                String::new(),
+                |mut title, (idx, char)| {
+                    if idx > 0 && char.is_uppercase() {
+                        title.push(' ');
+                    }
+                    title.push(char);
+                    title
+                },
+            ),
+        },
+    }
+}
+
+/// Build a filename safe to use in a `Content-Disposition` header (and on most filesystems) for a
+/// downloaded activity, e.g. `2024-05-01_cycling.fit`. The start date is expressed in the
+/// activity's own timezone, and the activity id is used in its place when it is unknown.
+pub fn safe_download_filename(
+    id: &ActivityId,
+    sport: &Sport,
+    start_time: Option<&ActivityStartTime>,
+    extension: &str,
+) -> String {
+    let prefix = match start_time {
+        Some(start_time) => start_time.datetime().format("%Y-%m-%d").to_string(),
+        None => sanitize_filename_part(id.as_ref()),
+    };
+    let sport = sanitize_filename_part(&sport.to_string());
+    let extension = sanitize_filename_part(extension);
+
+    if extension.is_empty() {
+        format!("{prefix}_{sport}")
+    } else {
+        format!("{prefix}_{sport}.{extension}")
+    }
+}
+
+/// Lower snake case version of `value` only made of ASCII alphanumerics, `-` and `_`.
+fn sanitize_filename_part(value: &str) -> String {
+    let mut sanitized = String::with_capacity(value.len());
+    let mut previous: Option<char> = None;
+    for char in value.chars() {
+        let next = match char {
+            'A'..='Z' => {
+                // Split CamelCase words, e.g. "IndoorCycling" -> "indoor_cycling"
+                if previous.is_some_and(|prev| prev.is_ascii_lowercase() || prev.is_ascii_digit()) {
+                    sanitized.push('_');
+                }
+                char.to_ascii_lowercase()
+            }
+            'a'..='z' | '0'..='9' | '-' => char,
+            _ => '_',
+        };
+        if !(next == '_' && (sanitized.is_empty() || sanitized.ends_with('_'))) {
+            sanitized.push(next);
+        }
+        previous = Some(char);
+    }
+    sanitized.trim_end_matches('_').to_string()
+}
+
 #[derive(Clone, Debug, Display, PartialEq, PartialOrd, From, Into, Copy, Constructor, Default)]
 pub struct ActivityDuration(f64);
 
@@ -620,6 +1056,7 @@ pub enum Sport {
     MixedMartialArts,
     Golf,
 
+    Multisport,
     Other,
 }
 
@@ -709,6 +1146,7 @@ impl FromStr for Sport {
             "MixedMartialArts" => Ok(Self::MixedMartialArts),
             "Golf" => Ok(Self::Golf),
 
+            "Multisport" => Ok(Self::Multisport),
             "Other" => Ok(Self::Other),
             _ => Err(InvalidSport {}),
         }
@@ -793,12 +1231,152 @@ impl Sport {
             Self::MixedMartialArts => None,
             Self::Golf => None,
 
+            Self::Multisport => None,
             Self::Other => None,
         }
     }
 }
 
-#[derive(Clone, Debug, Copy, PartialEq, Display, Serialize, Deserialize)]
+/// Summary of a single session of an activity file. Multisport activities (e.g. a triathlon)
+/// have one session per sport, transitions included.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionSummary {
+    sport: Sport,
+    statistics: ActivityStatistics,
+    details: SessionDetails,
+}
+
+impl SessionSummary {
+    pub fn new(sport: Sport, statistics: ActivityStatistics) -> Self {
+        Self {
+            sport,
+            statistics,
+            details: SessionDetails::default(),
+        }
+    }
+
+    pub fn with_details(self, details: SessionDetails) -> Self {
+        Self { details, ..self }
+    }
+
+    pub fn sport(&self) -> &Sport {
+        &self.sport
+    }
+
+    pub fn statistics(&self) -> &ActivityStatistics {
+        &self.statistics
+    }
+
+    pub fn details(&self) -> &SessionDetails {
+        &self.details
+    }
+}
+
+/// An activity along with a summary built from its stored statistics, without having to load
+/// and parse its raw file. The summary has no session details, as those are only available from
+/// the raw file.
+#[derive(Clone, Debug)]
+pub struct ActivityWithSummary {
+    activity: Activity,
+    summary: SessionSummary,
+}
+
+impl ActivityWithSummary {
+    pub fn new(activity: Activity, statistics: ActivityStatistics) -> Self {
+        let summary = SessionSummary::new(*activity.sport(), statistics);
+        Self { activity, summary }
+    }
+
+    pub fn activity(&self) -> &Activity {
+        &self.activity
+    }
+
+    pub fn summary(&self) -> &SessionSummary {
+        &self.summary
+    }
+}
+
+/// Values recorded by the device in a session message. They are all optional, as a device only
+/// records what its sensors support. Enum values are kept by name, e.g. `Trail` for the sub sport.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionDetails {
+    pub sub_sport: Option<String>,
+    pub trigger: Option<String>,
+    /// In kcal
+    pub total_calories: Option<f64>,
+    /// In meters
+    pub total_ascent: Option<f64>,
+    /// In meters
+    pub total_descent: Option<f64>,
+    /// In beats per minute
+    pub avg_heart_rate: Option<f64>,
+    pub max_heart_rate: Option<f64>,
+    /// In meters per second
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    /// In revolutions (or strides) per minute
+    pub avg_cadence: Option<f64>,
+    pub max_cadence: Option<f64>,
+    /// In watts
+    pub avg_power: Option<f64>,
+    pub max_power: Option<f64>,
+    /// Aerobic training effect, from 0 to 5
+    pub total_training_effect: Option<f64>,
+    /// Anaerobic training effect, from 0 to 5
+    pub total_anaerobic_training_effect: Option<f64>,
+}
+
+/// Raw bytes of the messages an activity file contains but the app does not know about, e.g.
+/// recorded by a device more recent than the profile in use. They are keyed by global message
+/// number and field number, each occurrence of the field being kept in file order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnknownMessages(BTreeMap<(u16, u8), Vec<Vec<u8>>>);
+
+impl UnknownMessages {
+    pub fn push(&mut self, message: u16, field: u8, bytes: Vec<u8>) {
+        self.0.entry((message, field)).or_default().push(bytes);
+    }
+
+    /// Occurrences of `field` of `message`, empty if it was never recorded.
+    pub fn get(&self, message: u16, field: u8) -> &[Vec<u8>] {
+        self.0
+            .get(&(message, field))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Global numbers of the retained messages, in ascending order.
+    pub fn messages(&self) -> Vec<u16> {
+        let mut messages: Vec<u16> = self.0.keys().map(|(message, _)| *message).collect();
+        messages.dedup();
+        messages
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Beat-to-beat (RR) intervals recorded during an activity, in seconds and in file order, e.g. for
+/// heart rate variability analysis.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BeatIntervals(Vec<f32>);
+
+impl BeatIntervals {
+    pub fn new(intervals: Vec<f32>) -> Self {
+        Self(intervals)
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, Display, Serialize, Deserialize)]
 pub enum SportCategory {
     Running,
     Cycling,
@@ -821,6 +1399,10 @@ impl ActivityStatistics {
         self.0.get(stat)
     }
 
+    pub fn insert(&mut self, stat: ActivityStatistic, value: f64) {
+        self.0.insert(stat, value);
+    }
+
     pub fn items(&self) -> HashMap<String, f64> {
         HashMap::from_iter(
             self.0
@@ -828,6 +1410,87 @@ impl ActivityStatistics {
                 .map(|(stat, value)| (stat.to_string(), *value)),
         )
     }
+
+    /// Fill [ActivityStatistic::Calories] when the file does not hold it, from the power if
+    /// recorded (see [estimate_calories_from_power]) or else from the heart rate and the athlete's
+    /// profile (see [estimate_calories_from_heart_rate]). The estimate is also stored as
+    /// [ActivityStatistic::EstimatedCalories], marking the calories as estimated.
+    pub fn with_estimated_calories(
+        mut self,
+        timeseries: &ActivityTimeseries,
+        profile: Option<&AthleteProfile>,
+    ) -> Self {
+        if self.0.contains_key(&ActivityStatistic::Calories) {
+            return self;
+        }
+
+        let estimate = estimate_calories_from_power(timeseries).or_else(|| {
+            profile.and_then(|profile| estimate_calories_from_heart_rate(timeseries, profile))
+        });
+        if let Some(calories) = estimate {
+            self.insert(ActivityStatistic::Calories, calories);
+            self.insert(ActivityStatistic::EstimatedCalories, calories);
+        }
+        self
+    }
+}
+
+/// Share of the metabolic energy turned into mechanical work when cycling.
+pub const GROSS_EFFICIENCY: f64 = 0.24;
+
+const KILOJOULES_PER_KILOCALORIE: f64 = 4.184;
+
+/// Calories, in kcal, burnt to produce the mechanical work of the power timeseries, assuming a
+/// [GROSS_EFFICIENCY]. `None` if there is no power.
+pub fn estimate_calories_from_power(timeseries: &ActivityTimeseries) -> Option<f64> {
+    let work = integrate_metric(timeseries, &TimeseriesMetric::Power, |power| power)?;
+    Some(work / 1000. / GROSS_EFFICIENCY / KILOJOULES_PER_KILOCALORIE)
+}
+
+/// Calories, in kcal, estimated from the heart rate with the equation of Keytel et al. (2005),
+/// "Prediction of energy expenditure from heart rate monitoring during submaximal exercise".
+/// `None` if there is no heart rate.
+pub fn estimate_calories_from_heart_rate(
+    timeseries: &ActivityTimeseries,
+    profile: &AthleteProfile,
+) -> Option<f64> {
+    // In kJ per minute, never negative for very low heart rates
+    let rate = |heart_rate: f64| {
+        (-55.0969 + 0.6309 * heart_rate + 0.1988 * profile.weight() + 0.2017 * profile.age() as f64)
+            .max(0.)
+    };
+    let energy = integrate_metric(timeseries, &TimeseriesMetric::HeartRate, rate)?;
+    Some(energy / 60. / KILOJOULES_PER_KILOCALORIE)
+}
+
+/// Sum of `value(sample) * duration` over the samples of `metric`, each sample lasting until the
+/// next point of the timeseries. `None` if the metric has no value.
+fn integrate_metric(
+    timeseries: &ActivityTimeseries,
+    metric: &TimeseriesMetric,
+    value: impl Fn(f64) -> f64,
+) -> Option<f64> {
+    let values = timeseries
+        .metrics()
+        .iter()
+        .find(|timeseries| timeseries.metric() == metric)?
+        .values();
+    if values.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let time = timeseries.time().values();
+    Some(
+        time.windows(2)
+            .zip(values)
+            .filter_map(|(span, sample)| {
+                let duration = span[1].saturating_sub(span[0]) as f64;
+                sample
+                    .as_ref()
+                    .map(|sample| value(f64::from(sample)) * duration)
+            })
+            .sum(),
+    )
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize, Deserialize)]
@@ -837,6 +1500,20 @@ pub enum ActivityStatistic {
     Elevation,
     Distance,
     NormalizedPower,
+    /// RMSSD of the beat-to-beat intervals
+    HeartRateVariability,
+    /// Average respiration rate
+    RespirationRate,
+    /// Number of GPS fixes removed as spikes
+    RemovedGpsPoints,
+    /// Unix timestamp of the first record, which can differ from the start time reported by the
+    /// recording device
+    FirstTimestamp,
+    /// Unix timestamp of the last record
+    LastTimestamp,
+    /// Calories estimated from the timeseries when the file does not hold them, also used as
+    /// [ActivityStatistic::Calories] (see [ActivityStatistics::with_estimated_calories])
+    EstimatedCalories,
 }
 
 impl ToUnit for ActivityStatistic {
@@ -847,6 +1524,12 @@ impl ToUnit for ActivityStatistic {
             Self::Elevation => Unit::Meter,
             Self::Distance => Unit::Meter,
             Self::NormalizedPower => Unit::Watt,
+            Self::HeartRateVariability => Unit::Millisecond,
+            Self::RespirationRate => Unit::BreathPerMinute,
+            Self::RemovedGpsPoints => Unit::NumberOfPoints,
+            Self::FirstTimestamp => Unit::Second,
+            Self::LastTimestamp => Unit::Second,
+            Self::EstimatedCalories => Unit::KiloCalorie,
         }
     }
 }
@@ -871,6 +1554,13 @@ pub enum Unit {
     Second,
     NumberOfActivities,
     Degree,
+    DegreeCelsius,
+    Millisecond,
+    BreathPerMinute,
+    NumberOfPoints,
+    /// Unitless score, e.g. a stress level between 0 and 100
+    Score,
+    Percent,
 }
 
 impl fmt::Display for Unit {
@@ -889,6 +1579,12 @@ impl fmt::Display for Unit {
             Self::Second => "s",
             Self::NumberOfActivities => "activities",
             Self::Degree => "°",
+            Self::DegreeCelsius => "°C",
+            Self::Millisecond => "ms",
+            Self::BreathPerMinute => "brpm",
+            Self::NumberOfPoints => "points",
+            Self::Score => "score",
+            Self::Percent => "%",
         };
 
         write!(f, "{}", unit)
@@ -907,6 +1603,9 @@ pub enum ActivityMetricV2 {
     Elevation,
     Distance,
     NormalizedPower,
+    HeartRateVariability,
+    RespirationRate,
+    RemovedGpsPoints,
 
     // Derived from timeseries
     ActiveDuration,
@@ -961,6 +1660,15 @@ impl ActivityMetricV2 {
             Self::NormalizedPower => {
                 ActivityMetricSource::Statistic(ActivityStatistic::NormalizedPower)
             }
+            Self::HeartRateVariability => {
+                ActivityMetricSource::Statistic(ActivityStatistic::HeartRateVariability)
+            }
+            Self::RespirationRate => {
+                ActivityMetricSource::Statistic(ActivityStatistic::RespirationRate)
+            }
+            Self::RemovedGpsPoints => {
+                ActivityMetricSource::Statistic(ActivityStatistic::RemovedGpsPoints)
+            }
 
             // Derived from timeseries
             Self::ActiveDuration => ActivityMetricSource::ActiveDuration,
@@ -1106,6 +1814,16 @@ impl TryFrom<&ActivityMetricSource> for ActivityMetricV2 {
                 ActivityStatistic::Duration => ActivityMetricV2::Duration,
                 ActivityStatistic::NormalizedPower => ActivityMetricV2::NormalizedPower,
                 ActivityStatistic::Elevation => ActivityMetricV2::Elevation,
+                ActivityStatistic::HeartRateVariability => ActivityMetricV2::HeartRateVariability,
+                ActivityStatistic::RespirationRate => ActivityMetricV2::RespirationRate,
+                ActivityStatistic::RemovedGpsPoints => ActivityMetricV2::RemovedGpsPoints,
+                ActivityStatistic::FirstTimestamp
+                | ActivityStatistic::LastTimestamp
+                | ActivityStatistic::EstimatedCalories => {
+                    return Err(format!(
+                        "{statistic} cannot be parsed into an ActivityMetricV2"
+                    ));
+                }
             }),
             ActivityMetricSource::Timeseries((metric, aggregate)) => match (metric, aggregate) {
                 (TimeseriesMetric::Speed, TimeseriesAggregate::Average) => {
@@ -1195,6 +1913,7 @@ pub struct ActivityTimeseries {
     active_time: TimeseriesActiveTime,
     metrics: Vec<Timeseries>,
     laps: Vec<Lap>,
+    lengths: Vec<SwimLength>,
 }
 
 #[derive(Debug, Clone, Error)]
@@ -1203,6 +1922,20 @@ pub enum NewTimeseriesError {
     InvalidLengths,
 }
 
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ReverseTimeseriesError {
+    #[error("Only a timeseries with positions can be reversed")]
+    NoPosition,
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum TrimByDistanceError {
+    #[error("Activity has no distance data")]
+    NoDistance,
+    #[error("Cannot remove {0}m at the start and {1}m at the end of a {2}m activity")]
+    InvalidWindow(f64, f64, f64),
+}
+
 impl ActivityTimeseries {
     pub fn new(
         time: TimeseriesTime,
@@ -1223,15 +1956,21 @@ impl ActivityTimeseries {
             active_time,
             laps,
             metrics,
+            lengths: Vec::new(),
         })
     }
 
+    pub fn with_lengths(self, lengths: Vec<SwimLength>) -> Self {
+        Self { lengths, ..self }
+    }
+
     pub fn empty() -> Self {
         Self {
             time: TimeseriesTime(Vec::new()),
             active_time: TimeseriesActiveTime(Vec::new()),
             metrics: Vec::new(),
             laps: Vec::new(),
+            lengths: Vec::new(),
         }
     }
 
@@ -1247,40 +1986,581 @@ impl ActivityTimeseries {
         &self.laps
     }
 
+    /// Pool lengths of a swim, empty for other activities.
+    pub fn lengths(&self) -> &[SwimLength] {
+        &self.lengths
+    }
+
     pub fn metrics(&self) -> &[Timeseries] {
         &self.metrics
     }
 
-    /// Returns the numeric values for the requested metric, filtering out missing entries.
-    ///
-    /// This scans the stored timeseries for a matching metric and collects only the
-    /// present values, converting them to `f64`.
-    ///
-    /// # Trade-offs
-    /// - Missing samples are removed, so the resulting vector may be shorter than the
-    ///   original timeseries and no longer align by index with other metrics or time.
-    /// - Returning `None` indicates the metric was not found; an empty vector would
-    ///   indicate the metric exists but all values are missing.
-    pub fn metric_values(&self, metric: &TimeseriesMetric) -> Option<Vec<f64>> {
-        self.metrics.iter().find_map(|m| {
-            if m.metric() == metric {
-                Some(
-                    m.values()
-                        .iter()
-                        .filter_map(|val| val.as_ref().map(f64::from))
-                        .collect(),
-                )
-            } else {
-                None
-            }
-        })
-    }
-}
+    /// `(latitude, longitude)` of every point having both coordinates, in degrees.
+    pub fn positions(&self) -> Vec<(f64, f64)> {
+        let find = |metric: TimeseriesMetric| {
+            self.metrics
+                .iter()
+                .find(|m| m.metric() == &metric)
+                .map(|m| m.values())
+        };
+        let (Some(latitudes), Some(longitudes)) = (
+            find(TimeseriesMetric::Latitude),
+            find(TimeseriesMetric::Longitude),
+        ) else {
+            return vec![];
+        };
 
-/// [TimeseriesTime] represents the relative timestamp of a timeseries, starting from the
-/// [Activity::start_time]. This time is strictly increasing, i.e. event when the activity is paused.
-#[derive(Debug, Clone, PartialEq, Constructor, AsRef, Default)]
-pub struct TimeseriesTime(Vec<usize>);
+        latitudes
+            .iter()
+            .zip(longitudes)
+            .filter_map(|position| match position {
+                (Some(lat), Some(lon)) => Some((f64::from(lat), f64::from(lon))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Number of seconds spent moving, i.e. not paused and with a speed of at least `threshold`
+    /// m/s. Each point accounts for the time elapsed since the previous one. `None` if there is no
+    /// speed value at all.
+    pub fn moving_duration(&self, threshold: f64) -> Option<usize> {
+        let speed = self
+            .metrics
+            .iter()
+            .find(|metric| metric.metric() == &TimeseriesMetric::Speed)?
+            .values();
+        if speed.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let time = self.time.values();
+        let active_time = self.active_time.values();
+        Some(
+            (1..time.len())
+                .filter(|&idx| {
+                    !active_time[idx].is_paused()
+                        && speed[idx]
+                            .as_ref()
+                            .is_some_and(|speed| f64::from(speed) >= threshold)
+                })
+                .map(|idx| time[idx].saturating_sub(time[idx - 1]))
+                .sum(),
+        )
+    }
+
+    /// Number of seconds spent in each power zone, as for [Self::moving_duration] each point
+    /// accounts for the time elapsed since the previous one. Paused points and points without
+    /// power are excluded.
+    pub fn power_zone_durations(&self, ftp: &Ftp, zones: &PowerZones) -> Vec<usize> {
+        self.zone_durations(&TimeseriesMetric::Power, &zones.limits(ftp))
+    }
+
+    /// Number of seconds spent in each pace zone of [DEFAULT_PACE_ZONES], from the speed and
+    /// the threshold speed in m/s, see [Self::power_zone_durations].
+    pub fn pace_zone_durations(&self, threshold_speed: f64) -> Vec<usize> {
+        self.zone_durations(&TimeseriesMetric::Speed, &pace_zone_limits(threshold_speed))
+    }
+
+    /// Number of seconds spent in each zone of `metric`, `limits` being the upper bounds of all
+    /// the zones but the last one.
+    fn zone_durations(&self, metric: &TimeseriesMetric, limits: &[f64]) -> Vec<usize> {
+        let mut durations = vec![0; limits.len() + 1];
+        let Some(values) = self
+            .metrics
+            .iter()
+            .find(|timeseries| timeseries.metric() == metric)
+            .map(|timeseries| timeseries.values())
+        else {
+            return durations;
+        };
+
+        // A zone includes its upper bound
+        let time = self.time.values();
+        let active_time = self.active_time.values();
+        for idx in 1..time.len() {
+            if active_time[idx].is_paused() {
+                continue;
+            }
+            let Some(value) = values[idx].as_ref() else {
+                continue;
+            };
+            let zone = limits
+                .iter()
+                .position(|limit| f64::from(value) <= *limit)
+                .unwrap_or(limits.len());
+            durations[zone] += time[idx].saturating_sub(time[idx - 1]);
+        }
+        durations
+    }
+
+    /// Fill the missing values of `metric` with the values of the same metric in `other`, e.g. to
+    /// add the heart rate recorded by a watch to a ride recorded by a GPS computer.
+    ///
+    /// Both timeseries are aligned on their absolute timestamps: each point takes the value of the
+    /// last point of `other` at or before it, if no older than [MERGE_MAX_GAP] seconds. Existing
+    /// values are kept as is.
+    pub fn merge_metric(
+        &self,
+        start_time: &ActivityStartTime,
+        other: &ActivityTimeseries,
+        other_start_time: &ActivityStartTime,
+        metric: &TimeseriesMetric,
+    ) -> Self {
+        let Some(other_values) = other.metrics.iter().find(|m| m.metric() == metric) else {
+            return self.clone();
+        };
+        let offset = start_time.datetime().timestamp() - other_start_time.datetime().timestamp();
+        let other_time = other.time.values();
+
+        let value_at = |time: usize| -> Option<TimeseriesValue> {
+            let target = time as i64 + offset;
+            let end = other_time.partition_point(|t| (*t as i64) <= target);
+            other_time[..end]
+                .iter()
+                .zip(other_values.values())
+                .rev()
+                .take_while(|(t, _)| target - **t as i64 <= MERGE_MAX_GAP)
+                .find_map(|(_, value)| value.clone())
+        };
+
+        let mut metrics = self.metrics.clone();
+        match metrics.iter_mut().find(|m| m.metric() == metric) {
+            Some(existing) => {
+                for (time, value) in self.time.values().iter().zip(existing.values.iter_mut()) {
+                    if value.is_none() {
+                        *value = value_at(*time);
+                    }
+                }
+            }
+            None => metrics.push(Timeseries::new(
+                metric.clone(),
+                self.time
+                    .values()
+                    .iter()
+                    .map(|time| value_at(*time))
+                    .collect(),
+            )),
+        }
+
+        Self {
+            metrics,
+            ..self.clone()
+        }
+    }
+
+    /// Indices of the first and last points whose distance is at least `start` meters from the
+    /// beginning and `end` meters from the end of the timeseries.
+    pub fn distance_window(
+        &self,
+        start: f64,
+        end: f64,
+    ) -> Result<RangeInclusive<usize>, TrimByDistanceError> {
+        let distances = self
+            .metrics
+            .iter()
+            .find(|metric| metric.metric() == &TimeseriesMetric::Distance)
+            .map(|metric| metric.values())
+            .unwrap_or_default();
+        let total = distances
+            .iter()
+            .flatten()
+            .map(f64::from)
+            .reduce(f64::max)
+            .ok_or(TrimByDistanceError::NoDistance)?;
+
+        if !(start >= 0. && end >= 0. && start + end < total) {
+            return Err(TrimByDistanceError::InvalidWindow(start, end, total));
+        }
+
+        let within = |value: &Option<TimeseriesValue>| {
+            value
+                .as_ref()
+                .map(f64::from)
+                .is_some_and(|distance| distance >= start && distance <= total - end)
+        };
+        match (
+            distances.iter().position(within),
+            distances.iter().rposition(within),
+        ) {
+            (Some(first), Some(last)) => Ok(first..=last),
+            _ => Err(TrimByDistanceError::InvalidWindow(start, end, total)),
+        }
+    }
+
+    /// Points within `window`, with time, active time and distance starting back from 0. Laps are
+    /// cut to the window and pool lengths not entirely within it are removed.
+    pub fn slice(&self, window: RangeInclusive<usize>) -> Self {
+        let time = &self.time.values()[window.clone()];
+        let offset = time.first().copied().unwrap_or_default();
+        let end = time.last().copied().unwrap_or_default();
+
+        let active_time = &self.active_time.values()[window.clone()];
+        let active_offset = active_time
+            .iter()
+            .find_map(ActiveTime::value)
+            .unwrap_or_default();
+
+        let metrics = self
+            .metrics
+            .iter()
+            .map(|metric| {
+                let values = &metric.values()[window.clone()];
+                if metric.metric() != &TimeseriesMetric::Distance {
+                    return Timeseries::new(metric.metric().clone(), values.to_vec());
+                }
+                let start = values.first().cloned().flatten();
+                Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    values
+                        .iter()
+                        .map(|value| match (value, &start) {
+                            (Some(TimeseriesValue::Int(value)), Some(start)) => {
+                                Some(TimeseriesValue::Int(
+                                    value.saturating_sub(f64::from(start) as usize),
+                                ))
+                            }
+                            (Some(value), Some(start)) => {
+                                Some(TimeseriesValue::Float(f64::from(value) - f64::from(start)))
+                            }
+                            (value, None) => value.clone(),
+                            (None, _) => None,
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Self {
+            time: TimeseriesTime(time.iter().map(|time| time - offset).collect()),
+            active_time: TimeseriesActiveTime(
+                active_time
+                    .iter()
+                    .map(|active| match active {
+                        ActiveTime::Running(dt) => {
+                            ActiveTime::Running(dt.saturating_sub(active_offset))
+                        }
+                        ActiveTime::Paused => ActiveTime::Paused,
+                    })
+                    .collect(),
+            ),
+            metrics,
+            laps: self
+                .laps
+                .iter()
+                .filter(|lap| lap.start.max(offset) < lap.end.min(end))
+                .map(|lap| Lap::new(lap.start.max(offset) - offset, lap.end.min(end) - offset))
+                .collect(),
+            lengths: self
+                .lengths
+                .iter()
+                .filter(|length| length.start >= offset && length.end() <= end)
+                .map(|length| SwimLength {
+                    start: length.start - offset,
+                    ..length.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Points in reverse order, as if the track had been recorded from its end. Time, active time
+    /// and distance are re-derived so that they still increase from their first value, the other
+    /// metrics are reversed as is. Laps and pool lengths are mirrored. Reversing twice yields the
+    /// original timeseries.
+    ///
+    /// Only a track can be reversed, a timeseries without positions yields an error.
+    pub fn reverse(&self) -> Result<Self, ReverseTimeseriesError> {
+        if self.positions().is_empty() {
+            return Err(ReverseTimeseriesError::NoPosition);
+        }
+
+        // A value `v` becomes `first + last - v`, which keeps the series bounds
+        let mirror = |values: &[usize]| -> Vec<usize> {
+            let (Some(first), Some(last)) = (values.first(), values.last()) else {
+                return Vec::new();
+            };
+            values
+                .iter()
+                .rev()
+                .map(|value| (first + last).saturating_sub(*value))
+                .collect()
+        };
+
+        let time = self.time.values();
+        let (first, last) = (
+            time.first().copied().unwrap_or_default(),
+            time.last().copied().unwrap_or_default(),
+        );
+
+        let active_time = self.active_time.values();
+        let active_first = active_time
+            .iter()
+            .find_map(ActiveTime::value)
+            .unwrap_or_default();
+        let active_last = self.active_time.duration().unwrap_or_default();
+
+        let metrics = self
+            .metrics
+            .iter()
+            .map(|metric| {
+                let values = metric.values().iter().rev();
+                if metric.metric() != &TimeseriesMetric::Distance {
+                    return Timeseries::new(metric.metric().clone(), values.cloned().collect());
+                }
+                let mut distances = metric.values().iter().flatten().map(f64::from);
+                let first = distances.next().unwrap_or_default();
+                let bounds = first + distances.next_back().unwrap_or(first);
+                Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    values
+                        .map(|value| match value {
+                            Some(TimeseriesValue::Int(value)) => Some(TimeseriesValue::Int(
+                                (bounds as usize).saturating_sub(*value),
+                            )),
+                            Some(value) => Some(TimeseriesValue::Float(bounds - f64::from(value))),
+                            None => None,
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            time: TimeseriesTime(mirror(time)),
+            active_time: TimeseriesActiveTime(
+                active_time
+                    .iter()
+                    .rev()
+                    .map(|active| match active {
+                        ActiveTime::Running(dt) => {
+                            ActiveTime::Running((active_first + active_last).saturating_sub(*dt))
+                        }
+                        ActiveTime::Paused => ActiveTime::Paused,
+                    })
+                    .collect(),
+            ),
+            metrics,
+            laps: self
+                .laps
+                .iter()
+                .rev()
+                .map(|lap| Lap::new(first + last - lap.end, first + last - lap.start))
+                .collect(),
+            lengths: self
+                .lengths
+                .iter()
+                .rev()
+                .map(|length| SwimLength {
+                    start: first + last - length.end(),
+                    ..length.clone()
+                })
+                .collect(),
+        })
+    }
+
+    /// Drop the GPS fixes implying a speed above `max_speed` m/s both from the previous kept fix
+    /// and to the next fix, i.e. isolated spikes. The distance between the kept fixes around the
+    /// spikes is recomputed from their positions, the distance recorded elsewhere being kept as
+    /// is. Returns the cleaned timeseries and the number of removed fixes.
+    pub fn remove_gps_spikes(&self, max_speed: f64) -> (Self, usize) {
+        let find = |metric: TimeseriesMetric| {
+            self.metrics
+                .iter()
+                .find(|m| m.metric() == &metric)
+                .map(|m| m.values())
+        };
+        let (Some(latitudes), Some(longitudes)) = (
+            find(TimeseriesMetric::Latitude),
+            find(TimeseriesMetric::Longitude),
+        ) else {
+            return (self.clone(), 0);
+        };
+
+        let time = self.time.values();
+        let fixes = latitudes
+            .iter()
+            .zip(longitudes)
+            .enumerate()
+            .filter_map(|(idx, position)| match position {
+                (Some(lat), Some(lon)) => Some((idx, (f64::from(lat), f64::from(lon)))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let speed = |(from_idx, from): (usize, (f64, f64)), (to_idx, to): (usize, (f64, f64))| {
+            let duration = time[to_idx].saturating_sub(time[from_idx]).max(1);
+            haversine_distance(from, to) / duration as f64
+        };
+
+        let mut spikes = vec![false; time.len()];
+        let mut kept = Vec::with_capacity(fixes.len());
+        for (position, fix) in fixes.iter().enumerate() {
+            let is_spike = kept.last().is_some_and(|previous| {
+                speed(*previous, *fix) > max_speed
+                    && fixes
+                        .get(position + 1)
+                        .is_none_or(|next| speed(*fix, *next) > max_speed)
+            });
+            if is_spike {
+                spikes[fix.0] = true;
+            } else {
+                kept.push(*fix);
+            }
+        }
+
+        let removed = fixes.len() - kept.len();
+        if removed == 0 {
+            return (self.clone(), 0);
+        }
+
+        // Only the distance covered between the kept fixes around the spikes is recomputed. The
+        // rest of the distance recorded by the device, which can come from a wheel sensor or a
+        // footpod, is kept and shifted by the distance removed before it.
+        let mut offset = 0.;
+        let mut held: Option<f64> = None;
+        let mut previous_fix: Option<(usize, (f64, f64))> = None;
+        let mut kept_fixes = kept.iter().peekable();
+        let mut distances: Vec<Option<f64>> = Vec::with_capacity(time.len());
+        for (idx, distance) in find(TimeseriesMetric::Distance)
+            .unwrap_or_default()
+            .iter()
+            .map(|value| value.as_ref().map(f64::from))
+            .enumerate()
+        {
+            if let Some(fix) = kept_fixes.next_if(|(fix_idx, _)| *fix_idx == idx) {
+                if let (Some(held), Some((_, previous)), Some(distance)) =
+                    (held.take(), previous_fix, distance)
+                {
+                    offset = held + haversine_distance(previous, fix.1) - distance;
+                }
+                previous_fix = Some(*fix);
+            } else if spikes[idx] && held.is_none() {
+                // The distance stays the one of the last kept fix until the next one
+                held = previous_fix.and_then(|(fix_idx, _)| distances[fix_idx]);
+            }
+            distances.push(distance.map(|distance| held.unwrap_or(distance + offset)));
+        }
+
+        let metrics = self
+            .metrics
+            .iter()
+            .map(|metric| match metric.metric() {
+                TimeseriesMetric::Latitude | TimeseriesMetric::Longitude => Timeseries::new(
+                    metric.metric().clone(),
+                    metric
+                        .values()
+                        .iter()
+                        .zip(&spikes)
+                        .map(|(value, is_spike)| if *is_spike { None } else { value.clone() })
+                        .collect(),
+                ),
+                TimeseriesMetric::Distance => Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    distances
+                        .iter()
+                        .map(|distance| distance.map(TimeseriesValue::Float))
+                        .collect(),
+                ),
+                _ => metric.clone(),
+            })
+            .collect();
+
+        (
+            Self {
+                metrics,
+                ..self.clone()
+            },
+            removed,
+        )
+    }
+
+    /// Remove the positions whose accuracy, in meters, is above `max_accuracy`. `accuracies` is
+    /// aligned with the timeseries, positions without a known accuracy being kept. Other metrics,
+    /// including the distance recorded by the device, are left untouched. Returns the new
+    /// timeseries and the number of removed positions.
+    pub fn remove_inaccurate_positions(
+        &self,
+        accuracies: &[Option<f64>],
+        max_accuracy: &MaxGpsAccuracy,
+    ) -> (Self, usize) {
+        let is_inaccurate = |idx: usize| {
+            accuracies
+                .get(idx)
+                .copied()
+                .flatten()
+                .is_some_and(|accuracy| accuracy > max_accuracy.meters())
+        };
+
+        let mut removed = 0;
+        let metrics = self
+            .metrics
+            .iter()
+            .map(|metric| match metric.metric() {
+                TimeseriesMetric::Latitude | TimeseriesMetric::Longitude => {
+                    let values: Vec<_> = metric
+                        .values()
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, value)| {
+                            if value.is_some() && is_inaccurate(idx) {
+                                if metric.metric() == &TimeseriesMetric::Latitude {
+                                    removed += 1;
+                                }
+                                return None;
+                            }
+                            value.clone()
+                        })
+                        .collect();
+                    Timeseries::new(metric.metric().clone(), values)
+                }
+                _ => metric.clone(),
+            })
+            .collect();
+        if removed == 0 {
+            return (self.clone(), 0);
+        }
+
+        (
+            Self {
+                metrics,
+                ..self.clone()
+            },
+            removed,
+        )
+    }
+
+    /// Returns the numeric values for the requested metric, filtering out missing entries.
+    ///
+    /// This scans the stored timeseries for a matching metric and collects only the
+    /// present values, converting them to `f64`.
+    ///
+    /// # Trade-offs
+    /// - Missing samples are removed, so the resulting vector may be shorter than the
+    ///   original timeseries and no longer align by index with other metrics or time.
+    /// - Returning `None` indicates the metric was not found; an empty vector would
+    ///   indicate the metric exists but all values are missing.
+    pub fn metric_values(&self, metric: &TimeseriesMetric) -> Option<Vec<f64>> {
+        self.metrics.iter().find_map(|m| {
+            if m.metric() == metric {
+                Some(
+                    m.values()
+                        .iter()
+                        .filter_map(|val| val.as_ref().map(f64::from))
+                        .collect(),
+                )
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Maximum gap, in seconds, between a point and the point of another timeseries it takes its
+/// value from when merging them (see [ActivityTimeseries::merge_metric]).
+pub const MERGE_MAX_GAP: i64 = 5;
+
+/// [TimeseriesTime] represents the relative timestamp of a timeseries, starting from the
+/// [Activity::start_time]. This time is strictly increasing, i.e. event when the activity is paused.
+#[derive(Debug, Clone, PartialEq, Constructor, AsRef, Default)]
+pub struct TimeseriesTime(Vec<usize>);
 
 impl TimeseriesTime {
     pub fn len(&self) -> usize {
@@ -1315,121 +2595,735 @@ impl ActiveTime {
     }
 }
 
-/// [TimeseriesTime] represents the active time of a timeseries, i.e. it does not increase
-/// when the activity is paused.
-#[derive(Debug, Clone, PartialEq, Constructor, Default)]
-pub struct TimeseriesActiveTime(Vec<ActiveTime>);
+/// Speed threshold, in m/s, used for sports without a category.
+pub const DEFAULT_MOVING_SPEED_THRESHOLD: f64 = 0.5;
+
+/// Default speed thresholds, in m/s, under which an athlete is considered stopped.
+pub const DEFAULT_MOVING_SPEED_THRESHOLDS: [(SportCategory, f64); 11] = [
+    (SportCategory::Running, 1.),
+    (SportCategory::Cycling, 1.5),
+    (SportCategory::Swimming, 0.1),
+    (SportCategory::Walking, 0.3),
+    (SportCategory::Rowing, 0.5),
+    (SportCategory::WaterSports, 0.5),
+    (SportCategory::Ski, 1.),
+    (SportCategory::Cardio, DEFAULT_MOVING_SPEED_THRESHOLD),
+    (SportCategory::Climbing, 0.1),
+    (SportCategory::TeamSports, DEFAULT_MOVING_SPEED_THRESHOLD),
+    (SportCategory::Racket, DEFAULT_MOVING_SPEED_THRESHOLD),
+];
 
-impl TimeseriesActiveTime {
-    pub fn len(&self) -> usize {
-        self.0.len()
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Moving speed threshold must be a finite and positive speed, got {0}")]
+pub struct InvalidMovingSpeedThreshold(f64);
+
+/// Speed thresholds, in m/s, under which an athlete is considered stopped when computing the
+/// moving duration of an activity. Only overridden categories are stored, the others use
+/// [DEFAULT_MOVING_SPEED_THRESHOLDS].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "HashMap<SportCategory, f64>",
+    into = "HashMap<SportCategory, f64>"
+)]
+pub struct MovingSpeedThresholds(HashMap<SportCategory, f64>);
+
+impl TryFrom<HashMap<SportCategory, f64>> for MovingSpeedThresholds {
+    type Error = InvalidMovingSpeedThreshold;
+
+    fn try_from(overrides: HashMap<SportCategory, f64>) -> Result<Self, Self::Error> {
+        if let Some(invalid) = overrides
+            .values()
+            .find(|threshold| !threshold.is_finite() || **threshold < 0.)
+        {
+            return Err(InvalidMovingSpeedThreshold(*invalid));
+        }
+        Ok(Self(overrides))
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+impl From<MovingSpeedThresholds> for HashMap<SportCategory, f64> {
+    fn from(value: MovingSpeedThresholds) -> Self {
+        value.0
     }
+}
 
-    pub fn values(&self) -> &[ActiveTime] {
-        &self.0
+impl MovingSpeedThresholds {
+    pub fn for_category(&self, category: &SportCategory) -> f64 {
+        self.0.get(category).copied().unwrap_or_else(|| {
+            DEFAULT_MOVING_SPEED_THRESHOLDS
+                .iter()
+                .find_map(|(default_category, threshold)| {
+                    (default_category == category).then_some(*threshold)
+                })
+                .unwrap_or(DEFAULT_MOVING_SPEED_THRESHOLD)
+        })
     }
 
-    pub fn duration(&self) -> Option<usize> {
-        self.0.iter().rev().find_map(|dt| dt.value())
+    pub fn for_sport(&self, sport: &Sport) -> f64 {
+        sport
+            .category()
+            .map_or(DEFAULT_MOVING_SPEED_THRESHOLD, |category| {
+                self.for_category(&category)
+            })
     }
-}
 
-#[derive(Debug, Clone, Constructor, PartialEq)]
-pub struct Lap {
-    start: usize,
-    end: usize,
+    /// Threshold of every [SportCategory], overridden or not.
+    pub fn effective(&self) -> HashMap<SportCategory, f64> {
+        DEFAULT_MOVING_SPEED_THRESHOLDS
+            .iter()
+            .map(|(category, _)| (*category, self.for_category(category)))
+            .collect()
+    }
 }
 
-impl Lap {
-    pub fn start(&self) -> usize {
-        self.start
+/// Speed, in m/s, above which a GPS fix is considered a spike for sports without a category.
+pub const DEFAULT_MAX_GPS_SPEED: f64 = 50.;
+
+/// Default speeds, in m/s, above which moving between two consecutive GPS fixes is considered
+/// implausible.
+pub const DEFAULT_MAX_GPS_SPEEDS: [(SportCategory, f64); 11] = [
+    (SportCategory::Running, 12.),
+    (SportCategory::Cycling, 35.),
+    (SportCategory::Swimming, 5.),
+    (SportCategory::Walking, 6.),
+    (SportCategory::Rowing, 10.),
+    (SportCategory::WaterSports, 30.),
+    (SportCategory::Ski, 45.),
+    (SportCategory::Cardio, 12.),
+    (SportCategory::Climbing, 5.),
+    (SportCategory::TeamSports, 12.),
+    (SportCategory::Racket, 12.),
+];
+
+/// Maximum speeds, in m/s, used to detect GPS spikes (see
+/// [ActivityTimeseries::remove_gps_spikes]). Only overridden categories are stored, the others
+/// use [DEFAULT_MAX_GPS_SPEEDS].
+#[derive(Debug, Clone, Default, PartialEq, Constructor)]
+pub struct MaxGpsSpeeds(HashMap<SportCategory, f64>);
+
+impl MaxGpsSpeeds {
+    pub fn for_sport(&self, sport: &Sport) -> f64 {
+        let Some(category) = sport.category() else {
+            return DEFAULT_MAX_GPS_SPEED;
+        };
+        self.0.get(&category).copied().unwrap_or_else(|| {
+            DEFAULT_MAX_GPS_SPEEDS
+                .iter()
+                .find_map(|(default_category, speed)| {
+                    (default_category == &category).then_some(*speed)
+                })
+                .unwrap_or(DEFAULT_MAX_GPS_SPEED)
+        })
     }
+}
 
-    pub fn end(&self) -> usize {
-        self.end
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("GPS accuracy threshold must be a finite and strictly positive distance, got {0}")]
+pub struct InvalidMaxGpsAccuracy(f64);
+
+/// Accuracy, in meters, above which a GPS fix is dropped from the track (see
+/// [ActivityTimeseries::remove_inaccurate_positions]).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct MaxGpsAccuracy(f64);
+
+impl TryFrom<f64> for MaxGpsAccuracy {
+    type Error = InvalidMaxGpsAccuracy;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value <= 0. {
+            return Err(InvalidMaxGpsAccuracy(value));
+        }
+        Ok(Self(value))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Constructor)]
-pub struct Timeseries {
-    metric: TimeseriesMetric,
-    values: Vec<Option<TimeseriesValue>>,
+impl From<MaxGpsAccuracy> for f64 {
+    fn from(value: MaxGpsAccuracy) -> Self {
+        value.0
+    }
 }
 
-impl Timeseries {
-    pub fn metric(&self) -> &TimeseriesMetric {
-        &self.metric
+impl MaxGpsAccuracy {
+    pub fn meters(&self) -> f64 {
+        self.0
     }
+}
 
-    pub fn len(&self) -> usize {
-        self.values.len()
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("FTP must be a finite and strictly positive power, got {0}")]
+pub struct InvalidFtp(f64);
+
+/// Functional Threshold Power of the athlete, in watts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Ftp(f64);
+
+impl TryFrom<f64> for Ftp {
+    type Error = InvalidFtp;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value <= 0. {
+            return Err(InvalidFtp(value));
+        }
+        Ok(Self(value))
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.values.is_empty()
+impl From<Ftp> for f64 {
+    fn from(value: Ftp) -> Self {
+        value.0
     }
+}
 
-    pub fn values(&self) -> &[Option<TimeseriesValue>] {
-        &self.values
+impl Ftp {
+    pub fn watts(&self) -> f64 {
+        self.0
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Display, Serialize, Deserialize)]
-pub enum TimeseriesMetric {
-    Speed,
-    Power,
-    HeartRate,
-    Distance,
-    Cadence,
-    Altitude,
-    Pace,
-    Latitude,
-    Longitude,
+/// Upper bounds, as fractions of the FTP, of the first 6 zones of the Coggan 7-zone model. The
+/// 7th zone has no upper bound.
+pub const DEFAULT_POWER_ZONES: [f64; 6] = [0.55, 0.75, 0.90, 1.05, 1.20, 1.50];
+
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Power zones must be 6 finite, positive and increasing FTP fractions, got {0:?}")]
+pub struct InvalidPowerZones(Vec<f64>);
+
+/// Boundaries of the power zones, as fractions of the FTP. Each value is the upper bound of a
+/// zone and the lower bound of the next one, see [DEFAULT_POWER_ZONES].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<f64>", into = "Vec<f64>")]
+pub struct PowerZones(Vec<f64>);
+
+impl Default for PowerZones {
+    fn default() -> Self {
+        Self(DEFAULT_POWER_ZONES.to_vec())
+    }
 }
 
-impl ToUnit for TimeseriesMetric {
-    fn unit(&self) -> Unit {
-        match self {
-            Self::Distance => Unit::Meter,
-            Self::Power => Unit::Watt,
-            Self::HeartRate => Unit::BeatPerMinute,
-            Self::Speed => Unit::MeterPerSecond,
-            Self::Altitude => Unit::Meter,
-            Self::Cadence => Unit::RevolutionPerMinute,
-            Self::Pace => Unit::SecondPerMeter,
-            Self::Latitude => Unit::Degree,
-            Self::Longitude => Unit::Degree,
+impl TryFrom<Vec<f64>> for PowerZones {
+    type Error = InvalidPowerZones;
+
+    fn try_from(bounds: Vec<f64>) -> Result<Self, Self::Error> {
+        let valid = bounds.len() == DEFAULT_POWER_ZONES.len()
+            && bounds.iter().all(|bound| bound.is_finite() && *bound > 0.)
+            && bounds.windows(2).all(|pair| pair[0] < pair[1]);
+        if !valid {
+            return Err(InvalidPowerZones(bounds));
         }
+        Ok(Self(bounds))
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum TimeseriesValue {
-    Int(usize),
-    Float(f64),
+impl From<PowerZones> for Vec<f64> {
+    fn from(value: PowerZones) -> Self {
+        value.0
+    }
 }
 
-impl From<&TimeseriesValue> for f64 {
-    fn from(value: &TimeseriesValue) -> Self {
-        match value {
-            TimeseriesValue::Int(val) => *val as f64,
-            TimeseriesValue::Float(val) => *val,
-        }
+impl PowerZones {
+    /// Upper bound of the first zones, rounded to the watt.
+    fn limits(&self, ftp: &Ftp) -> Vec<f64> {
+        self.0
+            .iter()
+            .map(|fraction| (fraction * ftp.watts()).round())
+            .collect()
+    }
+
+    /// `(lower, upper)` bounds of each zone, in watts. The upper bound of the last zone is `None`.
+    pub fn bounds(&self, ftp: &Ftp) -> Vec<(f64, Option<f64>)> {
+        let limits = self.limits(ftp);
+        std::iter::once(0.)
+            .chain(limits.iter().copied())
+            .zip(
+                limits
+                    .iter()
+                    .copied()
+                    .map(Some)
+                    .chain(std::iter::once(None)),
+            )
+            .collect()
     }
 }
 
-impl TimeseriesValue {
-    pub fn inverse(&self) -> Option<TimeseriesValue> {
-        match self {
-            TimeseriesValue::Float(val) => {
-                if *val == 0. {
-                    return None;
-                }
-                Some(Self::Float(1. / val))
+/// Upper bounds, as fractions of the threshold speed, of the first 4 of the 5 pace zones (Friel's
+/// running pace zones, converted to speeds). The 5th zone has no upper bound.
+pub const DEFAULT_PACE_ZONES: [f64; 4] = [0.78, 0.88, 0.94, 1.01];
+
+/// Upper bound of the first pace zones, in m/s.
+fn pace_zone_limits(threshold_speed: f64) -> Vec<f64> {
+    DEFAULT_PACE_ZONES
+        .iter()
+        .map(|fraction| fraction * threshold_speed)
+        .collect()
+}
+
+/// `(lower, upper)` bounds of each pace zone, in m/s. The upper bound of the last zone is `None`.
+pub fn pace_zone_bounds(threshold_speed: f64) -> Vec<(f64, Option<f64>)> {
+    let limits = pace_zone_limits(threshold_speed);
+    std::iter::once(0.)
+        .chain(limits.iter().copied())
+        .zip(
+            limits
+                .iter()
+                .copied()
+                .map(Some)
+                .chain(std::iter::once(None)),
+        )
+        .collect()
+}
+
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Threshold pace must be a finite and strictly positive duration, got {0}")]
+pub struct InvalidThresholdPace(f64);
+
+/// Pace held at threshold, in seconds per distance unit (the kilometre for running, 100 meters
+/// for the swimming Critical Swim Speed).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct ThresholdPace(f64);
+
+impl TryFrom<f64> for ThresholdPace {
+    type Error = InvalidThresholdPace;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value <= 0. {
+            return Err(InvalidThresholdPace(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<ThresholdPace> for f64 {
+    fn from(value: ThresholdPace) -> Self {
+        value.0
+    }
+}
+
+impl ThresholdPace {
+    pub fn seconds(&self) -> f64 {
+        self.0
+    }
+
+    /// Speed in m/s of the pace over `distance` meters.
+    fn speed(&self, distance: f64) -> f64 {
+        distance / self.0
+    }
+}
+
+/// Thresholds of the athlete for the sports where a single FTP does not fit, each one used to
+/// compute the zones of the activities of its sport category.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SportThresholds {
+    /// Cycling FTP, in watts, the global FTP is used if not set
+    pub cycling_ftp: Option<Ftp>,
+    /// Running threshold pace, in seconds per kilometre
+    pub running_threshold_pace: Option<ThresholdPace>,
+    /// Swimming Critical Swim Speed, in seconds per 100 meters
+    pub swimming_css: Option<ThresholdPace>,
+}
+
+impl SportThresholds {
+    /// FTP for the power zones of an activity of `sport`: the cycling FTP for cycling, the
+    /// global `ftp` otherwise.
+    pub fn ftp(&self, sport: &Sport, ftp: Option<Ftp>) -> Option<Ftp> {
+        match sport.category() {
+            Some(SportCategory::Cycling) => self.cycling_ftp.or(ftp),
+            _ => ftp,
+        }
+    }
+
+    /// Threshold speed, in m/s, for the pace zones of an activity of `sport`. Only running and
+    /// swimming have one.
+    pub fn threshold_speed(&self, sport: &Sport) -> Option<f64> {
+        match sport.category() {
+            Some(SportCategory::Running) => {
+                self.running_threshold_pace.map(|pace| pace.speed(1000.))
+            }
+            Some(SportCategory::Swimming) => self.swimming_css.map(|pace| pace.speed(100.)),
+            _ => None,
+        }
+    }
+}
+
+/// Channels returned when fetching an activity's streams without specifying them.
+pub const DEFAULT_STREAMS: [TimeseriesMetric; 4] = [
+    TimeseriesMetric::Speed,
+    TimeseriesMetric::HeartRate,
+    TimeseriesMetric::Power,
+    TimeseriesMetric::Altitude,
+];
+
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Default streams must be a non empty list of distinct channels, got {0:?}")]
+pub struct InvalidDefaultStreams(Vec<TimeseriesMetric>);
+
+/// Channels to return when fetching an activity's streams without specifying them, see
+/// [DEFAULT_STREAMS].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<TimeseriesMetric>", into = "Vec<TimeseriesMetric>")]
+pub struct DefaultStreams(Vec<TimeseriesMetric>);
+
+impl Default for DefaultStreams {
+    fn default() -> Self {
+        Self(DEFAULT_STREAMS.to_vec())
+    }
+}
+
+impl TryFrom<Vec<TimeseriesMetric>> for DefaultStreams {
+    type Error = InvalidDefaultStreams;
+
+    fn try_from(channels: Vec<TimeseriesMetric>) -> Result<Self, Self::Error> {
+        let distinct = channels
+            .iter()
+            .enumerate()
+            .all(|(idx, channel)| !channels[..idx].contains(channel));
+        if channels.is_empty() || !distinct {
+            return Err(InvalidDefaultStreams(channels));
+        }
+        Ok(Self(channels))
+    }
+}
+
+impl From<DefaultStreams> for Vec<TimeseriesMetric> {
+    fn from(value: DefaultStreams) -> Self {
+        value.0
+    }
+}
+
+impl DefaultStreams {
+    pub fn channels(&self) -> &[TimeseriesMetric] {
+        &self.0
+    }
+}
+
+/// Distance, in meters, under which a position is considered to be at a [CommuteLocations] place.
+pub const COMMUTE_LOCATION_RADIUS: f64 = 500.;
+
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error(
+    "Invalid position ({0}, {1}), latitude must be within [-90, 90] and longitude within [-180, 180]"
+)]
+pub struct InvalidGeoPoint(f64, f64);
+
+/// A `(latitude, longitude)` position, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "(f64, f64)", into = "(f64, f64)")]
+pub struct GeoPoint {
+    lat: f64,
+    lon: f64,
+}
+
+impl GeoPoint {
+    pub fn new(lat: f64, lon: f64) -> Result<Self, InvalidGeoPoint> {
+        if !((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)) {
+            return Err(InvalidGeoPoint(lat, lon));
+        }
+        Ok(Self { lat, lon })
+    }
+
+    pub fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    pub fn lon(&self) -> f64 {
+        self.lon
+    }
+
+    fn is_near(&self, position: (f64, f64)) -> bool {
+        haversine_distance((self.lat, self.lon), position) <= COMMUTE_LOCATION_RADIUS
+    }
+}
+
+impl TryFrom<(f64, f64)> for GeoPoint {
+    type Error = InvalidGeoPoint;
+
+    fn try_from((lat, lon): (f64, f64)) -> Result<Self, Self::Error> {
+        Self::new(lat, lon)
+    }
+}
+
+impl From<GeoPoint> for (f64, f64) {
+    fn from(value: GeoPoint) -> Self {
+        (value.lat, value.lon)
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Athlete weight must be a finite and strictly positive mass, got {0}")]
+pub struct InvalidAthleteProfile(f64);
+
+/// Weight, in kg, and age of the athlete, used to estimate the calories of activities recorded
+/// without power (see [estimate_calories_from_heart_rate]).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "AthleteProfileValues")]
+pub struct AthleteProfile {
+    weight: f64,
+    age: u32,
+}
+
+#[derive(Deserialize)]
+struct AthleteProfileValues {
+    weight: f64,
+    age: u32,
+}
+
+impl TryFrom<AthleteProfileValues> for AthleteProfile {
+    type Error = InvalidAthleteProfile;
+
+    fn try_from(values: AthleteProfileValues) -> Result<Self, Self::Error> {
+        Self::new(values.weight, values.age)
+    }
+}
+
+impl AthleteProfile {
+    pub fn new(weight: f64, age: u32) -> Result<Self, InvalidAthleteProfile> {
+        if !weight.is_finite() || weight <= 0. {
+            return Err(InvalidAthleteProfile(weight));
+        }
+        Ok(Self { weight, age })
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+}
+
+/// Home and work places of the athlete, used to recognize commutes among uploaded activities.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Constructor)]
+pub struct CommuteLocations {
+    home: GeoPoint,
+    work: GeoPoint,
+}
+
+impl CommuteLocations {
+    pub fn home(&self) -> &GeoPoint {
+        &self.home
+    }
+
+    pub fn work(&self) -> &GeoPoint {
+        &self.work
+    }
+
+    /// Whether a track looks like a commute: it starts at home (resp. work) and either ends at
+    /// work (resp. home), or comes back to its start after passing by work (resp. home). Places
+    /// are matched within [COMMUTE_LOCATION_RADIUS].
+    pub fn matches(&self, positions: &[(f64, f64)]) -> bool {
+        let (Some(start), Some(end)) = (positions.first(), positions.last()) else {
+            return false;
+        };
+
+        [(self.home, self.work), (self.work, self.home)]
+            .iter()
+            .any(|(from, to)| {
+                from.is_near(*start)
+                    && (to.is_near(*end)
+                        || from.is_near(*end)
+                            && positions.iter().any(|position| to.is_near(*position)))
+            })
+    }
+}
+
+/// Great-circle distance, in meters, between two (latitude, longitude) positions in degrees.
+pub fn haversine_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    const EARTH_RADIUS: f64 = 6_371_000.;
+
+    let (lat_from, lat_to) = (from.0.to_radians(), to.0.to_radians());
+    let delta_lat = lat_to - lat_from;
+    let delta_lon = (to.1 - from.1).to_radians();
+
+    let a = (delta_lat / 2.).sin().powi(2)
+        + lat_from.cos() * lat_to.cos() * (delta_lon / 2.).sin().powi(2);
+    2. * EARTH_RADIUS * a.sqrt().asin()
+}
+
+/// [TimeseriesTime] represents the active time of a timeseries, i.e. it does not increase
+/// when the activity is paused.
+#[derive(Debug, Clone, PartialEq, Constructor, Default)]
+pub struct TimeseriesActiveTime(Vec<ActiveTime>);
+
+impl TimeseriesActiveTime {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn values(&self) -> &[ActiveTime] {
+        &self.0
+    }
+
+    pub fn duration(&self) -> Option<usize> {
+        self.0.iter().rev().find_map(|dt| dt.value())
+    }
+}
+
+#[derive(Debug, Clone, Constructor, PartialEq)]
+pub struct Lap {
+    start: usize,
+    end: usize,
+}
+
+impl Lap {
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+/// Stroke used to swim a pool length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum SwimStroke {
+    Freestyle,
+    Backstroke,
+    Breaststroke,
+    Butterfly,
+    Drill,
+    /// Several strokes within the same length
+    Mixed,
+    IndividualMedley,
+}
+
+/// A single active pool length of a swim. The stroke is unknown when the device could not detect
+/// it, which is frequent for drills.
+#[derive(Debug, Clone, Constructor, PartialEq)]
+pub struct SwimLength {
+    /// Start of the length, in seconds from the start of the activity
+    start: usize,
+    /// Duration of the length, in seconds
+    duration: f64,
+    stroke: Option<SwimStroke>,
+    total_strokes: Option<usize>,
+}
+
+impl SwimLength {
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.start + self.duration.round() as usize
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn stroke(&self) -> Option<&SwimStroke> {
+        self.stroke.as_ref()
+    }
+
+    pub fn total_strokes(&self) -> Option<usize> {
+        self.total_strokes
+    }
+
+    /// SWOLF (swim golf) score of the length: its number of strokes plus its duration in seconds.
+    /// Not defined for drills, whose strokes are not counted.
+    pub fn swolf(&self) -> Option<usize> {
+        if self.stroke == Some(SwimStroke::Drill) {
+            return None;
+        }
+        self.total_strokes
+            .map(|strokes| strokes + self.duration.round() as usize)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Constructor)]
+pub struct Timeseries {
+    metric: TimeseriesMetric,
+    values: Vec<Option<TimeseriesValue>>,
+}
+
+impl Timeseries {
+    pub fn metric(&self) -> &TimeseriesMetric {
+        &self.metric
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[Option<TimeseriesValue>] {
+        &self.values
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Display, Serialize, Deserialize)]
+pub enum TimeseriesMetric {
+    Speed,
+    Power,
+    HeartRate,
+    Distance,
+    Cadence,
+    Altitude,
+    Pace,
+    Latitude,
+    Longitude,
+    Temperature,
+    RespirationRate,
+    StressLevel,
+    /// Slope of the terrain, in percent
+    Grade,
+}
+
+impl ToUnit for TimeseriesMetric {
+    fn unit(&self) -> Unit {
+        match self {
+            Self::Distance => Unit::Meter,
+            Self::Power => Unit::Watt,
+            Self::HeartRate => Unit::BeatPerMinute,
+            Self::Speed => Unit::MeterPerSecond,
+            Self::Altitude => Unit::Meter,
+            Self::Cadence => Unit::RevolutionPerMinute,
+            Self::Pace => Unit::SecondPerMeter,
+            Self::Latitude => Unit::Degree,
+            Self::Longitude => Unit::Degree,
+            Self::Temperature => Unit::DegreeCelsius,
+            Self::RespirationRate => Unit::BreathPerMinute,
+            Self::StressLevel => Unit::Score,
+            Self::Grade => Unit::Percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeseriesValue {
+    Int(usize),
+    Float(f64),
+}
+
+impl From<&TimeseriesValue> for f64 {
+    fn from(value: &TimeseriesValue) -> Self {
+        match value {
+            TimeseriesValue::Int(val) => *val as f64,
+            TimeseriesValue::Float(val) => *val,
+        }
+    }
+}
+
+impl TimeseriesValue {
+    pub fn inverse(&self) -> Option<TimeseriesValue> {
+        match self {
+            TimeseriesValue::Float(val) => {
+                if *val == 0. {
+                    return None;
+                }
+                Some(Self::Float(1. / val))
             }
             TimeseriesValue::Int(val) => {
                 if *val == 0 {
@@ -1514,6 +3408,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_activity_id_round_trips_through_string() {
+        let id: ActivityId = "abc-123".to_string().into();
+
+        assert_eq!(id.to_string(), "abc-123");
+        assert_eq!(ActivityId::from(id.to_string().as_str()), id);
+    }
+
+    #[test]
+    fn test_activity_id_deserializes_from_string() {
+        let id: ActivityId = serde_json::from_str("\"abc-123\"").unwrap();
+
+        assert_eq!(id, ActivityId::from("abc-123"));
+        assert!(serde_json::from_str::<ActivityId>("123").is_err());
+    }
+
     #[test]
     fn test_different_activities_different_natural_keys() {
         let first_activity = Activity::new_empty(
@@ -1622,10 +3532,208 @@ mod tests {
         assert_eq!(BonkStatus::None.to_string(), "none");
         assert_eq!(BonkStatus::Bonked.to_string(), "bonked");
     }
+
+    #[test]
+    fn test_visibility_from_str_and_display() {
+        for visibility in [
+            ActivityVisibility::Private,
+            ActivityVisibility::Unlisted,
+            ActivityVisibility::Public,
+        ] {
+            assert_eq!(
+                ActivityVisibility::from_str(&visibility.to_string()).unwrap(),
+                visibility
+            );
+        }
+        assert_eq!(
+            ActivityVisibility::from_str("PUBLIC").unwrap(),
+            ActivityVisibility::Public
+        );
+        assert!(ActivityVisibility::from_str("friends").is_err());
+        assert_eq!(ActivityVisibility::default(), ActivityVisibility::Private);
+    }
 }
 
 #[cfg(test)]
-mod test_timeseries {
+mod test_swim_length {
+
+    use super::*;
+
+    #[test]
+    fn test_swolf_is_strokes_plus_seconds() {
+        let length = SwimLength::new(10, 32.6, Some(SwimStroke::Freestyle), Some(18));
+
+        assert_eq!(length.swolf(), Some(51));
+        assert_eq!(length.end(), 43);
+    }
+
+    #[test]
+    fn test_swolf_without_stroke_count() {
+        let length = SwimLength::new(0, 40., Some(SwimStroke::Breaststroke), None);
+
+        assert_eq!(length.swolf(), None);
+    }
+
+    #[test]
+    fn test_swolf_of_unknown_and_mixed_strokes() {
+        assert_eq!(SwimLength::new(0, 40., None, Some(20)).swolf(), Some(60));
+        assert_eq!(
+            SwimLength::new(0, 40., Some(SwimStroke::Mixed), Some(20)).swolf(),
+            Some(60)
+        );
+    }
+
+    #[test]
+    fn test_no_swolf_for_drills() {
+        let length = SwimLength::new(0, 55., Some(SwimStroke::Drill), Some(12));
+
+        assert_eq!(length.swolf(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_safe_download_filename {
+
+    use super::*;
+
+    #[test]
+    fn test_filename_from_start_date_and_sport() {
+        let start_time = ActivityStartTime::new(
+            "2024-05-01T07:30:00+02:00"
+                .parse::<DateTime<FixedOffset>>()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            safe_download_filename(
+                &ActivityId::from("id"),
+                &Sport::Cycling,
+                Some(&start_time),
+                "fit"
+            ),
+            "2024-05-01_cycling.fit"
+        );
+        assert_eq!(
+            safe_download_filename(
+                &ActivityId::from("id"),
+                &Sport::IndoorCycling,
+                Some(&start_time),
+                "gpx"
+            ),
+            "2024-05-01_indoor_cycling.gpx"
+        );
+    }
+
+    #[test]
+    fn test_filename_uses_local_date_of_activity() {
+        // Still the 30th of April in UTC
+        let start_time = ActivityStartTime::new(
+            "2024-05-01T01:00:00+03:00"
+                .parse::<DateTime<FixedOffset>>()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            safe_download_filename(
+                &ActivityId::from("id"),
+                &Sport::Running,
+                Some(&start_time),
+                "csv"
+            ),
+            "2024-05-01_running.csv"
+        );
+    }
+
+    #[test]
+    fn test_filename_without_start_time_uses_id() {
+        assert_eq!(
+            safe_download_filename(
+                &ActivityId::from("1b4e28ba-2fa1-11d2-883f-0016d3cca427"),
+                &Sport::Swimming,
+                None,
+                "fit"
+            ),
+            "1b4e28ba-2fa1-11d2-883f-0016d3cca427_swimming.fit"
+        );
+    }
+
+    #[test]
+    fn test_filename_is_sanitized() {
+        assert_eq!(
+            safe_download_filename(
+                &ActivityId::from("../my \"best\" ride;\r\n"),
+                &Sport::Cycling,
+                None,
+                ".T/CX\""
+            ),
+            "my_best_ride_cycling.t_cx"
+        );
+        assert_eq!(
+            safe_download_filename(&ActivityId::from("id"), &Sport::Cycling, None, ""),
+            "id_cycling"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_default_title {
+
+    use super::*;
+
+    fn start_at(datetime: &str) -> ActivityStartTime {
+        ActivityStartTime::new(datetime.parse::<DateTime<FixedOffset>>().unwrap())
+    }
+
+    #[test]
+    fn test_default_title_time_of_day_buckets() {
+        for (datetime, expected) in [
+            ("2024-05-01T04:59:00+00:00", "Night Run"),
+            ("2024-05-01T05:00:00+00:00", "Morning Run"),
+            ("2024-05-01T10:59:00+00:00", "Morning Run"),
+            ("2024-05-01T11:00:00+00:00", "Lunch Run"),
+            ("2024-05-01T14:00:00+00:00", "Afternoon Run"),
+            ("2024-05-01T18:00:00+00:00", "Evening Run"),
+            ("2024-05-01T21:59:00+00:00", "Evening Run"),
+            ("2024-05-01T22:00:00+00:00", "Night Run"),
+        ] {
+            assert_eq!(
+                default_title(&Sport::Running, &start_at(datetime)),
+                expected,
+                "{datetime}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_title_uses_local_hour() {
+        // 6h UTC but 12h in the activity's timezone
+        let start_time = start_at("2024-05-01T12:00:00+06:00");
+
+        assert_eq!(default_title(&Sport::Cycling, &start_time), "Lunch Ride");
+    }
+
+    #[test]
+    fn test_default_title_sports() {
+        let start_time = start_at("2024-05-01T19:00:00+02:00");
+
+        for (sport, expected) in [
+            (Sport::TrailRunning, "Evening Run"),
+            (Sport::MountainBiking, "Evening Ride"),
+            (Sport::OpenWaterSwimming, "Evening Swim"),
+            (Sport::Walking, "Evening Walk"),
+            (Sport::Hiking, "Evening Hike"),
+            (Sport::IndoorRowing, "Evening Row"),
+            (Sport::StrengthTraining, "Evening Strength Training"),
+            (Sport::Hiit, "Evening HIIT"),
+            (Sport::Other, "Evening Activity"),
+        ] {
+            assert_eq!(default_title(&sport, &start_time), expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_timeseries {
 
     use super::*;
 
@@ -2430,4 +4538,967 @@ mod test_timeseries {
 
         assert_eq!(result, Some(3600.0));
     }
+
+    fn running_timeseries(time: Vec<usize>, metrics: Vec<Timeseries>) -> ActivityTimeseries {
+        let active_time = time.iter().map(|t| ActiveTime::Running(*t)).collect();
+        ActivityTimeseries::new(
+            TimeseriesTime::new(time),
+            TimeseriesActiveTime::new(active_time),
+            vec![],
+            metrics,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_merge_heart_rate_into_gps_only_ride() {
+        let ride = running_timeseries(
+            vec![0, 1, 2, 3, 4],
+            vec![Timeseries::new(
+                TimeseriesMetric::Speed,
+                vec![Some(TimeseriesValue::Float(8.)); 5],
+            )],
+        );
+        // HR strap started 2 seconds after the GPS computer
+        let heart_rate = running_timeseries(
+            vec![0, 1, 2],
+            vec![Timeseries::new(
+                TimeseriesMetric::HeartRate,
+                vec![
+                    Some(TimeseriesValue::Int(120)),
+                    Some(TimeseriesValue::Int(125)),
+                    Some(TimeseriesValue::Int(130)),
+                ],
+            )],
+        );
+
+        let merged = ride.merge_metric(
+            &ActivityStartTime::from_timestamp(1000).unwrap(),
+            &heart_rate,
+            &ActivityStartTime::from_timestamp(1002).unwrap(),
+            &TimeseriesMetric::HeartRate,
+        );
+
+        assert_eq!(merged.metrics().len(), 2);
+        assert_eq!(
+            merged.metrics()[1],
+            Timeseries::new(
+                TimeseriesMetric::HeartRate,
+                vec![
+                    None,
+                    None,
+                    Some(TimeseriesValue::Int(120)),
+                    Some(TimeseriesValue::Int(125)),
+                    Some(TimeseriesValue::Int(130)),
+                ]
+            )
+        );
+        assert_eq!(merged.time(), ride.time());
+    }
+
+    #[test]
+    fn test_merge_metric_keeps_existing_values_and_fills_gaps() {
+        let ride = running_timeseries(
+            vec![0, 1, 2, 10],
+            vec![Timeseries::new(
+                TimeseriesMetric::HeartRate,
+                vec![Some(TimeseriesValue::Int(100)), None, None, None],
+            )],
+        );
+        let heart_rate = running_timeseries(
+            vec![0, 2],
+            vec![Timeseries::new(
+                TimeseriesMetric::HeartRate,
+                vec![
+                    Some(TimeseriesValue::Int(120)),
+                    Some(TimeseriesValue::Int(130)),
+                ],
+            )],
+        );
+
+        let merged = ride.merge_metric(
+            &ActivityStartTime::from_timestamp(1000).unwrap(),
+            &heart_rate,
+            &ActivityStartTime::from_timestamp(1000).unwrap(),
+            &TimeseriesMetric::HeartRate,
+        );
+
+        // Last point is more than MERGE_MAX_GAP seconds after the last HR sample
+        assert_eq!(
+            merged.metrics()[0].values(),
+            &[
+                Some(TimeseriesValue::Int(100)),
+                Some(TimeseriesValue::Int(120)),
+                Some(TimeseriesValue::Int(130)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_metric_missing_in_other_is_noop() {
+        let ride = running_timeseries(vec![0, 1], vec![]);
+
+        let merged = ride.merge_metric(
+            &ActivityStartTime::from_timestamp(1000).unwrap(),
+            &running_timeseries(vec![0, 1], vec![]),
+            &ActivityStartTime::from_timestamp(1000).unwrap(),
+            &TimeseriesMetric::HeartRate,
+        );
+
+        assert_eq!(merged, ride);
+    }
+
+    fn activity_with_speed(sport: Sport, speed: Vec<f64>) -> ActivityWithParsedData {
+        let time = (0..speed.len()).collect();
+        ActivityWithParsedData::new(
+            Activity::new_empty(
+                ActivityId::default(),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(1000).unwrap(),
+                ActivityDuration::from(speed.len() as f64),
+                sport,
+            ),
+            running_timeseries(
+                time,
+                vec![Timeseries::new(
+                    TimeseriesMetric::Speed,
+                    speed
+                        .into_iter()
+                        .map(|speed| Some(TimeseriesValue::Float(speed)))
+                        .collect(),
+                )],
+            ),
+            ActivityStatistics::default(),
+        )
+    }
+
+    #[test]
+    fn test_moving_duration_depends_on_sport_threshold() {
+        let speed = vec![1.2, 1.2, 0., 1.2, 1.2, 4., 4.];
+        let walk = activity_with_speed(Sport::Walking, speed.clone());
+        let ride = activity_with_speed(Sport::Cycling, speed);
+        let thresholds = MovingSpeedThresholds::default();
+
+        assert_eq!(
+            walk.moving_duration(&thresholds),
+            Some(ActivityDuration::from(5.))
+        );
+        assert_eq!(
+            ride.moving_duration(&thresholds),
+            Some(ActivityDuration::from(2.))
+        );
+    }
+
+    #[test]
+    fn test_moving_duration_uses_overridden_threshold() {
+        let ride = activity_with_speed(Sport::Cycling, vec![1.2, 1.2, 1.2]);
+        let thresholds =
+            MovingSpeedThresholds::try_from(HashMap::from([(SportCategory::Cycling, 1.)])).unwrap();
+
+        assert_eq!(
+            ride.moving_duration(&thresholds),
+            Some(ActivityDuration::from(2.))
+        );
+        assert_eq!(thresholds.for_category(&SportCategory::Cycling), 1.);
+        assert_eq!(thresholds.for_category(&SportCategory::Running), 1.);
+    }
+
+    #[test]
+    fn test_moving_duration_skips_paused_points() {
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new(vec![0, 1, 2, 3]),
+            TimeseriesActiveTime::new(vec![
+                ActiveTime::Running(0),
+                ActiveTime::Paused,
+                ActiveTime::Running(1),
+                ActiveTime::Running(2),
+            ]),
+            vec![],
+            vec![Timeseries::new(
+                TimeseriesMetric::Speed,
+                vec![Some(TimeseriesValue::Float(3.)); 4],
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(timeseries.moving_duration(1.), Some(2));
+    }
+
+    #[test]
+    fn test_moving_duration_without_speed() {
+        let timeseries = running_timeseries(
+            vec![0, 1],
+            vec![Timeseries::new(TimeseriesMetric::Speed, vec![None, None])],
+        );
+
+        assert_eq!(timeseries.moving_duration(1.), None);
+        assert_eq!(
+            running_timeseries(vec![0, 1], vec![]).moving_duration(1.),
+            None
+        );
+    }
+
+    #[test]
+    fn test_power_zone_durations() {
+        // FTP of 200W: zones upper bounds are 110, 150, 180, 210, 240 and 300W
+        let power = [0, 100, 110, 140, 170, 200, 230, 290, 400, 400]
+            .into_iter()
+            .map(|power| Some(TimeseriesValue::Int(power)))
+            .chain([None, Some(TimeseriesValue::Int(100))])
+            .collect();
+        let timeseries = running_timeseries(
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 14],
+            vec![Timeseries::new(TimeseriesMetric::Power, power)],
+        );
+
+        let durations =
+            timeseries.power_zone_durations(&Ftp::try_from(200.).unwrap(), &PowerZones::default());
+
+        assert_eq!(durations, vec![5, 1, 1, 1, 1, 1, 3]);
+    }
+
+    #[test]
+    fn test_power_zone_durations_skips_paused_points() {
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new(vec![0, 1, 2, 3]),
+            TimeseriesActiveTime::new(vec![
+                ActiveTime::Running(0),
+                ActiveTime::Paused,
+                ActiveTime::Running(1),
+                ActiveTime::Running(2),
+            ]),
+            vec![],
+            vec![Timeseries::new(
+                TimeseriesMetric::Power,
+                vec![Some(TimeseriesValue::Int(250)); 4],
+            )],
+        )
+        .unwrap();
+
+        let durations =
+            timeseries.power_zone_durations(&Ftp::try_from(200.).unwrap(), &PowerZones::default());
+
+        assert_eq!(durations, vec![0, 0, 0, 0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn test_power_zone_durations_with_custom_zones() {
+        let timeseries = running_timeseries(
+            vec![0, 1, 2],
+            vec![Timeseries::new(
+                TimeseriesMetric::Power,
+                vec![Some(TimeseriesValue::Int(100)); 3],
+            )],
+        );
+        let zones = PowerZones::try_from(vec![0.4, 0.45, 0.5, 0.55, 0.6, 0.7]).unwrap();
+
+        let durations = timeseries.power_zone_durations(&Ftp::try_from(200.).unwrap(), &zones);
+
+        assert_eq!(durations, vec![0, 0, 2, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_power_zone_durations_without_power() {
+        let timeseries = running_timeseries(vec![0, 1], vec![]);
+
+        let durations =
+            timeseries.power_zone_durations(&Ftp::try_from(200.).unwrap(), &PowerZones::default());
+
+        assert_eq!(durations, vec![0; 7]);
+    }
+
+    #[test]
+    fn test_power_zones_bounds() {
+        let bounds = PowerZones::default().bounds(&Ftp::try_from(200.).unwrap());
+
+        assert_eq!(bounds.len(), 7);
+        assert_eq!(bounds[0], (0., Some(110.)));
+        assert_eq!(bounds[1], (110., Some(150.)));
+        assert_eq!(bounds[6], (300., None));
+    }
+
+    #[test]
+    fn test_power_zones_and_ftp_reject_invalid_values() {
+        assert!(PowerZones::try_from(vec![0.5, 0.6]).is_err());
+        assert!(PowerZones::try_from(vec![0.55, 0.75, 0.7, 1.05, 1.2, 1.5]).is_err());
+        assert!(PowerZones::try_from(vec![-0.1, 0.75, 0.9, 1.05, 1.2, 1.5]).is_err());
+        assert!(Ftp::try_from(0.).is_err());
+        assert!(Ftp::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_pace_zone_durations() {
+        // Threshold speed of 4 m/s: zones upper bounds are 3.12, 3.52, 3.76 and 4.04 m/s
+        let speed = [0., 3., 3.5, 3.7, 4., 4.5]
+            .into_iter()
+            .map(|speed| Some(TimeseriesValue::Float(speed)))
+            .collect();
+        let timeseries = running_timeseries(
+            vec![0, 1, 2, 3, 4, 6],
+            vec![Timeseries::new(TimeseriesMetric::Speed, speed)],
+        );
+
+        assert_eq!(timeseries.pace_zone_durations(4.), vec![1, 1, 1, 1, 2]);
+        assert_eq!(pace_zone_bounds(4.)[0], (0., Some(3.12)));
+        assert_eq!(pace_zone_bounds(4.)[4], (4.04, None));
+    }
+
+    #[test]
+    fn test_sport_thresholds() {
+        let thresholds = SportThresholds {
+            cycling_ftp: Some(Ftp::try_from(250.).unwrap()),
+            running_threshold_pace: Some(ThresholdPace::try_from(250.).unwrap()),
+            swimming_css: Some(ThresholdPace::try_from(100.).unwrap()),
+        };
+        let ftp = Some(Ftp::try_from(200.).unwrap());
+
+        assert_eq!(
+            thresholds.ftp(&Sport::Cycling, ftp),
+            Some(Ftp::try_from(250.).unwrap())
+        );
+        assert_eq!(thresholds.ftp(&Sport::Rowing, ftp), ftp);
+        assert_eq!(SportThresholds::default().ftp(&Sport::Cycling, ftp), ftp);
+        assert_eq!(thresholds.threshold_speed(&Sport::Running), Some(4.));
+        assert_eq!(thresholds.threshold_speed(&Sport::Swimming), Some(1.));
+        assert_eq!(thresholds.threshold_speed(&Sport::Cycling), None);
+        assert!(ThresholdPace::try_from(0.).is_err());
+        assert_eq!(
+            serde_json::from_str::<SportThresholds>(r#"{"running_threshold_pace": 250}"#).unwrap(),
+            SportThresholds {
+                running_threshold_pace: Some(ThresholdPace::try_from(250.).unwrap()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_default_streams_reject_invalid_values() {
+        assert!(DefaultStreams::try_from(vec![]).is_err());
+        assert!(
+            DefaultStreams::try_from(vec![TimeseriesMetric::Power, TimeseriesMetric::Power])
+                .is_err()
+        );
+        assert!(serde_json::from_str::<DefaultStreams>(r#"["Power", "Watts"]"#).is_err());
+        assert_eq!(
+            serde_json::from_str::<DefaultStreams>(r#"["HeartRate", "Power"]"#).unwrap(),
+            DefaultStreams::try_from(vec![TimeseriesMetric::HeartRate, TimeseriesMetric::Power])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_moving_speed_thresholds_reject_invalid_values() {
+        assert!(
+            MovingSpeedThresholds::try_from(HashMap::from([(SportCategory::Running, -1.)]))
+                .is_err()
+        );
+        assert!(
+            MovingSpeedThresholds::try_from(HashMap::from([(SportCategory::Running, f64::NAN)]))
+                .is_err()
+        );
+    }
+
+    fn activity_with_distance(distance: Vec<f64>) -> ActivityWithParsedData {
+        let points = distance.len();
+        ActivityWithParsedData::new(
+            Activity::new_empty(
+                ActivityId::default(),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(1000).unwrap(),
+                ActivityDuration::from((points - 1) as f64),
+                Sport::Running,
+            ),
+            ActivityTimeseries::new(
+                TimeseriesTime::new((0..points).collect()),
+                TimeseriesActiveTime::new((0..points).map(ActiveTime::Running).collect()),
+                vec![Lap::new(0, 5), Lap::new(5, points - 1)],
+                vec![
+                    Timeseries::new(
+                        TimeseriesMetric::Distance,
+                        distance
+                            .into_iter()
+                            .map(|distance| Some(TimeseriesValue::Float(distance)))
+                            .collect(),
+                    ),
+                    Timeseries::new(
+                        TimeseriesMetric::HeartRate,
+                        (0..points)
+                            .map(|idx| Some(TimeseriesValue::Int(100 + idx)))
+                            .collect(),
+                    ),
+                ],
+            )
+            .unwrap(),
+            ActivityStatistics::new(HashMap::from([
+                (ActivityStatistic::Distance, 1000.),
+                (ActivityStatistic::Calories, 80.),
+            ])),
+        )
+    }
+
+    #[test]
+    fn test_trim_by_distance() {
+        // 100m per second over 10 seconds
+        let activity = activity_with_distance((0..=10).map(|idx| idx as f64 * 100.).collect());
+
+        let trimmed = activity.trim_by_distance(200., 300.).unwrap();
+
+        assert_eq!(
+            trimmed.start_time(),
+            &ActivityStartTime::from_timestamp(1002).unwrap()
+        );
+        assert_eq!(trimmed.duration(), &ActivityDuration::from(5.));
+        assert_eq!(trimmed.timeseries().time().values(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(trimmed.active_duration(), ActivityDuration::from(5.));
+        assert_eq!(
+            trimmed
+                .timeseries()
+                .metric_values(&TimeseriesMetric::Distance),
+            Some(vec![0., 100., 200., 300., 400., 500.])
+        );
+        assert_eq!(
+            trimmed
+                .timeseries()
+                .metric_values(&TimeseriesMetric::HeartRate),
+            Some(vec![102., 103., 104., 105., 106., 107.])
+        );
+        assert_eq!(
+            trimmed.timeseries().laps(),
+            &[Lap::new(0, 3), Lap::new(3, 5)]
+        );
+        assert_eq!(
+            trimmed.statistics(),
+            &ActivityStatistics::new(HashMap::from([
+                (ActivityStatistic::Distance, 500.),
+                (ActivityStatistic::Duration, 5.),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_trim_by_distance_between_points() {
+        let activity = activity_with_distance((0..=10).map(|idx| idx as f64 * 100.).collect());
+
+        let trimmed = activity.trim_by_distance(150., 0.).unwrap();
+
+        assert_eq!(trimmed.timeseries().time().len(), 9);
+        assert_eq!(
+            trimmed.statistics().get(&ActivityStatistic::Distance),
+            Some(&800.)
+        );
+    }
+
+    #[test]
+    fn test_trim_by_distance_invalid_window() {
+        let activity = activity_with_distance((0..=10).map(|idx| idx as f64 * 100.).collect());
+
+        assert_eq!(
+            activity.clone().trim_by_distance(600., 400.).err(),
+            Some(TrimByDistanceError::InvalidWindow(600., 400., 1000.))
+        );
+        assert_eq!(
+            activity.clone().trim_by_distance(-1., 0.).err(),
+            Some(TrimByDistanceError::InvalidWindow(-1., 0., 1000.))
+        );
+        assert!(activity.trim_by_distance(f64::NAN, 0.).is_err());
+    }
+
+    #[test]
+    fn test_trim_by_distance_without_distance() {
+        let activity = activity_with_speed(Sport::Running, vec![3., 3., 3.]);
+
+        assert_eq!(
+            activity.trim_by_distance(0., 0.).err(),
+            Some(TrimByDistanceError::NoDistance)
+        );
+    }
+
+    /// Track of 5 points with a pause between the 3rd and 4th points, a lap and a distance not
+    /// starting at 0.
+    fn track() -> ActivityTimeseries {
+        ActivityTimeseries::new(
+            TimeseriesTime::new(vec![0, 2, 3, 10, 12]),
+            TimeseriesActiveTime::new(vec![
+                ActiveTime::Running(0),
+                ActiveTime::Running(2),
+                ActiveTime::Running(3),
+                ActiveTime::Paused,
+                ActiveTime::Running(5),
+            ]),
+            vec![Lap::new(0, 3), Lap::new(3, 12)],
+            vec![
+                Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    [10., 20., 50., 50., 70.]
+                        .into_iter()
+                        .map(|distance| Some(TimeseriesValue::Float(distance)))
+                        .collect(),
+                ),
+                Timeseries::new(
+                    TimeseriesMetric::Latitude,
+                    (0..5)
+                        .map(|idx| Some(TimeseriesValue::Float(45. + idx as f64 * 0.001)))
+                        .collect(),
+                ),
+                Timeseries::new(
+                    TimeseriesMetric::Longitude,
+                    vec![Some(TimeseriesValue::Float(5.)); 5],
+                ),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reverse_timeseries() {
+        let reversed = track().reverse().unwrap();
+
+        assert_eq!(reversed.time().values(), &[0, 2, 9, 10, 12]);
+        assert_eq!(
+            reversed.active_time().values(),
+            &[
+                ActiveTime::Running(0),
+                ActiveTime::Paused,
+                ActiveTime::Running(2),
+                ActiveTime::Running(3),
+                ActiveTime::Running(5),
+            ]
+        );
+        assert_eq!(
+            reversed.metric_values(&TimeseriesMetric::Distance),
+            Some(vec![10., 30., 30., 60., 70.])
+        );
+        assert_eq!(reversed.positions().first(), track().positions().last());
+        assert_eq!(reversed.laps(), &[Lap::new(0, 9), Lap::new(9, 12)]);
+    }
+
+    #[test]
+    fn test_reverse_timeseries_twice_yields_the_original() {
+        assert_eq!(track().reverse().unwrap().reverse().unwrap(), track());
+    }
+
+    #[test]
+    fn test_reverse_timeseries_without_positions() {
+        let timeseries = running_timeseries(
+            vec![0, 1],
+            vec![Timeseries::new(
+                TimeseriesMetric::Distance,
+                vec![
+                    Some(TimeseriesValue::Float(0.)),
+                    Some(TimeseriesValue::Float(5.)),
+                ],
+            )],
+        );
+
+        assert_eq!(
+            timeseries.reverse().err(),
+            Some(ReverseTimeseriesError::NoPosition)
+        );
+    }
+
+    /// Track heading north at ~11.1 m/s, one fix per second, with a ~1.1 km jump at the fifth
+    /// point. The distance timeseries follows the raw fixes, spike included.
+    fn timeseries_with_gps_spike() -> ActivityTimeseries {
+        let latitudes = (0..11)
+            .map(|idx| match idx {
+                5 => 45.01,
+                _ => 45. + idx as f64 * 0.0001,
+            })
+            .collect::<Vec<_>>();
+        let mut distance = 0.;
+        let distances = (0..11)
+            .map(|idx| {
+                if idx > 0 {
+                    distance += haversine_distance((latitudes[idx - 1], 5.), (latitudes[idx], 5.));
+                }
+                Some(TimeseriesValue::Float(distance))
+            })
+            .collect();
+
+        ActivityTimeseries::new(
+            TimeseriesTime::new((0..11).collect()),
+            TimeseriesActiveTime::new((0..11).map(ActiveTime::Running).collect()),
+            vec![],
+            vec![
+                Timeseries::new(
+                    TimeseriesMetric::Latitude,
+                    latitudes
+                        .iter()
+                        .map(|lat| Some(TimeseriesValue::Float(*lat)))
+                        .collect(),
+                ),
+                Timeseries::new(
+                    TimeseriesMetric::Longitude,
+                    vec![Some(TimeseriesValue::Float(5.)); 11],
+                ),
+                Timeseries::new(TimeseriesMetric::Distance, distances),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_remove_gps_spikes() {
+        let timeseries = timeseries_with_gps_spike();
+        let raw_distance = timeseries
+            .metric_values(&TimeseriesMetric::Distance)
+            .unwrap()[10];
+        assert!(raw_distance > 2000.);
+
+        let (cleaned, removed) = timeseries.remove_gps_spikes(12.);
+
+        assert_eq!(removed, 1);
+        let latitudes = &cleaned.metrics()[0].values();
+        assert_eq!(latitudes[5], None);
+        assert_eq!(latitudes.iter().flatten().count(), 10);
+
+        let distances = cleaned.metric_values(&TimeseriesMetric::Distance).unwrap();
+        assert_eq!(distances.len(), 11);
+        assert!((distances[10] - 111.19).abs() < 0.1);
+        // The removed fix keeps the distance of the previous one
+        assert_eq!(distances[5], distances[4]);
+    }
+
+    #[test]
+    fn test_remove_gps_spikes_keeps_device_distance_outside_spikes() {
+        // The device records 12 m every second, e.g. from a footpod, the spike adding ~2.2 km
+        let device_distances = [
+            0., 12., 24., 36., 48., 1200., 2350., 2362., 2374., 2386., 2398.,
+        ];
+        let timeseries = timeseries_with_gps_spike();
+        let timeseries = ActivityTimeseries::new(
+            timeseries.time().clone(),
+            timeseries.active_time().clone(),
+            vec![],
+            vec![
+                timeseries.metrics()[0].clone(),
+                timeseries.metrics()[1].clone(),
+                Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    device_distances
+                        .iter()
+                        .map(|distance| Some(TimeseriesValue::Float(*distance)))
+                        .collect(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let (cleaned, removed) = timeseries.remove_gps_spikes(12.);
+
+        assert_eq!(removed, 1);
+        let distances = cleaned.metric_values(&TimeseriesMetric::Distance).unwrap();
+        assert_eq!(distances[..5], device_distances[..5]);
+        assert_eq!(distances[5], 48.);
+        // Only the distance between the fixes around the spike is recomputed, ~22.2 m
+        assert!((distances[6] - 70.24).abs() < 0.1);
+        for idx in 7..11 {
+            assert!((distances[idx] - distances[idx - 1] - 12.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_remove_gps_spikes_keeps_plausible_track() {
+        let timeseries = timeseries_with_gps_spike();
+
+        let (cleaned, removed) = timeseries.remove_gps_spikes(5000.);
+
+        assert_eq!(removed, 0);
+        assert_eq!(cleaned, timeseries);
+    }
+
+    #[test]
+    fn test_remove_gps_spikes_without_position() {
+        let activity = activity_with_speed(Sport::Running, vec![3., 3., 3.]);
+
+        let (cleaned, removed) = activity.timeseries().remove_gps_spikes(12.);
+
+        assert_eq!(removed, 0);
+        assert_eq!(&cleaned, activity.timeseries());
+    }
+
+    #[test]
+    fn test_remove_inaccurate_positions() {
+        let timeseries = timeseries_with_gps_spike();
+        let mut accuracies = vec![Some(3.); 11];
+        accuracies[2] = Some(40.);
+        accuracies[7] = None;
+
+        let (cleaned, removed) = timeseries
+            .remove_inaccurate_positions(&accuracies, &MaxGpsAccuracy::try_from(20.).unwrap());
+
+        assert_eq!(removed, 1);
+        assert_eq!(cleaned.metrics()[0].values()[2], None);
+        assert_eq!(cleaned.metrics()[1].values()[2], None);
+        assert_eq!(cleaned.positions().len(), 10);
+        assert_eq!(
+            cleaned.metric_values(&TimeseriesMetric::Distance),
+            timeseries.metric_values(&TimeseriesMetric::Distance)
+        );
+    }
+
+    #[test]
+    fn test_remove_inaccurate_positions_all_accurate() {
+        let timeseries = timeseries_with_gps_spike();
+
+        let (cleaned, removed) = timeseries
+            .remove_inaccurate_positions(&[Some(3.); 11], &MaxGpsAccuracy::try_from(20.).unwrap());
+
+        assert_eq!(removed, 0);
+        assert_eq!(cleaned, timeseries);
+    }
+
+    fn constant_timeseries(metric: TimeseriesMetric, value: usize) -> ActivityTimeseries {
+        ActivityTimeseries::new(
+            TimeseriesTime::new((0..=3600).collect()),
+            TimeseriesActiveTime::new((0..=3600).map(ActiveTime::Running).collect()),
+            vec![],
+            vec![Timeseries::new(
+                metric,
+                vec![Some(TimeseriesValue::Int(value)); 3601],
+            )],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_estimate_calories_from_power() {
+        // 200 W for an hour is 720 kJ of mechanical work
+        let timeseries = constant_timeseries(TimeseriesMetric::Power, 200);
+
+        let calories = estimate_calories_from_power(&timeseries).unwrap();
+
+        assert!((calories - 720. / GROSS_EFFICIENCY / 4.184).abs() < 1e-6);
+        assert!((calories - 717.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_estimate_calories_from_heart_rate() {
+        // (-55.0969 + 0.6309 * 150 + 0.1988 * 70 + 0.2017 * 30) kJ/min for 60 min
+        let timeseries = constant_timeseries(TimeseriesMetric::HeartRate, 150);
+        let profile = AthleteProfile::new(70., 30).unwrap();
+
+        let calories = estimate_calories_from_heart_rate(&timeseries, &profile).unwrap();
+
+        assert!((calories - 853.3).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_estimate_calories_without_metric() {
+        let timeseries = constant_timeseries(TimeseriesMetric::Cadence, 90);
+        let profile = AthleteProfile::new(70., 30).unwrap();
+
+        assert_eq!(estimate_calories_from_power(&timeseries), None);
+        assert_eq!(
+            estimate_calories_from_heart_rate(&timeseries, &profile),
+            None
+        );
+    }
+
+    #[test]
+    fn test_with_estimated_calories_marks_estimate() {
+        let timeseries = constant_timeseries(TimeseriesMetric::Power, 200);
+
+        let statistics = ActivityStatistics::default().with_estimated_calories(&timeseries, None);
+
+        let calories = statistics.get(&ActivityStatistic::Calories).unwrap();
+        assert!((*calories - 717.0).abs() < 0.1);
+        assert_eq!(
+            statistics.get(&ActivityStatistic::EstimatedCalories),
+            Some(calories)
+        );
+    }
+
+    #[test]
+    fn test_with_estimated_calories_heart_rate_requires_profile() {
+        let timeseries = constant_timeseries(TimeseriesMetric::HeartRate, 150);
+
+        let statistics = ActivityStatistics::default().with_estimated_calories(&timeseries, None);
+
+        assert_eq!(statistics.get(&ActivityStatistic::Calories), None);
+        assert_eq!(statistics.get(&ActivityStatistic::EstimatedCalories), None);
+    }
+
+    #[test]
+    fn test_with_estimated_calories_keeps_recorded_calories() {
+        let timeseries = constant_timeseries(TimeseriesMetric::Power, 200);
+        let statistics =
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Calories, 500.)]));
+
+        let statistics = statistics.with_estimated_calories(&timeseries, None);
+
+        assert_eq!(statistics.get(&ActivityStatistic::Calories), Some(&500.));
+        assert_eq!(statistics.get(&ActivityStatistic::EstimatedCalories), None);
+    }
+
+    #[test]
+    fn test_athlete_profile_weight_must_be_positive() {
+        assert!(AthleteProfile::new(70., 30).is_ok());
+        assert!(AthleteProfile::new(0., 30).is_err());
+        assert!(AthleteProfile::new(f64::NAN, 30).is_err());
+        assert!(serde_json::from_str::<AthleteProfile>(r#"{"weight":-1,"age":30}"#).is_err());
+    }
+
+    #[test]
+    fn test_max_gps_accuracy_must_be_positive() {
+        assert!(MaxGpsAccuracy::try_from(10.).is_ok());
+        assert!(MaxGpsAccuracy::try_from(0.).is_err());
+        assert!(MaxGpsAccuracy::try_from(-5.).is_err());
+        assert!(MaxGpsAccuracy::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_positions_skip_points_without_coordinates() {
+        let (cleaned, _) = timeseries_with_gps_spike().remove_gps_spikes(12.);
+
+        let positions = cleaned.positions();
+
+        assert_eq!(positions.len(), 10);
+        assert_eq!(positions[0], (45., 5.));
+        assert!(!positions.contains(&(45.01, 5.)));
+    }
+
+    #[test]
+    fn test_positions_without_coordinates() {
+        let activity = activity_with_speed(Sport::Running, vec![3., 3., 3.]);
+
+        assert!(activity.timeseries().positions().is_empty());
+    }
+
+    #[test]
+    fn test_max_gps_speeds_for_sport() {
+        let speeds = MaxGpsSpeeds::new(HashMap::from([(SportCategory::Cycling, 20.)]));
+
+        assert_eq!(speeds.for_sport(&Sport::Running), 12.);
+        assert_eq!(speeds.for_sport(&Sport::Cycling), 20.);
+        assert_eq!(speeds.for_sport(&Sport::Other), DEFAULT_MAX_GPS_SPEED);
+    }
+
+    fn activity_starting_at(timestamp: usize, duration: f64) -> Activity {
+        Activity::new_empty(
+            ActivityId::new(),
+            UserId::test_default(),
+            ActivityStartTime::from_timestamp(timestamp).unwrap(),
+            ActivityDuration::from(duration),
+            Sport::Cycling,
+        )
+    }
+
+    #[test]
+    fn test_near_duplicate_window_matches() {
+        let window = NearDuplicateWindow::new(300);
+        let activity = activity_starting_at(1000, 3600.);
+
+        let watch = activity_starting_at(1120, 3500.);
+        assert!(window.matches((&activity, Some(30000.)), (&watch, Some(29000.))));
+        assert!(window.matches((&activity, None), (&watch, Some(29000.))));
+
+        let later = activity_starting_at(1400, 3600.);
+        assert!(!window.matches((&activity, None), (&later, None)));
+
+        let shorter = activity_starting_at(1000, 1800.);
+        assert!(!window.matches((&activity, None), (&shorter, None)));
+
+        assert!(!window.matches((&activity, Some(30000.)), (&watch, Some(20000.))));
+    }
+
+    #[test]
+    fn test_bounding_box_validation() {
+        assert!(BoundingBox::new(45., 5., 46., 6.).is_ok());
+        assert!(BoundingBox::new(45., 5., 45., 5.).is_ok());
+        assert!(BoundingBox::new(46., 5., 45., 6.).is_err());
+        assert!(BoundingBox::new(45., 6., 46., 5.).is_err());
+        assert!(BoundingBox::new(-91., 5., 46., 6.).is_err());
+        assert!(BoundingBox::new(45., 5., 46., 181.).is_err());
+        assert!(BoundingBox::new(f64::NAN, 5., 46., 6.).is_err());
+    }
+
+    #[test]
+    fn test_bounding_box_from_positions() {
+        let positions = [(45.2, 5.8), (45.1, 5.9), (45.3, 5.7)];
+
+        assert_eq!(
+            BoundingBox::from_positions(&positions),
+            Some(BoundingBox::new(45.1, 5.7, 45.3, 5.9).unwrap())
+        );
+        assert_eq!(BoundingBox::from_positions(&[]), None);
+    }
+
+    fn commute_locations() -> CommuteLocations {
+        CommuteLocations::new(
+            GeoPoint::new(45.19, 5.72).unwrap(),
+            GeoPoint::new(45.21, 5.80).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_commute_locations_match_round_trip_to_work() {
+        let positions = [
+            (45.1902, 5.7201),
+            (45.20, 5.76),
+            (45.2101, 5.7998),
+            (45.20, 5.76),
+            (45.1899, 5.7202),
+        ];
+
+        assert!(commute_locations().matches(&positions));
+    }
+
+    #[test]
+    fn test_commute_locations_match_one_way_trip() {
+        let positions = [(45.2101, 5.7998), (45.20, 5.76), (45.1902, 5.7201)];
+
+        assert!(commute_locations().matches(&positions));
+    }
+
+    #[test]
+    fn test_commute_locations_do_not_match_point_to_point_route() {
+        let positions = [(45.1902, 5.7201), (45.30, 5.90), (45.45, 6.05)];
+
+        assert!(!commute_locations().matches(&positions));
+    }
+
+    #[test]
+    fn test_commute_locations_do_not_match_loop_from_home() {
+        let positions = [(45.1902, 5.7201), (45.15, 5.65), (45.1899, 5.7202)];
+
+        assert!(!commute_locations().matches(&positions));
+        assert!(!commute_locations().matches(&[]));
+    }
+
+    #[test]
+    fn test_commute_locations_serde() {
+        assert_eq!(
+            serde_json::from_str::<CommuteLocations>(
+                r#"{"home": [45.19, 5.72], "work": [45.21, 5.80]}"#
+            )
+            .unwrap(),
+            commute_locations()
+        );
+        assert!(
+            serde_json::from_str::<CommuteLocations>(
+                r#"{"home": [95.0, 5.72], "work": [45.21, 5.80]}"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bounding_box_intersects() {
+        let area = BoundingBox::new(45., 5., 46., 6.).unwrap();
+
+        let inside = BoundingBox::new(45.2, 5.2, 45.4, 5.4).unwrap();
+        let overlapping = BoundingBox::new(45.5, 5.5, 47., 7.).unwrap();
+        let touching = BoundingBox::new(46., 6., 47., 7.).unwrap();
+        let north = BoundingBox::new(46.1, 5., 47., 6.).unwrap();
+        let east = BoundingBox::new(45., 6.1, 46., 7.).unwrap();
+
+        assert!(area.intersects(&inside));
+        assert!(inside.intersects(&area));
+        assert!(area.intersects(&overlapping));
+        assert!(area.intersects(&touching));
+        assert!(!area.intersects(&north));
+        assert!(!area.intersects(&east));
+    }
 }
@@ -0,0 +1,207 @@
+//! Hashes of the raw content of uploaded files, to detect identical uploads. The algorithm is
+//! recorded with each hash, so that hashes stay comparable when the default algorithm changes.
+
+use std::{fmt, str::FromStr};
+
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    /// Faster than SHA-256 on large files, only available with the `blake3` feature. Hashes
+    /// using it can still be parsed without the feature, but not recomputed.
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Hasher of the algorithm, `None` if the algorithm is not built in.
+    pub fn hasher(&self) -> Option<&'static dyn ContentHasher> {
+        match self {
+            Self::Sha256 => Some(&Sha256Hasher),
+            #[cfg(feature = "blake3")]
+            Self::Blake3 => Some(&Blake3Hasher),
+            #[cfg(not(feature = "blake3"))]
+            Self::Blake3 => None,
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        };
+        write!(f, "{algorithm}")
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(Self::Sha256),
+            "blake3" => Ok(Self::Blake3),
+            _ => Err(format!("Unknown hash algorithm: '{s}'")),
+        }
+    }
+}
+
+/// Hash of a file content, stored as `<algorithm>:<hex digest>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContentHash {
+    algorithm: HashAlgorithm,
+    digest: Vec<u8>,
+}
+
+impl ContentHash {
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Whether `content` has this hash, computed with the same algorithm. Always `false` if the
+    /// algorithm is not built in.
+    pub fn matches(&self, content: &[u8]) -> bool {
+        self.algorithm
+            .hasher()
+            .is_some_and(|hasher| hasher.hash(content) == *self)
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, const_hex::encode(&self.digest))
+    }
+}
+
+impl FromStr for ContentHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (algorithm, digest) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid content hash: '{s}'"))?;
+        let digest =
+            const_hex::decode(digest).map_err(|_| format!("Invalid content hash: '{s}'"))?;
+
+        Ok(Self {
+            algorithm: algorithm.parse()?,
+            digest,
+        })
+    }
+}
+
+pub trait ContentHasher: Send + Sync {
+    fn algorithm(&self) -> HashAlgorithm;
+
+    fn digest(&self, content: &[u8]) -> Vec<u8>;
+
+    fn hash(&self, content: &[u8]) -> ContentHash {
+        ContentHash {
+            algorithm: self.algorithm(),
+            digest: self.digest(content),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256Hasher;
+
+impl ContentHasher for Sha256Hasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Sha256
+    }
+
+    fn digest(&self, content: &[u8]) -> Vec<u8> {
+        Sha256::digest(content).to_vec()
+    }
+}
+
+#[cfg(feature = "blake3")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Blake3Hasher;
+
+#[cfg(feature = "blake3")]
+impl ContentHasher for Blake3Hasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Blake3
+    }
+
+    fn digest(&self, content: &[u8]) -> Vec<u8> {
+        blake3::hash(content).as_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hashers of all the algorithms built in.
+    fn hashers() -> Vec<&'static dyn ContentHasher> {
+        [HashAlgorithm::Sha256, HashAlgorithm::Blake3]
+            .iter()
+            .filter_map(HashAlgorithm::hasher)
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_contents_hash_equal() {
+        for hasher in hashers() {
+            let hash = hasher.hash(b"some fit file");
+
+            assert_eq!(hash, hasher.hash(b"some fit file"));
+            assert_ne!(hash, hasher.hash(b"another fit file"));
+            assert!(hash.matches(b"some fit file"));
+            assert!(!hash.matches(b"another fit file"));
+        }
+    }
+
+    #[test]
+    fn test_hash_records_its_algorithm() {
+        for hasher in hashers() {
+            let hash = hasher.hash(b"some fit file");
+
+            assert_eq!(hash.algorithm(), hasher.algorithm());
+            assert!(
+                hash.to_string()
+                    .starts_with(&format!("{}:", hasher.algorithm()))
+            );
+            assert_eq!(hash.to_string().parse::<ContentHash>(), Ok(hash));
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash() {
+        let hash = Sha256Hasher.hash(b"abc");
+
+        assert_eq!(
+            hash.to_string(),
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_hashes_of_different_algorithms_differ() {
+        let hash: ContentHash =
+            "blake3:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                .parse()
+                .unwrap();
+
+        assert_eq!(hash.algorithm(), HashAlgorithm::Blake3);
+        assert_ne!(hash, Sha256Hasher.hash(b"abc"));
+        assert!(!hash.matches(b"abc"));
+    }
+
+    #[test]
+    fn test_parse_invalid_content_hash() {
+        assert!("ba7816bf".parse::<ContentHash>().is_err());
+        assert!("md5:ba7816bf".parse::<ContentHash>().is_err());
+        assert!("sha256:not-hex".parse::<ContentHash>().is_err());
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt;
 use derive_more::Constructor;
 
 pub mod activity;
+pub mod hashing;
 pub mod preferences;
 pub mod training;
 
@@ -1,4 +1,10 @@
-use crate::domain::models::training::TrainingMetricId;
+use crate::domain::models::{
+    activity::{
+        ActivityVisibility, AthleteProfile, CommuteLocations, DefaultStreams, Ftp, MaxGpsAccuracy,
+        MovingSpeedThresholds, PowerZones, SportThresholds,
+    },
+    training::TrainingMetricId,
+};
 
 ///////////////////////////////////////////////////////////////////
 /// PREFERENCE ENUM AND KEY
@@ -7,12 +13,30 @@ use crate::domain::models::training::TrainingMetricId;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PreferenceKey {
     FavoriteMetric,
+    DefaultActivityVisibility,
+    MovingSpeedThresholds,
+    Ftp,
+    SportThresholds,
+    PowerZones,
+    DefaultStreams,
+    CommuteLocations,
+    MaxGpsAccuracy,
+    AthleteProfile,
 }
 
 impl std::fmt::Display for PreferenceKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PreferenceKey::FavoriteMetric => write!(f, "favorite_metric"),
+            PreferenceKey::DefaultActivityVisibility => write!(f, "default_activity_visibility"),
+            PreferenceKey::MovingSpeedThresholds => write!(f, "moving_speed_thresholds"),
+            PreferenceKey::Ftp => write!(f, "ftp"),
+            PreferenceKey::SportThresholds => write!(f, "sport_thresholds"),
+            PreferenceKey::PowerZones => write!(f, "power_zones"),
+            PreferenceKey::DefaultStreams => write!(f, "default_streams"),
+            PreferenceKey::CommuteLocations => write!(f, "commute_locations"),
+            PreferenceKey::MaxGpsAccuracy => write!(f, "max_gps_accuracy"),
+            PreferenceKey::AthleteProfile => write!(f, "athlete_profile"),
         }
     }
 }
@@ -23,6 +47,15 @@ impl std::str::FromStr for PreferenceKey {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "favorite_metric" => Ok(PreferenceKey::FavoriteMetric),
+            "default_activity_visibility" => Ok(PreferenceKey::DefaultActivityVisibility),
+            "moving_speed_thresholds" => Ok(PreferenceKey::MovingSpeedThresholds),
+            "ftp" => Ok(PreferenceKey::Ftp),
+            "sport_thresholds" => Ok(PreferenceKey::SportThresholds),
+            "power_zones" => Ok(PreferenceKey::PowerZones),
+            "default_streams" => Ok(PreferenceKey::DefaultStreams),
+            "commute_locations" => Ok(PreferenceKey::CommuteLocations),
+            "max_gps_accuracy" => Ok(PreferenceKey::MaxGpsAccuracy),
+            "athlete_profile" => Ok(PreferenceKey::AthleteProfile),
             _ => Err(format!("Unknown preference key: {}", s)),
         }
     }
@@ -32,6 +65,24 @@ impl std::str::FromStr for PreferenceKey {
 #[derive(Clone, Debug, PartialEq)]
 pub enum Preference {
     FavoriteMetric(TrainingMetricId),
+    /// Visibility given to newly created activities
+    DefaultActivityVisibility(ActivityVisibility),
+    /// Per sport category overrides of the speed under which the athlete is considered stopped
+    MovingSpeedThresholds(MovingSpeedThresholds),
+    /// Functional Threshold Power used to compute power zones
+    Ftp(Ftp),
+    /// Per sport thresholds (cycling FTP, running threshold pace and swimming CSS)
+    SportThresholds(SportThresholds),
+    /// Override of the default power zones boundaries
+    PowerZones(PowerZones),
+    /// Channels returned by the streams endpoint when none are requested
+    DefaultStreams(DefaultStreams),
+    /// Home and work places used to tag uploaded commutes
+    CommuteLocations(CommuteLocations),
+    /// Accuracy above which GPS fixes of uploaded activities are dropped from their track
+    MaxGpsAccuracy(MaxGpsAccuracy),
+    /// Weight and age used to estimate the calories of activities recorded without them
+    AthleteProfile(AthleteProfile),
 }
 
 impl Preference {
@@ -39,6 +90,15 @@ impl Preference {
     pub fn key(&self) -> PreferenceKey {
         match self {
             Preference::FavoriteMetric(_) => PreferenceKey::FavoriteMetric,
+            Preference::DefaultActivityVisibility(_) => PreferenceKey::DefaultActivityVisibility,
+            Preference::MovingSpeedThresholds(_) => PreferenceKey::MovingSpeedThresholds,
+            Preference::Ftp(_) => PreferenceKey::Ftp,
+            Preference::SportThresholds(_) => PreferenceKey::SportThresholds,
+            Preference::PowerZones(_) => PreferenceKey::PowerZones,
+            Preference::DefaultStreams(_) => PreferenceKey::DefaultStreams,
+            Preference::CommuteLocations(_) => PreferenceKey::CommuteLocations,
+            Preference::MaxGpsAccuracy(_) => PreferenceKey::MaxGpsAccuracy,
+            Preference::AthleteProfile(_) => PreferenceKey::AthleteProfile,
         }
     }
 }
@@ -55,6 +115,46 @@ mod tests {
             "favorite_metric".parse::<PreferenceKey>().unwrap(),
             PreferenceKey::FavoriteMetric
         );
+        assert_eq!(
+            PreferenceKey::DefaultActivityVisibility.to_string(),
+            "default_activity_visibility"
+        );
+        assert_eq!(
+            "default_activity_visibility"
+                .parse::<PreferenceKey>()
+                .unwrap(),
+            PreferenceKey::DefaultActivityVisibility
+        );
+        assert_eq!(
+            "moving_speed_thresholds".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::MovingSpeedThresholds
+        );
+        assert_eq!(PreferenceKey::Ftp.to_string(), "ftp");
+        assert_eq!("ftp".parse::<PreferenceKey>().unwrap(), PreferenceKey::Ftp);
+        assert_eq!(
+            "sport_thresholds".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::SportThresholds
+        );
+        assert_eq!(
+            "power_zones".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::PowerZones
+        );
+        assert_eq!(
+            "default_streams".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::DefaultStreams
+        );
+        assert_eq!(
+            "commute_locations".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::CommuteLocations
+        );
+        assert_eq!(
+            "max_gps_accuracy".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::MaxGpsAccuracy
+        );
+        assert_eq!(
+            "athlete_profile".parse::<PreferenceKey>().unwrap(),
+            PreferenceKey::AthleteProfile
+        );
         assert!("unknown".parse::<PreferenceKey>().is_err());
     }
 }
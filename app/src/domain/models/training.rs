@@ -2272,7 +2272,7 @@ mod test_granularity_bins {
 mod test_training_metric_filters {
     use crate::domain::models::activity::{
         ActivityDuration, ActivityFeedback, ActivityId, ActivityName, ActivityNutrition,
-        ActivityStartTime,
+        ActivityStartTime, ActivityVisibility,
     };
 
     use super::*;
@@ -2308,6 +2308,7 @@ mod test_training_metric_filters {
             Some(workout_type),
             ActivityNutrition::empty(),
             ActivityFeedback::empty(),
+            ActivityVisibility::default(),
         )
     }
 
@@ -2323,6 +2324,7 @@ mod test_training_metric_filters {
             WorkoutType::empty(),
             Some(ActivityNutrition::new(bonk_status, None)),
             ActivityFeedback::empty(),
+            ActivityVisibility::default(),
         )
     }
 
@@ -2338,6 +2340,7 @@ mod test_training_metric_filters {
             WorkoutType::empty(),
             ActivityNutrition::empty(),
             ActivityFeedback::empty(),
+            ActivityVisibility::default(),
         )
     }
 
@@ -2357,6 +2360,7 @@ mod test_training_metric_filters {
             Some(workout_type),
             Some(ActivityNutrition::new(bonk_status, None)),
             ActivityFeedback::empty(),
+            ActivityVisibility::default(),
         )
     }
 
@@ -3073,7 +3077,7 @@ mod test_training_period {
 mod test_training_metric_group_by {
     use crate::domain::models::activity::{
         ActivityDuration, ActivityFeedback, ActivityId, ActivityName, ActivityNutrition,
-        ActivityRpe, ActivityStartTime, BonkStatus, WorkoutType,
+        ActivityRpe, ActivityStartTime, ActivityVisibility, BonkStatus, WorkoutType,
     };
 
     use super::*;
@@ -3095,6 +3099,7 @@ mod test_training_metric_group_by {
             Some(WorkoutType::Intervals),
             Some(ActivityNutrition::new(BonkStatus::Bonked, None)),
             ActivityFeedback::empty(),
+            ActivityVisibility::default(),
         );
 
         assert_eq!(
@@ -10,8 +10,10 @@ use crate::domain::{
         activity::{
             Activity, ActivityDuration, ActivityFeedback, ActivityId, ActivityMetricV2,
             ActivityMetricsV2, ActivityName, ActivityNaturalKey, ActivityNutrition, ActivityRpe,
-            ActivityStartTime, ActivityStatistics, ActivityTimeseries, ActivityWithParsedData,
-            Sport, WorkoutType,
+            ActivityStartTime, ActivityStatistics, ActivityTimeseries, ActivityVisibility,
+            ActivityWithParsedData, ActivityWithSummary, AthleteProfile, BoundingBox,
+            CommuteLocations, ReverseTimeseriesError, Sport, TimeseriesMetric, TrimByDistanceError,
+            WorkoutType,
         },
     },
     ports::{DateRange, DateTimeRange},
@@ -42,6 +44,14 @@ pub trait IActivityService: Clone + Send + Sync + 'static {
         metrics: &[ActivityMetricV2],
     ) -> impl Future<Output = Result<Vec<(Activity, ActivityMetricsV2)>, ListActivitiesError>> + Send;
 
+    /// Whether at least one activity of `user` has a value for `metric`, e.g. a power metric for
+    /// a user who never recorded power. Relies on the cached metric values of the activities.
+    fn has_metric_data(
+        &self,
+        user: &UserId,
+        metric: &ActivityMetricV2,
+    ) -> impl Future<Output = Result<bool, ListActivitiesError>> + Send;
+
     fn list_activities_with_metrics_and_parsed_data(
         &self,
         user: &UserId,
@@ -51,6 +61,13 @@ pub trait IActivityService: Clone + Send + Sync + 'static {
         Output = Result<Vec<(ActivityWithParsedData, ActivityMetricsV2)>, ListActivitiesError>,
     > + Send;
 
+    /// Most recent activities of `user`, newest first, each with a summary of its statistics.
+    fn list_activities_feed(
+        &self,
+        user: &UserId,
+        filters: &ActivitiesFeedFilters,
+    ) -> impl Future<Output = Result<Vec<ActivityWithSummary>, ListActivitiesError>> + Send;
+
     fn get_activity_with_parsed_data(
         &self,
         activity_id: &ActivityId,
@@ -87,16 +104,56 @@ pub trait IActivityService: Clone + Send + Sync + 'static {
         req: UpdateActivityFeedbackRequest,
     ) -> impl Future<Output = Result<(), UpdateActivityFeedbackError>> + Send;
 
+    fn update_activity_visibility(
+        &self,
+        req: UpdateActivityVisibilityRequest,
+    ) -> impl Future<Output = Result<(), UpdateActivityVisibilityError>> + Send;
+
     fn delete_activity(
         &self,
         req: DeleteActivityRequest,
     ) -> impl Future<Output = Result<(), DeleteActivityError>> + Send;
 
+    /// Fill the missing values of a timeseries metric of an activity with the values of another
+    /// activity of the same user (e.g. a separate heart rate recording). The merged activity is
+    /// returned, along with its metrics, but is not persisted.
+    fn merge_activity_stream(
+        &self,
+        req: MergeActivityStreamRequest,
+    ) -> impl Future<
+        Output = Result<(ActivityWithParsedData, ActivityMetricsV2), MergeActivityStreamError>,
+    > + Send;
+
+    /// Remove the first and last meters of an activity, see
+    /// [ActivityWithParsedData::trim_by_distance]. The trimmed activity is returned, along with its
+    /// metrics, but is not persisted.
+    fn trim_activity(
+        &self,
+        req: TrimActivityRequest,
+    ) -> impl Future<Output = Result<(ActivityWithParsedData, ActivityMetricsV2), TrimActivityError>>
+    + Send;
+
+    /// Reverse the order of the points of an activity, see [ActivityWithParsedData::reverse]. The
+    /// reversed activity is returned, along with its metrics, but is not persisted.
+    fn reverse_activity(
+        &self,
+        req: ReverseActivityRequest,
+    ) -> impl Future<
+        Output = Result<(ActivityWithParsedData, ActivityMetricsV2), ReverseActivityError>,
+    > + Send;
+
     fn get_raw_activity(
         &self,
         req: GetRawActivityRequest,
     ) -> impl Future<Output = Result<RawActivity, GetRawActivityError>> + Send;
 
+    /// Replace the raw file of an activity, and refresh everything derived from it (start time,
+    /// duration, sport and cached metrics). User-provided fields (name, RPE, etc.) are kept.
+    fn replace_raw_activity(
+        &self,
+        req: ReplaceRawActivityRequest,
+    ) -> impl Future<Output = Result<Activity, ReplaceRawActivityError>> + Send;
+
     fn get_all_raw_activities(
         &self,
         req: GetAllActivitiesRequest,
@@ -112,6 +169,11 @@ pub struct CreateActivityRequest {
     statistics: ActivityStatistics,
     timeseries: ActivityTimeseries,
     raw_content: RawContent,
+    visibility: ActivityVisibility,
+    name: Option<ActivityName>,
+    check_near_duplicates: bool,
+    commute_locations: Option<CommuteLocations>,
+    athlete_profile: Option<AthleteProfile>,
 }
 
 impl CreateActivityRequest {
@@ -132,6 +194,48 @@ impl CreateActivityRequest {
             statistics,
             timeseries,
             raw_content,
+            visibility: ActivityVisibility::default(),
+            name: None,
+            check_near_duplicates: false,
+            commute_locations: None,
+            athlete_profile: None,
+        }
+    }
+
+    pub fn with_visibility(self, visibility: ActivityVisibility) -> Self {
+        Self { visibility, ..self }
+    }
+
+    /// Name of the activity, a default title based on its sport and start time is used if
+    /// `None` (see [crate::domain::models::activity::default_title]).
+    pub fn with_name(self, name: Option<ActivityName>) -> Self {
+        Self { name, ..self }
+    }
+
+    /// Reject the activity if the user already has a near duplicate of it, when the service is
+    /// configured with a near duplicate window.
+    pub fn with_near_duplicate_check(self, check_near_duplicates: bool) -> Self {
+        Self {
+            check_near_duplicates,
+            ..self
+        }
+    }
+
+    /// Tag the activity as a [WorkoutType::Commute] if its track goes between the athlete's places,
+    /// see [CommuteLocations::matches].
+    pub fn with_commute_locations(self, commute_locations: Option<CommuteLocations>) -> Self {
+        Self {
+            commute_locations,
+            ..self
+        }
+    }
+
+    /// Profile used to estimate the calories of the activity when it has neither calories nor
+    /// power, see [ActivityStatistics::with_estimated_calories].
+    pub fn with_athlete_profile(self, athlete_profile: Option<AthleteProfile>) -> Self {
+        Self {
+            athlete_profile,
+            ..self
         }
     }
 
@@ -162,6 +266,26 @@ impl CreateActivityRequest {
     pub fn timeseries(&self) -> &ActivityTimeseries {
         &self.timeseries
     }
+
+    pub fn visibility(&self) -> &ActivityVisibility {
+        &self.visibility
+    }
+
+    pub fn name(&self) -> Option<&ActivityName> {
+        self.name.as_ref()
+    }
+
+    pub fn check_near_duplicates(&self) -> bool {
+        self.check_near_duplicates
+    }
+
+    pub fn commute_locations(&self) -> Option<&CommuteLocations> {
+        self.commute_locations.as_ref()
+    }
+
+    pub fn athlete_profile(&self) -> Option<&AthleteProfile> {
+        self.athlete_profile.as_ref()
+    }
 }
 
 #[derive(Debug, Error)]
@@ -170,6 +294,8 @@ pub enum CreateActivityError {
     Unknown(#[from] anyhow::Error),
     #[error("An activity with similar data already exists")]
     SimilarActivityExistsError,
+    #[error("Activity {0} is a possible duplicate")]
+    PossibleDuplicate(ActivityId),
     #[error("User {0} does not exists")]
     UserDoesNotExist(UserId),
 }
@@ -347,6 +473,37 @@ pub enum UpdateActivityFeedbackError {
     Unknown(#[from] anyhow::Error),
 }
 
+#[derive(Debug, Clone, Constructor)]
+pub struct UpdateActivityVisibilityRequest {
+    user: UserId,
+    activity: ActivityId,
+    visibility: ActivityVisibility,
+}
+
+impl UpdateActivityVisibilityRequest {
+    pub fn user(&self) -> &UserId {
+        &self.user
+    }
+
+    pub fn activity(&self) -> &ActivityId {
+        &self.activity
+    }
+
+    pub fn visibility(&self) -> &ActivityVisibility {
+        &self.visibility
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateActivityVisibilityError {
+    #[error("Activity {0} does not exists")]
+    ActivityDoesNotExist(ActivityId),
+    #[error("User {0} does not own activity {1}")]
+    UserDoesNotOwnActivity(UserId, ActivityId),
+    #[error(transparent)]
+    Unknown(#[from] anyhow::Error),
+}
+
 #[derive(Debug, Clone, Constructor)]
 pub struct DeleteActivityRequest {
     user: UserId,
@@ -373,10 +530,151 @@ pub enum DeleteActivityError {
     UserDoesNotOwnActivity(UserId, ActivityId),
 }
 
+#[derive(Debug, Clone, Constructor)]
+pub struct MergeActivityStreamRequest {
+    user: UserId,
+    activity: ActivityId,
+    source: ActivityId,
+    metric: TimeseriesMetric,
+}
+
+impl MergeActivityStreamRequest {
+    pub fn user(&self) -> &UserId {
+        &self.user
+    }
+
+    pub fn activity(&self) -> &ActivityId {
+        &self.activity
+    }
+
+    pub fn source(&self) -> &ActivityId {
+        &self.source
+    }
+
+    pub fn metric(&self) -> &TimeseriesMetric {
+        &self.metric
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum MergeActivityStreamError {
+    #[error("Activity {0} does not exists")]
+    ActivityDoesNotExist(ActivityId),
+    #[error("User {0} does not own activity {1}")]
+    UserDoesNotOwnActivity(UserId, ActivityId),
+    #[error(transparent)]
+    Unknown(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, Constructor)]
+pub struct TrimActivityRequest {
+    user: UserId,
+    activity: ActivityId,
+    /// Meters to remove at the start of the activity
+    start: f64,
+    /// Meters to remove at the end of the activity
+    end: f64,
+}
+
+impl TrimActivityRequest {
+    pub fn user(&self) -> &UserId {
+        &self.user
+    }
+
+    pub fn activity(&self) -> &ActivityId {
+        &self.activity
+    }
+
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TrimActivityError {
+    #[error("Activity {0} does not exists")]
+    ActivityDoesNotExist(ActivityId),
+    #[error("User {0} does not own activity {1}")]
+    UserDoesNotOwnActivity(UserId, ActivityId),
+    #[error(transparent)]
+    InvalidWindow(#[from] TrimByDistanceError),
+    #[error(transparent)]
+    Unknown(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, Constructor)]
+pub struct ReverseActivityRequest {
+    user: UserId,
+    activity: ActivityId,
+}
+
+impl ReverseActivityRequest {
+    pub fn user(&self) -> &UserId {
+        &self.user
+    }
+
+    pub fn activity(&self) -> &ActivityId {
+        &self.activity
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReverseActivityError {
+    #[error("Activity {0} does not exists")]
+    ActivityDoesNotExist(ActivityId),
+    #[error("User {0} does not own activity {1}")]
+    UserDoesNotOwnActivity(UserId, ActivityId),
+    #[error(transparent)]
+    InvalidData(#[from] ReverseTimeseriesError),
+    #[error(transparent)]
+    Unknown(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, Constructor)]
+pub struct ReplaceRawActivityRequest {
+    activity: ActivityId,
+    /// Content parsed from the new raw file, its visibility is ignored
+    content: CreateActivityRequest,
+}
+
+impl ReplaceRawActivityRequest {
+    pub fn user(&self) -> &UserId {
+        self.content.user()
+    }
+
+    pub fn activity(&self) -> &ActivityId {
+        &self.activity
+    }
+
+    pub fn content(&self) -> &CreateActivityRequest {
+        &self.content
+    }
+
+    pub fn raw_content(self) -> RawContent {
+        self.content.raw_content()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplaceRawActivityError {
+    #[error("Activity {0} does not exists")]
+    ActivityDoesNotExist(ActivityId),
+    #[error("User {0} does not own activity {1}")]
+    UserDoesNotOwnActivity(UserId, ActivityId),
+    #[error(transparent)]
+    Unknown(#[from] anyhow::Error),
+}
+
 #[derive(Debug, Clone, Constructor)]
 pub struct ListActivitiesFilters {
     limit: Option<usize>,
     date_range: Option<DateRange>,
+    visibility: Option<ActivityVisibility>,
+    bounding_box: Option<BoundingBox>,
 }
 
 impl ListActivitiesFilters {
@@ -384,6 +682,8 @@ impl ListActivitiesFilters {
         Self {
             limit: None,
             date_range: None,
+            visibility: None,
+            bounding_box: None,
         }
     }
 
@@ -402,6 +702,63 @@ impl ListActivitiesFilters {
     pub fn set_date_range(self, date_range: Option<DateRange>) -> Self {
         Self { date_range, ..self }
     }
+
+    pub fn visibility(&self) -> &Option<ActivityVisibility> {
+        &self.visibility
+    }
+
+    pub fn set_visibility(self, visibility: Option<ActivityVisibility>) -> Self {
+        Self { visibility, ..self }
+    }
+
+    /// Activities whose track's bounding box intersects this one.
+    pub fn bounding_box(&self) -> &Option<BoundingBox> {
+        &self.bounding_box
+    }
+
+    pub fn set_bounding_box(self, bounding_box: Option<BoundingBox>) -> Self {
+        Self {
+            bounding_box,
+            ..self
+        }
+    }
+}
+
+/// Keyset pagination over the activities of a user, newest first: the next page starts strictly
+/// after the last activity of the previous one, see [ActivitiesFeedCursor].
+#[derive(Debug, Clone, Constructor)]
+pub struct ActivitiesFeedFilters {
+    limit: usize,
+    before: Option<ActivitiesFeedCursor>,
+}
+
+impl ActivitiesFeedFilters {
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn before(&self) -> &Option<ActivitiesFeedCursor> {
+        &self.before
+    }
+}
+
+/// Position in the feed, activities being ordered by start time then id so that activities
+/// sharing the same start time are neither skipped nor repeated across pages. Without an id, the
+/// page starts strictly before `start_time`.
+#[derive(Debug, Clone, PartialEq, Constructor)]
+pub struct ActivitiesFeedCursor {
+    start_time: ActivityStartTime,
+    id: Option<ActivityId>,
+}
+
+impl ActivitiesFeedCursor {
+    pub fn start_time(&self) -> &ActivityStartTime {
+        &self.start_time
+    }
+
+    pub fn id(&self) -> &Option<ActivityId> {
+        &self.id
+    }
 }
 
 #[derive(Debug, Clone, Constructor)]
@@ -516,6 +873,13 @@ pub trait ActivityRepository: Clone + Send + Sync + 'static {
         activity: &ActivityWithParsedData,
     ) -> impl Future<Output = Result<(), SaveActivityError>> + Send;
 
+    /// Update the fields of an existing activity derived from its raw file, and drop its cached
+    /// metric values.
+    fn replace_activity_data(
+        &self,
+        activity: &ActivityWithParsedData,
+    ) -> impl Future<Output = Result<(), SaveActivityError>> + Send;
+
     fn list_activities(
         &self,
         user: &UserId,
@@ -553,6 +917,14 @@ pub trait ActivityRepository: Clone + Send + Sync + 'static {
         metrics: &[ActivityMetricV2],
     ) -> impl Future<Output = Result<Vec<(Activity, ActivityMetricsV2)>, ListActivitiesError>> + Send;
 
+    /// Activities of `user` ordered by start time, newest first, along with their stored
+    /// statistics, fetched in a single query.
+    fn get_activities_feed(
+        &self,
+        user: &UserId,
+        filters: &ActivitiesFeedFilters,
+    ) -> impl Future<Output = Result<Vec<ActivityWithSummary>, ListActivitiesError>> + Send;
+
     fn get_activity(
         &self,
         id: &ActivityId,
@@ -599,6 +971,12 @@ pub trait ActivityRepository: Clone + Send + Sync + 'static {
         feedback: Option<ActivityFeedback>,
     ) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
 
+    fn update_activity_visibility(
+        &self,
+        id: &ActivityId,
+        visibility: ActivityVisibility,
+    ) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
+
     fn delete_activity(
         &self,
         activity: &ActivityId,
@@ -610,6 +988,23 @@ pub trait ActivityRepository: Clone + Send + Sync + 'static {
     ) -> impl Future<Output = Result<Option<DateTimeRange>, anyhow::Error>> + Send;
 }
 
+/// Raw data written by [RawDataRepository::stage_raw_data], not yet the content of its activity.
+#[derive(Debug, Clone, PartialEq, Constructor)]
+pub struct StagedRawData {
+    activity_id: ActivityId,
+    extension: String,
+}
+
+impl StagedRawData {
+    pub fn activity_id(&self) -> &ActivityId {
+        &self.activity_id
+    }
+
+    pub fn extension(&self) -> &str {
+        &self.extension
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum SaveRawDataError {
     #[error("Raw data already exist for activity {0}")]
@@ -633,6 +1028,28 @@ pub trait RawDataRepository: Clone + Send + Sync + 'static {
         content: RawContent,
     ) -> impl Future<Output = Result<(), SaveRawDataError>> + Send;
 
+    /// Write the new raw data of an activity without replacing its current one yet, so that the
+    /// replacement can wait for the activity data to be updated. The staged content must then be
+    /// either committed or discarded.
+    fn stage_raw_data(
+        &self,
+        activity_id: &ActivityId,
+        content: RawContent,
+    ) -> impl Future<Output = Result<StagedRawData, SaveRawDataError>> + Send;
+
+    /// Make staged raw data the content of its activity, whatever the extension of the previous
+    /// content.
+    fn commit_raw_data(
+        &self,
+        staged: &StagedRawData,
+    ) -> impl Future<Output = Result<(), SaveRawDataError>> + Send;
+
+    /// Drop staged raw data, keeping the current content of its activity.
+    fn discard_raw_data(
+        &self,
+        staged: &StagedRawData,
+    ) -> impl Future<Output = Result<(), SaveRawDataError>> + Send;
+
     fn get_raw_data(
         &self,
         activity_id: &ActivityId,
@@ -659,6 +1076,16 @@ pub mod test_utils {
                 content: RawContent,
             ) -> Result<(), SaveRawDataError>;
 
+            async fn stage_raw_data(
+                &self,
+                activity_id: &ActivityId,
+                content: RawContent,
+            ) -> Result<StagedRawData, SaveRawDataError>;
+
+            async fn commit_raw_data(&self, staged: &StagedRawData) -> Result<(), SaveRawDataError>;
+
+            async fn discard_raw_data(&self, staged: &StagedRawData) -> Result<(), SaveRawDataError>;
+
             async fn get_raw_data(
                 &self,
                 activity_id: &ActivityId,
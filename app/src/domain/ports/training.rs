@@ -281,6 +281,14 @@ pub trait ITrainingService: Clone + Send + Sync + 'static {
         req: CopyTrainingMetricRequest,
     ) -> impl Future<Output = Result<(), CopyTrainingMetricError>> + Send;
 
+    /// Definition of a metric of the user, `None` if it does not exist or belongs to another
+    /// user.
+    fn get_training_metric(
+        &self,
+        user: &UserId,
+        metric_id: &TrainingMetricId,
+    ) -> impl Future<Output = Result<Option<TrainingMetric>, GetTrainingMetricError>> + Send;
+
     fn get_training_metrics_values(
         &self,
         user: &UserId,
@@ -360,6 +368,13 @@ pub trait ITrainingService: Clone + Send + Sync + 'static {
         req: CreateTrainingNoteRequest,
     ) -> impl Future<Output = Result<TrainingNoteId, CreateTrainingNoteError>> + Send;
 
+    /// Create all the notes of `reqs`, or none of them if one fails. Ids are returned in the
+    /// order of `reqs`.
+    fn create_training_notes(
+        &self,
+        reqs: Vec<CreateTrainingNoteRequest>,
+    ) -> impl Future<Output = Result<Vec<TrainingNoteId>, CreateTrainingNoteError>> + Send;
+
     fn get_training_note(
         &self,
         user: &UserId,
@@ -724,12 +739,18 @@ pub enum GetTrainingNoteError {
 
 #[derive(Debug, Error)]
 pub enum UpdateTrainingNoteError {
+    /// Also returned when the note belongs to another user.
+    #[error("Training note {0} does not exist")]
+    NoteDoesNotExist(TrainingNoteId),
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
 }
 
 #[derive(Debug, Error)]
 pub enum DeleteTrainingNoteError {
+    /// Also returned when the note belongs to another user.
+    #[error("Training note {0} does not exist")]
+    NoteDoesNotExist(TrainingNoteId),
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
 }
@@ -828,6 +849,12 @@ pub trait TrainingRepository: Clone + Send + Sync + 'static {
         note: TrainingNote,
     ) -> impl Future<Output = Result<(), SaveTrainingNoteError>> + Send;
 
+    /// Save all the `notes` in a single transaction.
+    fn save_training_notes(
+        &self,
+        notes: Vec<TrainingNote>,
+    ) -> impl Future<Output = Result<(), SaveTrainingNoteError>> + Send;
+
     fn get_training_note(
         &self,
         user: &UserId,
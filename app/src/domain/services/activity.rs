@@ -1,24 +1,44 @@
 use anyhow::anyhow;
+use chrono::Days;
 
 use crate::domain::{
     models::{
         UserId,
         activity::{
-            Activity, ActivityId, ActivityMetricV2, ActivityMetricsV2, ActivityWithParsedData,
-            DEFAULT_METRICS,
+            Activity, ActivityId, ActivityMetricV2, ActivityMetricsV2, ActivityName,
+            ActivityStatistic, ActivityWithParsedData, ActivityWithSummary, DEFAULT_METRICS,
+            NearDuplicateWindow, WorkoutType, default_title,
         },
     },
-    ports::activity::{
-        ActivityRepository, CreateActivityError, CreateActivityRequest, DeleteActivityError,
-        DeleteActivityRequest, GetActivityError, GetAllActivitiesError, GetAllActivitiesRequest,
-        GetRawActivityError, GetRawActivityRequest, IActivityService, ListActivitiesError,
-        ListActivitiesFilters, ModifyActivityError, ModifyActivityRequest, RawActivity,
-        RawDataRepository, UpdateActivityFeedbackError, UpdateActivityFeedbackRequest,
-        UpdateActivityNutritionError, UpdateActivityNutritionRequest, UpdateActivityRpeError,
-        UpdateActivityRpeRequest, UpdateActivityWorkoutTypeError, UpdateActivityWorkoutTypeRequest,
+    ports::{
+        DateRange,
+        activity::{
+            ActivitiesFeedFilters, ActivityRepository, CreateActivityError, CreateActivityRequest,
+            DeleteActivityError, DeleteActivityRequest, GetActivityError, GetAllActivitiesError,
+            GetAllActivitiesRequest, GetRawActivityError, GetRawActivityRequest, IActivityService,
+            ListActivitiesError, ListActivitiesFilters, MergeActivityStreamError,
+            MergeActivityStreamRequest, ModifyActivityError, ModifyActivityRequest, RawActivity,
+            RawDataRepository, ReplaceRawActivityError, ReplaceRawActivityRequest,
+            ReverseActivityError, ReverseActivityRequest, TrimActivityError, TrimActivityRequest,
+            UpdateActivityFeedbackError, UpdateActivityFeedbackRequest,
+            UpdateActivityNutritionError, UpdateActivityNutritionRequest, UpdateActivityRpeError,
+            UpdateActivityRpeRequest, UpdateActivityVisibilityError,
+            UpdateActivityVisibilityRequest, UpdateActivityWorkoutTypeError,
+            UpdateActivityWorkoutTypeRequest,
+        },
     },
 };
 
+/// Compute the [DEFAULT_METRICS] of an activity that is not persisted.
+fn default_metrics(activity: &ActivityWithParsedData) -> ActivityMetricsV2 {
+    let mut metrics = ActivityMetricsV2::default();
+    for metric in DEFAULT_METRICS {
+        let value = metric.compute_value(activity);
+        metrics.insert(metric, value);
+    }
+    metrics
+}
+
 #[derive(Debug, Clone)]
 pub struct ActivityService<AR, RDR>
 where
@@ -27,6 +47,7 @@ where
 {
     activity_repository: AR,
     raw_data_repository: RDR,
+    near_duplicate_window: Option<NearDuplicateWindow>,
 }
 
 impl<AR, RDR> ActivityService<AR, RDR>
@@ -38,8 +59,52 @@ where
         Self {
             activity_repository,
             raw_data_repository,
+            near_duplicate_window: None,
+        }
+    }
+
+    /// Enable the near duplicate check of new activities requesting it, see
+    /// [CreateActivityRequest::with_near_duplicate_check].
+    pub fn with_near_duplicate_window(
+        self,
+        near_duplicate_window: Option<NearDuplicateWindow>,
+    ) -> Self {
+        Self {
+            near_duplicate_window,
+            ..self
         }
     }
+
+    /// First activity of the user that is a near duplicate of `activity`, within `window`.
+    async fn find_near_duplicate(
+        &self,
+        activity: &ActivityWithParsedData,
+        window: &NearDuplicateWindow,
+    ) -> Result<Option<ActivityId>, ListActivitiesError> {
+        let start = activity.start_time().datetime().date_naive();
+        let margin = Days::new(window.seconds().div_ceil(86400) as u64 + 1);
+        let filters = ListActivitiesFilters::empty()
+            .set_date_range(Some(DateRange::new(start - margin, start + margin)));
+        let distance = activity
+            .statistics()
+            .get(&ActivityStatistic::Distance)
+            .copied();
+
+        let candidates = self
+            .list_activities_with_metrics(activity.user(), &filters, &[ActivityMetricV2::Distance])
+            .await?;
+        Ok(candidates
+            .into_iter()
+            .find(|(candidate, metrics)| {
+                let candidate_distance =
+                    metrics.get(&ActivityMetricV2::Distance).copied().flatten();
+                window.matches(
+                    (activity.activity(), distance),
+                    (candidate, candidate_distance),
+                )
+            })
+            .map(|(candidate, _)| candidate.id().clone()))
+    }
 }
 
 impl<AR, RDR> IActivityService for ActivityService<AR, RDR>
@@ -53,18 +118,30 @@ where
     ) -> Result<Activity, CreateActivityError> {
         // Create activity from request
         let id = ActivityId::new();
+        let name = req
+            .name()
+            .cloned()
+            .unwrap_or_else(|| ActivityName::new(default_title(req.sport(), req.start_time())));
+        let is_commute = req
+            .commute_locations()
+            .is_some_and(|locations| locations.matches(&req.timeseries().positions()));
         let activity = Activity::new_empty(
             id.clone(),
             req.user().clone(),
             *req.start_time(),
             *req.duration(),
             *req.sport(),
-        );
+        )
+        .with_visibility(*req.visibility())
+        .with_name(Some(name))
+        .with_workout_type(is_commute.then_some(WorkoutType::Commute));
 
         let activity_with_parsed_data = ActivityWithParsedData::new(
             activity.clone(),
             req.timeseries().clone(),
-            req.statistics().clone(),
+            req.statistics()
+                .clone()
+                .with_estimated_calories(req.timeseries(), req.athlete_profile()),
         );
 
         if self
@@ -78,6 +155,16 @@ where
             return Err(CreateActivityError::SimilarActivityExistsError);
         }
 
+        if let Some(window) = self.near_duplicate_window.as_ref()
+            && req.check_near_duplicates()
+            && let Some(candidate) = self
+                .find_near_duplicate(&activity_with_parsed_data, window)
+                .await
+                .map_err(|err| anyhow!(err).context("Failed to look for near duplicates"))?
+        {
+            return Err(CreateActivityError::PossibleDuplicate(candidate));
+        }
+
         // Persist raw data
         self.raw_data_repository
             .save_raw_data(&id, req.raw_content())
@@ -168,6 +255,20 @@ where
         Ok(activities)
     }
 
+    async fn has_metric_data(
+        &self,
+        user: &UserId,
+        metric: &ActivityMetricV2,
+    ) -> Result<bool, ListActivitiesError> {
+        let activities = self
+            .list_activities_with_metrics(user, &ListActivitiesFilters::empty(), &[*metric])
+            .await?;
+
+        Ok(activities
+            .iter()
+            .any(|(_, metrics)| metrics.get(metric).is_some_and(Option::is_some)))
+    }
+
     async fn list_activities_with_metrics_and_parsed_data(
         &self,
         user: &UserId,
@@ -191,6 +292,16 @@ where
         Ok(res)
     }
 
+    async fn list_activities_feed(
+        &self,
+        user: &UserId,
+        filters: &ActivitiesFeedFilters,
+    ) -> Result<Vec<ActivityWithSummary>, ListActivitiesError> {
+        self.activity_repository
+            .get_activities_feed(user, filters)
+            .await
+    }
+
     async fn get_activity_with_parsed_data(
         &self,
         activity_id: &ActivityId,
@@ -356,6 +467,29 @@ where
         Ok(())
     }
 
+    async fn update_activity_visibility(
+        &self,
+        req: UpdateActivityVisibilityRequest,
+    ) -> Result<(), UpdateActivityVisibilityError> {
+        let Ok(Some(activity)) = self.activity_repository.get_activity(req.activity()).await else {
+            return Err(UpdateActivityVisibilityError::ActivityDoesNotExist(
+                req.activity().clone(),
+            ));
+        };
+
+        if activity.user() != req.user() {
+            return Err(UpdateActivityVisibilityError::UserDoesNotOwnActivity(
+                req.user().clone(),
+                req.activity().clone(),
+            ));
+        }
+
+        self.activity_repository
+            .update_activity_visibility(req.activity(), *req.visibility())
+            .await
+            .map_err(UpdateActivityVisibilityError::Unknown)
+    }
+
     async fn delete_activity(&self, req: DeleteActivityRequest) -> Result<(), DeleteActivityError> {
         let Ok(Some(activity)) = self.activity_repository.get_activity(req.activity()).await else {
             return Err(DeleteActivityError::ActivityDoesNotExist(
@@ -377,6 +511,98 @@ where
         Ok(())
     }
 
+    async fn merge_activity_stream(
+        &self,
+        req: MergeActivityStreamRequest,
+    ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), MergeActivityStreamError> {
+        let mut activities = Vec::with_capacity(2);
+        for id in [req.activity(), req.source()] {
+            let activity = match self
+                .activity_repository
+                .get_activity_with_parsed_data(id)
+                .await
+            {
+                Ok(Some(activity)) => activity,
+                Ok(None) => return Err(MergeActivityStreamError::ActivityDoesNotExist(id.clone())),
+                Err(err) => return Err(anyhow!(err).into()),
+            };
+            if activity.user() != req.user() {
+                return Err(MergeActivityStreamError::UserDoesNotOwnActivity(
+                    req.user().clone(),
+                    id.clone(),
+                ));
+            }
+            activities.push(activity);
+        }
+        let source = activities.pop().expect("Source activity has been fetched");
+        let activity = activities.pop().expect("Target activity has been fetched");
+
+        let merged = activity.merge_metric_from(&source, req.metric());
+        let metrics = default_metrics(&merged);
+
+        Ok((merged, metrics))
+    }
+
+    async fn trim_activity(
+        &self,
+        req: TrimActivityRequest,
+    ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), TrimActivityError> {
+        let activity = match self
+            .activity_repository
+            .get_activity_with_parsed_data(req.activity())
+            .await
+        {
+            Ok(Some(activity)) => activity,
+            Ok(None) => {
+                return Err(TrimActivityError::ActivityDoesNotExist(
+                    req.activity().clone(),
+                ));
+            }
+            Err(err) => return Err(anyhow!(err).into()),
+        };
+        if activity.user() != req.user() {
+            return Err(TrimActivityError::UserDoesNotOwnActivity(
+                req.user().clone(),
+                req.activity().clone(),
+            ));
+        }
+
+        let trimmed = activity.trim_by_distance(req.start(), req.end())?;
+        let metrics = default_metrics(&trimmed);
+
+        Ok((trimmed, metrics))
+    }
+
+    async fn reverse_activity(
+        &self,
+        req: ReverseActivityRequest,
+    ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), ReverseActivityError> {
+        let activity = match self
+            .activity_repository
+            .get_activity_with_parsed_data(req.activity())
+            .await
+        {
+            Ok(Some(activity)) => activity,
+            Ok(None) => {
+                return Err(ReverseActivityError::ActivityDoesNotExist(
+                    req.activity().clone(),
+                ));
+            }
+            Err(err) => return Err(anyhow!(err).into()),
+        };
+        if activity.user() != req.user() {
+            return Err(ReverseActivityError::UserDoesNotOwnActivity(
+                req.user().clone(),
+                req.activity().clone(),
+            ));
+        }
+
+        let reversed = activity.reverse()?;
+        let metrics = default_metrics(&reversed);
+
+        Ok((reversed, metrics))
+    }
+
     async fn get_raw_activity(
         &self,
         req: GetRawActivityRequest,
@@ -386,6 +612,93 @@ where
             .await
     }
 
+    async fn replace_raw_activity(
+        &self,
+        req: ReplaceRawActivityRequest,
+    ) -> Result<Activity, ReplaceRawActivityError> {
+        let existing = match self.activity_repository.get_activity(req.activity()).await {
+            Ok(Some(activity)) => activity,
+            Ok(None) => {
+                return Err(ReplaceRawActivityError::ActivityDoesNotExist(
+                    req.activity().clone(),
+                ));
+            }
+            Err(err) => return Err(anyhow!(err).into()),
+        };
+        if existing.user() != req.user() {
+            return Err(ReplaceRawActivityError::UserDoesNotOwnActivity(
+                req.user().clone(),
+                req.activity().clone(),
+            ));
+        }
+
+        let content = req.content();
+        let activity = Activity::new(
+            existing.id().clone(),
+            existing.user().clone(),
+            existing.name().cloned(),
+            *content.start_time(),
+            *content.duration(),
+            *content.sport(),
+            *existing.rpe(),
+            *existing.workout_type(),
+            existing.nutrition().clone(),
+            existing.feedback().clone(),
+            *existing.visibility(),
+        );
+        let activity_with_parsed_data = ActivityWithParsedData::new(
+            activity.clone(),
+            content.timeseries().clone(),
+            content.statistics().clone(),
+        );
+
+        // The new raw data only replaces the current one once the activity data is updated, so
+        // that the stored file always matches the activity statistics
+        let id = activity.id().clone();
+        let staged = self
+            .raw_data_repository
+            .stage_raw_data(&id, req.raw_content())
+            .await
+            .map_err(|err| {
+                anyhow!(err).context(format!("Failed to write new raw data for activity {}", id))
+            })?;
+
+        if let Err(err) = self
+            .activity_repository
+            .replace_activity_data(&activity_with_parsed_data)
+            .await
+        {
+            if let Err(discard_err) = self.raw_data_repository.discard_raw_data(&staged).await {
+                tracing::warn!("Failed to discard new raw data for activity {id}: {discard_err}");
+            }
+            return Err(anyhow!(err)
+                .context(format!("Failed to update activity {}", id))
+                .into());
+        }
+
+        self.raw_data_repository
+            .commit_raw_data(&staged)
+            .await
+            .map_err(|err| {
+                anyhow!(err).context(format!("Failed to replace raw data for activity {}", id))
+            })?;
+
+        // Cached metrics have been dropped, pre-compute the base ones again. They are computed
+        // again when missing, so a failure does not fail the replacement.
+        for ref metric in DEFAULT_METRICS {
+            let value = metric.compute_value(&activity_with_parsed_data);
+            if let Err(err) = self
+                .activity_repository
+                .update_activity_metric(&id, metric, &value)
+                .await
+            {
+                tracing::warn!("Failed to update metric {metric:?} of activity {id}: {err}");
+            }
+        }
+
+        Ok(activity)
+    }
+
     async fn get_all_raw_activities(
         &self,
         req: GetAllActivitiesRequest,
@@ -449,6 +762,12 @@ pub mod test_utils {
                 metrics: &[ActivityMetricV2],
             ) -> Result<Vec<(Activity, ActivityMetricsV2)>, ListActivitiesError>;
 
+            async fn has_metric_data(
+                &self,
+                user: &UserId,
+                metric: &ActivityMetricV2,
+            ) -> Result<bool, ListActivitiesError>;
+
             async fn list_activities_with_metrics_and_parsed_data(
                 &self,
                 user: &UserId,
@@ -456,6 +775,12 @@ pub mod test_utils {
                 metrics: &[ActivityMetricV2],
             ) -> Result<Vec<(ActivityWithParsedData, ActivityMetricsV2)>, ListActivitiesError>;
 
+            async fn list_activities_feed(
+                &self,
+                user: &UserId,
+                filters: &ActivitiesFeedFilters,
+            ) -> Result<Vec<ActivityWithSummary>, ListActivitiesError>;
+
             async fn get_activity_with_parsed_data(
                 &self,
                 activity_id: &ActivityId,
@@ -492,16 +817,41 @@ pub mod test_utils {
                 _req: UpdateActivityFeedbackRequest,
             ) -> Result<(), UpdateActivityFeedbackError>;
 
+            async fn update_activity_visibility(
+                &self,
+                _req: UpdateActivityVisibilityRequest,
+            ) -> Result<(), UpdateActivityVisibilityError>;
+
             async fn delete_activity(
                 &self,
                 req: DeleteActivityRequest,
             ) -> Result<(), DeleteActivityError>;
 
+            async fn merge_activity_stream(
+                &self,
+                req: MergeActivityStreamRequest,
+            ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), MergeActivityStreamError>;
+
+            async fn trim_activity(
+                &self,
+                req: TrimActivityRequest,
+            ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), TrimActivityError>;
+
+            async fn reverse_activity(
+                &self,
+                req: ReverseActivityRequest,
+            ) -> Result<(ActivityWithParsedData, ActivityMetricsV2), ReverseActivityError>;
+
             async fn get_raw_activity(
                 &self,
                 req: GetRawActivityRequest,
             ) -> Result<RawActivity, GetRawActivityError>;
 
+            async fn replace_raw_activity(
+                &self,
+                req: ReplaceRawActivityRequest,
+            ) -> Result<Activity, ReplaceRawActivityError>;
+
             async fn get_all_raw_activities(
                 &self,
                 req: GetAllActivitiesRequest,
@@ -571,6 +921,11 @@ pub mod test_utils {
                 activity: &ActivityWithParsedData,
             ) -> Result<(), SaveActivityError>;
 
+            async fn replace_activity_data(
+                &self,
+                activity: &ActivityWithParsedData,
+            ) -> Result<(), SaveActivityError>;
+
             async fn list_activities(
                 &self,
                 user: &UserId,
@@ -608,6 +963,12 @@ pub mod test_utils {
                 metrics: &[ActivityMetricV2],
             ) -> Result<Vec<(Activity, ActivityMetricsV2)>, ListActivitiesError>;
 
+            async fn get_activities_feed(
+                &self,
+                user: &UserId,
+                filters: &ActivitiesFeedFilters,
+            ) -> Result<Vec<ActivityWithSummary>, ListActivitiesError>;
+
             async fn get_activity(
                 &self,
                 id: &ActivityId,
@@ -654,6 +1015,12 @@ pub mod test_utils {
                 feedback: Option<crate::domain::models::activity::ActivityFeedback>,
             ) -> Result<(), anyhow::Error>;
 
+            async fn update_activity_visibility(
+                &self,
+                id: &ActivityId,
+                visibility: crate::domain::models::activity::ActivityVisibility,
+            ) -> Result<(), anyhow::Error>;
+
             async fn delete_activity(
                 &self,
                 activity: &ActivityId,
@@ -679,13 +1046,14 @@ mod tests_activity_service {
         models::{
             UserId,
             activity::{
-                ActivityDuration, ActivityName, ActivityStartTime, ActivityStatistics,
-                ActivityTimeseries, Sport,
+                ActiveTime, ActivityDuration, ActivityName, ActivityStartTime, ActivityStatistics,
+                ActivityTimeseries, CommuteLocations, GeoPoint, Sport, Timeseries,
+                TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
             },
         },
         ports::activity::{
             DeleteActivityError, DeleteActivityRequest, GetRawDataError, ModifyActivityError,
-            ModifyActivityRequest, RawContent, SaveActivityError, SaveRawDataError,
+            ModifyActivityRequest, RawContent, SaveActivityError, SaveRawDataError, StagedRawData,
         },
         services::activity::test_utils::MockActivityRepository,
     };
@@ -706,6 +1074,16 @@ mod tests_activity_service {
                 _content: RawContent,
             ) -> Result<(), SaveRawDataError>;
 
+            async fn stage_raw_data(
+                &self,
+                _activity_id: &ActivityId,
+                _content: RawContent,
+            ) -> Result<StagedRawData, SaveRawDataError>;
+
+            async fn commit_raw_data(&self, _staged: &StagedRawData) -> Result<(), SaveRawDataError>;
+
+            async fn discard_raw_data(&self, _staged: &StagedRawData) -> Result<(), SaveRawDataError>;
+
             async fn get_raw_data(
                 &self,
                 _activity_id: &ActivityId,
@@ -780,105 +1158,275 @@ mod tests_activity_service {
         let res = service.create_activity(req).await;
 
         assert!(res.is_ok());
+        // Started at 01:00
+        assert_eq!(
+            res.unwrap().name(),
+            Some(&ActivityName::new("Night Run".to_string()))
+        );
     }
 
     #[tokio::test]
-    async fn test_service_create_activity_save_activity_error() {
+    async fn test_service_create_activity_keeps_supplied_name() {
         let mut activity_repository = MockActivityRepository::new();
         activity_repository
             .expect_similar_activity_exists()
             .returning(|_| Ok(false));
         activity_repository
             .expect_save_activity()
-            .returning(|_| Err(SaveActivityError::Unknown(anyhow!("an error occured"))));
-
+            .times(1)
+            .withf(|activity| activity.name() == Some(&ActivityName::from("Race day")))
+            .returning(|_| Ok(()));
+        activity_repository
+            .expect_update_activity_metric()
+            .returning(|_, _, _| Ok(()));
         let mut raw_data_repository = MockRawDataRepository::new();
         raw_data_repository
             .expect_save_raw_data()
-            .returning(|_, _| Ok(()));
+            .returning(|_, __| Ok(()));
+
         let service = ActivityService::new(activity_repository, raw_data_repository);
 
-        let req = default_activity_request();
+        let req = default_activity_request().with_name(Some(ActivityName::from("Race day")));
 
         let res = service.create_activity(req).await;
 
-        assert!(res.is_err())
+        assert!(res.is_ok());
     }
 
-    #[tokio::test]
-    async fn test_service_create_activity_raw_data_error_do_not_save_activity() {
+    fn commute_request(latitudes: Vec<f64>, longitudes: Vec<f64>) -> CreateActivityRequest {
+        let len = latitudes.len();
+        let values = |values: Vec<f64>| values.into_iter().map(TimeseriesValue::Float).map(Some);
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new((0..len).collect()),
+            TimeseriesActiveTime::new((0..len).map(ActiveTime::Running).collect()),
+            vec![],
+            vec![
+                Timeseries::new(TimeseriesMetric::Latitude, values(latitudes).collect()),
+                Timeseries::new(TimeseriesMetric::Longitude, values(longitudes).collect()),
+            ],
+        )
+        .unwrap();
+        CreateActivityRequest::new(
+            UserId::test_default(),
+            Sport::Cycling,
+            ActivityStartTime::from_timestamp(3600).unwrap(),
+            ActivityDuration::default(),
+            ActivityStatistics::default(),
+            timeseries,
+            RawContent::new("fit".to_string(), vec![1, 2, 3]),
+        )
+        .with_commute_locations(Some(CommuteLocations::new(
+            GeoPoint::new(45.19, 5.72).unwrap(),
+            GeoPoint::new(45.21, 5.80).unwrap(),
+        )))
+    }
+
+    fn service_saving_workout_type(
+        workout_type: Option<WorkoutType>,
+    ) -> ActivityService<MockActivityRepository, MockRawDataRepository> {
         let mut activity_repository = MockActivityRepository::new();
         activity_repository
             .expect_similar_activity_exists()
             .returning(|_| Ok(false));
-        activity_repository.expect_save_activity().times(0);
-
+        activity_repository
+            .expect_save_activity()
+            .times(1)
+            .withf(move |activity| activity.workout_type() == &workout_type)
+            .returning(|_| Ok(()));
+        activity_repository
+            .expect_update_activity_metric()
+            .returning(|_, _, _| Ok(()));
         let mut raw_data_repository = MockRawDataRepository::new();
         raw_data_repository
             .expect_save_raw_data()
-            .returning(|_, _| Err(SaveRawDataError::Unknown));
+            .returning(|_, __| Ok(()));
 
-        let service = ActivityService::new(activity_repository, raw_data_repository);
+        ActivityService::new(activity_repository, raw_data_repository)
+    }
 
-        let req = default_activity_request();
+    #[tokio::test]
+    async fn test_service_create_activity_tags_round_trip_commute() {
+        let service = service_saving_workout_type(Some(WorkoutType::Commute));
+        let req = commute_request(
+            vec![45.1902, 45.20, 45.2101, 45.20, 45.1899],
+            vec![5.7201, 5.76, 5.7998, 5.76, 5.7202],
+        );
 
-        let res = service.create_activity(req).await;
+        let activity = service.create_activity(req).await.unwrap();
 
-        assert!(res.is_err())
+        assert_eq!(activity.workout_type(), &Some(WorkoutType::Commute));
     }
 
     #[tokio::test]
-    async fn test_activity_service_modify_activity_not_found() {
-        let mut activity_repository = MockActivityRepository::new();
-        activity_repository
-            .expect_get_activity()
-            .returning(|_| Ok(None));
-
-        let raw_data_repository = MockRawDataRepository::default();
-        let service = ActivityService::new(activity_repository, raw_data_repository);
+    async fn test_service_create_activity_does_not_tag_point_to_point_route() {
+        let service = service_saving_workout_type(None);
+        let req = commute_request(vec![45.1902, 45.30, 45.45], vec![5.7201, 5.90, 6.05]);
 
-        let req =
-            ModifyActivityRequest::new(UserId::test_default(), ActivityId::from("test"), None);
+        let activity = service.create_activity(req).await.unwrap();
 
-        let Err(ModifyActivityError::ActivityDoesNotExist(activity)) =
-            service.modify_activity(req).await
-        else {
-            unreachable!("Should have returned an err")
-        };
-        assert_eq!(activity, ActivityId::from("test"));
+        assert_eq!(activity.workout_type(), &None);
     }
 
-    #[tokio::test]
-    async fn test_activity_service_modify_activity_not_owned_by_user() {
+    fn repository_with_near_duplicate() -> MockActivityRepository {
         let mut activity_repository = MockActivityRepository::new();
-        activity_repository.expect_get_activity().returning(|_| {
-            Ok(Some(Activity::new_empty(
-                ActivityId::from("test_activity"),
-                UserId::from("another_user".to_string()),
-                ActivityStartTime::from_timestamp(0).unwrap(),
-                ActivityDuration::default(),
-                Sport::Cycling,
-            )))
-        });
+        activity_repository
+            .expect_similar_activity_exists()
+            .returning(|_| Ok(false));
+        activity_repository
+            .expect_get_activities_with_metrics()
+            .returning(|_, _, _| {
+                Ok(vec![(
+                    Activity::new_empty(
+                        ActivityId::from("watch-run"),
+                        UserId::test_default(),
+                        ActivityStartTime::from_timestamp(3660).unwrap(),
+                        ActivityDuration::default(),
+                        Sport::Running,
+                    ),
+                    ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::Distance, None)])),
+                )])
+            });
+        activity_repository
+    }
 
-        let raw_data_repository = MockRawDataRepository::default();
-        let service = ActivityService::new(activity_repository, raw_data_repository);
+    #[tokio::test]
+    async fn test_service_create_activity_err_if_near_duplicate_exists() {
+        let service = ActivityService::new(
+            repository_with_near_duplicate(),
+            MockRawDataRepository::new(),
+        )
+        .with_near_duplicate_window(Some(NearDuplicateWindow::new(300)));
 
-        let req =
-            ModifyActivityRequest::new("test_user".into(), ActivityId::from("test_activity"), None);
+        let req = default_activity_request().with_near_duplicate_check(true);
 
-        let Err(ModifyActivityError::UserDoesNotOwnActivity(user, activity)) =
-            service.modify_activity(req).await
+        let Err(CreateActivityError::PossibleDuplicate(candidate)) =
+            service.create_activity(req).await
         else {
-            unreachable!("Should have returned an err")
+            unreachable!("Should have returned a Err(CreateActivityError::PossibleDuplicate)")
         };
-        assert_eq!(user, "test_user".to_string().into());
-        assert_eq!(activity, ActivityId::from("test_activity"));
+        assert_eq!(candidate, ActivityId::from("watch-run"));
     }
 
     #[tokio::test]
-    async fn test_activity_service_modify_activity_ok() {
-        let mut activity_repository = MockActivityRepository::new();
+    async fn test_service_create_activity_near_duplicate_not_checked() {
+        let mut activity_repository = repository_with_near_duplicate();
+        activity_repository
+            .expect_save_activity()
+            .times(1)
+            .returning(|_| Ok(()));
+        activity_repository
+            .expect_update_activity_metric()
+            .returning(|_, _, _| Ok(()));
+        let mut raw_data_repository = MockRawDataRepository::new();
+        raw_data_repository
+            .expect_save_raw_data()
+            .returning(|_, __| Ok(()));
+
+        let service = ActivityService::new(activity_repository, raw_data_repository)
+            .with_near_duplicate_window(Some(NearDuplicateWindow::new(300)));
+
+        let req = default_activity_request().with_near_duplicate_check(false);
+
+        assert!(service.create_activity(req).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_service_create_activity_save_activity_error() {
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository
+            .expect_similar_activity_exists()
+            .returning(|_| Ok(false));
+        activity_repository
+            .expect_save_activity()
+            .returning(|_| Err(SaveActivityError::Unknown(anyhow!("an error occured"))));
+
+        let mut raw_data_repository = MockRawDataRepository::new();
+        raw_data_repository
+            .expect_save_raw_data()
+            .returning(|_, _| Ok(()));
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req = default_activity_request();
+
+        let res = service.create_activity(req).await;
+
+        assert!(res.is_err())
+    }
+
+    #[tokio::test]
+    async fn test_service_create_activity_raw_data_error_do_not_save_activity() {
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository
+            .expect_similar_activity_exists()
+            .returning(|_| Ok(false));
+        activity_repository.expect_save_activity().times(0);
+
+        let mut raw_data_repository = MockRawDataRepository::new();
+        raw_data_repository
+            .expect_save_raw_data()
+            .returning(|_, _| Err(SaveRawDataError::Unknown));
+
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req = default_activity_request();
+
+        let res = service.create_activity(req).await;
+
+        assert!(res.is_err())
+    }
+
+    #[tokio::test]
+    async fn test_activity_service_modify_activity_not_found() {
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository
+            .expect_get_activity()
+            .returning(|_| Ok(None));
+
+        let raw_data_repository = MockRawDataRepository::default();
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req =
+            ModifyActivityRequest::new(UserId::test_default(), ActivityId::from("test"), None);
+
+        let Err(ModifyActivityError::ActivityDoesNotExist(activity)) =
+            service.modify_activity(req).await
+        else {
+            unreachable!("Should have returned an err")
+        };
+        assert_eq!(activity, ActivityId::from("test"));
+    }
+
+    #[tokio::test]
+    async fn test_activity_service_modify_activity_not_owned_by_user() {
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository.expect_get_activity().returning(|_| {
+            Ok(Some(Activity::new_empty(
+                ActivityId::from("test_activity"),
+                UserId::from("another_user".to_string()),
+                ActivityStartTime::from_timestamp(0).unwrap(),
+                ActivityDuration::default(),
+                Sport::Cycling,
+            )))
+        });
+
+        let raw_data_repository = MockRawDataRepository::default();
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req =
+            ModifyActivityRequest::new("test_user".into(), ActivityId::from("test_activity"), None);
+
+        let Err(ModifyActivityError::UserDoesNotOwnActivity(user, activity)) =
+            service.modify_activity(req).await
+        else {
+            unreachable!("Should have returned an err")
+        };
+        assert_eq!(user, "test_user".to_string().into());
+        assert_eq!(activity, ActivityId::from("test_activity"));
+    }
+
+    #[tokio::test]
+    async fn test_activity_service_modify_activity_ok() {
+        let mut activity_repository = MockActivityRepository::new();
         activity_repository.expect_get_activity().returning(|_| {
             Ok(Some(Activity::new_empty(
                 ActivityId::from("test_activity"),
@@ -1094,6 +1642,77 @@ mod tests_activity_service {
         assert_eq!(activity, ActivityId::from("test_activity"));
     }
 
+    #[tokio::test]
+    async fn test_activity_service_update_activity_visibility_ok() {
+        use crate::domain::models::activity::ActivityVisibility;
+
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository.expect_get_activity().returning(|_| {
+            Ok(Some(Activity::new_empty(
+                ActivityId::from("test"),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(0).unwrap(),
+                ActivityDuration::default(),
+                Sport::Cycling,
+            )))
+        });
+        activity_repository
+            .expect_update_activity_visibility()
+            .withf(|id, visibility| {
+                *id == ActivityId::from("test") && *visibility == ActivityVisibility::Public
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let raw_data_repository = MockRawDataRepository::default();
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req = UpdateActivityVisibilityRequest::new(
+            UserId::test_default(),
+            ActivityId::from("test"),
+            ActivityVisibility::Public,
+        );
+
+        let res = service.update_activity_visibility(req).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_activity_service_update_activity_visibility_wrong_user() {
+        use crate::domain::models::activity::ActivityVisibility;
+
+        let mut activity_repository = MockActivityRepository::new();
+        activity_repository.expect_get_activity().returning(|_| {
+            Ok(Some(Activity::new_empty(
+                ActivityId::from("test_activity"),
+                "other_user".into(),
+                ActivityStartTime::from_timestamp(0).unwrap(),
+                ActivityDuration::default(),
+                Sport::Cycling,
+            )))
+        });
+        activity_repository
+            .expect_update_activity_visibility()
+            .never();
+
+        let raw_data_repository = MockRawDataRepository::default();
+        let service = ActivityService::new(activity_repository, raw_data_repository);
+
+        let req = UpdateActivityVisibilityRequest::new(
+            UserId::test_default(),
+            ActivityId::from("test_activity"),
+            ActivityVisibility::Public,
+        );
+
+        let Err(UpdateActivityVisibilityError::UserDoesNotOwnActivity(user, activity)) =
+            service.update_activity_visibility(req).await
+        else {
+            unreachable!("Should have returned an error")
+        };
+        assert_eq!(user, UserId::test_default());
+        assert_eq!(activity, ActivityId::from("test_activity"));
+    }
+
     #[tokio::test]
     async fn test_activity_service_update_activity_workout_type_ok() {
         use crate::domain::models::activity::WorkoutType;
@@ -1726,4 +2345,618 @@ mod tests_activity_service {
             assert!(res.is_err());
         }
     }
+
+    mod test_activity_service_has_metric_data {
+        use crate::domain::models::activity::ActivityId;
+
+        use super::*;
+
+        fn service_with_metrics(
+            metrics: Vec<ActivityMetricsV2>,
+        ) -> ActivityService<MockActivityRepository, MockRawDataRepository> {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activities_with_metrics()
+                .returning(move |_, _, _| {
+                    Ok(metrics
+                        .iter()
+                        .map(|metrics| {
+                            (
+                                Activity::new_empty(
+                                    ActivityId::new(),
+                                    UserId::test_default(),
+                                    ActivityStartTime::from_timestamp(0).unwrap(),
+                                    ActivityDuration::from(1200.),
+                                    Sport::Running,
+                                ),
+                                metrics.clone(),
+                            )
+                        })
+                        .collect())
+                });
+            ActivityService::new(activity_repository, MockRawDataRepository::default())
+        }
+
+        #[tokio::test]
+        async fn test_has_metric_data_when_one_activity_has_a_value() {
+            let service = service_with_metrics(vec![
+                ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::AvgPower, None)])),
+                ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::AvgPower, Some(210.))])),
+            ]);
+
+            assert!(
+                service
+                    .has_metric_data(&UserId::test_default(), &ActivityMetricV2::AvgPower)
+                    .await
+                    .unwrap()
+            );
+        }
+
+        #[tokio::test]
+        async fn test_has_no_metric_data_when_no_activity_has_a_value() {
+            let service = service_with_metrics(vec![ActivityMetricsV2::new(HashMap::from([(
+                ActivityMetricV2::AvgPower,
+                None,
+            )]))]);
+
+            assert!(
+                !service
+                    .has_metric_data(&UserId::test_default(), &ActivityMetricV2::AvgPower)
+                    .await
+                    .unwrap()
+            );
+        }
+
+        #[tokio::test]
+        async fn test_has_no_metric_data_without_activities() {
+            let service = service_with_metrics(vec![]);
+
+            assert!(
+                !service
+                    .has_metric_data(&UserId::test_default(), &ActivityMetricV2::AvgPower)
+                    .await
+                    .unwrap()
+            );
+        }
+    }
+
+    mod test_activity_service_merge_activity_stream {
+        use crate::domain::models::activity::{
+            ActiveTime, ActivityMetricV2, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
+            TimeseriesTime, TimeseriesValue,
+        };
+        use crate::domain::ports::activity::{
+            MergeActivityStreamError, MergeActivityStreamRequest,
+        };
+
+        use super::*;
+
+        fn activity(
+            id: &str,
+            user: &str,
+            start: usize,
+            metric: Timeseries,
+        ) -> ActivityWithParsedData {
+            ActivityWithParsedData::new(
+                Activity::new_empty(
+                    ActivityId::from(id),
+                    UserId::from(user.to_string()),
+                    ActivityStartTime::from_timestamp(start).unwrap(),
+                    ActivityDuration::from(3.),
+                    Sport::Cycling,
+                ),
+                ActivityTimeseries::new(
+                    TimeseriesTime::new(vec![0, 1, 2]),
+                    TimeseriesActiveTime::new(vec![
+                        ActiveTime::Running(0),
+                        ActiveTime::Running(1),
+                        ActiveTime::Running(2),
+                    ]),
+                    vec![],
+                    vec![metric],
+                )
+                .unwrap(),
+                ActivityStatistics::default(),
+            )
+        }
+
+        fn repository(source_user: &'static str) -> MockActivityRepository {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity_with_parsed_data()
+                .returning(move |id| {
+                    Ok(Some(match id.to_string().as_str() {
+                        "ride" => activity(
+                            "ride",
+                            "user",
+                            1000,
+                            Timeseries::new(
+                                TimeseriesMetric::Speed,
+                                vec![Some(TimeseriesValue::Float(8.)); 3],
+                            ),
+                        ),
+                        _ => activity(
+                            "hr",
+                            source_user,
+                            1001,
+                            Timeseries::new(
+                                TimeseriesMetric::HeartRate,
+                                vec![
+                                    Some(TimeseriesValue::Int(120)),
+                                    Some(TimeseriesValue::Int(130)),
+                                    Some(TimeseriesValue::Int(140)),
+                                ],
+                            ),
+                        ),
+                    }))
+                });
+            activity_repository
+        }
+
+        #[tokio::test]
+        async fn test_merge_activity_stream() {
+            let service =
+                ActivityService::new(repository("user"), MockRawDataRepository::default());
+
+            let (merged, metrics) = service
+                .merge_activity_stream(MergeActivityStreamRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("ride"),
+                    ActivityId::from("hr"),
+                    TimeseriesMetric::HeartRate,
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(merged.id(), &ActivityId::from("ride"));
+            assert_eq!(
+                merged
+                    .timeseries()
+                    .metric_values(&TimeseriesMetric::HeartRate),
+                Some(vec![120., 130.])
+            );
+            assert_eq!(
+                metrics.get(&ActivityMetricV2::AvgHeartRate),
+                Some(&Some(125.))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_merge_activity_stream_source_owned_by_another_user() {
+            let service =
+                ActivityService::new(repository("other_user"), MockRawDataRepository::default());
+
+            let res = service
+                .merge_activity_stream(MergeActivityStreamRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("ride"),
+                    ActivityId::from("hr"),
+                    TimeseriesMetric::HeartRate,
+                ))
+                .await;
+
+            assert!(matches!(
+                res,
+                Err(MergeActivityStreamError::UserDoesNotOwnActivity(_, id)) if id == ActivityId::from("hr")
+            ));
+        }
+    }
+
+    mod test_activity_service_trim_activity {
+        use crate::domain::models::activity::{
+            ActiveTime, ActivityMetricV2, ActivityStatistic, Timeseries, TimeseriesActiveTime,
+            TimeseriesMetric, TimeseriesTime, TimeseriesValue, TrimByDistanceError,
+        };
+        use crate::domain::ports::activity::{TrimActivityError, TrimActivityRequest};
+
+        use super::*;
+
+        fn repository() -> MockActivityRepository {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity_with_parsed_data()
+                .returning(|_| {
+                    Ok(Some(ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("run"),
+                            UserId::from("user".to_string()),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(4.),
+                            Sport::Running,
+                        ),
+                        ActivityTimeseries::new(
+                            TimeseriesTime::new(vec![0, 1, 2, 3, 4]),
+                            TimeseriesActiveTime::new((0..5).map(ActiveTime::Running).collect()),
+                            vec![],
+                            vec![Timeseries::new(
+                                TimeseriesMetric::Distance,
+                                (0..5)
+                                    .map(|idx| Some(TimeseriesValue::Float(idx as f64 * 250.)))
+                                    .collect(),
+                            )],
+                        )
+                        .unwrap(),
+                        ActivityStatistics::new(HashMap::from([(
+                            ActivityStatistic::Distance,
+                            1000.,
+                        )])),
+                    )))
+                });
+            activity_repository
+        }
+
+        #[tokio::test]
+        async fn test_trim_activity() {
+            let service = ActivityService::new(repository(), MockRawDataRepository::default());
+
+            let (trimmed, metrics) = service
+                .trim_activity(TrimActivityRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("run"),
+                    250.,
+                    250.,
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(trimmed.id(), &ActivityId::from("run"));
+            assert_eq!(metrics.get(&ActivityMetricV2::Distance), Some(&Some(500.)));
+            assert_eq!(metrics.get(&ActivityMetricV2::Duration), Some(&Some(2.)));
+        }
+
+        #[tokio::test]
+        async fn test_trim_activity_invalid_window() {
+            let service = ActivityService::new(repository(), MockRawDataRepository::default());
+
+            let res = service
+                .trim_activity(TrimActivityRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("run"),
+                    500.,
+                    500.,
+                ))
+                .await;
+
+            assert!(matches!(
+                res,
+                Err(TrimActivityError::InvalidWindow(
+                    TrimByDistanceError::InvalidWindow(_, _, _)
+                ))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_trim_activity_of_another_user() {
+            let service = ActivityService::new(repository(), MockRawDataRepository::default());
+
+            let res = service
+                .trim_activity(TrimActivityRequest::new(
+                    UserId::from("other_user".to_string()),
+                    ActivityId::from("run"),
+                    0.,
+                    0.,
+                ))
+                .await;
+
+            assert!(matches!(
+                res,
+                Err(TrimActivityError::UserDoesNotOwnActivity(_, _))
+            ));
+        }
+    }
+
+    mod test_activity_service_reverse_activity {
+        use crate::domain::models::activity::{
+            ActiveTime, ActivityMetricV2, ActivityStatistic, ReverseTimeseriesError, Timeseries,
+            TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
+        };
+        use crate::domain::ports::activity::{ReverseActivityError, ReverseActivityRequest};
+
+        use super::*;
+
+        fn repository(metrics: Vec<Timeseries>) -> MockActivityRepository {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity_with_parsed_data()
+                .returning(move |_| {
+                    Ok(Some(ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("run"),
+                            UserId::from("user".to_string()),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(4.),
+                            Sport::Running,
+                        ),
+                        ActivityTimeseries::new(
+                            TimeseriesTime::new(vec![0, 1, 2, 3, 4]),
+                            TimeseriesActiveTime::new((0..5).map(ActiveTime::Running).collect()),
+                            vec![],
+                            metrics.clone(),
+                        )
+                        .unwrap(),
+                        ActivityStatistics::new(HashMap::from([(
+                            ActivityStatistic::Distance,
+                            1000.,
+                        )])),
+                    )))
+                });
+            activity_repository
+        }
+
+        fn distance() -> Timeseries {
+            Timeseries::new(
+                TimeseriesMetric::Distance,
+                (0..5)
+                    .map(|idx| Some(TimeseriesValue::Float(idx as f64 * 250.)))
+                    .collect(),
+            )
+        }
+
+        fn track() -> Vec<Timeseries> {
+            vec![
+                distance(),
+                Timeseries::new(
+                    TimeseriesMetric::Latitude,
+                    (0..5)
+                        .map(|idx| Some(TimeseriesValue::Float(45. + idx as f64 * 0.002)))
+                        .collect(),
+                ),
+                Timeseries::new(
+                    TimeseriesMetric::Longitude,
+                    vec![Some(TimeseriesValue::Float(5.)); 5],
+                ),
+            ]
+        }
+
+        #[tokio::test]
+        async fn test_reverse_activity() {
+            let service =
+                ActivityService::new(repository(track()), MockRawDataRepository::default());
+
+            let (reversed, metrics) = service
+                .reverse_activity(ReverseActivityRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("run"),
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(reversed.id(), &ActivityId::from("run"));
+            assert_eq!(
+                reversed
+                    .timeseries()
+                    .metric_values(&TimeseriesMetric::Latitude)
+                    .and_then(|latitudes| latitudes.first().copied()),
+                Some(45.008)
+            );
+            assert_eq!(metrics.get(&ActivityMetricV2::Distance), Some(&Some(1000.)));
+        }
+
+        #[tokio::test]
+        async fn test_reverse_activity_without_positions() {
+            let service = ActivityService::new(
+                repository(vec![distance()]),
+                MockRawDataRepository::default(),
+            );
+
+            let res = service
+                .reverse_activity(ReverseActivityRequest::new(
+                    UserId::from("user".to_string()),
+                    ActivityId::from("run"),
+                ))
+                .await;
+
+            assert!(matches!(
+                res,
+                Err(ReverseActivityError::InvalidData(
+                    ReverseTimeseriesError::NoPosition
+                ))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_reverse_activity_of_another_user() {
+            let service =
+                ActivityService::new(repository(track()), MockRawDataRepository::default());
+
+            let res = service
+                .reverse_activity(ReverseActivityRequest::new(
+                    UserId::from("other_user".to_string()),
+                    ActivityId::from("run"),
+                ))
+                .await;
+
+            assert!(matches!(
+                res,
+                Err(ReverseActivityError::UserDoesNotOwnActivity(_, _))
+            ));
+        }
+    }
+
+    mod test_activity_service_replace_raw_activity {
+        use crate::domain::models::activity::{ActivityMetricV2, ActivityRpe, ActivityStatistic};
+        use crate::domain::ports::activity::{
+            ReplaceRawActivityError, ReplaceRawActivityRequest, UpdateActivityMetricError,
+        };
+
+        use super::*;
+
+        fn existing_activity(user: &str) -> Activity {
+            Activity::new(
+                ActivityId::from("activity"),
+                UserId::from(user.to_string()),
+                Some(ActivityName::new("Morning ride".to_string())),
+                ActivityStartTime::from_timestamp(1000).unwrap(),
+                ActivityDuration::from(60.),
+                Sport::Cycling,
+                Some(ActivityRpe::Five),
+                None,
+                None,
+                None,
+                Default::default(),
+            )
+        }
+
+        fn replace_request() -> ReplaceRawActivityRequest {
+            ReplaceRawActivityRequest::new(
+                ActivityId::from("activity"),
+                CreateActivityRequest::new(
+                    UserId::from("user".to_string()),
+                    Sport::Running,
+                    ActivityStartTime::from_timestamp(2000).unwrap(),
+                    ActivityDuration::from(120.),
+                    ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, 1500.)])),
+                    ActivityTimeseries::default(),
+                    RawContent::new("tcx".to_string(), vec![4, 5, 6]),
+                ),
+            )
+        }
+
+        #[tokio::test]
+        async fn test_replace_raw_activity_refreshes_derived_data() {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity()
+                .returning(|_| Ok(Some(existing_activity("user"))));
+            activity_repository
+                .expect_replace_activity_data()
+                .times(1)
+                .withf(|activity| {
+                    activity.start_time() == &ActivityStartTime::from_timestamp(2000).unwrap()
+                        && activity.sport() == &Sport::Running
+                        && activity.name() == Some(&ActivityName::new("Morning ride".to_string()))
+                        && activity.rpe() == &Some(ActivityRpe::Five)
+                })
+                .returning(|_| Ok(()));
+            activity_repository
+                .expect_update_activity_metric()
+                .times(1)
+                .withf(|_, metric, value| {
+                    metric == &ActivityMetricV2::Distance && value == &Some(1500.)
+                })
+                .returning(|_, _, _| Ok(()));
+            activity_repository
+                .expect_update_activity_metric()
+                .times(DEFAULT_METRICS.len() - 1)
+                .returning(|_, _, _| Ok(()));
+
+            let mut raw_data_repository = MockRawDataRepository::new();
+            raw_data_repository
+                .expect_stage_raw_data()
+                .times(1)
+                .withf(|id, content| {
+                    id == &ActivityId::from("activity") && content.extension() == "tcx"
+                })
+                .returning(|id, content| {
+                    Ok(StagedRawData::new(
+                        id.clone(),
+                        content.extension().to_string(),
+                    ))
+                });
+            raw_data_repository
+                .expect_commit_raw_data()
+                .times(1)
+                .returning(|_| Ok(()));
+            raw_data_repository.expect_discard_raw_data().times(0);
+
+            let service = ActivityService::new(activity_repository, raw_data_repository);
+
+            let activity = service
+                .replace_raw_activity(replace_request())
+                .await
+                .unwrap();
+
+            assert_eq!(activity.id(), &ActivityId::from("activity"));
+            assert_eq!(activity.duration(), &ActivityDuration::from(120.));
+        }
+
+        #[tokio::test]
+        async fn test_replace_raw_activity_owned_by_another_user() {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity()
+                .returning(|_| Ok(Some(existing_activity("other_user"))));
+            activity_repository.expect_replace_activity_data().times(0);
+            let mut raw_data_repository = MockRawDataRepository::new();
+            raw_data_repository.expect_stage_raw_data().times(0);
+
+            let service = ActivityService::new(activity_repository, raw_data_repository);
+
+            let res = service.replace_raw_activity(replace_request()).await;
+
+            assert!(matches!(
+                res,
+                Err(ReplaceRawActivityError::UserDoesNotOwnActivity(_, _))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_replace_raw_activity_keeps_raw_data_when_update_fails() {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity()
+                .returning(|_| Ok(Some(existing_activity("user"))));
+            activity_repository
+                .expect_replace_activity_data()
+                .times(1)
+                .returning(|_| Err(SaveActivityError::Unknown(anyhow!("error"))));
+            activity_repository.expect_update_activity_metric().times(0);
+            let mut raw_data_repository = MockRawDataRepository::new();
+            raw_data_repository
+                .expect_stage_raw_data()
+                .times(1)
+                .returning(|id, content| {
+                    Ok(StagedRawData::new(
+                        id.clone(),
+                        content.extension().to_string(),
+                    ))
+                });
+            raw_data_repository.expect_commit_raw_data().times(0);
+            raw_data_repository
+                .expect_discard_raw_data()
+                .times(1)
+                .withf(|staged| staged.extension() == "tcx")
+                .returning(|_| Ok(()));
+
+            let service = ActivityService::new(activity_repository, raw_data_repository);
+
+            let res = service.replace_raw_activity(replace_request()).await;
+
+            assert!(matches!(res, Err(ReplaceRawActivityError::Unknown(_))));
+        }
+
+        #[tokio::test]
+        async fn test_replace_raw_activity_ignores_metric_update_errors() {
+            let mut activity_repository = MockActivityRepository::new();
+            activity_repository
+                .expect_get_activity()
+                .returning(|_| Ok(Some(existing_activity("user"))));
+            activity_repository
+                .expect_replace_activity_data()
+                .returning(|_| Ok(()));
+            activity_repository
+                .expect_update_activity_metric()
+                .times(DEFAULT_METRICS.len())
+                .returning(|_, _, _| Err(UpdateActivityMetricError::Unknown(anyhow!("error"))));
+            let mut raw_data_repository = MockRawDataRepository::new();
+            raw_data_repository
+                .expect_stage_raw_data()
+                .returning(|id, content| {
+                    Ok(StagedRawData::new(
+                        id.clone(),
+                        content.extension().to_string(),
+                    ))
+                });
+            raw_data_repository
+                .expect_commit_raw_data()
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let service = ActivityService::new(activity_repository, raw_data_repository);
+
+            let res = service.replace_raw_activity(replace_request()).await;
+
+            assert!(res.is_ok());
+        }
+    }
 }
@@ -119,6 +119,14 @@ pub mod tests_utils {
         }
     }
 
+    impl MockPreferencesService {
+        pub fn test_default() -> Self {
+            let mut mock = Self::new();
+            mock.expect_get_preference().returning(|_, _| Ok(None));
+            mock
+        }
+    }
+
     mock! {
         pub PreferencesRepository {}
 
@@ -21,14 +21,15 @@ use crate::domain::{
             CreateTrainingMetricError, CreateTrainingMetricRequest, CreateTrainingNoteError,
             CreateTrainingNoteRequest, CreateTrainingPeriodError, CreateTrainingPeriodRequest,
             DeleteTrainingMetricError, DeleteTrainingMetricRequest, DeleteTrainingNoteError,
-            DeleteTrainingPeriodError, DeleteTrainingPeriodRequest, GetTrainingMetricValuesError,
-            GetTrainingMetricValuesRequest, GetTrainingMetricsOrderingError, GetTrainingNoteError,
-            ITrainingService, SetTrainingMetricsOrderingError, TrainingRepository,
-            UpdateTrainingMetricError, UpdateTrainingMetricNameError,
-            UpdateTrainingMetricNameRequest, UpdateTrainingMetricRequest, UpdateTrainingNoteError,
-            UpdateTrainingPeriodDatesError, UpdateTrainingPeriodDatesRequest,
-            UpdateTrainingPeriodNameError, UpdateTrainingPeriodNameRequest,
-            UpdateTrainingPeriodNoteError, UpdateTrainingPeriodNoteRequest,
+            DeleteTrainingPeriodError, DeleteTrainingPeriodRequest, GetTrainingMetricError,
+            GetTrainingMetricValuesError, GetTrainingMetricValuesRequest,
+            GetTrainingMetricsOrderingError, GetTrainingNoteError, ITrainingService,
+            SetTrainingMetricsOrderingError, TrainingRepository, UpdateTrainingMetricError,
+            UpdateTrainingMetricNameError, UpdateTrainingMetricNameRequest,
+            UpdateTrainingMetricRequest, UpdateTrainingNoteError, UpdateTrainingPeriodDatesError,
+            UpdateTrainingPeriodDatesRequest, UpdateTrainingPeriodNameError,
+            UpdateTrainingPeriodNameRequest, UpdateTrainingPeriodNoteError,
+            UpdateTrainingPeriodNoteRequest,
         },
     },
 };
@@ -146,6 +147,14 @@ where
             .map_err(UpdateTrainingMetricError::SaveMetricError)
     }
 
+    async fn get_training_metric(
+        &self,
+        user: &UserId,
+        metric_id: &TrainingMetricId,
+    ) -> Result<Option<TrainingMetric>, GetTrainingMetricError> {
+        self.training_repository.get_metric(user, metric_id).await
+    }
+
     async fn copy_training_metric(
         &self,
         req: CopyTrainingMetricRequest,
@@ -441,6 +450,36 @@ where
         Ok(note_id)
     }
 
+    async fn create_training_notes(
+        &self,
+        reqs: Vec<CreateTrainingNoteRequest>,
+    ) -> Result<Vec<TrainingNoteId>, CreateTrainingNoteError> {
+        use crate::domain::models::training::{TrainingNote, TrainingNoteId};
+
+        let created_at = chrono::Utc::now().into();
+        let notes: Vec<TrainingNote> = reqs
+            .into_iter()
+            .map(|req| {
+                TrainingNote::new(
+                    TrainingNoteId::new(),
+                    req.user().clone(),
+                    req.title().clone(),
+                    req.content().clone(),
+                    req.date().clone(),
+                    created_at,
+                )
+            })
+            .collect();
+        let note_ids = notes.iter().map(|note| note.id().clone()).collect();
+
+        self.training_repository
+            .save_training_notes(notes)
+            .await
+            .map_err(|err| CreateTrainingNoteError::Unknown(err.into()))?;
+
+        Ok(note_ids)
+    }
+
     async fn get_training_note(
         &self,
         user: &UserId,
@@ -479,7 +518,7 @@ where
         content: TrainingNoteContent,
         date: TrainingNoteDate,
     ) -> Result<(), UpdateTrainingNoteError> {
-        // Verify the note exists and belongs to the user
+        // Notes of other users are reported as missing, not to disclose their existence
         let note = self
             .training_repository
             .get_training_note(user, note_id)
@@ -493,9 +532,7 @@ where
                     .await?;
                 Ok(())
             }
-            _ => Err(UpdateTrainingNoteError::Unknown(anyhow::anyhow!(
-                "Training note not found or unauthorized"
-            ))),
+            _ => Err(UpdateTrainingNoteError::NoteDoesNotExist(note_id.clone())),
         }
     }
 
@@ -504,7 +541,7 @@ where
         user: &UserId,
         note_id: &TrainingNoteId,
     ) -> Result<(), DeleteTrainingNoteError> {
-        // Verify the note exists and belongs to the user
+        // Notes of other users are reported as missing, not to disclose their existence
         let note = self
             .training_repository
             .get_training_note(user, note_id)
@@ -518,9 +555,7 @@ where
                     .await?;
                 Ok(())
             }
-            _ => Err(DeleteTrainingNoteError::Unknown(anyhow::anyhow!(
-                "Training note not found or unauthorized"
-            ))),
+            _ => Err(DeleteTrainingNoteError::NoteDoesNotExist(note_id.clone())),
         }
     }
 
@@ -681,6 +716,12 @@ pub mod test_utils {
                 req: CopyTrainingMetricRequest,
             ) -> Result<(), CopyTrainingMetricError>;
 
+            async fn get_training_metric(
+                &self,
+                user: &UserId,
+                metric_id: &TrainingMetricId,
+            ) -> Result<Option<TrainingMetric>, GetTrainingMetricError>;
+
             async fn delete_metric(
                 &self,
                 req: DeleteTrainingMetricRequest,
@@ -750,6 +791,11 @@ pub mod test_utils {
                 req: CreateTrainingNoteRequest,
             ) -> Result<TrainingNoteId, CreateTrainingNoteError>;
 
+            async fn create_training_notes(
+                &self,
+                reqs: Vec<CreateTrainingNoteRequest>,
+            ) -> Result<Vec<TrainingNoteId>, CreateTrainingNoteError>;
+
             async fn get_training_note(
                 &self,
                 user: &UserId,
@@ -922,6 +968,11 @@ pub mod test_utils {
                 note: TrainingNote,
             ) -> Result<(), SaveTrainingNoteError>;
 
+            async fn save_training_notes(
+                &self,
+                notes: Vec<TrainingNote>,
+            ) -> Result<(), SaveTrainingNoteError>;
+
             async fn get_training_note(
                 &self,
                 user: &UserId,
@@ -968,7 +1019,10 @@ pub mod test_utils {
 #[cfg(test)]
 mod tests_training_metrics_service {
 
-    use std::collections::HashMap;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
 
     use anyhow::anyhow;
 
@@ -979,8 +1033,8 @@ mod tests_training_metrics_service {
     };
 
     use crate::domain::models::training::{
-        SportFilter, TrainingMetricBin, TrainingMetricSummary, TrainingMetricWindow,
-        TrainingPeriod, TrainingPeriodSports,
+        SportFilter, TrainingMetricBin, TrainingMetricSummary, TrainingMetricValue,
+        TrainingMetricWindow, TrainingPeriod, TrainingPeriodSports,
     };
     use crate::domain::ports::DateRange;
     use crate::domain::services::activity::test_utils::MockActivityService;
@@ -1923,7 +1977,7 @@ mod tests_training_metrics_service {
             .unwrap();
 
         assert_eq!(
-            res.get(0)
+            res.first()
                 .unwrap()
                 .1
                 .get(&TrainingMetricBin::from_granule("2025-09-24")),
@@ -1982,6 +2036,28 @@ mod tests_training_metrics_service {
         )
     }
 
+    #[tokio::test]
+    async fn test_training_service_get_training_metric() {
+        let metric = build_metric();
+        let metric_id = metric.id().clone();
+        let expected = metric.clone();
+        let mut repository = MockTrainingRepository::new();
+        repository
+            .expect_get_metric()
+            .times(1)
+            .withf(move |user, id| user == &UserId::from("user") && id == &metric_id)
+            .returning(move |_, _| Ok(Some(metric.clone())));
+
+        let service = TrainingService::new(repository, MockActivityService::default());
+
+        let res = service
+            .get_training_metric(&UserId::from("user"), expected.id())
+            .await
+            .unwrap();
+
+        assert_eq!(res, Some(expected));
+    }
+
     #[tokio::test]
     async fn test_training_service_delete_metric() {
         let mut repository = MockTrainingRepository::new();
@@ -2192,6 +2268,120 @@ mod tests_training_metrics_service {
             unreachable!("Should have returned Unknown")
         };
     }
+
+    #[tokio::test]
+    async fn test_update_training_metric_values_use_patched_definition() {
+        let stored = Arc::new(Mutex::new(TrainingMetric::new(
+            TrainingMetricId::from("test"),
+            Some(TrainingMetricName::from("Distance")),
+            TrainingMetricScope::Global,
+            TrainingMetricDefinition::new(
+                UserId::test_default(),
+                ActivityMetricV2::Distance,
+                Some(TrainingMetricWindow::new(
+                    TrainingMetricGranularity::Daily,
+                    TrainingMetricAggregate::Sum,
+                    TrainingMetricGroupBy::none(),
+                )),
+                TrainingMetricFilters::empty(),
+                TrainingMetricSummary::empty(),
+            ),
+        )));
+        let mut repository = MockTrainingRepository::new();
+        let get_stored = stored.clone();
+        repository
+            .expect_get_metric()
+            .returning(move |_, _| Ok(Some(get_stored.lock().unwrap().clone())));
+        let save_stored = stored.clone();
+        repository.expect_save_metric().returning(move |metric| {
+            *save_stored.lock().unwrap() = metric;
+            Ok(())
+        });
+
+        let mut activity_service = MockActivityService::default();
+        activity_service
+            .expect_list_activities_with_metrics()
+            .returning(|_, _, _| {
+                let activity = |id: &str, day: u32, sport: Sport, distance: f64| {
+                    (
+                        Activity::new_empty(
+                            ActivityId::from(id),
+                            UserId::test_default(),
+                            ActivityStartTime::new(
+                                NaiveDateTime::new(
+                                    NaiveDate::from_ymd_opt(2025, 9, day).unwrap(),
+                                    NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                                )
+                                .and_utc()
+                                .fixed_offset(),
+                            ),
+                            ActivityDuration::default(),
+                            sport,
+                        ),
+                        ActivityMetricsV2::new(HashMap::from([(
+                            ActivityMetricV2::Distance,
+                            Some(distance),
+                        )])),
+                    )
+                };
+                Ok(vec![
+                    activity("monday", 22, Sport::Running, 10.),
+                    activity("tuesday", 23, Sport::Cycling, 30.),
+                    activity("wednesday", 24, Sport::Running, 20.),
+                ])
+            });
+
+        let service = TrainingService::new(repository, activity_service);
+        let date_range = DateRange::new(
+            NaiveDate::from_ymd_opt(2025, 9, 22).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 9, 28).unwrap(),
+        );
+        let request = || {
+            GetTrainingMetricValuesRequest::ByTrainingMetricId(
+                UserId::test_default(),
+                TrainingMetricId::from("test"),
+            )
+        };
+
+        let values = service
+            .get_training_metric_values(request(), &date_range)
+            .await
+            .unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(
+            values.get(&TrainingMetricBin::from_granule("2025-09-23")),
+            Some(&TrainingMetricValue::Sum(30.))
+        );
+
+        service
+            .update_training_metric(UpdateTrainingMetricRequest::new(
+                UserId::test_default(),
+                TrainingMetricId::from("test"),
+                TrainingMetricName::from("Weekly running distance"),
+                ActivityMetricV2::Distance,
+                Some(TrainingMetricWindow::new(
+                    TrainingMetricGranularity::Weekly,
+                    TrainingMetricAggregate::Sum,
+                    TrainingMetricGroupBy::none(),
+                )),
+                TrainingMetricFilters::empty()
+                    .merge_default_sports(&Some(vec![SportFilter::Sport(Sport::Running)])),
+                TrainingMetricSummary::empty(),
+            ))
+            .await
+            .unwrap();
+
+        // Values now follow the weekly window and only include running activities
+        let values = service
+            .get_training_metric_values(request(), &date_range)
+            .await
+            .unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(
+            values.get(&TrainingMetricBin::from_granule("2025-09-22")),
+            Some(&TrainingMetricValue::Sum(30.))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -3356,6 +3546,110 @@ mod test_training_service_training_note {
         }
     }
 
+    #[tokio::test]
+    async fn test_create_training_notes_saves_all_notes_at_once() {
+        let user_id = UserId::from("user1");
+
+        let mut training_repository = MockTrainingRepository::new();
+        training_repository
+            .expect_save_training_notes()
+            .withf(|notes| {
+                notes.len() == 2
+                    && notes[0].content() == &TrainingNoteContent::from("First note")
+                    && notes[1].content() == &TrainingNoteContent::from("Second note")
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        training_repository.expect_save_training_note().never();
+
+        let activity_service = MockActivityService::default();
+        let service = TrainingService::new(training_repository, activity_service);
+
+        let reqs = ["First note", "Second note"]
+            .into_iter()
+            .map(|content| {
+                CreateTrainingNoteRequest::new(
+                    user_id.clone(),
+                    None,
+                    TrainingNoteContent::from(content),
+                    TrainingNoteDate::today(),
+                )
+            })
+            .collect();
+        let ids = service.create_training_notes(reqs).await.unwrap();
+
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn test_create_training_notes_repository_error() {
+        let mut training_repository = MockTrainingRepository::new();
+        training_repository
+            .expect_save_training_notes()
+            .times(1)
+            .returning(|_| Err(SaveTrainingNoteError::Unknown(anyhow!("database error"))));
+
+        let activity_service = MockActivityService::default();
+        let service = TrainingService::new(training_repository, activity_service);
+
+        let reqs = vec![CreateTrainingNoteRequest::new(
+            UserId::from("user1"),
+            None,
+            TrainingNoteContent::from("Note that fails to save"),
+            TrainingNoteDate::today(),
+        )];
+        let result = service.create_training_notes(reqs).await;
+
+        assert!(matches!(result, Err(CreateTrainingNoteError::Unknown(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_training_note_of_another_user() {
+        let note_id = TrainingNoteId::from("note1");
+
+        let mut training_repository = MockTrainingRepository::new();
+        training_repository
+            .expect_get_training_note()
+            .times(2)
+            .returning(|_, note_id| {
+                Ok(Some(TrainingNote::new(
+                    note_id.clone(),
+                    UserId::from("other_user"),
+                    None,
+                    TrainingNoteContent::from("Not yours"),
+                    TrainingNoteDate::today(),
+                    chrono::Utc::now().into(),
+                )))
+            });
+        training_repository.expect_update_training_note().never();
+        training_repository.expect_delete_training_note().never();
+
+        let activity_service = MockActivityService::default();
+        let service = TrainingService::new(training_repository, activity_service);
+        let user = UserId::from("user1");
+
+        let updated = service
+            .update_training_note(
+                &user,
+                &note_id,
+                None,
+                TrainingNoteContent::from("Mine now"),
+                TrainingNoteDate::today(),
+            )
+            .await;
+        let deleted = service.delete_training_note(&user, &note_id).await;
+
+        assert!(matches!(
+            updated,
+            Err(UpdateTrainingNoteError::NoteDoesNotExist(id)) if id == note_id
+        ));
+        assert!(matches!(
+            deleted,
+            Err(DeleteTrainingNoteError::NoteDoesNotExist(id)) if id == note_id
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_training_period_notes_ok() {
         let user_id = UserId::from("user1");
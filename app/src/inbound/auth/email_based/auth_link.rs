@@ -1,7 +1,6 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use chrono::{TimeDelta, Utc};
-use derive_more::Constructor;
 use thiserror::Error;
 use tokio::sync::Mutex;
 
@@ -10,7 +9,16 @@ use crate::inbound::auth::email_based::{
     HashedAuthLink, HashedAuthToken, IAuthLinkService,
 };
 
-#[derive(Debug, Clone, Constructor)]
+/// Default validity duration of a generated auth link.
+pub const DEFAULT_AUTH_LINK_TTL: TimeDelta = TimeDelta::minutes(15);
+
+/// Default number of times sending an auth link email is retried after a failure.
+pub const DEFAULT_MAIL_RETRIES: usize = 2;
+
+/// Default delay before the first retry of sending an auth link email.
+pub const DEFAULT_MAIL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone)]
 pub struct AuthLinkService<SR, MP>
 where
     SR: AuthLinkRepository,
@@ -18,6 +26,69 @@ where
 {
     auth_link_repository: Arc<Mutex<SR>>,
     mail_provider: Arc<MP>,
+    link_ttl: TimeDelta,
+    mail_retries: usize,
+    mail_retry_delay: Duration,
+}
+
+impl<SR, MP> AuthLinkService<SR, MP>
+where
+    SR: AuthLinkRepository,
+    MP: MailProvider,
+{
+    pub fn new(auth_link_repository: Arc<Mutex<SR>>, mail_provider: Arc<MP>) -> Self {
+        Self {
+            auth_link_repository,
+            mail_provider,
+            link_ttl: DEFAULT_AUTH_LINK_TTL,
+            mail_retries: DEFAULT_MAIL_RETRIES,
+            mail_retry_delay: DEFAULT_MAIL_RETRY_DELAY,
+        }
+    }
+
+    /// Set how long generated auth links stay valid.
+    pub fn with_link_ttl(self, link_ttl: TimeDelta) -> Self {
+        Self { link_ttl, ..self }
+    }
+
+    /// Set how many times sending an auth link email is retried after a failure, and the delay
+    /// before the first retry, doubled for each following one.
+    pub fn with_mail_retries(self, mail_retries: usize, mail_retry_delay: Duration) -> Self {
+        Self {
+            mail_retries,
+            mail_retry_delay,
+            ..self
+        }
+    }
+
+    /// Send the auth link email, retrying with an exponential backoff on failures.
+    async fn send_auth_link_email(
+        &self,
+        email: &EmailAddress,
+        auth_link: &AuthLink,
+    ) -> Result<(), ()> {
+        let mut delay = self.mail_retry_delay;
+        for retry in 1..=self.mail_retries {
+            if self
+                .mail_provider
+                .send_auth_link_email(email, auth_link)
+                .await
+                .is_ok()
+            {
+                return Ok(());
+            }
+            tracing::warn!(
+                "Failed to send auth link email, retry {retry}/{} in {delay:?}",
+                self.mail_retries
+            );
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2);
+        }
+
+        self.mail_provider
+            .send_auth_link_email(email, auth_link)
+            .await
+    }
 }
 
 impl<SR, MP> IAuthLinkService for AuthLinkService<SR, MP>
@@ -30,24 +101,28 @@ where
         let auth_link = AuthLink::new(
             req.user().clone(),
             auth_token.clone(),
-            Utc::now() + TimeDelta::minutes(15),
+            Utc::now() + self.link_ttl,
         );
         let Some(hashed_auth_link) = auth_link.as_hash() else {
             return GenerateAuthLinkResult::Retry;
         };
 
-        let repository = self.auth_link_repository.lock().await;
-
-        let Ok(()) = repository.store_auth_link(&hashed_auth_link).await else {
-            return GenerateAuthLinkResult::Retry;
-        };
-
         let Ok(()) = self
-            .mail_provider
-            .send_auth_link_email(req.email(), &auth_link)
+            .auth_link_repository
+            .lock()
+            .await
+            .store_auth_link(&hashed_auth_link)
             .await
         else {
-            let _ = repository
+            return GenerateAuthLinkResult::Retry;
+        };
+
+        // The repository is not locked while sending, as retries can take a while
+        let Ok(()) = self.send_auth_link_email(req.email(), &auth_link).await else {
+            let _ = self
+                .auth_link_repository
+                .lock()
+                .await
                 .delete_auth_link_by_hash(hashed_auth_link.hash())
                 .await;
             return GenerateAuthLinkResult::Retry;
@@ -204,9 +279,11 @@ mod test_auth_link_service_generate_auth_link {
         let mut email_provider = MockMailProvider::new();
         email_provider
             .expect_send_auth_link_email()
+            .times(3)
             .returning(|_, _| Err(()));
         let service =
-            AuthLinkService::new(Arc::new(Mutex::new(repository)), Arc::new(email_provider));
+            AuthLinkService::new(Arc::new(Mutex::new(repository)), Arc::new(email_provider))
+                .with_mail_retries(2, Duration::from_millis(1));
 
         let req = GenerateAuthLinkRequest::new(
             UserId::test_default(),
@@ -253,6 +330,81 @@ mod test_auth_link_service_generate_auth_link {
             unreachable!("Should have return a GenerateAuthLinkResult::Success")
         };
     }
+
+    #[tokio::test]
+    async fn test_ok_retry_sending_email_after_failure() {
+        let mut repository = MockSessionRepository::new();
+        repository
+            .expect_store_auth_link()
+            .times(1)
+            .returning(|_| Ok(()));
+        repository.expect_delete_auth_link_by_hash().times(0);
+
+        let mut email_provider = MockMailProvider::new();
+        let mut sequence = mockall::Sequence::new();
+        email_provider
+            .expect_send_auth_link_email()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Err(()));
+        email_provider
+            .expect_send_auth_link_email()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(()));
+
+        let service =
+            AuthLinkService::new(Arc::new(Mutex::new(repository)), Arc::new(email_provider))
+                .with_mail_retries(2, Duration::from_millis(1));
+
+        let req = GenerateAuthLinkRequest::new(
+            UserId::test_default(),
+            EmailAddress::try_from("test@email.test").unwrap(),
+        );
+
+        let res = service.generate_auth_link(req).await;
+
+        let GenerateAuthLinkResult::Success = res else {
+            unreachable!("Should have return a GenerateAuthLinkResult::Success")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_generated_link_expires_after_configured_ttl() {
+        let mut repository = MockSessionRepository::new();
+        let before = Utc::now();
+        repository
+            .expect_store_auth_link()
+            .times(1)
+            .withf(move |link| {
+                let expire_at = *link.expire_at();
+                expire_at >= before + TimeDelta::minutes(2)
+                    && expire_at <= Utc::now() + TimeDelta::minutes(2)
+            })
+            .returning(|_| Ok(()));
+
+        let mut email_provider = MockMailProvider::new();
+        email_provider
+            .expect_send_auth_link_email()
+            .times(1)
+            .withf(move |_, auth_link| *auth_link.expire_at() <= Utc::now() + TimeDelta::minutes(2))
+            .returning(|_, _| Ok(()));
+
+        let service =
+            AuthLinkService::new(Arc::new(Mutex::new(repository)), Arc::new(email_provider))
+                .with_link_ttl(TimeDelta::minutes(2));
+
+        let req = GenerateAuthLinkRequest::new(
+            UserId::test_default(),
+            EmailAddress::try_from("test@email.test").unwrap(),
+        );
+
+        let res = service.generate_auth_link(req).await;
+
+        let GenerateAuthLinkResult::Success = res else {
+            unreachable!("Should have return a GenerateAuthLinkResult::Success")
+        };
+    }
 }
 
 #[cfg(test)]
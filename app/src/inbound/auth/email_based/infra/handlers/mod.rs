@@ -35,12 +35,13 @@ where
 pub fn email_based_login_routes<US: IUserService, S>(
     mut base_router: Router<S>,
     user_service: US,
+    cookie_config: CookieConfig,
 ) -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
     let auth_state = AuthAppState {
-        cookie_config: Arc::new(CookieConfig::default()),
+        cookie_config: Arc::new(cookie_config),
         user_service: Arc::new(user_service),
     };
 
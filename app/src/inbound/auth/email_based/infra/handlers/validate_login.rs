@@ -28,3 +28,101 @@ pub async fn validate_login<UR: IUserService>(
         Err(()) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, routing::post};
+    use axum_test::TestServer;
+    use chrono::{TimeDelta, Utc};
+    use cookie::SameSite;
+
+    use crate::inbound::{
+        auth::email_based::{
+            GenerateSessionTokenResult, SessionToken, test_utils::MockUserService,
+        },
+        http::CookieConfig,
+    };
+
+    use super::*;
+
+    fn build_test_server(user_service: MockUserService, cookie_config: CookieConfig) -> TestServer {
+        let state = AuthAppState {
+            user_service: Arc::new(user_service),
+            cookie_config: Arc::new(cookie_config),
+        };
+
+        let app = Router::new()
+            .route(
+                "/login/validate/{auth_token}",
+                post(validate_login::<MockUserService>),
+            )
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    fn successful_validation() -> MockUserService {
+        let mut user_service = MockUserService::new();
+        user_service.expect_validate_auth_link().returning(|_| {
+            Ok(AuthLinkValidationResult::Success(
+                GenerateSessionTokenResult::new(
+                    SessionToken::from("session"),
+                    Utc::now() + TimeDelta::days(30),
+                ),
+            ))
+        });
+        user_service
+    }
+
+    #[tokio::test]
+    async fn test_validate_login_sets_cookie_from_default_config() {
+        let server = build_test_server(successful_validation(), CookieConfig::default());
+
+        let response = server.post("/login/validate/token").await;
+
+        response.assert_status_ok();
+        let cookie = response.cookie("session_token");
+        assert_eq!(cookie.value(), "session");
+        assert_eq!(cookie.secure(), Some(true));
+        assert_eq!(cookie.http_only(), Some(true));
+        assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+        assert_eq!(cookie.domain(), None);
+    }
+
+    #[tokio::test]
+    async fn test_validate_login_sets_cookie_from_custom_config() {
+        let server = build_test_server(
+            successful_validation(),
+            CookieConfig {
+                secure: false,
+                same_site: SameSite::Lax,
+                http_only: false,
+                domain: Some("example.com".to_string()),
+            },
+        );
+
+        let response = server.post("/login/validate/token").await;
+
+        response.assert_status_ok();
+        let cookie = response.cookie("session_token");
+        assert_eq!(cookie.secure(), None);
+        assert_eq!(cookie.http_only(), None);
+        assert_eq!(cookie.same_site(), Some(SameSite::Lax));
+        assert_eq!(cookie.domain(), Some("example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_login_invalid_link() {
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_validate_auth_link()
+            .returning(|_| Ok(AuthLinkValidationResult::Invalid));
+        let server = build_test_server(user_service, CookieConfig::default());
+
+        let response = server.post("/login/validate/token").await;
+
+        response.assert_status(StatusCode::UNAUTHORIZED);
+        assert!(response.maybe_cookie("session_token").is_none());
+    }
+}
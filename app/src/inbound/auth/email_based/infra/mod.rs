@@ -1,3 +1,5 @@
 pub mod handlers;
 pub mod mailer;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod sqlite;
@@ -0,0 +1,123 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::models::UserId,
+    inbound::auth::email_based::{EmailAddress, user::UserRepository},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresUserRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserRepository {
+    pub async fn new(url: &str) -> Result<Self, sqlx::Error> {
+        let pool = PgPool::connect(url).await?;
+
+        // Run migrations
+        sqlx::migrate!("migrations/postgres/users")
+            .run(&pool)
+            .await?;
+
+        Ok(Self { pool })
+    }
+}
+
+impl UserRepository for PostgresUserRepository {
+    async fn store_user_with_mail(&self, user: &UserId, email: &EmailAddress) -> Result<(), ()> {
+        match sqlx::query("INSERT INTO t_users (email, user_id) VALUES ($1, $2);")
+            .bind(email.to_string())
+            .bind(user.to_string())
+            .execute(&self.pool)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::warn!("Unable to insert user into database");
+                tracing::warn!("{}", err);
+                Err(())
+            }
+        }
+    }
+
+    async fn get_user_by_email(&self, email: &EmailAddress) -> Result<Option<UserId>, ()> {
+        match sqlx::query_scalar::<_, String>("SELECT user_id FROM t_users WHERE email = $1")
+            .bind(email.to_string())
+            .fetch_optional(&self.pool)
+            .await
+        {
+            Ok(user) => Ok(user.map(UserId::from)),
+            Err(err) => {
+                tracing::warn!("Cannot fetch users from database");
+                tracing::warn!("{}", err);
+                Err(())
+            }
+        }
+    }
+}
+
+/// Run against a live database with
+/// `TEST_DATABASE_URL=postgres://... cargo test --features postgres -- --ignored`.
+#[cfg(test)]
+mod test_postgres_user_repository {
+    use super::*;
+
+    async fn repository() -> PostgresUserRepository {
+        let url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL should be set");
+        PostgresUserRepository::new(&url)
+            .await
+            .expect("repo should init")
+    }
+
+    fn unique_email() -> EmailAddress {
+        EmailAddress::try_from(format!("{}@mail.test", uuid::Uuid::new_v4()).as_str()).unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_store_and_get_user() {
+        let repository = repository().await;
+        let email = unique_email();
+
+        repository
+            .store_user_with_mail(&UserId::test_default(), &email)
+            .await
+            .expect("should have store user with email");
+
+        assert_eq!(
+            repository.get_user_by_email(&email).await.unwrap(),
+            Some(UserId::test_default())
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_store_user_with_email_reject_if_emails_exists() {
+        let repository = repository().await;
+        let email = unique_email();
+
+        repository
+            .store_user_with_mail(&UserId::test_default(), &email)
+            .await
+            .unwrap();
+
+        repository
+            .store_user_with_mail(&UserId::from("another_user".to_string()), &email)
+            .await
+            .expect_err("Should have rejected duplicated email");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_get_user_does_not_exist() {
+        let repository = repository().await;
+
+        assert!(
+            repository
+                .get_user_by_email(&unique_email())
+                .await
+                .expect("Should not have err")
+                .is_none()
+        );
+    }
+}
@@ -1,24 +1,32 @@
 use axum::{Router, extract::State, response::IntoResponse, routing::get};
 
+use crate::config::TrustedProxy;
 use crate::inbound::auth::{
     AuthStrategy,
     email_based::{IUserService, infra::handlers::email_based_login_routes},
     no_auth::no_auth_login_routes,
     single_password::single_password_login_routes,
 };
+use crate::inbound::http::CookieConfig;
 
 pub fn add_auth_router<S, US: IUserService>(
     strategy: AuthStrategy,
     base_router: Router<S>,
     user_service: US,
+    trusted_proxy: TrustedProxy,
+    cookie_config: CookieConfig,
 ) -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
     let router = match &strategy {
         AuthStrategy::NoAuth => no_auth_login_routes(base_router),
-        AuthStrategy::SinglePassword(pwd) => single_password_login_routes(base_router, pwd),
-        AuthStrategy::EmailBased => email_based_login_routes(base_router, user_service),
+        AuthStrategy::SinglePassword(pwd) => {
+            single_password_login_routes(base_router, pwd, trusted_proxy, cookie_config)
+        }
+        AuthStrategy::EmailBased => {
+            email_based_login_routes(base_router, user_service, cookie_config)
+        }
     };
 
     let info_router = Router::new()
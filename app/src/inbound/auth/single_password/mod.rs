@@ -16,6 +16,7 @@ use serde::Deserialize;
 use sha2::Sha256;
 
 use crate::{
+    config::TrustedProxy,
     domain::models::UserId,
     inbound::{
         auth::SinglePassword,
@@ -72,6 +73,7 @@ fn build_cookie<'a>(
         .map_err(|_| format!("Cannot build datetime offset form expiry {expiry:?}"))?;
     let mut builder = Cookie::build(("token", const_hex::encode(results)))
         .expires(expire_at)
+        .secure(cookie_config.secure)
         .http_only(cookie_config.http_only)
         .same_site(cookie_config.same_site)
         .path("/");
@@ -92,13 +94,15 @@ fn verify_cookie(cookie: &Cookie<'_>, pwd: &SinglePassword) -> Option<()> {
 pub fn single_password_login_routes<S>(
     mut base_router: Router<S>,
     password: &SinglePassword,
+    trusted_proxy: TrustedProxy,
+    cookie_config: CookieConfig,
 ) -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
     let state = SinglePasswordAuthState {
         password: password.clone(),
-        cookie_config: CookieConfig::default(),
+        cookie_config,
     };
 
     base_router = base_router.route_layer(axum::middleware::from_fn_with_state(
@@ -108,11 +112,10 @@ where
 
     let login_router = Router::new()
         .route("/login", post(login_user))
-        .route_layer(IpRateLimitLayer::new(
-            RateLimitStore::new(),
-            100,
-            Duration::from_secs(60),
-        ))
+        .route_layer(
+            IpRateLimitLayer::new(RateLimitStore::new(), 100, Duration::from_secs(60))
+                .with_trusted_proxy(trusted_proxy),
+        )
         .with_state(state);
 
     base_router.nest("/api", login_router)
@@ -165,6 +168,8 @@ mod tests {
             let app = single_password_login_routes(
                 Router::new().route("/", get(protected_route)),
                 password,
+                TrustedProxy::None,
+                CookieConfig::default(),
             );
 
             let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
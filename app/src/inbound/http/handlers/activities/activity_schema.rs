@@ -4,8 +4,9 @@ use chrono::{DateTime, FixedOffset};
 use serde::Serialize;
 
 use crate::domain::models::activity::{
-    Activity, ActivityMetricV2, ActivityMetricsV2, ActivityNutrition, ActivityTimeseries,
-    ActivityWithParsedData, Lap, Timeseries, TimeseriesMetric, TimeseriesValue, ToUnit, Unit,
+    Activity, ActivityDuration, ActivityMetricV2, ActivityMetricsV2, ActivityNutrition,
+    ActivityTimeseries, ActivityWithParsedData, Lap, SessionDetails, SessionSummary, SwimLength,
+    Timeseries, TimeseriesMetric, TimeseriesValue, ToUnit, Unit,
 };
 
 // =============================================================================
@@ -37,6 +38,8 @@ pub struct PublicActivityTimeseries {
     pub active_time: Vec<Option<usize>>,
     pub metrics: HashMap<String, PublicTimeseries>,
     pub laps: Vec<PublicLap>,
+    /// Active pool lengths, only for pool swims
+    pub lengths: Vec<PublicSwimLength>,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -51,6 +54,15 @@ pub struct PublicLap {
     pub end: usize,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PublicSwimLength {
+    pub start: usize,
+    pub end: usize,
+    pub stroke: Option<String>,
+    pub total_strokes: Option<usize>,
+    pub swolf: Option<usize>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PublicTimeseriesValue {
@@ -69,6 +81,17 @@ impl Mul<f64> for PublicTimeseriesValue {
     }
 }
 
+impl PublicTimeseriesValue {
+    /// Non-finite floats are not valid JSON, e.g. the NaN an invalid .FIT float field ends up as
+    /// once scaled. They are mapped to `None` so that they are serialized as `null`.
+    pub fn finite(self) -> Option<Self> {
+        match self {
+            Self::Float(val) if !val.is_finite() => None,
+            val => Some(val),
+        }
+    }
+}
+
 impl From<&TimeseriesValue> for PublicTimeseriesValue {
     fn from(value: &TimeseriesValue) -> Self {
         match value {
@@ -90,6 +113,19 @@ impl From<&ActivityTimeseries> for PublicActivityTimeseries {
                 .collect(),
             metrics: extract_and_convert_metrics(value.metrics()),
             laps: value.laps().iter().map(PublicLap::from).collect(),
+            lengths: value.lengths().iter().map(PublicSwimLength::from).collect(),
+        }
+    }
+}
+
+impl From<&SwimLength> for PublicSwimLength {
+    fn from(length: &SwimLength) -> Self {
+        Self {
+            start: length.start(),
+            end: length.end(),
+            stroke: length.stroke().map(|stroke| stroke.to_string()),
+            total_strokes: length.total_strokes(),
+            swolf: length.swolf(),
         }
     }
 }
@@ -115,6 +151,7 @@ fn extract_and_convert_metrics(metrics: &[Timeseries]) -> HashMap<String, Public
                         val.as_ref()
                             .map(PublicTimeseriesValue::from)
                             .map(|val| val * 3.6)
+                            .and_then(PublicTimeseriesValue::finite)
                     })
                     .collect(),
             ),
@@ -127,6 +164,7 @@ fn extract_and_convert_metrics(metrics: &[Timeseries]) -> HashMap<String, Public
                         val.as_ref()
                             .map(PublicTimeseriesValue::from)
                             .map(|val| val * 0.001)
+                            .and_then(PublicTimeseriesValue::finite)
                     })
                     .collect(),
             ),
@@ -135,7 +173,11 @@ fn extract_and_convert_metrics(metrics: &[Timeseries]) -> HashMap<String, Public
                 metric
                     .values()
                     .iter()
-                    .map(|val| val.as_ref().map(PublicTimeseriesValue::from))
+                    .map(|val| {
+                        val.as_ref()
+                            .map(PublicTimeseriesValue::from)
+                            .and_then(PublicTimeseriesValue::finite)
+                    })
                     .collect(),
             ),
         };
@@ -149,6 +191,77 @@ fn extract_and_convert_metrics(metrics: &[Timeseries]) -> HashMap<String, Public
     }))
 }
 
+// =============================================================================
+// Sessions
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PublicSessionSummary {
+    pub sport: String,
+    pub sport_category: Option<String>,
+    pub statistics: HashMap<String, f64>,
+    pub details: PublicSessionDetails,
+}
+
+/// Values recorded by the device in a session message, `null` when not recorded.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct PublicSessionDetails {
+    pub sub_sport: Option<String>,
+    pub trigger: Option<String>,
+    pub total_calories: Option<f64>,
+    pub total_ascent: Option<f64>,
+    pub total_descent: Option<f64>,
+    pub avg_heart_rate: Option<f64>,
+    pub max_heart_rate: Option<f64>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_cadence: Option<f64>,
+    pub max_cadence: Option<f64>,
+    pub avg_power: Option<f64>,
+    pub max_power: Option<f64>,
+    pub total_training_effect: Option<f64>,
+    pub total_anaerobic_training_effect: Option<f64>,
+}
+
+impl From<&SessionDetails> for PublicSessionDetails {
+    fn from(details: &SessionDetails) -> Self {
+        let finite = |value: Option<f64>| value.filter(|value| value.is_finite());
+        Self {
+            sub_sport: details.sub_sport.clone(),
+            trigger: details.trigger.clone(),
+            total_calories: finite(details.total_calories),
+            total_ascent: finite(details.total_ascent),
+            total_descent: finite(details.total_descent),
+            avg_heart_rate: finite(details.avg_heart_rate),
+            max_heart_rate: finite(details.max_heart_rate),
+            avg_speed: finite(details.avg_speed),
+            max_speed: finite(details.max_speed),
+            avg_cadence: finite(details.avg_cadence),
+            max_cadence: finite(details.max_cadence),
+            avg_power: finite(details.avg_power),
+            max_power: finite(details.max_power),
+            total_training_effect: finite(details.total_training_effect),
+            total_anaerobic_training_effect: finite(details.total_anaerobic_training_effect),
+        }
+    }
+}
+
+impl From<&SessionSummary> for PublicSessionSummary {
+    fn from(session: &SessionSummary) -> Self {
+        Self {
+            sport: session.sport().to_string(),
+            sport_category: session.sport().category().map(|cat| cat.to_string()),
+            statistics: session
+                .statistics()
+                .items()
+                .into_iter()
+                .filter(|(_, value)| value.is_finite())
+                .collect(),
+            details: session.details().into(),
+        }
+    }
+}
+
 // =============================================================================
 // Public representation of an Activity (without timeseries)
 // =============================================================================
@@ -167,6 +280,7 @@ pub struct PublicActivity {
     pub workout_type: Option<String>,
     pub feedback: Option<String>,
     pub nutrition: Option<PublicNutrition>,
+    pub visibility: String,
     pub metrics: HashMap<String, f64>,
 }
 
@@ -182,8 +296,11 @@ impl PublicActivity {
             workout_type: activity.workout_type().as_ref().map(|wt| wt.to_string()),
             feedback: activity.feedback().as_ref().map(|f| f.to_string()),
             nutrition: activity.nutrition().as_ref().map(PublicNutrition::from),
+            visibility: activity.visibility().to_string(),
             metrics: HashMap::from_iter(metrics.iter().filter_map(|(metric, value)| {
-                value.as_ref().map(|value| (metric.to_string(), *value))
+                value
+                    .filter(|value| value.is_finite())
+                    .map(|value| (metric.to_string(), value))
             })),
         }
     }
@@ -202,6 +319,8 @@ pub struct PublicActivityWithTimeseries {
     #[serde(flatten)]
     pub activity: PublicActivity,
     pub timeseries: PublicActivityTimeseries,
+    /// One entry per session of the activity file, more than one for multisport activities.
+    pub sessions: Vec<PublicSessionSummary>,
 }
 
 impl PublicActivityWithTimeseries {
@@ -209,6 +328,86 @@ impl PublicActivityWithTimeseries {
         Self {
             activity: PublicActivity::from(activity.activity(), metrics),
             timeseries: activity.timeseries().into(),
+            sessions: activity
+                .sessions()
+                .iter()
+                .map(PublicSessionSummary::from)
+                .collect(),
+        }
+    }
+
+    /// Add the moving duration, in seconds, to the activity's metrics as `MovingDuration`.
+    pub fn with_moving_duration(mut self, moving_duration: Option<ActivityDuration>) -> Self {
+        if let Some(duration) = moving_duration {
+            self.activity
+                .metrics
+                .insert("MovingDuration".to_string(), *duration.as_f64());
         }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::activity::{
+        ActiveTime, SwimStroke, TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_invalid_float_serializes_as_null() {
+        // An invalid .FIT float field (all bits set) is NaN once scaled
+        let invalid = f32::from_le_bytes([0xFF, 0xFF, 0xFF, 0xFF]) as f64;
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new(vec![0, 1]),
+            TimeseriesActiveTime::new(vec![ActiveTime::Running(0), ActiveTime::Running(1)]),
+            vec![],
+            vec![
+                Timeseries::new(
+                    TimeseriesMetric::Power,
+                    vec![
+                        Some(TimeseriesValue::Float(invalid)),
+                        Some(TimeseriesValue::Float(200.)),
+                    ],
+                ),
+                Timeseries::new(
+                    TimeseriesMetric::Speed,
+                    vec![Some(TimeseriesValue::Float(invalid)), None],
+                ),
+            ],
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&PublicActivityTimeseries::from(&timeseries)).unwrap();
+
+        assert!(!json.contains("NaN"));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value["metrics"]["Power"]["values"],
+            serde_json::json!([null, 200.0])
+        );
+        assert_eq!(
+            value["metrics"]["Speed"]["values"],
+            serde_json::json!([null, null])
+        );
+    }
+
+    #[test]
+    fn test_swim_lengths_serialization() {
+        let timeseries = ActivityTimeseries::empty().with_lengths(vec![
+            SwimLength::new(0, 30.4, Some(SwimStroke::Freestyle), Some(16)),
+            SwimLength::new(50, 45., Some(SwimStroke::Drill), None),
+        ]);
+
+        let value = serde_json::to_value(PublicActivityTimeseries::from(&timeseries)).unwrap();
+
+        assert_eq!(
+            value["lengths"],
+            serde_json::json!([
+                {"start": 0, "end": 30, "stroke": "Freestyle", "total_strokes": 16, "swolf": 46},
+                {"start": 50, "end": 95, "stroke": "Drill", "total_strokes": null, "swolf": null},
+            ])
+        );
     }
 }
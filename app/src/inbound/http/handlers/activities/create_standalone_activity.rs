@@ -11,6 +11,8 @@ use crate::{
     },
 };
 
+use super::upload_activity::default_visibility;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateStandaloneActivityResponse {
     id: String,
@@ -29,7 +31,10 @@ pub async fn create_standalone_activity<
     let parsed_content: ParsedFileContent = activity
         .try_into()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let create_request = parsed_content.into_request(user.user());
+    let visibility = default_visibility(state.preferences_service.as_ref(), user.user()).await;
+    let create_request = parsed_content
+        .into_request(user.user())
+        .with_visibility(visibility);
 
     let activity = state
         .activity_service
@@ -139,12 +144,7 @@ mod tests {
                 ))
             });
 
-        let state = AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(MockTrainingService::test_default()),
-            file_parser: Arc::new(MockFileParser::test_default()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default().with_activity_service(service);
 
         let app = Router::new()
             .route(
@@ -179,12 +179,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_standalone_activity_invalid_json_returns_422() {
-        let state = AppState {
-            activity_service: Arc::new(MockActivityService::test_default()),
-            training_metrics_service: Arc::new(MockTrainingService::test_default()),
-            file_parser: Arc::new(MockFileParser::test_default()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default();
 
         let app = Router::new()
             .route(
@@ -4,6 +4,7 @@ use axum::{
     http::StatusCode,
 };
 
+use crate::inbound::http::handlers::NOT_OWNED;
 use crate::{
     domain::{
         models::activity::ActivityId,
@@ -18,7 +19,7 @@ impl From<DeleteActivityError> for StatusCode {
     fn from(value: DeleteActivityError) -> Self {
         match value {
             DeleteActivityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
-            DeleteActivityError::UserDoesNotOwnActivity(_, _) => Self::FORBIDDEN,
+            DeleteActivityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -32,9 +33,9 @@ pub async fn delete_activity<
 >(
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
-    Path(activity_id): Path<String>,
+    Path(activity_id): Path<ActivityId>,
 ) -> Result<StatusCode, StatusCode> {
-    let req = DeleteActivityRequest::new(user.user().clone(), ActivityId::from(&activity_id));
+    let req = DeleteActivityRequest::new(user.user().clone(), activity_id);
     state
         .activity_service
         .delete_activity(req)
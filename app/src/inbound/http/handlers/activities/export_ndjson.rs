@@ -0,0 +1,184 @@
+use std::convert::Infallible;
+
+use axum::{
+    Extension,
+    body::Body,
+    extract::{Path, State},
+    http::{
+        StatusCode,
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
+    response::Response,
+};
+use fit_parser::{json::messages_to_ndjson, parse_fit_messages};
+
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{GetRawActivityError, GetRawActivityRequest, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{
+        auth::AuthenticatedUser,
+        http::AppState,
+        parser::{ParseFile, SupportedExtension},
+    },
+};
+
+/// Handler for GET /api/activity/{activity_id}/export/ndjson
+///
+/// Streams the messages of the activity's raw .FIT file as JSON Lines, one JSON object per
+/// message and per line. Activities not created from a .FIT file have no messages to export and
+/// are rejected.
+pub async fn export_activity_ndjson<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Response, StatusCode> {
+    let request = GetRawActivityRequest::new(activity_id.clone(), user.user().clone());
+    let raw = state
+        .activity_service
+        .get_raw_activity(request)
+        .await
+        .map_err(|err| match err {
+            GetRawActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            GetRawActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+        })?;
+
+    let suffix = raw.name().rsplit('.').next().unwrap_or_default();
+    if !matches!(
+        SupportedExtension::try_from(suffix),
+        Ok(SupportedExtension::FIT)
+    ) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let messages =
+        tokio::task::spawn_blocking(move || parse_fit_messages(raw.as_vec().into_iter(), false))
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .map_err(|err| {
+                tracing::warn!("Cannot parse the raw file of activity {activity_id}: {err}");
+                StatusCode::UNPROCESSABLE_ENTITY
+            })?;
+
+    // Messages are only serialized as the body is consumed
+    let lines = messages_to_ndjson(messages).map(Ok::<_, Infallible>);
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/x-ndjson")
+        .header(
+            CONTENT_DISPOSITION,
+            "attachment; filename=\"messages.ndjson\"",
+        )
+        .body(Body::from_stream(tokio_stream::iter(lines)))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            ports::activity::RawActivity,
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn server(activity_service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/export/ndjson",
+                get(export_activity_ndjson),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    fn activity_service(name: &'static str, content: Vec<u8>) -> MockActivityService {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_get_raw_activity()
+            .returning(move |_| Ok(RawActivity::new(name.to_string(), content.clone())));
+        activity_service
+    }
+
+    #[tokio::test]
+    async fn test_export_activity_ndjson() {
+        let content = std::fs::read("src/inbound/parser/test.fit").unwrap();
+        let expected = parse_fit_messages(content.clone().into_iter(), false).unwrap();
+
+        let response = server(activity_service("activity.fit", content))
+            .get("/activity/activity/export/ndjson")
+            .await;
+
+        response.assert_status_ok();
+        assert_eq!(
+            response.header(CONTENT_TYPE).to_str().unwrap(),
+            "application/x-ndjson"
+        );
+        let text = response.text();
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), expected.len());
+        for (line, message) in lines.iter().zip(expected.iter()) {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["message"], message.to_json()["message"]);
+            assert_eq!(
+                value["fields"].as_object().unwrap().len(),
+                message.to_json()["fields"].as_object().unwrap().len()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_non_fit_activity_ndjson() {
+        let response = server(activity_service("activity.json", b"{}".to_vec()))
+            .get("/activity/activity/export/ndjson")
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_export_activity_ndjson_not_found() {
+        let mut activity_service = MockActivityService::new();
+        activity_service.expect_get_raw_activity().returning(|req| {
+            Err(GetRawActivityError::ActivityDoesNotExist(
+                req.activity().clone(),
+            ))
+        });
+
+        let response = server(activity_service)
+            .get("/activity/activity/export/ndjson")
+            .await;
+
+        response.assert_status_not_found();
+    }
+}
@@ -6,7 +6,11 @@ use axum::{
 
 use crate::{
     domain::{
-        models::activity::{ActivityId, DEFAULT_METRICS},
+        models::{
+            UserId,
+            activity::{ActivityId, DEFAULT_METRICS, MovingSpeedThresholds},
+            preferences::{Preference, PreferenceKey},
+        },
         ports::{
             activity::IActivityService, preferences::IPreferencesService,
             training::ITrainingService,
@@ -16,6 +20,7 @@ use crate::{
 };
 
 use super::activity_schema::PublicActivityWithTimeseries;
+use crate::inbound::http::handlers::check_owner;
 
 pub async fn get_activity<
     AS: IActivityService,
@@ -23,24 +28,39 @@ pub async fn get_activity<
     TMS: ITrainingService,
     PS: IPreferencesService,
 >(
-    Extension(_user): Extension<AuthenticatedUser>,
+    Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
-    Path(activity_id): Path<String>,
+    Path(activity_id): Path<ActivityId>,
 ) -> Result<Json<PublicActivityWithTimeseries>, StatusCode> {
     let Ok((activity, metrics)) = state
         .activity_service
-        .get_activity_with_metrics_and_parsed_data(
-            &ActivityId::from(&activity_id),
-            &DEFAULT_METRICS,
-        )
+        .get_activity_with_metrics_and_parsed_data(&activity_id, &DEFAULT_METRICS)
         .await
     else {
         return Err(StatusCode::NOT_FOUND);
     };
+    check_owner(activity.user(), &user)?;
 
-    Ok(Json(PublicActivityWithTimeseries::from(
-        &activity, &metrics,
-    )))
+    let thresholds = moving_speed_thresholds(&*state.preferences_service, user.user()).await;
+
+    Ok(Json(
+        PublicActivityWithTimeseries::from(&activity, &metrics)
+            .with_moving_duration(activity.moving_duration(&thresholds)),
+    ))
+}
+
+/// Moving speed thresholds of the user, with the defaults if not overridden.
+async fn moving_speed_thresholds<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> MovingSpeedThresholds {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::MovingSpeedThresholds)
+        .await
+    {
+        Ok(Some(Preference::MovingSpeedThresholds(thresholds))) => thresholds,
+        _ => MovingSpeedThresholds::default(),
+    }
 }
 
 #[cfg(test)]
@@ -58,8 +78,9 @@ mod tests {
                 activity::{
                     ActiveTime, Activity, ActivityDuration, ActivityId, ActivityMetricV2,
                     ActivityMetricsV2, ActivityStartTime, ActivityStatistic, ActivityStatistics,
-                    ActivityTimeseries, ActivityWithParsedData, Sport, Timeseries,
-                    TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
+                    ActivityTimeseries, ActivityWithParsedData, SessionDetails, SessionSummary,
+                    Sport, SportCategory, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
+                    TimeseriesTime, TimeseriesValue,
                 },
             },
             ports::activity::GetActivityError,
@@ -71,7 +92,8 @@ mod tests {
         },
         inbound::{
             http::handlers::activities::activity_schema::{
-                PublicActivity, PublicActivityTimeseries, PublicTimeseries, PublicTimeseriesValue,
+                PublicActivity, PublicActivityTimeseries, PublicSessionDetails,
+                PublicSessionSummary, PublicTimeseries, PublicTimeseriesValue,
             },
             parser::test_utils::MockFileParser,
         },
@@ -128,16 +150,14 @@ mod tests {
                     )])),
                 ))
             });
-        let file_parser = MockFileParser::test_default();
         let metrics = MockTrainingService::test_default();
 
-        let state = axum::extract::State(AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        });
-        let path = Path("target_id".to_string());
+        let state = axum::extract::State(
+            AppState::test_default()
+                .with_activity_service(service)
+                .with_training_metrics_service(metrics),
+        );
+        let path = Path(ActivityId::from("target_id"));
 
         let response = get_activity(
             Extension(AuthenticatedUser::new(UserId::test_default())),
@@ -162,6 +182,7 @@ mod tests {
                     workout_type: None,
                     nutrition: None,
                     feedback: None,
+                    visibility: "private".to_string(),
                     metrics: HashMap::from([("Duration".to_string(), 1200.)]),
                 },
                 timeseries: PublicActivityTimeseries {
@@ -178,12 +199,156 @@ mod tests {
                             ]
                         }
                     )]),
-                    laps: vec![]
-                }
+                    laps: vec![],
+                    lengths: vec![]
+                },
+                sessions: vec![],
             }
         );
     }
 
+    #[tokio::test]
+    async fn test_get_activity_exposes_sessions() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_metrics_and_parsed_data()
+            .returning(|_, _| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("target_id"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(3600.),
+                            Sport::Multisport,
+                        ),
+                        ActivityTimeseries::default(),
+                        ActivityStatistics::default(),
+                    )
+                    .with_sessions(vec![
+                        SessionSummary::new(
+                            Sport::OpenWaterSwimming,
+                            ActivityStatistics::new(HashMap::from([(
+                                ActivityStatistic::Distance,
+                                1500.,
+                            )])),
+                        ),
+                        SessionSummary::new(Sport::Cycling, ActivityStatistics::default())
+                            .with_details(SessionDetails {
+                                total_ascent: Some(420.),
+                                avg_power: Some(210.),
+                                trigger: Some("ActivityEnd".to_string()),
+                                ..Default::default()
+                            }),
+                    ]),
+                    ActivityMetricsV2::default(),
+                ))
+            });
+
+        let state = axum::extract::State(AppState::test_default().with_activity_service(service));
+
+        let response = get_activity(
+            Extension(AuthenticatedUser::new(UserId::test_default())),
+            state,
+            Path(ActivityId::from("target_id")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.0.activity.sport, "Multisport".to_string());
+        assert_eq!(
+            response.0.sessions,
+            vec![
+                PublicSessionSummary {
+                    sport: "OpenWaterSwimming".to_string(),
+                    sport_category: Some("Swimming".to_string()),
+                    statistics: HashMap::from([("Distance".to_string(), 1500.)]),
+                    details: PublicSessionDetails::default(),
+                },
+                PublicSessionSummary {
+                    sport: "Cycling".to_string(),
+                    sport_category: Some("Cycling".to_string()),
+                    statistics: HashMap::new(),
+                    details: PublicSessionDetails {
+                        total_ascent: Some(420.),
+                        avg_power: Some(210.),
+                        trigger: Some("ActivityEnd".to_string()),
+                        ..Default::default()
+                    },
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_moving_duration_uses_preference() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_metrics_and_parsed_data()
+            .returning(|_, _| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("target_id"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(0).unwrap(),
+                            ActivityDuration::from(3.),
+                            Sport::Cycling,
+                        ),
+                        ActivityTimeseries::new(
+                            TimeseriesTime::new(vec![0, 1, 2, 3]),
+                            TimeseriesActiveTime::new(vec![
+                                ActiveTime::Running(0),
+                                ActiveTime::Running(1),
+                                ActiveTime::Running(2),
+                                ActiveTime::Running(3),
+                            ]),
+                            vec![],
+                            vec![Timeseries::new(
+                                TimeseriesMetric::Speed,
+                                vec![
+                                    Some(TimeseriesValue::Float(1.)),
+                                    Some(TimeseriesValue::Float(1.)),
+                                    Some(TimeseriesValue::Float(3.)),
+                                    Some(TimeseriesValue::Float(1.)),
+                                ],
+                            )],
+                        )
+                        .unwrap(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::default(),
+                ))
+            });
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::MovingSpeedThresholds)
+            .returning(|_, _| {
+                Ok(Some(Preference::MovingSpeedThresholds(
+                    MovingSpeedThresholds::try_from(HashMap::from([(SportCategory::Cycling, 0.5)]))
+                        .unwrap(),
+                )))
+            });
+
+        let state = axum::extract::State(
+            AppState::test_default()
+                .with_activity_service(service)
+                .with_preferences_service(preferences_service),
+        );
+
+        let response = get_activity(
+            Extension(AuthenticatedUser::new(UserId::test_default())),
+            state,
+            Path(ActivityId::from("target_id")),
+        )
+        .await
+        .unwrap();
+
+        // The default cycling threshold would only count 1 second
+        assert_eq!(response.0.activity.metrics.get("MovingDuration"), Some(&3.));
+    }
+
     #[tokio::test]
     async fn test_get_activity_does_not_exist() {
         let mut service = MockActivityService::new();
@@ -196,16 +361,12 @@ mod tests {
                 )))
             });
 
-        let file_parser = MockFileParser::test_default();
-        let metrics = MockTrainingService::test_default();
-
-        let state = axum::extract::State(AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        });
-        let path = Path("target_id".to_string());
+        let state = axum::extract::State(
+            AppState::test_default()
+                .with_activity_service(service)
+                .with_preferences_service(MockPreferencesService::new()),
+        );
+        let path = Path(ActivityId::from("target_id"));
 
         let response = get_activity(
             Extension(AuthenticatedUser::new(UserId::test_default())),
@@ -217,6 +378,49 @@ mod tests {
         let response = response.unwrap_err();
         assert_eq!(response, StatusCode::NOT_FOUND);
     }
+
+    #[tokio::test]
+    async fn test_get_activity_of_another_user_is_not_found() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_metrics_and_parsed_data()
+            .returning(|id, _| match id.to_string().as_str() {
+                "other_user_activity" => Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            id.clone(),
+                            UserId::from("other_user"),
+                            ActivityStartTime::from_timestamp(0).unwrap(),
+                            ActivityDuration::from(3600.),
+                            Sport::Running,
+                        ),
+                        ActivityTimeseries::default(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::default(),
+                )),
+                _ => Err(GetActivityError::ActivityDoesNotExist(id.clone())),
+            });
+
+        let state = axum::extract::State(
+            AppState::test_default()
+                .with_activity_service(service)
+                .with_preferences_service(MockPreferencesService::new()),
+        );
+        let get = |id: &str| {
+            get_activity(
+                Extension(AuthenticatedUser::new(UserId::test_default())),
+                state.clone(),
+                Path(ActivityId::from(id)),
+            )
+        };
+
+        let other_user = get("other_user_activity").await.unwrap_err();
+        let missing = get("missing_activity").await.unwrap_err();
+
+        assert_eq!(other_user, StatusCode::NOT_FOUND);
+        assert_eq!(other_user, missing);
+    }
 }
 
 // left: PublicActivityWithTimeseries { activity: PublicActivity { id: "target_id", sport: "IndoorCycling", sport_category: Some("Cycling"), name: None, start_time: 2025-09-03T00:00:00+00:00, rpe: None, workout_type: None, feedback: None, nutrition: None, metrics: {} }, timeseries: PublicActivityTimeseries { time: [0, 1, 2], active_time: [Some(0), Some(1), Some(2)], metrics: {"Power": PublicTimeseries { unit: "W", values: [Some(Int(120)), None, Some(Int(130))] }}, laps: [] } }
@@ -0,0 +1,145 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Serialize;
+
+use crate::inbound::http::handlers::check_owner;
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{GetActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+#[derive(Debug, Serialize)]
+pub struct BeatIntervalsResponse {
+    /// Beat-to-beat (RR) intervals, in seconds
+    intervals: Vec<f32>,
+}
+
+/// Handler for GET /api/activity/{activity_id}/rr
+///
+/// Returns the beat-to-beat (RR) intervals recorded during the activity, empty if the device did
+/// not record any.
+pub async fn get_activity_beat_intervals<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<BeatIntervalsResponse>, StatusCode> {
+    let activity = state
+        .activity_service
+        .get_activity_with_parsed_data(&activity_id)
+        .await
+        .map_err(|err| match err {
+            GetActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+            GetActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    check_owner(activity.user(), &user)?;
+
+    Ok(Json(BeatIntervalsResponse {
+        intervals: activity.beat_intervals().values().to_vec(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use serde_json::json;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    Activity, ActivityDuration, ActivityStartTime, ActivityStatistics,
+                    ActivityTimeseries, ActivityWithParsedData, BeatIntervals, Sport,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/rr",
+                get(get_activity_beat_intervals::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    fn activity_service(user: UserId) -> MockActivityService {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(move |_| {
+                Ok(ActivityWithParsedData::new(
+                    Activity::new_empty(
+                        ActivityId::from("activity"),
+                        user.clone(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::from(5.),
+                        Sport::Running,
+                    ),
+                    ActivityTimeseries::default(),
+                    ActivityStatistics::default(),
+                )
+                .with_beat_intervals(BeatIntervals::new(vec![0.5, 0.75])))
+            });
+        service
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_beat_intervals() {
+        let response = test_server(activity_service(UserId::default()))
+            .get("/activity/activity/rr")
+            .await;
+
+        response.assert_status_ok();
+        response.assert_json(&json!({"intervals": [0.5, 0.75]}));
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_beat_intervals_of_another_user_is_not_found() {
+        let response = test_server(activity_service(UserId::from("other")))
+            .get("/activity/activity/rr")
+            .await;
+
+        response.assert_status_not_found();
+    }
+}
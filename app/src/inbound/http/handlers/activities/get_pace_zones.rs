@@ -0,0 +1,252 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Serialize;
+
+use crate::inbound::http::handlers::check_owner;
+use crate::{
+    domain::{
+        models::activity::{ActivityId, pace_zone_bounds},
+        ports::{
+            activity::{GetActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::get_power_zones::sport_thresholds;
+
+#[derive(Debug, Serialize)]
+pub struct PaceZonesResponse {
+    /// Threshold speed used to compute the zones, in m/s
+    threshold_speed: f64,
+    zones: Vec<PaceZoneResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaceZoneResponse {
+    /// Zone number, starting at 1
+    zone: usize,
+    /// Lower bound of the zone, in m/s
+    min: f64,
+    /// Upper bound of the zone, in m/s, none for the last zone
+    max: Option<f64>,
+    seconds: usize,
+}
+
+/// Handler for GET /api/activity/{activity_id}/pace-zones
+///
+/// Returns the time spent in each pace zone, computed from the running threshold pace or the
+/// swimming CSS of the user's sport thresholds, depending on the sport of the activity. Fails with
+/// 422 if the user has no threshold for the sport.
+pub async fn get_activity_pace_zones<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<PaceZonesResponse>, StatusCode> {
+    let activity = state
+        .activity_service
+        .get_activity_with_parsed_data(&activity_id)
+        .await
+        .map_err(|err| match err {
+            GetActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+            GetActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    check_owner(activity.user(), &user)?;
+
+    let threshold_speed = sport_thresholds(&*state.preferences_service, user.user())
+        .await?
+        .threshold_speed(activity.sport())
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    let durations = activity.timeseries().pace_zone_durations(threshold_speed);
+    Ok(Json(PaceZonesResponse {
+        threshold_speed,
+        zones: pace_zone_bounds(threshold_speed)
+            .into_iter()
+            .zip(durations)
+            .enumerate()
+            .map(|(idx, ((min, max), seconds))| PaceZoneResponse {
+                zone: idx + 1,
+                min,
+                max,
+                seconds,
+            })
+            .collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use serde_json::json;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    ActiveTime, Activity, ActivityDuration, ActivityStartTime, ActivityStatistics,
+                    ActivityTimeseries, ActivityWithParsedData, Ftp, Sport, SportThresholds,
+                    ThresholdPace, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
+                    TimeseriesTime, TimeseriesValue,
+                },
+                preferences::{Preference, PreferenceKey},
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(
+        service: MockActivityService,
+        preferences_service: MockPreferencesService,
+    ) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(preferences_service);
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/pace-zones",
+                get(get_activity_pace_zones::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    /// Activity of 6 seconds: 2 seconds at 3 m/s and 3 seconds at 4.5 m/s, with power.
+    fn activity_service(sport: Sport) -> MockActivityService {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(move |_| {
+                let speed = [0., 3., 3., 4.5, 4.5, 4.5]
+                    .into_iter()
+                    .map(|speed| Some(TimeseriesValue::Float(speed)))
+                    .collect();
+                let power = vec![Some(TimeseriesValue::Int(250)); 6];
+                Ok(ActivityWithParsedData::new(
+                    Activity::new_empty(
+                        ActivityId::from("activity"),
+                        UserId::default(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::from(5.),
+                        sport,
+                    ),
+                    ActivityTimeseries::new(
+                        TimeseriesTime::new((0..6).collect()),
+                        TimeseriesActiveTime::new((0..6).map(ActiveTime::Running).collect()),
+                        vec![],
+                        vec![
+                            Timeseries::new(TimeseriesMetric::Speed, speed),
+                            Timeseries::new(TimeseriesMetric::Power, power),
+                        ],
+                    )
+                    .unwrap(),
+                    ActivityStatistics::default(),
+                ))
+            });
+        service
+    }
+
+    fn preferences_service(thresholds: SportThresholds) -> MockPreferencesService {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::SportThresholds)
+            .returning(move |_, _| Ok(Some(Preference::SportThresholds(thresholds.clone()))));
+        preferences_service
+    }
+
+    fn thresholds() -> SportThresholds {
+        SportThresholds {
+            cycling_ftp: Some(Ftp::try_from(250.).unwrap()),
+            // 4 m/s
+            running_threshold_pace: Some(ThresholdPace::try_from(250.).unwrap()),
+            // 2 m/s
+            swimming_css: Some(ThresholdPace::try_from(50.).unwrap()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_pace_zones_of_a_run_uses_threshold_pace() {
+        let response = test_server(
+            activity_service(Sport::Running),
+            preferences_service(thresholds()),
+        )
+        .get("/activity/activity/pace-zones")
+        .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["threshold_speed"], json!(4.));
+        assert_eq!(
+            body["zones"][0],
+            json!({"zone": 1, "min": 0., "max": 3.12, "seconds": 2})
+        );
+        assert_eq!(
+            body["zones"][4],
+            json!({"zone": 5, "min": 4.04, "max": null, "seconds": 3})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_pace_zones_of_a_swim_uses_css() {
+        let response = test_server(
+            activity_service(Sport::Swimming),
+            preferences_service(thresholds()),
+        )
+        .get("/activity/activity/pace-zones")
+        .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["threshold_speed"], json!(2.));
+        let seconds = body["zones"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|zone| zone["seconds"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(seconds, vec![0, 0, 0, 0, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_pace_zones_without_threshold() {
+        let response = test_server(
+            activity_service(Sport::Cycling),
+            preferences_service(thresholds()),
+        )
+        .get("/activity/activity/pace-zones")
+        .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
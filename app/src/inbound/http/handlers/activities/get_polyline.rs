@@ -0,0 +1,188 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use fit_parser::polyline::encode_polyline;
+use serde::Serialize;
+
+use crate::inbound::http::handlers::check_owner;
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{GetActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+/// Number of decimals kept for each coordinate, the standard polyline precision.
+const POLYLINE_PRECISION: u32 = 5;
+
+#[derive(Debug, Serialize)]
+pub struct ActivityPolylineResponse {
+    polyline: String,
+}
+
+/// Handler for GET /api/activity/{activity_id}/polyline
+///
+/// Returns the track of the activity as a Google encoded polyline, skipping points without
+/// coordinates. The polyline is empty if the activity has no position at all.
+pub async fn get_activity_polyline<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<ActivityPolylineResponse>, StatusCode> {
+    let activity = state
+        .activity_service
+        .get_activity_with_parsed_data(&activity_id)
+        .await
+        .map_err(|err| match err {
+            GetActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+            GetActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    check_owner(activity.user(), &user)?;
+
+    Ok(Json(ActivityPolylineResponse {
+        polyline: encode_polyline(&activity.timeseries().positions(), POLYLINE_PRECISION),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    ActiveTime, Activity, ActivityDuration, ActivityStartTime, ActivityStatistics,
+                    ActivityTimeseries, ActivityWithParsedData, Sport, Timeseries,
+                    TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/polyline",
+                get(get_activity_polyline::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    fn activity(user: UserId) -> ActivityWithParsedData {
+        let position = |values: [Option<f64>; 3]| {
+            values
+                .into_iter()
+                .map(|value| value.map(TimeseriesValue::Float))
+                .collect()
+        };
+
+        ActivityWithParsedData::new(
+            Activity::new_empty(
+                ActivityId::from("run"),
+                user,
+                ActivityStartTime::from_timestamp(1000).unwrap(),
+                ActivityDuration::from(3.),
+                Sport::Running,
+            ),
+            ActivityTimeseries::new(
+                TimeseriesTime::new(vec![0, 1, 2]),
+                TimeseriesActiveTime::new(vec![
+                    ActiveTime::Running(0),
+                    ActiveTime::Running(1),
+                    ActiveTime::Running(2),
+                ]),
+                vec![],
+                vec![
+                    Timeseries::new(
+                        TimeseriesMetric::Latitude,
+                        position([Some(38.5), None, Some(40.7)]),
+                    ),
+                    Timeseries::new(
+                        TimeseriesMetric::Longitude,
+                        position([Some(-120.2), Some(-120.5), Some(-120.95)]),
+                    ),
+                ],
+            )
+            .unwrap(),
+            ActivityStatistics::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_polyline() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .with(eq(ActivityId::from("run")))
+            .times(1)
+            .returning(|_| Ok(activity(UserId::default())));
+
+        let response = test_server(service).get("/activity/run/polyline").await;
+
+        response.assert_status_ok();
+        response.assert_json(&serde_json::json!({"polyline": "_p~iF~ps|U_ulLnnqC"}));
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_polyline_of_another_user() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(|_| Ok(activity(UserId::from("other_user"))));
+
+        let response = test_server(service).get("/activity/run/polyline").await;
+
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_polyline_not_found() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(|id| Err(GetActivityError::ActivityDoesNotExist(id.clone())));
+
+        let response = test_server(service).get("/activity/run/polyline").await;
+
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,309 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Serialize;
+
+use crate::inbound::http::handlers::check_owner;
+use crate::{
+    domain::{
+        models::{
+            UserId,
+            activity::{ActivityId, PowerZones, SportThresholds},
+            preferences::{Preference, PreferenceKey},
+        },
+        ports::{
+            activity::{GetActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+#[derive(Debug, Serialize)]
+pub struct PowerZonesResponse {
+    /// FTP used to compute the zones, in watts
+    ftp: f64,
+    zones: Vec<PowerZoneResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PowerZoneResponse {
+    /// Zone number, starting at 1
+    zone: usize,
+    /// Lower bound of the zone, in watts
+    min: f64,
+    /// Upper bound of the zone, in watts, none for the last zone
+    max: Option<f64>,
+    seconds: usize,
+}
+
+/// Handler for GET /api/activity/{activity_id}/power-zones
+///
+/// Returns the time spent in each power zone, computed from the user's FTP and power zones
+/// preferences (the Coggan 7-zone model by default). Cycling activities use the cycling FTP of the
+/// sport thresholds if set. Fails with 422 if the user has no FTP.
+pub async fn get_activity_power_zones<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<PowerZonesResponse>, StatusCode> {
+    let activity = state
+        .activity_service
+        .get_activity_with_parsed_data(&activity_id)
+        .await
+        .map_err(|err| match err {
+            GetActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+            GetActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    check_owner(activity.user(), &user)?;
+
+    let ftp = match state
+        .preferences_service
+        .get_preference(user.user(), &PreferenceKey::Ftp)
+        .await
+        .map_err(StatusCode::from)?
+    {
+        Some(Preference::Ftp(ftp)) => Some(ftp),
+        _ => None,
+    };
+    let ftp = sport_thresholds(&*state.preferences_service, user.user())
+        .await?
+        .ftp(activity.sport(), ftp)
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let zones = match state
+        .preferences_service
+        .get_preference(user.user(), &PreferenceKey::PowerZones)
+        .await
+        .map_err(StatusCode::from)?
+    {
+        Some(Preference::PowerZones(zones)) => zones,
+        _ => PowerZones::default(),
+    };
+
+    let durations = activity.timeseries().power_zone_durations(&ftp, &zones);
+    Ok(Json(PowerZonesResponse {
+        ftp: ftp.watts(),
+        zones: zones
+            .bounds(&ftp)
+            .into_iter()
+            .zip(durations)
+            .enumerate()
+            .map(|(idx, ((min, max), seconds))| PowerZoneResponse {
+                zone: idx + 1,
+                min,
+                max,
+                seconds,
+            })
+            .collect(),
+    }))
+}
+
+/// Sport thresholds of the user, none are set by default.
+pub(super) async fn sport_thresholds<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> Result<SportThresholds, StatusCode> {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::SportThresholds)
+        .await
+        .map_err(StatusCode::from)?
+    {
+        Some(Preference::SportThresholds(thresholds)) => Ok(thresholds),
+        _ => Ok(SportThresholds::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use serde_json::json;
+
+    use crate::{
+        domain::{
+            models::activity::{
+                ActiveTime, Activity, ActivityDuration, ActivityStartTime, ActivityStatistics,
+                ActivityTimeseries, ActivityWithParsedData, Ftp, Sport, Timeseries,
+                TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(
+        service: MockActivityService,
+        preferences_service: MockPreferencesService,
+    ) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(preferences_service);
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/power-zones",
+                get(get_activity_power_zones::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    /// Ride of 6 seconds: 2 seconds at 100W, 3 seconds at 250W and 1 second without power.
+    fn activity_service() -> MockActivityService {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(|_| {
+                let power = [0, 100, 100, 250, 250, 250]
+                    .into_iter()
+                    .map(|power| Some(TimeseriesValue::Int(power)))
+                    .chain([None])
+                    .collect();
+                Ok(ActivityWithParsedData::new(
+                    Activity::new_empty(
+                        ActivityId::from("ride"),
+                        UserId::default(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::from(6.),
+                        Sport::Cycling,
+                    ),
+                    ActivityTimeseries::new(
+                        TimeseriesTime::new((0..7).collect()),
+                        TimeseriesActiveTime::new((0..7).map(ActiveTime::Running).collect()),
+                        vec![],
+                        vec![Timeseries::new(TimeseriesMetric::Power, power)],
+                    )
+                    .unwrap(),
+                    ActivityStatistics::default(),
+                ))
+            });
+        service
+    }
+
+    fn preferences_service(zones: Option<PowerZones>) -> MockPreferencesService {
+        preferences_service_with_thresholds(zones, SportThresholds::default())
+    }
+
+    fn preferences_service_with_thresholds(
+        zones: Option<PowerZones>,
+        thresholds: SportThresholds,
+    ) -> MockPreferencesService {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::SportThresholds)
+            .returning(move |_, _| Ok(Some(Preference::SportThresholds(thresholds.clone()))));
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::Ftp)
+            .returning(|_, _| Ok(Some(Preference::Ftp(Ftp::try_from(200.).unwrap()))));
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::PowerZones)
+            .returning(move |_, _| Ok(zones.clone().map(Preference::PowerZones)));
+        preferences_service
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_power_zones() {
+        let response = test_server(activity_service(), preferences_service(None))
+            .get("/activity/ride/power-zones")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["ftp"], json!(200.));
+        assert_eq!(
+            body["zones"][0],
+            json!({"zone": 1, "min": 0., "max": 110., "seconds": 2})
+        );
+        assert_eq!(
+            body["zones"][5],
+            json!({"zone": 6, "min": 240., "max": 300., "seconds": 3})
+        );
+        assert_eq!(
+            body["zones"][6],
+            json!({"zone": 7, "min": 300., "max": null, "seconds": 0})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_power_zones_with_overridden_zones() {
+        let zones = PowerZones::try_from(vec![0.4, 0.6, 0.8, 1.0, 1.1, 1.2]).unwrap();
+
+        let response = test_server(activity_service(), preferences_service(Some(zones)))
+            .get("/activity/ride/power-zones")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        let seconds = body["zones"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|zone| zone["seconds"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(seconds, vec![0, 2, 0, 0, 0, 0, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_power_zones_uses_cycling_ftp() {
+        let thresholds = SportThresholds {
+            cycling_ftp: Some(Ftp::try_from(100.).unwrap()),
+            ..Default::default()
+        };
+
+        let response = test_server(
+            activity_service(),
+            preferences_service_with_thresholds(None, thresholds),
+        )
+        .get("/activity/ride/power-zones")
+        .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["ftp"], json!(100.));
+        assert_eq!(
+            body["zones"][6],
+            json!({"zone": 7, "min": 150., "max": null, "seconds": 3})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_power_zones_without_ftp() {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .returning(|_, _| Ok(None));
+
+        let response = test_server(activity_service(), preferences_service)
+            .get("/activity/ride/power-zones")
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1,153 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    domain::{
+        models::activity::{ActivityId, ActivityVisibility, DEFAULT_METRICS},
+        ports::{
+            activity::IActivityService, preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivityWithTimeseries;
+
+/// Handler for GET /api/public/activity/{activity_id}
+///
+/// Does not require authentication, only activities whose visibility is public are served, any
+/// other activity is reported as not found so that its existence is not leaked.
+pub async fn get_public_activity<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<PublicActivityWithTimeseries>, StatusCode> {
+    let Ok((activity, metrics)) = state
+        .activity_service
+        .get_activity_with_metrics_and_parsed_data(&activity_id, &DEFAULT_METRICS)
+        .await
+    else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    if activity.visibility() != &ActivityVisibility::Public {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(Json(PublicActivityWithTimeseries::from(
+        &activity, &metrics,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, routing::get};
+    use axum_test::TestServer;
+
+    use crate::domain::{
+        models::{
+            UserId,
+            activity::{
+                Activity, ActivityDuration, ActivityMetricsV2, ActivityStartTime,
+                ActivityStatistics, ActivityTimeseries, ActivityWithParsedData, Sport,
+            },
+        },
+        ports::activity::GetActivityError,
+        services::{
+            activity::test_utils::MockActivityService,
+            preferences::tests_utils::MockPreferencesService,
+            training::test_utils::MockTrainingService,
+        },
+    };
+    use crate::inbound::parser::test_utils::MockFileParser;
+
+    use super::*;
+
+    fn test_server(visibility: ActivityVisibility) -> TestServer {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_metrics_and_parsed_data()
+            .returning(move |id, _| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            id.clone(),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(3600.),
+                            Sport::Running,
+                        )
+                        .with_visibility(visibility),
+                        ActivityTimeseries::default(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::default(),
+                ))
+            });
+
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        // No authentication layer, as in the public router
+        let app = Router::new()
+            .route("/public/activity/{activity_id}", get(get_public_activity))
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_get_public_activity_serves_public_activity() {
+        let server = test_server(ActivityVisibility::Public);
+
+        let response = server.get("/public/activity/target_id").await;
+
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["id"], "target_id");
+        assert_eq!(json["visibility"], "public");
+    }
+
+    #[tokio::test]
+    async fn test_get_public_activity_private_activity_not_found() {
+        let server = test_server(ActivityVisibility::Private);
+
+        let response = server.get("/public/activity/target_id").await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_get_public_activity_unlisted_activity_not_found() {
+        let server = test_server(ActivityVisibility::Unlisted);
+
+        let response = server.get("/public/activity/target_id").await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_get_public_activity_does_not_exist() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_metrics_and_parsed_data()
+            .returning(|id, _| Err(GetActivityError::ActivityDoesNotExist(id.clone())));
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let response = get_public_activity(State(state), Path(ActivityId::from("target_id"))).await;
+
+        assert_eq!(response.unwrap_err(), StatusCode::NOT_FOUND);
+    }
+}
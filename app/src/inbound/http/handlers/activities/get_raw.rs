@@ -1,12 +1,13 @@
 use axum::body::Body;
 use axum::extract::{Path, Query};
-use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, ETAG};
 use axum::{
     Extension,
     extract::State,
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use sha2::{Digest, Sha256};
 use std::io::Write;
 
 use crate::domain::models::activity::ActivityId;
@@ -75,9 +76,9 @@ pub async fn get_raw_activity<
 >(
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
-    Path(activity): Path<String>,
+    Path(activity_id): Path<ActivityId>,
 ) -> Result<Response, StatusCode> {
-    let request = GetRawActivityRequest::new(ActivityId::from(&activity), user.user().clone());
+    let request = GetRawActivityRequest::new(activity_id, user.user().clone());
 
     let activity = state
         .activity_service
@@ -88,7 +89,7 @@ pub async fn get_raw_activity<
             GetRawActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
         })?;
 
-    let content_type = if activity.name().ends_with(".tcx") {
+    let content_type = if activity.name().ends_with(".tcx") || activity.name().ends_with(".gpx") {
         "application/xml"
     } else {
         "application/octet-stream"
@@ -101,12 +102,18 @@ pub async fn get_raw_activity<
             CONTENT_DISPOSITION,
             format!("attachment; filename=\"{}\"", activity.name()),
         )
+        .header(ETAG, raw_content_etag(activity.content()))
         .body(Body::from(activity.as_vec()))
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(response)
 }
 
+/// Strong `ETag` of a raw activity file, derived from its content.
+pub(super) fn raw_content_etag(content: &[u8]) -> String {
+    format!("\"{}\"", const_hex::encode(Sha256::digest(content)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,12 +141,11 @@ mod tests {
                 ])
             });
 
-        let state = AppState {
-            activity_service: Arc::new(activity_service),
-            file_parser: Arc::new(MockFileParser::new()),
-            training_metrics_service: Arc::new(MockTrainingService::new()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
 
         let response = get_all_raw_activities(Extension(authenticated_user), State(state))
             .await
@@ -171,12 +177,11 @@ mod tests {
                 )
             });
 
-        let state = AppState {
-            activity_service: Arc::new(activity_service),
-            file_parser: Arc::new(MockFileParser::new()),
-            training_metrics_service: Arc::new(MockTrainingService::new()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
 
         let result = get_all_raw_activities(Extension(authenticated_user), State(state)).await;
 
@@ -194,12 +199,11 @@ mod tests {
             .expect_get_all_raw_activities()
             .returning(|_| Ok(vec![]));
 
-        let state = AppState {
-            activity_service: Arc::new(activity_service),
-            file_parser: Arc::new(MockFileParser::new()),
-            training_metrics_service: Arc::new(MockTrainingService::new()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
 
         let response = get_all_raw_activities(Extension(authenticated_user), State(state))
             .await
@@ -207,4 +211,41 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_get_raw_activity_etag_follows_content() {
+        let mut activity_service = MockActivityService::new();
+        let mut sequence = mockall::Sequence::new();
+        activity_service
+            .expect_get_raw_activity()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(RawActivity::new("activity.fit".to_string(), vec![1, 2, 3])));
+        activity_service
+            .expect_get_raw_activity()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(RawActivity::new("activity.tcx".to_string(), vec![4, 5, 6])));
+
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
+
+        let mut etags = Vec::new();
+        for _ in 0..2 {
+            let response = get_raw_activity(
+                Extension(AuthenticatedUser::new(UserId::test_default())),
+                State(state.clone()),
+                Path(ActivityId::from("activity")),
+            )
+            .await
+            .unwrap();
+            etags.push(response.headers().get(ETAG).unwrap().clone());
+        }
+
+        assert_eq!(etags[0], raw_content_etag(&[1, 2, 3]).as_str());
+        assert_ne!(etags[0], etags[1]);
+    }
 }
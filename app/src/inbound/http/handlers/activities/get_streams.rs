@@ -0,0 +1,268 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use serde::{
+    Deserialize,
+    de::{IntoDeserializer, value::Error as DeserializeError},
+};
+
+use crate::{
+    domain::{
+        models::{
+            activity::{ActivityId, DefaultStreams, TimeseriesMetric},
+            preferences::{Preference, PreferenceKey},
+        },
+        ports::{
+            activity::{GetActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivityTimeseries;
+use crate::inbound::http::handlers::check_owner;
+
+#[derive(Debug, Deserialize)]
+pub struct StreamsFilters {
+    /// Comma separated channel names, e.g. `Power,HeartRate`
+    channels: Option<String>,
+}
+
+/// Parse comma separated channel names, `None` if one of them is unknown.
+fn parse_channels(channels: &str) -> Option<Vec<TimeseriesMetric>> {
+    channels
+        .split(',')
+        .map(|channel| {
+            TimeseriesMetric::deserialize(channel.trim().into_deserializer())
+                .map_err(|_: DeserializeError| ())
+        })
+        .collect::<Result<_, _>>()
+        .ok()
+}
+
+/// Handler for GET /api/activity/{activity_id}/streams
+///
+/// Returns the activity's timeseries restricted to the requested `channels`. Without `channels`,
+/// the user's default streams preference is used, or [DefaultStreams::default] if not set. Fails
+/// with 400 if a channel is unknown.
+pub async fn get_activity_streams<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+    Query(filters): Query<StreamsFilters>,
+) -> Result<Json<PublicActivityTimeseries>, StatusCode> {
+    let channels = match filters.channels {
+        Some(channels) => parse_channels(&channels).ok_or(StatusCode::BAD_REQUEST)?,
+        None => match state
+            .preferences_service
+            .get_preference(user.user(), &PreferenceKey::DefaultStreams)
+            .await
+            .map_err(StatusCode::from)?
+        {
+            Some(Preference::DefaultStreams(streams)) => streams.channels().to_vec(),
+            _ => DefaultStreams::default().channels().to_vec(),
+        },
+    };
+
+    let activity = state
+        .activity_service
+        .get_activity_with_parsed_data(&activity_id)
+        .await
+        .map_err(|err| match err {
+            GetActivityError::ActivityDoesNotExist(_) => StatusCode::NOT_FOUND,
+            GetActivityError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    check_owner(activity.user(), &user)?;
+
+    let mut timeseries = PublicActivityTimeseries::from(activity.timeseries());
+    timeseries
+        .metrics
+        .retain(|name, _| channels.iter().any(|channel| channel.to_string() == *name));
+    Ok(Json(timeseries))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    ActiveTime, Activity, ActivityDuration, ActivityStartTime, ActivityStatistics,
+                    ActivityTimeseries, ActivityWithParsedData, Sport, Timeseries,
+                    TimeseriesActiveTime, TimeseriesTime, TimeseriesValue,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(preferences_service: MockPreferencesService) -> TestServer {
+        let mut service = MockActivityService::new();
+        service
+            .expect_get_activity_with_parsed_data()
+            .returning(|_| {
+                let values = |value: usize| vec![Some(TimeseriesValue::Int(value)); 3];
+                Ok(ActivityWithParsedData::new(
+                    Activity::new_empty(
+                        ActivityId::from("ride"),
+                        UserId::default(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::from(3.),
+                        Sport::Cycling,
+                    ),
+                    ActivityTimeseries::new(
+                        TimeseriesTime::new(vec![0, 1, 2]),
+                        TimeseriesActiveTime::new((0..3).map(ActiveTime::Running).collect()),
+                        vec![],
+                        vec![
+                            Timeseries::new(TimeseriesMetric::Power, values(200)),
+                            Timeseries::new(TimeseriesMetric::HeartRate, values(140)),
+                            Timeseries::new(TimeseriesMetric::Cadence, values(90)),
+                            Timeseries::new(
+                                TimeseriesMetric::Grade,
+                                vec![
+                                    Some(TimeseriesValue::Float(2.5)),
+                                    None,
+                                    Some(TimeseriesValue::Float(-1.5)),
+                                ],
+                            ),
+                            Timeseries::new(
+                                TimeseriesMetric::Temperature,
+                                vec![None, Some(TimeseriesValue::Float(21.)), None],
+                            ),
+                        ],
+                    )
+                    .unwrap(),
+                    ActivityStatistics::default(),
+                ))
+            });
+
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(preferences_service);
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/streams",
+                get(get_activity_streams::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    fn channels(body: &serde_json::Value) -> Vec<String> {
+        let mut channels = body["metrics"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        channels.sort();
+        channels
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_streams_with_requested_channels() {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service.expect_get_preference().never();
+
+        let response = test_server(preferences_service)
+            .get("/activity/ride/streams?channels=Cadence,Power")
+            .await;
+
+        response.assert_status_ok();
+        assert_eq!(channels(&response.json()), vec!["Cadence", "Power"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_streams_grade_and_temperature() {
+        let response = test_server(MockPreferencesService::new())
+            .get("/activity/ride/streams?channels=Grade,Temperature")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(
+            body["metrics"]["Grade"],
+            serde_json::json!({"unit": "%", "values": [2.5, null, -1.5]})
+        );
+        assert_eq!(
+            body["metrics"]["Temperature"],
+            serde_json::json!({"unit": "°C", "values": [null, 21.0, null]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_streams_defaults_from_preference() {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::DefaultStreams)
+            .returning(|_, _| {
+                Ok(Some(Preference::DefaultStreams(
+                    DefaultStreams::try_from(vec![TimeseriesMetric::Cadence]).unwrap(),
+                )))
+            });
+
+        let response = test_server(preferences_service)
+            .get("/activity/ride/streams")
+            .await;
+
+        response.assert_status_ok();
+        assert_eq!(channels(&response.json()), vec!["Cadence"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_streams_falls_back_to_built_in_defaults() {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_preference()
+            .returning(|_, _| Ok(None));
+
+        let response = test_server(preferences_service)
+            .get("/activity/ride/streams")
+            .await;
+
+        response.assert_status_ok();
+        assert_eq!(channels(&response.json()), vec!["HeartRate", "Power"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_streams_unknown_channel() {
+        let response = test_server(MockPreferencesService::new())
+            .get("/activity/ride/streams?channels=Power,Watts")
+            .await;
+
+        response.assert_status_bad_request();
+    }
+}
@@ -8,7 +8,7 @@ use serde::Deserialize;
 
 use crate::{
     domain::{
-        models::activity::DEFAULT_METRICS,
+        models::activity::{ActivityVisibility, DEFAULT_METRICS},
         ports::{
             DateRange,
             activity::{IActivityService, ListActivitiesFilters},
@@ -26,6 +26,7 @@ pub struct Filters {
     limit: Option<usize>,
     start_date: Option<NaiveDate>,
     end_date: Option<NaiveDate>,
+    visibility: Option<ActivityVisibility>,
 }
 
 impl From<Filters> for ListActivitiesFilters {
@@ -37,6 +38,7 @@ impl From<Filters> for ListActivitiesFilters {
         Self::empty()
             .set_limit(value.limit)
             .set_date_range(date_range)
+            .set_visibility(value.visibility)
     }
 }
 
@@ -50,9 +52,11 @@ pub async fn list_activities<
     State(state): State<AppState<AS, PF, TMS, PS>>,
     Query(filters): Query<Filters>,
 ) -> Result<Json<Vec<PublicActivity>>, StatusCode> {
+    let limit = state.page_size.limit(filters.limit);
+    let filters = ListActivitiesFilters::from(filters).set_limit(limit);
     let Ok(activities) = state
         .activity_service
-        .list_activities_with_metrics(user.user(), &filters.into(), &DEFAULT_METRICS)
+        .list_activities_with_metrics(user.user(), &filters, &DEFAULT_METRICS)
         .await
     else {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
@@ -65,3 +69,86 @@ pub async fn list_activities<
             .collect(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::services::{
+            activity::test_utils::MockActivityService,
+            preferences::tests_utils::MockPreferencesService,
+            training::test_utils::MockTrainingService,
+        },
+        inbound::{
+            auth::no_auth::DefaultUserExtractor, http::page_size::PageSize,
+            parser::test_utils::MockFileParser,
+        },
+    };
+
+    use super::*;
+
+    fn test_server(expected_limit: Option<usize>, page_size: PageSize) -> TestServer {
+        let mut service = MockActivityService::new();
+        service
+            .expect_list_activities_with_metrics()
+            .withf(move |_, filters, _| filters.limit() == &expected_limit)
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new())
+            .with_page_size(page_size);
+
+        let app = Router::new()
+            .route(
+                "/activities",
+                get(list_activities::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_without_page_sizes() {
+        test_server(None, PageSize::default())
+            .get("/activities")
+            .await
+            .assert_status_ok();
+        test_server(Some(500), PageSize::default())
+            .get("/activities?limit=500")
+            .await
+            .assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_uses_default_page_size() {
+        test_server(Some(20), PageSize::new(Some(20), Some(100)))
+            .get("/activities")
+            .await
+            .assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_clamps_limit_to_max_page_size() {
+        test_server(Some(100), PageSize::new(Some(20), Some(100)))
+            .get("/activities?limit=500")
+            .await
+            .assert_status_ok();
+        test_server(Some(50), PageSize::new(Some(20), Some(100)))
+            .get("/activities?limit=50")
+            .await
+            .assert_status_ok();
+    }
+}
@@ -0,0 +1,255 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        models::activity::{ActivityId, ActivityMetricsV2, ActivityStartTime, ActivityWithSummary},
+        ports::{
+            activity::{ActivitiesFeedCursor, ActivitiesFeedFilters, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::{PublicActivity, PublicSessionSummary};
+
+/// Number of activities per page when neither the request nor the page size sets one.
+const DEFAULT_FEED_LIMIT: usize = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct FeedQuery {
+    limit: Option<usize>,
+    before: Option<DateTime<FixedOffset>>,
+    before_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PublicFeedItem {
+    #[serde(flatten)]
+    activity: PublicActivity,
+    summary: PublicSessionSummary,
+}
+
+impl From<&ActivityWithSummary> for PublicFeedItem {
+    fn from(item: &ActivityWithSummary) -> Self {
+        Self {
+            activity: PublicActivity::from(item.activity(), &ActivityMetricsV2::default()),
+            summary: item.summary().into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PublicFeed {
+    activities: Vec<PublicFeedItem>,
+    /// Values of `before` and `before_id` to fetch the next page, `null` when there are no more
+    /// activities.
+    next_before: Option<DateTime<FixedOffset>>,
+    next_before_id: Option<String>,
+}
+
+/// Handler for GET /api/activities/feed
+///
+/// Returns the most recent activities, newest first, each with a summary of its statistics. The
+/// next page is requested by passing the returned `next_before` and `next_before_id` as `before`
+/// and `before_id`, activities sharing the same start time being ordered by id.
+pub async fn list_activities_feed<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Query(query): Query<FeedQuery>,
+) -> Result<Json<PublicFeed>, StatusCode> {
+    let limit = state
+        .page_size
+        .limit(query.limit)
+        .unwrap_or(DEFAULT_FEED_LIMIT);
+    if limit == 0 {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let before = query.before.map(|start_time| {
+        ActivitiesFeedCursor::new(
+            ActivityStartTime::new(start_time),
+            query.before_id.as_deref().map(ActivityId::from),
+        )
+    });
+    let filters = ActivitiesFeedFilters::new(limit, before);
+
+    let Ok(activities) = state
+        .activity_service
+        .list_activities_feed(user.user(), &filters)
+        .await
+    else {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    let last = activities
+        .last()
+        .filter(|_| activities.len() == limit)
+        .map(ActivityWithSummary::activity);
+
+    Ok(Json(PublicFeed {
+        activities: activities.iter().map(PublicFeedItem::from).collect(),
+        next_before: last.map(|activity| *activity.start_time().datetime()),
+        next_before_id: last.map(|activity| activity.id().to_string()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    Activity, ActivityDuration, ActivityStatistic, ActivityStatistics, Sport,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{
+            auth::no_auth::DefaultUserExtractor, http::page_size::PageSize,
+            parser::test_utils::MockFileParser,
+        },
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService, page_size: PageSize) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new())
+            .with_page_size(page_size);
+
+        let app = Router::new()
+            .route(
+                "/activities/feed",
+                get(list_activities_feed::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    fn feed_item(id: &str, start: usize, distance: f64) -> ActivityWithSummary {
+        ActivityWithSummary::new(
+            Activity::new_empty(
+                ActivityId::from(id),
+                UserId::default(),
+                ActivityStartTime::from_timestamp(start).unwrap(),
+                ActivityDuration::from(60.),
+                Sport::Running,
+            ),
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, distance)])),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_feed_keeps_order_and_includes_summaries() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_list_activities_feed()
+            .withf(|_, filters| filters.limit() == 2 && filters.before().is_none())
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![
+                    feed_item("newest", 2000, 10000.),
+                    feed_item("oldest", 1000, 5000.),
+                ])
+            });
+
+        let response = test_server(service, PageSize::default())
+            .get("/activities/feed?limit=2")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        let activities = body["activities"].as_array().unwrap();
+        assert_eq!(activities.len(), 2);
+        assert_eq!(activities[0]["id"], "newest");
+        assert_eq!(activities[0]["summary"]["sport"], "Running");
+        assert_eq!(activities[0]["summary"]["statistics"]["Distance"], 10000.);
+        assert_eq!(activities[1]["id"], "oldest");
+        assert_eq!(activities[1]["summary"]["statistics"]["Distance"], 5000.);
+        assert_eq!(body["next_before"], activities[1]["start_time"]);
+        assert_eq!(body["next_before_id"], "oldest");
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_feed_passes_before_cursor() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_list_activities_feed()
+            .withf(|_, filters| {
+                filters.limit() == DEFAULT_FEED_LIMIT
+                    && filters.before()
+                        == &Some(ActivitiesFeedCursor::new(
+                            ActivityStartTime::new("2025-09-03T08:00:00+02:00".parse().unwrap()),
+                            Some(ActivityId::from("previous")),
+                        ))
+            })
+            .times(1)
+            .returning(|_, _| Ok(vec![feed_item("last", 1000, 5000.)]));
+
+        let response = test_server(service, PageSize::default())
+            .get("/activities/feed?before=2025-09-03T08:00:00%2B02:00&before_id=previous")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["activities"].as_array().unwrap().len(), 1);
+        assert_eq!(body["next_before"], serde_json::Value::Null);
+        assert_eq!(body["next_before_id"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_feed_clamps_limit_to_max_page_size() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_list_activities_feed()
+            .withf(|_, filters| filters.limit() == 100)
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+
+        test_server(service, PageSize::new(Some(20), Some(100)))
+            .get("/activities/feed?limit=500")
+            .await
+            .assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_feed_rejects_zero_limit() {
+        let mut service = MockActivityService::new();
+        service.expect_list_activities_feed().never();
+
+        test_server(service, PageSize::default())
+            .get("/activities/feed?limit=0")
+            .await
+            .assert_status_bad_request();
+    }
+}
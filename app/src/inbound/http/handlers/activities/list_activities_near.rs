@@ -0,0 +1,166 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        models::activity::{BoundingBox, DEFAULT_METRICS},
+        ports::{
+            activity::{IActivityService, ListActivitiesFilters},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivity;
+
+#[derive(Debug, Deserialize)]
+pub struct AreaFilters {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+/// Handler for GET /api/activities/near
+///
+/// Returns the activities whose track's bounding box intersects the requested area, in degrees.
+/// Activities without position are never returned. Fails with 400 if the area is invalid.
+pub async fn list_activities_near<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Query(area): Query<AreaFilters>,
+) -> Result<Json<Vec<PublicActivity>>, StatusCode> {
+    let bounding_box = BoundingBox::new(area.min_lat, area.min_lon, area.max_lat, area.max_lon)
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let Ok(activities) = state
+        .activity_service
+        .list_activities_with_metrics(
+            user.user(),
+            &ListActivitiesFilters::empty().set_bounding_box(Some(bounding_box)),
+            &DEFAULT_METRICS,
+        )
+        .await
+    else {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    Ok(Json(
+        activities
+            .iter()
+            .map(|(activity, metrics)| PublicActivity::from(activity, metrics))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    Activity, ActivityDuration, ActivityId, ActivityMetricsV2, ActivityStartTime,
+                    Sport,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activities/near",
+                get(list_activities_near::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_near() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_list_activities_with_metrics()
+            .withf(|_, filters, _| {
+                filters.bounding_box() == &Some(BoundingBox::new(45., 5., 46., 6.).unwrap())
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(vec![(
+                    Activity::new_empty(
+                        ActivityId::from("inside"),
+                        UserId::default(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::from(60.),
+                        Sport::Cycling,
+                    ),
+                    ActivityMetricsV2::default(),
+                )])
+            });
+
+        let response = test_server(service)
+            .get("/activities/near?min_lat=45&min_lon=5&max_lat=46&max_lon=6")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["id"], "inside");
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_near_invalid_area() {
+        let mut service = MockActivityService::new();
+        service.expect_list_activities_with_metrics().never();
+        let server = test_server(service);
+
+        server
+            .get("/activities/near?min_lat=46&min_lon=5&max_lat=45&max_lon=6")
+            .await
+            .assert_status_bad_request();
+        server
+            .get("/activities/near?min_lat=45&min_lon=5&max_lat=95&max_lon=6")
+            .await
+            .assert_status_bad_request();
+        server
+            .get("/activities/near?min_lat=45&min_lon=5")
+            .await
+            .assert_status_bad_request();
+    }
+}
@@ -0,0 +1,214 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        models::activity::{ActivityId, TimeseriesMetric},
+        ports::{
+            activity::{IActivityService, MergeActivityStreamError, MergeActivityStreamRequest},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivityWithTimeseries;
+use crate::inbound::http::handlers::NOT_OWNED;
+
+impl From<MergeActivityStreamError> for StatusCode {
+    fn from(value: MergeActivityStreamError) -> Self {
+        match value {
+            MergeActivityStreamError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            MergeActivityStreamError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
+            _ => Self::UNPROCESSABLE_ENTITY,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeStreamBody {
+    /// ID of the activity to take the channel values from
+    source: ActivityId,
+    /// Timeseries to merge, e.g. `HeartRate`
+    channel: TimeseriesMetric,
+}
+
+/// Handler for POST /api/activity/{activity_id}/merge-stream
+///
+/// Returns the activity with the missing values of `channel` filled from the `source` activity,
+/// both being aligned on their timestamps. The merged activity is not persisted.
+///
+/// # Example
+/// POST /api/activity/123/merge-stream
+/// Body: {"source": "456", "channel": "HeartRate"}
+pub async fn merge_activity_stream<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+    Json(body): Json<MergeStreamBody>,
+) -> Result<Json<PublicActivityWithTimeseries>, StatusCode> {
+    let req = MergeActivityStreamRequest::new(
+        user.user().clone(),
+        activity_id,
+        body.source,
+        body.channel,
+    );
+
+    let (activity, metrics) = state
+        .activity_service
+        .merge_activity_stream(req)
+        .await
+        .map_err(StatusCode::from)?;
+
+    Ok(Json(PublicActivityWithTimeseries::from(
+        &activity, &metrics,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+    use mockall::predicate::function;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    ActiveTime, Activity, ActivityDuration, ActivityMetricsV2, ActivityStartTime,
+                    ActivityStatistics, ActivityTimeseries, ActivityWithParsedData, Sport,
+                    Timeseries, TimeseriesActiveTime, TimeseriesTime, TimeseriesValue,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/merge-stream",
+                post(
+                    merge_activity_stream::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_merge_stream_returns_merged_activity() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_merge_activity_stream()
+            .times(1)
+            .with(function(|req: &MergeActivityStreamRequest| {
+                req.activity() == &ActivityId::from("ride")
+                    && req.source() == &ActivityId::from("hr")
+                    && req.metric() == &TimeseriesMetric::HeartRate
+            }))
+            .returning(|_| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("ride"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(2.),
+                            Sport::Cycling,
+                        ),
+                        ActivityTimeseries::new(
+                            TimeseriesTime::new(vec![0, 1]),
+                            TimeseriesActiveTime::new(vec![
+                                ActiveTime::Running(0),
+                                ActiveTime::Running(1),
+                            ]),
+                            vec![],
+                            vec![Timeseries::new(
+                                TimeseriesMetric::HeartRate,
+                                vec![None, Some(TimeseriesValue::Int(120))],
+                            )],
+                        )
+                        .unwrap(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::default(),
+                ))
+            });
+
+        let response = test_server(service)
+            .post("/activity/ride/merge-stream")
+            .json(&serde_json::json!({"source": "hr", "channel": "HeartRate"}))
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["id"], "ride");
+        assert_eq!(
+            body["timeseries"]["metrics"]["HeartRate"]["values"],
+            serde_json::json!([null, 120])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_stream_source_not_owned_returns_forbidden() {
+        let mut service = MockActivityService::new();
+        service.expect_merge_activity_stream().returning(|req| {
+            Err(MergeActivityStreamError::UserDoesNotOwnActivity(
+                req.user().clone(),
+                req.source().clone(),
+            ))
+        });
+
+        let response = test_server(service)
+            .post("/activity/ride/merge-stream")
+            .json(&serde_json::json!({"source": "hr", "channel": "HeartRate"}))
+            .await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_merge_stream_unknown_channel_returns_422() {
+        let mut service = MockActivityService::new();
+        service.expect_merge_activity_stream().never();
+
+        let response = test_server(service)
+            .post("/activity/ride/merge-stream")
+            .json(&serde_json::json!({"source": "hr", "channel": "Oxygen"}))
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
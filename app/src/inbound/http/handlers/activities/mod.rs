@@ -1,16 +1,46 @@
 pub mod activity_schema;
 mod create_standalone_activity;
 mod delete_activity;
+mod export_ndjson;
 mod get_activity;
+mod get_beat_intervals;
+mod get_pace_zones;
+mod get_polyline;
+mod get_power_zones;
+mod get_public_activity;
 mod get_raw;
+mod get_streams;
 mod list_activities;
+mod list_activities_feed;
+mod list_activities_near;
+mod merge_stream;
 mod patch_activity;
+mod replace_raw;
+mod reverse_activity;
+mod trim_activity;
 mod upload_activity;
+mod upload_events;
+mod validate_activity;
 
 pub use create_standalone_activity::create_standalone_activity;
 pub use delete_activity::delete_activity;
+pub use export_ndjson::export_activity_ndjson;
 pub use get_activity::get_activity;
+pub use get_beat_intervals::get_activity_beat_intervals;
+pub use get_pace_zones::get_activity_pace_zones;
+pub use get_polyline::get_activity_polyline;
+pub use get_power_zones::get_activity_power_zones;
+pub use get_public_activity::get_public_activity;
 pub use get_raw::{get_all_raw_activities, get_raw_activity};
+pub use get_streams::get_activity_streams;
 pub use list_activities::list_activities;
+pub use list_activities_feed::list_activities_feed;
+pub use list_activities_near::list_activities_near;
+pub use merge_stream::merge_activity_stream;
 pub use patch_activity::patch_activity;
+pub use replace_raw::replace_raw_activity;
+pub use reverse_activity::reverse_activity;
+pub use trim_activity::trim_activity;
 pub use upload_activity::upload_activities;
+pub use upload_events::get_upload_job_events;
+pub use validate_activity::validate_activity;
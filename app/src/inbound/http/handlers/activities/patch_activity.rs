@@ -5,17 +5,19 @@ use axum::{
 };
 use serde::Deserialize;
 
+use crate::inbound::http::handlers::NOT_OWNED;
 use crate::{
     domain::{
         models::activity::{
-            ActivityFeedback, ActivityId, ActivityName, ActivityNutrition, ActivityRpe, BonkStatus,
-            WorkoutType,
+            ActivityFeedback, ActivityId, ActivityName, ActivityNutrition, ActivityRpe,
+            ActivityVisibility, BonkStatus, WorkoutType,
         },
         ports::activity::{
             IActivityService, ModifyActivityError, ModifyActivityRequest,
             UpdateActivityFeedbackError, UpdateActivityFeedbackRequest,
             UpdateActivityNutritionError, UpdateActivityNutritionRequest, UpdateActivityRpeError,
-            UpdateActivityRpeRequest, UpdateActivityWorkoutTypeError,
+            UpdateActivityRpeRequest, UpdateActivityVisibilityError,
+            UpdateActivityVisibilityRequest, UpdateActivityWorkoutTypeError,
             UpdateActivityWorkoutTypeRequest,
         },
         ports::preferences::IPreferencesService,
@@ -28,6 +30,7 @@ impl From<ModifyActivityError> for StatusCode {
     fn from(value: ModifyActivityError) -> Self {
         match value {
             ModifyActivityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            ModifyActivityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -37,6 +40,7 @@ impl From<UpdateActivityRpeError> for StatusCode {
     fn from(value: UpdateActivityRpeError) -> Self {
         match value {
             UpdateActivityRpeError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateActivityRpeError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -46,6 +50,7 @@ impl From<UpdateActivityWorkoutTypeError> for StatusCode {
     fn from(value: UpdateActivityWorkoutTypeError) -> Self {
         match value {
             UpdateActivityWorkoutTypeError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateActivityWorkoutTypeError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -55,6 +60,7 @@ impl From<UpdateActivityNutritionError> for StatusCode {
     fn from(value: UpdateActivityNutritionError) -> Self {
         match value {
             UpdateActivityNutritionError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateActivityNutritionError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -64,6 +70,17 @@ impl From<UpdateActivityFeedbackError> for StatusCode {
     fn from(value: UpdateActivityFeedbackError) -> Self {
         match value {
             UpdateActivityFeedbackError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateActivityFeedbackError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
+            _ => Self::UNPROCESSABLE_ENTITY,
+        }
+    }
+}
+
+impl From<UpdateActivityVisibilityError> for StatusCode {
+    fn from(value: UpdateActivityVisibilityError) -> Self {
+        match value {
+            UpdateActivityVisibilityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateActivityVisibilityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
             _ => Self::UNPROCESSABLE_ENTITY,
         }
     }
@@ -84,6 +101,8 @@ pub struct PatchActivityQuery {
     bonk_status: Option<String>,
     /// Optional nutrition details/notes
     nutrition_details: Option<String>,
+    /// Optional visibility: private, unlisted or public
+    visibility: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -101,6 +120,7 @@ pub struct PatchActivityBody {
 /// - `workout_type`: Set workout type (easy, tempo, intervals, long_run, race), or empty string to clear (query parameter)
 /// - `bonk_status`: Set bonk status (none, bonked), or empty string to clear nutrition info (query parameter)
 /// - `nutrition_details`: Optional details about nutrition/hydration (query parameter)
+/// - `visibility`: Set visibility (private, unlisted, public) (query parameter)
 /// - `feedback`: Optional feedback/notes about the activity (request body, JSON)
 ///
 /// # Example
@@ -112,6 +132,7 @@ pub struct PatchActivityBody {
 /// PATCH /api/activity/123?bonk_status=bonked&nutrition_details=Forgot%20to%20eat
 /// PATCH /api/activity/123?bonk_status=none
 /// PATCH /api/activity/123?bonk_status=  // Clear nutrition info
+/// PATCH /api/activity/123?visibility=public
 ///
 /// With request body for feedback:
 /// PATCH /api/activity/123
@@ -127,7 +148,7 @@ pub async fn patch_activity<
 >(
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
-    Path(activity_id): Path<String>,
+    Path(activity_id): Path<ActivityId>,
     Query(query): Query<PatchActivityQuery>,
     body: Option<Json<PatchActivityBody>>,
 ) -> Result<StatusCode, StatusCode> {
@@ -135,7 +156,7 @@ pub async fn patch_activity<
     if let Some(name) = query.name {
         let req = ModifyActivityRequest::new(
             user.user().clone(),
-            ActivityId::from(&activity_id),
+            activity_id.clone(),
             Some(ActivityName::new(name)),
         );
 
@@ -154,8 +175,7 @@ pub async fn patch_activity<
             Some(ActivityRpe::try_from(rpe_value).map_err(|_| StatusCode::BAD_REQUEST)?)
         };
 
-        let req =
-            UpdateActivityRpeRequest::new(user.user().clone(), ActivityId::from(&activity_id), rpe);
+        let req = UpdateActivityRpeRequest::new(user.user().clone(), activity_id.clone(), rpe);
 
         state
             .activity_service
@@ -178,7 +198,7 @@ pub async fn patch_activity<
 
         let req = UpdateActivityWorkoutTypeRequest::new(
             user.user().clone(),
-            ActivityId::from(&activity_id),
+            activity_id.clone(),
             workout_type,
         );
 
@@ -206,7 +226,7 @@ pub async fn patch_activity<
 
         let req = UpdateActivityNutritionRequest::new(
             user.user().clone(),
-            ActivityId::from(&activity_id),
+            activity_id.clone(),
             nutrition,
         );
 
@@ -217,6 +237,25 @@ pub async fn patch_activity<
             .map_err(StatusCode::from)?;
     }
 
+    // Update activity visibility if provided
+    if let Some(visibility_str) = query.visibility {
+        let visibility = visibility_str
+            .parse::<ActivityVisibility>()
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        let req = UpdateActivityVisibilityRequest::new(
+            user.user().clone(),
+            activity_id.clone(),
+            visibility,
+        );
+
+        state
+            .activity_service
+            .update_activity_visibility(req)
+            .await
+            .map_err(StatusCode::from)?;
+    }
+
     // Update activity feedback if provided in request body
     if let Some(Json(body)) = body
         && let Some(feedback_str) = body.feedback
@@ -227,11 +266,8 @@ pub async fn patch_activity<
             Some(ActivityFeedback::from(feedback_str))
         };
 
-        let req = UpdateActivityFeedbackRequest::new(
-            user.user().clone(),
-            ActivityId::from(&activity_id),
-            feedback,
-        );
+        let req =
+            UpdateActivityFeedbackRequest::new(user.user().clone(), activity_id.clone(), feedback);
 
         state
             .activity_service
@@ -256,6 +292,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         };
         assert_eq!(query.rpe, Some(0));
     }
@@ -315,6 +352,7 @@ mod tests {
             workout_type: Some(String::new()),
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         };
         assert_eq!(query.workout_type, Some(String::new()));
     }
@@ -352,6 +390,7 @@ mod tests {
             workout_type: None,
             bonk_status: Some(String::new()),
             nutrition_details: None,
+            visibility: None,
         };
         assert_eq!(query.bonk_status, Some(String::new()));
     }
@@ -398,17 +437,14 @@ mod tests {
         activity_service: MockActivityService,
     ) -> AppState<MockActivityService, MockFileParser, MockTrainingService, MockPreferencesService>
     {
-        AppState {
-            activity_service: Arc::new(activity_service),
-            file_parser: Arc::new(MockFileParser::test_default()),
-            training_metrics_service: Arc::new(MockTrainingService::test_default()),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        }
+        AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_preferences_service(MockPreferencesService::new())
     }
 
     #[tokio::test]
     async fn test_patch_activity_update_feedback_ok() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
         let activity_id_clone = activity_id.clone();
         let user_id = UserId::from("test_user");
 
@@ -417,7 +453,7 @@ mod tests {
             .expect_update_activity_feedback()
             .with(function(move |req: &UpdateActivityFeedbackRequest| {
                 req.user() == &user_id
-                    && req.activity() == &ActivityId::from(&activity_id_clone)
+                    && req.activity() == &activity_id_clone
                     && req.feedback() == &Some(ActivityFeedback::from("Great session today!"))
             }))
             .times(1)
@@ -433,6 +469,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some("Great session today!".to_string()),
@@ -446,7 +483,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_clear_feedback() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
         let activity_id_clone = activity_id.clone();
         let user_id = UserId::from("test_user");
 
@@ -455,7 +492,7 @@ mod tests {
             .expect_update_activity_feedback()
             .with(function(move |req: &UpdateActivityFeedbackRequest| {
                 req.user() == &user_id
-                    && req.activity() == &ActivityId::from(&activity_id_clone)
+                    && req.activity() == &activity_id_clone
                     && req.feedback().is_none()
             }))
             .times(1)
@@ -471,6 +508,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some(String::new()), // Empty string clears feedback
@@ -484,7 +522,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_no_feedback_in_body() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
 
         let mut activity_service = MockActivityService::new();
         // Should not call update_activity_feedback when body has feedback: None
@@ -500,6 +538,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody { feedback: None }));
 
@@ -511,7 +550,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_no_body() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
 
         let mut activity_service = MockActivityService::new();
         // Should not call update_activity_feedback when no body is provided
@@ -527,6 +566,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
 
         let body = None;
@@ -539,7 +579,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_feedback_activity_not_found() {
-        let activity_id = "nonexistent_activity".to_string();
+        let activity_id = ActivityId::from("nonexistent_activity");
 
         let mut activity_service = MockActivityService::new();
         activity_service
@@ -561,6 +601,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some("This won't work".to_string()),
@@ -574,7 +615,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_feedback_wrong_user() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
         let user_id = UserId::from("test_user");
 
         let mut activity_service = MockActivityService::new();
@@ -598,6 +639,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some("Wrong user feedback".to_string()),
@@ -606,12 +648,12 @@ mod tests {
         let result = patch_activity(Extension(user), State(state), path, query, body).await;
 
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(result.unwrap_err(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
     async fn test_patch_activity_long_feedback() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
         let activity_id_clone = activity_id.clone();
         let user_id = UserId::from("test_user");
         let long_feedback = "This is a very long feedback message. ".repeat(100); // ~3800 chars
@@ -621,7 +663,7 @@ mod tests {
             .expect_update_activity_feedback()
             .with(function(move |req: &UpdateActivityFeedbackRequest| {
                 req.user() == &user_id
-                    && req.activity() == &ActivityId::from(&activity_id_clone)
+                    && req.activity() == &activity_id_clone
                     && req.feedback().is_some()
             }))
             .times(1)
@@ -637,6 +679,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some(long_feedback),
@@ -650,7 +693,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_patch_activity_combine_rpe_and_feedback() {
-        let activity_id = "test_activity_id".to_string();
+        let activity_id = ActivityId::from("test_activity_id");
         let activity_id_clone = activity_id.clone();
         let user_id = UserId::from("test_user");
 
@@ -664,7 +707,7 @@ mod tests {
             .expect_update_activity_feedback()
             .with(function(move |req: &UpdateActivityFeedbackRequest| {
                 req.user() == &user_id
-                    && req.activity() == &ActivityId::from(&activity_id_clone)
+                    && req.activity() == &activity_id_clone
                     && req.feedback() == &Some(ActivityFeedback::from("Hard session"))
             }))
             .times(1)
@@ -680,6 +723,7 @@ mod tests {
             workout_type: None,
             bonk_status: None,
             nutrition_details: None,
+            visibility: None,
         });
         let body = Some(Json(PatchActivityBody {
             feedback: Some("Hard session".to_string()),
@@ -690,4 +734,75 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_patch_activity_update_visibility_ok() {
+        let activity_id = ActivityId::from("test_activity_id");
+        let activity_id_clone = activity_id.clone();
+
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_update_activity_visibility()
+            .with(function(move |req: &UpdateActivityVisibilityRequest| {
+                req.user() == &UserId::from("test_user")
+                    && req.activity() == &activity_id_clone
+                    && req.visibility() == &ActivityVisibility::Public
+            }))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let state = create_test_state(activity_service);
+
+        let user = AuthenticatedUser::new(UserId::from("test_user"));
+        let query = Query(PatchActivityQuery {
+            name: None,
+            rpe: None,
+            workout_type: None,
+            bonk_status: None,
+            nutrition_details: None,
+            visibility: Some("public".to_string()),
+        });
+
+        let result = patch_activity(
+            Extension(user),
+            State(state),
+            Path(activity_id),
+            query,
+            None,
+        )
+        .await;
+
+        assert_eq!(result, Ok(StatusCode::OK));
+    }
+
+    #[tokio::test]
+    async fn test_patch_activity_invalid_visibility() {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_update_activity_visibility()
+            .times(0);
+
+        let state = create_test_state(activity_service);
+
+        let user = AuthenticatedUser::new(UserId::from("test_user"));
+        let query = Query(PatchActivityQuery {
+            name: None,
+            rpe: None,
+            workout_type: None,
+            bonk_status: None,
+            nutrition_details: None,
+            visibility: Some("friends".to_string()),
+        });
+
+        let result = patch_activity(
+            Extension(user),
+            State(state),
+            Path(ActivityId::from("test_activity_id")),
+            query,
+            None,
+        )
+        .await;
+
+        assert_eq!(result, Err(StatusCode::BAD_REQUEST));
+    }
 }
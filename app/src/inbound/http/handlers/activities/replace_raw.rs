@@ -0,0 +1,188 @@
+use axum::{
+    Extension,
+    extract::{Multipart, Path, State},
+    http::{
+        StatusCode,
+        header::{ETAG, RETRY_AFTER},
+    },
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{IActivityService, ReplaceRawActivityError, ReplaceRawActivityRequest},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::{
+    get_raw::raw_content_etag,
+    upload_activity::{UPLOAD_RETRY_AFTER_SECONDS, extract_content, extract_extension},
+};
+use crate::inbound::http::handlers::NOT_OWNED;
+
+impl From<ReplaceRawActivityError> for StatusCode {
+    fn from(value: ReplaceRawActivityError) -> Self {
+        match value {
+            ReplaceRawActivityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            ReplaceRawActivityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
+            ReplaceRawActivityError::Unknown(_) => Self::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Handler for PUT /api/activity/{activity_id}/raw
+///
+/// Replace the raw file of an activity with the first file of the multipart body. The new file is
+/// parsed and everything derived from it is refreshed. The `ETag` of the new raw file, as returned
+/// by `GET /api/activity/{activity_id}/download`, is returned.
+pub async fn replace_raw_activity<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+    mut multipart: Multipart,
+) -> Result<Response, StatusCode> {
+    let Some(_ticket) = state.upload_limiter.try_enter() else {
+        tracing::warn!("Upload queue is full, rejecting raw file replacement");
+        return Ok((
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, UPLOAD_RETRY_AFTER_SECONDS)],
+        )
+            .into_response());
+    };
+
+    let Ok(Some(field)) = multipart.next_field().await else {
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    let name = field
+        .name()
+        .map(|name| name.to_string())
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let extension = extract_extension(&name).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let content = extract_content(&name, field)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    let etag = raw_content_etag(&content);
+
+    let file_parser = state.file_parser.clone();
+    let parsed_content = state
+        .upload_limiter
+        .parse(move || file_parser.try_bytes_into_domain(&extension, content))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    let req = ReplaceRawActivityRequest::new(activity_id, parsed_content.into_request(user.user()));
+    state
+        .activity_service
+        .replace_raw_activity(req)
+        .await
+        .map_err(StatusCode::from)?;
+
+    Ok((StatusCode::NO_CONTENT, [(ETAG, etag)]).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::put};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{Activity, ActivityDuration, ActivityStartTime, Sport},
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn server(activity_service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
+        let app = Router::new()
+            .route("/activity/{activity_id}/raw", put(replace_raw_activity))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_replace_raw_activity_returns_new_etag() {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_replace_raw_activity()
+            .times(1)
+            .withf(|req| {
+                req.activity() == &ActivityId::from("activity")
+                    && req.content().sport() == &Sport::Cycling
+            })
+            .returning(|req| {
+                Ok(Activity::new_empty(
+                    req.activity().clone(),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::from(3.0),
+                    Sport::Cycling,
+                ))
+            });
+
+        let response = server(activity_service)
+            .put("/activity/activity/raw")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "new.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"new content".to_vec()),
+            ))
+            .await;
+
+        response.assert_status(StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.header(ETAG).to_str().unwrap(),
+            raw_content_etag(b"new content")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_raw_activity_not_owned() {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_replace_raw_activity()
+            .returning(|req| {
+                Err(ReplaceRawActivityError::UserDoesNotOwnActivity(
+                    req.user().clone(),
+                    req.activity().clone(),
+                ))
+            });
+
+        let response = server(activity_service)
+            .put("/activity/activity/raw")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "new.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"new content".to_vec()),
+            ))
+            .await;
+
+        response.assert_status_not_found();
+    }
+}
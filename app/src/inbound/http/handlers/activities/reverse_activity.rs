@@ -0,0 +1,159 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{IActivityService, ReverseActivityError, ReverseActivityRequest},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivityWithTimeseries;
+use crate::inbound::http::handlers::NOT_OWNED;
+
+impl From<ReverseActivityError> for StatusCode {
+    fn from(value: ReverseActivityError) -> Self {
+        match value {
+            ReverseActivityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            ReverseActivityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
+            ReverseActivityError::InvalidData(_) => Self::UNPROCESSABLE_ENTITY,
+            ReverseActivityError::Unknown(_) => Self::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Handler for POST /api/activity/{activity_id}/reverse
+///
+/// Returns the activity with its points in reverse order, for tracks recorded with reversed
+/// timestamps. Fails with 422 if the activity has no positions. The reversed activity is not
+/// persisted.
+pub async fn reverse_activity<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+) -> Result<Json<PublicActivityWithTimeseries>, StatusCode> {
+    let req = ReverseActivityRequest::new(user.user().clone(), activity_id);
+
+    let (activity, metrics) = state
+        .activity_service
+        .reverse_activity(req)
+        .await
+        .map_err(StatusCode::from)?;
+
+    Ok(Json(PublicActivityWithTimeseries::from(
+        &activity, &metrics,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+    use mockall::predicate::function;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    Activity, ActivityDuration, ActivityMetricsV2, ActivityStartTime,
+                    ActivityStatistics, ActivityTimeseries, ActivityWithParsedData,
+                    ReverseTimeseriesError, Sport,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/reverse",
+                post(
+                    reverse_activity::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_reverse_activity_returns_reversed_activity() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_reverse_activity()
+            .times(1)
+            .with(function(|req: &ReverseActivityRequest| {
+                req.activity() == &ActivityId::from("run") && req.user() == &UserId::default()
+            }))
+            .returning(|_| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("run"),
+                            UserId::default(),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(1200.),
+                            Sport::Running,
+                        ),
+                        ActivityTimeseries::default(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::default(),
+                ))
+            });
+
+        let response = test_server(service).post("/activity/run/reverse").await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["id"], "run");
+    }
+
+    #[tokio::test]
+    async fn test_reverse_activity_without_positions_returns_422() {
+        let mut service = MockActivityService::new();
+        service.expect_reverse_activity().returning(|_| {
+            Err(ReverseActivityError::InvalidData(
+                ReverseTimeseriesError::NoPosition,
+            ))
+        });
+
+        let response = test_server(service).post("/activity/run/reverse").await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1,201 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        models::activity::ActivityId,
+        ports::{
+            activity::{IActivityService, TrimActivityError, TrimActivityRequest},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+use super::activity_schema::PublicActivityWithTimeseries;
+use crate::inbound::http::handlers::NOT_OWNED;
+
+impl From<TrimActivityError> for StatusCode {
+    fn from(value: TrimActivityError) -> Self {
+        match value {
+            TrimActivityError::ActivityDoesNotExist(_) => Self::NOT_FOUND,
+            TrimActivityError::UserDoesNotOwnActivity(_, _) => NOT_OWNED,
+            TrimActivityError::InvalidWindow(_) => Self::UNPROCESSABLE_ENTITY,
+            TrimActivityError::Unknown(_) => Self::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrimActivityBody {
+    /// Meters to remove at the start of the activity
+    #[serde(default)]
+    start: f64,
+    /// Meters to remove at the end of the activity
+    #[serde(default)]
+    end: f64,
+}
+
+/// Handler for POST /api/activity/{activity_id}/trim
+///
+/// Returns the activity without its first `start` and last `end` meters, with its metrics
+/// recomputed. The trimmed activity is not persisted.
+///
+/// # Example
+/// POST /api/activity/123/trim
+/// Body: {"start": 500, "end": 200}
+pub async fn trim_activity<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(activity_id): Path<ActivityId>,
+    Json(body): Json<TrimActivityBody>,
+) -> Result<Json<PublicActivityWithTimeseries>, StatusCode> {
+    let req = TrimActivityRequest::new(user.user().clone(), activity_id, body.start, body.end);
+
+    let (activity, metrics) = state
+        .activity_service
+        .trim_activity(req)
+        .await
+        .map_err(StatusCode::from)?;
+
+    Ok(Json(PublicActivityWithTimeseries::from(
+        &activity, &metrics,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+    use mockall::predicate::function;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    Activity, ActivityDuration, ActivityMetricV2, ActivityMetricsV2,
+                    ActivityStartTime, ActivityStatistics, ActivityTimeseries,
+                    ActivityWithParsedData, Sport, TrimByDistanceError,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/activity/{activity_id}/trim",
+                post(
+                    trim_activity::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_trim_activity_returns_trimmed_activity() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_trim_activity()
+            .times(1)
+            .with(function(|req: &TrimActivityRequest| {
+                req.activity() == &ActivityId::from("run") && req.start() == 500. && req.end() == 0.
+            }))
+            .returning(|_| {
+                Ok((
+                    ActivityWithParsedData::new(
+                        Activity::new_empty(
+                            ActivityId::from("run"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1000).unwrap(),
+                            ActivityDuration::from(1200.),
+                            Sport::Running,
+                        ),
+                        ActivityTimeseries::default(),
+                        ActivityStatistics::default(),
+                    ),
+                    ActivityMetricsV2::new(HashMap::from([(
+                        ActivityMetricV2::Distance,
+                        Some(4500.),
+                    )])),
+                ))
+            });
+
+        let response = test_server(service)
+            .post("/activity/run/trim")
+            .json(&serde_json::json!({"start": 500}))
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["id"], "run");
+        assert_eq!(body["metrics"]["Distance"], 4500.);
+    }
+
+    #[tokio::test]
+    async fn test_trim_activity_invalid_window_returns_422() {
+        let mut service = MockActivityService::new();
+        service.expect_trim_activity().returning(|_| {
+            Err(TrimActivityError::InvalidWindow(
+                TrimByDistanceError::InvalidWindow(600., 600., 1000.),
+            ))
+        });
+
+        let response = test_server(service)
+            .post("/activity/run/trim")
+            .json(&serde_json::json!({"start": 600, "end": 600}))
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_trim_activity_not_found() {
+        let mut service = MockActivityService::new();
+        service.expect_trim_activity().returning(|req| {
+            Err(TrimActivityError::ActivityDoesNotExist(
+                req.activity().clone(),
+            ))
+        });
+
+        let response = test_server(service)
+            .post("/activity/run/trim")
+            .json(&serde_json::json!({"start": 100}))
+            .await;
+
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+}
@@ -3,32 +3,57 @@ use std::io::Read;
 use anyhow::anyhow;
 use axum::{
     Extension, Json,
-    extract::{Multipart, State, multipart::Field},
-    http::StatusCode,
+    extract::{Multipart, Query, State, multipart::Field},
+    http::{StatusCode, header::RETRY_AFTER},
     response::IntoResponse,
 };
 use flate2::read::GzDecoder;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::ports::{
-        activity::{CreateActivityError, IActivityService},
-        preferences::IPreferencesService,
-        training::ITrainingService,
+    domain::{
+        models::{
+            UserId,
+            activity::{
+                ActivityVisibility, AthleteProfile, CommuteLocations, MaxGpsAccuracy, Sport,
+            },
+            preferences::{Preference, PreferenceKey},
+        },
+        ports::{
+            activity::{CreateActivityError, IActivityService},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
     },
     inbound::{
         auth::AuthenticatedUser,
-        http::AppState,
+        http::{AppState, upload_jobs::UploadJobStatus},
         parser::{ParseBytesError, ParseFile, SupportedExtension},
     },
 };
 
+/// Optional multipart field used to override the sport parsed from the uploaded files.
+const SPORT_OVERRIDE_FIELD: &str = "sport";
+
+/// Delay, in seconds, suggested to clients when the upload queue is full.
+pub(super) const UPLOAD_RETRY_AFTER_SECONDS: &str = "5";
+
 impl From<CreateActivityError> for StatusCode {
     fn from(_value: CreateActivityError) -> Self {
         Self::UNPROCESSABLE_ENTITY
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UploadActivitiesQuery {
+    /// Optional client chosen ID to follow the upload processing through
+    /// `GET /api/activity/upload/{job_id}/events`
+    job_id: Option<String>,
+    /// Store files flagged as possible duplicates of existing activities anyway
+    #[serde(default)]
+    force: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 struct UploadActivitiesResponse {
     created_ids: Vec<String>,
@@ -40,6 +65,9 @@ enum RejectionReason {
     CannotReadContent,
     CannotProcessFile,
     DuplicatedActivity,
+    /// Near duplicate of an existing activity, whose ID is given. Uploading again with
+    /// `?force=true` stores it anyway.
+    PossibleDuplicate(String),
     IncoherentTimeseries,
     UnsupportedFileExtension,
     Unknown,
@@ -49,11 +77,19 @@ impl From<CreateActivityError> for RejectionReason {
     fn from(value: CreateActivityError) -> Self {
         match value {
             CreateActivityError::SimilarActivityExistsError => Self::DuplicatedActivity,
+            CreateActivityError::PossibleDuplicate(candidate) => {
+                Self::PossibleDuplicate(candidate.to_string())
+            }
             _ => Self::Unknown,
         }
     }
 }
 
+/// Handler for POST /api/activity
+///
+/// Files are processed one at a time as they are read from the multipart form, so that only one
+/// of them is held in memory at once. The optional `sport` field therefore only overrides the
+/// sport of the files following it in the form.
 pub async fn upload_activities<
     AS: IActivityService,
     PF: ParseFile,
@@ -62,14 +98,50 @@ pub async fn upload_activities<
 >(
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
+    Query(query): Query<UploadActivitiesQuery>,
     mut multipart: Multipart,
 ) -> Result<impl axum::response::IntoResponse, StatusCode> {
+    let Some(_ticket) = state.upload_limiter.try_enter() else {
+        tracing::warn!("Upload queue is full, rejecting upload");
+        return Ok((
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, UPLOAD_RETRY_AFTER_SECONDS)],
+        )
+            .into_response());
+    };
+
+    let job = match query.job_id {
+        Some(job_id) => Some(
+            state
+                .upload_jobs
+                .register(user.user(), &job_id)
+                .ok_or(StatusCode::CONFLICT)?,
+        ),
+        None => None,
+    };
+
+    let visibility = default_visibility(state.preferences_service.as_ref(), user.user()).await;
+    let commute_locations =
+        commute_locations(state.preferences_service.as_ref(), user.user()).await;
+    let max_gps_accuracy = max_gps_accuracy(state.preferences_service.as_ref(), user.user()).await;
+    let athlete_profile = athlete_profile(state.preferences_service.as_ref(), user.user()).await;
+
+    let mut sport_override = None;
     let mut created_ids = Vec::new();
     let mut unprocessable_files = Vec::new();
     while let Ok(Some(field)) = multipart.next_field().await {
         let Some(name) = field.name().map(|n| n.to_string()) else {
             continue;
         };
+        if name == SPORT_OVERRIDE_FIELD {
+            let value = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+            let sport = value
+                .trim()
+                .parse::<Sport>()
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            sport_override = Some(sport);
+            continue;
+        }
         let Some(extension) = extract_extension(&name) else {
             unprocessable_files.push((name.to_string(), RejectionReason::UnsupportedFileExtension));
             continue;
@@ -79,22 +151,48 @@ pub async fn upload_activities<
             continue;
         };
 
-        let parsed_content = match state
-            .file_parser
-            .try_bytes_into_domain(&extension, file_content)
-        {
-            Ok(parsed_content) => parsed_content,
-            Err(ParseBytesError::IncoherentTimeseriesLengths) => {
+        if let Some(job) = job.as_ref() {
+            job.update(UploadJobStatus::Parsing { file: name.clone() });
+        }
+        let file_parser = state.file_parser.clone();
+        let file_name = name.clone();
+        let parsed_content = state
+            .upload_limiter
+            .parse(move || {
+                file_parser.try_bytes_into_domain_with_progress(
+                    &extension,
+                    file_content,
+                    &mut |consumed, total| {
+                        tracing::debug!("Parsing {file_name}: {consumed}/{total} bytes");
+                    },
+                )
+            })
+            .await;
+        let mut parsed_content = match parsed_content {
+            Ok(Ok(parsed_content)) => parsed_content,
+            Ok(Err(ParseBytesError::IncoherentTimeseriesLengths)) => {
                 unprocessable_files.push((name.to_string(), RejectionReason::IncoherentTimeseries));
                 continue;
             }
-            Err(_) => {
+            Ok(Err(_)) | Err(_) => {
                 unprocessable_files.push((name.to_string(), RejectionReason::CannotProcessFile));
                 continue;
             }
         };
 
-        let create_activity_request = parsed_content.into_request(user.user());
+        if let Some(sport) = sport_override {
+            parsed_content.override_sport(sport);
+        }
+        if let Some(max_accuracy) = &max_gps_accuracy {
+            parsed_content = parsed_content.without_inaccurate_positions(max_accuracy);
+        }
+
+        let create_activity_request = parsed_content
+            .into_request(user.user())
+            .with_visibility(visibility)
+            .with_commute_locations(commute_locations)
+            .with_athlete_profile(athlete_profile)
+            .with_near_duplicate_check(!query.force);
 
         match state
             .activity_service
@@ -110,6 +208,18 @@ pub async fn upload_activities<
         }
     }
 
+    if let Some(job) = job {
+        job.update(UploadJobStatus::Stored {
+            created_ids: created_ids.clone(),
+            unprocessable_files: unprocessable_files
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+        });
+    }
+
+    // Possible duplicates are reported along the other unprocessable files, so that the client
+    // can confirm them and upload them again with `?force=true`
     Ok((
         StatusCode::CREATED,
         Json(UploadActivitiesResponse {
@@ -120,7 +230,66 @@ pub async fn upload_activities<
         .into_response())
 }
 
-async fn extract_content(filename: &str, field: Field<'_>) -> Result<Vec<u8>, anyhow::Error> {
+/// Visibility of newly created activities, from the user's preference if set.
+pub(super) async fn default_visibility<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> ActivityVisibility {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::DefaultActivityVisibility)
+        .await
+    {
+        Ok(Some(Preference::DefaultActivityVisibility(visibility))) => visibility,
+        _ => ActivityVisibility::default(),
+    }
+}
+
+/// Places used to tag uploaded commutes, `None` if the user did not set them.
+async fn commute_locations<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> Option<CommuteLocations> {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::CommuteLocations)
+        .await
+    {
+        Ok(Some(Preference::CommuteLocations(locations))) => Some(locations),
+        _ => None,
+    }
+}
+
+/// Accuracy above which GPS fixes are dropped, `None` if the user did not set it.
+async fn max_gps_accuracy<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> Option<MaxGpsAccuracy> {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::MaxGpsAccuracy)
+        .await
+    {
+        Ok(Some(Preference::MaxGpsAccuracy(accuracy))) => Some(accuracy),
+        _ => None,
+    }
+}
+
+/// Weight and age used to estimate missing calories, `None` if the user did not set them.
+async fn athlete_profile<PS: IPreferencesService>(
+    preferences_service: &PS,
+    user: &UserId,
+) -> Option<AthleteProfile> {
+    match preferences_service
+        .get_preference(user, &PreferenceKey::AthleteProfile)
+        .await
+    {
+        Ok(Some(Preference::AthleteProfile(profile))) => Some(profile),
+        _ => None,
+    }
+}
+
+pub(super) async fn extract_content(
+    filename: &str,
+    field: Field<'_>,
+) -> Result<Vec<u8>, anyhow::Error> {
     let content = match field.bytes().await {
         Ok(content) => content,
         Err(err) => return Err(anyhow!(err)),
@@ -139,7 +308,7 @@ async fn extract_content(filename: &str, field: Field<'_>) -> Result<Vec<u8>, an
     Ok(content.to_vec())
 }
 
-fn extract_extension(filename: &str) -> Option<SupportedExtension> {
+pub(super) fn extract_extension(filename: &str) -> Option<SupportedExtension> {
     let mut parts = filename.split('.').rev();
     let mut part = parts.next();
 
@@ -164,7 +333,7 @@ mod tests {
                 UserId,
                 activity::{
                     Activity, ActivityDuration, ActivityId, ActivityStartTime, ActivityStatistics,
-                    Sport,
+                    GeoPoint, Sport,
                 },
             },
             services::{
@@ -173,7 +342,10 @@ mod tests {
                 training::test_utils::MockTrainingService,
             },
         },
-        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+        inbound::{
+            auth::no_auth::DefaultUserExtractor, http::upload_limiter::UploadLimiter,
+            parser::test_utils::MockFileParser,
+        },
     };
 
     use super::*;
@@ -196,14 +368,7 @@ mod tests {
                 ))
             });
 
-        let metrics = MockTrainingService::test_default();
-        let file_parser = MockFileParser::test_default();
-        let state = AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default().with_activity_service(service);
 
         let app = Router::new()
             .route("/test_upload", post(upload_activities))
@@ -228,6 +393,117 @@ mod tests {
         assert!(json.unprocessable_files.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_upload_uses_default_visibility_preference() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_create_activity()
+            .withf(|req| req.visibility() == &ActivityVisibility::Public)
+            .times(1)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::new(),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Running,
+                ))
+            });
+
+        let mut preferences = MockPreferencesService::new();
+        preferences
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::DefaultActivityVisibility)
+            .returning(|_, _| {
+                Ok(Some(Preference::DefaultActivityVisibility(
+                    ActivityVisibility::Public,
+                )))
+            });
+        preferences
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::CommuteLocations)
+            .returning(|_, _| Ok(None));
+        preferences
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::MaxGpsAccuracy)
+            .returning(|_, _| Ok(None));
+        preferences
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::AthleteProfile)
+            .returning(|_, _| Ok(None));
+
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(preferences);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "test1.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"test fit file content".to_vec()),
+            ))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_upload_uses_commute_locations_preference() {
+        let locations = CommuteLocations::new(
+            GeoPoint::new(45.19, 5.72).unwrap(),
+            GeoPoint::new(45.21, 5.80).unwrap(),
+        );
+        let mut service = MockActivityService::new();
+        service
+            .expect_create_activity()
+            .withf(move |req| req.commute_locations() == Some(&locations))
+            .times(1)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::new(),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Cycling,
+                ))
+            });
+
+        let mut preferences = MockPreferencesService::new();
+        preferences
+            .expect_get_preference()
+            .withf(|_, key| key == &PreferenceKey::CommuteLocations)
+            .returning(move |_, _| Ok(Some(Preference::CommuteLocations(locations))));
+        preferences
+            .expect_get_preference()
+            .returning(|_, _| Ok(None));
+
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_preferences_service(preferences);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "commute.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"test fit file content".to_vec()),
+            ))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+    }
+
     #[tokio::test]
     async fn test_upload_multiple_activities() {
         let mut seq = Sequence::new();
@@ -264,14 +540,7 @@ mod tests {
                 ))
             });
 
-        let metrics = MockTrainingService::test_default();
-        let file_parser = MockFileParser::test_default();
-        let state = AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default().with_activity_service(service);
 
         let app = Router::new()
             .route("/test_upload", post(upload_activities))
@@ -333,14 +602,7 @@ mod tests {
             .in_sequence(&mut seq)
             .returning(|_| Err(CreateActivityError::SimilarActivityExistsError));
 
-        let metrics = MockTrainingService::test_default();
-        let file_parser = MockFileParser::test_default();
-        let state = AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default().with_activity_service(service);
 
         let app = Router::new()
             .route("/test_upload", post(upload_activities))
@@ -380,15 +642,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_upload_unsupported_file_extension() {
-        let service = MockActivityService::test_default();
-        let metrics = MockTrainingService::test_default();
-        let file_parser = MockFileParser::test_default();
-        let state = AppState {
-            activity_service: Arc::new(service),
-            training_metrics_service: Arc::new(metrics),
-            file_parser: Arc::new(file_parser),
-            preferences_service: Arc::new(MockPreferencesService::new()),
-        };
+        let state = AppState::test_default();
 
         let app = Router::new()
             .route("/test_upload", post(upload_activities))
@@ -401,7 +655,7 @@ mod tests {
         let response = server
             .post("/test_upload")
             .multipart(axum_test::multipart::MultipartForm::new().add_part(
-                "test.gpx".to_string(),
+                "test.kml".to_string(),
                 axum_test::multipart::Part::bytes(file_data),
             ))
             .await;
@@ -410,13 +664,216 @@ mod tests {
         let json: UploadActivitiesResponse = response.json();
         assert!(json.created_ids.is_empty());
         assert_eq!(json.unprocessable_files.len(), 1);
-        assert_eq!(json.unprocessable_files[0].0, "test.gpx");
+        assert_eq!(json.unprocessable_files[0].0, "test.kml");
         assert!(matches!(
             json.unprocessable_files[0].1,
             RejectionReason::UnsupportedFileExtension
         ));
     }
 
+    #[tokio::test]
+    async fn test_upload_with_sport_override() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_create_activity()
+            .withf(|req| req.sport() == &Sport::Running)
+            .times(1)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::new(),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Running,
+                ))
+            });
+
+        let state = AppState::test_default().with_activity_service(service);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(
+                axum_test::multipart::MultipartForm::new()
+                    .add_text("sport", "Running")
+                    .add_part(
+                        "test1.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content 1".to_vec()),
+                    ),
+            )
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        let json: UploadActivitiesResponse = response.json();
+        assert_eq!(json.created_ids.len(), 1);
+        assert!(json.unprocessable_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upload_sport_override_only_applies_to_following_files() {
+        let mut service = MockActivityService::new();
+        let mut seq = Sequence::new();
+        for sport in [Sport::Cycling, Sport::Running] {
+            service
+                .expect_create_activity()
+                .withf(move |req| req.sport() == &sport)
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(move |_| {
+                    Ok(Activity::new_empty(
+                        ActivityId::new(),
+                        UserId::test_default(),
+                        ActivityStartTime::from_timestamp(1000).unwrap(),
+                        ActivityDuration::default(),
+                        sport,
+                    ))
+                });
+        }
+
+        let state = AppState::test_default().with_activity_service(service);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(
+                axum_test::multipart::MultipartForm::new()
+                    .add_part(
+                        "test1.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content 1".to_vec()),
+                    )
+                    .add_text("sport", "Running")
+                    .add_part(
+                        "test2.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content 2".to_vec()),
+                    ),
+            )
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        let json: UploadActivitiesResponse = response.json();
+        assert_eq!(json.created_ids.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_upload_without_sport_override_keeps_parsed_sport() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_create_activity()
+            .withf(|req| req.sport() == &Sport::Cycling)
+            .times(1)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::new(),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Cycling,
+                ))
+            });
+
+        let state = AppState::test_default().with_activity_service(service);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "test1.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"test fit file content 1".to_vec()),
+            ))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        let json: UploadActivitiesResponse = response.json();
+        assert_eq!(json.created_ids.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_invalid_sport_override() {
+        let mut service = MockActivityService::new();
+        service.expect_create_activity().times(0);
+
+        let state = AppState::test_default().with_activity_service(service);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+
+        let response = server
+            .post("/test_upload")
+            .multipart(
+                axum_test::multipart::MultipartForm::new()
+                    .add_text("sport", "NotASport")
+                    .add_part(
+                        "test1.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content 1".to_vec()),
+                    ),
+            )
+            .await;
+
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejected_when_queue_is_full() {
+        let mut service = MockActivityService::new();
+        service.expect_create_activity().times(1).returning(|_| {
+            Ok(Activity::new_empty(
+                ActivityId::new(),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(1000).unwrap(),
+                ActivityDuration::default(),
+                Sport::Running,
+            ))
+        });
+
+        // A single file parsed at once and no waiting room
+        let limiter = Arc::new(UploadLimiter::new(1, 0));
+        let state = AppState::test_default()
+            .with_activity_service(service)
+            .with_upload_limiter(limiter.clone());
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        let server = TestServer::new(app);
+        let upload = || {
+            server.post("/test_upload").multipart(
+                axum_test::multipart::MultipartForm::new().add_part(
+                    "test1.fit".to_string(),
+                    axum_test::multipart::Part::bytes(b"test fit file content 1".to_vec()),
+                ),
+            )
+        };
+
+        // Another upload is in progress
+        let in_progress = limiter.try_enter().unwrap();
+        let response = upload().await;
+        response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.header(RETRY_AFTER), UPLOAD_RETRY_AFTER_SECONDS);
+
+        drop(in_progress);
+        let response = upload().await;
+        response.assert_status(StatusCode::CREATED);
+    }
+
     #[test]
     fn test_extract_file_extension() {
         assert_eq!(extract_extension("toto.fit"), Some(SupportedExtension::FIT));
@@ -429,6 +886,103 @@ mod tests {
             extract_extension("toto.tcx.gz"),
             Some(SupportedExtension::TCX)
         );
+        assert_eq!(extract_extension("toto.gpx"), Some(SupportedExtension::GPX));
+        assert_eq!(
+            extract_extension("toto.gpx.gz"),
+            Some(SupportedExtension::GPX)
+        );
         assert_eq!(extract_extension("toto"), None);
     }
+
+    fn near_duplicate_test_server(service: MockActivityService) -> TestServer {
+        let state = AppState::test_default().with_activity_service(service);
+
+        let app = Router::new()
+            .route("/test_upload", post(upload_activities))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_upload_near_duplicate_is_flagged() {
+        let mut service = MockActivityService::new();
+        let mut seq = Sequence::new();
+        service
+            .expect_create_activity()
+            .withf(|req| req.check_near_duplicates())
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| {
+                Err(CreateActivityError::PossibleDuplicate(ActivityId::from(
+                    "existing-ride",
+                )))
+            });
+        service
+            .expect_create_activity()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::from("run"),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Running,
+                ))
+            });
+
+        let response = near_duplicate_test_server(service)
+            .post("/test_upload")
+            .multipart(
+                axum_test::multipart::MultipartForm::new()
+                    .add_part(
+                        "phone.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content".to_vec()),
+                    )
+                    .add_part(
+                        "run.fit".to_string(),
+                        axum_test::multipart::Part::bytes(b"test fit file content 2".to_vec()),
+                    ),
+            )
+            .await;
+
+        // The other files of the batch are still created
+        response.assert_status(StatusCode::CREATED);
+        response.assert_json(&serde_json::json!({
+            "created_ids": ["run"],
+            "unprocessable_files": [["phone.fit", {"PossibleDuplicate": "existing-ride"}]],
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_upload_near_duplicate_with_force() {
+        let mut service = MockActivityService::new();
+        service
+            .expect_create_activity()
+            .withf(|req| !req.check_near_duplicates())
+            .times(1)
+            .returning(|_| {
+                Ok(Activity::new_empty(
+                    ActivityId::from("phone-ride"),
+                    UserId::test_default(),
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::default(),
+                    Sport::Cycling,
+                ))
+            });
+
+        let response = near_duplicate_test_server(service)
+            .post("/test_upload")
+            .add_query_param("force", true)
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "phone.fit".to_string(),
+                axum_test::multipart::Part::bytes(b"test fit file content".to_vec()),
+            ))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        let json: UploadActivitiesResponse = response.json();
+        assert_eq!(json.created_ids, vec!["phone-ride".to_string()]);
+    }
 }
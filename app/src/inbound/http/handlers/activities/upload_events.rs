@@ -0,0 +1,150 @@
+use std::convert::Infallible;
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use tokio_stream::{Stream, StreamExt, wrappers::WatchStream};
+
+use crate::{
+    domain::ports::{
+        activity::IActivityService, preferences::IPreferencesService, training::ITrainingService,
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile},
+};
+
+/// Handler for GET /api/activity/upload/{job_id}/events
+///
+/// Server-Sent Events stream of the status of an upload job started with
+/// `POST /api/activity?job_id={job_id}`. Each status change is sent as a `queued`, `parsing`,
+/// `stored` or `failed` event, the stream ending after the final `stored` or `failed` one.
+pub async fn get_upload_job_events<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(job_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    let receiver = state
+        .upload_jobs
+        .subscribe(user.user(), &job_id)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let events = WatchStream::new(receiver).map(|status| {
+        Ok(Event::default()
+            .event(status.event_name())
+            .json_data(&status)
+            .unwrap_or_default())
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        middleware::from_extractor,
+        routing::{get, post},
+    };
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::services::{
+            activity::test_utils::MockActivityService,
+            preferences::tests_utils::MockPreferencesService,
+            training::test_utils::MockTrainingService,
+        },
+        inbound::{
+            auth::no_auth::DefaultUserExtractor, http::handlers::activities::upload_activities,
+            parser::test_utils::MockFileParser,
+        },
+    };
+
+    use super::*;
+
+    fn test_server() -> TestServer {
+        let state = AppState::test_default();
+
+        let app = Router::new()
+            .route(
+                "/activity",
+                post(
+                    upload_activities::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route(
+                "/activity/upload/{job_id}/events",
+                get(get_upload_job_events::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_events_of_completed_upload_job() {
+        let server = test_server();
+        server
+            .post("/activity")
+            .add_query_param("job_id", "job")
+            .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                "test.fit".to_string(),
+                axum_test::multipart::Part::bytes(vec![1, 2, 3]),
+            ))
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let response = server.get("/activity/upload/job/events").await;
+
+        response.assert_status_ok();
+        let events = response.text();
+        let last_event = events.trim().split("\n\n").last().unwrap();
+        assert!(last_event.starts_with("event: stored\n"));
+        assert!(last_event.contains(r#""unprocessable_files":[]"#));
+    }
+
+    #[tokio::test]
+    async fn test_events_of_unknown_job() {
+        let server = test_server();
+
+        let response = server.get("/activity/upload/unknown/events").await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_already_used_job_id() {
+        let server = test_server();
+        let upload = || {
+            server
+                .post("/activity")
+                .add_query_param("job_id", "job")
+                .multipart(axum_test::multipart::MultipartForm::new().add_part(
+                    "test.fit".to_string(),
+                    axum_test::multipart::Part::bytes(vec![1, 2, 3]),
+                ))
+        };
+
+        upload().await.assert_status(StatusCode::CREATED);
+        upload().await.assert_status(StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use axum::{
+    Json,
+    extract::{Multipart, State},
+    http::{StatusCode, header::RETRY_AFTER},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::ports::{
+        activity::IActivityService, preferences::IPreferencesService, training::ITrainingService,
+    },
+    inbound::{
+        http::AppState,
+        parser::{ParseFile, ParsedFileContent},
+    },
+};
+
+use super::upload_activity::{UPLOAD_RETRY_AFTER_SECONDS, extract_content, extract_extension};
+
+/// What an activity would look like once its file is uploaded.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ActivityPreview {
+    sport: String,
+    sport_category: Option<String>,
+    start_time: DateTime<FixedOffset>,
+    /// Duration of the activity, in seconds
+    duration: f64,
+    statistics: HashMap<String, f64>,
+}
+
+impl From<&ParsedFileContent> for ActivityPreview {
+    fn from(content: &ParsedFileContent) -> Self {
+        Self {
+            sport: content.sport().to_string(),
+            sport_category: content.sport().category().map(|cat| cat.to_string()),
+            start_time: *content.start_time().datetime(),
+            duration: *content.duration().as_f64(),
+            statistics: content
+                .statistics()
+                .items()
+                .into_iter()
+                .filter(|(_, value)| value.is_finite())
+                .collect(),
+        }
+    }
+}
+
+/// Handler for POST /api/activity/validate
+///
+/// Parse the first file of the multipart body, with the same limits as an upload, and return a
+/// preview of the activity it would create. Nothing is stored.
+pub async fn validate_activity<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    mut multipart: Multipart,
+) -> Result<Response, StatusCode> {
+    let Some(_ticket) = state.upload_limiter.try_enter() else {
+        tracing::warn!("Upload queue is full, rejecting file validation");
+        return Ok((
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, UPLOAD_RETRY_AFTER_SECONDS)],
+        )
+            .into_response());
+    };
+
+    let Ok(Some(field)) = multipart.next_field().await else {
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    let name = field
+        .name()
+        .map(|name| name.to_string())
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let extension = extract_extension(&name).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let content = extract_content(&name, field)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let file_parser = state.file_parser.clone();
+    let parsed_content = state
+        .upload_limiter
+        .parse(move || file_parser.try_bytes_into_domain(&extension, content))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    Ok(Json(ActivityPreview::from(&parsed_content)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::activity::{
+                ActivityDuration, ActivityStartTime, ActivityStatistic, ActivityStatistics,
+                ActivityTimeseries, Sport,
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{
+            auth::no_auth::DefaultUserExtractor,
+            parser::{ParseBytesError, test_utils::MockFileParser},
+        },
+    };
+
+    use super::*;
+
+    /// The activity service has no expectation, so that storing anything fails the test.
+    fn server(file_parser: MockFileParser) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(MockActivityService::new())
+            .with_file_parser(file_parser)
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
+        let app = Router::new()
+            .route("/activity/validate", post(validate_activity))
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+        TestServer::new(app)
+    }
+
+    fn form(name: &str) -> axum_test::multipart::MultipartForm {
+        axum_test::multipart::MultipartForm::new().add_part(
+            name.to_string(),
+            axum_test::multipart::Part::bytes(b"file content".to_vec()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_validate_activity_returns_preview() {
+        let mut file_parser = MockFileParser::new();
+        file_parser
+            .expect_try_bytes_into_domain()
+            .times(1)
+            .withf(|_, bytes| bytes == b"file content")
+            .returning(|_, bytes| {
+                Ok(ParsedFileContent::new(
+                    Sport::Running,
+                    ActivityStartTime::from_timestamp(1000).unwrap(),
+                    ActivityDuration::from(3600.),
+                    ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, 10000.)])),
+                    ActivityTimeseries::default(),
+                    "fit".to_string(),
+                    bytes,
+                ))
+            });
+
+        let response = server(file_parser)
+            .post("/activity/validate")
+            .multipart(form("activity.fit"))
+            .await;
+
+        response.assert_status_ok();
+        let preview: ActivityPreview = response.json();
+        assert_eq!(
+            preview,
+            ActivityPreview {
+                sport: "Running".to_string(),
+                sport_category: Some("Running".to_string()),
+                start_time: *ActivityStartTime::from_timestamp(1000).unwrap().datetime(),
+                duration: 3600.,
+                statistics: HashMap::from([("Distance".to_string(), 10000.)]),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_invalid_activity() {
+        let mut file_parser = MockFileParser::new();
+        file_parser
+            .expect_try_bytes_into_domain()
+            .returning(|_, _| Err(ParseBytesError::InvalidContent));
+
+        let response = server(file_parser)
+            .post("/activity/validate")
+            .multipart(form("activity.fit"))
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_validate_unsupported_extension() {
+        let mut file_parser = MockFileParser::new();
+        file_parser.expect_try_bytes_into_domain().never();
+
+        let response = server(file_parser)
+            .post("/activity/validate")
+            .multipart(form("activity.txt"))
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
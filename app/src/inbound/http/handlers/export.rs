@@ -0,0 +1,389 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufWriter, Write},
+};
+
+use axum::{
+    Extension,
+    body::Body,
+    extract::State,
+    http::{
+        StatusCode,
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
+    response::Response,
+};
+use serde::Serialize;
+use tokio::{runtime::Handle, sync::mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    domain::{
+        models::activity::{Activity, DEFAULT_METRICS, safe_download_filename},
+        ports::{
+            activity::{GetRawActivityRequest, IActivityService, ListActivitiesFilters},
+            preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{
+        auth::AuthenticatedUser,
+        http::{
+            AppState,
+            handlers::{
+                activities::activity_schema::PublicActivity, preferences::PreferenceResponse,
+                training::TrainingNoteResponse,
+            },
+        },
+        parser::ParseFile,
+    },
+};
+
+/// Number of chunks of the archive that can be waiting to be sent to the client before the
+/// writer blocks.
+const EXPORT_CHANNEL_CAPACITY: usize = 8;
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Serialize)]
+struct ExportedActivity {
+    #[serde(flatten)]
+    activity: PublicActivity,
+    duration: f64,
+    /// Name of the raw file of the activity in the archive
+    file: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportManifest {
+    activities: Vec<ExportedActivity>,
+    training_notes: Vec<TrainingNoteResponse>,
+    preferences: Vec<PreferenceResponse>,
+}
+
+/// Name of the raw file of an activity in the archive, e.g. `activities/2025-09-03_cycling.fit`.
+/// Activities of the same sport starting on the same day are told apart with a `_2`, `_3`, ...
+/// suffix, `taken` holding the names already used in the archive.
+fn export_file_name(activity: &Activity, extension: &str, taken: &mut HashSet<String>) -> String {
+    let name = safe_download_filename(
+        activity.id(),
+        activity.sport(),
+        Some(activity.start_time()),
+        extension,
+    );
+    let mut file = name.clone();
+    let mut count = 1;
+    while !taken.insert(file.clone()) {
+        count += 1;
+        file = match name.split_once('.') {
+            Some((stem, extension)) => format!("{stem}_{count}.{extension}"),
+            None => format!("{name}_{count}"),
+        };
+    }
+    format!("activities/{file}")
+}
+
+/// [Write] implementation forwarding the archive content to the response body.
+struct ChannelWriter(mpsc::Sender<Result<Vec<u8>, io::Error>>);
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .blocking_send(Ok(buf.to_vec()))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "Export response dropped"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Handler for GET /api/export
+///
+/// Streams a zip archive with all the user's data: the raw file of each activity under
+/// `activities/`, and a `manifest.json` with the activities metadata, training notes and
+/// preferences. Raw files are fetched and written one at a time so that the whole archive is
+/// never held in memory.
+pub async fn export_user_data<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+) -> Result<Response, StatusCode> {
+    let activities = state
+        .activity_service
+        .list_activities_with_metrics(
+            user.user(),
+            &ListActivitiesFilters::empty(),
+            &DEFAULT_METRICS,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let training_notes = state
+        .training_metrics_service
+        .get_training_notes(user.user(), &None)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let preferences = state
+        .preferences_service
+        .get_all_preferences(user.user())
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+    let activity_service = state.activity_service.clone();
+    let user = user.user().clone();
+    let handle = Handle::current();
+    let errors = tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let writer = BufWriter::with_capacity(EXPORT_CHUNK_SIZE, ChannelWriter(tx));
+        let mut zip = zip::ZipWriter::new_stream(writer);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        let mut manifest = ExportManifest {
+            activities: Vec::with_capacity(activities.len()),
+            training_notes: training_notes
+                .into_iter()
+                .map(TrainingNoteResponse::from)
+                .collect(),
+            preferences: preferences
+                .into_iter()
+                .map(PreferenceResponse::from)
+                .collect(),
+        };
+
+        let res = (|| -> anyhow::Result<()> {
+            let mut taken = HashSet::new();
+            for (activity, metrics) in activities {
+                let raw = handle.block_on(activity_service.get_raw_activity(
+                    GetRawActivityRequest::new(activity.id().clone(), user.clone()),
+                ))?;
+                let extension = raw.name().rsplit('.').next().unwrap_or_default();
+                let file = export_file_name(&activity, extension, &mut taken);
+
+                zip.start_file(&file, options)?;
+                zip.write_all(raw.content())?;
+
+                manifest.activities.push(ExportedActivity {
+                    activity: PublicActivity::from(&activity, &metrics),
+                    duration: *activity.duration().as_f64(),
+                    file,
+                });
+            }
+
+            zip.start_file("manifest.json", options)?;
+            serde_json::to_writer_pretty(&mut zip, &manifest)?;
+            zip.finish()?.into_inner().flush()?;
+            Ok(())
+        })();
+
+        if let Err(err) = res {
+            tracing::warn!("Failed to export data for user {user}: {err:?}");
+            let _ = errors.blocking_send(Err(io::Error::other(err.to_string())));
+        }
+    });
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/zip")
+        .header(CONTENT_DISPOSITION, "attachment; filename=\"export.zip\"")
+        .body(Body::from_stream(ReceiverStream::new(rx)))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, sync::Arc};
+
+    use zip::ZipArchive;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::{
+                    ActivityDuration, ActivityId, ActivityMetricsV2, ActivityStartTime, Sport,
+                },
+                preferences::Preference,
+                training::TrainingMetricId,
+            },
+            ports::activity::RawActivity,
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::parser::test_utils::MockFileParser,
+    };
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_export_contains_activities_and_manifest() {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_list_activities_with_metrics()
+            .returning(|_, _, _| {
+                Ok(vec![
+                    (
+                        Activity::new_empty(
+                            ActivityId::from("first"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1756857600).unwrap(),
+                            ActivityDuration::from(3600.),
+                            Sport::Cycling,
+                        ),
+                        ActivityMetricsV2::default(),
+                    ),
+                    (
+                        Activity::new_empty(
+                            ActivityId::from("second"),
+                            UserId::test_default(),
+                            ActivityStartTime::from_timestamp(1756944000).unwrap(),
+                            ActivityDuration::from(1800.),
+                            Sport::Running,
+                        ),
+                        ActivityMetricsV2::default(),
+                    ),
+                ])
+            });
+        activity_service
+            .expect_get_raw_activity()
+            .times(2)
+            .returning(|req| match req.activity().to_string().as_str() {
+                "first" => Ok(RawActivity::new("first.fit".to_string(), vec![1, 2, 3])),
+                _ => Ok(RawActivity::new("second.tcx".to_string(), vec![4, 5])),
+            });
+
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_get_training_notes()
+            .returning(|_, _| Ok(vec![]));
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_all_preferences()
+            .returning(|_| {
+                Ok(vec![Preference::FavoriteMetric(TrainingMetricId::from(
+                    "metric",
+                ))])
+            });
+
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(training_service)
+            .with_preferences_service(preferences_service);
+
+        let response = export_user_data(
+            Extension(AuthenticatedUser::new(UserId::test_default())),
+            State(state),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/zip"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let mut archive = ZipArchive::new(io::Cursor::new(body.to_vec())).unwrap();
+        let mut names = archive.file_names().map(String::from).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "activities/2025-09-03_cycling.fit",
+                "activities/2025-09-04_running.tcx",
+                "manifest.json",
+            ]
+        );
+
+        let mut content = Vec::new();
+        archive
+            .by_name("activities/2025-09-03_cycling.fit")
+            .unwrap()
+            .read_to_end(&mut content)
+            .unwrap();
+        assert_eq!(content, vec![1, 2, 3]);
+
+        let manifest: serde_json::Value =
+            serde_json::from_reader(archive.by_name("manifest.json").unwrap()).unwrap();
+        assert_eq!(manifest["activities"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            manifest["activities"][1]["file"],
+            "activities/2025-09-04_running.tcx"
+        );
+        assert_eq!(manifest["training_notes"], serde_json::json!([]));
+        assert_eq!(
+            manifest["preferences"],
+            serde_json::json!([{"key": "favorite_metric", "value": "metric"}])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_service_error() {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_list_activities_with_metrics()
+            .returning(|_, _, _| {
+                Err(
+                    crate::domain::ports::activity::ListActivitiesError::Unknown(anyhow::anyhow!(
+                        "error"
+                    )),
+                )
+            });
+
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(MockPreferencesService::new());
+
+        let result = export_user_data(
+            Extension(AuthenticatedUser::new(UserId::test_default())),
+            State(state),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_export_file_name_is_unique_within_archive() {
+        let activity = |id: &str, sport| {
+            Activity::new_empty(
+                ActivityId::from(id),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(1756857600).unwrap(),
+                ActivityDuration::from(3600.),
+                sport,
+            )
+        };
+        let mut taken = HashSet::new();
+
+        assert_eq!(
+            export_file_name(&activity("first", Sport::Cycling), "fit", &mut taken),
+            "activities/2025-09-03_cycling.fit"
+        );
+        assert_eq!(
+            export_file_name(&activity("second", Sport::Cycling), "fit", &mut taken),
+            "activities/2025-09-03_cycling_2.fit"
+        );
+        assert_eq!(
+            export_file_name(&activity("third", Sport::Cycling), "fit", &mut taken),
+            "activities/2025-09-03_cycling_3.fit"
+        );
+        assert_eq!(
+            export_file_name(&activity("fourth", Sport::Running), "../fit", &mut taken),
+            "activities/2025-09-03_running.fit"
+        );
+    }
+}
@@ -1,22 +1,46 @@
 #![allow(unused_imports)]
 
 mod activities;
+mod export;
 mod preferences;
 mod training;
 
+use axum::http::StatusCode;
+
+use crate::{domain::models::UserId, inbound::auth::AuthenticatedUser};
+
 pub(super) use activities::{
-    create_standalone_activity, delete_activity, get_activity, get_all_raw_activities,
-    get_raw_activity, list_activities, patch_activity, upload_activities,
+    create_standalone_activity, delete_activity, export_activity_ndjson, get_activity,
+    get_activity_beat_intervals, get_activity_pace_zones, get_activity_polyline,
+    get_activity_power_zones, get_activity_streams, get_all_raw_activities, get_public_activity,
+    get_raw_activity, get_upload_job_events, list_activities, list_activities_feed,
+    list_activities_near, merge_activity_stream, patch_activity, replace_raw_activity,
+    reverse_activity, trim_activity, upload_activities, validate_activity,
 };
+pub(super) use export::export_user_data;
 pub(super) use preferences::{
-    delete_preference, get_all_preferences, get_preference, set_preference,
+    delete_preference, get_all_preferences, get_effective_preferences, get_preference,
+    set_preference,
 };
 pub(super) use training::{
     compute_training_metric_values, copy_training_metric, create_training_metric,
-    create_training_note, create_training_period, delete_training_metric, delete_training_note,
-    delete_training_period, get_active_training_periods, get_training_metric_templates,
-    get_training_metrics, get_training_metrics_ordering, get_training_note, get_training_notes,
-    get_training_period, get_training_period_metrics, get_training_period_notes,
-    get_training_periods, set_training_metrics_ordering, update_training_metric,
-    update_training_note, update_training_period,
+    create_training_note, create_training_notes, create_training_period, delete_training_metric,
+    delete_training_note, delete_training_period, get_active_training_periods, get_training_metric,
+    get_training_metric_templates, get_training_metrics, get_training_metrics_ordering,
+    get_training_note, get_training_notes, get_training_period, get_training_period_metrics,
+    get_training_period_notes, get_training_periods, set_training_metrics_ordering,
+    update_training_metric, update_training_note, update_training_period,
 };
+
+/// Status code of a resource owned by another user. It is the same as for a resource that does
+/// not exist, so that responses do not disclose whether an id exists.
+const NOT_OWNED: StatusCode = StatusCode::NOT_FOUND;
+
+/// Check that a resource fetched by id belongs to the authenticated user, see [NOT_OWNED].
+fn check_owner(owner: &UserId, user: &AuthenticatedUser) -> Result<(), StatusCode> {
+    if owner == user.user() {
+        Ok(())
+    } else {
+        Err(NOT_OWNED)
+    }
+}
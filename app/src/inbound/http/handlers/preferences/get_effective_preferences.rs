@@ -0,0 +1,91 @@
+use axum::{Extension, Json, extract::State, http::StatusCode};
+
+use crate::domain::ports::{
+    activity::IActivityService, preferences::IPreferencesService, training::ITrainingService,
+};
+use crate::inbound::{auth::AuthenticatedUser, http::AppState, parser::ParseFile};
+
+use super::types::EffectivePreferencesResponse;
+
+/// Return every known preference, using its default value when the user has not set it.
+pub async fn get_effective_preferences<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+) -> Result<Json<EffectivePreferencesResponse>, StatusCode> {
+    state
+        .preferences_service
+        .get_all_preferences(user.user())
+        .await
+        .map(|prefs| Json(EffectivePreferencesResponse::from_preferences(prefs)))
+        .map_err(StatusCode::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use crate::{
+        domain::{
+            models::{UserId, preferences::Preference, training::TrainingMetricId},
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::parser::test_utils::MockFileParser,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_effective_preferences_fills_defaults() {
+        let mut preferences_service = MockPreferencesService::new();
+        preferences_service
+            .expect_get_all_preferences()
+            .returning(|_| {
+                Ok(vec![Preference::FavoriteMetric(TrainingMetricId::from(
+                    "metric",
+                ))])
+            });
+
+        let state = AppState::test_default()
+            .with_activity_service(MockActivityService::new())
+            .with_file_parser(MockFileParser::new())
+            .with_training_metrics_service(MockTrainingService::new())
+            .with_preferences_service(preferences_service);
+
+        let Json(response) = get_effective_preferences(
+            Extension(AuthenticatedUser::new(UserId::test_default())),
+            State(state),
+        )
+        .await
+        .unwrap();
+
+        let response = serde_json::to_value(response).unwrap();
+        assert_eq!(response["favorite_metric"], json!("metric"));
+        assert_eq!(response["default_activity_visibility"], json!("private"));
+        assert_eq!(response["moving_speed_thresholds"]["Cycling"], json!(1.5));
+        assert_eq!(response["moving_speed_thresholds"]["Walking"], json!(0.3));
+        assert_eq!(response["ftp"], json!(null));
+        assert_eq!(
+            response["sport_thresholds"],
+            json!({"cycling_ftp": null, "running_threshold_pace": null, "swimming_css": null})
+        );
+        assert_eq!(
+            response["power_zones"],
+            json!([0.55, 0.75, 0.90, 1.05, 1.20, 1.50])
+        );
+        assert_eq!(
+            response["default_streams"],
+            json!(["Speed", "HeartRate", "Power", "Altitude"])
+        );
+    }
+}
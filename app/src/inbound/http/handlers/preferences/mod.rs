@@ -1,10 +1,13 @@
 mod delete_preference;
 mod get_all_preferences;
+mod get_effective_preferences;
 mod get_preference;
 mod set_preference;
 mod types;
 
 pub use delete_preference::delete_preference;
 pub use get_all_preferences::get_all_preferences;
+pub use get_effective_preferences::get_effective_preferences;
 pub use get_preference::get_preference;
 pub use set_preference::set_preference;
+pub(super) use types::PreferenceResponse;
@@ -1,8 +1,17 @@
+use std::collections::HashMap;
+
 use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::{
-    models::{preferences::Preference, training::TrainingMetricId},
+    models::{
+        activity::{
+            ActivityVisibility, AthleteProfile, CommuteLocations, DefaultStreams, Ftp,
+            MaxGpsAccuracy, MovingSpeedThresholds, PowerZones, SportCategory, SportThresholds,
+        },
+        preferences::Preference,
+        training::TrainingMetricId,
+    },
     ports::preferences::{DeletePreferenceError, GetPreferenceError, SetPreferenceError},
 };
 
@@ -11,21 +20,134 @@ use crate::domain::{
 pub enum PreferenceResponse {
     #[serde(rename = "favorite_metric")]
     FavoriteMetric(String),
+    #[serde(rename = "default_activity_visibility")]
+    DefaultActivityVisibility(ActivityVisibility),
+    #[serde(rename = "moving_speed_thresholds")]
+    MovingSpeedThresholds(MovingSpeedThresholds),
+    #[serde(rename = "ftp")]
+    Ftp(Ftp),
+    #[serde(rename = "sport_thresholds")]
+    SportThresholds(SportThresholds),
+    #[serde(rename = "power_zones")]
+    PowerZones(PowerZones),
+    #[serde(rename = "default_streams")]
+    DefaultStreams(DefaultStreams),
+    #[serde(rename = "commute_locations")]
+    CommuteLocations(CommuteLocations),
+    #[serde(rename = "max_gps_accuracy")]
+    MaxGpsAccuracy(MaxGpsAccuracy),
+    #[serde(rename = "athlete_profile")]
+    AthleteProfile(AthleteProfile),
 }
 
 impl From<Preference> for PreferenceResponse {
     fn from(pref: Preference) -> Self {
         match pref {
             Preference::FavoriteMetric(id) => PreferenceResponse::FavoriteMetric(id.to_string()),
+            Preference::DefaultActivityVisibility(visibility) => {
+                PreferenceResponse::DefaultActivityVisibility(visibility)
+            }
+            Preference::MovingSpeedThresholds(thresholds) => {
+                PreferenceResponse::MovingSpeedThresholds(thresholds)
+            }
+            Preference::Ftp(ftp) => PreferenceResponse::Ftp(ftp),
+            Preference::SportThresholds(thresholds) => {
+                PreferenceResponse::SportThresholds(thresholds)
+            }
+            Preference::PowerZones(zones) => PreferenceResponse::PowerZones(zones),
+            Preference::DefaultStreams(streams) => PreferenceResponse::DefaultStreams(streams),
+            Preference::CommuteLocations(locations) => {
+                PreferenceResponse::CommuteLocations(locations)
+            }
+            Preference::MaxGpsAccuracy(accuracy) => PreferenceResponse::MaxGpsAccuracy(accuracy),
+            Preference::AthleteProfile(profile) => PreferenceResponse::AthleteProfile(profile),
         }
     }
 }
 
+/// Value of every known preference, with defaults applied for the ones not set by the user.
+#[derive(Debug, Serialize)]
+pub struct EffectivePreferencesResponse {
+    /// No metric is favorited by default
+    favorite_metric: Option<String>,
+    default_activity_visibility: ActivityVisibility,
+    /// Speed in m/s, for every sport category
+    moving_speed_thresholds: HashMap<SportCategory, f64>,
+    /// No FTP is set by default
+    ftp: Option<Ftp>,
+    /// No sport threshold is set by default
+    sport_thresholds: SportThresholds,
+    power_zones: PowerZones,
+    default_streams: DefaultStreams,
+    /// Commutes are not tagged by default
+    commute_locations: Option<CommuteLocations>,
+    /// Positions are not filtered by accuracy by default
+    max_gps_accuracy: Option<MaxGpsAccuracy>,
+    /// No athlete profile is set by default
+    athlete_profile: Option<AthleteProfile>,
+}
+
+impl EffectivePreferencesResponse {
+    pub fn from_preferences(preferences: Vec<Preference>) -> Self {
+        let mut response = Self {
+            favorite_metric: None,
+            default_activity_visibility: ActivityVisibility::default(),
+            moving_speed_thresholds: MovingSpeedThresholds::default().effective(),
+            ftp: None,
+            sport_thresholds: SportThresholds::default(),
+            power_zones: PowerZones::default(),
+            default_streams: DefaultStreams::default(),
+            commute_locations: None,
+            max_gps_accuracy: None,
+            athlete_profile: None,
+        };
+        for preference in preferences {
+            match preference {
+                Preference::FavoriteMetric(id) => response.favorite_metric = Some(id.to_string()),
+                Preference::DefaultActivityVisibility(visibility) => {
+                    response.default_activity_visibility = visibility
+                }
+                Preference::MovingSpeedThresholds(thresholds) => {
+                    response.moving_speed_thresholds = thresholds.effective()
+                }
+                Preference::Ftp(ftp) => response.ftp = Some(ftp),
+                Preference::SportThresholds(thresholds) => response.sport_thresholds = thresholds,
+                Preference::PowerZones(zones) => response.power_zones = zones,
+                Preference::DefaultStreams(streams) => response.default_streams = streams,
+                Preference::CommuteLocations(locations) => {
+                    response.commute_locations = Some(locations)
+                }
+                Preference::MaxGpsAccuracy(accuracy) => response.max_gps_accuracy = Some(accuracy),
+                Preference::AthleteProfile(profile) => response.athlete_profile = Some(profile),
+            }
+        }
+        response
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "key", content = "value")]
 pub enum SetPreferenceRequest {
     #[serde(rename = "favorite_metric")]
     FavoriteMetric(String),
+    #[serde(rename = "default_activity_visibility")]
+    DefaultActivityVisibility(ActivityVisibility),
+    #[serde(rename = "moving_speed_thresholds")]
+    MovingSpeedThresholds(MovingSpeedThresholds),
+    #[serde(rename = "ftp")]
+    Ftp(Ftp),
+    #[serde(rename = "sport_thresholds")]
+    SportThresholds(SportThresholds),
+    #[serde(rename = "power_zones")]
+    PowerZones(PowerZones),
+    #[serde(rename = "default_streams")]
+    DefaultStreams(DefaultStreams),
+    #[serde(rename = "commute_locations")]
+    CommuteLocations(CommuteLocations),
+    #[serde(rename = "max_gps_accuracy")]
+    MaxGpsAccuracy(MaxGpsAccuracy),
+    #[serde(rename = "athlete_profile")]
+    AthleteProfile(AthleteProfile),
 }
 
 impl From<SetPreferenceRequest> for Preference {
@@ -34,6 +156,23 @@ impl From<SetPreferenceRequest> for Preference {
             SetPreferenceRequest::FavoriteMetric(id) => {
                 Preference::FavoriteMetric(TrainingMetricId::from(id.as_str()))
             }
+            SetPreferenceRequest::DefaultActivityVisibility(visibility) => {
+                Preference::DefaultActivityVisibility(visibility)
+            }
+            SetPreferenceRequest::MovingSpeedThresholds(thresholds) => {
+                Preference::MovingSpeedThresholds(thresholds)
+            }
+            SetPreferenceRequest::Ftp(ftp) => Preference::Ftp(ftp),
+            SetPreferenceRequest::SportThresholds(thresholds) => {
+                Preference::SportThresholds(thresholds)
+            }
+            SetPreferenceRequest::PowerZones(zones) => Preference::PowerZones(zones),
+            SetPreferenceRequest::DefaultStreams(streams) => Preference::DefaultStreams(streams),
+            SetPreferenceRequest::CommuteLocations(locations) => {
+                Preference::CommuteLocations(locations)
+            }
+            SetPreferenceRequest::MaxGpsAccuracy(accuracy) => Preference::MaxGpsAccuracy(accuracy),
+            SetPreferenceRequest::AthleteProfile(profile) => Preference::AthleteProfile(profile),
         }
     }
 }
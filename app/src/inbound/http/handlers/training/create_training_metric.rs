@@ -1,5 +1,5 @@
 use axum::{Extension, Json, extract::State, http::StatusCode};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     domain::{
@@ -68,6 +68,14 @@ fn build_request(
     ))
 }
 
+#[derive(Debug, Serialize)]
+pub struct CreateTrainingMetricResponse {
+    id: String,
+    /// No activity of the user has data for the metric, e.g. a power metric for a user who never
+    /// recorded power. The metric is created anyway.
+    missing_data: bool,
+}
+
 impl From<CreateTrainingMetricError> for StatusCode {
     fn from(_value: CreateTrainingMetricError) -> Self {
         Self::UNPROCESSABLE_ENTITY
@@ -83,32 +91,137 @@ pub async fn create_training_metric<
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
     Json(payload): Json<CreateTrainingMetricBody>,
-) -> Result<StatusCode, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<(StatusCode, Json<CreateTrainingMetricResponse>), (StatusCode, Json<serde_json::Value>)>
+{
     let req = build_request(payload, user.user()).map_err(|e| {
         (
             StatusCode::BAD_REQUEST,
             Json(serde_json::json!({ "error": e })),
         )
     })?;
+    let metric = *req.metric();
 
-    state
+    let id = state
         .training_metrics_service
         .create_metric(req)
         .await
-        .map(|_| StatusCode::CREATED)
         .map_err(|e| {
             (
                 StatusCode::from(e),
                 Json(serde_json::json!({ "error": "Failed to create training metric" })),
             )
-        })
+        })?;
+
+    // Only a warning, the metric is created even if the check fails
+    let missing_data = match state
+        .activity_service
+        .has_metric_data(user.user(), &metric)
+        .await
+    {
+        Ok(has_data) => !has_data,
+        Err(err) => {
+            tracing::warn!("Cannot check if metric {metric} has data: {err:?}");
+            false
+        }
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateTrainingMetricResponse {
+            id: id.to_string(),
+            missing_data,
+        }),
+    ))
 }
 
 #[cfg(test)]
 mod tests_create_training_metric {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            models::training::TrainingMetricId,
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
 
     use super::*;
 
+    fn test_server(has_data: bool) -> TestServer {
+        let mut activity_service = MockActivityService::new();
+        activity_service
+            .expect_has_metric_data()
+            .with(eq(UserId::default()), eq(ActivityMetricV2::AvgPower))
+            .times(1)
+            .returning(move |_, _| Ok(has_data));
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_create_metric()
+            .times(1)
+            .returning(|_| Ok(TrainingMetricId::from("metric-id")));
+
+        let state = AppState::test_default()
+            .with_activity_service(activity_service)
+            .with_training_metrics_service(training_service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/training/metric",
+                post(
+                    create_training_metric::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    fn power_metric_body() -> serde_json::Value {
+        serde_json::json!({
+            "name": "Weekly power",
+            "metric": "AvgPower",
+            "scope": {"type": "global"}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_metric_without_data_warns_but_succeeds() {
+        let response = test_server(false)
+            .post("/training/metric")
+            .json(&power_metric_body())
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        response.assert_json(&serde_json::json!({"id": "metric-id", "missing_data": true}));
+    }
+
+    #[tokio::test]
+    async fn test_create_metric_with_data_does_not_warn() {
+        let response = test_server(true)
+            .post("/training/metric")
+            .json(&power_metric_body())
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+        response.assert_json(&serde_json::json!({"id": "metric-id", "missing_data": false}));
+    }
+
     #[test]
     fn test_payload_format() {
         assert!(
@@ -14,9 +14,9 @@ use crate::{
 
 #[derive(Debug, Deserialize)]
 pub struct CreateTrainingNoteBody {
-    title: Option<String>,
-    content: String,
-    date: String,
+    pub(super) title: Option<String>,
+    pub(super) content: String,
+    pub(super) date: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,232 @@
+use axum::{Extension, Json, extract::State, http::StatusCode};
+use serde::Serialize;
+
+use crate::domain::models::training::{TrainingNoteDate, TrainingNoteTitle};
+use crate::domain::ports::{activity::IActivityService, preferences::IPreferencesService};
+use crate::inbound::parser::ParseFile;
+use crate::{
+    domain::{
+        models::{UserId, training::TrainingNoteContent},
+        ports::training::{CreateTrainingNoteRequest, ITrainingService},
+    },
+    inbound::{auth::AuthenticatedUser, http::AppState},
+};
+
+use super::create_training_note::CreateTrainingNoteBody;
+
+const MAX_NOTES_PER_BATCH: usize = 1000;
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_CONTENT_LENGTH: usize = 10_000;
+
+/// Result of a single note of the batch, in the order of the request.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CreateTrainingNotesResult {
+    Created {
+        id: String,
+    },
+    Invalid {
+        error: String,
+    },
+    /// The note is valid but was not created, as another note of the batch is invalid.
+    NotCreated,
+}
+
+fn build_request(
+    body: CreateTrainingNoteBody,
+    user: &UserId,
+) -> Result<CreateTrainingNoteRequest, String> {
+    let date = TrainingNoteDate::try_from(body.date.clone())
+        .map_err(|_| format!("Invalid date: {}", body.date))?;
+    if body
+        .title
+        .as_ref()
+        .is_some_and(|title| title.chars().count() > MAX_TITLE_LENGTH)
+    {
+        return Err(format!(
+            "Title is longer than {MAX_TITLE_LENGTH} characters"
+        ));
+    }
+    if body.content.chars().count() > MAX_CONTENT_LENGTH {
+        return Err(format!(
+            "Content is longer than {MAX_CONTENT_LENGTH} characters"
+        ));
+    }
+
+    Ok(CreateTrainingNoteRequest::new(
+        user.clone(),
+        body.title.map(TrainingNoteTitle::from),
+        TrainingNoteContent::from(body.content),
+        date,
+    ))
+}
+
+/// Handler for POST /api/training/notes/batch
+///
+/// Creates all the notes of the batch, or none of them if one is invalid. The response lists the
+/// result of each note, with a 422 status code if nothing was created.
+pub async fn create_training_notes<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Json(payload): Json<Vec<CreateTrainingNoteBody>>,
+) -> Result<(StatusCode, Json<Vec<CreateTrainingNotesResult>>), StatusCode> {
+    if payload.len() > MAX_NOTES_PER_BATCH {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    let requests: Vec<Result<CreateTrainingNoteRequest, String>> = payload
+        .into_iter()
+        .map(|body| build_request(body, user.user()))
+        .collect();
+
+    if requests.iter().any(Result::is_err) {
+        let results = requests
+            .into_iter()
+            .map(|req| match req {
+                Ok(_) => CreateTrainingNotesResult::NotCreated,
+                Err(error) => CreateTrainingNotesResult::Invalid { error },
+            })
+            .collect();
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, Json(results)));
+    }
+
+    let requests = requests.into_iter().flatten().collect();
+    let note_ids = state
+        .training_metrics_service
+        .create_training_notes(requests)
+        .await
+        .map_err(StatusCode::from)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(
+            note_ids
+                .into_iter()
+                .map(|id| CreateTrainingNotesResult::Created { id: id.to_string() })
+                .collect(),
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::post};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::training::TrainingNoteId,
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(training_service: MockTrainingService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(MockActivityService::new())
+            .with_training_metrics_service(training_service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/training/notes/batch",
+                post(
+                    create_training_notes::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_create_training_notes() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_create_training_notes()
+            .withf(|reqs| {
+                reqs.len() == 2
+                    && reqs[0].content() == &TrainingNoteContent::from("Easy run")
+                    && reqs[1].title() == &Some(TrainingNoteTitle::from("Race"))
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    TrainingNoteId::from("note-1"),
+                    TrainingNoteId::from("note-2"),
+                ])
+            });
+
+        let response = test_server(training_service)
+            .post("/training/notes/batch")
+            .json(&serde_json::json!([
+                {"content": "Easy run", "date": "2025-10-27"},
+                {"title": "Race", "content": "10k", "date": "2025-11-02"}
+            ]))
+            .await;
+
+        response.assert_status_ok();
+        response.assert_json(&serde_json::json!([
+            {"status": "created", "id": "note-1"},
+            {"status": "created", "id": "note-2"}
+        ]));
+    }
+
+    #[tokio::test]
+    async fn test_create_training_notes_with_an_invalid_note_creates_none() {
+        let mut training_service = MockTrainingService::new();
+        training_service.expect_create_training_notes().never();
+
+        let response = test_server(training_service)
+            .post("/training/notes/batch")
+            .json(&serde_json::json!([
+                {"content": "Easy run", "date": "2025-10-27"},
+                {"content": "x".repeat(MAX_CONTENT_LENGTH + 1), "date": "2025-11-02"},
+                {"content": "Rest day", "date": "yesterday"}
+            ]))
+            .await;
+
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        response.assert_json(&serde_json::json!([
+            {"status": "not_created"},
+            {"status": "invalid", "error": "Content is longer than 10000 characters"},
+            {"status": "invalid", "error": "Invalid date: yesterday"}
+        ]));
+    }
+
+    #[tokio::test]
+    async fn test_create_training_notes_too_many_notes() {
+        let mut training_service = MockTrainingService::new();
+        training_service.expect_create_training_notes().never();
+
+        let notes = vec![
+            serde_json::json!({"content": "Easy run", "date": "2025-10-27"});
+            MAX_NOTES_PER_BATCH + 1
+        ];
+        let response = test_server(training_service)
+            .post("/training/notes/batch")
+            .json(&notes)
+            .await;
+
+        response.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
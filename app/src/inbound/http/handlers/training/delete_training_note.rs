@@ -17,8 +17,11 @@ use crate::{
 };
 
 impl From<DeleteTrainingNoteError> for StatusCode {
-    fn from(_value: DeleteTrainingNoteError) -> Self {
-        Self::UNPROCESSABLE_ENTITY
+    fn from(value: DeleteTrainingNoteError) -> Self {
+        match value {
+            DeleteTrainingNoteError::NoteDoesNotExist(_) => Self::NOT_FOUND,
+            DeleteTrainingNoteError::Unknown(_) => Self::UNPROCESSABLE_ENTITY,
+        }
     }
 }
 
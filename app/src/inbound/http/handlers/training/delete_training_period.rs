@@ -10,8 +10,8 @@ use crate::domain::ports::training::{DeleteTrainingPeriodError, DeleteTrainingPe
 use crate::domain::ports::{
     activity::IActivityService, preferences::IPreferencesService, training::ITrainingService,
 };
-use crate::inbound::http::AppState;
 use crate::inbound::auth::AuthenticatedUser;
+use crate::inbound::http::AppState;
 use crate::inbound::parser::ParseFile;
 
 #[derive(Serialize)]
@@ -0,0 +1,207 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Serialize;
+
+use crate::{
+    domain::{
+        models::training::{TrainingMetric, TrainingMetricId, TrainingMetricSummaryAverage},
+        ports::{
+            activity::IActivityService, preferences::IPreferencesService,
+            training::ITrainingService,
+        },
+    },
+    inbound::{
+        auth::AuthenticatedUser,
+        http::{
+            AppState,
+            handlers::training::{
+                get_training_metrics::{ResponseSports, format_source_metric},
+                types::ScopePayload,
+            },
+        },
+        parser::ParseFile,
+    },
+};
+
+/// Definition of a training metric, without its values.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingMetricResponse {
+    id: String,
+    name: Option<String>,
+    metric: String,
+    metric_formated: String,
+    granularity: Option<String>,
+    aggregate: Option<String>,
+    group_by: Option<String>,
+    sports: ResponseSports,
+    workout_types: Option<Vec<String>>,
+    bonked: Option<String>,
+    rpes: Option<Vec<u8>>,
+    show_average: Option<TrainingMetricSummaryAverage>,
+    scope: ScopePayload,
+}
+
+impl From<TrainingMetric> for TrainingMetricResponse {
+    fn from(metric: TrainingMetric) -> Self {
+        let definition = metric.definition();
+        let window = definition.window().as_ref();
+        let filters = definition.filters();
+
+        Self {
+            id: metric.id().to_string(),
+            name: metric.name().as_ref().map(|n| n.as_str().to_string()),
+            metric: definition.metric().to_string(),
+            metric_formated: format_source_metric(&definition.metric().source()),
+            granularity: window.map(|w| w.granularity().to_string()),
+            aggregate: window.map(|w| w.aggregate().to_string()),
+            group_by: window.and_then(|w| w.group_by().as_ref().map(|g| format!("{:?}", g))),
+            sports: ResponseSports::from(filters.sports()),
+            workout_types: filters
+                .workout_types()
+                .as_ref()
+                .map(|types| types.iter().map(|wt| wt.to_string()).collect()),
+            bonked: filters.bonked().as_ref().map(|status| status.to_string()),
+            rpes: filters
+                .rpes()
+                .as_ref()
+                .map(|rpes| rpes.iter().map(|rpe| rpe.value()).collect()),
+            show_average: definition.summary().average().clone(),
+            scope: metric.scope().into(),
+        }
+    }
+}
+
+/// Handler for GET /api/training/metric/{metric_id}
+///
+/// Returns the definition of the metric, 404 if it does not exist or belongs to another user.
+pub async fn get_training_metric<
+    AS: IActivityService,
+    PF: ParseFile,
+    TMS: ITrainingService,
+    PS: IPreferencesService,
+>(
+    Extension(user): Extension<AuthenticatedUser>,
+    State(state): State<AppState<AS, PF, TMS, PS>>,
+    Path(metric_id): Path<String>,
+) -> Result<Json<TrainingMetricResponse>, StatusCode> {
+    state
+        .training_metrics_service
+        .get_training_metric(user.user(), &TrainingMetricId::from(&metric_id))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|metric| Json(TrainingMetricResponse::from(metric)))
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::get};
+    use axum_test::TestServer;
+    use serde_json::json;
+
+    use crate::{
+        domain::{
+            models::{
+                UserId,
+                activity::ActivityMetricV2,
+                training::{
+                    TrainingMetricAggregate, TrainingMetricDefinition, TrainingMetricFilters,
+                    TrainingMetricGranularity, TrainingMetricGroupBy, TrainingMetricName,
+                    TrainingMetricScope, TrainingMetricSummary, TrainingMetricWindow,
+                },
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
+    use super::*;
+
+    fn test_server(training_service: MockTrainingService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(MockActivityService::new())
+            .with_training_metrics_service(training_service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/training/metric/{metric_id}",
+                get(get_training_metric::<
+                    MockActivityService,
+                    MockFileParser,
+                    MockTrainingService,
+                    MockPreferencesService,
+                >),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_get_training_metric() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_get_training_metric()
+            .withf(|_, metric_id| metric_id == &TrainingMetricId::from("metric-1"))
+            .times(1)
+            .returning(|user, _| {
+                Ok(Some(TrainingMetric::new(
+                    TrainingMetricId::from("metric-1"),
+                    Some(TrainingMetricName::from("Weekly calories")),
+                    TrainingMetricScope::Global,
+                    TrainingMetricDefinition::new(
+                        user.clone(),
+                        ActivityMetricV2::Calories,
+                        Some(TrainingMetricWindow::new(
+                            TrainingMetricGranularity::Weekly,
+                            TrainingMetricAggregate::Sum,
+                            TrainingMetricGroupBy::none(),
+                        )),
+                        TrainingMetricFilters::empty(),
+                        TrainingMetricSummary::empty(),
+                    ),
+                )))
+            });
+
+        let response = test_server(training_service)
+            .get("/training/metric/metric-1")
+            .await;
+
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["id"], json!("metric-1"));
+        assert_eq!(body["name"], json!("Weekly calories"));
+        assert_eq!(body["metric"], json!("Calories"));
+        assert_eq!(body["granularity"], json!("Weekly"));
+        assert_eq!(body["aggregate"], json!("Sum"));
+        assert_eq!(body["scope"], json!({"type": "global"}));
+        assert!(body.get("values").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_training_metric_does_not_exist() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_get_training_metric()
+            .withf(|user, _| user == &UserId::default())
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        let response = test_server(training_service)
+            .get("/training/metric/unknown")
+            .await;
+
+        response.assert_status_not_found();
+    }
+}
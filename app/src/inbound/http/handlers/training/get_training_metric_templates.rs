@@ -224,7 +224,10 @@ fn metric_category(metric: &ActivityMetricV2) -> TrainingMetricTemplateCategory
         | ActivityMetricV2::MinPower
         | ActivityMetricV2::AvgPower
         | ActivityMetricV2::NormalizedPower => TrainingMetricTemplateCategory::Power,
-        ActivityMetricV2::NumberOfActivity => TrainingMetricTemplateCategory::Other,
+        ActivityMetricV2::HeartRateVariability => TrainingMetricTemplateCategory::HeartRate,
+        ActivityMetricV2::RespirationRate
+        | ActivityMetricV2::RemovedGpsPoints
+        | ActivityMetricV2::NumberOfActivity => TrainingMetricTemplateCategory::Other,
     }
 }
 
@@ -235,6 +238,9 @@ fn format_metric(metric: &ActivityMetricV2) -> String {
         ActivityMetricV2::Elevation => "elevation",
         ActivityMetricV2::Distance => "distance",
         ActivityMetricV2::NormalizedPower => "normalized power",
+        ActivityMetricV2::HeartRateVariability => "heart rate variability",
+        ActivityMetricV2::RespirationRate => "respiration rate",
+        ActivityMetricV2::RemovedGpsPoints => "removed GPS points",
 
         ActivityMetricV2::ActiveDuration => "active duration",
 
@@ -65,6 +65,15 @@ pub enum MetricsQuery {
     },
 }
 
+/// Optional parts of the response, off by default as they are not needed by every client.
+/// Example: ?scope=global&start=2025-01-01T00:00:00Z&with_latest=true
+#[derive(Debug, Default, Deserialize)]
+pub struct MetricsOptionsQuery {
+    /// Include the most recent bucket value of each metric group
+    #[serde(default)]
+    with_latest: bool,
+}
+
 impl MetricsQuery {
     pub fn scope(&self) -> TrainingMetricScope {
         match self {
@@ -140,15 +149,47 @@ pub struct ResponseBodyItem {
     group_by: Option<String>,
     scope: ScopePayload,
     summary: HashMap<String, f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<HashMap<String, ResponseLatestValue>>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ResponseLatestValue {
+    granule: String,
+    value: f64,
+}
+
+/// Most recent granule with a value, for each group.
+fn latest_values(values: &GroupedMetricValues) -> HashMap<String, ResponseLatestValue> {
+    values
+        .iter()
+        .filter_map(|(group, granules)| {
+            granules
+                .iter()
+                .max_by(|(a, _), (b, _)| a.cmp(b))
+                .map(|(granule, value)| {
+                    (
+                        group.clone(),
+                        ResponseLatestValue {
+                            granule: granule.clone(),
+                            value: *value,
+                        },
+                    )
+                })
+        })
+        .collect()
 }
 
 fn to_response_body_item(
     metric: (TrainingMetric, TrainingMetricValues),
     range: &MetricsDateRange,
+    with_latest: bool,
 ) -> ResponseBodyItem {
     let (metric, metric_values) = metric;
     let definition = metric.definition();
     let values = convert_metric_values_unit(group_metric_values(metric_values));
+    // Computed before filling the missing granules, which have no actual value
+    let latest = with_latest.then(|| latest_values(&values));
     let values = match metric.definition().window() {
         Some(window) => fill_missing_granules(values, window, range),
         None => values,
@@ -194,10 +235,11 @@ fn to_response_body_item(
             .and_then(|w| w.group_by().as_ref().map(|g| format!("{:?}", g))),
         scope: metric.scope().into(),
         summary,
+        latest,
     }
 }
 
-fn format_source_metric(source: &ActivityMetricSource) -> String {
+pub(super) fn format_source_metric(source: &ActivityMetricSource) -> String {
     match source {
         ActivityMetricSource::Statistic(stat) => stat.to_string(),
         ActivityMetricSource::Timeseries((metric, aggregate)) => {
@@ -217,6 +259,7 @@ pub async fn get_training_metrics<
     Extension(user): Extension<AuthenticatedUser>,
     State(state): State<AppState<AS, PF, TMS, PS>>,
     Query(query): Query<MetricsQuery>,
+    Query(options): Query<MetricsOptionsQuery>,
 ) -> Result<impl IntoResponse, StatusCode> {
     let res = state
         .training_metrics_service
@@ -229,7 +272,7 @@ pub async fn get_training_metrics<
 
     let body = ResponseBody(
         res.into_iter()
-            .map(|metric| to_response_body_item(metric, query.date_range()))
+            .map(|metric| to_response_body_item(metric, query.date_range(), options.with_latest))
             .collect(),
     );
 
@@ -285,7 +328,7 @@ pub async fn get_training_period_metrics<
 
     let body = ResponseBody(
         res.into_iter()
-            .map(|metric| to_response_body_item(metric, &date_range))
+            .map(|metric| to_response_body_item(metric, &date_range, false))
             .collect(),
     );
 
@@ -296,8 +339,20 @@ pub async fn get_training_period_metrics<
 mod tests {
     use serde_json::json;
 
-    use crate::domain::models::activity::{
-        ActivityStatistic, TimeseriesAggregate, TimeseriesMetric,
+    use crate::{
+        domain::models::{
+            UserId,
+            activity::{
+                ActivityMetricV2, ActivityStatistic, TimeseriesAggregate, TimeseriesMetric, Unit,
+            },
+            training::{
+                TrainingMetricAggregate, TrainingMetricBin, TrainingMetricFilters,
+                TrainingMetricGranularity, TrainingMetricGroupBy, TrainingMetricId,
+                TrainingMetricSummary, TrainingMetricSummaryValues, TrainingMetricValue,
+                TrainingMetricWindow,
+            },
+        },
+        inbound::http::handlers::training::utils::NO_GROUP,
     };
 
     use super::*;
@@ -377,6 +432,7 @@ mod tests {
                 training_period_id: "period-1".to_string(),
             },
             summary: HashMap::new(),
+            latest: None,
         }]);
 
         let serialized = serde_json::to_value(body).unwrap();
@@ -415,4 +471,92 @@ mod tests {
             ])
         );
     }
+
+    fn distance_metric_with_values() -> (TrainingMetric, TrainingMetricValues) {
+        let metric = TrainingMetric::new(
+            TrainingMetricId::from("metric-id"),
+            None,
+            TrainingMetricScope::Global,
+            TrainingMetricDefinition::new(
+                UserId::test_default(),
+                ActivityMetricV2::Distance,
+                Some(TrainingMetricWindow::new(
+                    TrainingMetricGranularity::Daily,
+                    TrainingMetricAggregate::Sum,
+                    TrainingMetricGroupBy::none(),
+                )),
+                TrainingMetricFilters::empty(),
+                TrainingMetricSummary::empty(),
+            ),
+        );
+        let values = TrainingMetricValues::new(
+            HashMap::from([
+                (
+                    TrainingMetricBin::from_granule("2025-09-24"),
+                    TrainingMetricValue::Sum(5000.),
+                ),
+                (
+                    TrainingMetricBin::from_granule("2025-09-26"),
+                    TrainingMetricValue::Sum(12000.),
+                ),
+            ]),
+            TrainingMetricSummaryValues::default(),
+            Unit::Meter,
+        );
+        (metric, values)
+    }
+
+    fn range() -> MetricsDateRange {
+        MetricsDateRange {
+            start: "2025-09-22T00:00:00+00:00".parse().unwrap(),
+            end: Some("2025-09-28T23:59:59+00:00".parse().unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_response_item_with_latest_value() {
+        let item = to_response_body_item(distance_metric_with_values(), &range(), true);
+
+        assert_eq!(
+            item.latest,
+            Some(HashMap::from([(
+                NO_GROUP.to_string(),
+                ResponseLatestValue {
+                    granule: "2025-09-26".to_string(),
+                    value: 12.
+                }
+            )]))
+        );
+        assert_eq!(
+            serde_json::to_value(&item).unwrap()["latest"],
+            json!({"Other": {"granule": "2025-09-26", "value": 12.0}})
+        );
+    }
+
+    #[test]
+    fn test_response_item_without_latest_value_by_default() {
+        let item = to_response_body_item(distance_metric_with_values(), &range(), false);
+
+        assert_eq!(item.latest, None);
+        assert!(serde_json::to_value(&item).unwrap().get("latest").is_none());
+    }
+
+    #[test]
+    fn test_parse_metrics_options_query() {
+        let Query(options) = Query::<MetricsOptionsQuery>::try_from_uri(
+            &"/training/metrics?scope=global&start=2025-01-01T00:00:00Z&with_latest=true"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(options.with_latest);
+
+        let Query(options) = Query::<MetricsOptionsQuery>::try_from_uri(
+            &"/training/metrics?scope=global&start=2025-01-01T00:00:00Z"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!options.with_latest);
+    }
 }
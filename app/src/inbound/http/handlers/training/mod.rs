@@ -2,11 +2,13 @@ mod compute_training_metric_values;
 mod copy_training_metric;
 mod create_training_metric;
 mod create_training_note;
+mod create_training_notes;
 mod create_training_period;
 mod delete_training_metric;
 mod delete_training_note;
 mod delete_training_period;
 mod get_active_training_periods;
+mod get_training_metric;
 mod get_training_metric_templates;
 mod get_training_metrics;
 mod get_training_metrics_ordering;
@@ -25,16 +27,19 @@ pub use compute_training_metric_values::compute_training_metric_values;
 pub use copy_training_metric::copy_training_metric;
 pub use create_training_metric::create_training_metric;
 pub use create_training_note::create_training_note;
+pub use create_training_notes::create_training_notes;
 pub use create_training_period::create_training_period;
 pub use delete_training_metric::delete_training_metric;
 pub use delete_training_note::delete_training_note;
 pub use delete_training_period::delete_training_period;
 pub use get_active_training_periods::get_active_training_periods;
+pub use get_training_metric::get_training_metric;
 pub use get_training_metric_templates::get_training_metric_templates;
 pub use get_training_metrics::get_training_metrics;
 pub use get_training_metrics::get_training_period_metrics;
 pub use get_training_metrics_ordering::get_training_metrics_ordering;
 pub use get_training_note::get_training_note;
+pub(super) use get_training_notes::TrainingNoteResponse;
 pub use get_training_notes::get_training_notes;
 pub use get_training_notes::get_training_period_notes;
 pub use get_training_period::get_training_period;
@@ -35,6 +35,11 @@ pub struct UpdateTrainingMetricBody {
     summary: APITrainingMetricSummary,
 }
 
+/// Handler for PATCH /api/training/metric/{metric_id}
+///
+/// Updates the name and definition (metric, window, filters and summary) of the metric in place,
+/// keeping its id and scope. Values are computed from the definition when read, so they follow
+/// the new window and filters without needing to be invalidated.
 pub async fn update_training_metric<
     AS: IActivityService,
     PF: ParseFile,
@@ -89,8 +94,122 @@ fn build_request(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware::from_extractor, routing::patch};
+    use axum_test::TestServer;
+
+    use crate::{
+        domain::{
+            models::training::{
+                TrainingMetricAggregate, TrainingMetricGranularity, TrainingMetricGroupBy,
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{auth::no_auth::DefaultUserExtractor, parser::test_utils::MockFileParser},
+    };
+
     use super::*;
 
+    const METRIC_ID: &str = "0b6f7a52-3c9e-4a8e-9f6d-0d1e2f3a4b5c";
+
+    fn test_server(training_service: MockTrainingService) -> TestServer {
+        let state = AppState::test_default()
+            .with_activity_service(MockActivityService::new())
+            .with_training_metrics_service(training_service)
+            .with_preferences_service(MockPreferencesService::new());
+
+        let app = Router::new()
+            .route(
+                "/training/metric/{metric_id}",
+                patch(
+                    update_training_metric::<
+                        MockActivityService,
+                        MockFileParser,
+                        MockTrainingService,
+                        MockPreferencesService,
+                    >,
+                ),
+            )
+            .route_layer(from_extractor::<DefaultUserExtractor>())
+            .with_state(state);
+
+        TestServer::new(app)
+    }
+
+    #[tokio::test]
+    async fn test_update_training_metric_name() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_update_training_metric()
+            .withf(|req| {
+                let patch = req.clone().patch();
+                req.id() == &TrainingMetricId::from(METRIC_ID)
+                    && patch.name() == &TrainingMetricName::from("Renamed")
+                    && patch.definition().window().is_none()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let response = test_server(training_service)
+            .patch(&format!("/training/metric/{METRIC_ID}"))
+            .json(&serde_json::json!({"name": "Renamed", "metric": "Calories"}))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_update_training_metric_window() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_update_training_metric()
+            .withf(|req| {
+                req.clone().patch().definition().window()
+                    == &Some(TrainingMetricWindow::new(
+                        TrainingMetricGranularity::Monthly,
+                        TrainingMetricAggregate::Sum,
+                        TrainingMetricGroupBy::none(),
+                    ))
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let response = test_server(training_service)
+            .patch(&format!("/training/metric/{METRIC_ID}"))
+            .json(&serde_json::json!({
+                "name": "Monthly calories",
+                "metric": "Calories",
+                "window": {"granularity": "Monthly", "aggregate": "Sum"}
+            }))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_update_training_metric_does_not_exist() {
+        let mut training_service = MockTrainingService::new();
+        training_service
+            .expect_update_training_metric()
+            .returning(|req| {
+                Err(UpdateTrainingMetricError::MetricDoesNotExist(
+                    req.id().clone(),
+                ))
+            });
+
+        let response = test_server(training_service)
+            .patch(&format!("/training/metric/{METRIC_ID}"))
+            .json(&serde_json::json!({"name": "Renamed", "metric": "Calories"}))
+            .await;
+
+        response.assert_status_not_found();
+    }
+
     #[test]
     fn test_deserialize_required_fields_only() {
         let json = r#"{"name": "New Metric Name", "metric": "Calories"}"#;
@@ -25,8 +25,11 @@ pub struct UpdateTrainingNoteBody {
 }
 
 impl From<UpdateTrainingNoteError> for StatusCode {
-    fn from(_value: UpdateTrainingNoteError) -> Self {
-        Self::UNPROCESSABLE_ENTITY
+    fn from(value: UpdateTrainingNoteError) -> Self {
+        match value {
+            UpdateTrainingNoteError::NoteDoesNotExist(_) => Self::NOT_FOUND,
+            UpdateTrainingNoteError::Unknown(_) => Self::UNPROCESSABLE_ENTITY,
+        }
     }
 }
 
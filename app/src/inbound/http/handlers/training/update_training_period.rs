@@ -15,8 +15,8 @@ use crate::domain::ports::training::{
     UpdateTrainingPeriodNameError, UpdateTrainingPeriodNameRequest, UpdateTrainingPeriodNoteError,
     UpdateTrainingPeriodNoteRequest,
 };
-use crate::inbound::http::AppState;
 use crate::inbound::auth::AuthenticatedUser;
+use crate::inbound::http::AppState;
 use crate::inbound::parser::ParseFile;
 
 #[derive(Serialize)]
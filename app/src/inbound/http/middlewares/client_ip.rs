@@ -0,0 +1,222 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::{Extensions, HeaderMap, HeaderName, request::Parts},
+};
+
+use crate::config::TrustedProxy;
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+const FORWARDED: HeaderName = HeaderName::from_static("forwarded");
+
+/// IP of the client that sent the request, `None` if the peer of the connection is unknown.
+///
+/// Without trusted proxies the peer of the connection is the client. With [TrustedProxy::Hops],
+/// the addresses appended by the proxies to `X-Forwarded-For` (or `Forwarded` if the former is
+/// missing) are walked from the right, skipping one address per trusted proxy, the peer being the
+/// last proxy. If the chain is shorter than the number of hops, the leftmost address is used. An
+/// address that cannot be parsed falls back to the peer.
+pub fn client_ip(parts: &Parts, trusted_proxy: TrustedProxy) -> Option<IpAddr> {
+    resolve_client_ip(&parts.headers, &parts.extensions, trusted_proxy)
+}
+
+/// Same as [client_ip], for a request that has not been split into its parts.
+pub fn request_client_ip<B>(request: &Request<B>, trusted_proxy: TrustedProxy) -> Option<IpAddr> {
+    resolve_client_ip(request.headers(), request.extensions(), trusted_proxy)
+}
+
+fn resolve_client_ip(
+    headers: &HeaderMap,
+    extensions: &Extensions,
+    trusted_proxy: TrustedProxy,
+) -> Option<IpAddr> {
+    let peer = extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip())?;
+
+    let TrustedProxy::Hops(hops) = trusted_proxy else {
+        return Some(peer);
+    };
+
+    let forwarded = forwarded_for(headers);
+    if forwarded.is_empty() {
+        return Some(peer);
+    }
+
+    // The peer is the closest proxy, i.e. the first trusted hop
+    let index = forwarded.len().saturating_sub(hops);
+    Some(forwarded[index].unwrap_or(peer))
+}
+
+/// Addresses from `X-Forwarded-For`, or from the `for` parameters of `Forwarded`, in header order.
+fn forwarded_for(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let x_forwarded_for = headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(parse_ip)
+        .collect::<Vec<_>>();
+    if !x_forwarded_for.is_empty() {
+        return x_forwarded_for;
+    }
+
+    headers
+        .get_all(FORWARDED)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|element| {
+            element.split(';').find_map(|pair| {
+                let (key, value) = pair.split_once('=')?;
+                key.trim().eq_ignore_ascii_case("for").then_some(value)
+            })
+        })
+        .map(parse_ip)
+        .collect()
+}
+
+/// Parse an address as found in forwarding headers: optionally quoted, with an optional port and
+/// IPv6 addresses optionally between brackets.
+fn parse_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    if let Ok(address) = value.parse::<SocketAddr>() {
+        return Some(address.ip());
+    }
+    value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+        .and_then(|value| value.parse::<IpAddr>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use axum::http::Request;
+
+    use super::*;
+
+    fn parts(headers: &[(&str, &str)]) -> Parts {
+        let mut builder = Request::builder().uri("/");
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        let (mut parts, _) = builder.body(()).unwrap().into_parts();
+        parts
+            .extensions
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 54321))));
+        parts
+    }
+
+    fn ipv4(a: u8, b: u8, c: u8, d: u8) -> Option<IpAddr> {
+        Some(IpAddr::V4(Ipv4Addr::new(a, b, c, d)))
+    }
+
+    #[test]
+    fn test_client_ip_direct_connection() {
+        let parts = parts(&[]);
+
+        assert_eq!(client_ip(&parts, TrustedProxy::None), ipv4(10, 0, 0, 1));
+        assert_eq!(client_ip(&parts, TrustedProxy::Hops(1)), ipv4(10, 0, 0, 1));
+    }
+
+    #[test]
+    fn test_client_ip_ignores_forwarded_headers_when_not_trusted() {
+        let parts = parts(&[
+            ("x-forwarded-for", "203.0.113.7"),
+            ("forwarded", "for=203.0.113.7"),
+        ]);
+
+        assert_eq!(client_ip(&parts, TrustedProxy::None), ipv4(10, 0, 0, 1));
+    }
+
+    #[test]
+    fn test_client_ip_without_connect_info() {
+        let (parts, _) = Request::builder()
+            .header("x-forwarded-for", "203.0.113.7")
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        assert_eq!(client_ip(&parts, TrustedProxy::Hops(1)), None);
+    }
+
+    #[test]
+    fn test_client_ip_from_x_forwarded_for() {
+        let parts = parts(&[("x-forwarded-for", "198.51.100.2, 203.0.113.7, 192.0.2.1")]);
+
+        assert_eq!(client_ip(&parts, TrustedProxy::Hops(1)), ipv4(192, 0, 2, 1));
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(2)),
+            ipv4(203, 0, 113, 7)
+        );
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(3)),
+            ipv4(198, 51, 100, 2)
+        );
+        // Fewer addresses than trusted hops
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(10)),
+            ipv4(198, 51, 100, 2)
+        );
+    }
+
+    #[test]
+    fn test_client_ip_from_multiple_x_forwarded_for_headers() {
+        let parts = parts(&[
+            ("x-forwarded-for", "198.51.100.2"),
+            ("x-forwarded-for", "203.0.113.7"),
+        ]);
+
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(1)),
+            ipv4(203, 0, 113, 7)
+        );
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(2)),
+            ipv4(198, 51, 100, 2)
+        );
+    }
+
+    #[test]
+    fn test_client_ip_from_forwarded() {
+        let parts = parts(&[(
+            "forwarded",
+            "for=\"[2001:db8::1]:4711\";proto=https, For=198.51.100.2:8080;by=10.0.0.1",
+        )]);
+
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(1)),
+            ipv4(198, 51, 100, 2)
+        );
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(2)),
+            Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_client_ip_prefers_x_forwarded_for() {
+        let parts = parts(&[
+            ("forwarded", "for=198.51.100.2"),
+            ("x-forwarded-for", "203.0.113.7"),
+        ]);
+
+        assert_eq!(
+            client_ip(&parts, TrustedProxy::Hops(1)),
+            ipv4(203, 0, 113, 7)
+        );
+    }
+
+    #[test]
+    fn test_client_ip_falls_back_to_peer_on_invalid_address() {
+        let parts = parts(&[("forwarded", "for=unknown")]);
+
+        assert_eq!(client_ip(&parts, TrustedProxy::Hops(1)), ipv4(10, 0, 0, 1));
+    }
+}
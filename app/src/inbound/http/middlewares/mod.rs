@@ -1 +1,2 @@
+pub mod client_ip;
 pub mod rate_limit;
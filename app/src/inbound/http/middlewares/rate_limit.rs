@@ -1,6 +1,5 @@
 use axum::{
     body::Body,
-    extract::ConnectInfo,
     http::{Request, Response, StatusCode},
 };
 use std::{
@@ -13,6 +12,8 @@ use std::{
 use tokio::sync::Mutex;
 use tower::{Layer, Service};
 
+use crate::{config::TrustedProxy, inbound::http::middlewares::client_ip::client_ip};
+
 /// Rate limit store using a simple [HashMap] for storing, mainly intended for low traffic routes.
 #[derive(Clone, Debug, Default)]
 pub struct RateLimitStore {
@@ -51,6 +52,7 @@ pub struct IpRateLimitLayer {
     store: RateLimitStore,
     limit: u32,
     window: Duration,
+    trusted_proxy: TrustedProxy,
 }
 
 impl IpRateLimitLayer {
@@ -59,6 +61,15 @@ impl IpRateLimitLayer {
             store,
             limit,
             window,
+            trusted_proxy: TrustedProxy::None,
+        }
+    }
+
+    /// Proxies whose forwarding headers are used to find the client IP, see [client_ip].
+    pub fn with_trusted_proxy(self, trusted_proxy: TrustedProxy) -> Self {
+        Self {
+            trusted_proxy,
+            ..self
         }
     }
 }
@@ -72,6 +83,7 @@ impl<S> Layer<S> for IpRateLimitLayer {
             store: self.store.clone(),
             limit: self.limit,
             window: self.window,
+            trusted_proxy: self.trusted_proxy,
         }
     }
 }
@@ -82,6 +94,7 @@ pub struct IpRateLimitService<S> {
     store: RateLimitStore,
     limit: u32,
     window: Duration,
+    trusted_proxy: TrustedProxy,
 }
 
 impl<S> Service<Request<Body>> for IpRateLimitService<S>
@@ -104,10 +117,9 @@ where
         let limit = self.limit;
         let window = self.window;
 
-        let ip = req
-            .extensions()
-            .get::<ConnectInfo<std::net::SocketAddr>>()
-            .map(|ci| ci.0.ip());
+        let (parts, body) = req.into_parts();
+        let ip = client_ip(&parts, self.trusted_proxy);
+        let req = Request::from_parts(parts, body);
 
         let clone = self.inner.clone();
         let mut ready_inner = std::mem::replace(&mut self.inner, clone);
@@ -284,4 +296,33 @@ mod tests {
         // Cloned store should see the same state
         assert!(!store2.is_allowed(ip, limit, window).await);
     }
+
+    #[tokio::test]
+    async fn test_rate_limit_layer_uses_forwarded_client_ip() {
+        use axum::{Router, extract::ConnectInfo, routing::get};
+        use tower::ServiceExt;
+
+        let app = Router::new().route("/", get(|| async {})).layer(
+            IpRateLimitLayer::new(RateLimitStore::new(), 1, Duration::from_secs(60))
+                .with_trusted_proxy(TrustedProxy::Hops(1)),
+        );
+        let request = |client: &str| {
+            let mut request = Request::builder()
+                .uri("/")
+                .header("x-forwarded-for", client)
+                .body(Body::empty())
+                .unwrap();
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(std::net::SocketAddr::from(([10, 0, 0, 1], 80))));
+            request
+        };
+
+        let response = app.clone().oneshot(request("203.0.113.7")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app.clone().oneshot(request("198.51.100.2")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app.oneshot(request("203.0.113.7")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
 }
@@ -7,13 +7,15 @@ use axum::extract::DefaultBodyLimit;
 use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, COOKIE, SET_COOKIE};
 use axum::http::{HeaderValue, Method};
 
-use axum::routing::{delete, get, patch};
+use axum::routing::{delete, get, patch, put};
 use axum::{Router, routing::post};
 use cookie::SameSite;
-use tokio::net;
+use tokio::{net, signal};
+use tower_http::classify::{ServerErrorsAsFailures, SharedClassifier};
 use tower_http::cors::CorsLayer;
+use tower_http::trace::TraceLayer;
 
-use crate::config::{AppMode, BaseConfig};
+use crate::config::{AppMode, BaseConfig, TrustedProxy};
 use crate::domain::ports::{
     activity::IActivityService, preferences::IPreferencesService, training::ITrainingService,
 };
@@ -22,17 +24,26 @@ use crate::inbound::auth::AuthStrategy;
 use crate::inbound::auth::email_based::IUserService;
 use crate::inbound::auth::infra::add_auth_router;
 use crate::inbound::http::handlers::get_training_metric_templates;
+use crate::inbound::http::middlewares::client_ip::request_client_ip;
+use crate::inbound::http::page_size::PageSize;
+use crate::inbound::http::upload_jobs::UploadJobRegistry;
+use crate::inbound::http::upload_limiter::UploadLimiter;
 use crate::inbound::parser::ParseFile;
 use handlers::{
     compute_training_metric_values, copy_training_metric, create_standalone_activity,
-    create_training_metric, create_training_note, create_training_period, delete_activity,
-    delete_preference, delete_training_metric, delete_training_note, delete_training_period,
-    get_active_training_periods, get_activity, get_all_preferences, get_all_raw_activities,
-    get_preference, get_raw_activity, get_training_metrics, get_training_metrics_ordering,
-    get_training_note, get_training_notes, get_training_period, get_training_period_metrics,
-    get_training_period_notes, get_training_periods, list_activities, patch_activity,
-    set_preference, set_training_metrics_ordering, update_training_metric, update_training_note,
-    update_training_period, upload_activities,
+    create_training_metric, create_training_note, create_training_notes, create_training_period,
+    delete_activity, delete_preference, delete_training_metric, delete_training_note,
+    delete_training_period, export_activity_ndjson, export_user_data, get_active_training_periods,
+    get_activity, get_activity_beat_intervals, get_activity_pace_zones, get_activity_polyline,
+    get_activity_power_zones, get_activity_streams, get_all_preferences, get_all_raw_activities,
+    get_effective_preferences, get_preference, get_public_activity, get_raw_activity,
+    get_training_metric, get_training_metrics, get_training_metrics_ordering, get_training_note,
+    get_training_notes, get_training_period, get_training_period_metrics,
+    get_training_period_notes, get_training_periods, get_upload_job_events, list_activities,
+    list_activities_feed, list_activities_near, merge_activity_stream, patch_activity,
+    replace_raw_activity, reverse_activity, set_preference, set_training_metrics_ordering,
+    trim_activity, update_training_metric, update_training_note, update_training_period,
+    upload_activities, validate_activity,
 };
 
 pub use crate::inbound::auth::email_based::infra::mailer::smtp::SMTPEmailProvider;
@@ -48,8 +59,14 @@ pub use crate::inbound::auth::email_based::{
     },
 };
 
+#[cfg(feature = "postgres")]
+pub use crate::inbound::auth::email_based::infra::postgres::user::PostgresUserRepository;
+
 mod handlers;
 pub mod middlewares;
+mod page_size;
+mod upload_jobs;
+mod upload_limiter;
 
 #[derive(Debug, Clone)]
 pub struct CookieConfig {
@@ -78,6 +95,9 @@ struct AppState<AS: IActivityService, PF: ParseFile, TMS: ITrainingService, PS:
     training_metrics_service: Arc<TMS>,
     #[allow(dead_code)]
     preferences_service: Arc<PS>,
+    upload_jobs: Arc<UploadJobRegistry>,
+    upload_limiter: Arc<UploadLimiter>,
+    page_size: Arc<PageSize>,
 }
 
 pub struct HttpServer<AS, PF, TMS, UR, PS> {
@@ -107,18 +127,22 @@ impl<
         preferences_service: PS,
         config: BaseConfig,
     ) -> anyhow::Result<Self> {
-        let trace_layer = tower_http::trace::TraceLayer::new_for_http().make_span_with(
-            |request: &axum::extract::Request<_>| {
-                let uri = request.uri().to_string();
-                tracing::info_span!("http_request", method = ?request.method(), uri)
-            },
-        );
+        let trace_layer = trace_layer(config.trusted_proxy);
 
         let state = AppState {
             activity_service: Arc::new(activity_service),
             training_metrics_service: training_metric_service,
             file_parser: Arc::new(file_parser),
             preferences_service: Arc::new(preferences_service),
+            upload_jobs: Arc::default(),
+            upload_limiter: Arc::new(UploadLimiter::new(
+                config.max_concurrent_uploads,
+                config.upload_queue_size,
+            )),
+            page_size: Arc::new(PageSize::new(
+                config.default_page_size,
+                config.max_page_size,
+            )),
         };
 
         let origin = config
@@ -133,20 +157,41 @@ impl<
             "App starting with authentication strategy: {:?}",
             &auth_strategy
         );
-        let mut router = add_auth_router(auth_strategy, router, user_service);
+        let cookie_config = CookieConfig {
+            secure: config.cookie_secure,
+            same_site: config.cookie_same_site,
+            http_only: config.cookie_http_only,
+            domain: config.cookie_domain.clone(),
+        };
+        let mut router = add_auth_router(
+            auth_strategy,
+            router,
+            user_service,
+            config.trusted_proxy,
+            cookie_config,
+        );
+        // Added after the authentication layer so that these routes stay reachable anonymously
+        router = router.nest("/api", public_routes(state));
 
         router = router.layer(trace_layer).layer(
             CorsLayer::new()
                 .allow_headers([CONTENT_TYPE, COOKIE, SET_COOKIE, CONTENT_DISPOSITION])
                 .expose_headers([CONTENT_DISPOSITION])
                 .allow_origin([origin])
-                .allow_methods([Method::GET, Method::POST, Method::DELETE, Method::PATCH])
+                .allow_methods([
+                    Method::GET,
+                    Method::POST,
+                    Method::PUT,
+                    Method::DELETE,
+                    Method::PATCH,
+                ])
                 .allow_credentials(true),
         );
 
-        let listener = net::TcpListener::bind(format!("0.0.0.0:{}", config.server_port))
+        let address = SocketAddr::new(config.bind_address, config.server_port);
+        let listener = net::TcpListener::bind(address)
             .await
-            .with_context(|| format!("failed to listen on {}", config.server_port))?;
+            .with_context(|| format!("failed to listen on {address}"))?;
 
         Ok(Self {
             router,
@@ -159,19 +204,92 @@ impl<
         })
     }
 
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Run the server until a SIGTERM or SIGINT is received, in-flight requests are then drained
+    /// before returning.
     pub async fn run(self) -> anyhow::Result<()> {
+        self.run_until(shutdown_signal()).await
+    }
+
+    /// Run the server until `signal` completes, in-flight requests are then drained before
+    /// returning.
+    pub async fn run_until<F>(self, signal: F) -> anyhow::Result<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
         tracing::debug!("listening on {}", self.listener.local_addr().unwrap());
         axum::serve(
             self.listener,
             self.router
                 .into_make_service_with_connect_info::<SocketAddr>(),
         )
+        .with_graceful_shutdown(async move {
+            signal.await;
+            tracing::info!("Shutdown requested, draining in-flight requests");
+        })
         .await
         .context("received error from running server")?;
+        tracing::info!("Server stopped");
         Ok(())
     }
 }
 
+/// Layer opening a span for each request, with the IP of the client resolved through the
+/// `trusted_proxy`, see [request_client_ip].
+fn trace_layer(
+    trusted_proxy: TrustedProxy,
+) -> TraceLayer<
+    SharedClassifier<ServerErrorsAsFailures>,
+    impl Fn(&axum::extract::Request) -> tracing::Span + Clone,
+> {
+    TraceLayer::new_for_http().make_span_with(move |request: &axum::extract::Request| {
+        let uri = request.uri().to_string();
+        let span = tracing::info_span!(
+            "http_request",
+            method = ?request.method(),
+            uri,
+            client_ip = tracing::field::Empty
+        );
+        if let Some(ip) = request_client_ip(request, trusted_proxy) {
+            span.record("client_ip", tracing::field::display(ip));
+        }
+        span
+    })
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = signal::ctrl_c().await {
+            tracing::error!("Unable to listen for SIGINT: {err}");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(err) => {
+                tracing::error!("Unable to listen for SIGTERM: {err}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Received SIGINT"),
+        _ = terminate => tracing::info!("Received SIGTERM"),
+    }
+}
+
 fn core_routes<
     AS: IActivityService,
     PF: ParseFile,
@@ -190,11 +308,28 @@ where
             post(upload_activities::<AS, PF, TS, PS>)
                 .route_layer(DefaultBodyLimit::max(1024 * 1024 * 1024)),
         )
+        .route(
+            "/activity/validate",
+            post(validate_activity::<AS, PF, TS, PS>)
+                .route_layer(DefaultBodyLimit::max(1024 * 1024 * 1024)),
+        )
+        .route(
+            "/activity/upload/{job_id}/events",
+            get(get_upload_job_events::<AS, PF, TS, PS>),
+        )
         .route(
             "/activity/standalone",
             post(create_standalone_activity::<AS, PF, TS, PS>),
         )
         .route("/activities", get(list_activities::<AS, PF, TS, PS>))
+        .route(
+            "/activities/feed",
+            get(list_activities_feed::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activities/near",
+            get(list_activities_near::<AS, PF, TS, PS>),
+        )
         .route(
             "/activities/download",
             get(get_all_raw_activities::<AS, PF, TS, PS>),
@@ -215,6 +350,47 @@ where
             "/activity/{activity_id}",
             delete(delete_activity::<AS, PF, TS, PS>),
         )
+        .route(
+            "/activity/{activity_id}/raw",
+            put(replace_raw_activity::<AS, PF, TS, PS>)
+                .route_layer(DefaultBodyLimit::max(1024 * 1024 * 1024)),
+        )
+        .route(
+            "/activity/{activity_id}/merge-stream",
+            post(merge_activity_stream::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/trim",
+            post(trim_activity::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/reverse",
+            post(reverse_activity::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/polyline",
+            get(get_activity_polyline::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/power-zones",
+            get(get_activity_power_zones::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/pace-zones",
+            get(get_activity_pace_zones::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/rr",
+            get(get_activity_beat_intervals::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/export/ndjson",
+            get(export_activity_ndjson::<AS, PF, TS, PS>),
+        )
+        .route(
+            "/activity/{activity_id}/streams",
+            get(get_activity_streams::<AS, PF, TS, PS>),
+        )
         .route(
             "/training/metrics",
             get(get_training_metrics::<AS, PF, TS, PS>),
@@ -234,7 +410,8 @@ where
         )
         .route(
             "/training/metric/{metric_id}",
-            delete(delete_training_metric::<AS, PF, TS, PS>)
+            get(get_training_metric::<AS, PF, TS, PS>)
+                .delete(delete_training_metric::<AS, PF, TS, PS>)
                 .patch(update_training_metric::<AS, PF, TS, PS>),
         )
         .route(
@@ -254,6 +431,10 @@ where
             post(create_training_note::<AS, PF, TS, PS>),
         )
         .route("/training/notes", get(get_training_notes::<AS, PF, TS, PS>))
+        .route(
+            "/training/notes/batch",
+            post(create_training_notes::<AS, PF, TS, PS>),
+        )
         .route(
             "/training/note/{note_id}",
             get(get_training_note::<AS, PF, TS, PS>),
@@ -294,8 +475,13 @@ where
             "/training/periods/active",
             get(get_active_training_periods::<AS, PF, TS, PS>),
         )
+        .route("/export", get(export_user_data::<AS, PF, TS, PS>))
         .route("/preferences", get(get_all_preferences::<AS, PF, TS, PS>))
         .route("/preferences", post(set_preference::<AS, PF, TS, PS>))
+        .route(
+            "/preferences/effective",
+            get(get_effective_preferences::<AS, PF, TS, PS>),
+        )
         .route("/preferences/{key}", get(get_preference::<AS, PF, TS, PS>))
         .route(
             "/preferences/{key}",
@@ -304,3 +490,307 @@ where
 
     router.with_state(state)
 }
+
+/// Routes served without authentication.
+fn public_routes<
+    AS: IActivityService,
+    PF: ParseFile,
+    TS: ITrainingService,
+    PS: IPreferencesService,
+    S,
+>(
+    state: AppState<AS, PF, TS, PS>,
+) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/public/activity/{activity_id}",
+            get(get_public_activity::<AS, PF, TS, PS>),
+        )
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod test_utils {
+    use std::sync::Arc;
+
+    use crate::{
+        domain::{
+            ports::{
+                activity::IActivityService, preferences::IPreferencesService,
+                training::ITrainingService,
+            },
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::{
+            http::{AppState, page_size::PageSize, upload_limiter::UploadLimiter},
+            parser::{ParseFile, test_utils::MockFileParser},
+        },
+    };
+
+    impl AppState<MockActivityService, MockFileParser, MockTrainingService, MockPreferencesService> {
+        /// State built from the `test_default` mocks with default upload and page size settings.
+        /// Use the `with_*` methods to replace the parts a test cares about.
+        pub fn test_default() -> Self {
+            Self {
+                activity_service: Arc::new(MockActivityService::test_default()),
+                file_parser: Arc::new(MockFileParser::test_default()),
+                training_metrics_service: Arc::new(MockTrainingService::test_default()),
+                preferences_service: Arc::new(MockPreferencesService::test_default()),
+                upload_jobs: Arc::default(),
+                upload_limiter: Arc::default(),
+                page_size: Arc::default(),
+            }
+        }
+    }
+
+    impl<AS: IActivityService, PF: ParseFile, TMS: ITrainingService, PS: IPreferencesService>
+        AppState<AS, PF, TMS, PS>
+    {
+        pub fn with_activity_service<T: IActivityService>(
+            self,
+            activity_service: T,
+        ) -> AppState<T, PF, TMS, PS> {
+            AppState {
+                activity_service: Arc::new(activity_service),
+                file_parser: self.file_parser,
+                training_metrics_service: self.training_metrics_service,
+                preferences_service: self.preferences_service,
+                upload_jobs: self.upload_jobs,
+                upload_limiter: self.upload_limiter,
+                page_size: self.page_size,
+            }
+        }
+
+        pub fn with_file_parser<T: ParseFile>(self, file_parser: T) -> AppState<AS, T, TMS, PS> {
+            AppState {
+                activity_service: self.activity_service,
+                file_parser: Arc::new(file_parser),
+                training_metrics_service: self.training_metrics_service,
+                preferences_service: self.preferences_service,
+                upload_jobs: self.upload_jobs,
+                upload_limiter: self.upload_limiter,
+                page_size: self.page_size,
+            }
+        }
+
+        pub fn with_training_metrics_service<T: ITrainingService>(
+            self,
+            training_metrics_service: T,
+        ) -> AppState<AS, PF, T, PS> {
+            AppState {
+                activity_service: self.activity_service,
+                file_parser: self.file_parser,
+                training_metrics_service: Arc::new(training_metrics_service),
+                preferences_service: self.preferences_service,
+                upload_jobs: self.upload_jobs,
+                upload_limiter: self.upload_limiter,
+                page_size: self.page_size,
+            }
+        }
+
+        pub fn with_preferences_service<T: IPreferencesService>(
+            self,
+            preferences_service: T,
+        ) -> AppState<AS, PF, TMS, T> {
+            AppState {
+                activity_service: self.activity_service,
+                file_parser: self.file_parser,
+                training_metrics_service: self.training_metrics_service,
+                preferences_service: Arc::new(preferences_service),
+                upload_jobs: self.upload_jobs,
+                upload_limiter: self.upload_limiter,
+                page_size: self.page_size,
+            }
+        }
+
+        /// Share `upload_limiter` with the test, to hold its slots while a request is running.
+        pub fn with_upload_limiter(mut self, upload_limiter: Arc<UploadLimiter>) -> Self {
+            self.upload_limiter = upload_limiter;
+            self
+        }
+
+        pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+            self.page_size = Arc::new(page_size);
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::Mutex;
+
+    use axum::extract::ConnectInfo;
+    use tokio::sync::oneshot;
+    use tower::ServiceExt;
+    use tracing::{
+        Subscriber,
+        field::{Field, Visit},
+        span,
+    };
+    use tracing_subscriber::{Layer, layer::Context, layer::SubscriberExt};
+
+    use crate::{
+        config::{LogFormat, SingleUserConfig, TrustedProxy},
+        domain::{
+            models::activity::MaxGpsSpeeds,
+            services::{
+                activity::test_utils::MockActivityService,
+                preferences::tests_utils::MockPreferencesService,
+                training::test_utils::MockTrainingService,
+            },
+        },
+        inbound::parser::test_utils::MockFileParser,
+    };
+
+    use super::*;
+
+    async fn test_server(
+        bind_address: IpAddr,
+    ) -> HttpServer<
+        MockActivityService,
+        MockFileParser,
+        MockTrainingService,
+        DisabledUserService,
+        MockPreferencesService,
+    > {
+        let config = BaseConfig {
+            server_port: 0,
+            bind_address,
+            allow_origin: "http://localhost:5173".to_string(),
+            activities_data_path: "/tmp/activities".to_string(),
+            sqlite_db_path: None,
+            max_concurrent_uploads: 1,
+            upload_queue_size: 0,
+            database_url: None,
+            log_level: None,
+            log_format: LogFormat::Pretty,
+            near_duplicate_window: None,
+            trusted_proxy: TrustedProxy::None,
+            cookie_secure: true,
+            cookie_same_site: SameSite::Strict,
+            cookie_http_only: true,
+            cookie_domain: None,
+            retain_unknown_fit_messages: false,
+            max_gps_speeds: MaxGpsSpeeds::default(),
+            default_page_size: None,
+            max_page_size: None,
+        };
+
+        HttpServer::new(
+            &AppMode::SingleUser(SingleUserConfig { password: None }),
+            MockActivityService::new(),
+            MockFileParser::new(),
+            Arc::new(MockTrainingService::new()),
+            DisabledUserService {},
+            MockPreferencesService::new(),
+            config,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_http_server_binds_to_configured_address() {
+        let server = test_server(IpAddr::V4(Ipv4Addr::LOCALHOST)).await;
+
+        let address = server.local_addr().unwrap();
+        assert_eq!(address.ip(), IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_ne!(address.port(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_allows_put() {
+        let server = test_server(IpAddr::V4(Ipv4Addr::LOCALHOST)).await;
+        let client = axum_test::TestServer::new(server.router);
+
+        let response = client
+            .method(Method::OPTIONS, "/api/activity/id/raw")
+            .add_header("origin", "http://localhost:5173")
+            .add_header("access-control-request-method", "PUT")
+            .await;
+
+        let allowed = response
+            .header("access-control-allow-methods")
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(allowed.split(',').any(|method| method.trim() == "PUT"));
+    }
+
+    /// Records the fields of every span, formatted with their `Debug` implementation.
+    #[derive(Clone, Default)]
+    struct SpanFields(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for SpanFields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for SpanFields {
+        fn on_new_span(&self, attrs: &span::Attributes<'_>, _: &span::Id, _: Context<'_, S>) {
+            attrs.record(&mut self.clone());
+        }
+
+        fn on_record(&self, _: &span::Id, values: &span::Record<'_>, _: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trace_layer_records_forwarded_client_ip() {
+        let fields = SpanFields::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+        let router = Router::new()
+            .route("/", get(|| async {}))
+            .layer(trace_layer(TrustedProxy::Hops(1)));
+
+        let mut request = axum::extract::Request::builder()
+            .uri("/")
+            .header("x-forwarded-for", "203.0.113.7")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 54321))));
+        router.oneshot(request).await.unwrap();
+
+        assert!(
+            fields
+                .0
+                .lock()
+                .unwrap()
+                .contains(&("client_ip".to_string(), "203.0.113.7".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_server_stops_on_shutdown_signal() {
+        let server = test_server(IpAddr::V4(Ipv4Addr::LOCALHOST)).await;
+        let (tx, rx) = oneshot::channel::<()>();
+
+        let handle = tokio::spawn(server.run_until(async move {
+            let _ = rx.await;
+        }));
+        tx.send(()).unwrap();
+
+        let res = tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+            .await
+            .expect("server should stop after shutdown signal")
+            .unwrap();
+        assert!(res.is_ok());
+    }
+}
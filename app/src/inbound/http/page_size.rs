@@ -0,0 +1,56 @@
+//! Number of items returned by the list endpoints, shared by all of them so that operators can
+//! tune it in one place.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageSize {
+    /// Used when a request has no `limit`, all items are returned if not set.
+    default: Option<usize>,
+    /// Upper bound of the `limit` of a request, unbounded if not set.
+    max: Option<usize>,
+}
+
+impl PageSize {
+    pub fn new(default: Option<usize>, max: Option<usize>) -> Self {
+        Self { default, max }
+    }
+
+    /// Number of items to return for a request with the `requested` limit, clamped to the max
+    /// page size. `None` means all items.
+    pub fn limit(&self, requested: Option<usize>) -> Option<usize> {
+        match (requested.or(self.default), self.max) {
+            (Some(limit), Some(max)) => Some(limit.min(max)),
+            (None, Some(max)) => Some(max),
+            (limit, None) => limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_without_page_sizes() {
+        let page_size = PageSize::default();
+
+        assert_eq!(page_size.limit(None), None);
+        assert_eq!(page_size.limit(Some(1000)), Some(1000));
+    }
+
+    #[test]
+    fn test_limit_defaults_and_clamps() {
+        let page_size = PageSize::new(Some(20), Some(100));
+
+        assert_eq!(page_size.limit(None), Some(20));
+        assert_eq!(page_size.limit(Some(50)), Some(50));
+        assert_eq!(page_size.limit(Some(1000)), Some(100));
+    }
+
+    #[test]
+    fn test_limit_with_max_only() {
+        let page_size = PageSize::new(None, Some(100));
+
+        assert_eq!(page_size.limit(None), Some(100));
+        assert_eq!(page_size.limit(Some(10)), Some(10));
+    }
+}
@@ -0,0 +1,168 @@
+//! Registry of upload jobs, so that clients can follow the processing of a (batch) upload while it
+//! is in progress.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::domain::models::UserId;
+
+/// How long the final status of a job stays available once the job is done.
+const JOB_RETENTION: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum UploadJobStatus {
+    Queued,
+    Parsing {
+        file: String,
+    },
+    Stored {
+        created_ids: Vec<String>,
+        unprocessable_files: Vec<String>,
+    },
+    Failed {
+        reason: String,
+    },
+}
+
+impl UploadJobStatus {
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Parsing { .. } => "parsing",
+            Self::Stored { .. } => "stored",
+            Self::Failed { .. } => "failed",
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Stored { .. } | Self::Failed { .. })
+    }
+}
+
+type Jobs = HashMap<String, (UserId, watch::Receiver<UploadJobStatus>)>;
+
+#[derive(Debug, Default)]
+pub struct UploadJobRegistry {
+    jobs: Arc<Mutex<Jobs>>,
+}
+
+impl UploadJobRegistry {
+    /// Register a new job for `user`, in the [UploadJobStatus::Queued] state. Returns `None` if a
+    /// job with the same ID is already known.
+    pub fn register(&self, user: &UserId, job_id: &str) -> Option<UploadJob> {
+        let mut jobs = self.jobs.lock().expect("Upload jobs lock poisoned");
+        if jobs.contains_key(job_id) {
+            return None;
+        }
+
+        let (sender, receiver) = watch::channel(UploadJobStatus::Queued);
+        jobs.insert(job_id.to_string(), (user.clone(), receiver));
+        Some(UploadJob {
+            id: job_id.to_string(),
+            sender,
+            jobs: self.jobs.clone(),
+        })
+    }
+
+    /// Subscribe to the status updates of a job of `user`. The receiver is closed once the job is
+    /// done, after its final status.
+    pub fn subscribe(
+        &self,
+        user: &UserId,
+        job_id: &str,
+    ) -> Option<watch::Receiver<UploadJobStatus>> {
+        let jobs = self.jobs.lock().expect("Upload jobs lock poisoned");
+        jobs.get(job_id)
+            .filter(|(owner, _)| owner == user)
+            .map(|(_, receiver)| receiver.clone())
+    }
+}
+
+/// Handle used to update the status of a job. If dropped before reaching a terminal status (e.g.
+/// the upload was aborted), the job is marked as [UploadJobStatus::Failed].
+#[derive(Debug)]
+pub struct UploadJob {
+    id: String,
+    sender: watch::Sender<UploadJobStatus>,
+    jobs: Arc<Mutex<Jobs>>,
+}
+
+impl UploadJob {
+    pub fn update(&self, status: UploadJobStatus) {
+        self.sender.send_replace(status);
+    }
+}
+
+impl Drop for UploadJob {
+    fn drop(&mut self) {
+        if !self.sender.borrow().is_terminal() {
+            self.sender.send_replace(UploadJobStatus::Failed {
+                reason: "Upload interrupted".to_string(),
+            });
+        }
+
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let jobs = self.jobs.clone();
+        let id = self.id.clone();
+        handle.spawn(async move {
+            tokio::time::sleep(JOB_RETENTION).await;
+            jobs.lock().expect("Upload jobs lock poisoned").remove(&id);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_register_same_job_twice() {
+        let registry = UploadJobRegistry::default();
+        let user = UserId::test_default();
+
+        let _job = registry.register(&user, "job").unwrap();
+
+        assert!(registry.register(&user, "job").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_to_job_of_another_user() {
+        let registry = UploadJobRegistry::default();
+        let _job = registry.register(&UserId::test_default(), "job").unwrap();
+
+        assert!(
+            registry
+                .subscribe(&UserId::from("other".to_string()), "job")
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dropped_job_is_failed() {
+        let registry = UploadJobRegistry::default();
+        let user = UserId::test_default();
+        let job = registry.register(&user, "job").unwrap();
+        job.update(UploadJobStatus::Parsing {
+            file: "test.fit".to_string(),
+        });
+
+        drop(job);
+
+        let receiver = registry.subscribe(&user, "job").unwrap();
+        assert_eq!(
+            *receiver.borrow(),
+            UploadJobStatus::Failed {
+                reason: "Upload interrupted".to_string()
+            }
+        );
+    }
+}
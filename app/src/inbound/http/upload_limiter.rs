@@ -0,0 +1,154 @@
+//! Bound the number of uploaded files parsed at the same time, parsing being CPU and memory
+//! intensive for large files.
+
+use std::sync::Arc;
+
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    task::JoinError,
+};
+
+use crate::config::{DEFAULT_MAX_CONCURRENT_UPLOADS, DEFAULT_UPLOAD_QUEUE_SIZE};
+
+#[derive(Debug)]
+pub struct UploadLimiter {
+    /// One permit per file being parsed.
+    parsing: Arc<Semaphore>,
+    /// One permit per upload either parsing or waiting to parse.
+    uploads: Arc<Semaphore>,
+}
+
+impl Default for UploadLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_UPLOADS, DEFAULT_UPLOAD_QUEUE_SIZE)
+    }
+}
+
+impl UploadLimiter {
+    /// At most `max_concurrent` files are parsed at once, and at most `queue_size` uploads wait
+    /// for their turn. `max_concurrent` is clamped to at least 1.
+    pub fn new(max_concurrent: usize, queue_size: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            parsing: Arc::new(Semaphore::new(max_concurrent)),
+            uploads: Arc::new(Semaphore::new(max_concurrent + queue_size)),
+        }
+    }
+
+    /// Enter the upload queue, returns `None` if the queue is full. The upload leaves the queue
+    /// when the returned [UploadTicket] is dropped.
+    pub fn try_enter(&self) -> Option<UploadTicket> {
+        self.uploads
+            .clone()
+            .try_acquire_owned()
+            .ok()
+            .map(|permit| UploadTicket { _permit: permit })
+    }
+
+    /// Wait for a parsing slot, the slot is released when the returned permit is dropped.
+    pub async fn parsing_slot(&self) -> OwnedSemaphorePermit {
+        self.parsing
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("Upload parsing semaphore is never closed")
+    }
+
+    /// Run `parse` on the blocking thread pool once a parsing slot is free, so that parsing a
+    /// large file does not stall the async runtime. The slot is held until `parse` returns, and
+    /// an error is returned if it panicked.
+    pub async fn parse<T, F>(&self, parse: F) -> Result<T, JoinError>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let slot = self.parsing_slot().await;
+        tokio::task::spawn_blocking(move || {
+            let _slot = slot;
+            parse()
+        })
+        .await
+    }
+}
+
+/// Place of an upload in the queue of an [UploadLimiter].
+#[derive(Debug)]
+pub struct UploadTicket {
+    _permit: OwnedSemaphorePermit,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_try_enter_rejects_when_queue_is_full() {
+        let limiter = UploadLimiter::new(1, 1);
+
+        let first = limiter.try_enter();
+        let second = limiter.try_enter();
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert!(limiter.try_enter().is_none());
+
+        drop(first);
+        assert!(limiter.try_enter().is_some());
+    }
+
+    #[test]
+    fn test_zero_concurrency_is_clamped() {
+        let limiter = UploadLimiter::new(0, 0);
+
+        let ticket = limiter.try_enter();
+        assert!(ticket.is_some());
+        assert!(limiter.try_enter().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_parsing_slot_waits_for_release() {
+        let limiter = UploadLimiter::new(1, 0);
+
+        let slot = limiter.parsing_slot().await;
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), limiter.parsing_slot())
+                .await
+                .is_err()
+        );
+
+        drop(slot);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), limiter.parsing_slot())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_holds_a_parsing_slot_until_done() {
+        let limiter = Arc::new(UploadLimiter::new(1, 0));
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+
+        let parsing = tokio::spawn({
+            let limiter = limiter.clone();
+            async move { limiter.parse(move || wait.recv().is_ok()).await }
+        });
+        while limiter.parsing.available_permits() > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), limiter.parsing_slot())
+                .await
+                .is_err()
+        );
+
+        release.send(()).unwrap();
+        assert!(parsing.await.unwrap().unwrap());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), limiter.parsing_slot())
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -1,27 +1,39 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, slice};
 
 use chrono::{DateTime, FixedOffset};
 use fit_parser::{
-    DataMessage, DataValue, Event, EventField, EventType, FitEnum, FitField, FitParserError,
-    LapField, MesgNum, RecordField, SessionField, Sport as FitSport, SubSport as FitSubSport,
-    parse_fit_messages,
-    utils::{find_field_value_as_float, find_field_value_by_kind},
+    BeatIntervalsField, DataMessage, DataValue, Event, EventField, EventType, FitDecoder, FitEnum,
+    FitField, FitParserError, HrvField, LapField, LengthField, LengthType, MesgNum, RawBbiField,
+    RawDefinition, RawFile, RawRecord, RecordField, RespirationRateField, SessionField,
+    SessionTrigger, Sport as FitSport, StressLevelField, SubSport as FitSubSport,
+    SwimStroke as FitSwimStroke,
+    utils::{
+        StreamStats, find_field_value_as_float, find_field_value_as_uint, find_field_value_by_kind,
+        fit_datetime_to_unix_timestamp, stream_stats,
+    },
 };
 
 use crate::{
     domain::models::activity::{
         ActiveTime, ActivityDuration, ActivityStartTime, ActivityStatistic, ActivityStatistics,
-        ActivityTimeseries, Lap, Sport, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
-        TimeseriesTime, TimeseriesValue,
+        ActivityTimeseries, BeatIntervals, Lap, SessionDetails, SessionSummary, Sport, SwimLength,
+        SwimStroke, Timeseries, TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime,
+        TimeseriesValue, UnknownMessages,
     },
     inbound::parser::{ParseBytesError, ParsedFileContent, SupportedExtension},
 };
 
-/// FIT datetimes have 00:00 Dec 31 1989 as their reference instead of January 1, 1970
-const FIT_DATETIME_OFFSET: usize = 631065600;
-
 pub fn try_fit_bytes_into_domain(bytes: Vec<u8>) -> Result<ParsedFileContent, ParseBytesError> {
-    let Ok(messages) = parse_fit_messages(bytes.clone().into_iter(), false) else {
+    try_fit_bytes_into_domain_with_progress(bytes, &mut |_, _| {})
+}
+
+pub fn try_fit_bytes_into_domain_with_progress(
+    bytes: Vec<u8>,
+    progress: &mut dyn FnMut(usize, usize),
+) -> Result<ParsedFileContent, ParseBytesError> {
+    let mut decoder = FitDecoder::new(false);
+    decoder.feed(&bytes);
+    let Ok(messages) = decoder.finish_with_progress(progress) else {
         return Err(ParseBytesError::InvalidContent);
     };
 
@@ -30,12 +42,23 @@ pub fn try_fit_bytes_into_domain(bytes: Vec<u8>) -> Result<ParsedFileContent, Pa
 
     let duration = extract_duration(&messages);
 
-    let sport = extract_sport(&messages);
+    let sessions = extract_sessions(&messages);
 
-    let timeseries = extract_timeseries(reference_timestamp, &messages)?;
+    let sport = if is_multisport(&sessions) {
+        Sport::Multisport
+    } else {
+        extract_sport(&messages)
+    };
+
+    let timeseries = extract_timeseries(reference_timestamp, &messages)?
+        .with_lengths(extract_lengths(reference_timestamp, &messages));
 
     let statistics = extract_statistics(&messages);
 
+    let beat_intervals = extract_beat_intervals(&messages);
+
+    let gps_accuracy = extract_gps_accuracy(reference_timestamp, &messages);
+
     Ok(ParsedFileContent::new(
         sport,
         start_time,
@@ -44,7 +67,149 @@ pub fn try_fit_bytes_into_domain(bytes: Vec<u8>) -> Result<ParsedFileContent, Pa
         timeseries,
         SupportedExtension::FIT.suffix().to_string(),
         bytes,
-    ))
+    )
+    .with_sessions(sessions)
+    .with_beat_intervals(beat_intervals)
+    .with_gps_accuracy(gps_accuracy))
+}
+
+/// Raw bytes of the fields of the messages whose global number is not in the FIT profile, so
+/// that they are not lost when a device records messages the profile does not know about yet.
+/// Invalid content yields no message.
+pub fn extract_unknown_messages(bytes: &[u8]) -> UnknownMessages {
+    let mut unknown_messages = UnknownMessages::default();
+    let Ok(file) = RawFile::decode(bytes.to_vec()) else {
+        return unknown_messages;
+    };
+
+    let mut definitions: HashMap<u8, RawDefinition> = HashMap::new();
+    for record in file.records {
+        match record {
+            RawRecord::Definition(definition) => {
+                definitions.insert(definition.local_message_type, definition);
+            }
+            RawRecord::Data(data) => {
+                let Some(definition) = definitions.get(&data.local_message_type) else {
+                    continue;
+                };
+                let message = definition.global_message_number;
+                if !matches!(MesgNum::from(message), MesgNum::UnknownVariant(_)) {
+                    continue;
+                }
+                for (field, bytes) in definition.fields.iter().zip(data.fields) {
+                    unknown_messages.push(message, field.number, bytes);
+                }
+            }
+        }
+    }
+
+    unknown_messages
+}
+
+/// Extract a [SessionSummary] for each session message, with statistics computed from that session
+/// only.
+fn extract_sessions(messages: &[DataMessage]) -> Vec<SessionSummary> {
+    messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Session)
+        .map(|session| {
+            let records = session_records(session, messages);
+            let session = slice::from_ref(session);
+            SessionSummary::new(extract_sport(session), extract_statistics(session))
+                .with_details(extract_session_details(session, &records))
+        })
+        .collect()
+}
+
+/// Records timestamped between the start and the end of a `session` message. Empty if the session
+/// does not have both timestamps.
+fn session_records<'a>(session: &DataMessage, messages: &'a [DataMessage]) -> Vec<&'a DataMessage> {
+    let (Some(start), Some(end)) = (
+        datetime_field_value(session, &FitField::Session(SessionField::StartTime)),
+        datetime_field_value(session, &FitField::Session(SessionField::Timestamp)),
+    ) else {
+        return Vec::new();
+    };
+
+    messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Record)
+        .filter(|message| record_timestamp(message).is_some_and(|ts| start <= ts && ts <= end))
+        .collect()
+}
+
+/// Statistics of a channel over `records`, using for each record the first of `fields` it holds
+/// (e.g. the enhanced speed over the speed).
+fn records_stats(records: &[&DataMessage], fields: &[RecordField]) -> StreamStats {
+    let values: Vec<DataValue> = records
+        .iter()
+        .filter_map(|record| {
+            fields.iter().find_map(|kind| {
+                record
+                    .fields
+                    .iter()
+                    .find(|field| matches!(&field.kind, FitField::Record(record_field) if record_field == kind))
+                    .and_then(|field| field.values.first().cloned())
+            })
+        })
+        .collect();
+    stream_stats(&values)
+}
+
+/// Extract the values recorded in a single `session` message. Averages and maximums the session
+/// does not hold are computed from its `records`.
+fn extract_session_details(session: &[DataMessage], records: &[&DataMessage]) -> SessionDetails {
+    let value = |field: SessionField| find_field_value_as_float(session, &FitField::Session(field));
+    let heart_rate = records_stats(records, &[RecordField::HeartRate]);
+    let speed = records_stats(records, &[RecordField::EnhancedSpeed, RecordField::Speed]);
+    let cadence = records_stats(records, &[RecordField::Cadence]);
+    let power = records_stats(records, &[RecordField::Power]);
+    let enum_value = |field: SessionField| {
+        find_field_value_by_kind(session, &FitField::Session(field)).and_then(|values| {
+            values.iter().find_map(|value| match value {
+                DataValue::Enum(FitEnum::SubSport(sub_sport))
+                    if !matches!(sub_sport, FitSubSport::UnknownVariant(_)) =>
+                {
+                    Some(format!("{sub_sport:?}"))
+                }
+                DataValue::Enum(FitEnum::SessionTrigger(trigger))
+                    if !matches!(trigger, SessionTrigger::UnknownVariant(_)) =>
+                {
+                    Some(format!("{trigger:?}"))
+                }
+                _ => None,
+            })
+        })
+    };
+
+    SessionDetails {
+        sub_sport: enum_value(SessionField::SubSport),
+        trigger: enum_value(SessionField::Trigger),
+        total_calories: value(SessionField::TotalCalories),
+        total_ascent: value(SessionField::TotalAscent),
+        total_descent: value(SessionField::TotalDescent),
+        avg_heart_rate: value(SessionField::AvgHeartRate).or(heart_rate.avg),
+        max_heart_rate: value(SessionField::MaxHeartRate).or(heart_rate.max),
+        avg_speed: value(SessionField::EnhancedAvgSpeed)
+            .or_else(|| value(SessionField::AvgSpeed))
+            .or(speed.avg),
+        max_speed: value(SessionField::EnhancedMaxSpeed)
+            .or_else(|| value(SessionField::MaxSpeed))
+            .or(speed.max),
+        avg_cadence: value(SessionField::AvgCadence).or(cadence.avg),
+        max_cadence: value(SessionField::MaxCadence).or(cadence.max),
+        avg_power: value(SessionField::AvgPower).or(power.avg),
+        max_power: value(SessionField::MaxPower).or(power.max),
+        total_training_effect: value(SessionField::TotalTrainingEffect),
+        total_anaerobic_training_effect: value(SessionField::TotalAnaerobicTrainingEffect),
+    }
+}
+
+/// An activity is multisport if it contains sessions of different sports.
+fn is_multisport(sessions: &[SessionSummary]) -> bool {
+    sessions
+        .iter()
+        .any(|session| Some(session.sport()) != sessions.first().map(|first| first.sport()))
 }
 
 fn extract_start_time(messages: &[DataMessage]) -> Option<(ActivityStartTime, u32)> {
@@ -59,35 +224,16 @@ fn extract_start_time(messages: &[DataMessage]) -> Option<(ActivityStartTime, u3
         })
     })?;
 
-    let activity_timestamp = find_field_value_by_kind(
-        messages,
-        &fit_parser::FitField::Activity(fit_parser::ActivityField::Timestamp),
-    )
-    .and_then(|values| {
-        values.iter().find_map(|val| match val {
-            DataValue::DateTime(dt) => Some(dt),
-            _ => None,
-        })
-    })?;
-
-    let activity_local_timestamp = find_field_value_by_kind(
+    // A valid activity file always ends with an activity message
+    find_datetime_value(
         messages,
-        &fit_parser::FitField::Activity(fit_parser::ActivityField::LocalTimestamp),
-    )
-    .and_then(|values| {
-        values.iter().find_map(|val| match val {
-            DataValue::DateTime(dt) => Some(dt),
-            _ => None,
-        })
-    })
-    .unwrap_or(&0);
-
-    let offset = *activity_local_timestamp as isize - *activity_timestamp as isize;
+        &FitField::Activity(fit_parser::ActivityField::Timestamp),
+    )?;
 
     let start_datetime =
-        DateTime::from_timestamp((*start_timestamp as usize + FIT_DATETIME_OFFSET) as i64, 0)?;
+        DateTime::from_timestamp(fit_datetime_to_unix_timestamp(*start_timestamp)?, 0)?;
 
-    let start_datetime_with_offset = match FixedOffset::east_opt(offset as i32) {
+    let start_datetime_with_offset = match extract_timezone_offset(messages) {
         Some(offset) => start_datetime.with_timezone(&offset),
         None => start_datetime.fixed_offset(),
     };
@@ -98,6 +244,38 @@ fn extract_start_time(messages: &[DataMessage]) -> Option<(ActivityStartTime, u3
     ))
 }
 
+/// Offset of the timezone the file was recorded in, derived from the UTC `timestamp` and the
+/// `local_timestamp` of the activity message, or of the monitoring_info message if the former does
+/// not carry both. Returns `None` if no such pair is found or if it does not yield a valid offset.
+fn extract_timezone_offset(messages: &[DataMessage]) -> Option<FixedOffset> {
+    [
+        (
+            FitField::Activity(fit_parser::ActivityField::Timestamp),
+            FitField::Activity(fit_parser::ActivityField::LocalTimestamp),
+        ),
+        (
+            FitField::MonitoringInfo(fit_parser::MonitoringInfoField::Timestamp),
+            FitField::MonitoringInfo(fit_parser::MonitoringInfoField::LocalTimestamp),
+        ),
+    ]
+    .iter()
+    .find_map(|(utc_field, local_field)| {
+        let utc = find_datetime_value(messages, utc_field)?;
+        let local = find_datetime_value(messages, local_field)?;
+        let offset = i32::try_from(local as i64 - utc as i64).ok()?;
+        FixedOffset::east_opt(offset)
+    })
+}
+
+fn find_datetime_value(messages: &[DataMessage], field: &FitField) -> Option<u32> {
+    find_field_value_by_kind(messages, field).and_then(|values| {
+        values.iter().find_map(|val| match val {
+            DataValue::DateTime(dt) => Some(*dt),
+            _ => None,
+        })
+    })
+}
+
 fn extract_duration(messages: &[DataMessage]) -> ActivityDuration {
     find_field_value_as_float(messages, &FitField::Session(SessionField::TotalElapsedTime))
         .map_or_else(ActivityDuration::default, ActivityDuration::from)
@@ -131,6 +309,9 @@ fn extract_sport(messages: &[DataMessage]) -> Sport {
     Sport::from((sport, sub_sport))
 }
 
+/// Some devices do not write records in chronological order (e.g. when interleaving sensors), so
+/// records are sorted by timestamp before building the timeseries, see
+/// [sort_records_by_timestamp].
 fn extract_timeseries(
     reference_timestamp: u32,
     messages: &[DataMessage],
@@ -146,14 +327,18 @@ fn extract_timeseries(
     let mut heart_rate_values = vec![];
     let mut latitude_values = vec![];
     let mut longitude_values = vec![];
+    let mut temperature_values = vec![];
+    let mut grade_values = vec![];
+    let mut respiration_rate_values = vec![];
 
     let mut laps: Vec<Lap> = vec![];
 
     let mut pauses_duration = 0;
     let mut paused = false;
     let mut last_paused_timestamp = None;
+    let mut compressed_distance = CompressedDistance::default();
 
-    for message in messages {
+    for message in sort_records_by_timestamp(messages) {
         if message.message_kind == MesgNum::Event {
             let Some((event, timestamp)) = extract_pause_event(message, reference_timestamp) else {
                 continue;
@@ -197,15 +382,9 @@ fn extract_timeseries(
             continue;
         }
 
-        let Some(timestamp) = message.fields.iter().find_map(|field| match field.kind {
-            FitField::Record(RecordField::Timestamp) => {
-                field.values.iter().find_map(|val| match val {
-                    DataValue::DateTime(timestamp) => timestamp.checked_sub(reference_timestamp),
-                    _ => None,
-                })
-            }
-            _ => None,
-        }) else {
+        let Some(timestamp) = record_timestamp(message)
+            .and_then(|timestamp| timestamp.checked_sub(reference_timestamp))
+        else {
             continue;
         };
         time.push(timestamp as usize);
@@ -229,6 +408,8 @@ fn extract_timeseries(
         });
         heart_rate_values.push(heart_rate);
 
+        let compressed_speed_distance = extract_compressed_speed_distance(message);
+
         let speed = message.fields.iter().find_map(|field| match field.kind {
             FitField::Record(RecordField::Speed) | FitField::Record(RecordField::EnhancedSpeed) => {
                 field.values.iter().find_map(|val| {
@@ -243,6 +424,8 @@ fn extract_timeseries(
             }
             _ => None,
         });
+        let speed =
+            speed.or(compressed_speed_distance.map(|(speed, _)| TimeseriesValue::Float(speed)));
         speed_values.push(speed.clone());
         pace_values.push(speed.and_then(|val| val.inverse()));
 
@@ -286,7 +469,9 @@ fn extract_timeseries(
             }),
             _ => None,
         });
-        distance_values.push(distance);
+        let accumulated_distance =
+            compressed_speed_distance.map(|(_, distance)| compressed_distance.accumulate(distance));
+        distance_values.push(distance.or(accumulated_distance.map(TimeseriesValue::Float)));
 
         let altitude = message.fields.iter().find_map(|field| match field.kind {
             FitField::Record(RecordField::Altitude)
@@ -338,8 +523,68 @@ fn extract_timeseries(
             _ => None,
         });
         longitude_values.push(longitude);
+
+        let temperature = message.fields.iter().find_map(|field| match field.kind {
+            FitField::Record(RecordField::Temperature) => field.values.iter().find_map(|val| {
+                if val.is_invalid() {
+                    return None;
+                }
+                match val {
+                    DataValue::Sint8(temperature) => {
+                        Some(TimeseriesValue::Float(*temperature as f64))
+                    }
+                    _ => None,
+                }
+            }),
+            _ => None,
+        });
+        temperature_values.push(temperature);
+
+        let grade = message.fields.iter().find_map(|field| match field.kind {
+            FitField::Record(RecordField::Grade) => field.values.iter().find_map(|val| {
+                if val.is_invalid() {
+                    return None;
+                }
+                match val {
+                    DataValue::Float32(grade) => Some(TimeseriesValue::Float(*grade as f64)),
+                    _ => None,
+                }
+            }),
+            _ => None,
+        });
+        grade_values.push(grade);
+
+        let respiration_rate = message.fields.iter().find_map(|field| match field.kind {
+            FitField::Record(RecordField::RespirationRate)
+            | FitField::Record(RecordField::EnhancedRespirationRate) => {
+                field.values.iter().find_map(|val| {
+                    if val.is_invalid() {
+                        return None;
+                    }
+                    match val {
+                        DataValue::Uint8(rate) => Some(TimeseriesValue::Float(*rate as f64)),
+                        DataValue::Float32(rate) => Some(TimeseriesValue::Float(*rate as f64)),
+                        _ => None,
+                    }
+                })
+            }
+            _ => None,
+        });
+        respiration_rate_values.push(respiration_rate);
     }
 
+    // Respiration rate messages are only used for the records without a respiration rate
+    let respiration_rate_values = respiration_rate_values
+        .into_iter()
+        .zip(align_samples(
+            &respiration_rate_samples(reference_timestamp, messages),
+            &time,
+        ))
+        .map(|(record, sample)| record.or(sample))
+        .collect();
+    let stress_level_values =
+        align_samples(&stress_level_samples(reference_timestamp, messages), &time);
+
     let metrics = vec![
         Timeseries::new(TimeseriesMetric::Speed, speed_values),
         Timeseries::new(TimeseriesMetric::Pace, pace_values),
@@ -350,6 +595,10 @@ fn extract_timeseries(
         Timeseries::new(TimeseriesMetric::Altitude, altitude_values),
         Timeseries::new(TimeseriesMetric::Longitude, longitude_values),
         Timeseries::new(TimeseriesMetric::Latitude, latitude_values),
+        Timeseries::new(TimeseriesMetric::Temperature, temperature_values),
+        Timeseries::new(TimeseriesMetric::Grade, grade_values),
+        Timeseries::new(TimeseriesMetric::RespirationRate, respiration_rate_values),
+        Timeseries::new(TimeseriesMetric::StressLevel, stress_level_values),
     ];
 
     ActivityTimeseries::new(
@@ -361,6 +610,190 @@ fn extract_timeseries(
     .map_err(|_err| ParseBytesError::IncoherentTimeseriesLengths)
 }
 
+/// Accuracy, in meters, of the position of each record, aligned with the timeseries built by
+/// [extract_timeseries]: records are sorted the same way, and the ones it skips are skipped.
+fn extract_gps_accuracy(reference_timestamp: u32, messages: &[DataMessage]) -> Vec<Option<f64>> {
+    sort_records_by_timestamp(messages)
+        .into_iter()
+        .filter(|message| message.message_kind == MesgNum::Record)
+        .filter(|message| {
+            record_timestamp(message)
+                .and_then(|timestamp| timestamp.checked_sub(reference_timestamp))
+                .is_some()
+        })
+        .map(|message| {
+            find_field_value_as_float(
+                slice::from_ref(message),
+                &FitField::Record(RecordField::GpsAccuracy),
+            )
+        })
+        .collect()
+}
+
+/// Speed (m/s) and raw distance (1/16 m, rolling over every 256 m) packed by older devices in the
+/// 3 bytes of a record `compressed_speed_distance` field: 12 bits of speed in 1/100 m/s followed
+/// by 12 bits of distance.
+/// Maximum number of seconds a sample of a message sparser than records (respiration rate, stress
+/// level) is held for when aligned on the records, see [align_samples].
+const SPARSE_SAMPLE_MAX_AGE: u32 = 300;
+
+/// `(seconds since reference_timestamp, breaths per minute)` of the `respiration_rate` messages.
+/// Negative values, written when the rate could not be measured, are skipped.
+fn respiration_rate_samples(
+    reference_timestamp: u32,
+    messages: &[DataMessage],
+) -> Vec<(u32, TimeseriesValue)> {
+    messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::RespirationRate)
+        .filter_map(|message| {
+            let timestamp = datetime_field_value(
+                message,
+                &FitField::RespirationRate(RespirationRateField::Timestamp),
+            )?;
+            let rate = find_field_value_as_float(
+                slice::from_ref(message),
+                &FitField::RespirationRate(RespirationRateField::RespirationRate),
+            )?;
+            (rate >= 0.).then_some((
+                timestamp.checked_sub(reference_timestamp)?,
+                TimeseriesValue::Float(rate),
+            ))
+        })
+        .collect()
+}
+
+/// `(seconds since reference_timestamp, stress level)` of the `stress_level` messages. Negative
+/// levels, written when the stress could not be measured (e.g. during intense efforts), are
+/// skipped.
+fn stress_level_samples(
+    reference_timestamp: u32,
+    messages: &[DataMessage],
+) -> Vec<(u32, TimeseriesValue)> {
+    messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::StressLevel)
+        .filter_map(|message| {
+            let timestamp = datetime_field_value(
+                message,
+                &FitField::StressLevel(StressLevelField::StressLevelTime),
+            )?;
+            let level = find_field_value_as_float(
+                slice::from_ref(message),
+                &FitField::StressLevel(StressLevelField::StressLevelValue),
+            )?;
+            (level >= 0.).then_some((
+                timestamp.checked_sub(reference_timestamp)?,
+                TimeseriesValue::Int(level as usize),
+            ))
+        })
+        .collect()
+}
+
+/// Raw timestamp held by the `field` of a message.
+fn datetime_field_value(message: &DataMessage, field: &FitField) -> Option<u32> {
+    find_field_value_by_kind(slice::from_ref(message), field)?
+        .iter()
+        .find_map(|value| match value {
+            DataValue::DateTime(timestamp) => Some(*timestamp),
+            _ => None,
+        })
+}
+
+/// Align `samples` on the records' `time`: each record takes the value of the last sample at or
+/// before it, as long as it is at most [SPARSE_SAMPLE_MAX_AGE] seconds old.
+fn align_samples(
+    samples: &[(u32, TimeseriesValue)],
+    time: &[usize],
+) -> Vec<Option<TimeseriesValue>> {
+    let mut samples = samples.to_vec();
+    samples.sort_by_key(|(timestamp, _)| *timestamp);
+
+    let mut next = 0;
+    time.iter()
+        .map(|timestamp| {
+            while next < samples.len() && samples[next].0 as usize <= *timestamp {
+                next += 1;
+            }
+            let (sample_timestamp, value) = samples[..next].last()?;
+            (*timestamp - *sample_timestamp as usize <= SPARSE_SAMPLE_MAX_AGE as usize)
+                .then(|| value.clone())
+        })
+        .collect()
+}
+
+/// Raw timestamp of a record message.
+fn record_timestamp(message: &DataMessage) -> Option<u32> {
+    message.fields.iter().find_map(|field| match field.kind {
+        FitField::Record(RecordField::Timestamp) => field.values.iter().find_map(|val| match val {
+            DataValue::DateTime(timestamp) => Some(*timestamp),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+/// View of `messages` where the records are sorted by timestamp. Each record takes the place of
+/// another record, so other messages (events, laps) keep their position. The sort is stable:
+/// records with the same timestamp keep their order, and records without a timestamp come first.
+///
+/// `messages` is left untouched, the raw order is still the one used to extract sessions and
+/// statistics, and the one of the original file stored with the activity.
+fn sort_records_by_timestamp(messages: &[DataMessage]) -> Vec<&DataMessage> {
+    let mut records = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Record)
+        .collect::<Vec<_>>();
+    records.sort_by_key(|record| record_timestamp(record));
+
+    let mut records = records.into_iter();
+    messages
+        .iter()
+        .map(|message| match message.message_kind {
+            MesgNum::Record => records.next().unwrap_or(message),
+            _ => message,
+        })
+        .collect()
+}
+
+fn extract_compressed_speed_distance(message: &DataMessage) -> Option<(f64, u16)> {
+    message.fields.iter().find_map(|field| match field.kind {
+        FitField::Record(RecordField::CompressedSpeedDistance) => {
+            field.values.iter().find_map(|val| {
+                if val.is_invalid() {
+                    return None;
+                }
+                match val.as_bytes()? {
+                    [first, second, third] => {
+                        let speed = *first as u16 | ((*second as u16 & 0x0F) << 8);
+                        let distance = (*second as u16 >> 4) | ((*third as u16) << 4);
+                        Some((speed as f64 / 100., distance))
+                    }
+                    _ => None,
+                }
+            })
+        }
+        _ => None,
+    })
+}
+
+/// Total distance accumulated from the rolling 12 bits distance of compressed records.
+#[derive(Debug, Default)]
+struct CompressedDistance {
+    last: Option<u16>,
+    total: f64,
+}
+
+impl CompressedDistance {
+    /// Add the distance travelled since the last compressed value and return the total, in meters.
+    fn accumulate(&mut self, raw: u16) -> f64 {
+        let delta = raw.wrapping_sub(self.last.unwrap_or(0)) & 0x0FFF;
+        self.last = Some(raw);
+        self.total += delta as f64 / 16.;
+        self.total
+    }
+}
+
 enum PauseEvent {
     Start,
     Stop,
@@ -444,6 +877,76 @@ fn extract_lap(message: &DataMessage, reference_timestamp: u32) -> Option<Lap> {
     ))
 }
 
+/// Extract the active pool lengths of a swim, idle lengths (i.e. rests at the wall) are skipped.
+fn extract_lengths(reference_timestamp: u32, messages: &[DataMessage]) -> Vec<SwimLength> {
+    messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Length)
+        .filter_map(|message| extract_length(message, reference_timestamp))
+        .collect()
+}
+
+fn extract_length(message: &DataMessage, reference_timestamp: u32) -> Option<SwimLength> {
+    let message = slice::from_ref(message);
+
+    let length_type = find_field_value_by_kind(message, &FitField::Length(LengthField::LengthType))
+        .and_then(|values| {
+            values.iter().find_map(|value| match value {
+                DataValue::Enum(FitEnum::LengthType(length_type)) => Some(length_type),
+                _ => None,
+            })
+        });
+    if matches!(length_type, Some(LengthType::Idle)) {
+        return None;
+    }
+
+    let start = find_field_value_by_kind(message, &FitField::Length(LengthField::StartTime))
+        .and_then(|values| {
+            values.iter().find_map(|value| match value {
+                DataValue::DateTime(dt) => dt.checked_sub(reference_timestamp),
+                _ => None,
+            })
+        })?;
+    let duration =
+        find_field_value_as_float(message, &FitField::Length(LengthField::TotalTimerTime))?;
+
+    let stroke = find_field_value_by_kind(message, &FitField::Length(LengthField::SwimStroke))
+        .and_then(|values| {
+            values.iter().find_map(|value| match value {
+                DataValue::Enum(FitEnum::SwimStroke(stroke)) => SwimStroke::try_from(stroke).ok(),
+                _ => None,
+            })
+        });
+    let total_strokes =
+        find_field_value_as_uint(message, &FitField::Length(LengthField::TotalStrokes));
+
+    Some(SwimLength::new(
+        start as usize,
+        duration,
+        stroke,
+        total_strokes,
+    ))
+}
+
+impl TryFrom<&FitSwimStroke> for SwimStroke {
+    type Error = ();
+
+    fn try_from(value: &FitSwimStroke) -> Result<Self, Self::Error> {
+        match value {
+            FitSwimStroke::Freestyle => Ok(Self::Freestyle),
+            FitSwimStroke::Backstroke => Ok(Self::Backstroke),
+            FitSwimStroke::Breaststroke => Ok(Self::Breaststroke),
+            FitSwimStroke::Butterfly => Ok(Self::Butterfly),
+            FitSwimStroke::Drill => Ok(Self::Drill),
+            FitSwimStroke::Mixed => Ok(Self::Mixed),
+            FitSwimStroke::Im | FitSwimStroke::ImByRound | FitSwimStroke::Rimo => {
+                Ok(Self::IndividualMedley)
+            }
+            FitSwimStroke::UnknownVariant(_) => Err(()),
+        }
+    }
+}
+
 fn extract_statistics(messages: &[DataMessage]) -> ActivityStatistics {
     let mut stats = HashMap::new();
     let pairs = [
@@ -475,9 +978,80 @@ fn extract_statistics(messages: &[DataMessage]) -> ActivityStatistics {
         }
     }
 
+    if let Some(respiration_rate) = find_field_value_as_float(
+        messages,
+        &FitField::Session(SessionField::EnhancedAvgRespirationRate),
+    )
+    .or_else(|| {
+        find_field_value_as_float(
+            messages,
+            &FitField::Session(SessionField::AvgRespirationRate),
+        )
+    }) {
+        stats.insert(ActivityStatistic::RespirationRate, respiration_rate);
+    }
+
+    if let Some(rmssd) = compute_rmssd(&extract_beat_intervals(messages)) {
+        stats.insert(ActivityStatistic::HeartRateVariability, rmssd);
+    }
+
     ActivityStatistics::new(stats)
 }
 
+/// Extract the beat-to-beat (RR) intervals from the `hrv` messages, or from the `beat_intervals`
+/// and `raw_bbi` messages recorded by newer devices if there is none. Each message holds several
+/// intervals, the unused trailing slots being filled with invalid values which end its array.
+fn extract_beat_intervals(messages: &[DataMessage]) -> BeatIntervals {
+    let hrv_intervals: Vec<f32> = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Hrv)
+        .flat_map(|message| message.fields.iter())
+        .filter(|field| field.kind == FitField::Hrv(HrvField::Time))
+        .flat_map(|field| field.values.iter().take_while(|value| !value.is_invalid()))
+        .filter_map(|value| match value {
+            // Already scaled to seconds
+            DataValue::Float32(interval) => Some(*interval),
+            _ => None,
+        })
+        .collect();
+    if !hrv_intervals.is_empty() {
+        return BeatIntervals::new(hrv_intervals);
+    }
+
+    BeatIntervals::new(
+        messages
+            .iter()
+            .flat_map(|message| message.fields.iter())
+            .filter(|field| {
+                field.kind == FitField::BeatIntervals(BeatIntervalsField::Time)
+                    || field.kind == FitField::RawBbi(RawBbiField::Time)
+            })
+            .flat_map(|field| field.values.iter().take_while(|value| !value.is_invalid()))
+            .filter_map(|value| match value {
+                // In milliseconds
+                DataValue::Uint16(interval) => Some(*interval as f32 / 1000.),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Root mean square of successive differences between beat intervals, in milliseconds, the usual
+/// short-term heart rate variability measure. Needs at least 2 intervals.
+fn compute_rmssd(intervals: &BeatIntervals) -> Option<f64> {
+    let intervals = intervals.values();
+    if intervals.len() < 2 {
+        return None;
+    }
+
+    let sum_of_squares: f64 = intervals
+        .windows(2)
+        .map(|pair| ((pair[1] as f64 - pair[0] as f64) * 1000.).powi(2))
+        .sum();
+
+    Some((sum_of_squares / (intervals.len() - 1) as f64).sqrt())
+}
+
 impl From<FitParserError> for ParseBytesError {
     fn from(_value: FitParserError) -> Self {
         Self::InvalidContent
@@ -589,7 +1163,7 @@ impl From<(&FitSport, Option<&FitSubSport>)> for Sport {
 
             FitSport::Generic => Self::Other,
             FitSport::Transition => Self::Other,
-            FitSport::Multisport => Self::Other,
+            FitSport::Multisport => Self::Multisport,
             FitSport::Flying => Self::Other,
             FitSport::Motorcycling => Self::Other,
             FitSport::Boating => Self::Other,
@@ -619,9 +1193,15 @@ mod tests_fit_parser {
 
     use assert_approx_eq::assert_approx_eq;
     use chrono::{DateTime, FixedOffset, Utc};
-    use fit_parser::{ActivityField, DataMessageField};
+    use fit_parser::{
+        ActivityField, DataMessageField, MonitoringInfoField, RawData, RawFieldDefinition,
+        utils::FIT_EPOCH_OFFSET,
+    };
 
-    use crate::domain::models::activity::{TimeseriesMetric, TimeseriesValue};
+    use crate::{
+        domain::models::activity::{MaxGpsAccuracy, TimeseriesMetric, TimeseriesValue},
+        inbound::parser::{ParseFile, Parser},
+    };
 
     use super::*;
 
@@ -675,7 +1255,7 @@ mod tests_fit_parser {
 
     #[test]
     fn test_fit_datetime_reference_utc_offset() {
-        let fit_zero_datetime = DateTime::from_timestamp(FIT_DATETIME_OFFSET as i64, 0).unwrap();
+        let fit_zero_datetime = DateTime::from_timestamp(FIT_EPOCH_OFFSET, 0).unwrap();
         let expected = "1989-12-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
 
         assert_eq!(fit_zero_datetime, expected);
@@ -700,6 +1280,22 @@ mod tests_fit_parser {
         );
     }
 
+    #[test]
+    fn test_parse_fit_reports_progress() {
+        let content = fs::read("src/inbound/parser/test.fit").unwrap();
+        let total = content.len();
+
+        let mut reports = Vec::new();
+        try_fit_bytes_into_domain_with_progress(content, &mut |consumed, total| {
+            reports.push((consumed, total))
+        })
+        .unwrap();
+
+        assert!(reports.len() > 1);
+        assert!(reports.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(reports.last(), Some(&(total, total)));
+    }
+
     #[test]
     fn test_parsing_of_timeseries() {
         let content = fs::read("src/inbound/parser/test.fit").unwrap();
@@ -754,6 +1350,7 @@ mod tests_fit_parser {
             fields: vec![DataMessageField {
                 kind: FitField::Record(RecordField::Timestamp),
                 values: vec![DataValue::DateTime(10)],
+                is_scaled: false,
             }],
         }];
         let reference = 10;
@@ -767,25 +1364,67 @@ mod tests_fit_parser {
     }
 
     #[test]
-    fn test_extract_timeseries_skip_records_without_timestamp() {
-        let messages = vec![DataMessage {
+    fn test_extract_timeseries_sorts_records_by_timestamp() {
+        let record = |timestamp: u32, heart_rate: u8| DataMessage {
             local_message_type: 0,
             message_kind: MesgNum::Record,
-            fields: vec![DataMessageField {
-                kind: FitField::Record(RecordField::Power),
-                values: vec![DataValue::Uint16(12)],
-            }],
-        }];
-        let reference = 10;
-
-        let timeseries = extract_timeseries(reference, &messages);
-        assert!(timeseries.is_ok());
-        let timeseries = timeseries.unwrap();
-
-        assert_eq!(timeseries.time().len(), 0);
-    }
-
-    #[test]
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Timestamp),
+                    values: vec![DataValue::DateTime(timestamp)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::HeartRate),
+                    values: vec![DataValue::Uint8(heart_rate)],
+                    is_scaled: false,
+                },
+            ],
+        };
+        let messages = vec![
+            record(10, 100),
+            record(12, 120),
+            record(11, 110),
+            record(14, 140),
+            record(11, 111),
+            record(13, 130),
+        ];
+
+        let timeseries = extract_timeseries(10, &messages).unwrap();
+
+        assert_eq!(timeseries.time().values(), &[0, 1, 1, 2, 3, 4]);
+        assert_eq!(
+            timeseries.active_time().values(),
+            [0, 1, 1, 2, 3, 4].map(ActiveTime::Running)
+        );
+        let TestMetrics { heart_rate, .. } = extract_metrics(&timeseries);
+        assert_eq!(
+            heart_rate.unwrap(),
+            [100, 110, 111, 120, 130, 140].map(|hr| Some(TimeseriesValue::Int(hr)))
+        );
+    }
+
+    #[test]
+    fn test_extract_timeseries_skip_records_without_timestamp() {
+        let messages = vec![DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![DataMessageField {
+                kind: FitField::Record(RecordField::Power),
+                values: vec![DataValue::Uint16(12)],
+                is_scaled: false,
+            }],
+        }];
+        let reference = 10;
+
+        let timeseries = extract_timeseries(reference, &messages);
+        assert!(timeseries.is_ok());
+        let timeseries = timeseries.unwrap();
+
+        assert_eq!(timeseries.time().len(), 0);
+    }
+
+    #[test]
     fn test_extract_timeseries_skip_records_before_reference() {
         let messages = vec![DataMessage {
             local_message_type: 0,
@@ -793,6 +1432,7 @@ mod tests_fit_parser {
             fields: vec![DataMessageField {
                 kind: FitField::Record(RecordField::Timestamp),
                 values: vec![DataValue::DateTime(5)],
+                is_scaled: false,
             }],
         }];
         let reference = 10;
@@ -814,10 +1454,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Power),
                         values: vec![DataValue::Uint16(12)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -828,10 +1470,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(11)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Power),
                         values: vec![DataValue::Uint16(u16::MAX)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -864,10 +1508,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::HeartRate),
                         values: vec![DataValue::Uint8(120)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -878,10 +1524,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(11)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::HeartRate),
                         values: vec![DataValue::Uint8(u8::MAX)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -914,10 +1562,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Distance),
                         values: vec![DataValue::Float32(120.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -928,10 +1578,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(11)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Distance),
                         values: vec![DataValue::Uint32(u32::MAX)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -967,10 +1619,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Speed),
                         values: vec![DataValue::Float32(12.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -981,12 +1635,14 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(11)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::Speed),
                         values: vec![DataValue::Float32(f32::from_le_bytes([
                             0xFF, 0xFF, 0xFF, 0xFF,
                         ]))],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1018,10 +1674,12 @@ mod tests_fit_parser {
                 DataMessageField {
                     kind: FitField::Record(RecordField::Timestamp),
                     values: vec![DataValue::DateTime(10)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::Record(RecordField::EnhancedSpeed),
                     values: vec![DataValue::Float32(12.)],
+                    is_scaled: false,
                 },
             ],
         }];
@@ -1040,6 +1698,473 @@ mod tests_fit_parser {
         assert_eq!(*speed.first().unwrap(), Some(TimeseriesValue::Float(12.)));
     }
 
+    /// Record with a `compressed_speed_distance` field, `speed` in 1/100 m/s and `distance` in
+    /// 1/16 m (both on 12 bits), and optional extra fields.
+    #[test]
+    fn test_inaccurate_positions_are_excluded_from_track() {
+        let record = |timestamp: u32, latitude: f64, accuracy: u8| DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Timestamp),
+                    values: vec![DataValue::DateTime(timestamp)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::PositionLat),
+                    values: vec![DataValue::Sint32(
+                        (latitude * (2.0_f64).powi(31) / 180.) as i32,
+                    )],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::PositionLong),
+                    values: vec![DataValue::Sint32(0)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::GpsAccuracy),
+                    values: vec![DataValue::Uint8(accuracy)],
+                    is_scaled: false,
+                },
+            ],
+        };
+        // The last record comes first, sorting must keep the accuracies aligned
+        let messages = vec![
+            record(13, 45.0003, 4),
+            record(10, 45., 3),
+            record(11, 45.0001, 80),
+            record(12, 45.0002, 5),
+        ];
+        let reference = 10;
+
+        let gps_accuracy = extract_gps_accuracy(reference, &messages);
+        assert_eq!(gps_accuracy, vec![Some(3.), Some(80.), Some(5.), Some(4.)]);
+
+        let parsed = ParsedFileContent::new(
+            Sport::Running,
+            ActivityStartTime::from_timestamp(1000).unwrap(),
+            ActivityDuration::from(3.),
+            ActivityStatistics::default(),
+            extract_timeseries(reference, &messages).unwrap(),
+            "fit".to_string(),
+            vec![],
+        )
+        .with_gps_accuracy(gps_accuracy)
+        .without_inaccurate_positions(&MaxGpsAccuracy::try_from(20.).unwrap());
+
+        let track = parsed.timeseries().positions();
+        assert_eq!(track.len(), 3);
+        assert!(
+            track
+                .iter()
+                .all(|(latitude, _)| (latitude - 45.0001).abs() > 1e-6)
+        );
+        assert_eq!(
+            parsed
+                .statistics()
+                .get(&ActivityStatistic::RemovedGpsPoints),
+            Some(&1.)
+        );
+    }
+
+    fn compressed_record(
+        timestamp: u32,
+        speed: u16,
+        distance: u16,
+        mut fields: Vec<DataMessageField>,
+    ) -> DataMessage {
+        let bytes = vec![
+            (speed & 0xFF) as u8,
+            ((speed >> 8) & 0x0F) as u8 | ((distance & 0x0F) << 4) as u8,
+            ((distance >> 4) & 0xFF) as u8,
+        ];
+        fields.append(&mut vec![
+            DataMessageField {
+                kind: FitField::Record(RecordField::Timestamp),
+                values: vec![DataValue::DateTime(timestamp)],
+                is_scaled: false,
+            },
+            DataMessageField {
+                kind: FitField::Record(RecordField::CompressedSpeedDistance),
+                values: vec![DataValue::Byte(bytes)],
+                is_scaled: false,
+            },
+        ]);
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_extract_timeseries_compressed_speed_distance() {
+        // Distance rolls over after 4096 / 16 = 256 m
+        let messages = vec![
+            compressed_record(10, 350, 100 * 16, vec![]),
+            compressed_record(11, 425, 200 * 16, vec![]),
+            compressed_record(12, 0, (300 * 16) % 4096, vec![]),
+        ];
+
+        let timeseries = extract_timeseries(10, &messages).unwrap();
+
+        let TestMetrics {
+            speed, distance, ..
+        } = extract_metrics(&timeseries);
+        assert_eq!(
+            speed.unwrap(),
+            &[
+                Some(TimeseriesValue::Float(3.5)),
+                Some(TimeseriesValue::Float(4.25)),
+                Some(TimeseriesValue::Float(0.)),
+            ]
+        );
+        assert_eq!(
+            distance.unwrap(),
+            &[
+                Some(TimeseriesValue::Float(100.)),
+                Some(TimeseriesValue::Float(200.)),
+                Some(TimeseriesValue::Float(300.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_timeseries_dedicated_fields_over_compressed_speed_distance() {
+        let messages = vec![compressed_record(
+            10,
+            350,
+            100 * 16,
+            vec![
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Speed),
+                    values: vec![DataValue::Float32(5.)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Distance),
+                    values: vec![DataValue::Float32(1000.)],
+                    is_scaled: false,
+                },
+            ],
+        )];
+
+        let timeseries = extract_timeseries(10, &messages).unwrap();
+
+        let TestMetrics {
+            speed, distance, ..
+        } = extract_metrics(&timeseries);
+        assert_eq!(speed.unwrap(), &[Some(TimeseriesValue::Float(5.))]);
+        assert_eq!(distance.unwrap(), &[Some(TimeseriesValue::Float(1000.))]);
+    }
+
+    fn session_message(sport: FitSport, distance: f32) -> DataMessage {
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Session,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Session(SessionField::Sport),
+                    values: vec![DataValue::Enum(FitEnum::Sport(sport))],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Session(SessionField::TotalDistance),
+                    values: vec![DataValue::Float32(distance)],
+                    is_scaled: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_extract_sessions_of_multisport_activity() {
+        let messages = vec![
+            session_message(FitSport::Swimming, 1500.),
+            session_message(FitSport::Cycling, 40000.),
+        ];
+
+        let sessions = extract_sessions(&messages);
+
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].sport(), &Sport::Swimming);
+        assert_eq!(
+            sessions[0].statistics().get(&ActivityStatistic::Distance),
+            Some(&1500.)
+        );
+        assert_eq!(sessions[1].sport(), &Sport::Cycling);
+        assert_eq!(
+            sessions[1].statistics().get(&ActivityStatistic::Distance),
+            Some(&40000.)
+        );
+        assert!(is_multisport(&sessions));
+    }
+
+    #[test]
+    fn test_extract_session_details() {
+        let field = |kind: SessionField, value: DataValue| DataMessageField {
+            kind: FitField::Session(kind),
+            values: vec![value],
+            is_scaled: false,
+        };
+        let messages = vec![DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Session,
+            fields: vec![
+                field(
+                    SessionField::Sport,
+                    DataValue::Enum(FitEnum::Sport(FitSport::Running)),
+                ),
+                field(
+                    SessionField::SubSport,
+                    DataValue::Enum(FitEnum::SubSport(FitSubSport::Trail)),
+                ),
+                field(
+                    SessionField::Trigger,
+                    DataValue::Enum(FitEnum::SessionTrigger(SessionTrigger::ActivityEnd)),
+                ),
+                field(SessionField::TotalCalories, DataValue::Uint16(812)),
+                field(SessionField::TotalAscent, DataValue::Uint16(540)),
+                field(SessionField::TotalDescent, DataValue::Uint16(535)),
+                field(SessionField::AvgHeartRate, DataValue::Uint8(148)),
+                field(SessionField::MaxHeartRate, DataValue::Uint8(181)),
+                field(SessionField::AvgSpeed, DataValue::Float32(2.5)),
+                field(SessionField::EnhancedAvgSpeed, DataValue::Float32(2.75)),
+                field(SessionField::MaxSpeed, DataValue::Float32(4.5)),
+                field(SessionField::AvgCadence, DataValue::Uint8(84)),
+                field(SessionField::MaxCadence, DataValue::Uint8(97)),
+                field(SessionField::AvgPower, DataValue::Uint16(265)),
+                field(SessionField::MaxPower, DataValue::Uint16(612)),
+                field(SessionField::TotalTrainingEffect, DataValue::Float32(3.5)),
+                field(
+                    SessionField::TotalAnaerobicTrainingEffect,
+                    DataValue::Float32(1.25),
+                ),
+            ],
+        }];
+
+        let sessions = extract_sessions(&messages);
+
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].sport(), &Sport::TrailRunning);
+        assert_eq!(
+            sessions[0].details(),
+            &SessionDetails {
+                sub_sport: Some("Trail".to_string()),
+                trigger: Some("ActivityEnd".to_string()),
+                total_calories: Some(812.),
+                total_ascent: Some(540.),
+                total_descent: Some(535.),
+                avg_heart_rate: Some(148.),
+                max_heart_rate: Some(181.),
+                avg_speed: Some(2.75),
+                max_speed: Some(4.5),
+                avg_cadence: Some(84.),
+                max_cadence: Some(97.),
+                avg_power: Some(265.),
+                max_power: Some(612.),
+                total_training_effect: Some(3.5),
+                total_anaerobic_training_effect: Some(1.25),
+            }
+        );
+    }
+
+    #[test]
+    fn test_extract_session_details_from_records() {
+        let field = |kind: FitField, value: DataValue| DataMessageField {
+            kind,
+            values: vec![value],
+            is_scaled: false,
+        };
+        let record = |timestamp: u32, heart_rate: u8, power: u16| DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![
+                field(
+                    FitField::Record(RecordField::Timestamp),
+                    DataValue::DateTime(timestamp),
+                ),
+                field(
+                    FitField::Record(RecordField::HeartRate),
+                    DataValue::Uint8(heart_rate),
+                ),
+                field(
+                    FitField::Record(RecordField::Power),
+                    DataValue::Uint16(power),
+                ),
+            ],
+        };
+        let messages = vec![
+            record(5, 200, 1000),
+            record(10, 120, 200),
+            record(11, u8::MAX, u16::MAX),
+            record(12, 140, 300),
+            DataMessage {
+                local_message_type: 0,
+                message_kind: MesgNum::Session,
+                fields: vec![
+                    field(
+                        FitField::Session(SessionField::StartTime),
+                        DataValue::DateTime(10),
+                    ),
+                    field(
+                        FitField::Session(SessionField::Timestamp),
+                        DataValue::DateTime(12),
+                    ),
+                    field(
+                        FitField::Session(SessionField::MaxPower),
+                        DataValue::Uint16(612),
+                    ),
+                ],
+            },
+        ];
+
+        let sessions = extract_sessions(&messages);
+
+        // The record before the session start and the invalid values are ignored, and the values
+        // held by the session take precedence
+        let details = sessions[0].details();
+        assert_eq!(details.avg_heart_rate, Some(130.));
+        assert_eq!(details.max_heart_rate, Some(140.));
+        assert_eq!(details.avg_power, Some(250.));
+        assert_eq!(details.max_power, Some(612.));
+        assert_eq!(details.avg_speed, None);
+    }
+
+    #[test]
+    fn test_extract_session_details_missing_values() {
+        let sessions = extract_sessions(&[session_message(FitSport::Running, 10000.)]);
+
+        assert_eq!(sessions[0].details(), &SessionDetails::default());
+    }
+
+    fn length_message(
+        start_time: u32,
+        duration: f32,
+        length_type: LengthType,
+        stroke: FitSwimStroke,
+        total_strokes: u16,
+    ) -> DataMessage {
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Length,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Length(LengthField::StartTime),
+                    values: vec![DataValue::DateTime(start_time)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Length(LengthField::TotalTimerTime),
+                    values: vec![DataValue::Float32(duration)],
+                    is_scaled: true,
+                },
+                DataMessageField {
+                    kind: FitField::Length(LengthField::LengthType),
+                    values: vec![DataValue::Enum(FitEnum::LengthType(length_type))],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Length(LengthField::SwimStroke),
+                    values: vec![DataValue::Enum(FitEnum::SwimStroke(stroke))],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Length(LengthField::TotalStrokes),
+                    values: vec![DataValue::Uint16(total_strokes)],
+                    is_scaled: false,
+                },
+            ],
+        }
+    }
+
+    /// A 25m pool swim: a freestyle length, a rest at the wall, a drill length without stroke
+    /// count, a length whose stroke was not detected, and a mixed length.
+    fn swim_fixture(reference_timestamp: u32) -> Vec<DataMessage> {
+        vec![
+            length_message(
+                reference_timestamp,
+                30.4,
+                LengthType::Active,
+                FitSwimStroke::Freestyle,
+                16,
+            ),
+            length_message(
+                reference_timestamp + 30,
+                20.,
+                LengthType::Idle,
+                FitSwimStroke::UnknownVariant(255),
+                0,
+            ),
+            length_message(
+                reference_timestamp + 50,
+                45.,
+                LengthType::Active,
+                FitSwimStroke::Drill,
+                0xFFFF,
+            ),
+            length_message(
+                reference_timestamp + 95,
+                35.,
+                LengthType::Active,
+                FitSwimStroke::UnknownVariant(255),
+                20,
+            ),
+            length_message(
+                reference_timestamp + 130,
+                38.,
+                LengthType::Active,
+                FitSwimStroke::Mixed,
+                22,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_extract_lengths_of_pool_swim() {
+        let reference_timestamp = 1000;
+
+        let lengths = extract_lengths(reference_timestamp, &swim_fixture(reference_timestamp));
+
+        assert_eq!(lengths.len(), 4, "Idle length should be skipped");
+
+        assert_eq!(lengths[0].start(), 0);
+        assert_eq!(lengths[0].stroke(), Some(&SwimStroke::Freestyle));
+        assert_eq!(lengths[0].total_strokes(), Some(16));
+        assert_eq!(lengths[0].swolf(), Some(46));
+
+        assert_eq!(lengths[1].start(), 50);
+        assert_eq!(lengths[1].stroke(), Some(&SwimStroke::Drill));
+        assert_eq!(lengths[1].total_strokes(), None);
+        assert_eq!(lengths[1].swolf(), None);
+
+        assert_eq!(lengths[2].start(), 95);
+        assert_eq!(lengths[2].stroke(), None);
+        assert_eq!(lengths[2].swolf(), Some(55));
+
+        assert_eq!(lengths[3].stroke(), Some(&SwimStroke::Mixed));
+        assert_eq!(lengths[3].swolf(), Some(60));
+    }
+
+    #[test]
+    fn test_extract_lengths_of_non_swim_activity() {
+        let messages = vec![session_message(FitSport::Running, 10000.)];
+
+        assert!(extract_lengths(0, &messages).is_empty());
+    }
+
+    #[test]
+    fn test_single_sport_activity_is_not_multisport() {
+        let messages = vec![session_message(FitSport::Running, 10000.)];
+
+        let sessions = extract_sessions(&messages);
+
+        assert_eq!(sessions.len(), 1);
+        assert!(!is_multisport(&sessions));
+    }
+
     #[test]
     fn test_extract_start_time_ok_with_timezone() {
         let messages = vec![
@@ -1049,6 +2174,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::StartTime),
                     values: vec![DataValue::DateTime(983185076)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -1058,10 +2184,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::Timestamp),
                         values: vec![DataValue::DateTime(983187416)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::LocalTimestamp),
                         values: vec![DataValue::DateTime(983191016)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1090,6 +2218,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::StartTime),
                     values: vec![DataValue::DateTime(983185076)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -1099,10 +2228,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::Timestamp),
                         values: vec![DataValue::DateTime(983187416)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::LocalTimestamp),
                         values: vec![DataValue::DateTime(0)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1131,6 +2262,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::StartTime),
                     values: vec![DataValue::DateTime(983185076)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -1139,6 +2271,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Activity(ActivityField::Timestamp),
                     values: vec![DataValue::DateTime(983187416)],
+                    is_scaled: false,
                 }],
             },
         ];
@@ -1157,6 +2290,146 @@ mod tests_fit_parser {
         )
     }
 
+    fn timestamps_message(
+        message_kind: MesgNum,
+        timestamp: FitField,
+        local_timestamp: FitField,
+        values: (u32, u32),
+    ) -> DataMessage {
+        DataMessage {
+            local_message_type: 0,
+            message_kind,
+            fields: vec![
+                DataMessageField {
+                    kind: timestamp,
+                    values: vec![DataValue::DateTime(values.0)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: local_timestamp,
+                    values: vec![DataValue::DateTime(values.1)],
+                    is_scaled: false,
+                },
+            ],
+        }
+    }
+
+    fn monitoring_info_message(timestamp: u32, local_timestamp: u32) -> DataMessage {
+        timestamps_message(
+            MesgNum::MonitoringInfo,
+            FitField::MonitoringInfo(MonitoringInfoField::Timestamp),
+            FitField::MonitoringInfo(MonitoringInfoField::LocalTimestamp),
+            (timestamp, local_timestamp),
+        )
+    }
+
+    fn activity_message(timestamp: u32, local_timestamp: u32) -> DataMessage {
+        timestamps_message(
+            MesgNum::Activity,
+            FitField::Activity(ActivityField::Timestamp),
+            FitField::Activity(ActivityField::LocalTimestamp),
+            (timestamp, local_timestamp),
+        )
+    }
+
+    #[test]
+    fn test_extract_timezone_offset_from_activity() {
+        let messages = vec![activity_message(983187416, 983187416 - 5 * 3600)];
+
+        assert_eq!(
+            extract_timezone_offset(&messages),
+            FixedOffset::west_opt(5 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_extract_timezone_offset_from_monitoring_info() {
+        let messages = vec![monitoring_info_message(983187416, 983187416 + 19800)];
+
+        assert_eq!(
+            extract_timezone_offset(&messages),
+            FixedOffset::east_opt(19800)
+        );
+    }
+
+    #[test]
+    fn test_extract_timezone_offset_prefers_activity() {
+        let messages = vec![
+            monitoring_info_message(983187416, 983187416 + 7200),
+            activity_message(983187416, 983187416 + 3600),
+        ];
+
+        assert_eq!(
+            extract_timezone_offset(&messages),
+            FixedOffset::east_opt(3600)
+        );
+    }
+
+    #[test]
+    fn test_extract_timezone_offset_falls_back_on_monitoring_info() {
+        // Invalid local timestamp in the activity message
+        let messages = vec![
+            activity_message(983187416, 0),
+            monitoring_info_message(983187416, 983187416 + 3600),
+        ];
+
+        assert_eq!(
+            extract_timezone_offset(&messages),
+            FixedOffset::east_opt(3600)
+        );
+    }
+
+    #[test]
+    fn test_extract_timezone_offset_none_without_pair() {
+        let messages = vec![DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Activity,
+            fields: vec![DataMessageField {
+                kind: FitField::Activity(ActivityField::Timestamp),
+                values: vec![DataValue::DateTime(983187416)],
+                is_scaled: false,
+            }],
+        }];
+
+        assert_eq!(extract_timezone_offset(&messages), None);
+    }
+
+    #[test]
+    fn test_extract_start_time_with_monitoring_info_timezone() {
+        let messages = vec![
+            DataMessage {
+                local_message_type: 0,
+                message_kind: MesgNum::Session,
+                fields: vec![DataMessageField {
+                    kind: FitField::Session(SessionField::StartTime),
+                    values: vec![DataValue::DateTime(983185076)],
+                    is_scaled: false,
+                }],
+            },
+            monitoring_info_message(983185000, 983185000 + 2 * 3600),
+            DataMessage {
+                local_message_type: 0,
+                message_kind: MesgNum::Activity,
+                fields: vec![DataMessageField {
+                    kind: FitField::Activity(ActivityField::Timestamp),
+                    values: vec![DataValue::DateTime(983187416)],
+                    is_scaled: false,
+                }],
+            },
+        ];
+
+        let (start, _) = extract_start_time(&messages).expect("Should have returned Some");
+
+        assert_eq!(
+            start,
+            ActivityStartTime::new(
+                "2021-02-25T12:57:56+02:00"
+                    .parse::<DateTime<FixedOffset>>()
+                    .unwrap()
+            )
+        )
+    }
+
     #[test]
     fn test_extract_start_time_fails_when_missing_session_start_time() {
         let messages = vec![
@@ -1172,10 +2445,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::Timestamp),
                         values: vec![DataValue::DateTime(983187416)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Activity(ActivityField::LocalTimestamp),
                         values: vec![DataValue::DateTime(983191016)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1193,6 +2468,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::StartTime),
                     values: vec![DataValue::DateTime(983185076)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -1201,6 +2477,7 @@ mod tests_fit_parser {
                 fields: vec![DataMessageField {
                     kind: FitField::Activity(ActivityField::LocalTimestamp),
                     values: vec![DataValue::DateTime(983191016)],
+                    is_scaled: false,
                 }],
             },
         ];
@@ -1216,6 +2493,7 @@ mod tests_fit_parser {
             fields: vec![DataMessageField {
                 kind: FitField::Session(SessionField::TotalElapsedTime),
                 values: vec![DataValue::Float64(12.3)],
+                is_scaled: false,
             }],
         }];
 
@@ -1262,14 +2540,17 @@ mod tests_fit_parser {
                 DataMessageField {
                     kind: FitField::Record(RecordField::Timestamp),
                     values: vec![DataValue::DateTime(10)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::Record(RecordField::PositionLat),
                     values: vec![DataValue::Sint32(536870912)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::Record(RecordField::PositionLong),
                     values: vec![DataValue::Sint32(-536870912)],
+                    is_scaled: false,
                 },
             ],
         }];
@@ -1316,14 +2597,17 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::PositionLat),
                         values: vec![DataValue::Sint32(536870912)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::PositionLong),
                         values: vec![DataValue::Sint32(-536870912)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1334,14 +2618,17 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Record(RecordField::Timestamp),
                         values: vec![DataValue::DateTime(11)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::PositionLat),
                         values: vec![DataValue::Sint32(i32::MAX)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Record(RecordField::PositionLong),
                         values: vec![DataValue::Sint32(i32::MAX)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1395,10 +2682,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Lap(LapField::StartTime),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Lap(LapField::TotalTimerTime),
                         values: vec![DataValue::Float32(300.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1409,10 +2698,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Lap(LapField::StartTime),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Lap(LapField::TotalTimerTime),
                         values: vec![DataValue::Float32(300.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1436,10 +2727,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Lap(LapField::StartTime),
                         values: vec![DataValue::DateTime(10)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Lap(LapField::TotalTimerTime),
                         values: vec![DataValue::Float32(300.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1450,10 +2743,12 @@ mod tests_fit_parser {
                     DataMessageField {
                         kind: FitField::Lap(LapField::StartTime),
                         values: vec![DataValue::DateTime(310)],
+                        is_scaled: false,
                     },
                     DataMessageField {
                         kind: FitField::Lap(LapField::TotalTimerTime),
                         values: vec![DataValue::Float32(200.)],
+                        is_scaled: false,
                     },
                 ],
             },
@@ -1468,4 +2763,519 @@ mod tests_fit_parser {
             &vec![Lap::new(0, 300), Lap::new(300, 500)]
         );
     }
+
+    fn record_message(
+        timestamp: u32,
+        temperature: Option<i8>,
+        respiration_rate: f32,
+    ) -> DataMessage {
+        let mut fields = vec![
+            DataMessageField {
+                kind: FitField::Record(RecordField::Timestamp),
+                values: vec![DataValue::DateTime(timestamp)],
+                is_scaled: false,
+            },
+            DataMessageField {
+                kind: FitField::Record(RecordField::EnhancedRespirationRate),
+                values: vec![DataValue::Float32(respiration_rate)],
+                is_scaled: true,
+            },
+        ];
+        if let Some(temperature) = temperature {
+            fields.push(DataMessageField {
+                kind: FitField::Record(RecordField::Temperature),
+                values: vec![DataValue::Sint8(temperature)],
+                is_scaled: false,
+            });
+        }
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields,
+        }
+    }
+
+    /// Records with temperature (missing from the second one) and respiration rate, beat intervals
+    /// and a session with its average respiration rate.
+    fn temperature_and_beat_intervals_fixture(reference_timestamp: u32) -> Vec<DataMessage> {
+        vec![
+            record_message(reference_timestamp, Some(21), 18.5),
+            record_message(reference_timestamp + 1, None, 19.),
+            record_message(reference_timestamp + 2, Some(-3), 19.5),
+            DataMessage {
+                local_message_type: 1,
+                message_kind: MesgNum::BeatIntervals,
+                fields: vec![DataMessageField {
+                    kind: FitField::BeatIntervals(BeatIntervalsField::Time),
+                    values: vec![
+                        DataValue::Uint16(800),
+                        DataValue::Uint16(810),
+                        DataValue::Uint16(790),
+                        DataValue::Uint16(800),
+                    ],
+                    is_scaled: false,
+                }],
+            },
+            DataMessage {
+                local_message_type: 2,
+                message_kind: MesgNum::Session,
+                fields: vec![DataMessageField {
+                    kind: FitField::Session(SessionField::EnhancedAvgRespirationRate),
+                    values: vec![DataValue::Float32(19.)],
+                    is_scaled: true,
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_extract_timeseries_temperature_and_respiration_rate() {
+        let reference = 10;
+        let messages = temperature_and_beat_intervals_fixture(reference);
+
+        let timeseries = extract_timeseries(reference, &messages).unwrap();
+
+        let find_metric = |target: TimeseriesMetric| {
+            timeseries
+                .metrics()
+                .iter()
+                .find(|metric| metric.metric() == &target)
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(
+            find_metric(TimeseriesMetric::Temperature),
+            vec![
+                Some(TimeseriesValue::Float(21.)),
+                None,
+                Some(TimeseriesValue::Float(-3.))
+            ]
+        );
+        assert_eq!(
+            find_metric(TimeseriesMetric::RespirationRate),
+            vec![
+                Some(TimeseriesValue::Float(18.5)),
+                Some(TimeseriesValue::Float(19.)),
+                Some(TimeseriesValue::Float(19.5))
+            ]
+        );
+    }
+
+    /// Content of test.fit with extra records (local message type 14) whose grade and temperature
+    /// are valid or not: `(grade in 1/100 %, temperature in °C)`.
+    fn fit_file_with_grade_and_temperature(reference_timestamp: u32) -> Vec<u8> {
+        let content = fs::read("src/inbound/parser/test.fit").unwrap();
+        let mut file = RawFile::decode(content).unwrap();
+
+        let mut definition = file
+            .records
+            .iter()
+            .find_map(|record| match record {
+                RawRecord::Definition(definition) => Some(definition.clone()),
+                _ => None,
+            })
+            .unwrap();
+        definition.local_message_type = 14;
+        definition.global_message_number = 20;
+        definition.fields = vec![
+            RawFieldDefinition {
+                number: 253,
+                size: 4,
+                base_type: 0x86,
+            },
+            RawFieldDefinition {
+                number: 9,
+                size: 2,
+                base_type: 0x83,
+            },
+            RawFieldDefinition {
+                number: 13,
+                size: 1,
+                base_type: 0x01,
+            },
+        ];
+        definition.developer_fields = vec![];
+        file.records.push(RawRecord::Definition(definition));
+        for (offset, (grade, temperature)) in [(250_i16, 21_i8), (-120, i8::MAX), (i16::MAX, -3)]
+            .into_iter()
+            .enumerate()
+        {
+            file.records.push(RawRecord::Data(RawData {
+                local_message_type: 14,
+                time_offset: None,
+                fields: vec![
+                    (reference_timestamp + offset as u32).to_le_bytes().to_vec(),
+                    grade.to_le_bytes().to_vec(),
+                    temperature.to_le_bytes().to_vec(),
+                ],
+                developer_fields: vec![],
+            }));
+        }
+
+        file.encode()
+    }
+
+    #[test]
+    fn test_extract_timeseries_grade_and_temperature() {
+        let reference = 10;
+        let mut decoder = FitDecoder::new(false);
+        decoder.feed(&fit_file_with_grade_and_temperature(reference));
+        let messages: Vec<DataMessage> = decoder
+            .finish()
+            .unwrap()
+            .into_iter()
+            .filter(|message| message.local_message_type == 14)
+            .collect();
+
+        let timeseries = extract_timeseries(reference, &messages).unwrap();
+
+        let find_metric = |target: TimeseriesMetric| {
+            timeseries
+                .metrics()
+                .iter()
+                .find(|metric| metric.metric() == &target)
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        let grade = find_metric(TimeseriesMetric::Grade);
+        assert_eq!(grade.len(), 3);
+        let Some(TimeseriesValue::Float(uphill)) = grade[0] else {
+            panic!("Expected a grade, got {:?}", grade[0]);
+        };
+        assert_approx_eq!(uphill, 2.5);
+        let Some(TimeseriesValue::Float(downhill)) = grade[1] else {
+            panic!("Expected a grade, got {:?}", grade[1]);
+        };
+        assert_approx_eq!(downhill, -1.2, 1e-6);
+        assert_eq!(grade[2], None);
+        assert_eq!(
+            find_metric(TimeseriesMetric::Temperature),
+            vec![
+                Some(TimeseriesValue::Float(21.)),
+                None,
+                Some(TimeseriesValue::Float(-3.))
+            ]
+        );
+    }
+
+    fn respiration_rate_message(timestamp: u32, rate: f32) -> DataMessage {
+        DataMessage {
+            local_message_type: 3,
+            message_kind: MesgNum::RespirationRate,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::RespirationRate(RespirationRateField::Timestamp),
+                    values: vec![DataValue::DateTime(timestamp)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::RespirationRate(RespirationRateField::RespirationRate),
+                    values: vec![DataValue::Float32(rate)],
+                    is_scaled: true,
+                },
+            ],
+        }
+    }
+
+    fn stress_level_message(timestamp: u32, level: i16) -> DataMessage {
+        DataMessage {
+            local_message_type: 4,
+            message_kind: MesgNum::StressLevel,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::StressLevel(StressLevelField::StressLevelValue),
+                    values: vec![DataValue::Sint16(level)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::StressLevel(StressLevelField::StressLevelTime),
+                    values: vec![DataValue::DateTime(timestamp)],
+                    is_scaled: false,
+                },
+            ],
+        }
+    }
+
+    fn timestamp_message(timestamp: u32) -> DataMessage {
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![DataMessageField {
+                kind: FitField::Record(RecordField::Timestamp),
+                values: vec![DataValue::DateTime(timestamp)],
+                is_scaled: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_extract_timeseries_respiration_rate_and_stress_level_messages() {
+        let reference = 10;
+        // Records every 60s over 10 minutes, with respiration rate and stress level messages at
+        // their own, sparser, rates. The stress level is not measured at 240s.
+        let mut messages = (0..=10)
+            .map(|idx| timestamp_message(reference + idx * 60))
+            .collect::<Vec<_>>();
+        messages.extend([
+            respiration_rate_message(reference + 30, 20.5),
+            stress_level_message(reference, 25),
+            stress_level_message(reference + 240, -2),
+            respiration_rate_message(reference + 150, 24.),
+            stress_level_message(reference + 120, 40),
+        ]);
+        // Record with its own respiration rate, used over the messages
+        messages.push(record_message(reference + 660, None, 30.));
+
+        let timeseries = extract_timeseries(reference, &messages).unwrap();
+
+        let find_metric = |target: TimeseriesMetric| {
+            timeseries
+                .metrics()
+                .iter()
+                .find(|metric| metric.metric() == &target)
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        let float = |value: f64| Some(TimeseriesValue::Float(value));
+        assert_eq!(
+            find_metric(TimeseriesMetric::RespirationRate),
+            vec![
+                None,
+                float(20.5),
+                float(20.5),
+                float(24.),
+                float(24.),
+                float(24.),
+                float(24.),
+                float(24.),
+                // More than 5 minutes since the last sample
+                None,
+                None,
+                None,
+                float(30.)
+            ]
+        );
+        let int = |value: usize| Some(TimeseriesValue::Int(value));
+        assert_eq!(
+            find_metric(TimeseriesMetric::StressLevel),
+            vec![
+                int(25),
+                int(25),
+                int(40),
+                int(40),
+                int(40),
+                int(40),
+                int(40),
+                int(40),
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_statistics_heart_rate_variability_and_respiration_rate() {
+        let messages = temperature_and_beat_intervals_fixture(10);
+
+        let statistics = extract_statistics(&messages);
+
+        // Successive differences are 10, -20 and 10 ms
+        assert_approx_eq!(
+            *statistics
+                .get(&ActivityStatistic::HeartRateVariability)
+                .unwrap(),
+            200_f64.sqrt(),
+            1e-3
+        );
+        assert_eq!(
+            statistics.get(&ActivityStatistic::RespirationRate),
+            Some(&19.)
+        );
+    }
+
+    #[test]
+    fn test_extract_statistics_without_heart_rate_variability_and_respiration_rate() {
+        let messages = vec![session_message(FitSport::Running, 10000.)];
+
+        let statistics = extract_statistics(&messages);
+
+        assert!(
+            statistics
+                .get(&ActivityStatistic::HeartRateVariability)
+                .is_none()
+        );
+        assert!(
+            statistics
+                .get(&ActivityStatistic::RespirationRate)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_extract_beat_intervals_from_hrv_messages() {
+        let messages = vec![DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Hrv,
+            fields: vec![DataMessageField {
+                kind: FitField::Hrv(HrvField::Time),
+                values: vec![DataValue::Float32(0.8), DataValue::Float32(0.75)],
+                is_scaled: true,
+            }],
+        }];
+
+        let intervals = extract_beat_intervals(&messages);
+
+        assert_eq!(intervals.values(), &[0.8, 0.75]);
+    }
+
+    #[test]
+    fn test_extract_beat_intervals_stops_at_invalid_values() {
+        let messages = vec![DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::BeatIntervals,
+            fields: vec![DataMessageField {
+                kind: FitField::BeatIntervals(BeatIntervalsField::Time),
+                values: vec![
+                    DataValue::Uint16(800),
+                    DataValue::Uint16(0xFFFF),
+                    DataValue::Uint16(810),
+                ],
+                is_scaled: false,
+            }],
+        }];
+
+        let intervals = extract_beat_intervals(&messages);
+
+        assert_eq!(intervals.values(), &[0.8]);
+    }
+
+    /// Content of test.fit with `hrv` messages of 5 intervals each, the unused ones being invalid.
+    fn fit_file_with_hrv_messages() -> Vec<u8> {
+        let content = fs::read("src/inbound/parser/test.fit").unwrap();
+        let mut file = RawFile::decode(content).unwrap();
+
+        let mut definition = file
+            .records
+            .iter()
+            .find_map(|record| match record {
+                RawRecord::Definition(definition) => Some(definition.clone()),
+                _ => None,
+            })
+            .unwrap();
+        definition.local_message_type = 15;
+        definition.global_message_number = 78;
+        definition.fields = vec![RawFieldDefinition {
+            number: 0,
+            size: 10,
+            base_type: 0x84,
+        }];
+        definition.developer_fields = vec![];
+        file.records.push(RawRecord::Definition(definition));
+        for intervals in [
+            [800_u16, 750, 0xFFFF, 0xFFFF, 0xFFFF],
+            [820, 810, 790, 0xFFFF, 0xFFFF],
+        ] {
+            file.records.push(RawRecord::Data(RawData {
+                local_message_type: 15,
+                time_offset: None,
+                fields: vec![intervals.iter().flat_map(|ms| ms.to_le_bytes()).collect()],
+                developer_fields: vec![],
+            }));
+        }
+
+        file.encode()
+    }
+
+    #[test]
+    fn test_parse_beat_intervals_from_hrv_messages() {
+        let parsed = try_fit_bytes_into_domain(fit_file_with_hrv_messages()).unwrap();
+
+        let intervals = parsed.beat_intervals().values();
+        assert_eq!(intervals.len(), 5);
+        for (interval, expected) in intervals.iter().zip([0.8, 0.75, 0.82, 0.81, 0.79]) {
+            assert_approx_eq!(interval, expected, 1e-6);
+        }
+    }
+
+    /// Content of test.fit with an extra message whose global number is not in the profile.
+    fn fit_file_with_unknown_message() -> Vec<u8> {
+        let content = fs::read("src/inbound/parser/test.fit").unwrap();
+        let mut file = RawFile::decode(content).unwrap();
+
+        let mut definition = file
+            .records
+            .iter()
+            .find_map(|record| match record {
+                RawRecord::Definition(definition) => Some(definition.clone()),
+                _ => None,
+            })
+            .unwrap();
+        definition.local_message_type = 15;
+        definition.global_message_number = 0xF00D;
+        definition.fields = vec![
+            RawFieldDefinition {
+                number: 0,
+                size: 2,
+                base_type: 0x84,
+            },
+            RawFieldDefinition {
+                number: 3,
+                size: 4,
+                base_type: 0x0D,
+            },
+        ];
+        definition.developer_fields = vec![];
+        file.records.push(RawRecord::Definition(definition));
+        for value in [1_u8, 2] {
+            file.records.push(RawRecord::Data(RawData {
+                local_message_type: 15,
+                time_offset: None,
+                fields: vec![vec![value, 0], vec![value; 4]],
+                developer_fields: vec![],
+            }));
+        }
+
+        file.encode()
+    }
+
+    #[test]
+    fn test_extract_unknown_messages() {
+        let unknown_messages = extract_unknown_messages(&fit_file_with_unknown_message());
+
+        assert_eq!(unknown_messages.get(0xF00D, 0), &[vec![1, 0], vec![2, 0]]);
+        assert_eq!(unknown_messages.get(0xF00D, 3), &[vec![1; 4], vec![2; 4]]);
+        assert!(unknown_messages.get(0xF00D, 1).is_empty());
+        // Messages known to the profile are not retained
+        assert!(unknown_messages.get(20, 253).is_empty());
+    }
+
+    #[test]
+    fn test_extract_unknown_messages_invalid_content() {
+        assert!(extract_unknown_messages(&[0, 1, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_parser_retains_unknown_messages_only_if_configured() {
+        let content = fit_file_with_unknown_message();
+
+        let parsed = Parser::default()
+            .try_bytes_into_domain(&SupportedExtension::FIT, content.clone())
+            .unwrap();
+        assert!(parsed.unknown_messages().is_empty());
+
+        let parsed = Parser::default()
+            .with_unknown_messages_retained(true)
+            .try_bytes_into_domain(&SupportedExtension::FIT, content)
+            .unwrap();
+        assert_eq!(parsed.timeseries().time().len(), 3901);
+        assert_eq!(
+            parsed.unknown_messages().get(0xF00D, 0),
+            &[vec![1, 0], vec![2, 0]]
+        );
+    }
 }
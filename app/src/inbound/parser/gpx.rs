@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset};
+use roxmltree::{Document, Node};
+
+use crate::{
+    domain::models::activity::{
+        ActiveTime, ActivityDuration, ActivityStartTime, ActivityStatistic, ActivityStatistics,
+        ActivityTimeseries, Sport, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
+        TimeseriesTime, TimeseriesValue, haversine_distance,
+    },
+    inbound::parser::{ParseBytesError, ParsedFileContent, SupportedExtension},
+};
+
+pub fn try_gpx_bytes_into_domain(bytes: Vec<u8>) -> Result<ParsedFileContent, ParseBytesError> {
+    let content =
+        String::from_utf8(bytes.clone()).map_err(|_err| ParseBytesError::InvalidContent)?;
+
+    let doc = roxmltree::Document::parse(content.trim())
+        .map_err(|_err| ParseBytesError::InvalidContent)?;
+
+    let points = find_track_points(&doc);
+    let start_time = points
+        .iter()
+        .find_map(|point| point.time)
+        .map(ActivityStartTime::new)
+        .ok_or(ParseBytesError::NoStartTimeFound)?;
+    let sport = find_sport(&doc);
+    let timeseries = parse_timeseries(&points, start_time.datetime())?;
+    let statistics = compute_statistics(&timeseries);
+    let duration = statistics
+        .get(&ActivityStatistic::Duration)
+        .copied()
+        .map_or_else(ActivityDuration::default, ActivityDuration::from);
+
+    Ok(ParsedFileContent::new(
+        sport,
+        start_time,
+        duration,
+        statistics,
+        timeseries,
+        SupportedExtension::GPX.suffix().to_string(),
+        bytes,
+    ))
+}
+
+/// GPX has no sport, apart from the free form `<type>` of tracks set by some applications.
+fn find_sport(doc: &Document) -> Sport {
+    let Some(sport_type) = doc
+        .descendants()
+        .filter(|node| node.has_tag_name("trk"))
+        .find_map(|track| child_text(track, "type"))
+    else {
+        return Sport::Other;
+    };
+
+    match sport_type.trim().to_lowercase().as_str() {
+        "running" | "run" => Sport::Running,
+        "trail_running" | "trailrunning" => Sport::TrailRunning,
+        "cycling" | "biking" | "ride" => Sport::Cycling,
+        "mountain_biking" | "mountainbiking" => Sport::MountainBiking,
+        "walking" | "walk" => Sport::Walking,
+        "hiking" | "hike" => Sport::Hiking,
+        "swimming" | "swim" => Sport::Swimming,
+        _ => Sport::Other,
+    }
+}
+
+struct TrackPoint {
+    time: Option<DateTime<FixedOffset>>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    elevation: Option<f64>,
+    heart_rate: Option<f64>,
+    cadence: Option<f64>,
+}
+
+fn find_track_points(doc: &Document) -> Vec<TrackPoint> {
+    doc.descendants()
+        .filter(|node| node.has_tag_name("trkpt"))
+        .map(|node| TrackPoint {
+            time: child_text(node, "time").and_then(|txt| txt.parse().ok()),
+            latitude: node.attribute("lat").and_then(|lat| lat.parse().ok()),
+            longitude: node.attribute("lon").and_then(|lon| lon.parse().ok()),
+            elevation: child_text(node, "ele").and_then(|txt| txt.parse().ok()),
+            // Garmin's TrackPointExtension (gpxtpx:hr and gpxtpx:cad), matched on local names
+            heart_rate: extension_text(node, "hr").and_then(|txt| txt.parse().ok()),
+            cadence: extension_text(node, "cad").and_then(|txt| txt.parse().ok()),
+        })
+        .collect()
+}
+
+fn child_text<'a>(node: Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(tag))
+        .and_then(|child| child.text())
+}
+
+fn extension_text<'a>(node: Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name("extensions"))?
+        .descendants()
+        .find(|child| child.has_tag_name(tag))
+        .and_then(|child| child.text())
+}
+
+fn parse_timeseries(
+    points: &[TrackPoint],
+    reference_time: &DateTime<FixedOffset>,
+) -> Result<ActivityTimeseries, ParseBytesError> {
+    let mut time_values = Vec::new();
+    let mut latitude_values = vec![];
+    let mut longitude_values = vec![];
+    let mut altitude_values = vec![];
+    let mut heart_rate_values = vec![];
+    let mut cadence_values = vec![];
+    let mut distance_values = vec![];
+
+    let mut distance = 0.;
+    let mut previous_position = None;
+    for point in points {
+        // Points without time cannot be placed in the timeseries
+        let Some(time) = point.time else {
+            continue;
+        };
+        time_values.push((time - reference_time).num_seconds().max(0) as usize);
+
+        let position = point.latitude.zip(point.longitude);
+        if let Some(position) = position {
+            if let Some(previous) = previous_position {
+                distance += haversine_distance(previous, position);
+            }
+            previous_position = Some(position);
+        }
+        distance_values.push(Some(TimeseriesValue::Float(distance)));
+
+        latitude_values.push(point.latitude.map(TimeseriesValue::Float));
+        longitude_values.push(point.longitude.map(TimeseriesValue::Float));
+        altitude_values.push(point.elevation.map(TimeseriesValue::Float));
+        heart_rate_values.push(point.heart_rate.map(TimeseriesValue::Float));
+        cadence_values.push(point.cadence.map(TimeseriesValue::Float));
+    }
+
+    // Tracks without positions have no distance
+    if previous_position.is_none() {
+        distance_values.iter_mut().for_each(|value| *value = None);
+    }
+
+    let metrics = vec![
+        Timeseries::new(TimeseriesMetric::Distance, distance_values),
+        Timeseries::new(TimeseriesMetric::HeartRate, heart_rate_values),
+        Timeseries::new(TimeseriesMetric::Cadence, cadence_values),
+        Timeseries::new(TimeseriesMetric::Altitude, altitude_values),
+        Timeseries::new(TimeseriesMetric::Longitude, longitude_values),
+        Timeseries::new(TimeseriesMetric::Latitude, latitude_values),
+    ];
+
+    // GPX does not support pauses, so active time = time
+    let active_time = TimeseriesActiveTime::new(
+        time_values
+            .iter()
+            .cloned()
+            .map(ActiveTime::Running)
+            .collect(),
+    );
+
+    ActivityTimeseries::new(
+        TimeseriesTime::new(time_values),
+        active_time,
+        vec![],
+        metrics,
+    )
+    .map_err(|_err| ParseBytesError::IncoherentTimeseriesLengths)
+}
+
+fn compute_statistics(timeseries: &ActivityTimeseries) -> ActivityStatistics {
+    let mut stats = HashMap::new();
+
+    if let Some(duration) = timeseries.time().values().last() {
+        stats.insert(ActivityStatistic::Duration, *duration as f64);
+    }
+
+    let metric_values =
+        |metric: TimeseriesMetric| timeseries.metric_values(&metric).unwrap_or_default();
+
+    if let Some(distance) = metric_values(TimeseriesMetric::Distance).last() {
+        stats.insert(ActivityStatistic::Distance, *distance);
+    }
+
+    let altitudes = metric_values(TimeseriesMetric::Altitude);
+    if !altitudes.is_empty() {
+        let elevation_gain = altitudes
+            .windows(2)
+            .map(|window| f64::max(window[1] - window[0], 0.))
+            .sum();
+        stats.insert(ActivityStatistic::Elevation, elevation_gain);
+    }
+
+    ActivityStatistics::new(stats)
+}
+
+#[cfg(test)]
+mod test_gpx_parser {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_file_ok() {
+        let file = fs::read("src/inbound/parser/test.gpx").expect("Unable to load gpx test file");
+
+        let parsed = try_gpx_bytes_into_domain(file).expect("Should have returned Ok");
+
+        assert_eq!(parsed.sport(), &Sport::Running);
+        assert_eq!(
+            parsed.start_time(),
+            &ActivityStartTime::new(
+                "2024-08-28T07:12:54+00:00"
+                    .parse::<DateTime<FixedOffset>>()
+                    .unwrap()
+            )
+        );
+        assert_eq!(parsed.duration(), &ActivityDuration::from(10.));
+
+        let timeseries = parsed.timeseries();
+        assert_eq!(timeseries.time().values(), &[0, 5, 10]);
+        assert_eq!(
+            timeseries.metric_values(&TimeseriesMetric::Latitude),
+            Some(vec![45.0000, 45.0001, 45.0002])
+        );
+        assert_eq!(
+            timeseries.metric_values(&TimeseriesMetric::Longitude),
+            Some(vec![5., 5., 5.])
+        );
+        assert_eq!(
+            timeseries.metric_values(&TimeseriesMetric::Altitude),
+            Some(vec![200., 201.5, 201.])
+        );
+        assert_eq!(
+            timeseries.metric_values(&TimeseriesMetric::HeartRate),
+            Some(vec![120., 125.])
+        );
+        assert_eq!(
+            timeseries.metric_values(&TimeseriesMetric::Cadence),
+            Some(vec![80., 82.])
+        );
+
+        let statistics = parsed.statistics();
+        assert_eq!(statistics.get(&ActivityStatistic::Duration), Some(&10.));
+        assert_eq!(statistics.get(&ActivityStatistic::Elevation), Some(&1.5));
+        // About 11.1 m between each point
+        let distance = *statistics.get(&ActivityStatistic::Distance).unwrap();
+        assert!((distance - 22.24).abs() < 0.01, "{distance}");
+    }
+
+    #[test]
+    fn test_parse_file_content_is_not_a_valid_string() {
+        let file = vec![0xFF, 0xFE, 0xFD];
+
+        assert_eq!(
+            try_gpx_bytes_into_domain(file).unwrap_err(),
+            ParseBytesError::InvalidContent
+        );
+    }
+
+    #[test]
+    fn test_parse_file_content_is_not_a_valid_xml() {
+        let file = "<gpx><trk>".to_string().into_bytes();
+
+        assert_eq!(
+            try_gpx_bytes_into_domain(file).unwrap_err(),
+            ParseBytesError::InvalidContent
+        );
+    }
+
+    #[test]
+    fn test_parse_file_content_does_not_have_a_start_time() {
+        let file = "<gpx><trk><trkseg><trkpt lat=\"45\" lon=\"5\" /></trkseg></trk></gpx>"
+            .to_string()
+            .into_bytes();
+
+        assert_eq!(
+            try_gpx_bytes_into_domain(file).unwrap_err(),
+            ParseBytesError::NoStartTimeFound
+        );
+    }
+
+    #[test]
+    fn test_find_sport() {
+        let sport = |content: &str| find_sport(&roxmltree::Document::parse(content).unwrap());
+
+        assert_eq!(
+            sport("<gpx><trk><type>cycling</type></trk></gpx>"),
+            Sport::Cycling
+        );
+        assert_eq!(
+            sport("<gpx><trk><type>Hiking</type></trk></gpx>"),
+            Sport::Hiking
+        );
+        assert_eq!(sport("<gpx><trk><type>9</type></trk></gpx>"), Sport::Other);
+        assert_eq!(
+            sport("<gpx><trk><name>Ride</name></trk></gpx>"),
+            Sport::Other
+        );
+    }
+
+    #[test]
+    fn test_parse_timeseries_without_positions() {
+        let file = "<gpx><trk><trkseg>
+                <trkpt><time>2024-08-28T07:12:54Z</time></trkpt>
+                <trkpt><time>2024-08-28T07:12:55Z</time></trkpt>
+            </trkseg></trk></gpx>"
+            .to_string()
+            .into_bytes();
+
+        let parsed = try_gpx_bytes_into_domain(file).unwrap();
+
+        assert_eq!(parsed.sport(), &Sport::Other);
+        assert_eq!(
+            parsed
+                .timeseries()
+                .metric_values(&TimeseriesMetric::Distance),
+            Some(vec![])
+        );
+        assert_eq!(parsed.statistics().get(&ActivityStatistic::Distance), None);
+    }
+}
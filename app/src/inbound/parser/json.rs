@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     domain::models::activity::{
         ActivityDuration, ActivityStartTime, ActivityStatistic, ActivityStatistics,
-        ActivityTimeseries, Sport,
+        ActivityTimeseries, BeatIntervals, Sport, UnknownMessages,
     },
     inbound::parser::{ParseBytesError, ParsedFileContent, SupportedExtension},
 };
@@ -51,6 +51,10 @@ impl TryFrom<StandaloneActivity> for ParsedFileContent {
             duration: ActivityDuration::from(activity.duration),
             statistics: ActivityStatistics::new(statistics),
             timeseries: ActivityTimeseries::empty(),
+            sessions: Vec::new(),
+            unknown_messages: UnknownMessages::default(),
+            beat_intervals: BeatIntervals::default(),
+            gps_accuracy: Vec::new(),
             extension: SupportedExtension::CustomJSON.suffix().to_string(),
             raw_content,
         })
@@ -1,4 +1,3 @@
-use derive_more::Constructor;
 use thiserror::Error;
 
 use crate::{
@@ -6,33 +5,104 @@ use crate::{
         models::{
             UserId,
             activity::{
-                ActivityDuration, ActivityStartTime, ActivityStatistics, ActivityTimeseries, Sport,
+                ActivityDuration, ActivityStartTime, ActivityStatistic, ActivityStatistics,
+                ActivityTimeseries, BeatIntervals, MaxGpsAccuracy, MaxGpsSpeeds, SessionSummary,
+                Sport, TimeseriesMetric, UnknownMessages,
             },
         },
         ports::activity::{CreateActivityRequest, RawContent},
     },
     inbound::parser::{
-        fit::try_fit_bytes_into_domain, json::try_custom_json_bytes_into_domain,
+        fit::{
+            extract_unknown_messages, try_fit_bytes_into_domain,
+            try_fit_bytes_into_domain_with_progress,
+        },
+        gpx::try_gpx_bytes_into_domain,
+        json::try_custom_json_bytes_into_domain,
         tcx::try_tcx_bytes_into_domain,
     },
 };
 
 pub mod fit;
+pub mod gpx;
 pub mod json;
 pub mod tcx;
 
-#[derive(Debug, Clone, Constructor)]
+#[derive(Debug, Clone)]
 pub struct ParsedFileContent {
     sport: Sport,
     start_time: ActivityStartTime,
     duration: ActivityDuration,
     statistics: ActivityStatistics,
     timeseries: ActivityTimeseries,
+    sessions: Vec<SessionSummary>,
+    unknown_messages: UnknownMessages,
+    beat_intervals: BeatIntervals,
+    /// Accuracy, in meters, of the position of each point of the timeseries, when known
+    gps_accuracy: Vec<Option<f64>>,
     extension: String,
     raw_content: Vec<u8>,
 }
 
 impl ParsedFileContent {
+    pub fn new(
+        sport: Sport,
+        start_time: ActivityStartTime,
+        duration: ActivityDuration,
+        statistics: ActivityStatistics,
+        timeseries: ActivityTimeseries,
+        extension: String,
+        raw_content: Vec<u8>,
+    ) -> Self {
+        let mut statistics = statistics;
+        // Actual recording span, left unset if there are no records with a valid timestamp
+        let start = start_time.datetime().timestamp() as f64;
+        let time = timeseries.time().values();
+        if let (Some(first), Some(last)) = (time.first(), time.last()) {
+            statistics.insert(ActivityStatistic::FirstTimestamp, start + *first as f64);
+            statistics.insert(ActivityStatistic::LastTimestamp, start + *last as f64);
+        }
+
+        Self {
+            sport,
+            start_time,
+            duration,
+            statistics,
+            timeseries,
+            sessions: Vec::new(),
+            unknown_messages: UnknownMessages::default(),
+            beat_intervals: BeatIntervals::default(),
+            gps_accuracy: Vec::new(),
+            extension,
+            raw_content,
+        }
+    }
+
+    pub fn with_sessions(self, sessions: Vec<SessionSummary>) -> Self {
+        Self { sessions, ..self }
+    }
+
+    pub fn with_unknown_messages(self, unknown_messages: UnknownMessages) -> Self {
+        Self {
+            unknown_messages,
+            ..self
+        }
+    }
+
+    pub fn with_beat_intervals(self, beat_intervals: BeatIntervals) -> Self {
+        Self {
+            beat_intervals,
+            ..self
+        }
+    }
+
+    pub fn with_gps_accuracy(self, gps_accuracy: Vec<Option<f64>>) -> Self {
+        Self {
+            gps_accuracy,
+            ..self
+        }
+    }
+
     pub fn start_time(&self) -> &ActivityStartTime {
         &self.start_time
     }
@@ -49,6 +119,12 @@ impl ParsedFileContent {
         &self.sport
     }
 
+    /// Replace the [Sport] parsed from the file content, e.g. when the user knows better than the
+    /// recording device.
+    pub fn override_sport(&mut self, sport: Sport) {
+        self.sport = sport;
+    }
+
     pub fn statistics(&self) -> &ActivityStatistics {
         &self.statistics
     }
@@ -57,6 +133,71 @@ impl ParsedFileContent {
         &self.timeseries
     }
 
+    pub fn sessions(&self) -> &[SessionSummary] {
+        &self.sessions
+    }
+
+    pub fn unknown_messages(&self) -> &UnknownMessages {
+        &self.unknown_messages
+    }
+
+    pub fn beat_intervals(&self) -> &BeatIntervals {
+        &self.beat_intervals
+    }
+
+    /// Remove the GPS spikes of the timeseries (see [ActivityTimeseries::remove_gps_spikes]),
+    /// updating the distance statistic and counting the removed fixes.
+    pub fn without_gps_spikes(self, max_speeds: &MaxGpsSpeeds) -> Self {
+        let (timeseries, removed) = self
+            .timeseries
+            .remove_gps_spikes(max_speeds.for_sport(&self.sport));
+        if removed == 0 {
+            return self;
+        }
+
+        let mut statistics = self.statistics;
+        statistics.insert(ActivityStatistic::RemovedGpsPoints, removed as f64);
+        if let Some(distance) = timeseries
+            .metric_values(&TimeseriesMetric::Distance)
+            .and_then(|values| values.into_iter().reduce(f64::max))
+        {
+            statistics.insert(ActivityStatistic::Distance, distance);
+        }
+
+        Self {
+            statistics,
+            timeseries,
+            ..self
+        }
+    }
+
+    /// Remove the positions less accurate than `max_accuracy` (see
+    /// [ActivityTimeseries::remove_inaccurate_positions]), adding them to the removed fixes.
+    pub fn without_inaccurate_positions(self, max_accuracy: &MaxGpsAccuracy) -> Self {
+        let (timeseries, removed) = self
+            .timeseries
+            .remove_inaccurate_positions(&self.gps_accuracy, max_accuracy);
+        if removed == 0 {
+            return self;
+        }
+
+        let mut statistics = self.statistics;
+        let already_removed = statistics
+            .get(&ActivityStatistic::RemovedGpsPoints)
+            .copied()
+            .unwrap_or_default();
+        statistics.insert(
+            ActivityStatistic::RemovedGpsPoints,
+            already_removed + removed as f64,
+        );
+
+        Self {
+            statistics,
+            timeseries,
+            ..self
+        }
+    }
+
     pub fn into_request(self, user: &UserId) -> CreateActivityRequest {
         CreateActivityRequest::new(
             user.clone(),
@@ -74,6 +215,7 @@ impl ParsedFileContent {
 pub enum SupportedExtension {
     FIT,
     TCX,
+    GPX,
     CustomJSON,
 }
 
@@ -82,25 +224,27 @@ impl SupportedExtension {
         match self {
             Self::FIT => "fit",
             Self::TCX => "tcx",
+            Self::GPX => "gpx",
             Self::CustomJSON => "json",
         }
     }
 }
 
 impl TryFrom<&str> for SupportedExtension {
-    type Error = ();
+    type Error = ParseBytesError;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
             "fit" => Ok(Self::FIT),
             "tcx" => Ok(Self::TCX),
+            "gpx" => Ok(Self::GPX),
             "json" => Ok(Self::CustomJSON),
-            _ => Err(()),
+            _ => Err(ParseBytesError::UnsupportedExtension(value.to_string())),
         }
     }
 }
 
 impl TryFrom<String> for SupportedExtension {
-    type Error = ();
+    type Error = ParseBytesError;
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Self::try_from(value.as_str())
     }
@@ -114,6 +258,8 @@ pub enum ParseBytesError {
     NoStartTimeFound,
     #[error("Activity has timeseries with different lengths")]
     IncoherentTimeseriesLengths,
+    #[error("Unsupported file extension: {0}")]
+    UnsupportedExtension(String),
 }
 
 pub trait ParseFile: Clone + Send + Sync + 'static {
@@ -122,10 +268,56 @@ pub trait ParseFile: Clone + Send + Sync + 'static {
         extention: &SupportedExtension,
         bytes: Vec<u8>,
     ) -> Result<ParsedFileContent, ParseBytesError>;
+
+    /// Same as [ParseFile::try_bytes_into_domain], but `progress` is called with the number of
+    /// bytes consumed so far and the total number of bytes. Formats that are not parsed
+    /// incrementally only report once they are done.
+    fn try_bytes_into_domain_with_progress(
+        &self,
+        extension: &SupportedExtension,
+        bytes: Vec<u8>,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<ParsedFileContent, ParseBytesError> {
+        let total = bytes.len();
+        let parsed = self.try_bytes_into_domain(extension, bytes)?;
+        progress(total, total);
+        Ok(parsed)
+    }
 }
 
-#[derive(Clone)]
-pub struct Parser {}
+#[derive(Clone, Default)]
+pub struct Parser {
+    retain_unknown_messages: bool,
+    max_gps_speeds: MaxGpsSpeeds,
+}
+
+impl Parser {
+    /// Whether to keep the raw bytes of the .FIT messages unknown to the app on the parsed
+    /// content, see [ParsedFileContent::unknown_messages]. Disabled by default.
+    pub fn with_unknown_messages_retained(self, retain_unknown_messages: bool) -> Self {
+        Self {
+            retain_unknown_messages,
+            ..self
+        }
+    }
+
+    /// Speeds above which GPS fixes are removed as spikes, see
+    /// [ParsedFileContent::without_gps_spikes]. [MaxGpsSpeeds::default] if not set.
+    pub fn with_max_gps_speeds(self, max_gps_speeds: MaxGpsSpeeds) -> Self {
+        Self {
+            max_gps_speeds,
+            ..self
+        }
+    }
+
+    fn retain_unknown_fit_messages(&self, parsed: ParsedFileContent) -> ParsedFileContent {
+        if !self.retain_unknown_messages {
+            return parsed;
+        }
+        let unknown_messages = extract_unknown_messages(parsed.raw_content());
+        parsed.with_unknown_messages(unknown_messages)
+    }
+}
 
 impl ParseFile for Parser {
     fn try_bytes_into_domain(
@@ -133,14 +325,248 @@ impl ParseFile for Parser {
         extension: &SupportedExtension,
         bytes: Vec<u8>,
     ) -> Result<ParsedFileContent, ParseBytesError> {
-        match extension {
-            SupportedExtension::FIT => try_fit_bytes_into_domain(bytes),
+        let parsed = match extension {
+            SupportedExtension::FIT => try_fit_bytes_into_domain(bytes)
+                .map(|parsed| self.retain_unknown_fit_messages(parsed)),
             SupportedExtension::TCX => try_tcx_bytes_into_domain(bytes),
+            SupportedExtension::GPX => try_gpx_bytes_into_domain(bytes),
             SupportedExtension::CustomJSON => try_custom_json_bytes_into_domain(bytes),
+        }?;
+        Ok(parsed.without_gps_spikes(&self.max_gps_speeds))
+    }
+
+    fn try_bytes_into_domain_with_progress(
+        &self,
+        extension: &SupportedExtension,
+        bytes: Vec<u8>,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<ParsedFileContent, ParseBytesError> {
+        match extension {
+            SupportedExtension::FIT => try_fit_bytes_into_domain_with_progress(bytes, progress)
+                .map(|parsed| {
+                    self.retain_unknown_fit_messages(parsed)
+                        .without_gps_spikes(&self.max_gps_speeds)
+                }),
+            SupportedExtension::TCX | SupportedExtension::GPX | SupportedExtension::CustomJSON => {
+                let total = bytes.len();
+                let parsed = self.try_bytes_into_domain(extension, bytes)?;
+                progress(total, total);
+                Ok(parsed)
+            }
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::domain::models::activity::{
+        ActiveTime, SportCategory, Timeseries, TimeseriesActiveTime, TimeseriesTime,
+        TimeseriesValue,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_supported_extension_round_trips_through_suffix() {
+        for extension in [
+            SupportedExtension::FIT,
+            SupportedExtension::TCX,
+            SupportedExtension::GPX,
+            SupportedExtension::CustomJSON,
+        ] {
+            assert_eq!(
+                SupportedExtension::try_from(extension.suffix()),
+                Ok(extension)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parser_parses_tcx_file() {
+        let file = std::fs::read("src/inbound/parser/test.tcx").unwrap();
+
+        let parsed = Parser::default()
+            .try_bytes_into_domain(&SupportedExtension::TCX, file)
+            .unwrap();
+
+        assert_eq!(parsed.sport(), &Sport::Cycling);
+        assert!(!parsed.timeseries().time().values().is_empty());
+    }
+
+    #[test]
+    fn test_parser_rejects_malformed_tcx_file() {
+        assert_eq!(
+            Parser::default()
+                .try_bytes_into_domain(&SupportedExtension::TCX, b"<Activity".to_vec())
+                .unwrap_err(),
+            ParseBytesError::InvalidContent
+        );
+    }
+
+    #[test]
+    fn test_unsupported_extension() {
+        assert_eq!(
+            SupportedExtension::try_from("kml"),
+            Err(ParseBytesError::UnsupportedExtension("kml".to_string()))
+        );
+        assert_eq!(
+            SupportedExtension::try_from("FIT".to_string()),
+            Err(ParseBytesError::UnsupportedExtension("FIT".to_string()))
+        );
+    }
+
+    /// GPX running track with one fix per second and a ~1.1 km jump at the third one.
+    fn gpx_with_gps_spike() -> Vec<u8> {
+        let points = [45., 45.0001, 45.01, 45.0003]
+            .iter()
+            .enumerate()
+            .map(|(idx, lat)| {
+                format!(
+                    r#"<trkpt lat="{lat}" lon="5"><time>2024-08-28T07:12:0{idx}Z</time></trkpt>"#
+                )
+            })
+            .collect::<String>();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk><type>running</type><trkseg>{points}</trkseg></trk>
+</gpx>"#
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_parser_uses_configured_max_gps_speeds() {
+        let removed_points = |parser: Parser| {
+            parser
+                .try_bytes_into_domain(&SupportedExtension::GPX, gpx_with_gps_spike())
+                .unwrap()
+                .statistics()
+                .get(&ActivityStatistic::RemovedGpsPoints)
+                .copied()
+        };
+
+        assert_eq!(removed_points(Parser::default()), Some(1.));
+        assert_eq!(
+            removed_points(Parser::default().with_max_gps_speeds(MaxGpsSpeeds::new(
+                HashMap::from([(SportCategory::Running, 2000.)])
+            ))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_without_gps_spikes_updates_statistics() {
+        let float_values = |values: &[f64]| {
+            values
+                .iter()
+                .map(|value| Some(TimeseriesValue::Float(*value)))
+                .collect()
+        };
+        // The third fix is ~1.1 km away from the others
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new(vec![0, 1, 2, 3]),
+            TimeseriesActiveTime::new((0..4).map(ActiveTime::Running).collect()),
+            vec![],
+            vec![
+                Timeseries::new(
+                    TimeseriesMetric::Latitude,
+                    float_values(&[45., 45.0001, 45.01, 45.0003]),
+                ),
+                Timeseries::new(TimeseriesMetric::Longitude, float_values(&[5.; 4])),
+                Timeseries::new(
+                    TimeseriesMetric::Distance,
+                    float_values(&[0., 11., 1112., 2213.]),
+                ),
+            ],
+        )
+        .unwrap();
+        let parsed = ParsedFileContent::new(
+            Sport::Running,
+            ActivityStartTime::from_timestamp(1000).unwrap(),
+            ActivityDuration::from(10.),
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, 2213.)])),
+            timeseries,
+            "fit".to_string(),
+            vec![],
+        );
+
+        let cleaned = parsed.without_gps_spikes(&MaxGpsSpeeds::default());
+
+        assert_eq!(
+            cleaned
+                .statistics()
+                .get(&ActivityStatistic::RemovedGpsPoints),
+            Some(&1.)
+        );
+        let distance = cleaned
+            .statistics()
+            .get(&ActivityStatistic::Distance)
+            .unwrap();
+        // The 11 m recorded up to the second fix are kept, only the ~22.2 m between the fixes
+        // around the spike being recomputed
+        assert!((distance - 33.24).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_new_computes_recording_span_from_records() {
+        // The device reports a 100 seconds session starting at 1000, but records only span from
+        // 1005 to 1065
+        let timeseries = ActivityTimeseries::new(
+            TimeseriesTime::new(vec![5, 6, 65]),
+            TimeseriesActiveTime::new(vec![ActiveTime::Running(0); 3]),
+            vec![],
+            vec![],
+        )
+        .unwrap();
+
+        let parsed = ParsedFileContent::new(
+            Sport::Running,
+            ActivityStartTime::from_timestamp(1000).unwrap(),
+            ActivityDuration::from(100.),
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Duration, 100.)])),
+            timeseries,
+            "fit".to_string(),
+            vec![],
+        );
+
+        let statistics = parsed.statistics();
+        assert_eq!(
+            statistics.get(&ActivityStatistic::FirstTimestamp),
+            Some(&1005.)
+        );
+        assert_eq!(
+            statistics.get(&ActivityStatistic::LastTimestamp),
+            Some(&1065.)
+        );
+        assert_eq!(statistics.get(&ActivityStatistic::Duration), Some(&100.));
+    }
+
+    #[test]
+    fn test_new_without_records_leaves_recording_span_unset() {
+        let parsed = ParsedFileContent::new(
+            Sport::Running,
+            ActivityStartTime::from_timestamp(1000).unwrap(),
+            ActivityDuration::from(100.),
+            ActivityStatistics::default(),
+            ActivityTimeseries::default(),
+            "json".to_string(),
+            vec![],
+        );
+
+        assert_eq!(
+            parsed.statistics().get(&ActivityStatistic::FirstTimestamp),
+            None
+        );
+        assert_eq!(
+            parsed.statistics().get(&ActivityStatistic::LastTimestamp),
+            None
+        );
+    }
+}
+
 #[cfg(test)]
 pub mod test_utils {
 
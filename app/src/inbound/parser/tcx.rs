@@ -52,6 +52,8 @@ fn find_sport(doc: &Document) -> Sport {
     }
 }
 
+/// Earliest `StartTime` attribute (of the activity's laps), or the `<Time>` of the first trackpoint
+/// for files without laps.
 fn find_activity_start_time(doc: &Document) -> Option<ActivityStartTime> {
     let start_time_nodes = doc.descendants().filter_map(|node| {
         node.attribute("StartTime").and_then(|content| {
@@ -62,11 +64,25 @@ fn find_activity_start_time(doc: &Document) -> Option<ActivityStartTime> {
         })
     });
 
-    let start_time = start_time_nodes.min()?;
+    let start_time = start_time_nodes
+        .min()
+        .or_else(|| find_first_trackpoint_time(doc))?;
 
     Some(ActivityStartTime::new(start_time))
 }
 
+fn find_first_trackpoint_time(doc: &Document) -> Option<DateTime<FixedOffset>> {
+    doc.descendants()
+        .filter(|node| node.has_tag_name("Trackpoint"))
+        .find_map(|node| {
+            node.children()
+                .find(|elem| elem.has_tag_name("Time"))?
+                .text()?
+                .parse::<DateTime<FixedOffset>>()
+                .ok()
+        })
+}
+
 fn find_activity_duration(doc: &Document) -> ActivityDuration {
     accumulate_lap_tag_values(doc, "TotalTimeSeconds")
         .map_or_else(ActivityDuration::default, ActivityDuration::from)
@@ -426,6 +442,46 @@ mod test_tcx_parser {
         );
     }
 
+    #[test]
+    fn test_find_activity_start_time_from_first_trackpoint() {
+        let doc = roxmltree::Document::parse(
+            "<Track>
+                <Trackpoint><Time>not-a-valid-date-time</Time></Trackpoint>
+                <Trackpoint><Time>2024-08-28T07:12:54.000Z</Time></Trackpoint>
+                <Trackpoint><Time>2024-08-28T07:12:55.000Z</Time></Trackpoint>
+            </Track>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_activity_start_time(&doc),
+            Some(ActivityStartTime::new(
+                "2024-08-28T07:12:54+00:00"
+                    .parse::<DateTime<FixedOffset>>()
+                    .unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_find_activity_start_time_prefers_lap_start_time() {
+        let doc = roxmltree::Document::parse(
+            "<Lap StartTime=\"2024-08-28T07:12:50.000Z\">
+                <Trackpoint><Time>2024-08-28T07:12:54.000Z</Time></Trackpoint>
+            </Lap>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_activity_start_time(&doc),
+            Some(ActivityStartTime::new(
+                "2024-08-28T07:12:50+00:00"
+                    .parse::<DateTime<FixedOffset>>()
+                    .unwrap()
+            ))
+        );
+    }
+
     #[test]
     fn test_find_activity_duration() {
         assert_eq!(
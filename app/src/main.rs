@@ -1,21 +1,36 @@
 use anyhow::anyhow;
-use app::config::{AppMode, StdEnvironment};
+use app::config::{AppMode, Config, DatabaseBackend, StdEnvironment};
 
 async fn run() -> anyhow::Result<()> {
-    let mode = AppMode::try_from_env(&StdEnvironment {}).map_err(|err| anyhow!(err))?;
-    match &mode {
-        AppMode::MultiUser(config) => {
+    let Config { base, mode } = Config::from_env(&StdEnvironment {})?;
+    let backend = base.database_backend().map_err(|err| anyhow!(err))?;
+    match (&mode, backend) {
+        (AppMode::MultiUser(config), DatabaseBackend::Sqlite) => {
             app::bootstrap::multi_user::bootstrap_multi_user(config.clone(), mode)
                 .await?
                 .run()
                 .await
         }
-        AppMode::SingleUser(config) => {
+        (AppMode::SingleUser(config), DatabaseBackend::Sqlite) => {
             app::bootstrap::single_user::bootstrap_single_user(config.clone(), mode)
                 .await?
                 .run()
                 .await
         }
+        #[cfg(feature = "postgres")]
+        (AppMode::MultiUser(config), DatabaseBackend::Postgres(url)) => {
+            app::bootstrap::multi_user::bootstrap_multi_user_postgres(config.clone(), mode, &url)
+                .await?
+                .run()
+                .await
+        }
+        #[cfg(feature = "postgres")]
+        (AppMode::SingleUser(config), DatabaseBackend::Postgres(url)) => {
+            app::bootstrap::single_user::bootstrap_single_user_postgres(config.clone(), mode, &url)
+                .await?
+                .run()
+                .await
+        }
     }
 }
 
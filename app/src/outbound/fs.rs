@@ -6,7 +6,9 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use crate::{
     domain::{
         models::activity::ActivityId,
-        ports::activity::{GetRawDataError, RawContent, RawDataRepository, SaveRawDataError},
+        ports::activity::{
+            GetRawDataError, RawContent, RawDataRepository, SaveRawDataError, StagedRawData,
+        },
     },
     inbound::parser::SupportedExtension,
 };
@@ -56,10 +58,91 @@ impl RawDataRepository for FilesystemRawDataRepository {
         Ok(())
     }
 
+    async fn stage_raw_data(
+        &self,
+        activity_id: &ActivityId,
+        content: RawContent,
+    ) -> Result<StagedRawData, SaveRawDataError> {
+        let staged = StagedRawData::new(activity_id.clone(), content.extension().to_string());
+        tokio::fs::write(self.staged_path(&staged), content.raw_content())
+            .await
+            .map_err(|err| {
+                tracing::warn!(
+                    "Error while trying to write new raw data for activity {}",
+                    activity_id
+                );
+                tracing::warn!("{}", err);
+                SaveRawDataError::Unknown
+            })?;
+
+        Ok(staged)
+    }
+
+    async fn commit_raw_data(&self, staged: &StagedRawData) -> Result<(), SaveRawDataError> {
+        let activity_id = staged.activity_id();
+        // Renaming replaces the previous file with the same extension atomically, readers never
+        // see a partially written file
+        tokio::fs::rename(
+            self.staged_path(staged),
+            self.target_path(activity_id, staged.extension()),
+        )
+        .await
+        .map_err(|err| {
+            tracing::warn!(
+                "Error while trying to replace raw data for activity {}",
+                activity_id
+            );
+            tracing::warn!("{}", err);
+            SaveRawDataError::Unknown
+        })?;
+
+        // Remove the previous file if it had another extension
+        for ext in [
+            SupportedExtension::FIT,
+            SupportedExtension::TCX,
+            SupportedExtension::GPX,
+            SupportedExtension::CustomJSON,
+        ] {
+            if ext.suffix() == staged.extension() {
+                continue;
+            }
+            match tokio::fs::remove_file(self.target_path(activity_id, ext.suffix())).await {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    tracing::warn!(
+                        "Error while removing previous raw data for activity {}",
+                        activity_id
+                    );
+                    tracing::warn!("{}", err);
+                    return Err(SaveRawDataError::Unknown);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn discard_raw_data(&self, staged: &StagedRawData) -> Result<(), SaveRawDataError> {
+        match tokio::fs::remove_file(self.staged_path(staged)).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => {
+                tracing::warn!(
+                    "Error while discarding new raw data for activity {}",
+                    staged.activity_id()
+                );
+                tracing::warn!("{}", err);
+                Err(SaveRawDataError::Unknown)
+            }
+        }
+    }
+
     async fn get_raw_data(&self, activity_id: &ActivityId) -> Result<RawContent, GetRawDataError> {
         for ext in [
             SupportedExtension::FIT,
             SupportedExtension::TCX,
+            SupportedExtension::GPX,
             SupportedExtension::CustomJSON,
         ] {
             let mut file = match tokio::fs::OpenOptions::new()
@@ -99,6 +182,11 @@ impl FilesystemRawDataRepository {
         path.push(format!("{}.{}", activity_id, extension));
         path.to_path_buf()
     }
+
+    /// Path of staged raw data, ignored by [RawDataRepository::get_raw_data] until committed.
+    fn staged_path(&self, staged: &StagedRawData) -> PathBuf {
+        self.target_path(staged.activity_id(), &format!("{}.tmp", staged.extension()))
+    }
 }
 
 #[cfg(test)]
@@ -181,6 +269,67 @@ mod test_filesystem_raw_data_repository {
         assert_eq!(res, RawContent::new("tcx".to_string(), vec![0, 1, 2, 3]));
     }
 
+    #[tokio::test]
+    async fn test_replace_raw_data_with_another_extension() {
+        let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
+        let repository = FilesystemRawDataRepository::new(tmp_dir.path().to_path_buf());
+
+        let activity = ActivityId::new();
+        repository
+            .save_raw_data(
+                &activity,
+                RawContent::new("fit".to_string(), vec![0, 1, 2, 3]),
+            )
+            .await
+            .unwrap();
+
+        let staged = repository
+            .stage_raw_data(&activity, RawContent::new("tcx".to_string(), vec![4, 5]))
+            .await
+            .expect("Should have return OK");
+        // The current content is kept until the staged one is committed
+        let res = repository.get_raw_data(&activity).await.unwrap();
+        assert_eq!(res, RawContent::new("fit".to_string(), vec![0, 1, 2, 3]));
+
+        repository
+            .commit_raw_data(&staged)
+            .await
+            .expect("Should have return OK");
+
+        let res = repository.get_raw_data(&activity).await.unwrap();
+        assert_eq!(res, RawContent::new("tcx".to_string(), vec![4, 5]));
+        assert!(!tmp_dir.path().join(format!("{activity}.fit")).exists());
+        assert!(!tmp_dir.path().join(format!("{activity}.tcx.tmp")).exists());
+    }
+
+    #[tokio::test]
+    async fn test_discard_raw_data_keeps_current_content() {
+        let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
+        let repository = FilesystemRawDataRepository::new(tmp_dir.path().to_path_buf());
+
+        let activity = ActivityId::new();
+        repository
+            .save_raw_data(
+                &activity,
+                RawContent::new("fit".to_string(), vec![0, 1, 2, 3]),
+            )
+            .await
+            .unwrap();
+
+        let staged = repository
+            .stage_raw_data(&activity, RawContent::new("fit".to_string(), vec![4, 5]))
+            .await
+            .unwrap();
+        repository
+            .discard_raw_data(&staged)
+            .await
+            .expect("Should have return OK");
+
+        let res = repository.get_raw_data(&activity).await.unwrap();
+        assert_eq!(res, RawContent::new("fit".to_string(), vec![0, 1, 2, 3]));
+        assert!(!tmp_dir.path().join(format!("{activity}.fit.tmp")).exists());
+    }
+
     #[tokio::test]
     async fn test_get_raw_data_does_not_exist() {
         let tmp_dir = tempfile::tempdir().expect("Unable to create temporary directory");
@@ -1,2 +1,4 @@
 pub mod fs;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod sqlite;
@@ -0,0 +1,1176 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use chrono::{DateTime, FixedOffset, Utc};
+use sqlx::{PgPool, Postgres, error::BoxDynError, types::Json};
+
+use crate::{
+    domain::{
+        models::{
+            UserId,
+            activity::{
+                Activity, ActivityDuration, ActivityFeedback, ActivityId, ActivityMetricV2,
+                ActivityMetricsV2, ActivityName, ActivityNaturalKey, ActivityNutrition,
+                ActivityRpe, ActivityStartTime, ActivityStatistics, ActivityVisibility,
+                ActivityWithParsedData, ActivityWithSummary, Sport, WorkoutType,
+                safe_download_filename,
+            },
+        },
+        ports::{
+            DateTimeRange,
+            activity::{
+                ActivitiesFeedFilters, ActivityRepository, GetActivityError, GetRawActivityError,
+                ListActivitiesError, ListActivitiesFilters, RawActivity, RawDataRepository,
+                SaveActivityError, SimilarActivityError, UpdateActivityMetricError,
+            },
+        },
+    },
+    inbound::parser::{ParseBytesError, ParseFile},
+    outbound::sqlite::types::{activity_metric_from_str, activity_metric_to_str},
+};
+
+const ACTIVITY_COLUMNS: &str = "id, user_id, name, start_time, start_time_offset, duration, sport, rpe, workout_type, nutrition, feedback, visibility";
+
+type ActivityRow = (
+    String,
+    String,
+    Option<String>,
+    DateTime<Utc>,
+    i32,
+    Option<f64>,
+    String,
+    Option<i16>,
+    Option<String>,
+    Option<Json<ActivityNutrition>>,
+    Option<String>,
+    String,
+);
+
+/// An [ActivityRow] along with the statistics of the activity.
+type FeedRow = (
+    String,
+    String,
+    Option<String>,
+    DateTime<Utc>,
+    i32,
+    Option<f64>,
+    String,
+    Option<i16>,
+    Option<String>,
+    Option<Json<ActivityNutrition>>,
+    Option<String>,
+    String,
+    Option<Json<ActivityStatistics>>,
+);
+
+fn activity_from_row(row: ActivityRow) -> Result<Activity, BoxDynError> {
+    let (
+        id,
+        user_id,
+        name,
+        start_time,
+        start_time_offset,
+        duration,
+        sport,
+        rpe,
+        workout_type,
+        nutrition,
+        feedback,
+        visibility,
+    ) = row;
+
+    let rpe = match rpe {
+        Some(rpe) => Some(ActivityRpe::try_from(u8::try_from(rpe)?)?),
+        None => None,
+    };
+    let workout_type = match workout_type {
+        Some(workout_type) => Some(workout_type.parse::<WorkoutType>()?),
+        None => None,
+    };
+
+    Ok(Activity::new(
+        ActivityId::from(&id),
+        UserId::from(user_id),
+        name.as_deref().map(ActivityName::from),
+        ActivityStartTime::new(with_offset(start_time, start_time_offset)?),
+        duration.map(ActivityDuration::from).unwrap_or_default(),
+        sport
+            .parse::<Sport>()
+            .map_err(|_| format!("Unknown Sport: {sport}"))?,
+        rpe,
+        workout_type,
+        nutrition.map(|Json(nutrition)| nutrition),
+        feedback.map(ActivityFeedback::from),
+        visibility.parse::<ActivityVisibility>()?,
+    ))
+}
+
+/// Restore the offset of a datetime stored as a `TIMESTAMPTZ`, which only keeps the instant.
+fn with_offset(
+    datetime: DateTime<Utc>,
+    offset_seconds: i32,
+) -> Result<DateTime<FixedOffset>, BoxDynError> {
+    let offset = FixedOffset::east_opt(offset_seconds)
+        .ok_or_else(|| format!("Invalid UTC offset: {offset_seconds}"))?;
+    Ok(datetime.with_timezone(&offset))
+}
+
+/// Postgres implementation of [ActivityRepository]. Unlike the SQLite implementation there is no
+/// legacy `t_activities` table to keep in sync, all activities live in a single table.
+#[derive(Debug, Clone)]
+pub struct PostgresActivityRepository<R, FP> {
+    pool: PgPool,
+    raw_data_repository: R,
+    file_parser: FP,
+}
+
+impl<R, FP> PostgresActivityRepository<R, FP> {
+    pub async fn new(
+        url: &str,
+        raw_data_repository: R,
+        file_parser: FP,
+    ) -> Result<Self, sqlx::Error> {
+        let pool = PgPool::connect(url).await?;
+
+        // Run migrations
+        sqlx::migrate!("migrations/postgres/activities")
+            .run(&pool)
+            .await?;
+
+        Ok(Self {
+            pool,
+            raw_data_repository,
+            file_parser,
+        })
+    }
+
+    async fn metric_rowid(&self, metric: &ActivityMetricV2) -> Result<i64, anyhow::Error> {
+        // The no-op update makes RETURNING yield the row even when it already exists
+        sqlx::query_scalar::<_, i64>(
+            "INSERT INTO t_activities_metrics (metric)
+            VALUES ($1)
+            ON CONFLICT (metric) DO UPDATE SET metric = excluded.metric
+            RETURNING rowid;",
+        )
+        .bind(activity_metric_to_str(metric))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            anyhow!(
+                "Unable to insert {:} into t_activities_metrics. {err}",
+                metric
+            )
+        })
+    }
+
+    async fn fetch_metrics_values(
+        &self,
+        builder: &mut sqlx::QueryBuilder<'_, Postgres>,
+        metrics: &[ActivityMetricV2],
+    ) -> Result<HashMap<ActivityId, Vec<(ActivityMetricV2, Option<f64>)>>, anyhow::Error> {
+        builder.push(" AND t_activities_metrics.metric IN (");
+        let mut separated = builder.separated(", ");
+        for metric in metrics {
+            separated.push_bind(activity_metric_to_str(metric));
+        }
+        builder.push(")");
+
+        let mut metrics_values: HashMap<ActivityId, Vec<(ActivityMetricV2, Option<f64>)>> =
+            HashMap::new();
+        for (activity, metric, value) in builder
+            .build_query_as::<'_, (String, String, Option<f64>)>()
+            .fetch_all(&self.pool)
+            .await?
+        {
+            let metric = activity_metric_from_str(&metric).map_err(|err| anyhow!(err))?;
+            metrics_values
+                .entry(ActivityId::from(&activity))
+                .or_default()
+                .push((metric, value));
+        }
+
+        Ok(metrics_values)
+    }
+}
+
+impl<R, FP> PostgresActivityRepository<R, FP>
+where
+    R: RawDataRepository,
+    FP: ParseFile,
+{
+    async fn load_timeseries(
+        &self,
+        id: &ActivityId,
+        activity: Activity,
+    ) -> Result<ActivityWithParsedData, anyhow::Error> {
+        let raw_data = self
+            .raw_data_repository
+            .get_raw_data(id)
+            .await
+            .map_err(|err| anyhow!(err))?;
+
+        let extension = raw_data
+            .extension()
+            .try_into()
+            .map_err(|err: ParseBytesError| anyhow!(err))?;
+
+        let parsed_content = self
+            .file_parser
+            .try_bytes_into_domain(&extension, raw_data.raw_content())
+            .map_err(|err| anyhow!(err))?;
+
+        Ok(ActivityWithParsedData::new(
+            activity,
+            parsed_content.timeseries().clone(),
+            parsed_content.statistics().clone(),
+        )
+        .with_sessions(parsed_content.sessions().to_vec())
+        .with_unknown_messages(parsed_content.unknown_messages().clone())
+        .with_beat_intervals(parsed_content.beat_intervals().clone()))
+    }
+}
+
+impl<R, FP> ActivityRepository for PostgresActivityRepository<R, FP>
+where
+    R: RawDataRepository,
+    FP: ParseFile,
+{
+    async fn delete_activity(&self, activity: &ActivityId) -> Result<(), anyhow::Error> {
+        sqlx::query("DELETE FROM t_activities WHERE id = $1")
+            .bind(activity.to_string())
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|err| anyhow!("Unable to delete activity {}. {err}", activity))
+    }
+
+    async fn get_activity(&self, id: &ActivityId) -> Result<Option<Activity>, GetActivityError> {
+        match sqlx::query_as::<_, ActivityRow>(&format!(
+            "SELECT {ACTIVITY_COLUMNS} FROM t_activities WHERE id = $1 LIMIT 1;"
+        ))
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        {
+            Ok(Some(row)) => activity_from_row(row)
+                .map(Some)
+                .map_err(|err| GetActivityError::Unknown(anyhow!(err))),
+            Ok(None) => Err(GetActivityError::ActivityDoesNotExist(id.clone())),
+            Err(err) => Err(GetActivityError::Unknown(anyhow!(err))),
+        }
+    }
+
+    async fn get_activity_with_metrics(
+        &self,
+        id: &ActivityId,
+        metrics: &[ActivityMetricV2],
+    ) -> Result<Option<(Activity, ActivityMetricsV2)>, GetActivityError> {
+        let Some(activity) = self.get_activity(id).await? else {
+            return Err(GetActivityError::ActivityDoesNotExist(id.clone()));
+        };
+        if metrics.is_empty() {
+            return Ok(Some((activity, ActivityMetricsV2::default())));
+        }
+
+        let mut builder = sqlx::QueryBuilder::<'_, Postgres>::new(
+            "
+        SELECT
+            t_activities.id,
+            t_activities_metrics.metric,
+            t_activities_metrics_values.value
+        FROM t_activities
+        JOIN t_activities_metrics_values ON t_activities_metrics_values.activity_rowid = t_activities.rowid
+        JOIN t_activities_metrics ON t_activities_metrics_values.metric_rowid = t_activities_metrics.rowid",
+        );
+        builder
+            .push(" WHERE t_activities.id = ")
+            .push_bind(id.to_string());
+
+        let mut metrics_values = self
+            .fetch_metrics_values(&mut builder, metrics)
+            .await
+            .map_err(GetActivityError::Unknown)?;
+
+        Ok(Some((
+            activity,
+            ActivityMetricsV2::new(HashMap::from_iter(
+                metrics_values.remove(id).unwrap_or_default(),
+            )),
+        )))
+    }
+
+    async fn get_activity_with_parsed_data(
+        &self,
+        id: &ActivityId,
+    ) -> Result<Option<ActivityWithParsedData>, GetActivityError> {
+        let activity = match self.get_activity(id).await {
+            Ok(Some(activity)) => activity,
+            Ok(None) => return Err(GetActivityError::ActivityDoesNotExist(id.clone())),
+            Err(err) => return Err(err),
+        };
+
+        self.load_timeseries(id, activity)
+            .await
+            .map(Some)
+            .map_err(GetActivityError::Unknown)
+    }
+
+    async fn list_activities(
+        &self,
+        user: &UserId,
+        filters: &ListActivitiesFilters,
+    ) -> Result<Vec<Activity>, ListActivitiesError> {
+        let mut builder = sqlx::QueryBuilder::<'_, Postgres>::new(format!(
+            "SELECT {ACTIVITY_COLUMNS} FROM t_activities"
+        ));
+        builder
+            .push(" WHERE user_id = ")
+            .push_bind(user.to_string());
+
+        if let Some(date_range) = filters.date_range() {
+            builder
+                .push(" AND start_time >= ")
+                .push_bind(*date_range.start());
+            builder
+                .push(" AND start_time < ")
+                .push_bind(*date_range.end());
+        }
+
+        if let Some(visibility) = filters.visibility() {
+            builder
+                .push(" AND visibility = ")
+                .push_bind(visibility.to_string());
+        }
+
+        if let Some(bounding_box) = filters.bounding_box() {
+            builder
+                .push(" AND max_lat >= ")
+                .push_bind(bounding_box.min_lat());
+            builder
+                .push(" AND min_lat <= ")
+                .push_bind(bounding_box.max_lat());
+            builder
+                .push(" AND max_lon >= ")
+                .push_bind(bounding_box.min_lon());
+            builder
+                .push(" AND min_lon <= ")
+                .push_bind(bounding_box.max_lon());
+        }
+
+        builder.push(" ORDER BY start_time DESC");
+
+        if let Some(limit) = *filters.limit() {
+            builder.push(" LIMIT ").push_bind(limit as i64);
+        }
+
+        builder
+            .build_query_as::<'_, ActivityRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))?
+            .into_iter()
+            .map(|row| {
+                activity_from_row(row).map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))
+            })
+            .collect()
+    }
+
+    async fn get_raw_activity(
+        &self,
+        user: &UserId,
+        activity: &ActivityId,
+    ) -> Result<RawActivity, GetRawActivityError> {
+        let Some((sport, start_time, start_time_offset)) =
+            sqlx::query_as::<_, (String, DateTime<Utc>, i32)>(
+                "SELECT sport, start_time, start_time_offset
+                FROM t_activities
+                WHERE user_id = $1 AND id = $2
+                LIMIT 1;",
+            )
+            .bind(user.to_string())
+            .bind(activity.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|err| GetRawActivityError::Unknown(anyhow!(err)))?
+        else {
+            return Err(GetRawActivityError::ActivityDoesNotExist(activity.clone()));
+        };
+
+        let sport = sport
+            .parse::<Sport>()
+            .map_err(|_| GetRawActivityError::Unknown(anyhow!("Unknown Sport: {sport}")))?;
+        let start_time = with_offset(start_time, start_time_offset)
+            .map(ActivityStartTime::new)
+            .map_err(|err| GetRawActivityError::Unknown(anyhow!(err)))?;
+
+        let content = self
+            .raw_data_repository
+            .get_raw_data(activity)
+            .await
+            .map_err(|err| GetRawActivityError::Unknown(anyhow!(err)))?;
+
+        Ok(RawActivity::new(
+            safe_download_filename(activity, &sport, Some(&start_time), content.extension()),
+            content.raw_content(),
+        ))
+    }
+
+    async fn list_all_raw_activities(
+        &self,
+        user: &UserId,
+    ) -> Result<Vec<RawActivity>, ListActivitiesError> {
+        let activities = sqlx::query_scalar::<_, String>(
+            "SELECT id
+            FROM t_activities
+            WHERE user_id = $1;",
+        )
+        .bind(user.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))?;
+
+        let mut files = Vec::new();
+        for id in activities.iter().map(|id| ActivityId::from(id)) {
+            if let Ok(res) = self.raw_data_repository.get_raw_data(&id).await {
+                files.push(RawActivity::new(
+                    format!("{id}.{}", res.extension()),
+                    res.raw_content(),
+                ));
+            }
+        }
+
+        Ok(files)
+    }
+
+    async fn list_activities_with_parsed_data(
+        &self,
+        user: &UserId,
+        filters: &ListActivitiesFilters,
+    ) -> Result<Vec<ActivityWithParsedData>, ListActivitiesError> {
+        let activities = self.list_activities(user, filters).await?;
+
+        let mut res = vec![];
+        for activity in activities.into_iter() {
+            let Ok(activity_with_parsed_data) =
+                self.load_timeseries(&activity.id().clone(), activity).await
+            else {
+                continue;
+            };
+            res.push(activity_with_parsed_data);
+        }
+        Ok(res)
+    }
+
+    async fn update_activity_metric(
+        &self,
+        activity: &ActivityId,
+        metric: &ActivityMetricV2,
+        value: &Option<f64>,
+    ) -> Result<(), UpdateActivityMetricError> {
+        let activity_rowid =
+            sqlx::query_scalar::<_, i64>("SELECT rowid FROM t_activities WHERE id = $1 LIMIT 1;")
+                .bind(activity.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|_err| {
+                    UpdateActivityMetricError::ActivityDoesNotExist(activity.clone())
+                })?;
+
+        let metric_rowid = self.metric_rowid(metric).await?;
+
+        sqlx::query(
+            "INSERT INTO t_activities_metrics_values
+            (activity_rowid, metric_rowid, value)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (activity_rowid, metric_rowid)
+            DO UPDATE SET value = excluded.value;",
+        )
+        .bind(activity_rowid)
+        .bind(metric_rowid)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|err| match err {
+            sqlx::Error::Database(db_error) if db_error.is_foreign_key_violation() => {
+                UpdateActivityMetricError::ActivityDoesNotExist(activity.clone())
+            }
+            _ => UpdateActivityMetricError::Unknown(anyhow!(err)),
+        })
+    }
+
+    async fn get_activities_with_metrics(
+        &self,
+        user: &UserId,
+        filters: &ListActivitiesFilters,
+        metrics: &[ActivityMetricV2],
+    ) -> Result<Vec<(Activity, ActivityMetricsV2)>, ListActivitiesError> {
+        let mut metrics_values = if metrics.is_empty() {
+            HashMap::new()
+        } else {
+            let mut builder = sqlx::QueryBuilder::<'_, Postgres>::new(
+                "
+            SELECT
+                t_activities.id,
+                t_activities_metrics.metric,
+                t_activities_metrics_values.value
+            FROM t_activities
+            JOIN t_activities_metrics_values ON t_activities_metrics_values.activity_rowid = t_activities.rowid
+            JOIN t_activities_metrics ON t_activities_metrics_values.metric_rowid = t_activities_metrics.rowid",
+            );
+            builder
+                .push(" WHERE t_activities.user_id = ")
+                .push_bind(user.to_string());
+
+            if let Some(date_range) = filters.date_range() {
+                builder
+                    .push(" AND t_activities.start_time >= ")
+                    .push_bind(*date_range.start());
+                builder
+                    .push(" AND t_activities.start_time < ")
+                    .push_bind(*date_range.end());
+            }
+
+            self.fetch_metrics_values(&mut builder, metrics)
+                .await
+                .map_err(ListActivitiesError::Unknown)?
+        };
+
+        let mut res = vec![];
+        for activity in self.list_activities(user, filters).await? {
+            let metrics = metrics_values.remove(activity.id()).unwrap_or_default();
+            res.push((
+                activity,
+                ActivityMetricsV2::new(HashMap::from_iter(metrics)),
+            ));
+        }
+
+        Ok(res)
+    }
+
+    async fn get_activities_feed(
+        &self,
+        user: &UserId,
+        filters: &ActivitiesFeedFilters,
+    ) -> Result<Vec<ActivityWithSummary>, ListActivitiesError> {
+        let mut builder = sqlx::QueryBuilder::<'_, Postgres>::new(format!(
+            "SELECT {ACTIVITY_COLUMNS}, statistics FROM t_activities"
+        ));
+        builder
+            .push(" WHERE user_id = ")
+            .push_bind(user.to_string());
+
+        if let Some(before) = filters.before() {
+            let start_time = before.start_time().datetime().with_timezone(&Utc);
+            match before.id() {
+                Some(id) => builder
+                    .push(" AND (start_time, id) < (")
+                    .push_bind(start_time)
+                    .push(", ")
+                    .push_bind(id.to_string())
+                    .push(")"),
+                None => builder.push(" AND start_time < ").push_bind(start_time),
+            };
+        }
+
+        builder
+            .push(" ORDER BY start_time DESC, id DESC LIMIT ")
+            .push_bind(filters.limit() as i64);
+
+        builder
+            .build_query_as::<'_, FeedRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))?
+            .into_iter()
+            .map(
+                |(
+                    id,
+                    user_id,
+                    name,
+                    start_time,
+                    start_time_offset,
+                    duration,
+                    sport,
+                    rpe,
+                    workout_type,
+                    nutrition,
+                    feedback,
+                    visibility,
+                    statistics,
+                )| {
+                    let activity = activity_from_row((
+                        id,
+                        user_id,
+                        name,
+                        start_time,
+                        start_time_offset,
+                        duration,
+                        sport,
+                        rpe,
+                        workout_type,
+                        nutrition,
+                        feedback,
+                        visibility,
+                    ))
+                    .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))?;
+                    Ok(ActivityWithSummary::new(
+                        activity,
+                        statistics
+                            .map(|Json(statistics)| statistics)
+                            .unwrap_or_default(),
+                    ))
+                },
+            )
+            .collect()
+    }
+
+    async fn modify_activity_name(
+        &self,
+        id: &ActivityId,
+        name: Option<ActivityName>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET name = $1 WHERE id = $2;")
+            .bind(name.map(|name| name.to_string()))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn update_activity_rpe(
+        &self,
+        id: &ActivityId,
+        rpe: Option<ActivityRpe>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET rpe = $1 WHERE id = $2;")
+            .bind(rpe.map(|rpe| rpe.value() as i16))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn update_activity_workout_type(
+        &self,
+        id: &ActivityId,
+        workout_type: Option<WorkoutType>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET workout_type = $1 WHERE id = $2;")
+            .bind(workout_type.map(|workout_type| workout_type.to_string()))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn update_activity_nutrition(
+        &self,
+        id: &ActivityId,
+        nutrition: Option<ActivityNutrition>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET nutrition = $1 WHERE id = $2;")
+            .bind(nutrition.map(Json))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn update_activity_feedback(
+        &self,
+        id: &ActivityId,
+        feedback: Option<ActivityFeedback>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET feedback = $1 WHERE id = $2;")
+            .bind(feedback.map(|feedback| feedback.to_string()))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn update_activity_visibility(
+        &self,
+        id: &ActivityId,
+        visibility: ActivityVisibility,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities SET visibility = $1 WHERE id = $2;")
+            .bind(visibility.to_string())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
+    async fn save_activity(
+        &self,
+        activity: &ActivityWithParsedData,
+    ) -> Result<(), SaveActivityError> {
+        let start_time = activity.start_time().datetime();
+        let bounding_box = activity.bounding_box();
+
+        sqlx::query(
+            "INSERT INTO t_activities (
+                id, user_id, name, start_time, start_time_offset, duration, sport, natural_key, rpe, workout_type, nutrition, feedback, visibility,
+                min_lat, min_lon, max_lat, max_lon, statistics
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18
+            );",
+        )
+        .bind(activity.id().to_string())
+        .bind(activity.user().to_string())
+        .bind(activity.name().map(|name| name.to_string()))
+        .bind(start_time.with_timezone(&Utc))
+        .bind(start_time.offset().local_minus_utc())
+        .bind(*activity.duration().as_f64())
+        .bind(activity.sport().to_string())
+        .bind(activity.natural_key().to_string())
+        .bind(activity.rpe().as_ref().map(|rpe| rpe.value() as i16))
+        .bind(
+            activity
+                .workout_type()
+                .as_ref()
+                .map(|workout_type| workout_type.to_string()),
+        )
+        .bind(activity.nutrition().clone().map(Json))
+        .bind(activity.feedback().as_ref().map(|feedback| feedback.to_string()))
+        .bind(activity.visibility().to_string())
+        .bind(bounding_box.map(|bounds| bounds.min_lat()))
+        .bind(bounding_box.map(|bounds| bounds.min_lon()))
+        .bind(bounding_box.map(|bounds| bounds.max_lat()))
+        .bind(bounding_box.map(|bounds| bounds.max_lon()))
+        .bind(Json(activity.statistics()))
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!("Unable to save activity {}. {err}", activity.id()))
+        })
+    }
+
+    async fn replace_activity_data(
+        &self,
+        activity: &ActivityWithParsedData,
+    ) -> Result<(), SaveActivityError> {
+        let start_time = activity.start_time().datetime();
+        let bounding_box = activity.bounding_box();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| SaveActivityError::Unknown(err.into()))?;
+
+        sqlx::query(
+            "UPDATE t_activities
+            SET start_time = $1, start_time_offset = $2, duration = $3, sport = $4, natural_key = $5,
+                min_lat = $6, min_lon = $7, max_lat = $8, max_lon = $9, statistics = $10
+            WHERE id = $11;",
+        )
+        .bind(start_time.with_timezone(&Utc))
+        .bind(start_time.offset().local_minus_utc())
+        .bind(*activity.duration().as_f64())
+        .bind(activity.sport().to_string())
+        .bind(activity.natural_key().to_string())
+        .bind(bounding_box.map(|bounds| bounds.min_lat()))
+        .bind(bounding_box.map(|bounds| bounds.min_lon()))
+        .bind(bounding_box.map(|bounds| bounds.max_lat()))
+        .bind(bounding_box.map(|bounds| bounds.max_lon()))
+        .bind(Json(activity.statistics()))
+        .bind(activity.id().to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!(
+                "Unable to update activity {}. {err}",
+                activity.id()
+            ))
+        })?;
+
+        sqlx::query(
+            "DELETE FROM t_activities_metrics_values
+            WHERE activity_rowid = (SELECT rowid FROM t_activities WHERE id = $1);",
+        )
+        .bind(activity.id().to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!(
+                "Unable to clear metrics of activity {}. {err}",
+                activity.id()
+            ))
+        })?;
+
+        tx.commit()
+            .await
+            .map_err(|err| SaveActivityError::Unknown(err.into()))
+    }
+
+    async fn similar_activity_exists(
+        &self,
+        natural_key: &ActivityNaturalKey,
+    ) -> Result<bool, SimilarActivityError> {
+        sqlx::query("SELECT natural_key FROM t_activities WHERE natural_key = $1 LIMIT 1;")
+            .bind(natural_key.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map(|row| row.is_some())
+            .map_err(|err| SimilarActivityError::Unknown(anyhow!(err)))
+    }
+
+    async fn get_user_history_date_range(
+        &self,
+        user: &UserId,
+    ) -> Result<Option<DateTimeRange>, anyhow::Error> {
+        // Bounds are read with their own offset, MIN/MAX(...) would only return the instants
+        let bound = |order: &str| {
+            format!(
+                "SELECT start_time, start_time_offset
+                FROM t_activities
+                WHERE user_id = $1
+                ORDER BY start_time {order}
+                LIMIT 1;"
+            )
+        };
+
+        let mut bounds = Vec::with_capacity(2);
+        for order in ["ASC", "DESC"] {
+            let Some((start_time, offset)) =
+                sqlx::query_as::<_, (DateTime<Utc>, i32)>(&bound(order))
+                    .bind(user.to_string())
+                    .fetch_optional(&self.pool)
+                    .await
+                    .map_err(|err| {
+                        anyhow!("Unable to get history date range for user {}. {err}", user)
+                    })?
+            else {
+                return Ok(None);
+            };
+            bounds.push(with_offset(start_time, offset).map_err(|err| anyhow!(err))?);
+        }
+
+        Ok(Some(DateTimeRange::new(bounds[0], Some(bounds[1]))))
+    }
+}
+
+/// Run against a live database with
+/// `TEST_DATABASE_URL=postgres://... cargo test --features postgres -- --ignored`. Each test uses
+/// its own user so that tests can share the same database.
+#[cfg(test)]
+mod test_postgres_activity_repository {
+    use crate::{
+        domain::{
+            models::activity::{ActivityStatistic, ActivityStatistics, ActivityTimeseries},
+            ports::activity::{ActivitiesFeedCursor, test_utils::MockRawDataRepository},
+        },
+        inbound::parser::test_utils::MockFileParser,
+    };
+
+    use super::*;
+
+    async fn repository() -> PostgresActivityRepository<MockRawDataRepository, MockFileParser> {
+        let url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL should be set");
+        PostgresActivityRepository::new(&url, MockRawDataRepository::new(), MockFileParser::new())
+            .await
+            .expect("repo should init")
+    }
+
+    fn unique_user() -> UserId {
+        UserId::from(uuid::Uuid::new_v4().to_string())
+    }
+
+    fn build_activity(user: &UserId, start: &str) -> ActivityWithParsedData {
+        build_activity_with_visibility(user, start, ActivityVisibility::Private)
+    }
+
+    fn build_activity_with_visibility(
+        user: &UserId,
+        start: &str,
+        visibility: ActivityVisibility,
+    ) -> ActivityWithParsedData {
+        ActivityWithParsedData::new(
+            Activity::new_empty(
+                ActivityId::new(),
+                user.clone(),
+                ActivityStartTime::new(start.parse::<DateTime<FixedOffset>>().unwrap()),
+                ActivityDuration::from(3600.),
+                Sport::Cycling,
+            )
+            .with_visibility(visibility),
+            ActivityTimeseries::default(),
+            ActivityStatistics::default(),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_save_and_get_activity_keeps_offset() {
+        let repository = repository().await;
+        let activity = build_activity(&unique_user(), "2025-06-01T08:30:00+02:00");
+        repository.save_activity(&activity).await.unwrap();
+
+        let saved = repository
+            .get_activity(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(saved.id(), activity.id());
+        assert_eq!(saved.start_time(), activity.start_time());
+        assert_eq!(
+            saved.start_time().datetime().offset().local_minus_utc(),
+            7200
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_get_activities_feed() {
+        let repository = repository().await;
+        let user = unique_user();
+        let oldest = build_activity(&user, "2025-06-01T08:30:00+02:00");
+        let newest = ActivityWithParsedData::new(
+            build_activity(&user, "2025-06-03T08:30:00+02:00")
+                .activity()
+                .clone(),
+            ActivityTimeseries::default(),
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, 42000.)])),
+        );
+        let middle = build_activity(&user, "2025-06-02T08:30:00+02:00");
+        for activity in [&oldest, &newest, &middle] {
+            repository.save_activity(activity).await.unwrap();
+        }
+
+        let first_page = repository
+            .get_activities_feed(&user, &ActivitiesFeedFilters::new(2, None))
+            .await
+            .unwrap();
+        assert_eq!(
+            first_page
+                .iter()
+                .map(|item| item.activity().id().clone())
+                .collect::<Vec<_>>(),
+            vec![newest.id().clone(), middle.id().clone()]
+        );
+        assert_eq!(
+            first_page[0]
+                .summary()
+                .statistics()
+                .get(&ActivityStatistic::Distance),
+            Some(&42000.)
+        );
+
+        let second_page = repository
+            .get_activities_feed(
+                &user,
+                &ActivitiesFeedFilters::new(
+                    2,
+                    Some(ActivitiesFeedCursor::new(
+                        *first_page[1].activity().start_time(),
+                        Some(first_page[1].activity().id().clone()),
+                    )),
+                ),
+            )
+            .await
+            .unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].activity().id(), oldest.id());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_get_activity_does_not_exist() {
+        let repository = repository().await;
+
+        assert!(matches!(
+            repository.get_activity(&ActivityId::new()).await,
+            Err(GetActivityError::ActivityDoesNotExist(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_similar_activity_exists() {
+        let repository = repository().await;
+        let activity = build_activity(&unique_user(), "2025-06-01T08:30:00+02:00");
+
+        assert!(
+            !repository
+                .similar_activity_exists(&activity.natural_key())
+                .await
+                .unwrap()
+        );
+        repository.save_activity(&activity).await.unwrap();
+        assert!(
+            repository
+                .similar_activity_exists(&activity.natural_key())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_update_and_get_activity_metrics() {
+        let repository = repository().await;
+        let activity = build_activity(&unique_user(), "2025-06-01T08:30:00+02:00");
+        repository.save_activity(&activity).await.unwrap();
+
+        repository
+            .update_activity_metric(activity.id(), &ActivityMetricV2::Calories, &Some(500.))
+            .await
+            .unwrap();
+        repository
+            .update_activity_metric(activity.id(), &ActivityMetricV2::Calories, &Some(600.))
+            .await
+            .unwrap();
+
+        let (_, metrics) = repository
+            .get_activity_with_metrics(
+                activity.id(),
+                &[ActivityMetricV2::Calories, ActivityMetricV2::Distance],
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            metrics,
+            ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::Calories, Some(600.))]))
+        );
+
+        assert!(matches!(
+            repository
+                .update_activity_metric(&ActivityId::new(), &ActivityMetricV2::Calories, &None)
+                .await,
+            Err(UpdateActivityMetricError::ActivityDoesNotExist(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_list_activities_filters_and_order() {
+        let repository = repository().await;
+        let user = unique_user();
+        let first = build_activity(&user, "2025-06-01T08:30:00+02:00");
+        let second = build_activity_with_visibility(
+            &user,
+            "2025-06-02T08:30:00+02:00",
+            ActivityVisibility::Public,
+        );
+        repository.save_activity(&first).await.unwrap();
+        repository.save_activity(&second).await.unwrap();
+
+        let activities = repository
+            .list_activities(&user, &ListActivitiesFilters::empty())
+            .await
+            .unwrap();
+        assert_eq!(
+            activities.iter().map(Activity::id).collect::<Vec<_>>(),
+            vec![second.id(), first.id()]
+        );
+
+        let public = repository
+            .list_activities(
+                &user,
+                &ListActivitiesFilters::empty().set_visibility(Some(ActivityVisibility::Public)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            public.iter().map(Activity::id).collect::<Vec<_>>(),
+            vec![second.id()]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_update_activity_fields() {
+        let repository = repository().await;
+        let activity = build_activity(&unique_user(), "2025-06-01T08:30:00+02:00");
+        repository.save_activity(&activity).await.unwrap();
+
+        repository
+            .modify_activity_name(activity.id(), Some(ActivityName::from("Morning ride")))
+            .await
+            .unwrap();
+        repository
+            .update_activity_rpe(activity.id(), Some(ActivityRpe::try_from(7).unwrap()))
+            .await
+            .unwrap();
+        repository
+            .update_activity_visibility(activity.id(), ActivityVisibility::Unlisted)
+            .await
+            .unwrap();
+
+        let saved = repository
+            .get_activity(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.name(), Some(&ActivityName::from("Morning ride")));
+        assert_eq!(saved.rpe(), &Some(ActivityRpe::try_from(7).unwrap()));
+        assert_eq!(saved.visibility(), &ActivityVisibility::Unlisted);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_delete_activity() {
+        let repository = repository().await;
+        let activity = build_activity(&unique_user(), "2025-06-01T08:30:00+02:00");
+        repository.save_activity(&activity).await.unwrap();
+        repository
+            .update_activity_metric(activity.id(), &ActivityMetricV2::Calories, &Some(500.))
+            .await
+            .unwrap();
+
+        repository.delete_activity(activity.id()).await.unwrap();
+
+        assert!(matches!(
+            repository.get_activity(activity.id()).await,
+            Err(GetActivityError::ActivityDoesNotExist(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database"]
+    async fn test_get_user_history_date_range() {
+        let repository = repository().await;
+        let user = unique_user();
+        assert!(
+            repository
+                .get_user_history_date_range(&user)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        repository
+            .save_activity(&build_activity(&user, "2025-06-02T08:30:00-05:00"))
+            .await
+            .unwrap();
+        repository
+            .save_activity(&build_activity(&user, "2025-06-01T08:30:00+02:00"))
+            .await
+            .unwrap();
+
+        let range = repository
+            .get_user_history_date_range(&user)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            range,
+            DateTimeRange::new(
+                "2025-06-01T08:30:00+02:00".parse().unwrap(),
+                Some("2025-06-02T08:30:00-05:00".parse().unwrap())
+            )
+        );
+    }
+}
@@ -11,19 +11,21 @@ use crate::{
             activity::{
                 Activity, ActivityDuration, ActivityFeedback, ActivityId, ActivityMetricV2,
                 ActivityMetricsV2, ActivityName, ActivityNaturalKey, ActivityNutrition,
-                ActivityRpe, ActivityStartTime, ActivityWithParsedData, Sport, WorkoutType,
+                ActivityRpe, ActivityStartTime, ActivityStatistics, ActivityVisibility,
+                ActivityWithParsedData, ActivityWithSummary, Sport, WorkoutType,
+                safe_download_filename,
             },
         },
         ports::{
             DateTimeRange,
             activity::{
-                ActivityRepository, GetActivityError, GetRawActivityError, ListActivitiesError,
-                ListActivitiesFilters, RawActivity, RawDataRepository, SaveActivityError,
-                SimilarActivityError, UpdateActivityMetricError,
+                ActivitiesFeedFilters, ActivityRepository, GetActivityError, GetRawActivityError,
+                ListActivitiesError, ListActivitiesFilters, RawActivity, RawDataRepository,
+                SaveActivityError, SimilarActivityError, UpdateActivityMetricError,
             },
         },
     },
-    inbound::parser::ParseFile,
+    inbound::parser::{ParseBytesError, ParseFile},
 };
 
 type ActivityRow = (
@@ -37,8 +39,55 @@ type ActivityRow = (
     Option<WorkoutType>,
     Option<ActivityNutrition>,
     Option<ActivityFeedback>,
+    ActivityVisibility,
 );
 
+/// An [ActivityRow] along with the statistics stored in the legacy `t_activities` table.
+type FeedRow = (
+    ActivityId,
+    UserId,
+    Option<ActivityName>,
+    ActivityStartTime,
+    Option<ActivityDuration>,
+    Sport,
+    Option<ActivityRpe>,
+    Option<WorkoutType>,
+    Option<ActivityNutrition>,
+    Option<ActivityFeedback>,
+    ActivityVisibility,
+    Option<ActivityStatistics>,
+);
+
+fn activity_from_row(
+    (
+        id,
+        user_id,
+        name,
+        start_time,
+        duration,
+        sport,
+        rpe,
+        workout_type,
+        nutrition,
+        feedback,
+        visibility,
+    ): ActivityRow,
+) -> Activity {
+    Activity::new(
+        id,
+        user_id,
+        name,
+        start_time,
+        duration.unwrap_or_default(),
+        sport,
+        rpe,
+        workout_type,
+        nutrition,
+        feedback,
+        visibility,
+    )
+}
+
 #[derive(Debug, Clone)]
 pub struct SqliteActivityRepository<R, FP> {
     pool: SqlitePool,
@@ -121,7 +170,7 @@ where
         let extension = raw_data
             .extension()
             .try_into()
-            .map_err(|_| anyhow!("Unsupported file format: {}", raw_data.extension()))?;
+            .map_err(|err: ParseBytesError| anyhow!(err))?;
 
         let parsed_content = match self
             .file_parser
@@ -135,7 +184,10 @@ where
             activity,
             parsed_content.timeseries().clone(),
             parsed_content.statistics().clone(),
-        ))
+        )
+        .with_sessions(parsed_content.sessions().to_vec())
+        .with_unknown_messages(parsed_content.unknown_messages().clone())
+        .with_beat_intervals(parsed_content.beat_intervals().clone()))
     }
 }
 
@@ -165,7 +217,7 @@ where
 
     async fn get_activity(&self, id: &ActivityId) -> Result<Option<Activity>, GetActivityError> {
         match sqlx::query_as::<_, ActivityRow>(
-            "SELECT id, user_id, name, start_time, duration, sport, rpe, workout_type, nutrition, feedback
+            "SELECT id, user_id, name, start_time, duration, sport, rpe, workout_type, nutrition, feedback, visibility
             FROM t_activities_v2
             WHERE id = ?1
             LIMIT 1;",
@@ -174,7 +226,7 @@ where
         .fetch_one(&self.pool)
         .await
         {
-            Ok((id, user_id, name, start_time, duration , sport, rpe, workout_type, nutrition, feedback)) => {
+            Ok((id, user_id, name, start_time, duration , sport, rpe, workout_type, nutrition, feedback, visibility)) => {
 
                 Ok(Some(Activity::new(
                     id,
@@ -187,6 +239,7 @@ where
                     workout_type,
                     nutrition,
                     feedback,
+                    visibility,
                 )))
             }
             Err(sqlx::Error::RowNotFound) => {
@@ -265,7 +318,7 @@ where
         filters: &ListActivitiesFilters,
     ) -> Result<Vec<Activity>, ListActivitiesError> {
         let mut builder = sqlx::QueryBuilder::<'_, Sqlite>::new(
-            "SELECT id, user_id, name, start_time, duration, sport, rpe, workout_type, nutrition, feedback
+            "SELECT id, user_id, name, start_time, duration, sport, rpe, workout_type, nutrition, feedback, visibility
             FROM t_activities_v2",
         );
         builder.push(" WHERE user_id = ").push_bind(user);
@@ -279,6 +332,25 @@ where
                 .push_bind(date_range.end());
         }
 
+        if let Some(visibility) = filters.visibility() {
+            builder.push(" AND visibility = ").push_bind(*visibility);
+        }
+
+        if let Some(bounding_box) = filters.bounding_box() {
+            builder
+                .push(" AND max_lat >= ")
+                .push_bind(bounding_box.min_lat());
+            builder
+                .push(" AND min_lat <= ")
+                .push_bind(bounding_box.max_lat());
+            builder
+                .push(" AND max_lon >= ")
+                .push_bind(bounding_box.min_lon());
+            builder
+                .push(" AND min_lon <= ")
+                .push_bind(bounding_box.max_lon());
+        }
+
         builder.push("ORDER BY start_time DESC ");
 
         if let Some(limit) = *filters.limit() {
@@ -291,37 +363,7 @@ where
             .fetch_all(&self.pool)
             .await
             .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))
-            .map(|rows| {
-                rows.into_iter()
-                    .map(
-                        |(
-                            id,
-                            user_id,
-                            name,
-                            start_time,
-                            duration,
-                            sport,
-                            rpe,
-                            workout_type,
-                            nutrition,
-                            feedback,
-                        )| {
-                            Activity::new(
-                                id,
-                                user_id,
-                                name,
-                                start_time,
-                                duration.unwrap_or_default(),
-                                sport,
-                                rpe,
-                                workout_type,
-                                nutrition,
-                                feedback,
-                            )
-                        },
-                    )
-                    .collect()
-            })
+            .map(|rows| rows.into_iter().map(activity_from_row).collect())
     }
 
     async fn get_raw_activity(
@@ -329,8 +371,8 @@ where
         user: &UserId,
         activity: &ActivityId,
     ) -> Result<RawActivity, GetRawActivityError> {
-        let Some(_) = sqlx::query_as::<_, (ActivityId,)>(
-            "SELECT id
+        let Some((sport, start_time)) = sqlx::query_as::<_, (Sport, Option<ActivityStartTime>)>(
+            "SELECT sport, start_time
             FROM t_activities_v2
             WHERE user_id = ?1 and id = ?2
             LIMIT 1;",
@@ -351,7 +393,7 @@ where
             .map_err(|err| GetRawActivityError::Unknown(anyhow!(err)))?;
 
         Ok(RawActivity::new(
-            format!("{}.{}", activity, content.extension()),
+            safe_download_filename(activity, &sport, start_time.as_ref(), content.extension()),
             content.raw_content(),
         ))
     }
@@ -508,6 +550,94 @@ where
         Ok(res)
     }
 
+    async fn get_activities_feed(
+        &self,
+        user: &UserId,
+        filters: &ActivitiesFeedFilters,
+    ) -> Result<Vec<ActivityWithSummary>, ListActivitiesError> {
+        let mut builder = sqlx::QueryBuilder::<'_, Sqlite>::new(
+            "SELECT
+                t_activities_v2.id,
+                t_activities_v2.user_id,
+                t_activities_v2.name,
+                t_activities_v2.start_time,
+                t_activities_v2.duration,
+                t_activities_v2.sport,
+                t_activities_v2.rpe,
+                t_activities_v2.workout_type,
+                t_activities_v2.nutrition,
+                t_activities_v2.feedback,
+                t_activities_v2.visibility,
+                t_activities.statistics
+            FROM t_activities_v2
+            LEFT JOIN t_activities ON t_activities.id = t_activities_v2.id",
+        );
+        builder
+            .push(" WHERE t_activities_v2.user_id = ")
+            .push_bind(user);
+
+        if let Some(before) = filters.before() {
+            let start_time = before.start_time().datetime();
+            builder
+                .push(" AND (t_activities_v2.start_time < ")
+                .push_bind(start_time);
+            if let Some(id) = before.id() {
+                builder
+                    .push(" OR (t_activities_v2.start_time = ")
+                    .push_bind(start_time)
+                    .push(" AND t_activities_v2.id < ")
+                    .push_bind(id)
+                    .push(")");
+            }
+            builder.push(")");
+        }
+
+        builder
+            .push(" ORDER BY t_activities_v2.start_time DESC, t_activities_v2.id DESC LIMIT ")
+            .push_bind(filters.limit() as i64);
+
+        let rows = builder
+            .build_query_as::<'_, FeedRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| ListActivitiesError::Unknown(anyhow!(err)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    id,
+                    user_id,
+                    name,
+                    start_time,
+                    duration,
+                    sport,
+                    rpe,
+                    workout_type,
+                    nutrition,
+                    feedback,
+                    visibility,
+                    statistics,
+                )| {
+                    let activity = activity_from_row((
+                        id,
+                        user_id,
+                        name,
+                        start_time,
+                        duration,
+                        sport,
+                        rpe,
+                        workout_type,
+                        nutrition,
+                        feedback,
+                        visibility,
+                    ));
+                    ActivityWithSummary::new(activity, statistics.unwrap_or_default())
+                },
+            )
+            .collect())
+    }
+
     async fn modify_activity_name(
         &self,
         id: &ActivityId,
@@ -638,10 +768,25 @@ where
         tx.commit().await.map_err(|err| anyhow!(err))
     }
 
+    async fn update_activity_visibility(
+        &self,
+        id: &ActivityId,
+        visibility: ActivityVisibility,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query("UPDATE t_activities_v2 SET visibility = ?1 WHERE id = ?2;")
+            .bind(visibility)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| anyhow!(err))
+            .map(|_| ())
+    }
+
     async fn save_activity(
         &self,
         activity: &ActivityWithParsedData,
     ) -> Result<(), SaveActivityError> {
+        let bounding_box = activity.bounding_box();
         let mut tx = self
             .pool
             .begin()
@@ -673,10 +818,11 @@ where
 
         sqlx::query(
             "INSERT INTO t_activities_v2 (
-                id, user_id, name, start_time, duration, sport, natural_key, rpe, workout_type, nutrition, feedback
+                id, user_id, name, start_time, duration, sport, natural_key, rpe, workout_type, nutrition, feedback, visibility,
+                min_lat, min_lon, max_lat, max_lon
             )
             VALUES (
-                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16
             );",
         )
         .bind(activity.id())
@@ -690,6 +836,11 @@ where
         .bind(activity.workout_type())
         .bind(activity.nutrition())
         .bind(activity.feedback())
+        .bind(activity.visibility())
+        .bind(bounding_box.map(|bounds| bounds.min_lat()))
+        .bind(bounding_box.map(|bounds| bounds.min_lon()))
+        .bind(bounding_box.map(|bounds| bounds.max_lat()))
+        .bind(bounding_box.map(|bounds| bounds.max_lon()))
         .execute(&mut *tx)
         .await
         .map(|_| ())
@@ -702,6 +853,79 @@ where
             .map_err(|err| SaveActivityError::Unknown(err.into()))
     }
 
+    async fn replace_activity_data(
+        &self,
+        activity: &ActivityWithParsedData,
+    ) -> Result<(), SaveActivityError> {
+        let bounding_box = activity.bounding_box();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| SaveActivityError::Unknown(err.into()))?;
+
+        sqlx::query(
+            "UPDATE t_activities
+            SET start_time = ?1, sport = ?2, statistics = ?3, natural_key = ?4
+            WHERE id = ?5;",
+        )
+        .bind(activity.start_time().datetime())
+        .bind(activity.sport())
+        .bind(activity.statistics())
+        .bind(activity.natural_key())
+        .bind(activity.id())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!(
+                "Unable to update activity {}. {err}",
+                activity.id()
+            ))
+        })?;
+
+        sqlx::query(
+            "UPDATE t_activities_v2
+            SET start_time = ?1, duration = ?2, sport = ?3, natural_key = ?4,
+                min_lat = ?5, min_lon = ?6, max_lat = ?7, max_lon = ?8
+            WHERE id = ?9;",
+        )
+        .bind(activity.start_time().datetime())
+        .bind(activity.duration())
+        .bind(activity.sport())
+        .bind(activity.natural_key())
+        .bind(bounding_box.map(|bounds| bounds.min_lat()))
+        .bind(bounding_box.map(|bounds| bounds.min_lon()))
+        .bind(bounding_box.map(|bounds| bounds.max_lat()))
+        .bind(bounding_box.map(|bounds| bounds.max_lon()))
+        .bind(activity.id())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!(
+                "Unable to update activity {}. {err}",
+                activity.id()
+            ))
+        })?;
+
+        sqlx::query(
+            "DELETE FROM t_activities_metrics_values
+            WHERE activity_rowid = (SELECT rowid FROM t_activities_v2 WHERE id = ?1);",
+        )
+        .bind(activity.id())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            SaveActivityError::Unknown(anyhow!(
+                "Unable to clear metrics of activity {}. {err}",
+                activity.id()
+            ))
+        })?;
+
+        tx.commit()
+            .await
+            .map_err(|err| SaveActivityError::Unknown(err.into()))
+    }
+
     async fn similar_activity_exists(
         &self,
         natural_key: &ActivityNaturalKey,
@@ -758,13 +982,17 @@ mod test_sqlite_activity_repository {
                 UserId,
                 activity::{
                     ActiveTime, ActivityDuration, ActivityNutrition, ActivityStartTime,
-                    ActivityStatistics, ActivityTimeseries, BonkStatus, Sport, Timeseries,
-                    TimeseriesActiveTime, TimeseriesMetric, TimeseriesTime, TimeseriesValue,
+                    ActivityStatistic, ActivityStatistics, ActivityTimeseries, BonkStatus,
+                    BoundingBox, Sport, Timeseries, TimeseriesActiveTime, TimeseriesMetric,
+                    TimeseriesTime, TimeseriesValue,
                 },
             },
             ports::{
                 DateRange,
-                activity::{GetRawDataError, RawContent, test_utils::MockRawDataRepository},
+                activity::{
+                    ActivitiesFeedCursor, GetRawDataError, RawContent,
+                    test_utils::MockRawDataRepository,
+                },
             },
         },
         inbound::parser::{ParseBytesError, ParsedFileContent, test_utils::MockFileParser},
@@ -1845,6 +2073,357 @@ mod test_sqlite_activity_repository {
         );
     }
 
+    #[tokio::test]
+    async fn test_update_activity_visibility() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let activity = build_activity();
+        repository
+            .save_activity(&activity)
+            .await
+            .expect("Insertion should have succeed");
+
+        let activity = repository
+            .get_activity(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(activity.visibility(), &ActivityVisibility::Private);
+
+        repository
+            .update_activity_visibility(activity.id(), ActivityVisibility::Public)
+            .await
+            .expect("Should not have err");
+
+        let activity = repository
+            .get_activity(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(activity.visibility(), &ActivityVisibility::Public);
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_with_visibility() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let private = build_activity();
+        repository.save_activity(&private).await.unwrap();
+        let public = build_activity_starting_at(
+            &"2025-09-03T00:00:00Z"
+                .parse::<DateTime<FixedOffset>>()
+                .unwrap(),
+        );
+        repository.save_activity(&public).await.unwrap();
+        repository
+            .update_activity_visibility(public.id(), ActivityVisibility::Public)
+            .await
+            .unwrap();
+
+        let filters =
+            ListActivitiesFilters::empty().set_visibility(Some(ActivityVisibility::Public));
+        let activities = repository
+            .list_activities(&UserId::test_default(), &filters)
+            .await
+            .unwrap();
+
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].id(), public.id());
+    }
+
+    fn build_activity_at_positions(positions: &[(f64, f64)]) -> ActivityWithParsedData {
+        let coordinates = |select: fn(&(f64, f64)) -> f64| {
+            positions
+                .iter()
+                .map(|position| Some(TimeseriesValue::Float(select(position))))
+                .collect()
+        };
+        ActivityWithParsedData::new(
+            Activity::new_empty(
+                ActivityId::new(),
+                UserId::test_default(),
+                ActivityStartTime::from_timestamp(random_range(100..1200)).unwrap(),
+                ActivityDuration::default(),
+                Sport::Cycling,
+            ),
+            ActivityTimeseries::new(
+                TimeseriesTime::new((0..positions.len()).collect()),
+                TimeseriesActiveTime::new((0..positions.len()).map(ActiveTime::Running).collect()),
+                vec![],
+                vec![
+                    Timeseries::new(TimeseriesMetric::Latitude, coordinates(|(lat, _)| *lat)),
+                    Timeseries::new(TimeseriesMetric::Longitude, coordinates(|(_, lon)| *lon)),
+                ],
+            )
+            .unwrap(),
+            ActivityStatistics::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_list_activities_with_bounding_box() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let inside = build_activity_at_positions(&[(45.1, 5.1), (45.2, 5.3)]);
+        repository.save_activity(&inside).await.unwrap();
+        let overlapping = build_activity_at_positions(&[(45.9, 5.9), (46.5, 6.5)]);
+        repository.save_activity(&overlapping).await.unwrap();
+        let outside = build_activity_at_positions(&[(48.8, 2.3), (48.9, 2.4)]);
+        repository.save_activity(&outside).await.unwrap();
+        let without_position = build_activity();
+        repository.save_activity(&without_position).await.unwrap();
+
+        let filters = ListActivitiesFilters::empty()
+            .set_bounding_box(Some(BoundingBox::new(45., 5., 46., 6.).unwrap()));
+        let mut activities = repository
+            .list_activities(&UserId::test_default(), &filters)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|activity| activity.id().clone())
+            .collect::<Vec<_>>();
+        activities.sort();
+
+        let mut expected = vec![inside.id().clone(), overlapping.id().clone()];
+        expected.sort();
+        assert_eq!(activities, expected);
+    }
+
+    #[tokio::test]
+    async fn test_replace_activity_data_updates_bounding_box() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let activity = build_activity();
+        repository.save_activity(&activity).await.unwrap();
+        let filters = ListActivitiesFilters::empty()
+            .set_bounding_box(Some(BoundingBox::new(45., 5., 46., 6.).unwrap()));
+        assert!(
+            repository
+                .list_activities(&UserId::test_default(), &filters)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let replaced = build_activity_at_positions(&[(45.1, 5.1), (45.2, 5.3)]);
+        let replaced = ActivityWithParsedData::new(
+            Activity::new_empty(
+                activity.id().clone(),
+                activity.user().clone(),
+                *activity.start_time(),
+                ActivityDuration::default(),
+                Sport::Cycling,
+            ),
+            replaced.timeseries().clone(),
+            ActivityStatistics::default(),
+        );
+        repository.replace_activity_data(&replaced).await.unwrap();
+
+        let activities = repository
+            .list_activities(&UserId::test_default(), &filters)
+            .await
+            .unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].id(), activity.id());
+    }
+
+    fn build_activity_with_statistics(
+        start: &str,
+        statistics: ActivityStatistics,
+    ) -> ActivityWithParsedData {
+        let activity = build_activity_starting_at(&start.parse::<DateTime<FixedOffset>>().unwrap());
+        ActivityWithParsedData::new(
+            activity.activity().clone(),
+            activity.timeseries().clone(),
+            statistics,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_activities_feed_newest_first_with_summaries() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let oldest = build_activity_with_statistics(
+            "2025-09-01T08:00:00+02:00",
+            ActivityStatistics::default(),
+        );
+        let newest = build_activity_with_statistics(
+            "2025-09-03T08:00:00+02:00",
+            ActivityStatistics::new(HashMap::from([(ActivityStatistic::Distance, 42000.)])),
+        );
+        let middle = build_activity_with_statistics(
+            "2025-09-02T08:00:00+02:00",
+            ActivityStatistics::default(),
+        );
+        for activity in [&oldest, &newest, &middle] {
+            repository.save_activity(activity).await.unwrap();
+        }
+
+        let feed = repository
+            .get_activities_feed(
+                &UserId::test_default(),
+                &ActivitiesFeedFilters::new(10, None),
+            )
+            .await
+            .expect("Get should have succeeded");
+
+        assert_eq!(
+            feed.iter()
+                .map(|item| item.activity().id().clone())
+                .collect::<Vec<_>>(),
+            vec![
+                newest.id().clone(),
+                middle.id().clone(),
+                oldest.id().clone()
+            ]
+        );
+        assert_eq!(feed[0].summary().sport(), &Sport::Cycling);
+        assert_eq!(
+            feed[0]
+                .summary()
+                .statistics()
+                .get(&ActivityStatistic::Distance),
+            Some(&42000.)
+        );
+        assert_eq!(
+            feed[1].summary().statistics(),
+            &ActivityStatistics::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_activities_feed_paginates_activities_with_same_start_time() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let mut activities = vec![];
+        for _ in 0..3 {
+            let activity = build_activity_with_statistics(
+                "2025-09-01T08:00:00+02:00",
+                ActivityStatistics::default(),
+            );
+            repository.save_activity(&activity).await.unwrap();
+            activities.push(activity.id().clone());
+        }
+        activities.sort();
+        activities.reverse();
+
+        let mut feed = vec![];
+        let mut before = None;
+        loop {
+            let page = repository
+                .get_activities_feed(
+                    &UserId::test_default(),
+                    &ActivitiesFeedFilters::new(2, before),
+                )
+                .await
+                .unwrap();
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(ActivitiesFeedCursor::new(
+                *last.activity().start_time(),
+                Some(last.activity().id().clone()),
+            ));
+            feed.extend(page.iter().map(|item| item.activity().id().clone()));
+        }
+
+        assert_eq!(feed, activities);
+    }
+
+    #[tokio::test]
+    async fn test_get_activities_feed_paginates_before_start_time() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteActivityRepository::new(
+            &db_file.path().to_string_lossy(),
+            MockRawDataRepository::new(),
+            MockFileParser::new(),
+        )
+        .await
+        .expect("repo should init");
+        let mut activities = vec![];
+        for start in [
+            "2025-09-01T08:00:00+02:00",
+            "2025-09-02T08:00:00+02:00",
+            "2025-09-03T08:00:00+02:00",
+        ] {
+            let activity = build_activity_with_statistics(start, ActivityStatistics::default());
+            repository.save_activity(&activity).await.unwrap();
+            activities.push(activity.id().clone());
+        }
+
+        let first_page = repository
+            .get_activities_feed(
+                &UserId::test_default(),
+                &ActivitiesFeedFilters::new(2, None),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            first_page
+                .iter()
+                .map(|item| item.activity().id().clone())
+                .collect::<Vec<_>>(),
+            vec![activities[2].clone(), activities[1].clone()]
+        );
+
+        let second_page = repository
+            .get_activities_feed(
+                &UserId::test_default(),
+                &ActivitiesFeedFilters::new(
+                    2,
+                    Some(ActivitiesFeedCursor::new(
+                        *first_page[1].activity().start_time(),
+                        Some(first_page[1].activity().id().clone()),
+                    )),
+                ),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            second_page
+                .iter()
+                .map(|item| item.activity().id().clone())
+                .collect::<Vec<_>>(),
+            vec![activities[0].clone()]
+        );
+    }
+
     #[tokio::test]
     async fn test_natural_key_exists() {
         let db_file = NamedTempFile::new().unwrap();
@@ -2373,7 +2952,16 @@ mod test_sqlite_activity_repository {
                 .get_raw_activity(activity.user(), activity.id())
                 .await
                 .expect("Should not err");
-            assert_eq!(res.name(), format!("{}.fit", &activity.id()));
+            assert_eq!(
+                res.name(),
+                safe_download_filename(
+                    activity.id(),
+                    activity.sport(),
+                    Some(activity.start_time()),
+                    "fit"
+                )
+            );
+            assert!(res.name().ends_with("_cycling.fit"));
             assert_eq!(res.content(), &[0, 1, 2]);
         }
 
@@ -2887,6 +3475,56 @@ mod test_sqlite_activity_repository {
             );
         }
 
+        #[tokio::test]
+        async fn test_replace_activity_data_updates_activity_and_clears_metrics() {
+            let db_file = NamedTempFile::new().unwrap();
+            let repo = SqliteActivityRepository::new(
+                &db_file.path().to_string_lossy(),
+                MockRawDataRepository::new(),
+                MockFileParser::new(),
+            )
+            .await
+            .expect("repo should init");
+            let activity = build_activity();
+            repo.save_activity(&activity).await.unwrap();
+            repo.update_activity_metric(activity.id(), &ActivityMetricV2::AvgPower, &Some(1.2))
+                .await
+                .unwrap();
+
+            let replaced = ActivityWithParsedData::new(
+                Activity::new_empty(
+                    activity.id().clone(),
+                    activity.user().clone(),
+                    ActivityStartTime::from_timestamp(5000).unwrap(),
+                    ActivityDuration::from(42.),
+                    Sport::Running,
+                ),
+                ActivityTimeseries::default(),
+                ActivityStatistics::default(),
+            );
+            repo.replace_activity_data(&replaced)
+                .await
+                .expect("Should have succeeded");
+
+            let res = repo
+                .get_activities_with_metrics(
+                    activity.user(),
+                    &ListActivitiesFilters::empty(),
+                    &[ActivityMetricV2::AvgPower],
+                )
+                .await
+                .unwrap();
+            assert_eq!(res.len(), 1);
+            let (saved, metrics) = res.first().unwrap();
+            assert_eq!(saved.sport(), &Sport::Running);
+            assert_eq!(saved.duration(), &ActivityDuration::from(42.));
+            assert_eq!(
+                saved.start_time(),
+                &ActivityStartTime::from_timestamp(5000).unwrap()
+            );
+            assert!(metrics.is_empty());
+        }
+
         #[tokio::test]
         async fn test_update_metric_value_activity_does_not_exist() {
             let db_file = NamedTempFile::new().unwrap();
@@ -2930,7 +3568,7 @@ mod test_sqlite_activity_repository {
                 .await
                 .expect("Should have succeed");
 
-            repo.update_activity_metric(activity.id(), &ActivityMetricV2::AvgPower, &Some(3.14))
+            repo.update_activity_metric(activity.id(), &ActivityMetricV2::AvgPower, &Some(3.5))
                 .await
                 .expect("Should have succeeded");
 
@@ -2943,7 +3581,7 @@ mod test_sqlite_activity_repository {
             assert_eq!(returned_activity.id(), activity.id());
             assert_eq!(
                 metrics,
-                ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::AvgPower, Some(3.14))]))
+                ActivityMetricsV2::new(HashMap::from([(ActivityMetricV2::AvgPower, Some(3.5))]))
             );
         }
 
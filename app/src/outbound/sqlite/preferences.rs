@@ -138,7 +138,16 @@ impl PreferencesRepository for SqlitePreferencesRepository {
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::models::training::TrainingMetricId;
+    use std::collections::HashMap;
+
+    use crate::domain::models::{
+        activity::{
+            AthleteProfile, CommuteLocations, DefaultStreams, Ftp, GeoPoint, MaxGpsAccuracy,
+            MovingSpeedThresholds, PowerZones, SportCategory, SportThresholds, ThresholdPace,
+            TimeseriesMetric,
+        },
+        training::TrainingMetricId,
+    };
 
     use super::*;
 
@@ -186,6 +195,140 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_save_and_get_moving_speed_thresholds() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::MovingSpeedThresholds(
+            MovingSpeedThresholds::try_from(HashMap::from([(SportCategory::Cycling, 2.5)]))
+                .unwrap(),
+        );
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        let result = repo
+            .get_preference(&user, &PreferenceKey::MovingSpeedThresholds)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(preference));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_power_preferences() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let ftp = Preference::Ftp(Ftp::try_from(250.).unwrap());
+        let zones = Preference::PowerZones(
+            PowerZones::try_from(vec![0.5, 0.7, 0.9, 1.0, 1.1, 1.3]).unwrap(),
+        );
+
+        repo.save_preference(&user, &ftp).await.unwrap();
+        repo.save_preference(&user, &zones).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::Ftp)
+                .await
+                .unwrap(),
+            Some(ftp)
+        );
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::PowerZones)
+                .await
+                .unwrap(),
+            Some(zones)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_sport_thresholds() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::SportThresholds(SportThresholds {
+            cycling_ftp: Some(Ftp::try_from(250.).unwrap()),
+            running_threshold_pace: Some(ThresholdPace::try_from(270.).unwrap()),
+            swimming_css: None,
+        });
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::SportThresholds)
+                .await
+                .unwrap(),
+            Some(preference)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_default_streams() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::DefaultStreams(
+            DefaultStreams::try_from(vec![TimeseriesMetric::HeartRate, TimeseriesMetric::Pace])
+                .unwrap(),
+        );
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::DefaultStreams)
+                .await
+                .unwrap(),
+            Some(preference)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_commute_locations() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::CommuteLocations(CommuteLocations::new(
+            GeoPoint::new(45.19, 5.72).unwrap(),
+            GeoPoint::new(45.21, 5.80).unwrap(),
+        ));
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::CommuteLocations)
+                .await
+                .unwrap(),
+            Some(preference)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_max_gps_accuracy() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::MaxGpsAccuracy(MaxGpsAccuracy::try_from(25.).unwrap());
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::MaxGpsAccuracy)
+                .await
+                .unwrap(),
+            Some(preference)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_athlete_profile() {
+        let repo = create_test_repo().await;
+        let user = UserId::test_default();
+        let preference = Preference::AthleteProfile(AthleteProfile::new(70., 30).unwrap());
+
+        repo.save_preference(&user, &preference).await.unwrap();
+
+        assert_eq!(
+            repo.get_preference(&user, &PreferenceKey::AthleteProfile)
+                .await
+                .unwrap(),
+            Some(preference)
+        );
+    }
+
     #[tokio::test]
     async fn test_save_preference_updates_existing() {
         let repo = create_test_repo().await;
@@ -566,6 +566,36 @@ impl TrainingRepository for SqliteTrainingRepository {
         .map(|_| ())
     }
 
+    async fn save_training_notes(
+        &self,
+        notes: Vec<TrainingNote>,
+    ) -> Result<(), SaveTrainingNoteError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| SaveTrainingNoteError::Unknown(anyhow!(err)))?;
+
+        for note in notes {
+            sqlx::query(
+                "INSERT INTO t_training_notes (id, user_id, title, content, date, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6);",
+            )
+            .bind(note.id().to_string())
+            .bind(note.user().to_string())
+            .bind(note.title().as_ref().map(|t| t.to_string()))
+            .bind(note.content().to_string())
+            .bind(note.date().to_string())
+            .bind(note.created_at().to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| SaveTrainingNoteError::Unknown(anyhow!(err)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| SaveTrainingNoteError::Unknown(anyhow!(err)))
+    }
+
     async fn get_training_note(
         &self,
         user: &UserId,
@@ -2469,6 +2499,50 @@ mod test_sqlite_training_repository {
         );
     }
 
+    #[tokio::test]
+    async fn test_save_training_notes() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteTrainingRepository::new(&db_file.path().to_string_lossy())
+            .await
+            .expect("repo should init");
+
+        repository
+            .save_training_notes(vec![build_training_note(), build_training_note()])
+            .await
+            .expect("Should save notes");
+
+        assert_eq!(
+            sqlx::query_scalar::<_, i64>("select count(*) from t_training_notes")
+                .fetch_one(&repository.pool)
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_training_notes_saves_none_on_error() {
+        let db_file = NamedTempFile::new().unwrap();
+        let repository = SqliteTrainingRepository::new(&db_file.path().to_string_lossy())
+            .await
+            .expect("repo should init");
+
+        // The second note has the same id as the first one and fails to insert
+        let note = build_training_note();
+        let result = repository
+            .save_training_notes(vec![note.clone(), note])
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            sqlx::query_scalar::<_, i64>("select count(*) from t_training_notes")
+                .fetch_one(&repository.pool)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
     #[tokio::test]
     async fn test_save_training_note_stores_all_fields() {
         let db_file = NamedTempFile::new().unwrap();
@@ -8,8 +8,8 @@ use crate::domain::models::{
     activity::{
         ActivityDuration, ActivityFeedback, ActivityId, ActivityMetricSource, ActivityMetricV2,
         ActivityName, ActivityNaturalKey, ActivityNutrition, ActivityRpe, ActivityStartTime,
-        ActivityStatistic, ActivityStatistics, Sport, TimeseriesAggregate, TimeseriesMetric,
-        WorkoutType,
+        ActivityStatistic, ActivityStatistics, ActivityVisibility, Sport, TimeseriesAggregate,
+        TimeseriesMetric, WorkoutType,
     },
     preferences::{Preference, PreferenceKey},
     training::{
@@ -502,6 +502,30 @@ impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for WorkoutType {
     }
 }
 
+impl sqlx::Type<sqlx::Sqlite> for ActivityVisibility {
+    fn type_info() -> <sqlx::Sqlite as sqlx::Database>::TypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for ActivityVisibility {
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = self.to_string();
+        args.push(sqlx::sqlite::SqliteArgumentValue::Text(text.into()));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for ActivityVisibility {
+    fn decode(value: <sqlx::Sqlite as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let s = <&str as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(s.parse()?)
+    }
+}
+
 impl sqlx::Type<sqlx::Sqlite> for ActivityNutrition {
     fn type_info() -> <sqlx::Sqlite as sqlx::Database>::TypeInfo {
         <Vec<u8> as sqlx::Type<sqlx::Sqlite>>::type_info()
@@ -689,6 +713,15 @@ impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for PreferenceKey {
 pub fn serialize_preference_value(preference: &Preference) -> Result<String, BoxDynError> {
     match preference {
         Preference::FavoriteMetric(id) => Ok(id.to_string()),
+        Preference::DefaultActivityVisibility(visibility) => Ok(visibility.to_string()),
+        Preference::MovingSpeedThresholds(thresholds) => Ok(serde_json::to_string(thresholds)?),
+        Preference::Ftp(ftp) => Ok(serde_json::to_string(ftp)?),
+        Preference::SportThresholds(thresholds) => Ok(serde_json::to_string(thresholds)?),
+        Preference::PowerZones(zones) => Ok(serde_json::to_string(zones)?),
+        Preference::DefaultStreams(streams) => Ok(serde_json::to_string(streams)?),
+        Preference::CommuteLocations(locations) => Ok(serde_json::to_string(locations)?),
+        Preference::MaxGpsAccuracy(accuracy) => Ok(serde_json::to_string(accuracy)?),
+        Preference::AthleteProfile(profile) => Ok(serde_json::to_string(profile)?),
     }
 }
 
@@ -699,6 +732,30 @@ pub fn deserialize_preference(key: &PreferenceKey, value: &str) -> Result<Prefer
             let id = TrainingMetricId::from(value);
             Ok(Preference::FavoriteMetric(id))
         }
+        PreferenceKey::DefaultActivityVisibility => {
+            let visibility = value.parse::<ActivityVisibility>()?;
+            Ok(Preference::DefaultActivityVisibility(visibility))
+        }
+        PreferenceKey::MovingSpeedThresholds => Ok(Preference::MovingSpeedThresholds(
+            serde_json::from_str(value)?,
+        )),
+        PreferenceKey::Ftp => Ok(Preference::Ftp(serde_json::from_str(value)?)),
+        PreferenceKey::SportThresholds => {
+            Ok(Preference::SportThresholds(serde_json::from_str(value)?))
+        }
+        PreferenceKey::PowerZones => Ok(Preference::PowerZones(serde_json::from_str(value)?)),
+        PreferenceKey::DefaultStreams => {
+            Ok(Preference::DefaultStreams(serde_json::from_str(value)?))
+        }
+        PreferenceKey::CommuteLocations => {
+            Ok(Preference::CommuteLocations(serde_json::from_str(value)?))
+        }
+        PreferenceKey::MaxGpsAccuracy => {
+            Ok(Preference::MaxGpsAccuracy(serde_json::from_str(value)?))
+        }
+        PreferenceKey::AthleteProfile => {
+            Ok(Preference::AthleteProfile(serde_json::from_str(value)?))
+        }
     }
 }
 
@@ -723,6 +780,10 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for TimeseriesMetric {
             Self::Pace => "pace-v3",
             Self::Latitude => "altitude",
             Self::Longitude => "longitude",
+            Self::Temperature => "temperature",
+            Self::RespirationRate => "respiration-rate",
+            Self::StressLevel => "stress-level",
+            Self::Grade => "grade",
         };
         args.push(sqlx::sqlite::SqliteArgumentValue::Text(s.into()));
         Ok(IsNull::No)
@@ -740,6 +801,10 @@ impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for TimeseriesMetric {
             "cadence" => Ok(Self::Cadence),
             "altitude" => Ok(Self::Altitude),
             "pace" => Ok(Self::Pace),
+            "temperature" => Ok(Self::Temperature),
+            "respiration-rate" => Ok(Self::RespirationRate),
+            "stress-level" => Ok(Self::StressLevel),
+            "grade" => Ok(Self::Grade),
             _ => Err(format!("Unknown TimeseriesMetric: {}", s).into()),
         }
     }
@@ -789,86 +854,102 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for ActivityMetricV2 {
         &self,
         args: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>,
     ) -> Result<IsNull, BoxDynError> {
-        let s = match self {
-            Self::Duration => "duration",
-            Self::Calories => "calories",
-            Self::Elevation => "elevation",
-            Self::Distance => "distance",
-            Self::NormalizedPower => "normalized-power",
+        args.push(sqlx::sqlite::SqliteArgumentValue::Text(
+            activity_metric_to_str(self).into(),
+        ));
+        Ok(IsNull::No)
+    }
+}
 
-            Self::ActiveDuration => "active-duration",
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for ActivityMetricV2 {
+    fn decode(value: <sqlx::Sqlite as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let s = <&str as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        activity_metric_from_str(s)
+    }
+}
 
-            Self::MaxSpeed => "max-speed",
-            Self::MinSpeed => "min-speed",
-            Self::AvgSpeed => "avg-speed",
+/// Name under which an [ActivityMetricV2] is stored, shared by all database backends.
+pub fn activity_metric_to_str(metric: &ActivityMetricV2) -> &'static str {
+    match metric {
+        ActivityMetricV2::Duration => "duration",
+        ActivityMetricV2::Calories => "calories",
+        ActivityMetricV2::Elevation => "elevation",
+        ActivityMetricV2::Distance => "distance",
+        ActivityMetricV2::NormalizedPower => "normalized-power",
+        ActivityMetricV2::HeartRateVariability => "hrv",
+        ActivityMetricV2::RespirationRate => "respiration-rate",
+        ActivityMetricV2::RemovedGpsPoints => "removed-gps-points",
 
-            Self::MaxPower => "max-power",
-            Self::MinPower => "min-power",
-            Self::AvgPower => "avg-power",
+        ActivityMetricV2::ActiveDuration => "active-duration",
 
-            Self::MaxHeartRate => "max-hr",
-            Self::MinHeartRate => "min-hr",
-            Self::AvgHeartRate => "avg-hr",
+        ActivityMetricV2::MaxSpeed => "max-speed",
+        ActivityMetricV2::MinSpeed => "min-speed",
+        ActivityMetricV2::AvgSpeed => "avg-speed",
 
-            Self::MaxCadence => "max-cadence",
-            Self::MinCadence => "min-cadence",
-            Self::AvgCadence => "avg-cadence",
+        ActivityMetricV2::MaxPower => "max-power",
+        ActivityMetricV2::MinPower => "min-power",
+        ActivityMetricV2::AvgPower => "avg-power",
 
-            Self::MaxAltitude => "max-altitude",
-            Self::MinAltitude => "min-altitude",
-            Self::AvgAltitude => "avg-altitude",
+        ActivityMetricV2::MaxHeartRate => "max-hr",
+        ActivityMetricV2::MinHeartRate => "min-hr",
+        ActivityMetricV2::AvgHeartRate => "avg-hr",
 
-            Self::MaxPace => "max-pace",
-            Self::MinPace => "min-pace",
-            Self::AvgPace => "avg-pace",
+        ActivityMetricV2::MaxCadence => "max-cadence",
+        ActivityMetricV2::MinCadence => "min-cadence",
+        ActivityMetricV2::AvgCadence => "avg-cadence",
 
-            Self::NumberOfActivity => "number-of-activities",
-        };
-        args.push(sqlx::sqlite::SqliteArgumentValue::Text(s.into()));
-        Ok(IsNull::No)
+        ActivityMetricV2::MaxAltitude => "max-altitude",
+        ActivityMetricV2::MinAltitude => "min-altitude",
+        ActivityMetricV2::AvgAltitude => "avg-altitude",
+
+        ActivityMetricV2::MaxPace => "max-pace",
+        ActivityMetricV2::MinPace => "min-pace",
+        ActivityMetricV2::AvgPace => "avg-pace",
+
+        ActivityMetricV2::NumberOfActivity => "number-of-activities",
     }
 }
 
-impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for ActivityMetricV2 {
-    fn decode(value: <sqlx::Sqlite as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
-        let s = <&str as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
-        match s {
-            "duration" => Ok(Self::Duration),
-            "calories" => Ok(Self::Calories),
-            "elevation" => Ok(Self::Elevation),
-            "distance" => Ok(Self::Distance),
-            "normalized-power" => Ok(Self::NormalizedPower),
+pub fn activity_metric_from_str(s: &str) -> Result<ActivityMetricV2, BoxDynError> {
+    match s {
+        "duration" => Ok(ActivityMetricV2::Duration),
+        "calories" => Ok(ActivityMetricV2::Calories),
+        "elevation" => Ok(ActivityMetricV2::Elevation),
+        "distance" => Ok(ActivityMetricV2::Distance),
+        "normalized-power" => Ok(ActivityMetricV2::NormalizedPower),
+        "hrv" => Ok(ActivityMetricV2::HeartRateVariability),
+        "respiration-rate" => Ok(ActivityMetricV2::RespirationRate),
+        "removed-gps-points" => Ok(ActivityMetricV2::RemovedGpsPoints),
 
-            "active-duration" => Ok(Self::ActiveDuration),
+        "active-duration" => Ok(ActivityMetricV2::ActiveDuration),
 
-            "max-speed" => Ok(Self::MaxSpeed),
-            "min-speed" => Ok(Self::MinSpeed),
-            "avg-speed" => Ok(Self::AvgSpeed),
+        "max-speed" => Ok(ActivityMetricV2::MaxSpeed),
+        "min-speed" => Ok(ActivityMetricV2::MinSpeed),
+        "avg-speed" => Ok(ActivityMetricV2::AvgSpeed),
 
-            "max-power" => Ok(Self::MaxPower),
-            "min-power" => Ok(Self::MinPower),
-            "avg-power" => Ok(Self::AvgPower),
+        "max-power" => Ok(ActivityMetricV2::MaxPower),
+        "min-power" => Ok(ActivityMetricV2::MinPower),
+        "avg-power" => Ok(ActivityMetricV2::AvgPower),
 
-            "max-hr" => Ok(Self::MaxHeartRate),
-            "min-hr" => Ok(Self::MinHeartRate),
-            "avg-hr" => Ok(Self::AvgHeartRate),
+        "max-hr" => Ok(ActivityMetricV2::MaxHeartRate),
+        "min-hr" => Ok(ActivityMetricV2::MinHeartRate),
+        "avg-hr" => Ok(ActivityMetricV2::AvgHeartRate),
 
-            "max-cadence" => Ok(Self::MaxCadence),
-            "min-cadence" => Ok(Self::MinCadence),
-            "avg-cadence" => Ok(Self::AvgCadence),
+        "max-cadence" => Ok(ActivityMetricV2::MaxCadence),
+        "min-cadence" => Ok(ActivityMetricV2::MinCadence),
+        "avg-cadence" => Ok(ActivityMetricV2::AvgCadence),
 
-            "max-altitude" => Ok(Self::MaxAltitude),
-            "min-altitude" => Ok(Self::MinAltitude),
-            "avg-altitude" => Ok(Self::AvgAltitude),
+        "max-altitude" => Ok(ActivityMetricV2::MaxAltitude),
+        "min-altitude" => Ok(ActivityMetricV2::MinAltitude),
+        "avg-altitude" => Ok(ActivityMetricV2::AvgAltitude),
 
-            "max-pace" => Ok(Self::MaxPace),
-            "min-pace" => Ok(Self::MinPace),
-            "avg-pace" => Ok(Self::AvgPace),
+        "max-pace" => Ok(ActivityMetricV2::MaxPace),
+        "min-pace" => Ok(ActivityMetricV2::MinPace),
+        "avg-pace" => Ok(ActivityMetricV2::AvgPace),
 
-            "number-of-activities" => Ok(Self::NumberOfActivity),
+        "number-of-activities" => Ok(ActivityMetricV2::NumberOfActivity),
 
-            _ => Err(format!("Unknown ActivityMetricV2: {}", s).into()),
-        }
+        _ => Err(format!("Unknown ActivityMetricV2: {}", s).into()),
     }
 }
 
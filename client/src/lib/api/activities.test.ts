@@ -0,0 +1,27 @@
+import { describe, it, expect } from 'vitest';
+import { PostActivitiesResponseSchema } from './activities';
+
+describe('PostActivitiesResponse Schema', () => {
+	it('should parse unprocessable files reasons', () => {
+		const data = {
+			created_ids: ['run'],
+			unprocessable_files: [
+				['duplicate.fit', 'DuplicatedActivity'],
+				['phone.fit', { PossibleDuplicate: 'existing-ride' }]
+			]
+		};
+
+		const result = PostActivitiesResponseSchema.parse(data);
+		expect(result.unprocessable_files[0][1]).toBe('DuplicatedActivity');
+		expect(result.unprocessable_files[1][1]).toEqual({ PossibleDuplicate: 'existing-ride' });
+	});
+
+	it('should reject unknown reason', () => {
+		const data = {
+			created_ids: [],
+			unprocessable_files: [['file.fit', 'NotAReason']]
+		};
+
+		expect(() => PostActivitiesResponseSchema.parse(data)).toThrow();
+	});
+});
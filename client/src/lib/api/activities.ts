@@ -145,27 +145,33 @@ export async function fetchActivityDetails(
 	return null;
 }
 
-const PostActivitiesResponseSchema = z.object({
+export const PostActivitiesResponseSchema = z.object({
 	created_ids: z.array(z.string()),
 	unprocessable_files: z.array(
 		z.tuple([
 			z.string(),
-			z.enum([
-				'CannotReadContent',
-				'CannotProcessFile',
-				'DuplicatedActivity',
-				'IncoherentTimeseries',
-				'UnsupportedFileExtension',
-				'Unknown'
+			z.union([
+				z.enum([
+					'CannotReadContent',
+					'CannotProcessFile',
+					'DuplicatedActivity',
+					'IncoherentTimeseries',
+					'UnsupportedFileExtension',
+					'Unknown'
+				]),
+				// Near duplicate of the existing activity whose ID is given
+				z.object({ PossibleDuplicate: z.string() })
 			])
 		])
 	)
 });
 
+export type UnprocessedReason = 'duplicated' | 'possible-duplicate' | 'invalid';
+
 export type PostActivitiesResponse =
 	| {
 			type: 'success';
-			unprocessed: { file: string; reason: 'duplicated' | 'invalid' }[];
+			unprocessed: { file: string; reason: UnprocessedReason }[];
 			nbOfProcessedFiles: number;
 	  }
 	| {
@@ -173,9 +179,20 @@ export type PostActivitiesResponse =
 	  }
 	| { type: 'authentication-error' };
 
-export async function postActivities(body: FormData): Promise<PostActivitiesResponse> {
+/**
+ * Upload activity files
+ * @param body - Form with one part per file
+ * @param force - Store files flagged as possible duplicates of existing activities anyway
+ */
+export async function postActivities(
+	body: FormData,
+	force: boolean = false
+): Promise<PostActivitiesResponse> {
 	try {
-		const response = await fetch(`${PUBLIC_APP_URL}/api/activity`, {
+		const url = force
+			? `${PUBLIC_APP_URL}/api/activity?force=true`
+			: `${PUBLIC_APP_URL}/api/activity`;
+		const response = await fetch(url, {
 			method: 'POST',
 			credentials: 'include',
 			mode: 'cors',
@@ -184,10 +201,15 @@ export async function postActivities(body: FormData): Promise<PostActivitiesResp
 
 		if (response.ok) {
 			const data = PostActivitiesResponseSchema.parse(await response.json());
-			const unprocessed: { file: string; reason: 'duplicated' | 'invalid' }[] =
+			const unprocessed: { file: string; reason: UnprocessedReason }[] =
 				data.unprocessable_files.map(([file, reason]) => {
-					const mappedReason = reason === 'DuplicatedActivity' ? 'duplicated' : 'invalid';
-					return { file, reason: mappedReason };
+					if (reason === 'DuplicatedActivity') {
+						return { file, reason: 'duplicated' };
+					}
+					if (typeof reason === 'object') {
+						return { file, reason: 'possible-duplicate' };
+					}
+					return { file, reason: 'invalid' };
 				});
 
 			return { type: 'success', unprocessed, nbOfProcessedFiles: data.created_ids.length };
@@ -114,6 +114,7 @@ export const sportsWithoutCategory: Sport[] = [
 	'Boxing',
 	'MixedMartialArts',
 	'Golf',
+	'Multisport',
 	'Other',
 	'InlineSkating'
 ];
@@ -198,6 +199,7 @@ export const sports = [
 	'MixedMartialArts',
 	'Golf',
 
+	'Multisport',
 	'Other'
 ] as const;
 
@@ -362,6 +364,8 @@ export const sportDisplay = (sport: Sport): string => {
 			return 'Mixed Martial Arts';
 		case 'Golf':
 			return 'Golf';
+		case 'Multisport':
+			return 'Multisport';
 		case 'Other':
 			return 'Other';
 	}
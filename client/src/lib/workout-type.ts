@@ -8,7 +8,8 @@ export const WORKOUT_TYPE_VALUES = [
 	'intervals',
 	'long_run',
 	'race',
-	'cross_training'
+	'cross_training',
+	'commute'
 ] as const;
 export type WorkoutType = (typeof WORKOUT_TYPE_VALUES)[number];
 
@@ -26,6 +27,8 @@ export const workoutTypeDisplay = (type: WorkoutType): string => {
 			return 'Race';
 		case 'cross_training':
 			return 'Cross Training';
+		case 'commute':
+			return 'Commute';
 	}
 };
 
@@ -43,6 +46,8 @@ export const workoutTypeToAPI = (type: WorkoutType): string => {
 			return 'Race';
 		case 'cross_training':
 			return 'CrossTraining';
+		case 'commute':
+			return 'Commute';
 	}
 };
 
@@ -55,7 +60,8 @@ export const WORKOUT_TYPE_LABELS: { value: WorkoutType; label: string }[] = [
 	{ value: 'intervals', label: 'Intervals' },
 	{ value: 'long_run', label: 'Long Run' },
 	{ value: 'race', label: 'Race' },
-	{ value: 'cross_training', label: 'Cross training' }
+	{ value: 'cross_training', label: 'Cross training' },
+	{ value: 'commute', label: 'Commute' }
 ];
 
 /**
@@ -88,6 +94,8 @@ export const getWorkoutTypeColor = (value: WorkoutType): string => {
 			return 'workout-race';
 		case 'cross_training':
 			return 'workout-cross-training';
+		case 'commute':
+			return 'workout-commute';
 	}
 };
 
@@ -105,5 +113,7 @@ export const getWorkoutTypeClass = (value: WorkoutType): string => {
 			return 'workout-race';
 		case 'cross_training':
 			return 'workout-cross-training';
+		case 'commute':
+			return 'workout-commute';
 	}
 };
@@ -4,7 +4,7 @@
 use std::path::Path;
 
 use crate::messages::{generate_messages_code, parse_messages_definitions};
-use crate::types::{generate_enums_code, parse_enums};
+use crate::types::{generate_enums_code, generate_enums_tests_code, parse_enums};
 use crate::utils::format_code;
 
 mod messages;
@@ -28,6 +28,7 @@ pub fn generate_code(profile: &Path) -> String {
 
     let mut code = generate_enums_code(&enums);
     code.push_str(&generate_messages_code(messages, enums_names));
+    code.push_str(&generate_enums_tests_code(&enums));
     code = format_code(&code);
 
     code
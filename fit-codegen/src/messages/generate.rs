@@ -62,6 +62,44 @@ pub enum FitField {{
 pub struct CustomField {{
     pub name: Option<String>,
     pub units: Option<String>,
+    /// Name as written in the developer field description, `name` being normalized when
+    /// [crate::ParseOptions::normalize_custom_names] is set.
+    pub original_name: Option<String>,
+}}"#
+    ));
+
+    let mut fit_field_names = join(
+        messages.iter().map(|(msg, _, __)| {
+            format!("Self::{}(field) => field.name()", snake_to_camel_case(msg))
+        }),
+        ",\n",
+    );
+    if !fit_field_names.is_empty() {
+        fit_field_names.push(',');
+    }
+
+    code.push_str(&format!(
+        r#"
+
+impl FitField {{
+    /// Name of the field, from the FIT profile or from the developer field description for
+    /// [FitField::Custom] fields.
+    pub fn name(&self) -> &str {{
+        match self {{
+            {fit_field_names}
+            Self::Custom(field) => field.name.as_deref().unwrap_or("unknown"),
+            Self::UnknownVariant(_) => "unknown"
+        }}
+    }}
+
+    /// Units of the field. Only known for [FitField::Custom] fields, from their developer field
+    /// description.
+    pub fn units(&self) -> Option<&str> {{
+        match self {{
+            Self::Custom(field) => field.units.as_deref(),
+            _ => None
+        }}
+    }}
 }}"#
     ));
 
@@ -85,7 +123,7 @@ pub struct CustomField {{
                         .unwrap_or_default();
 
                     subfields.insert(0, base_variant);
-                    join(subfields.into_iter(), ",\n")
+                    join(subfields, ",\n")
                 })
                 .chain(vec!["Unknown".to_string()]),
             ",\n",
@@ -161,6 +199,22 @@ pub enum {message}Field {{
             ",\n",
         );
 
+        let name_mappings = join(
+            definitions
+                .iter()
+                .flat_map(|def| {
+                    std::iter::once(&def.name).chain(
+                        subfields
+                            .get(&def.name)
+                            .into_iter()
+                            .flat_map(|fields| fields.iter().map(|field| &field.name)),
+                    )
+                })
+                .map(|name| format!("Self::{} => \"{name}\"", snake_to_camel_case(name)))
+                .chain(vec!["Self::Unknown => \"unknown\"".to_string()]),
+            ",\n",
+        );
+
         let timestamp_field = definitions
             .iter()
             .filter_map(|def| {
@@ -179,6 +233,13 @@ pub enum {message}Field {{
             r#"
 impl {message}Field {{
 
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {{
+        match self {{
+            {name_mappings}
+        }}
+    }}
+
     fn from(definition_field: u8) -> Self {{
         match definition_field {{
             {from_definition_field_mappings}
@@ -231,6 +292,7 @@ fn generate_subfields_enum(
             offset.unwrap_or(0.)
         ),
     };
+    let parent_is_scaled = field.scale.is_some() || field.offset.is_some();
     let mut code = String::new();
 
     let subfield_variants = join(
@@ -271,7 +333,8 @@ impl {message_name}Field{parent_field}Subfield {{
             .collect();
         Ok(DataMessageField {{
             kind: FitField::{message_name}({message_name}Field::{parent_field}),
-            values
+            values,
+            is_scaled: {parent_is_scaled}
         }})
     }}", ));
 
@@ -312,6 +375,7 @@ impl {message_name}Field{parent_field}Subfield {{
                     offset.unwrap_or(0.)
                 ),
             };
+            let is_scaled = subfield.scale.is_some() || subfield.offset.is_some();
             format!(
                 "
 |fields| {{
@@ -332,7 +396,8 @@ impl {message_name}Field{parent_field}Subfield {{
 
             Ok(DataMessageField {{
                 kind: FitField::{message_name}({message_name}Field::{subfield_name}),
-                values
+                values,
+                is_scaled: {is_scaled}
 
             }})
         }}),
@@ -55,7 +55,7 @@ pub fn parse_messages_definitions(
             definitions
                 .iter()
                 .filter_map(|def| is_fit_enum(&def.base_type))
-                .chain(subfields.iter().flat_map(|(_, subfields)| {
+                .chain(subfields.values().flat_map(|subfields| {
                     subfields
                         .iter()
                         .filter_map(|field| is_fit_enum(&field.base_type))
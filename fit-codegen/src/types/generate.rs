@@ -28,7 +28,7 @@ pub fn generate_enums_code(enums: &[(EnumName, EnumType, Vec<(usize, EnumVariant
         parse_uint64, parse_uint64z, parse_sint64,
         parse_float32, parse_float64, parse_string,
         parse_unknown, parse_byte_array as parse_byte, ScaleOffset,
-        DataValue, DataTypeError};",
+        DataValue, DataTypeError, UnknownVariantError, check_number_of_values};",
     );
     code.push_str("use crate::parser::definition::{Endianness};\n\n");
 
@@ -121,9 +121,11 @@ impl {name} {{
         endianness: &Endianness,
         number_of_bytes: u8
     ) -> Result<Vec<DataValue>, DataTypeError> {{
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
 
-        for _ in 0..number_of_bytes / 4 {{
+        for _ in 0..number_of_values {{
             values.push(DataValue::DateTime(reader.next_u32(endianness)?));
         }}
 
@@ -152,7 +154,7 @@ fn generate_enum_code(name: &str, base_type: &str, mapping: &[(usize, EnumVarian
     // Define the enum and its variants
     code.push_str(&format!(
         "
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum {enum_name} {{
     {variants}
 }}"
@@ -198,8 +200,10 @@ pub enum {enum_name} {{
             endianness: &Endianness,
             number_of_bytes: u8
         ) -> Result<Vec<DataValue>, DataTypeError> {{
+            let number_of_values = number_of_bytes / {enum_type_size};
+            check_number_of_values(reader, number_of_values as usize)?;
             let mut values = Vec::new();
-            for _ in 0..number_of_bytes / {enum_type_size} {{
+            for _ in 0..number_of_values {{
                 values.push(DataValue::Enum(FitEnum::{enum_name}(
                     Self::from(reader.next_{enum_type}({parse_arguments})?)
                 )));
@@ -208,6 +212,12 @@ pub enum {enum_name} {{
         }}"
     ));
 
+    code.push_str(&generate_str_helpers(&enum_name, mapping));
+
+    if let Some(helpers) = generate_bool_helpers(mapping) {
+        code.push_str(&helpers);
+    }
+
     // MesgNum need special treatment to be able to link to a FitField
     if enum_name == "MesgNum" {
         code.push_str(&generate_mesg_num_mappings(mapping));
@@ -216,9 +226,167 @@ pub enum {enum_name} {{
     // Close impl block
     code.push_str("}\n");
 
+    code.push_str(&generate_from_str_impl(&enum_name, mapping));
+
     code
 }
 
+/// Enumeration of the known variants of an enum and conversion to their names.
+fn generate_str_helpers(enum_name: &str, mapping: &[(usize, EnumVariant)]) -> String {
+    let variants: Vec<String> = mapping
+        .iter()
+        .map(|(_, variant)| snake_to_camel_case(variant))
+        .collect();
+
+    let all = join(
+        variants
+            .iter()
+            .map(|variant| format!("{enum_name}::{variant}")),
+        ",\n",
+    );
+    let as_str_mapping = join(
+        variants
+            .iter()
+            .map(|variant| format!("Self::{variant} => \"{variant}\""))
+            .chain(vec![
+                "Self::UnknownVariant(_) => \"UnknownVariant\"".to_string(),
+            ]),
+        ",\n",
+    );
+    format!(
+        r#"
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [{enum_name}] {{
+        &[{all}]
+    }}
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {{
+        match self {{
+            {as_str_mapping}
+        }}
+    }}
+"#
+    )
+}
+
+/// [std::str::FromStr] implementation of an enum, the reverse of its `as_str` helper.
+fn generate_from_str_impl(enum_name: &str, mapping: &[(usize, EnumVariant)]) -> String {
+    let from_str_mapping = join(
+        mapping
+            .iter()
+            .map(|(_, variant)| snake_to_camel_case(variant))
+            .map(|variant| format!("\"{variant}\" => Ok(Self::{variant})"))
+            .chain(vec![format!(
+                "_ => Err(UnknownVariantError::new(\"{enum_name}\", name))"
+            )]),
+        ",\n",
+    );
+
+    format!(
+        r#"
+impl std::str::FromStr for {enum_name} {{
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [{enum_name}::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {{
+        match name {{
+            {from_str_mapping}
+        }}
+    }}
+}}
+"#
+    )
+}
+
+/// Tests checking, for each enum, that [generate_str_helpers] enumerates all the known variants
+/// and that their names round-trip.
+pub fn generate_enums_tests_code(
+    enums: &[(EnumName, EnumType, Vec<(usize, EnumVariant)>)],
+) -> String {
+    let checks = join(
+        enums
+            .iter()
+            .filter(|(name, _, _)| !name.contains("date_time"))
+            .map(|(name, _, variants)| {
+                format!(
+                    "assert_str_helpers!({}, {});",
+                    snake_to_camel_case(name),
+                    variants.len()
+                )
+            }),
+        "\n",
+    );
+
+    format!(
+        r#"
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    macro_rules! assert_str_helpers {{
+        ($enum:ident, $count:expr) => {{
+            assert_eq!($enum::all().len(), $count, "{{}}", stringify!($enum));
+            for variant in $enum::all() {{
+                assert_eq!(variant.as_str().parse::<$enum>().as_ref(), Ok(variant));
+            }}
+        }};
+    }}
+
+    #[test]
+    fn test_enums_str_helpers() {{
+        {checks}
+    }}
+}}
+"#
+    )
+}
+
+/// Pairs of (true, false) variants marking an enum as a flag, e.g. `switch`.
+const BOOL_LIKE_VARIANTS: &[(&str, &str)] = &[
+    ("on", "off"),
+    ("enabled", "disabled"),
+    ("true", "false"),
+    ("yes", "no"),
+];
+
+/// Boolean helpers for flag-like enums, i.e. having one of [BOOL_LIKE_VARIANTS] pairs.
+fn generate_bool_helpers(mapping: &[(usize, EnumVariant)]) -> Option<String> {
+    let (true_variant, false_variant) = BOOL_LIKE_VARIANTS.iter().find(|(yes, no)| {
+        mapping.iter().any(|(_, variant)| variant == *yes)
+            && mapping.iter().any(|(_, variant)| variant == *no)
+    })?;
+    let true_name = snake_to_camel_case(true_variant);
+    let false_name = snake_to_camel_case(false_variant);
+
+    Some(format!(
+        r#"
+
+    /// Whether the value is `{true_name}`.
+    pub fn is_{true_variant}(&self) -> bool {{
+        matches!(self, Self::{true_name})
+    }}
+
+    /// `Some(true)` for `{true_name}`, `Some(false)` for `{false_name}` and `None` for any other
+    /// value.
+    pub fn as_bool(&self) -> Option<bool> {{
+        match self {{
+            Self::{true_name} => Some(true),
+            Self::{false_name} => Some(false),
+            _ => None,
+        }}
+    }}
+
+    pub fn from_bool(value: bool) -> Self {{
+        if value {{ Self::{true_name} }} else {{ Self::{false_name} }}
+    }}
+"#
+    ))
+}
+
 fn generate_mesg_num_mappings(mapping: &[(usize, String)]) -> String {
     let mut code = String::new();
     let mapping_field = join(
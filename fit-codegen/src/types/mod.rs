@@ -1,7 +1,7 @@
 mod generate;
 mod parse;
 
-pub use generate::generate_enums_code;
+pub use generate::{generate_enums_code, generate_enums_tests_code};
 pub use parse::parse_enums;
 
 pub type EnumName = String;
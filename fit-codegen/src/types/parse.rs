@@ -98,11 +98,10 @@ where
             break;
         }
 
-        if row.variant_name.is_some()
-            && row.variant_value.is_some()
-            && !ENUMS_SKIPPED_VARIANTS.contains(&row.variant_name.clone().unwrap().as_str())
+        if let (Some(variant_name), Some(variant_value)) = (row.variant_name, row.variant_value)
+            && !ENUMS_SKIPPED_VARIANTS.contains(&variant_name.as_str())
         {
-            variants.push((row.variant_value.unwrap(), row.variant_name.unwrap()));
+            variants.push((variant_value, variant_name));
         }
     }
 
@@ -1,5 +1,15 @@
-use clap::Parser;
-use fit_parser::parse_fit_file;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use clap::{Parser, ValueEnum};
+use fit_parser::{
+    DataMessage, DataValue, FitParserError, MesgNum, ParseOptions,
+    json::{messages_to_json, messages_to_ndjson, value_to_json},
+    parse_fit_reader_with_options,
+};
+use serde_json::Value;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     run_cli()
@@ -10,20 +20,400 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[command(about = "A CLI wrapper for parsing .fit files")]
 #[command(next_line_help = true)]
 struct Cli {
-    #[arg(long)]
-    file: String,
+    #[arg(long, required_unless_present = "check", conflicts_with = "check")]
+    file: Option<String>,
+
+    /// Check the integrity of every .fit file found in this directory, e.g. to validate a backup,
+    /// and exit with an error if any of them is corrupted
+    #[arg(long, value_name = "DIR")]
+    check: Option<PathBuf>,
+
+    /// How parsed messages are printed
+    #[arg(long, value_enum, default_value_t = OutputFormat::Debug)]
+    format: OutputFormat,
+
+    /// Only print messages of these types, e.g. `record,session`
+    #[arg(long, value_delimiter = ',', value_parser = parse_message_name)]
+    message: Vec<MesgNum>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    /// One JSON array of all messages
+    Json,
+    /// One JSON object per message and per line (JSON Lines)
+    Ndjson,
+    /// One message per line, using its `Debug` representation
+    Debug,
+    /// Fields of `Record` messages only, one record per line
+    Csv,
 }
 
 pub fn run_cli() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
-    let file = cli.file;
+    if let Some(directory) = cli.check {
+        let report = check_directory(&directory)?;
+        print!("{}", report.render());
+        if !report.is_ok() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    let messages = parse_fit_file(&file, false).unwrap();
+    let Some(file) = cli.file else {
+        return Err("either --file or --check is required".into());
+    };
+    let mut options = ParseOptions::builder();
+    if !cli.message.is_empty() {
+        options = options.messages(cli.message);
+    }
+    let options = options.build();
+    let messages = parse_fit_reader_with_options(std::fs::File::open(&file)?, options)?;
 
-    for message in messages {
-        println!("{message:?}");
+    match cli.format {
+        OutputFormat::Debug => {
+            for message in messages {
+                println!("{message:?}");
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&messages_to_json(&messages))?
+        ),
+        OutputFormat::Ndjson => {
+            for line in messages_to_ndjson(&messages) {
+                print!("{line}");
+            }
+        }
+        OutputFormat::Csv => print!("{}", records_to_csv(&messages)),
     }
 
     Ok(())
 }
+
+#[derive(Debug, Clone, PartialEq)]
+enum FileStatus {
+    Ok,
+    CrcFailure(String),
+    ParseError(String),
+}
+
+/// Status of every .fit file of a directory, see [check_directory].
+#[derive(Debug, Default)]
+struct IntegrityReport {
+    files: Vec<(PathBuf, FileStatus)>,
+}
+
+impl IntegrityReport {
+    fn count(&self, matches: fn(&FileStatus) -> bool) -> usize {
+        self.files
+            .iter()
+            .filter(|(_, status)| matches(status))
+            .count()
+    }
+
+    fn ok(&self) -> usize {
+        self.count(|status| matches!(status, FileStatus::Ok))
+    }
+
+    fn crc_failures(&self) -> usize {
+        self.count(|status| matches!(status, FileStatus::CrcFailure(_)))
+    }
+
+    fn parse_errors(&self) -> usize {
+        self.count(|status| matches!(status, FileStatus::ParseError(_)))
+    }
+
+    fn is_ok(&self) -> bool {
+        self.ok() == self.files.len()
+    }
+
+    /// One line per file followed by a summary line.
+    fn render(&self) -> String {
+        let mut output = String::new();
+        for (path, status) in self.files.iter() {
+            let line = match status {
+                FileStatus::Ok => format!("OK          {}", path.display()),
+                FileStatus::CrcFailure(err) => format!("CRC FAIL    {}: {err}", path.display()),
+                FileStatus::ParseError(err) => format!("PARSE ERROR {}: {err}", path.display()),
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "{} files checked: {} ok, {} CRC failures, {} parse errors\n",
+            self.files.len(),
+            self.ok(),
+            self.crc_failures(),
+            self.parse_errors()
+        ));
+        output
+    }
+}
+
+/// Parse every file with a `.fit` extension (case insensitive) found in `directory` and its
+/// subdirectories, in path order. Files are parsed with the body CRC enforced and a lenient data
+/// size, so that only files whose content is actually corrupted are reported.
+fn check_directory(directory: &Path) -> io::Result<IntegrityReport> {
+    let mut paths = Vec::new();
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("fit"))
+            {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+
+    let mut report = IntegrityReport::default();
+    for path in paths {
+        let options = ParseOptions::builder()
+            .validate_body_crc(true)
+            .lenient(true)
+            .build();
+        let status = match parse_fit_reader_with_options(std::fs::File::open(&path)?, options) {
+            Ok(_) => FileStatus::Ok,
+            Err(err @ FitParserError::InvalidBodyCRC(_, _)) => {
+                FileStatus::CrcFailure(err.to_string())
+            }
+            Err(err) => FileStatus::ParseError(err.to_string()),
+        };
+        report.files.push((path, status));
+    }
+    Ok(report)
+}
+
+/// Name of a message kind as used on the command line, e.g. `file_id` for [MesgNum::FileId].
+fn message_name(kind: &MesgNum) -> String {
+    let mut name = String::new();
+    for (index, char) in format!("{kind:?}").char_indices() {
+        if char.is_uppercase() && index > 0 {
+            name.push('_');
+        }
+        name.push(char.to_ascii_lowercase());
+    }
+    name
+}
+
+fn message_names() -> Vec<String> {
+    (0..=u16::MAX)
+        .map(MesgNum::from)
+        .filter(|kind| !matches!(kind, MesgNum::UnknownVariant(_)))
+        .map(|kind| message_name(&kind))
+        .collect()
+}
+
+fn parse_message_name(name: &str) -> Result<MesgNum, String> {
+    (0..=u16::MAX)
+        .map(MesgNum::from)
+        .filter(|kind| !matches!(kind, MesgNum::UnknownVariant(_)))
+        .find(|kind| message_name(kind) == name)
+        .ok_or_else(|| {
+            format!(
+                "unknown message `{name}`, valid messages are: {}",
+                message_names().join(", ")
+            )
+        })
+}
+
+/// Render the fields of `Record` messages as CSV, with one column per field found in any record.
+/// Fields with several values have them separated by `;`.
+fn records_to_csv(messages: &[DataMessage]) -> String {
+    let records = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Record)
+        .collect::<Vec<_>>();
+
+    let mut columns: Vec<String> = Vec::new();
+    for record in records.iter() {
+        for field in record.fields.iter() {
+            let name = field.kind.name().to_string();
+            if !columns.contains(&name) {
+                columns.push(name);
+            }
+        }
+    }
+
+    let mut csv = columns.join(",");
+    csv.push('\n');
+    for record in records {
+        let row = columns
+            .iter()
+            .map(|column| {
+                record
+                    .fields
+                    .iter()
+                    .find(|field| field.kind.name() == column)
+                    .map(|field| {
+                        field
+                            .values
+                            .iter()
+                            .map(value_to_csv)
+                            .collect::<Vec<_>>()
+                            .join(";")
+                    })
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn value_to_csv(value: &DataValue) -> String {
+    match value_to_json(value) {
+        Value::String(value) => value,
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fit_parser::{DataMessageField, FitField, RecordField};
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Cli {
+        Cli::try_parse_from(["fit-parser", "--file", "activity.fit"].iter().chain(args))
+            .expect("Should have parsed arguments")
+    }
+
+    #[test]
+    fn test_parse_format_argument() {
+        assert_eq!(parse_args(&[]).format, OutputFormat::Debug);
+        assert_eq!(
+            parse_args(&["--format", "debug"]).format,
+            OutputFormat::Debug
+        );
+        assert_eq!(parse_args(&["--format", "json"]).format, OutputFormat::Json);
+        assert_eq!(parse_args(&["--format", "csv"]).format, OutputFormat::Csv);
+        assert_eq!(
+            parse_args(&["--format", "ndjson"]).format,
+            OutputFormat::Ndjson
+        );
+
+        assert!(
+            Cli::try_parse_from(["fit-parser", "--file", "activity.fit", "--format", "xml"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_message_argument() {
+        assert!(parse_args(&[]).message.is_empty());
+        assert_eq!(
+            parse_args(&["--message", "record,session"]).message,
+            vec![MesgNum::Record, MesgNum::Session]
+        );
+        assert_eq!(
+            parse_args(&["--message", "file_id"]).message,
+            vec![MesgNum::FileId]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_message_argument() {
+        let err = Cli::try_parse_from([
+            "fit-parser",
+            "--file",
+            "activity.fit",
+            "--message",
+            "record,not_a_message",
+        ])
+        .expect_err("Should have rejected unknown message");
+
+        let err = err.to_string();
+        assert!(err.contains("unknown message `not_a_message`"));
+        assert!(err.contains("record, "));
+        assert!(err.contains("file_id, "));
+    }
+
+    #[test]
+    fn test_parse_check_argument() {
+        let cli = Cli::try_parse_from(["fit-parser", "--check", "backup"])
+            .expect("Should have parsed arguments");
+        assert_eq!(cli.check, Some(PathBuf::from("backup")));
+        assert_eq!(cli.file, None);
+
+        assert!(Cli::try_parse_from(["fit-parser"]).is_err());
+        assert!(
+            Cli::try_parse_from(["fit-parser", "--file", "activity.fit", "--check", "backup"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_directory() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/integrity");
+
+        let report = check_directory(&directory).expect("Should have read the directory");
+
+        assert_eq!(report.files.len(), 3);
+        assert_eq!(report.ok(), 1);
+        assert_eq!(report.crc_failures(), 1);
+        assert_eq!(report.parse_errors(), 1);
+        assert!(!report.is_ok());
+        assert_eq!(report.files[0].0, directory.join("good.fit"));
+        assert_eq!(report.files[0].1, FileStatus::Ok);
+        assert!(matches!(report.files[1].1, FileStatus::CrcFailure(_)));
+        assert!(
+            report
+                .render()
+                .ends_with("3 files checked: 1 ok, 1 CRC failures, 1 parse errors\n")
+        );
+    }
+
+    #[test]
+    fn test_render_records() {
+        let messages = vec![
+            DataMessage {
+                local_message_type: 0,
+                message_kind: MesgNum::Record,
+                fields: vec![
+                    DataMessageField {
+                        kind: FitField::Record(RecordField::Timestamp),
+                        values: vec![DataValue::DateTime(1000)],
+                        is_scaled: false,
+                    },
+                    DataMessageField {
+                        kind: FitField::Record(RecordField::HeartRate),
+                        values: vec![DataValue::Uint8(120)],
+                        is_scaled: false,
+                    },
+                ],
+            },
+            DataMessage {
+                local_message_type: 0,
+                message_kind: MesgNum::Record,
+                fields: vec![DataMessageField {
+                    kind: FitField::Record(RecordField::Timestamp),
+                    values: vec![DataValue::DateTime(1001)],
+                    is_scaled: false,
+                }],
+            },
+        ];
+
+        assert_eq!(
+            messages_to_json(&messages)[0],
+            json!({
+                "message": "Record",
+                "fields": {"timestamp": 1000, "heart_rate": 120},
+            })
+        );
+        assert_eq!(
+            records_to_csv(&messages),
+            "timestamp,heart_rate\n1000,120\n1001,\n"
+        );
+    }
+}
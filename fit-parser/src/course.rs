@@ -0,0 +1,342 @@
+//! Parsing of .FIT course files ([File::Course]) into a list of course points and a track.
+
+use std::slice;
+
+use thiserror::Error;
+
+use crate::{
+    CourseField, CoursePoint, CoursePointField, DataMessage, DataValue, File, FileIdField, FitEnum,
+    FitField, FitParserError, MesgNum, RecordField, Sport, parse_fit_messages,
+    utils::{find_fied_value_as_string, find_field_value_as_float, find_field_value_by_kind},
+};
+
+#[derive(Debug, Error)]
+pub enum CourseError {
+    #[error("Unable to parse .FIT content: {0}")]
+    Parser(#[from] FitParserError),
+
+    #[error("File is not a course file")]
+    NotACourse,
+}
+
+/// A position in degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// A turn-by-turn point of interest along a course, from a `course_point` message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waypoint {
+    pub name: Option<String>,
+    pub kind: CoursePoint,
+    pub position: Option<Position>,
+    /// Distance from the start of the course, in meters.
+    pub distance: Option<f64>,
+}
+
+/// A point of the course track, from a `record` message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPoint {
+    /// Raw FIT timestamp, i.e. seconds since the FIT epoch (1989-12-31T00:00:00Z).
+    pub timestamp: Option<u32>,
+    pub position: Option<Position>,
+    /// Distance from the start of the course, in meters.
+    pub distance: Option<f64>,
+    /// Altitude, in meters.
+    pub altitude: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Course {
+    pub name: Option<String>,
+    pub sport: Option<Sport>,
+    pub waypoints: Vec<Waypoint>,
+    pub track: Vec<TrackPoint>,
+}
+
+/// Parse the content of a .FIT course file. Unlike an activity, a course is made of a track
+/// (`record` messages) and of course points (`course_point` messages), and has no session.
+pub fn parse_course(bytes: Vec<u8>) -> Result<Course, CourseError> {
+    let messages = parse_fit_messages(bytes.into_iter(), false)?;
+
+    let file_type = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::FileId)
+        .find_map(|message| {
+            find_field_value_by_kind(
+                slice::from_ref(message),
+                &FitField::FileId(FileIdField::Type),
+            )
+        });
+    if !matches!(
+        file_type,
+        Some([DataValue::Enum(FitEnum::File(File::Course)), ..])
+    ) {
+        return Err(CourseError::NotACourse);
+    }
+
+    let mut course = Course::default();
+    for message in messages.iter() {
+        let message = slice::from_ref(message);
+        match message[0].message_kind {
+            MesgNum::Course => {
+                course.name =
+                    find_fied_value_as_string(message, &FitField::Course(CourseField::Name));
+                course.sport =
+                    find_field_value_by_kind(message, &FitField::Course(CourseField::Sport))
+                        .and_then(|values| {
+                            values.iter().find_map(|value| match value {
+                                DataValue::Enum(FitEnum::Sport(sport)) => Some(sport.clone()),
+                                _ => None,
+                            })
+                        });
+            }
+            MesgNum::CoursePoint => course.waypoints.push(Waypoint {
+                name: find_fied_value_as_string(
+                    message,
+                    &FitField::CoursePoint(CoursePointField::Name),
+                ),
+                kind: find_field_value_by_kind(
+                    message,
+                    &FitField::CoursePoint(CoursePointField::Type),
+                )
+                .and_then(|values| {
+                    values.iter().find_map(|value| match value {
+                        DataValue::Enum(FitEnum::CoursePoint(kind)) => Some(kind.clone()),
+                        _ => None,
+                    })
+                })
+                .unwrap_or(CoursePoint::Generic),
+                position: extract_position(
+                    message,
+                    &FitField::CoursePoint(CoursePointField::PositionLat),
+                    &FitField::CoursePoint(CoursePointField::PositionLong),
+                ),
+                distance: find_field_value_as_float(
+                    message,
+                    &FitField::CoursePoint(CoursePointField::Distance),
+                ),
+            }),
+            MesgNum::Record => course.track.push(TrackPoint {
+                timestamp: find_field_value_by_kind(
+                    message,
+                    &FitField::Record(RecordField::Timestamp),
+                )
+                .and_then(|values| {
+                    values.iter().find_map(|value| match value {
+                        DataValue::DateTime(timestamp) => Some(*timestamp),
+                        _ => None,
+                    })
+                }),
+                position: extract_position(
+                    message,
+                    &FitField::Record(RecordField::PositionLat),
+                    &FitField::Record(RecordField::PositionLong),
+                ),
+                distance: find_field_value_as_float(
+                    message,
+                    &FitField::Record(RecordField::Distance),
+                ),
+                altitude: find_field_value_as_float(
+                    message,
+                    &FitField::Record(RecordField::EnhancedAltitude),
+                )
+                .or_else(|| {
+                    find_field_value_as_float(message, &FitField::Record(RecordField::Altitude))
+                }),
+            }),
+            _ => {}
+        }
+    }
+
+    Ok(course)
+}
+
+/// Convert FIT semicircles into degrees.
+fn semicircles_to_degrees(semicircles: f64) -> f64 {
+    semicircles * 180. / (2.0_f64).powi(31)
+}
+
+pub(crate) fn extract_position(
+    message: &[DataMessage],
+    latitude: &FitField,
+    longitude: &FitField,
+) -> Option<Position> {
+    Some(Position {
+        latitude: semicircles_to_degrees(find_field_value_as_float(message, latitude)?),
+        longitude: semicircles_to_degrees(find_field_value_as_float(message, longitude)?),
+    })
+}
+
+impl CoursePoint {
+    /// Name of the course point type, as found in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Generic => "generic",
+            Self::Summit => "summit",
+            Self::Valley => "valley",
+            Self::Water => "water",
+            Self::Food => "food",
+            Self::Danger => "danger",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Straight => "straight",
+            Self::FirstAid => "first_aid",
+            Self::FourthCategory => "fourth_category",
+            Self::ThirdCategory => "third_category",
+            Self::SecondCategory => "second_category",
+            Self::FirstCategory => "first_category",
+            Self::HorsCategory => "hors_category",
+            Self::Sprint => "sprint",
+            Self::LeftFork => "left_fork",
+            Self::RightFork => "right_fork",
+            Self::MiddleFork => "middle_fork",
+            Self::SlightLeft => "slight_left",
+            Self::SharpLeft => "sharp_left",
+            Self::SlightRight => "slight_right",
+            Self::SharpRight => "sharp_right",
+            Self::UTurn => "u_turn",
+            Self::SegmentStart => "segment_start",
+            Self::SegmentEnd => "segment_end",
+            Self::Campsite => "campsite",
+            Self::AidStation => "aid_station",
+            Self::RestArea => "rest_area",
+            Self::GeneralDistance => "general_distance",
+            Self::Service => "service",
+            Self::EnergyGel => "energy_gel",
+            Self::SportsDrink => "sports_drink",
+            Self::MileMarker => "mile_marker",
+            Self::Checkpoint => "checkpoint",
+            Self::Shelter => "shelter",
+            Self::MeetingSpot => "meeting_spot",
+            Self::Overlook => "overlook",
+            Self::Toilet => "toilet",
+            Self::Shower => "shower",
+            Self::Gear => "gear",
+            Self::SharpCurve => "sharp_curve",
+            Self::SteepIncline => "steep_incline",
+            Self::Tunnel => "tunnel",
+            Self::Bridge => "bridge",
+            Self::Obstacle => "obstacle",
+            Self::Crossing => "crossing",
+            Self::Store => "store",
+            Self::Transition => "transition",
+            Self::Navaid => "navaid",
+            Self::Transport => "transport",
+            Self::Alert => "alert",
+            Self::Info => "info",
+            Self::UnknownVariant(_) => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::test_utils::{build_fit_file, data_record, definition_record};
+
+    use super::*;
+
+    /// Course file with a name, 3 track records and 2 course points (a left turn and a summit).
+    fn course_fixture() -> Vec<u8> {
+        // file_id: type (0, enum)
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[6]));
+
+        // course: name (5, string)
+        records.append(&mut definition_record(1, 31, &[(5, 8, 0x07)]));
+        records.append(&mut data_record(1, b"My loop\0"));
+
+        // record: timestamp (253), position_lat (0), position_long (1), distance (5)
+        records.append(&mut definition_record(
+            2,
+            20,
+            &[(253, 4, 0x86), (0, 4, 0x85), (1, 4, 0x85), (5, 4, 0x86)],
+        ));
+        for (index, lat) in [0_i32, 1 << 29, 1 << 30].iter().enumerate() {
+            let mut payload = (1000 + index as u32).to_le_bytes().to_vec();
+            payload.extend_from_slice(&lat.to_le_bytes());
+            payload.extend_from_slice(&0_i32.to_le_bytes());
+            payload.extend_from_slice(&(index as u32 * 10000).to_le_bytes());
+            records.append(&mut data_record(2, &payload));
+        }
+
+        // course_point: position_lat (2), position_long (3), distance (4), type (5), name (6)
+        records.append(&mut definition_record(
+            3,
+            32,
+            &[
+                (2, 4, 0x85),
+                (3, 4, 0x85),
+                (4, 4, 0x86),
+                (5, 1, 0x00),
+                (6, 6, 0x07),
+            ],
+        ));
+        let mut payload = (1_i32 << 29).to_le_bytes().to_vec();
+        payload.extend_from_slice(&0_i32.to_le_bytes());
+        payload.extend_from_slice(&10000_u32.to_le_bytes());
+        payload.push(6);
+        payload.extend_from_slice(b"Turn\0\0");
+        records.append(&mut data_record(3, &payload));
+        let mut payload = 0x7FFFFFFF_i32.to_le_bytes().to_vec();
+        payload.extend_from_slice(&0x7FFFFFFF_i32.to_le_bytes());
+        payload.extend_from_slice(&20000_u32.to_le_bytes());
+        payload.push(1);
+        payload.extend_from_slice(b"Top\0\0\0");
+        records.append(&mut data_record(3, &payload));
+
+        build_fit_file(&records)
+    }
+
+    #[test]
+    fn test_parse_course() {
+        let course = parse_course(course_fixture()).unwrap();
+
+        assert_eq!(course.name, Some("My loop".to_string()));
+        assert_eq!(course.track.len(), 3);
+        assert_eq!(course.track[1].timestamp, Some(1001));
+        assert_eq!(
+            course.track[1].position,
+            Some(Position {
+                latitude: 45.,
+                longitude: 0.
+            })
+        );
+        assert_eq!(course.track[2].distance, Some(200.));
+
+        assert_eq!(course.waypoints.len(), 2);
+        assert_eq!(
+            course
+                .waypoints
+                .iter()
+                .map(|waypoint| waypoint.kind.clone())
+                .collect::<Vec<_>>(),
+            vec![CoursePoint::Left, CoursePoint::Summit]
+        );
+        assert_eq!(course.waypoints[0].name, Some("Turn".to_string()));
+        assert_eq!(course.waypoints[0].distance, Some(100.));
+        assert_eq!(
+            course.waypoints[0].position,
+            Some(Position {
+                latitude: 45.,
+                longitude: 0.
+            })
+        );
+        assert_eq!(course.waypoints[1].kind.name(), "summit");
+        assert_eq!(course.waypoints[1].position, None);
+    }
+
+    #[test]
+    fn test_parse_course_rejects_activity_file() {
+        // file_id: type (0, enum) set to activity
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[4]));
+
+        assert!(matches!(
+            parse_course(build_fit_file(&records)),
+            Err(CourseError::NotACourse)
+        ));
+    }
+}
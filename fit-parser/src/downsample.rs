@@ -0,0 +1,112 @@
+//! Decimation of a series of points with the Largest-Triangle-Three-Buckets algorithm (see
+//! <https://skemman.is/bitstream/1946/15343/3/SS_MSthesis.pdf>), which keeps the visual shape of
+//! the series when plotting far fewer points.
+
+/// Keep `target` of the `points`, using `key` as the value of each point and its index as its
+/// position. The first and last points are always kept, and the selected points keep their
+/// original order. All the points are returned if there are no more than `target` of them.
+pub fn downsample<T: Clone>(points: &[T], target: usize, key: impl Fn(&T) -> f64) -> Vec<T> {
+    if target >= points.len() {
+        return points.to_vec();
+    }
+    match target {
+        0 => return vec![],
+        1 => return vec![points[0].clone()],
+        2 => return vec![points[0].clone(), points[points.len() - 1].clone()],
+        _ => {}
+    }
+
+    // Points between the first and the last ones are split into `target - 2` buckets, one point
+    // being selected in each of them
+    let bucket_size = (points.len() - 2) as f64 / (target - 2) as f64;
+    let bucket_start = |bucket: usize| (bucket as f64 * bucket_size) as usize + 1;
+
+    let mut selected = Vec::with_capacity(target);
+    selected.push(points[0].clone());
+    let mut previous = (0., key(&points[0]));
+    for bucket in 0..target - 2 {
+        // Average of the next bucket, or the last point for the last bucket
+        let next = bucket_start(bucket + 1)..bucket_start(bucket + 2).min(points.len());
+        let next_len = next.len() as f64;
+        let average = next.fold((0., 0.), |(x, y), idx| {
+            (x + idx as f64 / next_len, y + key(&points[idx]) / next_len)
+        });
+
+        let start = bucket_start(bucket);
+        let (mut best, mut best_area) = (start, f64::NEG_INFINITY);
+        for (idx, point) in points[..bucket_start(bucket + 1)]
+            .iter()
+            .enumerate()
+            .skip(start)
+        {
+            let (x, y) = (idx as f64, key(point));
+            let area = ((previous.0 - average.0) * (y - previous.1)
+                - (previous.0 - x) * (average.1 - previous.1))
+                .abs();
+            if area > best_area {
+                (best, best_area) = (idx, area);
+            }
+        }
+
+        selected.push(points[best].clone());
+        previous = (best as f64, key(&points[best]));
+    }
+    selected.push(points[points.len() - 1].clone());
+
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_wave(len: usize) -> Vec<(usize, f64)> {
+        (0..len)
+            .map(|idx| (idx, (idx as f64 / 10.).sin()))
+            .collect()
+    }
+
+    #[test]
+    fn test_downsample_sine_wave() {
+        let points = sine_wave(1000);
+
+        let downsampled = downsample(&points, 100, |(_, value)| *value);
+
+        assert_eq!(downsampled.len(), 100);
+        assert_eq!(downsampled.first(), points.first());
+        assert_eq!(downsampled.last(), points.last());
+        assert!(downsampled.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_downsample_keeps_peaks() {
+        let points = sine_wave(1000);
+
+        let downsampled = downsample(&points, 100, |(_, value)| *value);
+
+        let max = downsampled
+            .iter()
+            .map(|(_, value)| *value)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let min = downsampled
+            .iter()
+            .map(|(_, value)| *value)
+            .fold(f64::INFINITY, f64::min);
+        assert!(max > 0.99);
+        assert!(min < -0.99);
+    }
+
+    #[test]
+    fn test_downsample_small_targets() {
+        let points = sine_wave(10);
+
+        assert_eq!(downsample(&points, 20, |(_, value)| *value), points);
+        assert_eq!(downsample(&points, 10, |(_, value)| *value), points);
+        assert_eq!(
+            downsample(&points, 2, |(_, value)| *value),
+            vec![points[0], points[9]]
+        );
+        assert_eq!(downsample(&points, 1, |(_, value)| *value), vec![points[0]]);
+        assert!(downsample(&points, 0, |(_, value)| *value).is_empty());
+    }
+}
@@ -0,0 +1,620 @@
+//! Editable JSON representation of a whole .FIT file.
+//!
+//! Records are kept in file order with their local message types, and data fields hold their raw
+//! (i.e. not scaled) values, decoded according to the base type and endianness of their
+//! definition, so that the JSON can be converted back into an equivalent .FIT file:
+//!
+//! ```json
+//! {
+//!   "protocol_version": 32,
+//!   "profile_version": 2132,
+//!   "records": [
+//!     {"definition": {"local_message_type": 0, "global_message_number": 20, "endianness": "little",
+//!       "fields": [{"number": 3, "size": 1, "base_type": 2, "is_scaled": false}],
+//!       "developer_fields": []}},
+//!     {"data": {"local_message_type": 0, "time_offset": null,
+//!       "fields": [{"number": 3, "values": [120]}], "developer_fields": []}}
+//!   ]
+//! }
+//! ```
+//!
+//! Definition fields also tell with `is_scaled` whether the profile applies a scale and offset to
+//! their values when the file is parsed, in which case the raw values of the matching data fields
+//! are not their physical values.
+//!
+//! Float values that are not finite are represented as `null` and encoded back as invalid
+//! values. Developer fields values are kept as bytes.
+
+use std::borrow::Borrow;
+
+use serde_json::{Map, Value, json};
+use thiserror::Error;
+
+use crate::{
+    DataMessage, DataValue, FitBaseType, FitParserError, MesgNum,
+    parser::{
+        Endianness, RawData, RawDefinition, RawDeveloperFieldDefinition, RawFieldDefinition,
+        RawFile, RawRecord,
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum FitJsonError {
+    #[error("Unable to parse .FIT content: {0}")]
+    Parser(#[from] FitParserError),
+
+    #[error("Invalid JSON representation: {0}")]
+    Malformed(String),
+}
+
+/// Convert the content of a .FIT file into its JSON representation.
+pub fn fit_to_json(bytes: Vec<u8>) -> Result<Value, FitJsonError> {
+    let file = RawFile::decode(bytes)?;
+
+    let mut definitions: Vec<Option<&RawDefinition>> = vec![None; 16];
+    let mut records = Vec::new();
+    for record in file.records.iter() {
+        match record {
+            RawRecord::Definition(definition) => {
+                definitions[definition.local_message_type as usize] = Some(definition);
+                records.push(json!({ "definition": definition_to_json(definition) }));
+            }
+            RawRecord::Data(data) => {
+                let definition =
+                    definitions[data.local_message_type as usize].ok_or_else(|| {
+                        FitJsonError::Malformed(format!(
+                            "no definition for local message type {}",
+                            data.local_message_type
+                        ))
+                    })?;
+                records.push(json!({ "data": data_to_json(data, definition) }));
+            }
+        }
+    }
+
+    Ok(json!({
+        "protocol_version": file.protocol_version,
+        "profile_version": file.profile_version,
+        "records": records,
+    }))
+}
+
+impl DataMessage {
+    /// JSON representation of the message, an object with its kind and its fields by name. Fields
+    /// with a single value hold it directly, others hold an array:
+    ///
+    /// ```json
+    /// {"message": "Record", "fields": {"timestamp": 1000, "heart_rate": 120}}
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let mut fields = Map::new();
+        for field in self.fields.iter() {
+            let values = field.values.iter().map(value_to_json).collect::<Vec<_>>();
+            let value = match values.len() {
+                1 => values.into_iter().next().unwrap(),
+                _ => Value::Array(values),
+            };
+            fields.insert(field.kind.name().to_string(), value);
+        }
+        json!({
+            "message": format!("{:?}", self.message_kind),
+            "fields": fields,
+        })
+    }
+}
+
+/// Convert parsed [DataMessage]s into a JSON array of their [DataMessage::to_json]
+/// representations.
+pub fn messages_to_json(messages: &[DataMessage]) -> Value {
+    Value::Array(messages.iter().map(DataMessage::to_json).collect())
+}
+
+/// JSON Lines (NDJSON) representation of parsed [DataMessage]s, lazily yielding one line per
+/// message, see [DataMessage::to_json]. Each line ends with `\n`.
+pub fn messages_to_ndjson<M: Borrow<DataMessage>>(
+    messages: impl IntoIterator<Item = M>,
+) -> impl Iterator<Item = String> {
+    messages
+        .into_iter()
+        .map(|message| format!("{}\n", message.borrow().to_json()))
+}
+
+/// JSON representation of a parsed [DataValue], enums being represented by their name.
+pub fn value_to_json(value: &DataValue) -> Value {
+    match value {
+        DataValue::Enum(value) => json!(format!("{value:?}")),
+        DataValue::Sint8(value) => json!(value),
+        DataValue::Uint8(value) | DataValue::Uint8z(value) => json!(value),
+        DataValue::Sint16(value) => json!(value),
+        DataValue::Uint16(value) | DataValue::Uint16z(value) => json!(value),
+        DataValue::Sint32(value) => json!(value),
+        DataValue::Uint32(value) | DataValue::Uint32z(value) | DataValue::DateTime(value) => {
+            json!(value)
+        }
+        DataValue::Sint64(value) => json!(value),
+        DataValue::Uint64(value) | DataValue::Uint64z(value) => json!(value),
+        // Non finite floats are serialized as null
+        DataValue::Float32(value) => json!(value),
+        DataValue::Float64(value) => json!(value),
+        DataValue::String(value) => json!(value),
+        DataValue::Byte(bytes) | DataValue::Unknown(bytes) => json!(bytes),
+    }
+}
+
+/// Convert a JSON representation, as returned by [fit_to_json], back into the content of a .FIT
+/// file.
+pub fn json_to_fit(value: &Value) -> Result<Vec<u8>, FitJsonError> {
+    let protocol_version = get_u8(value, "protocol_version")?;
+    let profile_version = get_number(value, "profile_version")? as u16;
+
+    let mut definitions: Vec<Option<RawDefinition>> = vec![None; 16];
+    let mut records = Vec::new();
+    for record in get_array(value, "records")? {
+        if let Some(definition) = record.get("definition") {
+            let definition = definition_from_json(definition)?;
+            definitions[definition.local_message_type as usize] = Some(definition.clone());
+            records.push(RawRecord::Definition(definition));
+        } else if let Some(data) = record.get("data") {
+            let local_message_type = get_u8(data, "local_message_type")?;
+            let definition = definitions
+                .get(local_message_type as usize)
+                .and_then(|definition| definition.as_ref())
+                .ok_or_else(|| {
+                    FitJsonError::Malformed(format!(
+                        "no definition for local message type {local_message_type}"
+                    ))
+                })?;
+            records.push(RawRecord::Data(data_from_json(data, definition)?));
+        } else {
+            return Err(FitJsonError::Malformed(
+                "record is neither a definition nor a data record".to_string(),
+            ));
+        }
+    }
+
+    Ok(RawFile {
+        protocol_version,
+        profile_version,
+        records,
+    }
+    .encode())
+}
+
+fn definition_to_json(definition: &RawDefinition) -> Value {
+    let message_type = MesgNum::from(definition.global_message_number);
+    json!({
+        "local_message_type": definition.local_message_type,
+        "global_message_number": definition.global_message_number,
+        "endianness": match definition.endianness {
+            Endianness::Little => "little",
+            Endianness::Big => "big",
+        },
+        "fields": definition.fields.iter().map(|field| json!({
+            "number": field.number,
+            "size": field.size,
+            "base_type": field.base_type,
+            "is_scaled": message_type.scale_offset(field.number).is_some(),
+        })).collect::<Vec<_>>(),
+        "developer_fields": definition.developer_fields.iter().map(|field| json!({
+            "number": field.number,
+            "size": field.size,
+            "developer_data_index": field.developer_data_index,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+fn definition_from_json(value: &Value) -> Result<RawDefinition, FitJsonError> {
+    let endianness = match value.get("endianness").and_then(Value::as_str) {
+        Some("little") => Endianness::Little,
+        Some("big") => Endianness::Big,
+        _ => {
+            return Err(FitJsonError::Malformed(
+                "endianness must be either \"little\" or \"big\"".to_string(),
+            ));
+        }
+    };
+
+    Ok(RawDefinition {
+        local_message_type: get_u8(value, "local_message_type")?,
+        global_message_number: get_number(value, "global_message_number")? as u16,
+        endianness,
+        fields: get_array(value, "fields")?
+            .iter()
+            .map(|field| {
+                Ok(RawFieldDefinition {
+                    number: get_u8(field, "number")?,
+                    size: get_u8(field, "size")?,
+                    base_type: get_u8(field, "base_type")?,
+                })
+            })
+            .collect::<Result<_, FitJsonError>>()?,
+        developer_fields: get_array(value, "developer_fields")?
+            .iter()
+            .map(|field| {
+                Ok(RawDeveloperFieldDefinition {
+                    number: get_u8(field, "number")?,
+                    size: get_u8(field, "size")?,
+                    developer_data_index: get_u8(field, "developer_data_index")?,
+                })
+            })
+            .collect::<Result<_, FitJsonError>>()?,
+    })
+}
+
+fn data_to_json(data: &RawData, definition: &RawDefinition) -> Value {
+    json!({
+        "local_message_type": data.local_message_type,
+        "time_offset": data.time_offset,
+        "fields": definition.fields.iter().zip(data.fields.iter()).map(|(field, bytes)| json!({
+            "number": field.number,
+            "values": bytes_to_values(bytes, field.base_type, &definition.endianness),
+        })).collect::<Vec<_>>(),
+        "developer_fields": definition.developer_fields.iter().zip(data.developer_fields.iter())
+            .map(|(field, bytes)| json!({
+                "number": field.number,
+                "values": bytes,
+            })).collect::<Vec<_>>(),
+    })
+}
+
+fn data_from_json(value: &Value, definition: &RawDefinition) -> Result<RawData, FitJsonError> {
+    let time_offset = match value.get("time_offset") {
+        None | Some(Value::Null) => None,
+        Some(_) => Some(get_u8(value, "time_offset")?),
+    };
+
+    let fields = get_array(value, "fields")?;
+    if fields.len() != definition.fields.len() {
+        return Err(FitJsonError::Malformed(format!(
+            "expected {} fields for local message type {} but got {}",
+            definition.fields.len(),
+            definition.local_message_type,
+            fields.len()
+        )));
+    }
+    let fields = definition
+        .fields
+        .iter()
+        .zip(fields.iter())
+        .map(|(field, value)| {
+            if get_u8(value, "number")? != field.number {
+                return Err(FitJsonError::Malformed(format!(
+                    "fields must follow their definition order, expected field {}",
+                    field.number
+                )));
+            }
+            values_to_bytes(
+                get_array(value, "values")?,
+                field.size,
+                field.base_type,
+                &definition.endianness,
+            )
+        })
+        .collect::<Result<_, _>>()?;
+
+    let developer_fields = get_array(value, "developer_fields")?;
+    if developer_fields.len() != definition.developer_fields.len() {
+        return Err(FitJsonError::Malformed(format!(
+            "expected {} developer fields for local message type {} but got {}",
+            definition.developer_fields.len(),
+            definition.local_message_type,
+            developer_fields.len()
+        )));
+    }
+    let developer_fields = definition
+        .developer_fields
+        .iter()
+        .zip(developer_fields.iter())
+        .map(|(field, value)| {
+            values_to_bytes(
+                get_array(value, "values")?,
+                field.size,
+                0x0D,
+                &definition.endianness,
+            )
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(RawData {
+        local_message_type: definition.local_message_type,
+        time_offset,
+        fields,
+        developer_fields,
+    })
+}
+
+/// Size in bytes of a single value of a base type, [None] for strings which are a single value
+/// spanning the whole field.
+fn base_type_size(base_type: &FitBaseType) -> Option<usize> {
+    match base_type {
+        FitBaseType::String => None,
+        FitBaseType::Sint16 | FitBaseType::Uint16 | FitBaseType::Uint16z => Some(2),
+        FitBaseType::Sint32 | FitBaseType::Uint32 | FitBaseType::Uint32z | FitBaseType::Float32 => {
+            Some(4)
+        }
+        FitBaseType::Sint64 | FitBaseType::Uint64 | FitBaseType::Uint64z | FitBaseType::Float64 => {
+            Some(8)
+        }
+        _ => Some(1),
+    }
+}
+
+fn bytes_to_values(bytes: &[u8], base_type: u8, endianness: &Endianness) -> Vec<Value> {
+    let base_type = FitBaseType::from(base_type);
+    let Some(size) = base_type_size(&base_type) else {
+        let string = String::from_utf8_lossy(bytes);
+        return vec![Value::from(string.trim_end_matches(char::from(0)))];
+    };
+    if !bytes.len().is_multiple_of(size) {
+        // Field size does not match its base type, keep its bytes
+        return bytes.iter().map(|byte| Value::from(*byte)).collect();
+    }
+
+    bytes
+        .chunks(size)
+        .map(|chunk| {
+            let mut buffer = [0; 8];
+            match endianness {
+                Endianness::Little => buffer[..size].copy_from_slice(chunk),
+                Endianness::Big => buffer[8 - size..].copy_from_slice(chunk),
+            }
+            let raw = match endianness {
+                Endianness::Little => u64::from_le_bytes(buffer),
+                Endianness::Big => u64::from_be_bytes(buffer),
+            };
+            match base_type {
+                FitBaseType::Sint8 => Value::from(raw as u8 as i8),
+                FitBaseType::Sint16 => Value::from(raw as u16 as i16),
+                FitBaseType::Sint32 => Value::from(raw as u32 as i32),
+                FitBaseType::Sint64 => Value::from(raw as i64),
+                FitBaseType::Float32 => {
+                    let value = f32::from_bits(raw as u32);
+                    if value.is_finite() {
+                        Value::from(value)
+                    } else {
+                        Value::Null
+                    }
+                }
+                FitBaseType::Float64 => {
+                    let value = f64::from_bits(raw);
+                    if value.is_finite() {
+                        Value::from(value)
+                    } else {
+                        Value::Null
+                    }
+                }
+                _ => Value::from(raw),
+            }
+        })
+        .collect()
+}
+
+fn values_to_bytes(
+    values: &[Value],
+    size: u8,
+    base_type: u8,
+    endianness: &Endianness,
+) -> Result<Vec<u8>, FitJsonError> {
+    let size = size as usize;
+    let base_type = FitBaseType::from(base_type);
+
+    let Some(value_size) = base_type_size(&base_type) else {
+        let string = values.first().and_then(Value::as_str).unwrap_or_default();
+        if string.len() > size {
+            return Err(FitJsonError::Malformed(format!(
+                "string \"{string}\" does not fit in {size} bytes"
+            )));
+        }
+        let mut bytes = string.as_bytes().to_vec();
+        bytes.resize(size, 0);
+        return Ok(bytes);
+    };
+    let value_size = if size.is_multiple_of(value_size) {
+        value_size
+    } else {
+        1
+    };
+    if values.len() * value_size != size {
+        return Err(FitJsonError::Malformed(format!(
+            "expected {} values to fill {size} bytes but got {}",
+            size / value_size,
+            values.len()
+        )));
+    }
+
+    let mut bytes = Vec::with_capacity(size);
+    for value in values {
+        let raw = match (&base_type, value) {
+            (FitBaseType::Float32, Value::Null) => u32::MAX as u64,
+            (FitBaseType::Float64, Value::Null) => u64::MAX,
+            (FitBaseType::Float32, value) if value_size == 4 => {
+                (value.as_f64().ok_or_else(|| not_a_number(value))? as f32).to_bits() as u64
+            }
+            (FitBaseType::Float64, value) if value_size == 8 => {
+                value.as_f64().ok_or_else(|| not_a_number(value))?.to_bits()
+            }
+            (_, value) => value
+                .as_u64()
+                .or_else(|| value.as_i64().map(|value| value as u64))
+                .ok_or_else(|| not_a_number(value))?,
+        };
+        match endianness {
+            Endianness::Little => bytes.extend_from_slice(&raw.to_le_bytes()[..value_size]),
+            Endianness::Big => bytes.extend_from_slice(&raw.to_be_bytes()[8 - value_size..]),
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn not_a_number(value: &Value) -> FitJsonError {
+    FitJsonError::Malformed(format!("{value} is not a valid number"))
+}
+
+fn get_array<'a>(value: &'a Value, key: &str) -> Result<&'a Vec<Value>, FitJsonError> {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .ok_or_else(|| FitJsonError::Malformed(format!("missing array \"{key}\"")))
+}
+
+fn get_number(value: &Value, key: &str) -> Result<u64, FitJsonError> {
+    value
+        .get(key)
+        .and_then(Value::as_u64)
+        .ok_or_else(|| FitJsonError::Malformed(format!("missing number \"{key}\"")))
+}
+
+fn get_u8(value: &Value, key: &str) -> Result<u8, FitJsonError> {
+    u8::try_from(get_number(value, key)?)
+        .map_err(|_| FitJsonError::Malformed(format!("\"{key}\" must fit in a byte")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DataValue, FitField, RecordField, parse_fit_messages,
+        parser::test_utils::{build_fit_file, data_record, definition_record},
+    };
+
+    use super::*;
+
+    fn fixture() -> Vec<u8> {
+        // file_id: type (0, enum), product_name (8, string)
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00), (8, 8, 0x07)]);
+        let mut payload = vec![4];
+        payload.extend_from_slice(b"device\0\0");
+        records.append(&mut data_record(0, &payload));
+
+        // record: timestamp (253), heart_rate (3), speed (6), position_lat (0)
+        records.append(&mut definition_record(
+            1,
+            20,
+            &[(253, 4, 0x86), (3, 1, 0x02), (6, 2, 0x84), (0, 4, 0x85)],
+        ));
+        let mut payload = 1000_u32.to_le_bytes().to_vec();
+        payload.push(120);
+        payload.extend_from_slice(&2500_u16.to_le_bytes());
+        payload.extend_from_slice(&(-123456_i32).to_le_bytes());
+        records.append(&mut data_record(1, &payload));
+
+        // Record without timestamp, sent with a compressed timestamp header 2 seconds later
+        records.append(&mut definition_record(
+            2,
+            20,
+            &[(3, 1, 0x02), (6, 2, 0x84), (0, 4, 0x85)],
+        ));
+        records.push(0x80 | (2 << 5) | ((1000 + 2) & 0x1F) as u8);
+        records.extend_from_slice(&payload[4..]);
+
+        build_fit_file(&records)
+    }
+
+    #[test]
+    fn test_messages_to_ndjson() {
+        let messages = parse_fit_messages(fixture().into_iter(), true).unwrap();
+
+        let output = messages_to_ndjson(&messages).collect::<String>();
+
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), messages.len());
+        for (line, message) in lines.iter().zip(messages.iter()) {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value, message.to_json());
+        }
+        assert_eq!(
+            serde_json::from_str::<Value>(lines[1]).unwrap()["fields"]["heart_rate"],
+            json!(120)
+        );
+    }
+
+    #[test]
+    fn test_fit_to_json_to_fit_round_trip() {
+        let content = fixture();
+
+        let value = fit_to_json(content.clone()).unwrap();
+        let encoded = json_to_fit(&value).unwrap();
+
+        assert_eq!(encoded, content);
+        assert_eq!(
+            parse_fit_messages(encoded.into_iter(), true).unwrap(),
+            parse_fit_messages(content.into_iter(), true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fit_to_json_representation() {
+        let value = fit_to_json(fixture()).unwrap();
+
+        let records = value["records"].as_array().unwrap();
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[1]["data"]["fields"][1]["values"], json!(["device"]));
+        assert_eq!(
+            records[3]["data"]["fields"],
+            json!([
+                {"number": 253, "values": [1000]},
+                {"number": 3, "values": [120]},
+                {"number": 6, "values": [2500]},
+                {"number": 0, "values": [-123456]},
+            ])
+        );
+        assert_eq!(records[3]["data"]["time_offset"], Value::Null);
+        assert_eq!(records[5]["data"]["time_offset"], json!(10));
+    }
+
+    #[test]
+    fn test_fit_to_json_definition_fields_is_scaled() {
+        let value = fit_to_json(fixture()).unwrap();
+
+        let fields = value["records"][2]["definition"]["fields"]
+            .as_array()
+            .unwrap();
+        let is_scaled = |number: u64| {
+            fields
+                .iter()
+                .find(|field| field["number"] == json!(number))
+                .unwrap()["is_scaled"]
+                .clone()
+        };
+        assert_eq!(is_scaled(6), json!(true)); // speed
+        assert_eq!(is_scaled(3), json!(false)); // heart_rate
+
+        let messages = parse_fit_messages(fixture().into_iter(), true).unwrap();
+        let is_scaled = |kind: FitField| {
+            messages[1]
+                .fields
+                .iter()
+                .find(|field| field.kind == kind)
+                .unwrap()
+                .is_scaled
+        };
+        assert!(is_scaled(FitField::Record(RecordField::Speed)));
+        assert!(!is_scaled(FitField::Record(RecordField::HeartRate)));
+    }
+
+    #[test]
+    fn test_edit_json_then_convert_back() {
+        let mut value = fit_to_json(fixture()).unwrap();
+        value["records"][3]["data"]["fields"][1]["values"] = json!([150]);
+
+        let messages = parse_fit_messages(json_to_fit(&value).unwrap().into_iter(), true).unwrap();
+
+        let heart_rate = messages[1]
+            .fields
+            .iter()
+            .find(|field| field.kind == FitField::Record(RecordField::HeartRate))
+            .unwrap();
+        assert_eq!(heart_rate.values, vec![DataValue::Uint8(150)]);
+    }
+
+    #[test]
+    fn test_json_to_fit_rejects_too_long_string() {
+        let mut value = fit_to_json(fixture()).unwrap();
+        value["records"][1]["data"]["fields"][1]["values"] = json!(["a much longer name"]);
+
+        assert!(matches!(
+            json_to_fit(&value),
+            Err(FitJsonError::Malformed(_))
+        ));
+    }
+}
@@ -1,8 +1,25 @@
+pub mod course;
+pub mod downsample;
+#[cfg(feature = "serde")]
+pub mod json;
 mod parser;
+pub mod polyline;
+pub mod record;
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod workout;
 
-pub use crate::parser::types::DataValue;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::parser::parse_fit_file;
 pub use crate::parser::types::generated::*;
+pub use crate::parser::types::{DataValue, UnknownVariantError};
 pub use crate::parser::{
-    DataMessage, DataMessageField, FitParserError, parse_fit_file, parse_fit_messages,
+    CompressedMessageHeader, CompressedTimestamp, DEFAULT_MAX_DEFINITION_FIELDS,
+    DEFAULT_MAX_FIELD_VALUES, DataMessage, DataMessageField, DataMessageHeader,
+    DefinitionMessageHeader, FitDecoder, FitEncoder, FitParserError, ParseOptions,
+    ParseOptionsBuilder, RawData, RawDefinition, RawDeveloperFieldDefinition, RawFieldDefinition,
+    RawFile, RawRecord, RecordHeader, SUMMARY_MESSAGES, TIME_OFFSET_MASK, TIME_OFFSET_ROLLOVER,
+    index_fit, parse_fit_messages, parse_fit_messages_with_options, parse_fit_reader,
+    parse_fit_reader_with_options, parse_summary,
 };
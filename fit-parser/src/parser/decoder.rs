@@ -0,0 +1,883 @@
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read},
+};
+
+use crate::DataValue;
+use crate::parser::{
+    DEFAULT_MAX_DEFINITION_FIELDS, DEFAULT_MAX_FIELD_VALUES, DataMessage, Definition,
+    FitParserError, ParseOptions, Record,
+    definition::custom::{CustomDescription, parse_custom_definition_description},
+    header::{FileHeader, FileHeaderError, HEADER_SIZE_WITH_CRC},
+    reader::{Reader, compute_crc},
+    records::CompressedTimestamp,
+};
+
+/// Decoder for a single .FIT file, or for several .FIT files chained one after the other.
+///
+/// A [FitDecoder] owns all the state needed while decoding a file: the definitions by local
+/// message type, the developer fields descriptions and the last timestamp used to rebuild
+/// compressed timestamps. Nothing is shared between instances, so decoding several files in
+/// parallel (e.g. one decoder per file on different threads) is safe and gives the same results as
+/// decoding them sequentially. A decoder must not be reused across files, the state being only
+/// reset between the files chained in its content.
+///
+/// Bytes can be provided in several chunks with [FitDecoder::feed], the actual decoding happens
+/// when calling [FitDecoder::finish].
+#[derive(Debug, Default)]
+pub struct FitDecoder {
+    options: ParseOptions,
+    content: Vec<u8>,
+    definitions: HashMap<u8, Definition>,
+    custom_descriptions: HashMap<u8, HashMap<u8, CustomDescription>>,
+    compressed_timestamp: CompressedTimestamp,
+    messages: Vec<DataMessage>,
+}
+
+impl FitDecoder {
+    pub fn new(enforce_crc: bool) -> Self {
+        Self::with_options(
+            ParseOptions::builder()
+                .validate_body_crc(enforce_crc)
+                .build(),
+        )
+    }
+
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    /// Append a chunk of the file's bytes to the decoder.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.content.extend_from_slice(bytes);
+    }
+
+    /// Decode all the bytes fed so far and return the file's [DataMessage]s.
+    pub fn finish(self) -> Result<Vec<DataMessage>, FitParserError> {
+        self.finish_with_progress(&mut |_, _| {})
+    }
+
+    /// Same as [FitDecoder::finish], but `progress` is called after each decoded record with the
+    /// number of bytes consumed so far and the total number of bytes fed to the decoder. It is
+    /// called a last time with both values equal once the file has been fully decoded.
+    pub fn finish_with_progress(
+        mut self,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Vec<DataMessage>, FitParserError> {
+        let content = std::mem::take(&mut self.content);
+        let total = content.len();
+        let header_reader = Reader::from_read(HEADER_SIZE_WITH_CRC as u32, Cursor::new(content));
+        self.decode(header_reader, total, progress)
+    }
+
+    /// Same as [FitDecoder::finish], the messages being grouped by file when several .FIT files
+    /// are chained in the bytes fed to the decoder.
+    pub fn finish_files(mut self) -> Result<Vec<Vec<DataMessage>>, FitParserError> {
+        let content = std::mem::take(&mut self.content);
+        let total = content.len();
+        let header_reader = Reader::from_read(HEADER_SIZE_WITH_CRC as u32, Cursor::new(content));
+        self.decode_files(header_reader, total, &mut |_, _| {})
+    }
+
+    /// Decode a whole file pulled lazily from `read`, see [crate::parse_fit_reader].
+    pub(crate) fn decode_read(self, read: impl Read) -> Result<Vec<DataMessage>, FitParserError> {
+        let header_reader = Reader::from_read(HEADER_SIZE_WITH_CRC as u32, read);
+        // The total size of a stream is not known, progress is not reported
+        self.decode(header_reader, 0, &mut |_, _| {})
+    }
+
+    /// Decode the file whose content is read by `header_reader`, starting with its header, and the
+    /// files chained after it, see [FitDecoder::decode_files].
+    fn decode(
+        self,
+        header_reader: Reader,
+        total: usize,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Vec<DataMessage>, FitParserError> {
+        Ok(self
+            .decode_files(header_reader, total, progress)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    /// Decode the files whose content is read by `header_reader` and return the messages of each of
+    /// them. Some tools concatenate several .FIT files into a single one, each file starting with
+    /// its own header after the CRC of the previous one. Every file has its own definitions, and
+    /// trailing bytes that do not start with a .FIT header (e.g. padding) are ignored.
+    fn decode_files(
+        mut self,
+        mut header_reader: Reader,
+        total: usize,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Vec<Vec<DataMessage>>, FitParserError> {
+        let mut files = Vec::new();
+        let mut offset = 0;
+        loop {
+            let header = match FileHeader::parse(&mut header_reader, !self.options.skip_header_crc)
+            {
+                Ok(header) => header,
+                Err(err) => {
+                    if let Some(io_err) = header_reader.take_io_error() {
+                        return Err(FitParserError::Io(io_err));
+                    }
+                    if !files.is_empty() && !matches!(err, FileHeaderError::InvalidCRC(_, _)) {
+                        break;
+                    }
+                    return Err(err.into());
+                }
+            };
+            let header_size =
+                (HEADER_SIZE_WITH_CRC as u32 - header_reader.bytes_left_to_read()) as usize;
+
+            let next_reader = self.decode_file(
+                header_reader,
+                &header,
+                offset + header_size,
+                total,
+                progress,
+            )?;
+            files.push(std::mem::take(&mut self.messages));
+            self.definitions.clear();
+            self.custom_descriptions.clear();
+            self.compressed_timestamp = CompressedTimestamp::default();
+
+            let Some(mut reader) = next_reader else {
+                break;
+            };
+            if !reader.has_remaining_content() {
+                break;
+            }
+            offset += header_size + header.data_size as usize + 2;
+            header_reader = reader.next_file();
+        }
+        progress(total, total);
+
+        Ok(files)
+    }
+
+    /// Decode the body of a file, whose header has been read by `header_reader`, starting at
+    /// `body_start` in the content. Returns the reader following the trailing CRC, unless the body
+    /// did not end where the header reported it.
+    fn decode_file<'a>(
+        &mut self,
+        header_reader: Reader<'a>,
+        header: &FileHeader,
+        body_start: usize,
+        total: usize,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Option<Reader<'a>>, FitParserError> {
+        let reader = if self.options.lenient_data_size {
+            // Looking for the actual end of the body needs all of it
+            let body = header_reader.remaining_content();
+            if !crc_matches_at(body.as_slice(), header.data_size) {
+                self.decode_lenient(body, body_start, header.data_size, total, progress)?;
+                return Ok(None);
+            }
+            Reader::new(header.data_size, body)
+        } else {
+            // The body is streamed, its end being checked as it is read
+            header_reader.body(header.data_size)
+        };
+        let mut reader = reader
+            .with_max_field_values(
+                self.options
+                    .max_field_values
+                    .unwrap_or(DEFAULT_MAX_FIELD_VALUES),
+            )
+            .with_max_definition_fields(
+                self.options
+                    .max_definition_fields
+                    .unwrap_or(DEFAULT_MAX_DEFINITION_FIELDS),
+            );
+
+        loop {
+            match self.decode_record(&mut reader) {
+                Ok(()) => progress(
+                    body_start + (header.data_size - reader.bytes_left_to_read()) as usize,
+                    total,
+                ),
+                // The body ends cleanly after its last record, a truncated record is an error
+                Err(FitParserError::ParserError(err)) if err.is_eof() => break,
+                Err(err) => return Err(content_error(&mut reader, header.data_size).unwrap_or(err)),
+            }
+        }
+
+        let body_crc = reader.current_crc();
+        let expected_crc = reader
+            .read_trailing_crc()
+            .map_err(|err| content_error(&mut reader, header.data_size).unwrap_or(err.into()))?;
+
+        if self.options.enforce_crc && body_crc != expected_crc {
+            return Err(FitParserError::InvalidBodyCRC(expected_crc, body_crc));
+        }
+
+        Ok(Some(reader))
+    }
+
+    /// Decode a body whose reported `data_size` does not end on a valid CRC. Records are decoded
+    /// up to the end of the content, and the body is cut after the record whose CRC matches the
+    /// following 2 bytes and that is the closest to `data_size`. If there is none, the body ends
+    /// after the last complete record, or an error is returned when the CRC is enforced:
+    /// [FitParserError::InvalidBodyCRC] if `data_size` ends on a record, the content being corrupted,
+    /// [FitParserError::DataSizeMismatch] otherwise. Nothing after such a body is decoded.
+    fn decode_lenient(
+        &mut self,
+        body: std::vec::IntoIter<u8>,
+        body_start: usize,
+        data_size: u32,
+        total: usize,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<(), FitParserError> {
+        let bytes = body.as_slice().to_vec();
+        let available = (bytes.len() as u32).saturating_sub(2);
+
+        // (offset, number of messages, CRC) after each complete record
+        let mut ends = vec![(0, 0, 0)];
+        let mut reader = Reader::new(available, body)
+            .with_max_field_values(
+                self.options
+                    .max_field_values
+                    .unwrap_or(DEFAULT_MAX_FIELD_VALUES),
+            )
+            .with_max_definition_fields(
+                self.options
+                    .max_definition_fields
+                    .unwrap_or(DEFAULT_MAX_DEFINITION_FIELDS),
+            );
+        while !reader.is_empty() {
+            if self.decode_record(&mut reader).is_err() {
+                break;
+            }
+            let offset = available - reader.bytes_left_to_read();
+            ends.push((offset, self.messages.len(), reader.current_crc()));
+            progress(body_start + offset as usize, total);
+        }
+
+        let valid_end = ends
+            .iter()
+            .filter(|(offset, _, crc)| read_crc(&bytes, *offset) == Some(*crc))
+            .min_by_key(|(offset, _, _)| offset.abs_diff(data_size));
+        let (_, messages, _) = match valid_end {
+            Some(end) => *end,
+            None => {
+                let last_end = *ends.last().expect("Contains at least 1 value");
+                if self.options.enforce_crc {
+                    // The reported size ends on a record, so the content itself is corrupted
+                    if let Some((_, _, crc)) =
+                        ends.iter().find(|(offset, _, _)| *offset == data_size)
+                        && let Some(expected_crc) = read_crc(&bytes, data_size)
+                    {
+                        return Err(FitParserError::InvalidBodyCRC(expected_crc, *crc));
+                    }
+                    return Err(FitParserError::DataSizeMismatch(data_size, last_end.0));
+                }
+                last_end
+            }
+        };
+        self.messages.truncate(messages);
+
+        Ok(())
+    }
+
+    fn decode_record(&mut self, reader: &mut Reader) -> Result<(), FitParserError> {
+        let record = Record::parse(
+            reader,
+            &self.definitions,
+            &self.custom_descriptions,
+            &mut self.compressed_timestamp,
+        )?;
+
+        match record {
+            Record::Definition(definition) => {
+                self.definitions
+                    .insert(definition.local_message_type, definition);
+            }
+            Record::Data(mut data) => {
+                parse_custom_definition_description(
+                    &data,
+                    &self.definitions,
+                    &mut self.custom_descriptions,
+                    self.options.normalize_custom_names,
+                );
+                self.compressed_timestamp.update(&data);
+                if self.options.integral_scaled_values {
+                    for field in data.fields.iter_mut().filter(|field| field.is_scaled) {
+                        field.values = field.values.iter().map(DataValue::to_integral).collect();
+                    }
+                }
+                if !self.options.keep_invalid {
+                    data.fields
+                        .retain(|field| !field.values.iter().all(|value| value.is_invalid()));
+                }
+                if let Some(kinds) = &self.options.messages
+                    && !kinds.contains(&data.message_kind)
+                {
+                    return Ok(());
+                }
+                self.messages.push(data);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error to report instead of the one of the record being decoded when the body could not be read
+/// up to `data_size`, followed by its CRC, e.g. because `data_size` is larger than the file.
+fn content_error(reader: &mut Reader, data_size: u32) -> Option<FitParserError> {
+    if let Some(err) = reader.take_io_error() {
+        return Some(FitParserError::Io(err));
+    }
+    reader
+        .is_exhausted()
+        .then(|| FitParserError::DataSizeMismatch(data_size, reader.bytes_read()))
+}
+
+/// CRC stored in the 2 bytes of `body` following `offset`, if any.
+fn read_crc(body: &[u8], offset: u32) -> Option<u16> {
+    let offset = offset as usize;
+    body.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Whether the CRC of the first `data_size` bytes of `body` matches the 2 bytes that follow them.
+fn crc_matches_at(body: &[u8], data_size: u32) -> bool {
+    let Some(data) = body.get(..data_size as usize) else {
+        return false;
+    };
+    let crc = data.iter().fold(0, |crc, byte| compute_crc(&crc, *byte));
+    read_crc(body, data_size) == Some(crc)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, thread};
+
+    use crate::{
+        FitField, MesgNum, RecordField,
+        parser::{
+            header::FileHeaderError,
+            reader::ReaderError,
+            records::RecordError,
+            test_utils::{build_fit_file, data_record, definition_record, set_data_size},
+            types::DataTypeError,
+        },
+    };
+
+    use super::*;
+
+    fn record_file(heart_rates: &[u8]) -> Vec<u8> {
+        // Record message with timestamp (253, uint32) and heart_rate (3, uint8)
+        let mut records = definition_record(0, 20, &[(253, 4, 0x86), (3, 1, 0x02)]);
+        for (index, heart_rate) in heart_rates.iter().enumerate() {
+            let mut payload = (1000 + index as u32).to_le_bytes().to_vec();
+            payload.push(*heart_rate);
+            records.append(&mut data_record(0, &payload));
+        }
+        build_fit_file(&records)
+    }
+
+    #[test]
+    fn test_decode_file_in_multiple_chunks() {
+        let content = record_file(&[120, 121, 122]);
+
+        let mut decoder = FitDecoder::new(true);
+        for chunk in content.chunks(5) {
+            decoder.feed(chunk);
+        }
+        let messages = decoder.finish().unwrap();
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].message_kind, MesgNum::Record);
+        assert_eq!(
+            messages[2].fields[1].kind,
+            FitField::Record(RecordField::HeartRate)
+        );
+        assert_eq!(messages[2].fields[1].values, vec![DataValue::Uint8(122)]);
+    }
+
+    #[test]
+    fn test_decode_files_in_parallel_same_as_sequential() {
+        let files = [record_file(&[100, 110, 120]), record_file(&[150, 160])];
+
+        let sequential: Vec<_> = files
+            .iter()
+            .map(|content| {
+                let mut decoder = FitDecoder::new(true);
+                decoder.feed(content);
+                decoder.finish().unwrap()
+            })
+            .collect();
+
+        let parallel: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = files
+                .iter()
+                .map(|content| {
+                    scope.spawn(move || {
+                        let mut decoder = FitDecoder::new(true);
+                        decoder.feed(content);
+                        decoder.finish().unwrap()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(parallel[0].len(), 3);
+        assert_eq!(parallel[1].len(), 2);
+    }
+
+    #[test]
+    fn test_running_crc_at_body_end_equals_stored_crc() {
+        let content = record_file(&[120, 121, 122]);
+        let stored_crc =
+            u16::from_le_bytes([content[content.len() - 2], content[content.len() - 1]]);
+
+        let mut header_reader = Reader::new(HEADER_SIZE_WITH_CRC as u32, content.into_iter());
+        let header = FileHeader::from_bytes(&mut header_reader).unwrap();
+        let mut reader = Reader::new(header.data_size, header_reader.remaining_content());
+        let mut decoder = FitDecoder::new(true);
+        while !reader.is_empty() {
+            decoder.decode_record(&mut reader).unwrap();
+        }
+
+        assert_eq!(reader.current_crc(), stored_crc);
+        assert_eq!(reader.read_trailing_crc().unwrap(), stored_crc);
+        assert!(reader.remaining_content().as_slice().is_empty());
+    }
+
+    #[test]
+    fn test_decode_reports_increasing_progress_up_to_total() {
+        let content = record_file(&[120, 121, 122]);
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let mut reports = Vec::new();
+        decoder
+            .finish_with_progress(&mut |consumed, total| reports.push((consumed, total)))
+            .unwrap();
+
+        assert!(reports.len() > 1);
+        assert!(reports.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(reports.iter().all(|(_, total)| *total == content.len()));
+        assert_eq!(reports.last(), Some(&(content.len(), content.len())));
+    }
+
+    #[test]
+    fn test_decode_chained_files() {
+        let mut content = record_file(&[120, 121, 122]);
+        content.append(&mut record_file(&[150, 151]));
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let messages = decoder.finish().unwrap();
+
+        assert_eq!(messages.len(), 5);
+        assert_eq!(messages[3].fields[1].values, vec![DataValue::Uint8(150)]);
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let files = decoder.finish_files().unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].len(), 3);
+        assert_eq!(files[1].len(), 2);
+    }
+
+    #[test]
+    fn test_decode_chained_files_reports_progress_up_to_total() {
+        let mut content = record_file(&[120, 121, 122]);
+        content.append(&mut record_file(&[150, 151]));
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let mut reports = Vec::new();
+        decoder
+            .finish_with_progress(&mut |consumed, total| reports.push((consumed, total)))
+            .unwrap();
+
+        assert!(reports.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(
+            reports
+                .iter()
+                .all(|(consumed, _)| *consumed <= content.len())
+        );
+        assert_eq!(reports.last(), Some(&(content.len(), content.len())));
+    }
+
+    #[test]
+    fn test_decode_chained_file_does_not_reuse_definitions() {
+        // The second file uses the local message type defined by the first one
+        let mut content = record_file(&[120]);
+        let mut payload = 1000_u32.to_le_bytes().to_vec();
+        payload.push(150);
+        content.append(&mut build_fit_file(&data_record(0, &payload)));
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::ParserError(
+                RecordError::NoDefinitionMessageFound(0)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_decode_ignores_trailing_padding() {
+        for padding in [vec![0; 8], vec![HEADER_SIZE_WITH_CRC, 0x20, 0x54]] {
+            let mut content = record_file(&[120, 121, 122]);
+            content.extend_from_slice(&padding);
+
+            let mut decoder = FitDecoder::new(true);
+            decoder.feed(&content);
+
+            assert_eq!(decoder.finish().unwrap().len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_decode_chained_file_with_invalid_header_crc() {
+        let mut content = record_file(&[120]);
+        let mut chained = record_file(&[150]);
+        chained[12] ^= 0xFF;
+        content.append(&mut chained);
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::Header(FileHeaderError::InvalidCRC(_, _)))
+        ));
+    }
+
+    #[test]
+    fn test_decode_all_invalid_record_with_and_without_keep_invalid() {
+        // Record message with heart_rate (3, uint8), power (7, uint16) and speed (6, uint16)
+        let mut records = definition_record(0, 20, &[(3, 1, 0x02), (7, 2, 0x84), (6, 2, 0x84)]);
+        records.append(&mut data_record(0, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF]));
+        let content = build_fit_file(&records);
+
+        // Fields are returned verbatim by default, sentinels included
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let default_messages = decoder.finish().unwrap();
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            enforce_crc: true,
+            keep_invalid: true,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        let messages = decoder.finish().unwrap();
+        assert_eq!(messages, default_messages);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].fields.len(), 3);
+        assert_eq!(
+            messages[0].fields[0].kind,
+            FitField::Record(RecordField::HeartRate)
+        );
+        assert_eq!(messages[0].fields[0].values, vec![DataValue::Uint8(0xFF)]);
+        assert_eq!(
+            messages[0].fields[1].kind,
+            FitField::Record(RecordField::Power)
+        );
+        assert_eq!(
+            messages[0].fields[1].values,
+            vec![DataValue::Uint16(0xFFFF)]
+        );
+        assert!(
+            messages[0]
+                .fields
+                .iter()
+                .all(|field| field.values.iter().all(DataValue::is_invalid))
+        );
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            enforce_crc: true,
+            keep_invalid: false,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        let messages = decoder.finish().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].fields.is_empty());
+    }
+
+    #[test]
+    fn test_decode_integral_scaled_values() {
+        // Record message with distance (5, uint32, scale 100)
+        let mut records = definition_record(0, 20, &[(5, 4, 0x86)]);
+        records.append(&mut data_record(0, &30000_u32.to_le_bytes()));
+        records.append(&mut data_record(0, &30050_u32.to_le_bytes()));
+        let content = build_fit_file(&records);
+        let distances = |messages: Vec<DataMessage>| {
+            messages
+                .into_iter()
+                .map(|message| message.fields[0].values[0].clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        assert_eq!(
+            distances(decoder.finish().unwrap()),
+            vec![DataValue::Float32(300.), DataValue::Float32(300.5)]
+        );
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            integral_scaled_values: true,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        assert_eq!(
+            distances(decoder.finish().unwrap()),
+            vec![DataValue::Uint16(300), DataValue::Float32(300.5)]
+        );
+    }
+
+    #[test]
+    fn test_decode_field_with_too_many_values() {
+        // Record message with an 8 values heart_rate (3, uint8) field
+        let mut records = definition_record(0, 20, &[(3, 8, 0x02)]);
+        records.append(&mut data_record(0, &[120; 8]));
+        let content = build_fit_file(&records);
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            max_field_values: Some(4),
+            ..Default::default()
+        });
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::ParserError(RecordError::DataTypeError(
+                DataTypeError::TooManyValues(8, 4)
+            )))
+        ));
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        assert_eq!(decoder.finish().unwrap()[0].fields[0].values.len(), 8);
+    }
+
+    #[test]
+    fn test_decode_definition_with_too_many_fields() {
+        // Record message with heart_rate (3, uint8) and cadence (4, uint8) fields
+        let mut records = definition_record(0, 20, &[(3, 1, 0x02), (4, 1, 0x02)]);
+        records.append(&mut data_record(0, &[120, 80]));
+        let content = build_fit_file(&records);
+
+        let mut decoder =
+            FitDecoder::with_options(ParseOptions::builder().max_definition_fields(1).build());
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::ParserError(RecordError::TooManyFields(
+                2, 1
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_decode_only_selected_messages() {
+        // Event message with event (0, enum) and record message with heart_rate (3, uint8)
+        let mut records = definition_record(0, 21, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[0]));
+        records.append(&mut definition_record(1, 20, &[(3, 1, 0x02)]));
+        records.append(&mut data_record(1, &[120]));
+        let content = build_fit_file(&records);
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            messages: Some(HashSet::from([MesgNum::Record])),
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        let messages = decoder.finish().unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message_kind, MesgNum::Record);
+    }
+
+    #[test]
+    fn test_decode_invalid_body_crc() {
+        let mut content = record_file(&[120]);
+        let last = content.len() - 1;
+        content[last] ^= 0xFF;
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::InvalidBodyCRC(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_decode_too_large_data_size() {
+        let mut content = record_file(&[120, 121, 122]);
+        let data_size = (content.len() - 16) as u32;
+        set_data_size(&mut content, data_size + 10);
+
+        let mut decoder = FitDecoder::new(true);
+        decoder.feed(&content);
+        let Err(FitParserError::DataSizeMismatch(reported, actual)) = decoder.finish() else {
+            unreachable!("Should have reported the data size mismatch");
+        };
+        assert_eq!(reported, data_size + 10);
+        assert_eq!(actual, data_size);
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            enforce_crc: true,
+            lenient_data_size: true,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        let messages = decoder
+            .finish()
+            .expect("Should have recovered the body end");
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[test]
+    fn test_decode_too_small_data_size_lenient() {
+        let mut content = record_file(&[120, 121, 122]);
+        let data_size = (content.len() - 16) as u32;
+        // Ends in the middle of the last record
+        set_data_size(&mut content, data_size - 2);
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            enforce_crc: true,
+            lenient_data_size: true,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+        let messages = decoder
+            .finish()
+            .expect("Should have recovered the body end");
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[test]
+    fn test_decode_with_each_crc_and_lenient_option() {
+        let valid = record_file(&[120, 121, 122]);
+        let mut invalid_header_crc = valid.clone();
+        invalid_header_crc[12] ^= 0xFF;
+        let mut invalid_body_crc = valid.clone();
+        let last = invalid_body_crc.len() - 1;
+        invalid_body_crc[last] ^= 0xFF;
+        let mut too_large_data_size = valid.clone();
+        set_data_size(&mut too_large_data_size, (valid.len() - 16) as u32 + 10);
+
+        for validate_body_crc in [false, true] {
+            for validate_header_crc in [false, true] {
+                for lenient in [false, true] {
+                    let decode = |content: &[u8]| {
+                        let mut decoder = FitDecoder::with_options(
+                            ParseOptions::builder()
+                                .validate_body_crc(validate_body_crc)
+                                .validate_header_crc(validate_header_crc)
+                                .lenient(lenient)
+                                .build(),
+                        );
+                        decoder.feed(content);
+                        decoder.finish()
+                    };
+                    let options = format!("{validate_body_crc} {validate_header_crc} {lenient}");
+
+                    assert_eq!(decode(&valid).unwrap().len(), 3, "{options}");
+
+                    match decode(&invalid_header_crc) {
+                        Err(FitParserError::Header(FileHeaderError::InvalidCRC(_, _))) => {
+                            assert!(validate_header_crc, "{options}")
+                        }
+                        Ok(messages) => {
+                            assert!(!validate_header_crc, "{options}");
+                            assert_eq!(messages.len(), 3, "{options}");
+                        }
+                        Err(err) => panic!("Unexpected error {err:?} for {options}"),
+                    }
+
+                    match decode(&invalid_body_crc) {
+                        Err(FitParserError::InvalidBodyCRC(_, _)) => {
+                            assert!(validate_body_crc, "{options}")
+                        }
+                        Ok(messages) => {
+                            assert!(!validate_body_crc, "{options}");
+                            assert_eq!(messages.len(), 3, "{options}");
+                        }
+                        Err(err) => panic!("Unexpected error {err:?} for {options}"),
+                    }
+
+                    match decode(&too_large_data_size) {
+                        Err(FitParserError::DataSizeMismatch(_, _)) => {
+                            assert!(!lenient, "{options}")
+                        }
+                        Ok(messages) => {
+                            assert!(lenient, "{options}");
+                            assert_eq!(messages.len(), 3, "{options}");
+                        }
+                        Err(err) => panic!("Unexpected error {err:?} for {options}"),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_options_builder_defaults() {
+        let options = ParseOptions::builder().build();
+
+        assert!(!options.enforce_crc);
+        assert!(!options.skip_header_crc);
+        assert!(!options.lenient_data_size);
+        assert!(options.messages.is_none());
+    }
+
+    #[test]
+    fn test_decode_corrupted_body_lenient() {
+        let mut content = record_file(&[120, 121, 122]);
+        // Heart rate of the last record, the data size being right
+        let last = content.len() - 3;
+        content[last] = 130;
+
+        let mut decoder = FitDecoder::with_options(ParseOptions {
+            enforce_crc: true,
+            lenient_data_size: true,
+            ..Default::default()
+        });
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::InvalidBodyCRC(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_decode_body_ending_in_the_middle_of_a_record() {
+        let mut content = record_file(&[120, 121, 122]);
+        let data_size = (content.len() - 16) as u32;
+        // Cuts the timestamp of the last record
+        set_data_size(&mut content, data_size - 3);
+
+        let mut decoder = FitDecoder::new(false);
+        decoder.feed(&content);
+
+        assert!(matches!(
+            decoder.finish(),
+            Err(FitParserError::ParserError(RecordError::ReaderError(
+                ReaderError::UnexpectedEof
+            )))
+        ));
+    }
+}
@@ -12,14 +12,18 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct CustomDescription {
     pub base_type: FitBaseType,
+    /// Name of the field, normalized with [normalize_custom_name] if requested.
     pub name: Option<String>,
     pub units: Option<String>,
+    /// Name of the field as written in the description message.
+    pub original_name: Option<String>,
 }
 
 pub fn parse_custom_definition_description(
     message: &DataMessage,
     definitions: &HashMap<u8, Definition>,
     custom_descriptions: &mut HashMap<u8, HashMap<u8, CustomDescription>>,
+    normalize_names: bool,
 ) {
     let Some(definition) = definitions.get(&message.local_message_type) else {
         // No matching definition, should not be possible if message has been parsed (?)
@@ -53,14 +57,19 @@ pub fn parse_custom_definition_description(
         return;
     };
 
-    let name =
+    let original_name =
         find_value_of_field_as_string(message, definition, &FieldDescriptionField::FieldName);
+    let name = match &original_name {
+        Some(name) if normalize_names => Some(normalize_custom_name(name)),
+        _ => original_name.clone(),
+    };
     let units = find_value_of_field_as_string(message, definition, &FieldDescriptionField::Units);
 
     let description = CustomDescription {
         base_type,
         name,
         units,
+        original_name,
     };
 
     custom_descriptions
@@ -69,6 +78,33 @@ pub fn parse_custom_definition_description(
         .insert(field_number, description);
 }
 
+/// Normalize a developer field name to snake case: trimmed, lowercased, with words separated by a
+/// single `_`. Words are delimited by non-alphanumeric characters and by a lowercase letter or digit
+/// followed by an uppercase letter, e.g. `" Running Power"` and `"RunningPower"` both give
+/// `"running_power"`.
+fn normalize_custom_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut separator = false;
+    let mut previous: Option<char> = None;
+    for c in name.trim().chars() {
+        if !c.is_alphanumeric() {
+            separator = true;
+            previous = None;
+            continue;
+        }
+        if c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric()) {
+            separator = true;
+        }
+        if separator && !normalized.is_empty() {
+            normalized.push('_');
+        }
+        separator = false;
+        normalized.extend(c.to_lowercase());
+        previous = Some(c);
+    }
+    normalized
+}
+
 fn find_value_of_field(
     message: &DataMessage,
     definition: &Definition,
@@ -145,9 +181,7 @@ mod test {
 
     use super::*;
 
-    #[test]
-    fn test_parse_custom_definition() {
-        let mut descriptions = HashMap::new();
+    fn field_description_definitions() -> HashMap<u8, Definition> {
         let mut definitions = HashMap::new();
         definitions.insert(
             0,
@@ -195,36 +229,51 @@ mod test {
                 ],
             },
         );
+        definitions
+    }
 
-        let message = DataMessage {
+    fn field_description_message(name: &str) -> DataMessage {
+        DataMessage {
             local_message_type: 0,
             message_kind: MesgNum::FieldDescription,
             fields: vec![
                 DataMessageField {
                     kind: FitField::FieldDescription(FieldDescriptionField::DeveloperDataIndex),
                     values: vec![DataValue::Uint8(0)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::FieldDescription(FieldDescriptionField::FieldDefinitionNumber),
                     values: vec![DataValue::Uint8(0)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::FieldDescription(FieldDescriptionField::FitBaseTypeId),
                     values: vec![DataValue::Enum(FitEnum::FitBaseType(FitBaseType::Sint8))],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::FieldDescription(FieldDescriptionField::FieldName),
-                    values: vec![DataValue::String("new field".to_string())],
+                    values: vec![DataValue::String(name.to_string())],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::FieldDescription(FieldDescriptionField::Units),
                     values: vec![DataValue::String("km/h".to_string())],
+                    is_scaled: false,
                 },
             ],
-        };
+        }
+    }
+
+    #[test]
+    fn test_parse_custom_definition() {
+        let mut descriptions = HashMap::new();
+        let definitions = field_description_definitions();
+        let message = field_description_message("new field");
 
         assert!(descriptions.is_empty());
-        parse_custom_definition_description(&message, &definitions, &mut descriptions);
+        parse_custom_definition_description(&message, &definitions, &mut descriptions, false);
 
         assert!(descriptions.contains_key(&0));
 
@@ -233,7 +282,43 @@ mod test {
 
         let description = fields.get(&0).unwrap();
         assert_eq!(description.name, Some("new field".to_string()));
+        assert_eq!(description.original_name, Some("new field".to_string()));
         assert_eq!(description.units, Some("km/h".to_string()));
         assert_eq!(description.base_type, FitBaseType::Sint8);
     }
+
+    #[test]
+    fn test_parse_custom_definition_with_normalized_name() {
+        let mut descriptions = HashMap::new();
+        let definitions = field_description_definitions();
+        let message = field_description_message("  Running Power ");
+
+        parse_custom_definition_description(&message, &definitions, &mut descriptions, true);
+
+        let description = descriptions.get(&0).unwrap().get(&0).unwrap();
+        assert_eq!(description.name, Some("running_power".to_string()));
+        assert_eq!(
+            description.original_name,
+            Some("  Running Power ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_custom_name() {
+        for (name, expected) in [
+            ("Power", "power"),
+            ("power", "power"),
+            ("Running Power", "running_power"),
+            ("RunningPower", "running_power"),
+            ("running-power", "running_power"),
+            ("  Running  Power (W) ", "running_power_w"),
+            ("Stryd_Form Power", "stryd_form_power"),
+            ("HRV2Score", "hrv2_score"),
+            ("SpO2", "sp_o2"),
+            ("CIQ Field", "ciq_field"),
+            ("", ""),
+        ] {
+            assert_eq!(normalize_custom_name(name), expected, "{name:?}");
+        }
+    }
 }
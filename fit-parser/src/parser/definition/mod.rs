@@ -53,6 +53,7 @@ pub fn parse_definition_message(
     let message_type = MesgNum::from(content.next_u16(&endianness)?);
 
     let number_of_fields = content.next_u8()?;
+    check_number_of_fields(content, 0, number_of_fields)?;
     let mut fields: Vec<DefinitionField> = vec![];
 
     for _ in 0..number_of_fields {
@@ -63,6 +64,7 @@ pub fn parse_definition_message(
     // Parse size of optionnal developer fields
     if header.message_type_specific {
         let number_developer_fields = content.next_u8()?;
+        check_number_of_fields(content, fields.len(), number_developer_fields)?;
         for _ in 0..number_developer_fields {
             let field = parse_developer_field(custom_descriptions, endianness, content)?;
             fields.push(field);
@@ -76,6 +78,27 @@ pub fn parse_definition_message(
     })
 }
 
+/// Size in bytes of the definition of a single field, developer fields included.
+const FIELD_DEFINITION_SIZE: u32 = 3;
+
+/// Fail before reading `announced` more field definitions, on top of the `already_read` ones, if
+/// the definition then holds more fields than allowed by [Reader::max_definition_fields] or if the
+/// remaining content cannot hold them, so that a corrupted number of fields does not make the
+/// following records be read as field definitions.
+fn check_number_of_fields(
+    content: &Reader,
+    already_read: usize,
+    announced: u8,
+) -> Result<(), RecordError> {
+    let number_of_fields = already_read + announced as usize;
+    let readable = already_read + (content.bytes_left_to_read() / FIELD_DEFINITION_SIZE) as usize;
+    let max = content.max_definition_fields().min(readable);
+    if number_of_fields > max {
+        return Err(RecordError::TooManyFields(number_of_fields, max));
+    }
+    Ok(())
+}
+
 fn parse_developer_field(
     custom_descriptions: &HashMap<u8, HashMap<u8, CustomDescription>>,
     endianness: Endianness,
@@ -96,6 +119,7 @@ fn parse_developer_field(
         kind: FitField::Custom(CustomField {
             name: description.name.clone(),
             units: description.units.clone(),
+            original_name: description.original_name.clone(),
         }),
         parse: ParseFunction::Simple(description.base_type.get_parse_fn()),
         scale_offset: None,
@@ -164,4 +188,52 @@ mod tests {
             vec![DataValue::Uint8(12)]
         );
     }
+
+    #[test]
+    fn test_parse_definition_with_more_fields_than_content() {
+        // Announces 255 fields but only holds the definition of 1
+        let mut content = Reader::new(8, vec![0, 0, 20, 0, 255, 3, 1, 2].into_iter());
+        let res = parse_definition_message(
+            DefinitionMessageHeader {
+                message_type_specific: false,
+                local_message_type: 0,
+            },
+            &HashMap::new(),
+            &mut content,
+        );
+
+        assert!(matches!(res, Err(RecordError::TooManyFields(255, 1))));
+    }
+
+    #[test]
+    fn test_parse_definition_with_more_fields_than_allowed() {
+        let mut content = Reader::new(11, vec![0, 0, 20, 0, 2, 3, 1, 2, 4, 1, 2].into_iter())
+            .with_max_definition_fields(1);
+        let res = parse_definition_message(
+            DefinitionMessageHeader {
+                message_type_specific: false,
+                local_message_type: 0,
+            },
+            &HashMap::new(),
+            &mut content,
+        );
+
+        assert!(matches!(res, Err(RecordError::TooManyFields(2, 1))));
+    }
+
+    #[test]
+    fn test_parse_definition_with_more_developer_fields_than_content() {
+        // 1 field, then announces 2 developer fields but only holds the definition of 1
+        let mut content = Reader::new(12, vec![0, 0, 20, 0, 1, 3, 1, 2, 2, 0, 1, 0].into_iter());
+        let res = parse_definition_message(
+            DefinitionMessageHeader {
+                message_type_specific: true,
+                local_message_type: 0,
+            },
+            &HashMap::new(),
+            &mut content,
+        );
+
+        assert!(matches!(res, Err(RecordError::TooManyFields(3, 2))));
+    }
 }
@@ -26,6 +26,12 @@ pub struct FileHeader {
 
 impl FileHeader {
     pub fn from_bytes(reader: &mut Reader) -> Result<FileHeader, FileHeaderError> {
+        Self::parse(reader, true)
+    }
+
+    /// Same as [FileHeader::from_bytes], the header CRC, when present, being only checked if
+    /// `validate_crc` is `true`.
+    pub fn parse(reader: &mut Reader, validate_crc: bool) -> Result<FileHeader, FileHeaderError> {
         let header_size = reader
             .next_u8()
             .map_err(|_| FileHeaderError::HeaderMalformed)?;
@@ -75,7 +81,7 @@ impl FileHeader {
                 .next_u16(&Endianness::Little) // bytes 12 and 13
                 .map_err(|_| FileHeaderError::HeaderMalformed)?;
 
-            if crc != expected_crc {
+            if validate_crc && crc != expected_crc {
                 return Err(FileHeaderError::InvalidCRC(expected_crc, crc));
             }
         }
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{
+    MesgNum,
+    parser::{
+        Endianness, FitParserError,
+        header::{FileHeader, HEADER_SIZE_WITH_CRC},
+        reader::Reader,
+        records::{RecordError, RecordHeader},
+    },
+};
+
+/// Count the data messages of each [MesgNum] contained in a .FIT file.
+///
+/// Only definition records are decoded, data records are skipped according to the size of their
+/// definition, which makes it much cheaper than a full parse when only the content of a file is of
+/// interest (e.g. to index a large number of files).
+pub fn index_fit(bytes: Vec<u8>) -> Result<HashMap<MesgNum, usize>, FitParserError> {
+    let mut header_reader = Reader::new(HEADER_SIZE_WITH_CRC as u32, bytes.into_iter());
+    let header = FileHeader::from_bytes(&mut header_reader)?;
+    let mut reader = Reader::new(header.data_size, header_reader.remaining_content());
+
+    // Message type and size in bytes of a data record, by local message type
+    let mut definitions: Vec<Option<(MesgNum, u32)>> = vec![None; 16];
+    let mut counts = HashMap::new();
+    while !reader.is_empty() {
+        let local_message_type = match RecordHeader::parse(reader.next_u8()?) {
+            RecordHeader::Definition(header) => {
+                definitions[header.local_message_type as usize] =
+                    Some(skip_definition(&mut reader, header.message_type_specific)?);
+                continue;
+            }
+            RecordHeader::Data(header) => header.local_message_type,
+            RecordHeader::Compressed(header) => header.local_message_type,
+        };
+
+        let Some((message_type, size)) = &definitions[local_message_type as usize] else {
+            return Err(RecordError::NoDefinitionMessageFound(local_message_type).into());
+        };
+        reader.skip(*size)?;
+        *counts.entry(message_type.clone()).or_insert(0) += 1;
+    }
+
+    Ok(counts)
+}
+
+/// Read a definition record, only keeping its message type and the total size of its fields.
+fn skip_definition(
+    reader: &mut Reader,
+    has_developer_fields: bool,
+) -> Result<(MesgNum, u32), FitParserError> {
+    let _reserved = reader.next_u8()?;
+    let endianness = Endianness::from(reader.next_u8()?);
+    let message_type = MesgNum::from(reader.next_u16(&endianness)?);
+
+    let mut size = 0;
+    for _ in 0..reader.next_u8()? {
+        let _number = reader.next_u8()?;
+        size += reader.next_u8()? as u32;
+        let _base_type = reader.next_u8()?;
+    }
+    if has_developer_fields {
+        for _ in 0..reader.next_u8()? {
+            let _number = reader.next_u8()?;
+            size += reader.next_u8()? as u32;
+            let _developer_data_index = reader.next_u8()?;
+        }
+    }
+
+    Ok((message_type, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{
+        parse_fit_messages,
+        test_utils::{build_fit_file, data_record, definition_record},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_index_fit_counts_match_full_parse() {
+        // file_id: type (0, enum)
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[4]));
+
+        // record: timestamp (253), heart_rate (3)
+        records.append(&mut definition_record(
+            1,
+            20,
+            &[(253, 4, 0x86), (3, 1, 0x02)],
+        ));
+        for (timestamp, heart_rate) in [(1000_u32, 120), (1001, 121), (1002, 122)] {
+            let mut payload = timestamp.to_le_bytes().to_vec();
+            payload.push(heart_rate);
+            records.append(&mut data_record(1, &payload));
+        }
+
+        // Record without timestamp, sent with a compressed timestamp header
+        records.append(&mut definition_record(2, 20, &[(3, 1, 0x02)]));
+        records.push(0x80 | (2 << 5) | ((1000 + 3) & 0x1F) as u8);
+        records.push(123);
+
+        // Redefine local message type 0 as a session: total_calories (11)
+        records.append(&mut definition_record(0, 18, &[(11, 2, 0x84)]));
+        records.append(&mut data_record(0, &500_u16.to_le_bytes()));
+
+        let content = build_fit_file(&records);
+
+        let index = index_fit(content.clone()).unwrap();
+
+        let mut expected: HashMap<MesgNum, usize> = HashMap::new();
+        for message in parse_fit_messages(content.into_iter(), true).unwrap() {
+            *expected.entry(message.message_kind).or_insert(0) += 1;
+        }
+        assert_eq!(index, expected);
+        assert_eq!(index.get(&MesgNum::Record), Some(&4));
+        assert_eq!(index.get(&MesgNum::FileId), Some(&1));
+        assert_eq!(index.get(&MesgNum::Session), Some(&1));
+    }
+
+    #[test]
+    fn test_index_fit_data_without_definition() {
+        let content = build_fit_file(&data_record(0, &[4]));
+
+        assert!(matches!(
+            index_fit(content),
+            Err(FitParserError::ParserError(
+                RecordError::NoDefinitionMessageFound(0)
+            ))
+        ));
+    }
+}
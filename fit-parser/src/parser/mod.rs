@@ -1,22 +1,34 @@
-use std::{collections::HashMap, fs};
+use std::{collections::HashSet, io::Read};
 
 use thiserror::Error;
 
-use crate::parser::{
-    definition::custom::{CustomDescription, parse_custom_definition_description},
-    header::{FileHeader, FileHeaderError, HEADER_SIZE_WITH_CRC},
-    reader::{Reader, ReaderError},
-    records::{CompressedTimestamp, RecordError},
-};
+use crate::MesgNum;
+use crate::parser::{header::FileHeaderError, reader::ReaderError, records::RecordError};
 
+pub use crate::parser::decoder::FitDecoder;
 pub use crate::parser::definition::{Definition, Endianness};
+pub use crate::parser::index::index_fit;
+pub use crate::parser::raw::{
+    FitEncoder, RawData, RawDefinition, RawDeveloperFieldDefinition, RawFieldDefinition, RawFile,
+    RawRecord,
+};
 pub use crate::parser::records::Record;
-pub use crate::parser::records::{DataMessage, DataMessageField};
+pub use crate::parser::records::{
+    CompressedMessageHeader, CompressedTimestamp, DataMessage, DataMessageField, DataMessageHeader,
+    DefinitionMessageHeader, RecordHeader, TIME_OFFSET_MASK, TIME_OFFSET_ROLLOVER,
+};
+pub use crate::parser::summary::{SUMMARY_MESSAGES, parse_summary};
 
+mod decoder;
 mod definition;
 mod header;
+mod index;
+mod raw;
 mod reader;
 mod records;
+mod summary;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub mod types;
 
 #[derive(Error, Debug)]
@@ -30,6 +42,9 @@ pub enum FitParserError {
     #[error("Invalid body CRC: expected {0} but got {1}")]
     InvalidBodyCRC(u16, u16),
 
+    #[error("Header reports {0} bytes of data but the file only contains {1}")]
+    DataSizeMismatch(u32, u32),
+
     #[error("Reader error")]
     ReaderError(#[from] ReaderError),
 
@@ -37,72 +52,366 @@ pub enum FitParserError {
     ParserError(#[from] RecordError),
 }
 
-pub fn parse_fit_messages(
-    content: std::vec::IntoIter<u8>,
-    enforce_crc: bool,
-) -> Result<Vec<DataMessage>, FitParserError> {
-    let mut header_reader = Reader::new(HEADER_SIZE_WITH_CRC as u32, content);
-    let header = FileHeader::from_bytes(&mut header_reader)?;
+/// Default value of [ParseOptions::max_field_values]. Field sizes being stored on a single byte,
+/// no field can hold more values and the cap never applies.
+pub const DEFAULT_MAX_FIELD_VALUES: usize = u8::MAX as usize;
 
-    let mut reader = Reader::new(header.data_size, header_reader.remaining_content());
+/// Default value of [ParseOptions::max_definition_fields]. The number of fields being stored on a
+/// single byte, the cap never applies and only the remaining content bounds a definition.
+pub const DEFAULT_MAX_DEFINITION_FIELDS: usize = u8::MAX as usize;
 
-    let mut definitions: HashMap<u8, Definition> = HashMap::new();
-    let mut custom_descriptions: HashMap<u8, HashMap<u8, CustomDescription>> = HashMap::new();
-    let mut compressed_timestamp = CompressedTimestamp::default();
-    let mut messages = Vec::new();
+/// Options controlling how a .FIT file is decoded.
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// Return an error if the file's body CRC does not match its content.
+    pub enforce_crc: bool,
+    /// The header CRC, when present, is always checked by default. When `true`, a header whose CRC
+    /// does not match is accepted, e.g. for files patched by tools that do not update it.
+    pub skip_header_crc: bool,
+    /// When `true`, the default, every field is returned verbatim, sentinels included, and
+    /// [crate::DataValue::is_invalid] is still available to callers to check them. When `false`,
+    /// fields whose values are all invalid (i.e. set to their base type's sentinel) are treated as
+    /// missing and removed from their [DataMessage].
+    pub keep_invalid: bool,
+    /// When set, only [DataMessage]s whose kind is in the set are returned. Other messages are
+    /// still decoded, since they can carry developer fields descriptions or timestamps needed by
+    /// the kept ones.
+    pub messages: Option<HashSet<MesgNum>>,
+    /// Some devices write a wrong `data_size` in the file header, which makes the body CRC be read
+    /// in the middle of a record. When `true` and the CRC does not match at the reported offset,
+    /// the decoder looks for the end of the last complete record whose CRC matches the 2 bytes
+    /// that follow it, preferring the one closest to the reported size.
+    pub lenient_data_size: bool,
+    /// Scaled values are floats by default. When `true`, a scaled value that is integral (e.g. a
+    /// distance of `30000` with a scale of `100`) is returned as the smallest integer
+    /// [crate::DataValue] holding it instead, see [crate::DataValue::to_integral].
+    pub integral_scaled_values: bool,
+    /// Developer field names are free-form and differ between applications (e.g. `Power`,
+    /// `power` or `Running Power`). When `true`, they are normalized to snake case (`power`,
+    /// `running_power`), the name from the file being kept in [crate::CustomField::original_name].
+    pub normalize_custom_names: bool,
+    /// Maximum number of values a single field can be parsed into, to bound the memory used by
+    /// a file. Decoding fails with [crate::parser::types::DataTypeError::TooManyValues] when a
+    /// field's definition announces more values. Defaults to [DEFAULT_MAX_FIELD_VALUES].
+    pub max_field_values: Option<usize>,
+    /// Maximum number of fields, including developer fields, a definition can announce. Decoding
+    /// fails with [crate::parser::records::RecordError::TooManyFields] when a definition announces
+    /// more fields, or more than the remaining content can hold. Defaults to
+    /// [DEFAULT_MAX_DEFINITION_FIELDS].
+    pub max_definition_fields: Option<usize>,
+}
 
-    loop {
-        if reader.is_empty() {
-            break;
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            enforce_crc: false,
+            skip_header_crc: false,
+            keep_invalid: true,
+            messages: None,
+            lenient_data_size: false,
+            integral_scaled_values: false,
+            normalize_custom_names: false,
+            max_field_values: None,
+            max_definition_fields: None,
         }
+    }
+}
 
-        let record = Record::parse(
-            &mut reader,
-            &definitions,
-            &custom_descriptions,
-            &mut compressed_timestamp,
-        )?;
+impl ParseOptions {
+    /// Start building [ParseOptions] from the defaults: header CRC checked, body CRC not checked,
+    /// invalid values kept and no leniency on the reported data size.
+    pub fn builder() -> ParseOptionsBuilder {
+        ParseOptionsBuilder::default()
+    }
+}
 
-        match record {
-            Record::Definition(ref definition) => {
-                definitions.insert(definition.local_message_type, definition.clone());
-            }
-            Record::Data(data) => {
-                parse_custom_definition_description(&data, &definitions, &mut custom_descriptions);
-                compressed_timestamp.set_last_timestamp(data.last_timestamp());
-                messages.push(data);
-            }
-        }
+/// Builder for [ParseOptions], e.g.
+/// `ParseOptions::builder().validate_body_crc(true).lenient(true).build()`.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptionsBuilder {
+    options: ParseOptions,
+}
 
-        if reader.is_empty() {
-            break;
-        }
+impl ParseOptionsBuilder {
+    /// See [ParseOptions::enforce_crc].
+    pub fn validate_body_crc(mut self, validate: bool) -> Self {
+        self.options.enforce_crc = validate;
+        self
+    }
+
+    /// See [ParseOptions::skip_header_crc].
+    pub fn validate_header_crc(mut self, validate: bool) -> Self {
+        self.options.skip_header_crc = !validate;
+        self
+    }
+
+    /// See [ParseOptions::lenient_data_size].
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.options.lenient_data_size = lenient;
+        self
     }
 
-    let body_crc = reader.current_crc();
+    /// See [ParseOptions::keep_invalid].
+    pub fn keep_invalid(mut self, keep: bool) -> Self {
+        self.options.keep_invalid = keep;
+        self
+    }
+
+    /// See [ParseOptions::messages].
+    pub fn messages(mut self, messages: impl IntoIterator<Item = MesgNum>) -> Self {
+        self.options.messages = Some(messages.into_iter().collect());
+        self
+    }
+
+    /// See [ParseOptions::integral_scaled_values].
+    pub fn integral_scaled_values(mut self, integral: bool) -> Self {
+        self.options.integral_scaled_values = integral;
+        self
+    }
+
+    /// See [ParseOptions::normalize_custom_names].
+    pub fn normalize_custom_names(mut self, normalize: bool) -> Self {
+        self.options.normalize_custom_names = normalize;
+        self
+    }
+
+    /// See [ParseOptions::max_field_values].
+    pub fn max_field_values(mut self, max: usize) -> Self {
+        self.options.max_field_values = Some(max);
+        self
+    }
 
-    let mut crc_reader = Reader::new(2, reader.remaining_content());
-    let expected_crc = crc_reader.next_u16(&Endianness::Little)?;
+    /// See [ParseOptions::max_definition_fields].
+    pub fn max_definition_fields(mut self, max: usize) -> Self {
+        self.options.max_definition_fields = Some(max);
+        self
+    }
 
-    if enforce_crc && body_crc != expected_crc {
-        return Err(FitParserError::InvalidBodyCRC(expected_crc, body_crc));
+    pub fn build(self) -> ParseOptions {
+        self.options
     }
+}
+
+/// Parse a .FIT file with the default [ParseOptions], the body CRC being only checked if
+/// `enforce_crc` is `true`.
+pub fn parse_fit_messages(
+    content: std::vec::IntoIter<u8>,
+    enforce_crc: bool,
+) -> Result<Vec<DataMessage>, FitParserError> {
+    parse_fit_messages_with_options(
+        content,
+        ParseOptions::builder()
+            .validate_body_crc(enforce_crc)
+            .build(),
+    )
+}
+
+/// Parse a .FIT file held in memory, see [parse_fit_reader_with_options].
+pub fn parse_fit_messages_with_options(
+    content: std::vec::IntoIter<u8>,
+    options: ParseOptions,
+) -> Result<Vec<DataMessage>, FitParserError> {
+    parse_fit_reader_with_options(content.as_slice(), options)
+}
+
+/// Parse a .FIT file with the default [ParseOptions], bytes being pulled from `reader` as they
+/// are decoded so that the file is never held in memory. `reader` can be borrowed, e.g.
+/// `&mut File` or `&[u8]`. See [parse_fit_reader_with_options].
+pub fn parse_fit_reader<R: Read>(reader: R) -> Result<Vec<DataMessage>, FitParserError> {
+    parse_fit_reader_with_options(reader, ParseOptions::default())
+}
 
-    Ok(messages)
+/// Same as [parse_fit_reader], with the given [ParseOptions]. With
+/// [ParseOptions::lenient_data_size], the body of the file is read into memory, as finding its
+/// actual end needs all of it.
+///
+/// When several .FIT files are chained in the content, the messages of all of them are returned
+/// in order, see [FitDecoder::finish_files] to get them by file.
+pub fn parse_fit_reader_with_options<R: Read>(
+    reader: R,
+    options: ParseOptions,
+) -> Result<Vec<DataMessage>, FitParserError> {
+    FitDecoder::with_options(options).decode_read(reader)
 }
 
+/// Read and parse a .FIT file, not available in WebAssembly which has no filesystem.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn parse_fit_file(file: &str, enforce_crc: bool) -> Result<Vec<DataMessage>, FitParserError> {
-    let content = fs::read(file)?.into_iter();
-    parse_fit_messages(content, enforce_crc)
+    parse_fit_reader_with_options(
+        std::fs::File::open(file)?,
+        ParseOptions::builder()
+            .validate_body_crc(enforce_crc)
+            .build(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::parser::parse_fit_file;
+    use std::io::{BufReader, Cursor};
+
+    use crate::parser::test_utils::{build_fit_file, data_record, definition_record};
+
+    use super::*;
 
     #[test]
     fn test_no_error() {
         let _ = parse_fit_file("test.fit", false);
     }
+
+    /// [Read] implementation returning at most `chunk` bytes per call, to check that parsing does
+    /// not depend on how the content is split.
+    struct ChunkedReader {
+        content: Vec<u8>,
+        position: usize,
+        chunk: usize,
+    }
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let end = (self.position + self.chunk.min(buf.len())).min(self.content.len());
+            let read = end - self.position;
+            buf[..read].copy_from_slice(&self.content[self.position..end]);
+            self.position = end;
+            Ok(read)
+        }
+    }
+
+    /// Fails once `fail_after` bytes have been read.
+    struct FailingReader {
+        content: Vec<u8>,
+        fail_after: usize,
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.fail_after == 0 {
+                return Err(std::io::Error::other("disconnected"));
+            }
+            let read = self.fail_after.min(buf.len()).min(self.content.len());
+            buf[..read].copy_from_slice(&self.content[..read]);
+            self.content.drain(..read);
+            self.fail_after -= read;
+            Ok(read)
+        }
+    }
+
+    fn record_file() -> Vec<u8> {
+        // Record message with heart_rate (3, uint8)
+        let mut records = definition_record(0, 20, &[(3, 1, 0x02)]);
+        for heart_rate in [120, 121, 122] {
+            records.append(&mut data_record(0, &[heart_rate]));
+        }
+        build_fit_file(&records)
+    }
+
+    #[test]
+    fn test_parse_fit_reader_same_as_in_memory() {
+        let content = record_file();
+
+        let in_memory = parse_fit_messages(content.clone().into_iter(), true).unwrap();
+        for chunk in [1, 3, 1024] {
+            let streamed = parse_fit_reader_with_options(
+                ChunkedReader {
+                    content: content.clone(),
+                    position: 0,
+                    chunk,
+                },
+                ParseOptions::builder().validate_body_crc(true).build(),
+            )
+            .unwrap();
+            assert_eq!(streamed, in_memory);
+        }
+        assert_eq!(in_memory.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_fit_reader_borrowed_reader() {
+        let content = record_file();
+        let in_memory = parse_fit_messages(content.clone().into_iter(), false).unwrap();
+
+        assert_eq!(parse_fit_reader(content.as_slice()).unwrap(), in_memory);
+
+        let path = std::env::temp_dir().join(format!(
+            "fit-parser-borrowed-reader-{}.fit",
+            std::process::id()
+        ));
+        std::fs::write(&path, &content).unwrap();
+        let mut file = std::fs::File::open(&path).unwrap();
+        assert_eq!(parse_fit_reader(&mut file).unwrap(), in_memory);
+        let file = std::fs::File::open(&path).unwrap();
+        assert_eq!(parse_fit_reader(BufReader::new(&file)).unwrap(), in_memory);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_fit_reader_chained_files() {
+        let mut content = record_file();
+        content.append(&mut record_file());
+
+        let in_memory = parse_fit_messages(content.clone().into_iter(), true).unwrap();
+        for chunk in [1, 3, 1024] {
+            let streamed = parse_fit_reader_with_options(
+                ChunkedReader {
+                    content: content.clone(),
+                    position: 0,
+                    chunk,
+                },
+                ParseOptions::builder().validate_body_crc(true).build(),
+            )
+            .unwrap();
+            assert_eq!(streamed, in_memory);
+        }
+        assert_eq!(in_memory.len(), 6);
+
+        let lenient = parse_fit_messages_with_options(
+            content.into_iter(),
+            ParseOptions::builder().lenient(true).build(),
+        )
+        .unwrap();
+        assert_eq!(lenient, in_memory);
+    }
+
+    #[test]
+    fn test_parse_fit_reader_checks_trailing_crc() {
+        let mut content = record_file();
+        let last = content.len() - 1;
+        content[last] ^= 0xFF;
+
+        assert!(parse_fit_reader(Cursor::new(content.clone())).is_ok());
+        assert!(matches!(
+            parse_fit_reader_with_options(
+                Cursor::new(content),
+                ParseOptions::builder().validate_body_crc(true).build()
+            ),
+            Err(FitParserError::InvalidBodyCRC(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_fit_reader_missing_trailing_crc() {
+        let mut content = record_file();
+        content.truncate(content.len() - 1);
+        let data_size = (content.len() - 15) as u32;
+
+        let Err(FitParserError::DataSizeMismatch(reported, actual)) =
+            parse_fit_reader(Cursor::new(content))
+        else {
+            unreachable!("Should have reported the data size mismatch");
+        };
+        assert_eq!(reported, data_size);
+        assert_eq!(actual, data_size - 1);
+    }
+
+    #[test]
+    fn test_parse_fit_reader_io_error() {
+        let content = record_file();
+
+        let res = parse_fit_reader(FailingReader {
+            fail_after: content.len() - 4,
+            content,
+        });
+
+        assert!(matches!(res, Err(FitParserError::Io(_))));
+    }
 }
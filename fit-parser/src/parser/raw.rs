@@ -0,0 +1,331 @@
+//! Lossless, profile-agnostic view of a .FIT file: records are kept as they are laid out in the
+//! file, with data fields as raw bytes, so that they can be encoded back with [FitEncoder].
+
+use crate::parser::{
+    Endianness, FitParserError,
+    header::{FileHeader, HEADER_SIZE_WITH_CRC},
+    reader::{Reader, compute_crc},
+    records::{
+        CompressedMessageHeader, DataMessageHeader, DefinitionMessageHeader, RecordError,
+        RecordHeader,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawFile {
+    pub protocol_version: u8,
+    pub profile_version: u16,
+    pub records: Vec<RawRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawRecord {
+    Definition(RawDefinition),
+    Data(RawData),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawDefinition {
+    pub local_message_type: u8,
+    pub global_message_number: u16,
+    pub endianness: Endianness,
+    pub fields: Vec<RawFieldDefinition>,
+    pub developer_fields: Vec<RawDeveloperFieldDefinition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawFieldDefinition {
+    pub number: u8,
+    pub size: u8,
+    pub base_type: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawDeveloperFieldDefinition {
+    pub number: u8,
+    pub size: u8,
+    pub developer_data_index: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawData {
+    pub local_message_type: u8,
+    /// Set for data records using a compressed timestamp header.
+    pub time_offset: Option<u8>,
+    /// Bytes of each field, in the order of the matching [RawDefinition] fields.
+    pub fields: Vec<Vec<u8>>,
+    /// Bytes of each developer field, in the order of the matching [RawDefinition] developer
+    /// fields.
+    pub developer_fields: Vec<Vec<u8>>,
+}
+
+impl RawFile {
+    /// Split the content of a .FIT file into its records, without interpreting their content.
+    pub fn decode(content: Vec<u8>) -> Result<Self, FitParserError> {
+        let mut header_reader = Reader::new(HEADER_SIZE_WITH_CRC as u32, content.into_iter());
+        let header = FileHeader::from_bytes(&mut header_reader)?;
+        let mut reader = Reader::new(header.data_size, header_reader.remaining_content());
+
+        let mut definitions: Vec<Option<RawDefinition>> = vec![None; 16];
+        let mut records = Vec::new();
+        while !reader.is_empty() {
+            let record = match RecordHeader::parse(reader.next_u8()?) {
+                RecordHeader::Definition(header) => {
+                    let definition = decode_definition(
+                        &mut reader,
+                        header.local_message_type,
+                        header.message_type_specific,
+                    )?;
+                    definitions[header.local_message_type as usize] = Some(definition.clone());
+                    RawRecord::Definition(definition)
+                }
+                RecordHeader::Data(header) => RawRecord::Data(decode_data(
+                    &mut reader,
+                    &definitions,
+                    header.local_message_type,
+                    None,
+                )?),
+                RecordHeader::Compressed(header) => RawRecord::Data(decode_data(
+                    &mut reader,
+                    &definitions,
+                    header.local_message_type,
+                    Some(header.time_offset),
+                )?),
+            };
+            records.push(record);
+        }
+
+        Ok(Self {
+            protocol_version: header._protocol,
+            profile_version: header._profile_version,
+            records,
+        })
+    }
+}
+
+fn decode_definition(
+    reader: &mut Reader,
+    local_message_type: u8,
+    has_developer_fields: bool,
+) -> Result<RawDefinition, FitParserError> {
+    let _reserved = reader.next_u8()?;
+    let endianness = Endianness::from(reader.next_u8()?);
+    let global_message_number = reader.next_u16(&endianness)?;
+
+    let number_of_fields = reader.next_u8()?;
+    let mut fields = Vec::new();
+    for _ in 0..number_of_fields {
+        fields.push(RawFieldDefinition {
+            number: reader.next_u8()?,
+            size: reader.next_u8()?,
+            base_type: reader.next_u8()?,
+        });
+    }
+
+    let mut developer_fields = Vec::new();
+    if has_developer_fields {
+        let number_of_fields = reader.next_u8()?;
+        for _ in 0..number_of_fields {
+            developer_fields.push(RawDeveloperFieldDefinition {
+                number: reader.next_u8()?,
+                size: reader.next_u8()?,
+                developer_data_index: reader.next_u8()?,
+            });
+        }
+    }
+
+    Ok(RawDefinition {
+        local_message_type,
+        global_message_number,
+        endianness,
+        fields,
+        developer_fields,
+    })
+}
+
+fn decode_data(
+    reader: &mut Reader,
+    definitions: &[Option<RawDefinition>],
+    local_message_type: u8,
+    time_offset: Option<u8>,
+) -> Result<RawData, FitParserError> {
+    let Some(Some(definition)) = definitions.get(local_message_type as usize) else {
+        return Err(RecordError::NoDefinitionMessageFound(local_message_type).into());
+    };
+
+    let mut read_bytes = |size: u8| -> Result<Vec<u8>, FitParserError> {
+        (0..size)
+            .map(|_| reader.next_u8().map_err(FitParserError::from))
+            .collect()
+    };
+
+    let fields = definition
+        .fields
+        .iter()
+        .map(|field| read_bytes(field.size))
+        .collect::<Result<_, _>>()?;
+    let developer_fields = definition
+        .developer_fields
+        .iter()
+        .map(|field| read_bytes(field.size))
+        .collect::<Result<_, _>>()?;
+
+    Ok(RawData {
+        local_message_type,
+        time_offset,
+        fields,
+        developer_fields,
+    })
+}
+
+/// Encoder writing [RawRecord]s back into a valid .FIT file, with a 14 bytes header and both the
+/// header and body CRCs computed from the written content.
+#[derive(Debug)]
+pub struct FitEncoder {
+    protocol_version: u8,
+    profile_version: u16,
+    body: Vec<u8>,
+}
+
+impl FitEncoder {
+    pub fn new(protocol_version: u8, profile_version: u16) -> Self {
+        Self {
+            protocol_version,
+            profile_version,
+            body: Vec::new(),
+        }
+    }
+
+    pub fn write(&mut self, record: &RawRecord) {
+        match record {
+            RawRecord::Definition(definition) => self.write_definition(definition),
+            RawRecord::Data(data) => self.write_data(data),
+        }
+    }
+
+    fn write_definition(&mut self, definition: &RawDefinition) {
+        let header = RecordHeader::Definition(DefinitionMessageHeader {
+            message_type_specific: !definition.developer_fields.is_empty(),
+            local_message_type: definition.local_message_type,
+        });
+        self.body.push(header.to_byte());
+        self.body.push(0); // Reserved
+        match definition.endianness {
+            Endianness::Little => {
+                self.body.push(0);
+                self.body
+                    .extend_from_slice(&definition.global_message_number.to_le_bytes());
+            }
+            Endianness::Big => {
+                self.body.push(1);
+                self.body
+                    .extend_from_slice(&definition.global_message_number.to_be_bytes());
+            }
+        }
+
+        self.body.push(definition.fields.len() as u8);
+        for field in definition.fields.iter() {
+            self.body
+                .extend_from_slice(&[field.number, field.size, field.base_type]);
+        }
+
+        if !definition.developer_fields.is_empty() {
+            self.body.push(definition.developer_fields.len() as u8);
+            for field in definition.developer_fields.iter() {
+                self.body.extend_from_slice(&[
+                    field.number,
+                    field.size,
+                    field.developer_data_index,
+                ]);
+            }
+        }
+    }
+
+    fn write_data(&mut self, data: &RawData) {
+        let header = match data.time_offset {
+            Some(time_offset) => RecordHeader::Compressed(CompressedMessageHeader {
+                local_message_type: data.local_message_type,
+                time_offset,
+            }),
+            None => RecordHeader::Data(DataMessageHeader {
+                local_message_type: data.local_message_type,
+            }),
+        };
+        self.body.push(header.to_byte());
+        for field in data.fields.iter().chain(data.developer_fields.iter()) {
+            self.body.extend_from_slice(field);
+        }
+    }
+
+    /// Return the content of the encoded file.
+    pub fn finish(self) -> Vec<u8> {
+        let mut content = vec![HEADER_SIZE_WITH_CRC, self.protocol_version];
+        content.extend_from_slice(&self.profile_version.to_le_bytes());
+        content.extend_from_slice(&(self.body.len() as u32).to_le_bytes());
+        content.extend_from_slice(b".FIT");
+        content.extend_from_slice(&crc(&content).to_le_bytes());
+
+        content.extend_from_slice(&self.body);
+        content.extend_from_slice(&crc(&self.body).to_le_bytes());
+
+        content
+    }
+}
+
+impl RawFile {
+    /// Encode the records back into the content of a .FIT file.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoder = FitEncoder::new(self.protocol_version, self.profile_version);
+        for record in self.records.iter() {
+            encoder.write(record);
+        }
+        encoder.finish()
+    }
+}
+
+fn crc(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0, |crc, byte| compute_crc(&crc, *byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::test_utils::{build_fit_file, data_record, definition_record};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_then_encode_is_identity() {
+        let mut records = definition_record(0, 20, &[(253, 4, 0x86), (3, 1, 0x02)]);
+        records.append(&mut data_record(0, &[0xE8, 0x03, 0, 0, 120]));
+        records.append(&mut data_record(0, &[0xE9, 0x03, 0, 0, 121]));
+        let content = build_fit_file(&records);
+
+        let raw = RawFile::decode(content.clone()).unwrap();
+        assert_eq!(raw.records.len(), 3);
+        assert_eq!(
+            raw.records[2],
+            RawRecord::Data(RawData {
+                local_message_type: 0,
+                time_offset: None,
+                fields: vec![vec![0xE9, 0x03, 0, 0], vec![121]],
+                developer_fields: vec![],
+            })
+        );
+
+        assert_eq!(raw.encode(), content);
+    }
+
+    #[test]
+    fn test_encode_compressed_timestamp_header() {
+        let mut encoder = FitEncoder::new(0x20, 2132);
+        encoder.write(&RawRecord::Data(RawData {
+            local_message_type: 1,
+            time_offset: Some(3),
+            fields: vec![vec![120]],
+            developer_fields: vec![],
+        }));
+        let content = encoder.finish();
+
+        assert_eq!(content[14], 0b1010_0011);
+    }
+}
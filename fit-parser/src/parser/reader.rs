@@ -1,53 +1,279 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufReader, Read},
+};
+
 use thiserror::Error;
 
-use crate::parser::definition::Endianness;
+use crate::parser::{
+    DEFAULT_MAX_DEFINITION_FIELDS, DEFAULT_MAX_FIELD_VALUES, definition::Endianness,
+    header::HEADER_SIZE_WITH_CRC,
+};
 
+/// Reads the bytes of a .FIT file, either held in memory or pulled from an [io::Read] that is
+/// borrowed for `'a`.
 #[derive(Debug)]
-pub struct Reader {
+pub struct Reader<'a> {
     bytes_left_to_read: u32,
     crc: u16,
-    content: std::vec::IntoIter<u8>,
+    content: Content<'a>,
+    max_field_values: usize,
+    max_definition_fields: usize,
+}
+
+/// Bytes a [Reader] reads from.
+#[derive(Debug)]
+enum Content<'a> {
+    Bytes(std::vec::IntoIter<u8>),
+    Stream(Stream<'a>),
+}
+
+impl Content<'_> {
+    fn next(&mut self) -> Option<u8> {
+        match self {
+            Self::Bytes(bytes) => bytes.next(),
+            Self::Stream(stream) => stream.next(),
+        }
+    }
+}
+
+/// Bytes pulled lazily from an [io::Read], see [Reader::from_read].
+struct Stream<'a> {
+    bytes: io::Bytes<BufReader<Box<dyn Read + 'a>>>,
+    /// Bytes already pulled from `bytes` but not returned yet.
+    pending: VecDeque<u8>,
+    /// Number of bytes at the end of the content that are never returned by [Stream::next], see
+    /// [Reader::body].
+    held_back: usize,
+    /// Number of bytes returned since the stream was created or since [Reader::body].
+    returned: u32,
+    /// Whether the content ended, or bytes could not be read, before a byte was requested.
+    exhausted: bool,
+    error: Option<io::Error>,
+}
+
+impl std::fmt::Debug for Stream<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Stream")
+            .field("pending", &self.pending)
+            .field("held_back", &self.held_back)
+            .field("returned", &self.returned)
+            .field("exhausted", &self.exhausted)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream<'_> {
+    /// Next byte of the content, unless it is one of the `held_back` last bytes.
+    fn next(&mut self) -> Option<u8> {
+        if !self.has_next() {
+            return None;
+        }
+
+        self.returned += 1;
+        self.pending.pop_front()
+    }
+
+    /// Whether [Stream::next] would return a byte, pulling it from `bytes` if needed.
+    fn has_next(&mut self) -> bool {
+        while self.pending.len() <= self.held_back && !self.exhausted {
+            match self.bytes.next() {
+                Some(Ok(byte)) => self.pending.push_back(byte),
+                Some(Err(err)) => {
+                    self.error = Some(err);
+                    self.exhausted = true;
+                }
+                None => self.exhausted = true,
+            }
+        }
+        self.pending.len() > self.held_back
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum ReaderError {
+    /// Every expected byte has already been read, i.e. the content ended cleanly.
     #[error("Content has been exhausted of expected number of bytes")]
-    ContentExhausted,
-    #[error("Content is empty while more bytes were expected")]
-    ContentPrematurelyEmpty,
+    Eof,
+    /// The content ended in the middle of a value, or before the expected number of bytes.
+    #[error("Content ended while more bytes were expected")]
+    UnexpectedEof,
+    #[error("{0} bytes are left to read before the trailing CRC")]
+    UnreadContent(u32),
 }
 
-impl Reader {
+impl ReaderError {
+    /// Error to report when reading past the first byte of a value: even if the expected
+    /// content is exhausted, ending there means the value is truncated.
+    pub fn truncated(self) -> Self {
+        match self {
+            Self::Eof => Self::UnexpectedEof,
+            err => err,
+        }
+    }
+}
+
+impl<'a> Reader<'a> {
+    /// Whether every expected byte has been read, in which case the next read fails with
+    /// [ReaderError::Eof].
     pub fn is_empty(&self) -> bool {
         self.bytes_left_to_read == 0
     }
 
+    pub fn bytes_left_to_read(&self) -> u32 {
+        self.bytes_left_to_read
+    }
+
     pub fn new(bytes_to_read: u32, content: std::vec::IntoIter<u8>) -> Self {
         Self {
             bytes_left_to_read: bytes_to_read,
             crc: 0,
+            content: Content::Bytes(content),
+            max_field_values: DEFAULT_MAX_FIELD_VALUES,
+            max_definition_fields: DEFAULT_MAX_DEFINITION_FIELDS,
+        }
+    }
+
+    /// Same as [Reader::new], bytes being lazily pulled from `read` instead of being held in
+    /// memory.
+    pub fn from_read(bytes_to_read: u32, read: impl Read + 'a) -> Self {
+        let read: Box<dyn Read + 'a> = Box::new(read);
+        Self {
+            bytes_left_to_read: bytes_to_read,
+            crc: 0,
+            content: Content::Stream(Stream {
+                bytes: BufReader::new(read).bytes(),
+                pending: VecDeque::new(),
+                held_back: 0,
+                returned: 0,
+                exhausted: false,
+                error: None,
+            }),
+            max_field_values: DEFAULT_MAX_FIELD_VALUES,
+            max_definition_fields: DEFAULT_MAX_DEFINITION_FIELDS,
+        }
+    }
+
+    /// Reader over the `data_size` bytes of a .FIT file's body, following the content already
+    /// read (i.e. the file header), with a new CRC. When the content is streamed, its last 2
+    /// bytes are held back as the trailing CRC: reading them fails with
+    /// [ReaderError::UnexpectedEof] and [Reader::is_exhausted], as if the content ended there,
+    /// and only [Reader::read_trailing_crc] returns them.
+    pub fn body(self, data_size: u32) -> Self {
+        let content = match self.content {
+            Content::Stream(mut stream) => {
+                stream.held_back = 2;
+                stream.returned = 0;
+                Content::Stream(stream)
+            }
+            content => content,
+        };
+        Self {
+            bytes_left_to_read: data_size,
+            crc: 0,
             content,
+            ..self
+        }
+    }
+
+    /// Reader over the header of the file following the current one, once its trailing CRC has
+    /// been read, for .FIT files chained in a single content. See [Reader::has_remaining_content].
+    pub fn next_file(self) -> Self {
+        let content = match self.content {
+            Content::Stream(mut stream) => {
+                stream.held_back = 0;
+                stream.returned = 0;
+                Content::Stream(stream)
+            }
+            content => content,
+        };
+        Self {
+            bytes_left_to_read: HEADER_SIZE_WITH_CRC as u32,
+            crc: 0,
+            content,
+            ..self
+        }
+    }
+
+    /// Whether any byte follows the ones already read, regardless of the expected number of bytes.
+    pub fn has_remaining_content(&mut self) -> bool {
+        match &mut self.content {
+            Content::Bytes(bytes) => !bytes.as_slice().is_empty(),
+            Content::Stream(stream) => stream.has_next(),
+        }
+    }
+
+    /// Whether a streamed content ended, or could not be read, before the expected number of
+    /// bytes, see [Reader::body]. The number of bytes read until then is given by
+    /// [Reader::bytes_read].
+    pub fn is_exhausted(&self) -> bool {
+        match &self.content {
+            Content::Bytes(_) => false,
+            Content::Stream(stream) => stream.exhausted && stream.pending.len() <= stream.held_back,
+        }
+    }
+
+    /// Number of bytes returned by a streamed content since [Reader::body].
+    pub fn bytes_read(&self) -> u32 {
+        match &self.content {
+            Content::Bytes(_) => 0,
+            Content::Stream(stream) => stream.returned,
+        }
+    }
+
+    /// Error that stopped the streamed content from being read, if any.
+    pub fn take_io_error(&mut self) -> Option<io::Error> {
+        match &mut self.content {
+            Content::Bytes(_) => None,
+            Content::Stream(stream) => stream.error.take(),
+        }
+    }
+
+    /// Maximum number of values a single field can be parsed into, see
+    /// [crate::ParseOptions::max_field_values].
+    pub fn with_max_field_values(self, max_field_values: usize) -> Self {
+        Self {
+            max_field_values,
+            ..self
+        }
+    }
+
+    pub fn max_field_values(&self) -> usize {
+        self.max_field_values
+    }
+
+    /// Maximum number of fields a definition can announce, see
+    /// [crate::ParseOptions::max_definition_fields].
+    pub fn with_max_definition_fields(self, max_definition_fields: usize) -> Self {
+        Self {
+            max_definition_fields,
+            ..self
         }
     }
 
+    pub fn max_definition_fields(&self) -> usize {
+        self.max_definition_fields
+    }
+
     pub fn next_u8(&mut self) -> Result<u8, ReaderError> {
         if self.bytes_left_to_read == 0 {
-            return Err(ReaderError::ContentExhausted);
+            return Err(ReaderError::Eof);
         }
 
-        let res = self
-            .content
-            .next()
-            .ok_or(ReaderError::ContentPrematurelyEmpty);
-        if let Ok(byte) = res {
-            self.crc = compute_crc(&self.crc, byte);
-        }
-        self.bytes_left_to_read = self.bytes_left_to_read.saturating_sub(1);
-        res
+        let byte = self.content.next().ok_or(ReaderError::UnexpectedEof)?;
+        self.crc = compute_crc(&self.crc, byte);
+        self.bytes_left_to_read -= 1;
+        Ok(byte)
+    }
+
+    /// Next byte of a value whose first byte has already been read, see [ReaderError::truncated].
+    fn next_continuation_u8(&mut self) -> Result<u8, ReaderError> {
+        self.next_u8().map_err(ReaderError::truncated)
     }
 
     pub fn next_u16(&mut self, endianness: &Endianness) -> Result<u16, ReaderError> {
-        let bytes = [self.next_u8()?, self.next_u8()?];
+        let bytes = [self.next_u8()?, self.next_continuation_u8()?];
         match endianness {
             Endianness::Big => Ok(u16::from_be_bytes(bytes)),
             Endianness::Little => Ok(u16::from_le_bytes(bytes)),
@@ -57,9 +283,9 @@ impl Reader {
     pub fn next_u32(&mut self, endianness: &Endianness) -> Result<u32, ReaderError> {
         let bytes = [
             self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
         ];
         match endianness {
             Endianness::Big => Ok(u32::from_be_bytes(bytes)),
@@ -70,13 +296,13 @@ impl Reader {
     pub fn next_u64(&mut self, endianness: &Endianness) -> Result<u64, ReaderError> {
         let bytes = [
             self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
-            self.next_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
+            self.next_continuation_u8()?,
         ];
         match endianness {
             Endianness::Big => Ok(u64::from_be_bytes(bytes)),
@@ -84,6 +310,14 @@ impl Reader {
         }
     }
 
+    /// Consume the next `n` bytes without returning them, the CRC is still updated.
+    pub fn skip(&mut self, n: u32) -> Result<(), ReaderError> {
+        for _ in 0..n {
+            self.next_u8()?;
+        }
+        Ok(())
+    }
+
     pub fn current_crc(&self) -> u16 {
         self.crc
     }
@@ -92,8 +326,33 @@ impl Reader {
         self.crc == expected_crc
     }
 
+    /// Read the little endian CRC stored in the 2 bytes following the expected content, without
+    /// folding them into [Reader::current_crc]. Fails if the expected content has not been
+    /// entirely read, so that the running CRC is never compared to bytes within the content.
+    pub fn read_trailing_crc(&mut self) -> Result<u16, ReaderError> {
+        if !self.is_empty() {
+            return Err(ReaderError::UnreadContent(self.bytes_left_to_read));
+        }
+
+        if let Content::Stream(stream) = &mut self.content {
+            stream.held_back = 0;
+        }
+        let mut next = || self.content.next().ok_or(ReaderError::UnexpectedEof);
+        Ok(u16::from_le_bytes([next()?, next()?]))
+    }
+
+    /// Content following the bytes already read. A streamed content is read until its end and
+    /// collected, held back bytes included.
     pub fn remaining_content(self) -> std::vec::IntoIter<u8> {
-        self.content
+        match self.content {
+            Content::Bytes(bytes) => bytes,
+            Content::Stream(mut stream) => {
+                stream.held_back = 0;
+                std::iter::from_fn(|| stream.next())
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            }
+        }
     }
 }
 
@@ -102,7 +361,7 @@ const CRC_TABLE: [u16; 16] = [
     0x5000, 0x9C01, 0x8801, 0x4400,
 ];
 
-fn compute_crc(crc: &u16, byte: u8) -> u16 {
+pub(crate) fn compute_crc(crc: &u16, byte: u8) -> u16 {
     // Process lower 4 bits of byte
     let tmp = CRC_TABLE[(crc & 0xF) as usize];
     let mut crc = (*crc >> 4) & 0x0FFF;
@@ -136,7 +395,7 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(
             discriminant(&res.unwrap_err()),
-            discriminant(&ReaderError::ContentExhausted)
+            discriminant(&ReaderError::Eof)
         );
     }
 
@@ -154,7 +413,7 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(
             discriminant(&res.unwrap_err()),
-            discriminant(&ReaderError::ContentExhausted)
+            discriminant(&ReaderError::Eof)
         );
     }
 
@@ -169,7 +428,7 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(
             discriminant(&res.unwrap_err()),
-            discriminant(&ReaderError::ContentPrematurelyEmpty)
+            discriminant(&ReaderError::UnexpectedEof)
         );
     }
 
@@ -257,6 +516,19 @@ mod tests {
         assert_eq!(res.unwrap(), u64::from_le_bytes([17, 5, 0, 0, 17, 5, 0, 0]));
     }
 
+    #[test]
+    fn test_skip_bytes() {
+        let content = vec![1, 2, 3].into_iter();
+        let bytes_to_read = 3;
+
+        let mut reader = Reader::new(bytes_to_read, content);
+
+        assert!(reader.skip(2).is_ok());
+        assert_eq!(reader.bytes_left_to_read(), 1);
+        assert_eq!(reader.next_u8().unwrap(), 3);
+        assert!(reader.skip(1).is_err());
+    }
+
     #[test]
     fn test_reaader_is_empty() {
         let content = vec![0].into_iter();
@@ -268,4 +540,73 @@ mod tests {
         let _ = reader.next_u8();
         assert!(reader.is_empty());
     }
+
+    #[test]
+    fn test_read_value_at_clean_end_of_content() {
+        let mut reader = Reader::new(2, vec![1, 0, 0xAA, 0xBB].into_iter());
+
+        assert_eq!(reader.next_u16(&Endianness::Little).unwrap(), 1);
+        assert!(reader.is_empty());
+        assert!(matches!(
+            reader.next_u32(&Endianness::Little),
+            Err(ReaderError::Eof)
+        ));
+    }
+
+    #[test]
+    fn test_read_truncated_value() {
+        let mut reader = Reader::new(2, vec![1, 0, 0xAA, 0xBB].into_iter());
+
+        assert!(matches!(
+            reader.next_u32(&Endianness::Little),
+            Err(ReaderError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn test_read_value_cut_by_end_of_underlying_content() {
+        let mut reader = Reader::new(8, vec![1, 0].into_iter());
+
+        assert!(matches!(
+            reader.next_u64(&Endianness::Little),
+            Err(ReaderError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn test_read_trailing_crc_excludes_crc_bytes() {
+        let body = [1, 2, 3];
+        let crc = body.iter().fold(0, |crc, byte| compute_crc(&crc, *byte));
+        let mut content = body.to_vec();
+        content.extend_from_slice(&crc.to_le_bytes());
+
+        let mut reader = Reader::new(body.len() as u32, content.into_iter());
+        reader.skip(body.len() as u32).unwrap();
+
+        assert_eq!(reader.current_crc(), crc);
+        assert_eq!(reader.read_trailing_crc().unwrap(), crc);
+        assert_eq!(reader.current_crc(), crc);
+    }
+
+    #[test]
+    fn test_read_trailing_crc_before_end_of_content() {
+        let mut reader = Reader::new(3, vec![1, 2, 3, 0, 0].into_iter());
+        reader.skip(2).unwrap();
+
+        assert!(matches!(
+            reader.read_trailing_crc(),
+            Err(ReaderError::UnreadContent(1))
+        ));
+    }
+
+    #[test]
+    fn test_read_trailing_crc_missing_bytes() {
+        let mut reader = Reader::new(1, vec![1, 0].into_iter());
+        reader.skip(1).unwrap();
+
+        assert!(matches!(
+            reader.read_trailing_crc(),
+            Err(ReaderError::UnexpectedEof)
+        ));
+    }
 }
@@ -14,15 +14,19 @@ use crate::{
     },
 };
 
-#[derive(Debug)]
-enum RecordHeader {
+/// Header byte of a record, which tells how to decode the rest of the record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordHeader {
     Definition(DefinitionMessageHeader),
     Data(DataMessageHeader),
+    /// Data message whose timestamp is an offset from the last timestamp, see
+    /// [CompressedTimestamp].
     Compressed(CompressedMessageHeader),
 }
 
 impl RecordHeader {
-    fn from_byte(byte: u8) -> RecordHeader {
+    /// Decode the header byte of a record.
+    pub fn parse(byte: u8) -> RecordHeader {
         let normal = (byte >> 7) & 1 == 0;
         let data = (byte >> 6) & 1 == 0;
         match (normal, data) {
@@ -44,22 +48,40 @@ impl RecordHeader {
             }),
         }
     }
+
+    /// Encode the header back to its byte, values out of range of their bits are truncated.
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            RecordHeader::Definition(header) => {
+                0x40 | (u8::from(header.message_type_specific) << 5)
+                    | (header.local_message_type & 0x0F)
+            }
+            RecordHeader::Data(header) => header.local_message_type & 0x0F,
+            RecordHeader::Compressed(header) => {
+                0x80 | ((header.local_message_type & 0x03) << 5) | (header.time_offset & 0x1F)
+            }
+        }
+    }
 }
-#[derive(Debug)]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DefinitionMessageHeader {
+    /// Whether the definition has developer fields.
     pub message_type_specific: bool,
     pub local_message_type: u8,
 }
 
-#[derive(Debug)]
-struct DataMessageHeader {
-    local_message_type: u8,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataMessageHeader {
+    pub local_message_type: u8,
 }
 
-#[derive(Debug)]
-struct CompressedMessageHeader {
-    local_message_type: u8,
-    time_offset: u8,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedMessageHeader {
+    /// Only the 2 lower bits are available in a compressed timestamp header.
+    pub local_message_type: u8,
+    /// The 5 lower bits of the timestamp.
+    pub time_offset: u8,
 }
 
 #[derive(Error, Debug)]
@@ -78,6 +100,28 @@ pub enum RecordError {
     TimestampMissingForCompressedTimestamp,
     #[error("Trying to scale a value by")]
     ScaleByZeroError,
+    #[error("Invalid scale {0} or offset {1}, both must be finite")]
+    InvalidScaleOffset(f32, f32),
+    #[error("Definition announces {0} fields, more than the maximum of {1}")]
+    TooManyFields(usize, usize),
+}
+
+impl RecordError {
+    /// Whether the content ended cleanly, before the header of a record.
+    pub fn is_eof(&self) -> bool {
+        matches!(self, Self::ReaderError(ReaderError::Eof))
+    }
+
+    /// Error to report once the header of a record has been read: the end of the content then
+    /// means the record is truncated, see [ReaderError::truncated].
+    fn truncated(self) -> Self {
+        match self {
+            Self::ReaderError(err) | Self::DataTypeError(DataTypeError::ReaderError(err)) => {
+                Self::ReaderError(err.truncated())
+            }
+            err => err,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -104,12 +148,56 @@ impl DataMessage {
         }
         last_timestamp
     }
+
+    /// Value of the message's `timestamp` field, if its message type has one and it is set.
+    pub fn timestamp(&self) -> Option<u32> {
+        let timestamp_field = self.message_kind.timestamp_field()?;
+        self.fields
+            .iter()
+            .filter(|field| field.kind == timestamp_field)
+            .find_map(|field| match field.values.first() {
+                Some(DataValue::DateTime(timestamp)) => Some(*timestamp),
+                _ => None,
+            })
+    }
+
+    /// Iterate over all the values of the message as `(name, units, value)`, without having to
+    /// match on [FitField]. A field with several values yields one item per value.
+    pub fn fields_named(&self) -> impl Iterator<Item = (&str, Option<&str>, &DataValue)> {
+        self.fields.iter().flat_map(|field| {
+            field
+                .values
+                .iter()
+                .map(|value| (field.kind.name(), field.kind.units(), value))
+        })
+    }
+
+    /// All the values of the fields named `name`, in order: the elements of an array field and
+    /// the values of a field repeated in the message.
+    pub fn values(&self, name: &str) -> Vec<&DataValue> {
+        self.fields
+            .iter()
+            .filter(|field| field.kind.name() == name)
+            .flat_map(|field| field.values.iter())
+            .collect()
+    }
+
+    /// First value of the fields named `name`, see [DataMessage::values] to get all of them.
+    pub fn value(&self, name: &str) -> Option<&DataValue> {
+        self.fields
+            .iter()
+            .filter(|field| field.kind.name() == name)
+            .find_map(|field| field.values.first())
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct DataMessageField {
     pub kind: FitField,
     pub values: Vec<DataValue>,
+    /// Whether `values` had the scale and offset of the field applied, i.e. are not the raw values
+    /// stored in the file.
+    pub is_scaled: bool,
 }
 
 #[derive(Debug)]
@@ -125,7 +213,57 @@ impl Record {
         custom_descriptions: &HashMap<u8, HashMap<u8, CustomDescription>>,
         compressed_timestamp: &mut CompressedTimestamp,
     ) -> Result<Self, RecordError> {
-        let header = RecordHeader::from_byte(content.next_u8()?);
+        let header = RecordHeader::parse(content.next_u8()?);
+
+        match header {
+            RecordHeader::Data(header) => {
+                parse_data_message(header, definitions, content).map(Record::Data)
+            }
+
+            RecordHeader::Definition(header) => {
+                parse_definition_message(header, custom_descriptions, content)
+                    .map(Record::Definition)
+            }
+
+            RecordHeader::Compressed(header) => {
+                parse_compressed_message(header, definitions, compressed_timestamp, content)
+                    .map(Record::Data)
+            }
+        }
+        .map_err(RecordError::truncated)
+    }
+
+    /// Same as [Record::parse], but data records whose message type is not accepted by `decode`
+    /// are skipped according to the size of their definition, and `None` is returned. As skipped
+    /// records are not decoded, their timestamps are not used to rebuild compressed timestamps.
+    pub fn parse_or_skip(
+        content: &mut Reader,
+        definitions: &HashMap<u8, Definition>,
+        custom_descriptions: &HashMap<u8, HashMap<u8, CustomDescription>>,
+        compressed_timestamp: &mut CompressedTimestamp,
+        decode: &dyn Fn(&MesgNum) -> bool,
+    ) -> Result<Option<Self>, RecordError> {
+        let header = RecordHeader::parse(content.next_u8()?);
+
+        let local_message_type = match &header {
+            RecordHeader::Definition(_) => None,
+            RecordHeader::Data(header) => Some(header.local_message_type),
+            RecordHeader::Compressed(header) => Some(header.local_message_type),
+        };
+        if let Some(definition) =
+            local_message_type.and_then(|local_message_type| definitions.get(&local_message_type))
+            && !decode(&definition.message_type)
+        {
+            let size = definition
+                .fields
+                .iter()
+                .map(|field| field.size as u32)
+                .sum();
+            return content
+                .skip(size)
+                .map(|_| None)
+                .map_err(|err| RecordError::from(err).truncated());
+        }
 
         match header {
             RecordHeader::Data(header) => {
@@ -142,6 +280,8 @@ impl Record {
                     .map(Record::Data)
             }
         }
+        .map(Some)
+        .map_err(RecordError::truncated)
     }
 }
 
@@ -165,6 +305,7 @@ fn parse_data_message(
                     .flat_map(|val| val.apply_scale_offset(&field.scale_offset))
                     .collect(),
                 kind: field.kind.clone(),
+                is_scaled: field.scale_offset.is_some(),
             },
 
             ParseFunction::Dynamic(parse) => {
@@ -205,6 +346,7 @@ fn parse_compressed_message(
         fields.push(DataMessageField {
             kind: timestamp_field,
             values: vec![DataValue::DateTime(timestamp)],
+            is_scaled: false,
         });
     }
 
@@ -217,6 +359,7 @@ fn parse_compressed_message(
                     .flat_map(|val| val.apply_scale_offset(&field.scale_offset))
                     .collect(),
                 kind: field.kind.clone(),
+                is_scaled: field.scale_offset.is_some(),
             },
 
             ParseFunction::Dynamic(parse) => {
@@ -234,12 +377,38 @@ fn parse_compressed_message(
     })
 }
 
-#[derive(Debug, Default)]
+/// Bits of a compressed record header holding the time offset, i.e. the 5 least significant bits
+/// of the record's timestamp.
+pub const TIME_OFFSET_MASK: u32 = 0x1F;
+
+/// Period, in seconds, after which a time offset rolls over, the offset being stored on 5 bits.
+pub const TIME_OFFSET_ROLLOVER: u32 = TIME_OFFSET_MASK + 1;
+
+/// Rebuild the timestamps of compressed timestamp records from a base timestamp, with a 1 second
+/// resolution. The base is the timestamp of the last record that has one, either a full
+/// `timestamp` field or a timestamp rebuilt from a previous compressed record.
+#[derive(Debug, Clone, Default)]
 pub struct CompressedTimestamp {
     last_timestamp: Option<u32>,
 }
 
 impl CompressedTimestamp {
+    /// Timestamp the next time offset is relative to, `None` until a message with a timestamp has
+    /// been read.
+    pub fn base_timestamp(&self) -> Option<u32> {
+        self.last_timestamp
+    }
+
+    /// Update the base from a decoded message: its full `timestamp` field becomes the base, even
+    /// if earlier than the current one. Without one, the base is only moved forward to the most
+    /// recent date of the message, see [DataMessage::last_timestamp].
+    pub fn update(&mut self, message: &DataMessage) {
+        match message.timestamp() {
+            Some(timestamp) => self.last_timestamp = Some(timestamp),
+            None => self.set_last_timestamp(message.last_timestamp()),
+        }
+    }
+
     pub fn set_last_timestamp(&mut self, new_timestamp: Option<u32>) {
         match (self.last_timestamp, new_timestamp) {
             (Some(last), Some(new)) if new > last => {
@@ -251,16 +420,19 @@ impl CompressedTimestamp {
             _ => {}
         }
     }
+
+    /// Rebuild the timestamp of a compressed record from its 5 bits time offset, which then
+    /// becomes the base.
     pub fn parse_offset(&mut self, time_offset: u8) -> Option<u32> {
         // We compare the time_offset (5bits) to the 5 least significants bits of the last_timestamp
         // known. If they are lower then a rollover has happened and we add 0x20 to represent that.
         // In both cases we replace the 5 least significants bits of the last_timestamp with those of
-        // the time_offset.
+        // the time_offset. An offset equal to the base's bits is the same second, not a rollover.
         let last_timestamp = self.last_timestamp?;
-        let offset = time_offset as u32;
-        let mut new_timestamp = (last_timestamp & 0xFFFFFFE0) + offset;
-        if offset < (last_timestamp & 0x0000001F) {
-            new_timestamp += 0x20;
+        let offset = time_offset as u32 & TIME_OFFSET_MASK;
+        let mut new_timestamp = (last_timestamp & !TIME_OFFSET_MASK) + offset;
+        if offset < (last_timestamp & TIME_OFFSET_MASK) {
+            new_timestamp += TIME_OFFSET_ROLLOVER;
         }
 
         self.last_timestamp = Some(new_timestamp);
@@ -276,7 +448,9 @@ mod tests {
         parser::{
             Endianness,
             definition::DefinitionField,
-            types::generated::{Event, EventField, EventFieldDataSubfield, RecordField},
+            types::generated::{
+                CustomField, Event, EventField, EventFieldDataSubfield, RecordField,
+            },
         },
     };
 
@@ -290,6 +464,7 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::Record(RecordField::Timestamp),
                 values: vec![DataValue::DateTime(0)],
+                is_scaled: false,
             }],
         };
 
@@ -305,6 +480,7 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::Record(RecordField::Timestamp),
                 values: vec![DataValue::DateTime(0), DataValue::DateTime(3)],
+                is_scaled: false,
             }],
         };
 
@@ -321,10 +497,12 @@ mod tests {
                 DataMessageField {
                     kind: FitField::Record(RecordField::Timestamp),
                     values: vec![DataValue::DateTime(16)],
+                    is_scaled: false,
                 },
                 DataMessageField {
                     kind: FitField::Record(RecordField::Timestamp),
                     values: vec![DataValue::DateTime(0), DataValue::DateTime(3)],
+                    is_scaled: false,
                 },
             ],
         };
@@ -341,12 +519,143 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::Record(RecordField::Timestamp),
                 values: vec![DataValue::String("toto".to_string())],
+                is_scaled: false,
             }],
         };
 
         assert!(message_w_timestamp.last_timestamp().is_none());
     }
 
+    #[test]
+    fn test_data_message_fields_named() {
+        let message = DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Timestamp),
+                    values: vec![DataValue::DateTime(16)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Speed1s),
+                    values: vec![DataValue::Float32(1.), DataValue::Float32(2.)],
+                    is_scaled: true,
+                },
+                DataMessageField {
+                    kind: FitField::Custom(CustomField {
+                        name: Some("Glucose".to_string()),
+                        units: Some("mg/dL".to_string()),
+                        original_name: Some("Glucose".to_string()),
+                    }),
+                    values: vec![DataValue::Uint16(98)],
+                    is_scaled: false,
+                },
+            ],
+        };
+
+        let fields = message.fields_named().collect::<Vec<_>>();
+
+        assert_eq!(
+            fields.iter().map(|(name, _, _)| *name).collect::<Vec<_>>(),
+            vec!["timestamp", "speed_1s", "speed_1s", "Glucose"]
+        );
+        assert_eq!(fields[0], ("timestamp", None, &DataValue::DateTime(16)));
+        assert_eq!(
+            fields[3],
+            ("Glucose", Some("mg/dL"), &DataValue::Uint16(98))
+        );
+    }
+
+    #[test]
+    fn test_record_header_normal_data() {
+        let header = RecordHeader::parse(0b0000_0101);
+
+        assert_eq!(
+            header,
+            RecordHeader::Data(DataMessageHeader {
+                local_message_type: 5
+            })
+        );
+        assert_eq!(header.to_byte(), 0b0000_0101);
+    }
+
+    #[test]
+    fn test_record_header_definition() {
+        let header = RecordHeader::parse(0b0100_0011);
+        assert_eq!(
+            header,
+            RecordHeader::Definition(DefinitionMessageHeader {
+                message_type_specific: false,
+                local_message_type: 3
+            })
+        );
+        assert_eq!(header.to_byte(), 0b0100_0011);
+
+        // With developer fields
+        let header = RecordHeader::parse(0b0110_1111);
+        assert_eq!(
+            header,
+            RecordHeader::Definition(DefinitionMessageHeader {
+                message_type_specific: true,
+                local_message_type: 15
+            })
+        );
+        assert_eq!(header.to_byte(), 0b0110_1111);
+    }
+
+    #[test]
+    fn test_record_header_compressed_timestamp() {
+        let header = RecordHeader::parse(0b1101_0110);
+
+        assert_eq!(
+            header,
+            RecordHeader::Compressed(CompressedMessageHeader {
+                local_message_type: 2,
+                time_offset: 22
+            })
+        );
+        assert_eq!(header.to_byte(), 0b1101_0110);
+    }
+
+    #[test]
+    fn test_data_message_values_of_array_and_repeated_fields() {
+        let message = DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Speed1s),
+                    values: vec![DataValue::Float32(1.), DataValue::Float32(2.)],
+                    is_scaled: true,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::HeartRate),
+                    values: vec![DataValue::Uint8(140)],
+                    is_scaled: false,
+                },
+                DataMessageField {
+                    kind: FitField::Record(RecordField::Speed1s),
+                    values: vec![DataValue::Float32(3.)],
+                    is_scaled: true,
+                },
+            ],
+        };
+
+        assert_eq!(
+            message.values("speed_1s"),
+            vec![
+                &DataValue::Float32(1.),
+                &DataValue::Float32(2.),
+                &DataValue::Float32(3.)
+            ]
+        );
+        assert_eq!(message.value("speed_1s"), Some(&DataValue::Float32(1.)));
+        assert_eq!(message.values("heart_rate"), vec![&DataValue::Uint8(140)]);
+        assert!(message.values("power").is_empty());
+        assert_eq!(message.value("power"), None);
+    }
+
     #[test]
     fn test_parse_data_message_with_dynamic_fields_and_scale_offset() {
         let header = DataMessageHeader {
@@ -389,14 +698,16 @@ mod tests {
             *message.fields.first().unwrap(),
             DataMessageField {
                 kind: FitField::Event(EventField::Event),
-                values: vec![DataValue::Enum(FitEnum::Event(Event::SpeedHighAlert))]
+                values: vec![DataValue::Enum(FitEnum::Event(Event::SpeedHighAlert))],
+                is_scaled: false,
             }
         );
         assert_eq!(
             *message.fields.get(1).unwrap(),
             DataMessageField {
                 kind: FitField::Event(EventField::SpeedHighAlert),
-                values: vec![DataValue::Float32(0.051)] // Scale of 1000
+                values: vec![DataValue::Float32(0.051)], // Scale of 1000
+                is_scaled: true,
             }
         );
     }
@@ -443,14 +754,16 @@ mod tests {
             *message.fields.first().unwrap(),
             DataMessageField {
                 kind: FitField::Event(EventField::Event),
-                values: vec![DataValue::Enum(FitEnum::Event(Event::TankPressureCritical))]
+                values: vec![DataValue::Enum(FitEnum::Event(Event::TankPressureCritical))],
+                is_scaled: false,
             }
         );
         assert_eq!(
             *message.fields.get(1).unwrap(),
             DataMessageField {
                 kind: FitField::Event(EventField::Data),
-                values: vec![DataValue::Uint32(51)]
+                values: vec![DataValue::Uint32(51)],
+                is_scaled: false,
             }
         );
     }
@@ -459,6 +772,8 @@ mod tests {
 #[cfg(test)]
 mod tests_compressed_timestamp {
 
+    use crate::parser::types::generated::{RecordField, SessionField};
+
     use super::*;
 
     #[test]
@@ -479,4 +794,79 @@ mod tests_compressed_timestamp {
         assert_eq!(compressed.parse_offset(0b10010), Some(0x11111172));
         assert_eq!(compressed.parse_offset(0b00001), Some(0x11111181));
     }
+
+    #[test]
+    fn test_offset_equal_to_base_is_same_second() {
+        let mut compressed = CompressedTimestamp::default();
+        compressed.set_last_timestamp(Some(0x1000003F));
+
+        assert_eq!(compressed.parse_offset(0b11111), Some(0x1000003F));
+        assert_eq!(compressed.base_timestamp(), Some(0x1000003F));
+    }
+
+    #[test]
+    fn test_successive_rollovers() {
+        let mut compressed = CompressedTimestamp::default();
+        compressed.set_last_timestamp(Some(0x1000001E));
+
+        assert_eq!(compressed.parse_offset(0b11111), Some(0x1000001F));
+        assert_eq!(compressed.parse_offset(0b00000), Some(0x10000020));
+        assert_eq!(compressed.parse_offset(0b11111), Some(0x1000003F));
+        assert_eq!(compressed.parse_offset(0b00000), Some(0x10000040));
+        assert_eq!(compressed.base_timestamp(), Some(0x10000040));
+    }
+
+    #[test]
+    fn test_offset_bits_outside_mask_are_ignored() {
+        let mut compressed = CompressedTimestamp::default();
+        compressed.set_last_timestamp(Some(0x10000000));
+
+        assert_eq!(compressed.parse_offset(0b1110_0011), Some(0x10000003));
+    }
+
+    fn record_message(timestamp: u32) -> DataMessage {
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields: vec![DataMessageField {
+                kind: FitField::Record(RecordField::Timestamp),
+                values: vec![DataValue::DateTime(timestamp)],
+                is_scaled: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_full_timestamp_resets_base() {
+        let mut compressed = CompressedTimestamp::default();
+        compressed.update(&record_message(0x10000010));
+        assert_eq!(compressed.parse_offset(0b10100), Some(0x10000014));
+
+        // An earlier full timestamp still becomes the base
+        compressed.update(&record_message(0x10000008));
+        assert_eq!(compressed.base_timestamp(), Some(0x10000008));
+        assert_eq!(compressed.parse_offset(0b01010), Some(0x1000000A));
+    }
+
+    #[test]
+    fn test_message_without_timestamp_field_only_moves_base_forward() {
+        let mut compressed = CompressedTimestamp::default();
+        compressed.update(&record_message(0x10000010));
+
+        let mut message = DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Session,
+            fields: vec![DataMessageField {
+                kind: FitField::Session(SessionField::StartTime),
+                values: vec![DataValue::DateTime(0x10000001)],
+                is_scaled: false,
+            }],
+        };
+        compressed.update(&message);
+        assert_eq!(compressed.base_timestamp(), Some(0x10000010));
+
+        message.fields[0].values = vec![DataValue::DateTime(0x10000020)];
+        compressed.update(&message);
+        assert_eq!(compressed.base_timestamp(), Some(0x10000020));
+    }
 }
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::{
+    MesgNum,
+    parser::{
+        DataMessage, FitParserError, Record,
+        definition::custom::parse_custom_definition_description,
+        header::{FileHeader, HEADER_SIZE_WITH_CRC},
+        reader::Reader,
+        records::CompressedTimestamp,
+    },
+};
+
+/// Kinds of the [DataMessage]s returned by [parse_summary].
+pub const SUMMARY_MESSAGES: [MesgNum; 2] = [MesgNum::Session, MesgNum::Activity];
+
+/// Decode only the summary messages of a .FIT file (see [SUMMARY_MESSAGES]), which is much
+/// cheaper than a full parse when the records are not needed, e.g. to list activities.
+///
+/// The summary messages usually come after the records, and definitions of later messages can
+/// interleave with them, so the whole file is read: definitions and developer fields
+/// descriptions are decoded, other data records are skipped according to the size of their
+/// definition. Fields whose values are all invalid are removed and the body CRC is not checked.
+pub fn parse_summary(bytes: Vec<u8>) -> Result<Vec<DataMessage>, FitParserError> {
+    let mut header_reader = Reader::new(HEADER_SIZE_WITH_CRC as u32, bytes.into_iter());
+    let header = FileHeader::from_bytes(&mut header_reader)?;
+    let mut reader = Reader::new(header.data_size, header_reader.remaining_content());
+
+    let decode = |kind: &MesgNum| {
+        SUMMARY_MESSAGES.contains(kind)
+            || matches!(kind, MesgNum::FieldDescription | MesgNum::DeveloperDataId)
+    };
+    let mut definitions = HashMap::new();
+    let mut custom_descriptions = HashMap::new();
+    let mut compressed_timestamp = CompressedTimestamp::default();
+    let mut messages = Vec::new();
+    loop {
+        let record = match Record::parse_or_skip(
+            &mut reader,
+            &definitions,
+            &custom_descriptions,
+            &mut compressed_timestamp,
+            &decode,
+        ) {
+            Ok(record) => record,
+            // The body ends cleanly after its last record, a truncated record is an error
+            Err(err) if err.is_eof() => break,
+            Err(err) => return Err(err.into()),
+        };
+
+        match record {
+            Some(Record::Definition(definition)) => {
+                definitions.insert(definition.local_message_type, definition);
+            }
+            Some(Record::Data(mut data)) => {
+                parse_custom_definition_description(
+                    &data,
+                    &definitions,
+                    &mut custom_descriptions,
+                    false,
+                );
+                compressed_timestamp.update(&data);
+                data.fields
+                    .retain(|field| !field.values.iter().all(|value| value.is_invalid()));
+                if SUMMARY_MESSAGES.contains(&data.message_kind) {
+                    messages.push(data);
+                }
+            }
+            None => {}
+        }
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        DataValue, FitEnum, FitField, SessionField, Sport,
+        parser::{
+            parse_fit_messages,
+            test_utils::{build_fit_file, data_record, definition_record, set_data_size},
+        },
+    };
+
+    use super::*;
+
+    /// A file with `records` record messages, followed by a session and an activity message.
+    fn activity_file(records: u32) -> Vec<u8> {
+        let mut content = definition_record(0, 20, &[(253, 4, 0x86), (3, 1, 0x02), (7, 2, 0x84)]);
+        for timestamp in 1000..1000 + records {
+            let mut payload = timestamp.to_le_bytes().to_vec();
+            payload.push(140);
+            payload.extend_from_slice(&200_u16.to_le_bytes());
+            content.extend(data_record(0, &payload));
+        }
+
+        // Summary messages reuse local message type 0
+        content.extend(definition_record(0, 18, &[(253, 4, 0x86), (5, 1, 0x00)]));
+        let mut payload = (1000 + records).to_le_bytes().to_vec();
+        payload.push(2); // Cycling
+        content.extend(data_record(0, &payload));
+        content.extend(definition_record(1, 34, &[(253, 4, 0x86), (1, 2, 0x84)]));
+        let mut payload = (1000 + records).to_le_bytes().to_vec();
+        payload.extend_from_slice(&1_u16.to_le_bytes());
+        content.extend(data_record(1, &payload));
+
+        build_fit_file(&content)
+    }
+
+    #[test]
+    fn test_parse_summary_returns_summary_messages_only() {
+        let content = activity_file(100);
+
+        let summary = parse_summary(content.clone()).unwrap();
+
+        let expected: Vec<DataMessage> = parse_fit_messages(content.into_iter(), false)
+            .unwrap()
+            .into_iter()
+            .filter(|message| SUMMARY_MESSAGES.contains(&message.message_kind))
+            .collect();
+        assert_eq!(summary, expected);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].message_kind, MesgNum::Session);
+        assert!(summary[0].fields.iter().any(|field| {
+            field.kind == FitField::Session(SessionField::Sport)
+                && field.values == [DataValue::Enum(FitEnum::Sport(Sport::Cycling))]
+        }));
+        assert_eq!(summary[1].message_kind, MesgNum::Activity);
+    }
+
+    #[test]
+    fn test_parse_summary_truncated_record() {
+        let mut content = activity_file(10);
+        // Cut the activity message, keeping a valid header
+        let data_size = content.len() as u32 - 14 - 2 - 3;
+        content.truncate(content.len() - 5);
+        set_data_size(&mut content, data_size);
+
+        assert!(parse_summary(content).is_err());
+    }
+
+    #[test]
+    fn test_parse_summary_is_faster_than_full_parse() {
+        let content = activity_file(20_000);
+
+        let time = |parse: &dyn Fn(Vec<u8>)| {
+            (0..3)
+                .map(|_| {
+                    let start = Instant::now();
+                    parse(content.clone());
+                    start.elapsed()
+                })
+                .min()
+                .unwrap_or(Duration::MAX)
+        };
+        let summary = time(&|content| {
+            parse_summary(content).unwrap();
+        });
+        let full = time(&|content| {
+            parse_fit_messages(content.into_iter(), false).unwrap();
+        });
+
+        assert!(summary < full, "{summary:?} vs {full:?}");
+    }
+}
@@ -0,0 +1,52 @@
+//! Helpers to build small .FIT files byte by byte in tests.
+
+use crate::parser::{header::HEADER_SIZE_WITH_CRC, reader::compute_crc};
+
+fn crc(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0, |crc, byte| compute_crc(&crc, *byte))
+}
+
+/// Wrap `records` into a valid .FIT file: a 14 bytes header with its CRC, the records and the
+/// trailing body CRC.
+pub fn build_fit_file(records: &[u8]) -> Vec<u8> {
+    let mut content = vec![HEADER_SIZE_WITH_CRC, 0x20];
+    content.extend_from_slice(&2132_u16.to_le_bytes()); // Profile version
+    content.extend_from_slice(&(records.len() as u32).to_le_bytes());
+    content.extend_from_slice(b".FIT");
+    content.extend_from_slice(&crc(&content).to_le_bytes());
+
+    content.extend_from_slice(records);
+    content.extend_from_slice(&crc(records).to_le_bytes());
+
+    content
+}
+
+/// Overwrite the `data_size` of a file built with [build_fit_file], keeping its header CRC valid.
+pub fn set_data_size(content: &mut [u8], data_size: u32) {
+    content[4..8].copy_from_slice(&data_size.to_le_bytes());
+    let header_crc = crc(&content[0..12]);
+    content[12..14].copy_from_slice(&header_crc.to_le_bytes());
+}
+
+/// Little endian definition record for `local_message_type`, with `fields` given as
+/// `(definition_number, size, base_type)`.
+pub fn definition_record(
+    local_message_type: u8,
+    global_message_number: u16,
+    fields: &[(u8, u8, u8)],
+) -> Vec<u8> {
+    let mut record = vec![0x40 | local_message_type, 0, 0];
+    record.extend_from_slice(&global_message_number.to_le_bytes());
+    record.push(fields.len() as u8);
+    for (definition_number, size, base_type) in fields {
+        record.extend_from_slice(&[*definition_number, *size, *base_type]);
+    }
+    record
+}
+
+/// Data record for `local_message_type` with an already encoded `payload`.
+pub fn data_record(local_message_type: u8, payload: &[u8]) -> Vec<u8> {
+    let mut record = vec![local_message_type & 0x0F];
+    record.extend_from_slice(payload);
+    record
+}
@@ -14,10 +14,10 @@ use crate::parser::definition::Endianness;
 use crate::parser::reader::Reader;
 use crate::parser::records::DataMessageField;
 use crate::parser::types::{
-    DataTypeError, DataValue, ScaleOffset, parse_byte_array as parse_byte, parse_float32,
-    parse_float64, parse_sint8, parse_sint16, parse_sint32, parse_sint64, parse_string,
-    parse_uint8, parse_uint8z, parse_uint16, parse_uint16z, parse_uint32, parse_uint32z,
-    parse_uint64, parse_uint64z, parse_unknown,
+    DataTypeError, DataValue, ScaleOffset, UnknownVariantError, check_number_of_values,
+    parse_byte_array as parse_byte, parse_float32, parse_float64, parse_sint8, parse_sint16,
+    parse_sint32, parse_sint64, parse_string, parse_uint8, parse_uint8z, parse_uint16,
+    parse_uint16z, parse_uint32, parse_uint32z, parse_uint64, parse_uint64z, parse_unknown,
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -149,7 +149,7 @@ pub enum FitEnum {
     NoFlyTimeMode(NoFlyTimeMode),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum File {
     Device,
     Settings,
@@ -201,17 +201,68 @@ impl File {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::File(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [File] {
+        &[
+            File::Device,
+            File::Settings,
+            File::Sport,
+            File::Activity,
+            File::Workout,
+            File::Course,
+            File::Schedules,
+            File::Weight,
+            File::Totals,
+            File::Goals,
+            File::BloodPressure,
+            File::MonitoringA,
+            File::ActivitySummary,
+            File::MonitoringDaily,
+            File::MonitoringB,
+            File::Segment,
+            File::SegmentList,
+            File::ExdConfiguration,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Device => "Device",
+            Self::Settings => "Settings",
+            Self::Sport => "Sport",
+            Self::Activity => "Activity",
+            Self::Workout => "Workout",
+            Self::Course => "Course",
+            Self::Schedules => "Schedules",
+            Self::Weight => "Weight",
+            Self::Totals => "Totals",
+            Self::Goals => "Goals",
+            Self::BloodPressure => "BloodPressure",
+            Self::MonitoringA => "MonitoringA",
+            Self::ActivitySummary => "ActivitySummary",
+            Self::MonitoringDaily => "MonitoringDaily",
+            Self::MonitoringB => "MonitoringB",
+            Self::Segment => "Segment",
+            Self::SegmentList => "SegmentList",
+            Self::ExdConfiguration => "ExdConfiguration",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MesgNum {
     FileId,
     Capabilities,
@@ -335,6 +386,36 @@ pub enum MesgNum {
     HsaWristTemperatureData,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for File {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [File::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Device" => Ok(Self::Device),
+            "Settings" => Ok(Self::Settings),
+            "Sport" => Ok(Self::Sport),
+            "Activity" => Ok(Self::Activity),
+            "Workout" => Ok(Self::Workout),
+            "Course" => Ok(Self::Course),
+            "Schedules" => Ok(Self::Schedules),
+            "Weight" => Ok(Self::Weight),
+            "Totals" => Ok(Self::Totals),
+            "Goals" => Ok(Self::Goals),
+            "BloodPressure" => Ok(Self::BloodPressure),
+            "MonitoringA" => Ok(Self::MonitoringA),
+            "ActivitySummary" => Ok(Self::ActivitySummary),
+            "MonitoringDaily" => Ok(Self::MonitoringDaily),
+            "MonitoringB" => Ok(Self::MonitoringB),
+            "Segment" => Ok(Self::Segment),
+            "SegmentList" => Ok(Self::SegmentList),
+            "ExdConfiguration" => Ok(Self::ExdConfiguration),
+            _ => Err(UnknownVariantError::new("File", name)),
+        }
+    }
+}
 impl MesgNum {
     pub fn from(content: u16) -> MesgNum {
         match content {
@@ -467,14 +548,270 @@ impl MesgNum {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MesgNum(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MesgNum] {
+        &[
+            MesgNum::FileId,
+            MesgNum::Capabilities,
+            MesgNum::DeviceSettings,
+            MesgNum::UserProfile,
+            MesgNum::HrmProfile,
+            MesgNum::SdmProfile,
+            MesgNum::BikeProfile,
+            MesgNum::ZonesTarget,
+            MesgNum::HrZone,
+            MesgNum::PowerZone,
+            MesgNum::MetZone,
+            MesgNum::Sport,
+            MesgNum::TrainingSettings,
+            MesgNum::Goal,
+            MesgNum::Session,
+            MesgNum::Lap,
+            MesgNum::Record,
+            MesgNum::Event,
+            MesgNum::DeviceInfo,
+            MesgNum::Workout,
+            MesgNum::WorkoutStep,
+            MesgNum::Schedule,
+            MesgNum::WeightScale,
+            MesgNum::Course,
+            MesgNum::CoursePoint,
+            MesgNum::Totals,
+            MesgNum::Activity,
+            MesgNum::Software,
+            MesgNum::FileCapabilities,
+            MesgNum::MesgCapabilities,
+            MesgNum::FieldCapabilities,
+            MesgNum::FileCreator,
+            MesgNum::BloodPressure,
+            MesgNum::SpeedZone,
+            MesgNum::Monitoring,
+            MesgNum::TrainingFile,
+            MesgNum::Hrv,
+            MesgNum::AntRx,
+            MesgNum::AntTx,
+            MesgNum::AntChannelId,
+            MesgNum::Length,
+            MesgNum::MonitoringInfo,
+            MesgNum::SlaveDevice,
+            MesgNum::Connectivity,
+            MesgNum::WeatherConditions,
+            MesgNum::WeatherAlert,
+            MesgNum::CadenceZone,
+            MesgNum::Hr,
+            MesgNum::SegmentLap,
+            MesgNum::MemoGlob,
+            MesgNum::SegmentId,
+            MesgNum::SegmentLeaderboardEntry,
+            MesgNum::SegmentPoint,
+            MesgNum::SegmentFile,
+            MesgNum::WorkoutSession,
+            MesgNum::WatchfaceSettings,
+            MesgNum::GpsMetadata,
+            MesgNum::CameraEvent,
+            MesgNum::TimestampCorrelation,
+            MesgNum::GyroscopeData,
+            MesgNum::AccelerometerData,
+            MesgNum::ThreeDSensorCalibration,
+            MesgNum::VideoFrame,
+            MesgNum::ObdiiData,
+            MesgNum::NmeaSentence,
+            MesgNum::AviationAttitude,
+            MesgNum::Video,
+            MesgNum::VideoTitle,
+            MesgNum::VideoDescription,
+            MesgNum::VideoClip,
+            MesgNum::OhrSettings,
+            MesgNum::ExdScreenConfiguration,
+            MesgNum::ExdDataFieldConfiguration,
+            MesgNum::ExdDataConceptConfiguration,
+            MesgNum::FieldDescription,
+            MesgNum::DeveloperDataId,
+            MesgNum::MagnetometerData,
+            MesgNum::BarometerData,
+            MesgNum::OneDSensorCalibration,
+            MesgNum::MonitoringHrData,
+            MesgNum::TimeInZone,
+            MesgNum::Set,
+            MesgNum::StressLevel,
+            MesgNum::MaxMetData,
+            MesgNum::DiveSettings,
+            MesgNum::DiveGas,
+            MesgNum::DiveAlarm,
+            MesgNum::ExerciseTitle,
+            MesgNum::DiveSummary,
+            MesgNum::Spo2Data,
+            MesgNum::SleepLevel,
+            MesgNum::Jump,
+            MesgNum::AadAccelFeatures,
+            MesgNum::BeatIntervals,
+            MesgNum::RespirationRate,
+            MesgNum::HsaAccelerometerData,
+            MesgNum::HsaStepData,
+            MesgNum::HsaSpo2Data,
+            MesgNum::HsaStressData,
+            MesgNum::HsaRespirationData,
+            MesgNum::HsaHeartRateData,
+            MesgNum::Split,
+            MesgNum::SplitSummary,
+            MesgNum::HsaBodyBatteryData,
+            MesgNum::HsaEvent,
+            MesgNum::ClimbPro,
+            MesgNum::TankUpdate,
+            MesgNum::TankSummary,
+            MesgNum::SleepAssessment,
+            MesgNum::HrvStatusSummary,
+            MesgNum::HrvValue,
+            MesgNum::RawBbi,
+            MesgNum::DeviceAuxBatteryInfo,
+            MesgNum::HsaGyroscopeData,
+            MesgNum::ChronoShotSession,
+            MesgNum::ChronoShotData,
+            MesgNum::HsaConfigurationData,
+            MesgNum::DiveApneaAlarm,
+            MesgNum::SkinTempOvernight,
+            MesgNum::HsaWristTemperatureData,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FileId => "FileId",
+            Self::Capabilities => "Capabilities",
+            Self::DeviceSettings => "DeviceSettings",
+            Self::UserProfile => "UserProfile",
+            Self::HrmProfile => "HrmProfile",
+            Self::SdmProfile => "SdmProfile",
+            Self::BikeProfile => "BikeProfile",
+            Self::ZonesTarget => "ZonesTarget",
+            Self::HrZone => "HrZone",
+            Self::PowerZone => "PowerZone",
+            Self::MetZone => "MetZone",
+            Self::Sport => "Sport",
+            Self::TrainingSettings => "TrainingSettings",
+            Self::Goal => "Goal",
+            Self::Session => "Session",
+            Self::Lap => "Lap",
+            Self::Record => "Record",
+            Self::Event => "Event",
+            Self::DeviceInfo => "DeviceInfo",
+            Self::Workout => "Workout",
+            Self::WorkoutStep => "WorkoutStep",
+            Self::Schedule => "Schedule",
+            Self::WeightScale => "WeightScale",
+            Self::Course => "Course",
+            Self::CoursePoint => "CoursePoint",
+            Self::Totals => "Totals",
+            Self::Activity => "Activity",
+            Self::Software => "Software",
+            Self::FileCapabilities => "FileCapabilities",
+            Self::MesgCapabilities => "MesgCapabilities",
+            Self::FieldCapabilities => "FieldCapabilities",
+            Self::FileCreator => "FileCreator",
+            Self::BloodPressure => "BloodPressure",
+            Self::SpeedZone => "SpeedZone",
+            Self::Monitoring => "Monitoring",
+            Self::TrainingFile => "TrainingFile",
+            Self::Hrv => "Hrv",
+            Self::AntRx => "AntRx",
+            Self::AntTx => "AntTx",
+            Self::AntChannelId => "AntChannelId",
+            Self::Length => "Length",
+            Self::MonitoringInfo => "MonitoringInfo",
+            Self::SlaveDevice => "SlaveDevice",
+            Self::Connectivity => "Connectivity",
+            Self::WeatherConditions => "WeatherConditions",
+            Self::WeatherAlert => "WeatherAlert",
+            Self::CadenceZone => "CadenceZone",
+            Self::Hr => "Hr",
+            Self::SegmentLap => "SegmentLap",
+            Self::MemoGlob => "MemoGlob",
+            Self::SegmentId => "SegmentId",
+            Self::SegmentLeaderboardEntry => "SegmentLeaderboardEntry",
+            Self::SegmentPoint => "SegmentPoint",
+            Self::SegmentFile => "SegmentFile",
+            Self::WorkoutSession => "WorkoutSession",
+            Self::WatchfaceSettings => "WatchfaceSettings",
+            Self::GpsMetadata => "GpsMetadata",
+            Self::CameraEvent => "CameraEvent",
+            Self::TimestampCorrelation => "TimestampCorrelation",
+            Self::GyroscopeData => "GyroscopeData",
+            Self::AccelerometerData => "AccelerometerData",
+            Self::ThreeDSensorCalibration => "ThreeDSensorCalibration",
+            Self::VideoFrame => "VideoFrame",
+            Self::ObdiiData => "ObdiiData",
+            Self::NmeaSentence => "NmeaSentence",
+            Self::AviationAttitude => "AviationAttitude",
+            Self::Video => "Video",
+            Self::VideoTitle => "VideoTitle",
+            Self::VideoDescription => "VideoDescription",
+            Self::VideoClip => "VideoClip",
+            Self::OhrSettings => "OhrSettings",
+            Self::ExdScreenConfiguration => "ExdScreenConfiguration",
+            Self::ExdDataFieldConfiguration => "ExdDataFieldConfiguration",
+            Self::ExdDataConceptConfiguration => "ExdDataConceptConfiguration",
+            Self::FieldDescription => "FieldDescription",
+            Self::DeveloperDataId => "DeveloperDataId",
+            Self::MagnetometerData => "MagnetometerData",
+            Self::BarometerData => "BarometerData",
+            Self::OneDSensorCalibration => "OneDSensorCalibration",
+            Self::MonitoringHrData => "MonitoringHrData",
+            Self::TimeInZone => "TimeInZone",
+            Self::Set => "Set",
+            Self::StressLevel => "StressLevel",
+            Self::MaxMetData => "MaxMetData",
+            Self::DiveSettings => "DiveSettings",
+            Self::DiveGas => "DiveGas",
+            Self::DiveAlarm => "DiveAlarm",
+            Self::ExerciseTitle => "ExerciseTitle",
+            Self::DiveSummary => "DiveSummary",
+            Self::Spo2Data => "Spo2Data",
+            Self::SleepLevel => "SleepLevel",
+            Self::Jump => "Jump",
+            Self::AadAccelFeatures => "AadAccelFeatures",
+            Self::BeatIntervals => "BeatIntervals",
+            Self::RespirationRate => "RespirationRate",
+            Self::HsaAccelerometerData => "HsaAccelerometerData",
+            Self::HsaStepData => "HsaStepData",
+            Self::HsaSpo2Data => "HsaSpo2Data",
+            Self::HsaStressData => "HsaStressData",
+            Self::HsaRespirationData => "HsaRespirationData",
+            Self::HsaHeartRateData => "HsaHeartRateData",
+            Self::Split => "Split",
+            Self::SplitSummary => "SplitSummary",
+            Self::HsaBodyBatteryData => "HsaBodyBatteryData",
+            Self::HsaEvent => "HsaEvent",
+            Self::ClimbPro => "ClimbPro",
+            Self::TankUpdate => "TankUpdate",
+            Self::TankSummary => "TankSummary",
+            Self::SleepAssessment => "SleepAssessment",
+            Self::HrvStatusSummary => "HrvStatusSummary",
+            Self::HrvValue => "HrvValue",
+            Self::RawBbi => "RawBbi",
+            Self::DeviceAuxBatteryInfo => "DeviceAuxBatteryInfo",
+            Self::HsaGyroscopeData => "HsaGyroscopeData",
+            Self::ChronoShotSession => "ChronoShotSession",
+            Self::ChronoShotData => "ChronoShotData",
+            Self::HsaConfigurationData => "HsaConfigurationData",
+            Self::DiveApneaAlarm => "DiveApneaAlarm",
+            Self::SkinTempOvernight => "SkinTempOvernight",
+            Self::HsaWristTemperatureData => "HsaWristTemperatureData",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+
     pub fn message_field(&self, def_number: u8) -> FitField {
         match self {
             Self::FileId => FitField::FileId(FileIdField::from(def_number)),
@@ -1076,7 +1413,139 @@ impl MesgNum {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl std::str::FromStr for MesgNum {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MesgNum::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "FileId" => Ok(Self::FileId),
+            "Capabilities" => Ok(Self::Capabilities),
+            "DeviceSettings" => Ok(Self::DeviceSettings),
+            "UserProfile" => Ok(Self::UserProfile),
+            "HrmProfile" => Ok(Self::HrmProfile),
+            "SdmProfile" => Ok(Self::SdmProfile),
+            "BikeProfile" => Ok(Self::BikeProfile),
+            "ZonesTarget" => Ok(Self::ZonesTarget),
+            "HrZone" => Ok(Self::HrZone),
+            "PowerZone" => Ok(Self::PowerZone),
+            "MetZone" => Ok(Self::MetZone),
+            "Sport" => Ok(Self::Sport),
+            "TrainingSettings" => Ok(Self::TrainingSettings),
+            "Goal" => Ok(Self::Goal),
+            "Session" => Ok(Self::Session),
+            "Lap" => Ok(Self::Lap),
+            "Record" => Ok(Self::Record),
+            "Event" => Ok(Self::Event),
+            "DeviceInfo" => Ok(Self::DeviceInfo),
+            "Workout" => Ok(Self::Workout),
+            "WorkoutStep" => Ok(Self::WorkoutStep),
+            "Schedule" => Ok(Self::Schedule),
+            "WeightScale" => Ok(Self::WeightScale),
+            "Course" => Ok(Self::Course),
+            "CoursePoint" => Ok(Self::CoursePoint),
+            "Totals" => Ok(Self::Totals),
+            "Activity" => Ok(Self::Activity),
+            "Software" => Ok(Self::Software),
+            "FileCapabilities" => Ok(Self::FileCapabilities),
+            "MesgCapabilities" => Ok(Self::MesgCapabilities),
+            "FieldCapabilities" => Ok(Self::FieldCapabilities),
+            "FileCreator" => Ok(Self::FileCreator),
+            "BloodPressure" => Ok(Self::BloodPressure),
+            "SpeedZone" => Ok(Self::SpeedZone),
+            "Monitoring" => Ok(Self::Monitoring),
+            "TrainingFile" => Ok(Self::TrainingFile),
+            "Hrv" => Ok(Self::Hrv),
+            "AntRx" => Ok(Self::AntRx),
+            "AntTx" => Ok(Self::AntTx),
+            "AntChannelId" => Ok(Self::AntChannelId),
+            "Length" => Ok(Self::Length),
+            "MonitoringInfo" => Ok(Self::MonitoringInfo),
+            "SlaveDevice" => Ok(Self::SlaveDevice),
+            "Connectivity" => Ok(Self::Connectivity),
+            "WeatherConditions" => Ok(Self::WeatherConditions),
+            "WeatherAlert" => Ok(Self::WeatherAlert),
+            "CadenceZone" => Ok(Self::CadenceZone),
+            "Hr" => Ok(Self::Hr),
+            "SegmentLap" => Ok(Self::SegmentLap),
+            "MemoGlob" => Ok(Self::MemoGlob),
+            "SegmentId" => Ok(Self::SegmentId),
+            "SegmentLeaderboardEntry" => Ok(Self::SegmentLeaderboardEntry),
+            "SegmentPoint" => Ok(Self::SegmentPoint),
+            "SegmentFile" => Ok(Self::SegmentFile),
+            "WorkoutSession" => Ok(Self::WorkoutSession),
+            "WatchfaceSettings" => Ok(Self::WatchfaceSettings),
+            "GpsMetadata" => Ok(Self::GpsMetadata),
+            "CameraEvent" => Ok(Self::CameraEvent),
+            "TimestampCorrelation" => Ok(Self::TimestampCorrelation),
+            "GyroscopeData" => Ok(Self::GyroscopeData),
+            "AccelerometerData" => Ok(Self::AccelerometerData),
+            "ThreeDSensorCalibration" => Ok(Self::ThreeDSensorCalibration),
+            "VideoFrame" => Ok(Self::VideoFrame),
+            "ObdiiData" => Ok(Self::ObdiiData),
+            "NmeaSentence" => Ok(Self::NmeaSentence),
+            "AviationAttitude" => Ok(Self::AviationAttitude),
+            "Video" => Ok(Self::Video),
+            "VideoTitle" => Ok(Self::VideoTitle),
+            "VideoDescription" => Ok(Self::VideoDescription),
+            "VideoClip" => Ok(Self::VideoClip),
+            "OhrSettings" => Ok(Self::OhrSettings),
+            "ExdScreenConfiguration" => Ok(Self::ExdScreenConfiguration),
+            "ExdDataFieldConfiguration" => Ok(Self::ExdDataFieldConfiguration),
+            "ExdDataConceptConfiguration" => Ok(Self::ExdDataConceptConfiguration),
+            "FieldDescription" => Ok(Self::FieldDescription),
+            "DeveloperDataId" => Ok(Self::DeveloperDataId),
+            "MagnetometerData" => Ok(Self::MagnetometerData),
+            "BarometerData" => Ok(Self::BarometerData),
+            "OneDSensorCalibration" => Ok(Self::OneDSensorCalibration),
+            "MonitoringHrData" => Ok(Self::MonitoringHrData),
+            "TimeInZone" => Ok(Self::TimeInZone),
+            "Set" => Ok(Self::Set),
+            "StressLevel" => Ok(Self::StressLevel),
+            "MaxMetData" => Ok(Self::MaxMetData),
+            "DiveSettings" => Ok(Self::DiveSettings),
+            "DiveGas" => Ok(Self::DiveGas),
+            "DiveAlarm" => Ok(Self::DiveAlarm),
+            "ExerciseTitle" => Ok(Self::ExerciseTitle),
+            "DiveSummary" => Ok(Self::DiveSummary),
+            "Spo2Data" => Ok(Self::Spo2Data),
+            "SleepLevel" => Ok(Self::SleepLevel),
+            "Jump" => Ok(Self::Jump),
+            "AadAccelFeatures" => Ok(Self::AadAccelFeatures),
+            "BeatIntervals" => Ok(Self::BeatIntervals),
+            "RespirationRate" => Ok(Self::RespirationRate),
+            "HsaAccelerometerData" => Ok(Self::HsaAccelerometerData),
+            "HsaStepData" => Ok(Self::HsaStepData),
+            "HsaSpo2Data" => Ok(Self::HsaSpo2Data),
+            "HsaStressData" => Ok(Self::HsaStressData),
+            "HsaRespirationData" => Ok(Self::HsaRespirationData),
+            "HsaHeartRateData" => Ok(Self::HsaHeartRateData),
+            "Split" => Ok(Self::Split),
+            "SplitSummary" => Ok(Self::SplitSummary),
+            "HsaBodyBatteryData" => Ok(Self::HsaBodyBatteryData),
+            "HsaEvent" => Ok(Self::HsaEvent),
+            "ClimbPro" => Ok(Self::ClimbPro),
+            "TankUpdate" => Ok(Self::TankUpdate),
+            "TankSummary" => Ok(Self::TankSummary),
+            "SleepAssessment" => Ok(Self::SleepAssessment),
+            "HrvStatusSummary" => Ok(Self::HrvStatusSummary),
+            "HrvValue" => Ok(Self::HrvValue),
+            "RawBbi" => Ok(Self::RawBbi),
+            "DeviceAuxBatteryInfo" => Ok(Self::DeviceAuxBatteryInfo),
+            "HsaGyroscopeData" => Ok(Self::HsaGyroscopeData),
+            "ChronoShotSession" => Ok(Self::ChronoShotSession),
+            "ChronoShotData" => Ok(Self::ChronoShotData),
+            "HsaConfigurationData" => Ok(Self::HsaConfigurationData),
+            "DiveApneaAlarm" => Ok(Self::DiveApneaAlarm),
+            "SkinTempOvernight" => Ok(Self::SkinTempOvernight),
+            "HsaWristTemperatureData" => Ok(Self::HsaWristTemperatureData),
+            _ => Err(UnknownVariantError::new("MesgNum", name)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum FileFlags {
     Read,
     Write,
@@ -1098,23 +1567,55 @@ impl FileFlags {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::FileFlags(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [FileFlags] {
+        &[FileFlags::Read, FileFlags::Write, FileFlags::Erase]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "Read",
+            Self::Write => "Write",
+            Self::Erase => "Erase",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MesgCount {
     NumPerFile,
     MaxPerFile,
     MaxPerFileType,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for FileFlags {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [FileFlags::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Read" => Ok(Self::Read),
+            "Write" => Ok(Self::Write),
+            "Erase" => Ok(Self::Erase),
+            _ => Err(UnknownVariantError::new("FileFlags", name)),
+        }
+    }
+}
 impl MesgCount {
     pub fn from(content: u8) -> MesgCount {
         match content {
@@ -1130,14 +1631,35 @@ impl MesgCount {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MesgCount(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MesgCount] {
+        &[
+            MesgCount::NumPerFile,
+            MesgCount::MaxPerFile,
+            MesgCount::MaxPerFileType,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NumPerFile => "NumPerFile",
+            Self::MaxPerFile => "MaxPerFile",
+            Self::MaxPerFileType => "MaxPerFileType",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -1153,9 +1675,11 @@ impl DateTime {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
 
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::DateTime(reader.next_u32(endianness)?));
         }
 
@@ -1163,6 +1687,21 @@ impl DateTime {
     }
 }
 
+impl std::str::FromStr for MesgCount {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MesgCount::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NumPerFile" => Ok(Self::NumPerFile),
+            "MaxPerFile" => Ok(Self::MaxPerFile),
+            "MaxPerFileType" => Ok(Self::MaxPerFileType),
+            _ => Err(UnknownVariantError::new("MesgCount", name)),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct LocalDateTime(u32);
 
@@ -1176,9 +1715,11 @@ impl LocalDateTime {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
 
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::DateTime(reader.next_u32(endianness)?));
         }
 
@@ -1186,7 +1727,7 @@ impl LocalDateTime {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MessageIndex {
     Selected,
     Reserved,
@@ -1208,21 +1749,57 @@ impl MessageIndex {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MessageIndex(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MessageIndex] {
+        &[
+            MessageIndex::Selected,
+            MessageIndex::Reserved,
+            MessageIndex::Mask,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Selected => "Selected",
+            Self::Reserved => "Reserved",
+            Self::Mask => "Mask",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DeviceIndex {
     Creator,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for MessageIndex {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MessageIndex::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Selected" => Ok(Self::Selected),
+            "Reserved" => Ok(Self::Reserved),
+            "Mask" => Ok(Self::Mask),
+            _ => Err(UnknownVariantError::new("MessageIndex", name)),
+        }
+    }
+}
 impl DeviceIndex {
     pub fn from(content: u8) -> DeviceIndex {
         match content {
@@ -1236,22 +1813,50 @@ impl DeviceIndex {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DeviceIndex(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DeviceIndex] {
+        &[DeviceIndex::Creator]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Creator => "Creator",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Gender {
     Female,
     Male,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DeviceIndex {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DeviceIndex::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Creator" => Ok(Self::Creator),
+            _ => Err(UnknownVariantError::new("DeviceIndex", name)),
+        }
+    }
+}
 impl Gender {
     pub fn from(content: u8) -> Gender {
         match content {
@@ -1266,17 +1871,33 @@ impl Gender {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Gender(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Gender] {
+        &[Gender::Female, Gender::Male]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Female => "Female",
+            Self::Male => "Male",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Language {
     English,
     French,
@@ -1319,6 +1940,20 @@ pub enum Language {
     Custom,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Gender {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Gender::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Female" => Ok(Self::Female),
+            "Male" => Ok(Self::Male),
+            _ => Err(UnknownVariantError::new("Gender", name)),
+        }
+    }
+}
 impl Language {
     pub fn from(content: u8) -> Language {
         match content {
@@ -1370,23 +2005,167 @@ impl Language {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Language(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::English,
+            Language::French,
+            Language::Italian,
+            Language::German,
+            Language::Spanish,
+            Language::Croatian,
+            Language::Czech,
+            Language::Danish,
+            Language::Dutch,
+            Language::Finnish,
+            Language::Greek,
+            Language::Hungarian,
+            Language::Norwegian,
+            Language::Polish,
+            Language::Portuguese,
+            Language::Slovakian,
+            Language::Slovenian,
+            Language::Swedish,
+            Language::Russian,
+            Language::Turkish,
+            Language::Latvian,
+            Language::Ukrainian,
+            Language::Arabic,
+            Language::Farsi,
+            Language::Bulgarian,
+            Language::Romanian,
+            Language::Chinese,
+            Language::Japanese,
+            Language::Korean,
+            Language::Taiwanese,
+            Language::Thai,
+            Language::Hebrew,
+            Language::BrazilianPortuguese,
+            Language::Indonesian,
+            Language::Malaysian,
+            Language::Vietnamese,
+            Language::Burmese,
+            Language::Mongolian,
+            Language::Custom,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::French => "French",
+            Self::Italian => "Italian",
+            Self::German => "German",
+            Self::Spanish => "Spanish",
+            Self::Croatian => "Croatian",
+            Self::Czech => "Czech",
+            Self::Danish => "Danish",
+            Self::Dutch => "Dutch",
+            Self::Finnish => "Finnish",
+            Self::Greek => "Greek",
+            Self::Hungarian => "Hungarian",
+            Self::Norwegian => "Norwegian",
+            Self::Polish => "Polish",
+            Self::Portuguese => "Portuguese",
+            Self::Slovakian => "Slovakian",
+            Self::Slovenian => "Slovenian",
+            Self::Swedish => "Swedish",
+            Self::Russian => "Russian",
+            Self::Turkish => "Turkish",
+            Self::Latvian => "Latvian",
+            Self::Ukrainian => "Ukrainian",
+            Self::Arabic => "Arabic",
+            Self::Farsi => "Farsi",
+            Self::Bulgarian => "Bulgarian",
+            Self::Romanian => "Romanian",
+            Self::Chinese => "Chinese",
+            Self::Japanese => "Japanese",
+            Self::Korean => "Korean",
+            Self::Taiwanese => "Taiwanese",
+            Self::Thai => "Thai",
+            Self::Hebrew => "Hebrew",
+            Self::BrazilianPortuguese => "BrazilianPortuguese",
+            Self::Indonesian => "Indonesian",
+            Self::Malaysian => "Malaysian",
+            Self::Vietnamese => "Vietnamese",
+            Self::Burmese => "Burmese",
+            Self::Mongolian => "Mongolian",
+            Self::Custom => "Custom",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DisplayMeasure {
     Metric,
     Statute,
     Nautical,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Language {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Language::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "English" => Ok(Self::English),
+            "French" => Ok(Self::French),
+            "Italian" => Ok(Self::Italian),
+            "German" => Ok(Self::German),
+            "Spanish" => Ok(Self::Spanish),
+            "Croatian" => Ok(Self::Croatian),
+            "Czech" => Ok(Self::Czech),
+            "Danish" => Ok(Self::Danish),
+            "Dutch" => Ok(Self::Dutch),
+            "Finnish" => Ok(Self::Finnish),
+            "Greek" => Ok(Self::Greek),
+            "Hungarian" => Ok(Self::Hungarian),
+            "Norwegian" => Ok(Self::Norwegian),
+            "Polish" => Ok(Self::Polish),
+            "Portuguese" => Ok(Self::Portuguese),
+            "Slovakian" => Ok(Self::Slovakian),
+            "Slovenian" => Ok(Self::Slovenian),
+            "Swedish" => Ok(Self::Swedish),
+            "Russian" => Ok(Self::Russian),
+            "Turkish" => Ok(Self::Turkish),
+            "Latvian" => Ok(Self::Latvian),
+            "Ukrainian" => Ok(Self::Ukrainian),
+            "Arabic" => Ok(Self::Arabic),
+            "Farsi" => Ok(Self::Farsi),
+            "Bulgarian" => Ok(Self::Bulgarian),
+            "Romanian" => Ok(Self::Romanian),
+            "Chinese" => Ok(Self::Chinese),
+            "Japanese" => Ok(Self::Japanese),
+            "Korean" => Ok(Self::Korean),
+            "Taiwanese" => Ok(Self::Taiwanese),
+            "Thai" => Ok(Self::Thai),
+            "Hebrew" => Ok(Self::Hebrew),
+            "BrazilianPortuguese" => Ok(Self::BrazilianPortuguese),
+            "Indonesian" => Ok(Self::Indonesian),
+            "Malaysian" => Ok(Self::Malaysian),
+            "Vietnamese" => Ok(Self::Vietnamese),
+            "Burmese" => Ok(Self::Burmese),
+            "Mongolian" => Ok(Self::Mongolian),
+            "Custom" => Ok(Self::Custom),
+            _ => Err(UnknownVariantError::new("Language", name)),
+        }
+    }
+}
 impl DisplayMeasure {
     pub fn from(content: u8) -> DisplayMeasure {
         match content {
@@ -1402,23 +2181,59 @@ impl DisplayMeasure {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DisplayMeasure(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DisplayMeasure] {
+        &[
+            DisplayMeasure::Metric,
+            DisplayMeasure::Statute,
+            DisplayMeasure::Nautical,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Metric => "Metric",
+            Self::Statute => "Statute",
+            Self::Nautical => "Nautical",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DisplayHeart {
     Bpm,
     Max,
     Reserve,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DisplayMeasure {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DisplayMeasure::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Metric" => Ok(Self::Metric),
+            "Statute" => Ok(Self::Statute),
+            "Nautical" => Ok(Self::Nautical),
+            _ => Err(UnknownVariantError::new("DisplayMeasure", name)),
+        }
+    }
+}
 impl DisplayHeart {
     pub fn from(content: u8) -> DisplayHeart {
         match content {
@@ -1434,22 +2249,54 @@ impl DisplayHeart {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DisplayHeart(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DisplayHeart] {
+        &[DisplayHeart::Bpm, DisplayHeart::Max, DisplayHeart::Reserve]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bpm => "Bpm",
+            Self::Max => "Max",
+            Self::Reserve => "Reserve",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DisplayPower {
     Watts,
     PercentFtp,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DisplayHeart {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DisplayHeart::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Bpm" => Ok(Self::Bpm),
+            "Max" => Ok(Self::Max),
+            "Reserve" => Ok(Self::Reserve),
+            _ => Err(UnknownVariantError::new("DisplayHeart", name)),
+        }
+    }
+}
 impl DisplayPower {
     pub fn from(content: u8) -> DisplayPower {
         match content {
@@ -1464,17 +2311,33 @@ impl DisplayPower {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DisplayPower(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DisplayPower] {
+        &[DisplayPower::Watts, DisplayPower::PercentFtp]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Watts => "Watts",
+            Self::PercentFtp => "PercentFtp",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DisplayPosition {
     Degree,
     DegreeMinute,
@@ -1520,6 +2383,20 @@ pub enum DisplayPosition {
     SwedishRef99Grid,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DisplayPower {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DisplayPower::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Watts" => Ok(Self::Watts),
+            "PercentFtp" => Ok(Self::PercentFtp),
+            _ => Err(UnknownVariantError::new("DisplayPower", name)),
+        }
+    }
+}
 impl DisplayPosition {
     pub fn from(content: u8) -> DisplayPosition {
         match content {
@@ -1574,23 +2451,176 @@ impl DisplayPosition {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DisplayPosition(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DisplayPosition] {
+        &[
+            DisplayPosition::Degree,
+            DisplayPosition::DegreeMinute,
+            DisplayPosition::DegreeMinuteSecond,
+            DisplayPosition::AustrianGrid,
+            DisplayPosition::BritishGrid,
+            DisplayPosition::DutchGrid,
+            DisplayPosition::HungarianGrid,
+            DisplayPosition::FinnishGrid,
+            DisplayPosition::GermanGrid,
+            DisplayPosition::IcelandicGrid,
+            DisplayPosition::IndonesianEquatorial,
+            DisplayPosition::IndonesianIrian,
+            DisplayPosition::IndonesianSouthern,
+            DisplayPosition::IndiaZone0,
+            DisplayPosition::IndiaZoneIA,
+            DisplayPosition::IndiaZoneIB,
+            DisplayPosition::IndiaZoneIIA,
+            DisplayPosition::IndiaZoneIIB,
+            DisplayPosition::IndiaZoneIIIA,
+            DisplayPosition::IndiaZoneIIIB,
+            DisplayPosition::IndiaZoneIVA,
+            DisplayPosition::IndiaZoneIVB,
+            DisplayPosition::IrishTransverse,
+            DisplayPosition::IrishGrid,
+            DisplayPosition::Loran,
+            DisplayPosition::MaidenheadGrid,
+            DisplayPosition::MgrsGrid,
+            DisplayPosition::NewZealandGrid,
+            DisplayPosition::NewZealandTransverse,
+            DisplayPosition::QatarGrid,
+            DisplayPosition::ModifiedSwedishGrid,
+            DisplayPosition::SwedishGrid,
+            DisplayPosition::SouthAfricanGrid,
+            DisplayPosition::SwissGrid,
+            DisplayPosition::TaiwanGrid,
+            DisplayPosition::UnitedStatesGrid,
+            DisplayPosition::UtmUpsGrid,
+            DisplayPosition::WestMalayan,
+            DisplayPosition::BorneoRso,
+            DisplayPosition::EstonianGrid,
+            DisplayPosition::LatvianGrid,
+            DisplayPosition::SwedishRef99Grid,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Degree => "Degree",
+            Self::DegreeMinute => "DegreeMinute",
+            Self::DegreeMinuteSecond => "DegreeMinuteSecond",
+            Self::AustrianGrid => "AustrianGrid",
+            Self::BritishGrid => "BritishGrid",
+            Self::DutchGrid => "DutchGrid",
+            Self::HungarianGrid => "HungarianGrid",
+            Self::FinnishGrid => "FinnishGrid",
+            Self::GermanGrid => "GermanGrid",
+            Self::IcelandicGrid => "IcelandicGrid",
+            Self::IndonesianEquatorial => "IndonesianEquatorial",
+            Self::IndonesianIrian => "IndonesianIrian",
+            Self::IndonesianSouthern => "IndonesianSouthern",
+            Self::IndiaZone0 => "IndiaZone0",
+            Self::IndiaZoneIA => "IndiaZoneIA",
+            Self::IndiaZoneIB => "IndiaZoneIB",
+            Self::IndiaZoneIIA => "IndiaZoneIIA",
+            Self::IndiaZoneIIB => "IndiaZoneIIB",
+            Self::IndiaZoneIIIA => "IndiaZoneIIIA",
+            Self::IndiaZoneIIIB => "IndiaZoneIIIB",
+            Self::IndiaZoneIVA => "IndiaZoneIVA",
+            Self::IndiaZoneIVB => "IndiaZoneIVB",
+            Self::IrishTransverse => "IrishTransverse",
+            Self::IrishGrid => "IrishGrid",
+            Self::Loran => "Loran",
+            Self::MaidenheadGrid => "MaidenheadGrid",
+            Self::MgrsGrid => "MgrsGrid",
+            Self::NewZealandGrid => "NewZealandGrid",
+            Self::NewZealandTransverse => "NewZealandTransverse",
+            Self::QatarGrid => "QatarGrid",
+            Self::ModifiedSwedishGrid => "ModifiedSwedishGrid",
+            Self::SwedishGrid => "SwedishGrid",
+            Self::SouthAfricanGrid => "SouthAfricanGrid",
+            Self::SwissGrid => "SwissGrid",
+            Self::TaiwanGrid => "TaiwanGrid",
+            Self::UnitedStatesGrid => "UnitedStatesGrid",
+            Self::UtmUpsGrid => "UtmUpsGrid",
+            Self::WestMalayan => "WestMalayan",
+            Self::BorneoRso => "BorneoRso",
+            Self::EstonianGrid => "EstonianGrid",
+            Self::LatvianGrid => "LatvianGrid",
+            Self::SwedishRef99Grid => "SwedishRef99Grid",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Switch {
     Off,
     On,
     Auto,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DisplayPosition {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DisplayPosition::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Degree" => Ok(Self::Degree),
+            "DegreeMinute" => Ok(Self::DegreeMinute),
+            "DegreeMinuteSecond" => Ok(Self::DegreeMinuteSecond),
+            "AustrianGrid" => Ok(Self::AustrianGrid),
+            "BritishGrid" => Ok(Self::BritishGrid),
+            "DutchGrid" => Ok(Self::DutchGrid),
+            "HungarianGrid" => Ok(Self::HungarianGrid),
+            "FinnishGrid" => Ok(Self::FinnishGrid),
+            "GermanGrid" => Ok(Self::GermanGrid),
+            "IcelandicGrid" => Ok(Self::IcelandicGrid),
+            "IndonesianEquatorial" => Ok(Self::IndonesianEquatorial),
+            "IndonesianIrian" => Ok(Self::IndonesianIrian),
+            "IndonesianSouthern" => Ok(Self::IndonesianSouthern),
+            "IndiaZone0" => Ok(Self::IndiaZone0),
+            "IndiaZoneIA" => Ok(Self::IndiaZoneIA),
+            "IndiaZoneIB" => Ok(Self::IndiaZoneIB),
+            "IndiaZoneIIA" => Ok(Self::IndiaZoneIIA),
+            "IndiaZoneIIB" => Ok(Self::IndiaZoneIIB),
+            "IndiaZoneIIIA" => Ok(Self::IndiaZoneIIIA),
+            "IndiaZoneIIIB" => Ok(Self::IndiaZoneIIIB),
+            "IndiaZoneIVA" => Ok(Self::IndiaZoneIVA),
+            "IndiaZoneIVB" => Ok(Self::IndiaZoneIVB),
+            "IrishTransverse" => Ok(Self::IrishTransverse),
+            "IrishGrid" => Ok(Self::IrishGrid),
+            "Loran" => Ok(Self::Loran),
+            "MaidenheadGrid" => Ok(Self::MaidenheadGrid),
+            "MgrsGrid" => Ok(Self::MgrsGrid),
+            "NewZealandGrid" => Ok(Self::NewZealandGrid),
+            "NewZealandTransverse" => Ok(Self::NewZealandTransverse),
+            "QatarGrid" => Ok(Self::QatarGrid),
+            "ModifiedSwedishGrid" => Ok(Self::ModifiedSwedishGrid),
+            "SwedishGrid" => Ok(Self::SwedishGrid),
+            "SouthAfricanGrid" => Ok(Self::SouthAfricanGrid),
+            "SwissGrid" => Ok(Self::SwissGrid),
+            "TaiwanGrid" => Ok(Self::TaiwanGrid),
+            "UnitedStatesGrid" => Ok(Self::UnitedStatesGrid),
+            "UtmUpsGrid" => Ok(Self::UtmUpsGrid),
+            "WestMalayan" => Ok(Self::WestMalayan),
+            "BorneoRso" => Ok(Self::BorneoRso),
+            "EstonianGrid" => Ok(Self::EstonianGrid),
+            "LatvianGrid" => Ok(Self::LatvianGrid),
+            "SwedishRef99Grid" => Ok(Self::SwedishRef99Grid),
+            _ => Err(UnknownVariantError::new("DisplayPosition", name)),
+        }
+    }
+}
 impl Switch {
     pub fn from(content: u8) -> Switch {
         match content {
@@ -1606,17 +2636,68 @@ impl Switch {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Switch(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Switch] {
+        &[Switch::Off, Switch::On, Switch::Auto]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::On => "On",
+            Self::Auto => "Auto",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+
+    /// Whether the value is `On`.
+    pub fn is_on(&self) -> bool {
+        matches!(self, Self::On)
+    }
+
+    /// `Some(true)` for `On`, `Some(false)` for `Off` and `None` for any other
+    /// value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::On => Some(true),
+            Self::Off => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn from_bool(value: bool) -> Self {
+        if value { Self::On } else { Self::Off }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl std::str::FromStr for Switch {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Switch::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Off" => Ok(Self::Off),
+            "On" => Ok(Self::On),
+            "Auto" => Ok(Self::Auto),
+            _ => Err(UnknownVariantError::new("Switch", name)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Sport {
     Generic,
     Running,
@@ -1770,17 +2851,170 @@ impl Sport {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Sport(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Sport] {
+        &[
+            Sport::Generic,
+            Sport::Running,
+            Sport::Cycling,
+            Sport::Transition,
+            Sport::FitnessEquipment,
+            Sport::Swimming,
+            Sport::Basketball,
+            Sport::Soccer,
+            Sport::Tennis,
+            Sport::AmericanFootball,
+            Sport::Training,
+            Sport::Walking,
+            Sport::CrossCountrySkiing,
+            Sport::AlpineSkiing,
+            Sport::Snowboarding,
+            Sport::Rowing,
+            Sport::Mountaineering,
+            Sport::Hiking,
+            Sport::Multisport,
+            Sport::Paddling,
+            Sport::Flying,
+            Sport::EBiking,
+            Sport::Motorcycling,
+            Sport::Boating,
+            Sport::Driving,
+            Sport::Golf,
+            Sport::HangGliding,
+            Sport::HorsebackRiding,
+            Sport::Hunting,
+            Sport::Fishing,
+            Sport::InlineSkating,
+            Sport::RockClimbing,
+            Sport::Sailing,
+            Sport::IceSkating,
+            Sport::SkyDiving,
+            Sport::Snowshoeing,
+            Sport::Snowmobiling,
+            Sport::StandUpPaddleboarding,
+            Sport::Surfing,
+            Sport::Wakeboarding,
+            Sport::WaterSkiing,
+            Sport::Kayaking,
+            Sport::Rafting,
+            Sport::Windsurfing,
+            Sport::Kitesurfing,
+            Sport::Tactical,
+            Sport::Jumpmaster,
+            Sport::Boxing,
+            Sport::FloorClimbing,
+            Sport::Baseball,
+            Sport::Diving,
+            Sport::Hiit,
+            Sport::Racket,
+            Sport::WheelchairPushWalk,
+            Sport::WheelchairPushRun,
+            Sport::Meditation,
+            Sport::DiscGolf,
+            Sport::Cricket,
+            Sport::Rugby,
+            Sport::Hockey,
+            Sport::Lacrosse,
+            Sport::Volleyball,
+            Sport::WaterTubing,
+            Sport::Wakesurfing,
+            Sport::MixedMartialArts,
+            Sport::Snorkeling,
+            Sport::Dance,
+            Sport::JumpRope,
+            Sport::All,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Running => "Running",
+            Self::Cycling => "Cycling",
+            Self::Transition => "Transition",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::Swimming => "Swimming",
+            Self::Basketball => "Basketball",
+            Self::Soccer => "Soccer",
+            Self::Tennis => "Tennis",
+            Self::AmericanFootball => "AmericanFootball",
+            Self::Training => "Training",
+            Self::Walking => "Walking",
+            Self::CrossCountrySkiing => "CrossCountrySkiing",
+            Self::AlpineSkiing => "AlpineSkiing",
+            Self::Snowboarding => "Snowboarding",
+            Self::Rowing => "Rowing",
+            Self::Mountaineering => "Mountaineering",
+            Self::Hiking => "Hiking",
+            Self::Multisport => "Multisport",
+            Self::Paddling => "Paddling",
+            Self::Flying => "Flying",
+            Self::EBiking => "EBiking",
+            Self::Motorcycling => "Motorcycling",
+            Self::Boating => "Boating",
+            Self::Driving => "Driving",
+            Self::Golf => "Golf",
+            Self::HangGliding => "HangGliding",
+            Self::HorsebackRiding => "HorsebackRiding",
+            Self::Hunting => "Hunting",
+            Self::Fishing => "Fishing",
+            Self::InlineSkating => "InlineSkating",
+            Self::RockClimbing => "RockClimbing",
+            Self::Sailing => "Sailing",
+            Self::IceSkating => "IceSkating",
+            Self::SkyDiving => "SkyDiving",
+            Self::Snowshoeing => "Snowshoeing",
+            Self::Snowmobiling => "Snowmobiling",
+            Self::StandUpPaddleboarding => "StandUpPaddleboarding",
+            Self::Surfing => "Surfing",
+            Self::Wakeboarding => "Wakeboarding",
+            Self::WaterSkiing => "WaterSkiing",
+            Self::Kayaking => "Kayaking",
+            Self::Rafting => "Rafting",
+            Self::Windsurfing => "Windsurfing",
+            Self::Kitesurfing => "Kitesurfing",
+            Self::Tactical => "Tactical",
+            Self::Jumpmaster => "Jumpmaster",
+            Self::Boxing => "Boxing",
+            Self::FloorClimbing => "FloorClimbing",
+            Self::Baseball => "Baseball",
+            Self::Diving => "Diving",
+            Self::Hiit => "Hiit",
+            Self::Racket => "Racket",
+            Self::WheelchairPushWalk => "WheelchairPushWalk",
+            Self::WheelchairPushRun => "WheelchairPushRun",
+            Self::Meditation => "Meditation",
+            Self::DiscGolf => "DiscGolf",
+            Self::Cricket => "Cricket",
+            Self::Rugby => "Rugby",
+            Self::Hockey => "Hockey",
+            Self::Lacrosse => "Lacrosse",
+            Self::Volleyball => "Volleyball",
+            Self::WaterTubing => "WaterTubing",
+            Self::Wakesurfing => "Wakesurfing",
+            Self::MixedMartialArts => "MixedMartialArts",
+            Self::Snorkeling => "Snorkeling",
+            Self::Dance => "Dance",
+            Self::JumpRope => "JumpRope",
+            Self::All => "All",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SportBits0 {
     Generic,
     Running,
@@ -1792,6 +3026,87 @@ pub enum SportBits0 {
     Soccer,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Sport {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Sport::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Running" => Ok(Self::Running),
+            "Cycling" => Ok(Self::Cycling),
+            "Transition" => Ok(Self::Transition),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "Swimming" => Ok(Self::Swimming),
+            "Basketball" => Ok(Self::Basketball),
+            "Soccer" => Ok(Self::Soccer),
+            "Tennis" => Ok(Self::Tennis),
+            "AmericanFootball" => Ok(Self::AmericanFootball),
+            "Training" => Ok(Self::Training),
+            "Walking" => Ok(Self::Walking),
+            "CrossCountrySkiing" => Ok(Self::CrossCountrySkiing),
+            "AlpineSkiing" => Ok(Self::AlpineSkiing),
+            "Snowboarding" => Ok(Self::Snowboarding),
+            "Rowing" => Ok(Self::Rowing),
+            "Mountaineering" => Ok(Self::Mountaineering),
+            "Hiking" => Ok(Self::Hiking),
+            "Multisport" => Ok(Self::Multisport),
+            "Paddling" => Ok(Self::Paddling),
+            "Flying" => Ok(Self::Flying),
+            "EBiking" => Ok(Self::EBiking),
+            "Motorcycling" => Ok(Self::Motorcycling),
+            "Boating" => Ok(Self::Boating),
+            "Driving" => Ok(Self::Driving),
+            "Golf" => Ok(Self::Golf),
+            "HangGliding" => Ok(Self::HangGliding),
+            "HorsebackRiding" => Ok(Self::HorsebackRiding),
+            "Hunting" => Ok(Self::Hunting),
+            "Fishing" => Ok(Self::Fishing),
+            "InlineSkating" => Ok(Self::InlineSkating),
+            "RockClimbing" => Ok(Self::RockClimbing),
+            "Sailing" => Ok(Self::Sailing),
+            "IceSkating" => Ok(Self::IceSkating),
+            "SkyDiving" => Ok(Self::SkyDiving),
+            "Snowshoeing" => Ok(Self::Snowshoeing),
+            "Snowmobiling" => Ok(Self::Snowmobiling),
+            "StandUpPaddleboarding" => Ok(Self::StandUpPaddleboarding),
+            "Surfing" => Ok(Self::Surfing),
+            "Wakeboarding" => Ok(Self::Wakeboarding),
+            "WaterSkiing" => Ok(Self::WaterSkiing),
+            "Kayaking" => Ok(Self::Kayaking),
+            "Rafting" => Ok(Self::Rafting),
+            "Windsurfing" => Ok(Self::Windsurfing),
+            "Kitesurfing" => Ok(Self::Kitesurfing),
+            "Tactical" => Ok(Self::Tactical),
+            "Jumpmaster" => Ok(Self::Jumpmaster),
+            "Boxing" => Ok(Self::Boxing),
+            "FloorClimbing" => Ok(Self::FloorClimbing),
+            "Baseball" => Ok(Self::Baseball),
+            "Diving" => Ok(Self::Diving),
+            "Hiit" => Ok(Self::Hiit),
+            "Racket" => Ok(Self::Racket),
+            "WheelchairPushWalk" => Ok(Self::WheelchairPushWalk),
+            "WheelchairPushRun" => Ok(Self::WheelchairPushRun),
+            "Meditation" => Ok(Self::Meditation),
+            "DiscGolf" => Ok(Self::DiscGolf),
+            "Cricket" => Ok(Self::Cricket),
+            "Rugby" => Ok(Self::Rugby),
+            "Hockey" => Ok(Self::Hockey),
+            "Lacrosse" => Ok(Self::Lacrosse),
+            "Volleyball" => Ok(Self::Volleyball),
+            "WaterTubing" => Ok(Self::WaterTubing),
+            "Wakesurfing" => Ok(Self::Wakesurfing),
+            "MixedMartialArts" => Ok(Self::MixedMartialArts),
+            "Snorkeling" => Ok(Self::Snorkeling),
+            "Dance" => Ok(Self::Dance),
+            "JumpRope" => Ok(Self::JumpRope),
+            "All" => Ok(Self::All),
+            _ => Err(UnknownVariantError::new("Sport", name)),
+        }
+    }
+}
 impl SportBits0 {
     pub fn from(content: u8) -> SportBits0 {
         match content {
@@ -1812,17 +3127,48 @@ impl SportBits0 {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SportBits0(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SportBits0] {
+        &[
+            SportBits0::Generic,
+            SportBits0::Running,
+            SportBits0::Cycling,
+            SportBits0::Transition,
+            SportBits0::FitnessEquipment,
+            SportBits0::Swimming,
+            SportBits0::Basketball,
+            SportBits0::Soccer,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Running => "Running",
+            Self::Cycling => "Cycling",
+            Self::Transition => "Transition",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::Swimming => "Swimming",
+            Self::Basketball => "Basketball",
+            Self::Soccer => "Soccer",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SubSport {
     Generic,
     Treadmill,
@@ -1915,6 +3261,26 @@ pub enum SubSport {
     All,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SportBits0 {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SportBits0::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Running" => Ok(Self::Running),
+            "Cycling" => Ok(Self::Cycling),
+            "Transition" => Ok(Self::Transition),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "Swimming" => Ok(Self::Swimming),
+            "Basketball" => Ok(Self::Basketball),
+            "Soccer" => Ok(Self::Soccer),
+            _ => Err(UnknownVariantError::new("SportBits0", name)),
+        }
+    }
+}
 impl SubSport {
     pub fn from(content: u8) -> SubSport {
         match content {
@@ -2016,17 +3382,210 @@ impl SubSport {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SubSport(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SubSport] {
+        &[
+            SubSport::Generic,
+            SubSport::Treadmill,
+            SubSport::Street,
+            SubSport::Trail,
+            SubSport::Track,
+            SubSport::Spin,
+            SubSport::IndoorCycling,
+            SubSport::Road,
+            SubSport::Mountain,
+            SubSport::Downhill,
+            SubSport::Recumbent,
+            SubSport::Cyclocross,
+            SubSport::HandCycling,
+            SubSport::TrackCycling,
+            SubSport::IndoorRowing,
+            SubSport::Elliptical,
+            SubSport::StairClimbing,
+            SubSport::LapSwimming,
+            SubSport::OpenWater,
+            SubSport::FlexibilityTraining,
+            SubSport::StrengthTraining,
+            SubSport::WarmUp,
+            SubSport::Match,
+            SubSport::Exercise,
+            SubSport::Challenge,
+            SubSport::IndoorSkiing,
+            SubSport::CardioTraining,
+            SubSport::IndoorWalking,
+            SubSport::EBikeFitness,
+            SubSport::Bmx,
+            SubSport::CasualWalking,
+            SubSport::SpeedWalking,
+            SubSport::BikeToRunTransition,
+            SubSport::RunToBikeTransition,
+            SubSport::SwimToBikeTransition,
+            SubSport::Atv,
+            SubSport::Motocross,
+            SubSport::Backcountry,
+            SubSport::Resort,
+            SubSport::RcDrone,
+            SubSport::Wingsuit,
+            SubSport::Whitewater,
+            SubSport::SkateSkiing,
+            SubSport::Yoga,
+            SubSport::Pilates,
+            SubSport::IndoorRunning,
+            SubSport::GravelCycling,
+            SubSport::EBikeMountain,
+            SubSport::Commuting,
+            SubSport::MixedSurface,
+            SubSport::Navigate,
+            SubSport::TrackMe,
+            SubSport::Map,
+            SubSport::SingleGasDiving,
+            SubSport::MultiGasDiving,
+            SubSport::GaugeDiving,
+            SubSport::ApneaDiving,
+            SubSport::ApneaHunting,
+            SubSport::VirtualActivity,
+            SubSport::Obstacle,
+            SubSport::Breathing,
+            SubSport::SailRace,
+            SubSport::Ultra,
+            SubSport::IndoorClimbing,
+            SubSport::Bouldering,
+            SubSport::Hiit,
+            SubSport::Amrap,
+            SubSport::Emom,
+            SubSport::Tabata,
+            SubSport::Pickleball,
+            SubSport::Padel,
+            SubSport::IndoorWheelchairWalk,
+            SubSport::IndoorWheelchairRun,
+            SubSport::IndoorHandCycling,
+            SubSport::Squash,
+            SubSport::Badminton,
+            SubSport::Racquetball,
+            SubSport::TableTennis,
+            SubSport::FlyCanopy,
+            SubSport::FlyParaglide,
+            SubSport::FlyParamotor,
+            SubSport::FlyPressurized,
+            SubSport::FlyNavigate,
+            SubSport::FlyTimer,
+            SubSport::FlyAltimeter,
+            SubSport::FlyWx,
+            SubSport::FlyVfr,
+            SubSport::FlyIfr,
+            SubSport::All,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Treadmill => "Treadmill",
+            Self::Street => "Street",
+            Self::Trail => "Trail",
+            Self::Track => "Track",
+            Self::Spin => "Spin",
+            Self::IndoorCycling => "IndoorCycling",
+            Self::Road => "Road",
+            Self::Mountain => "Mountain",
+            Self::Downhill => "Downhill",
+            Self::Recumbent => "Recumbent",
+            Self::Cyclocross => "Cyclocross",
+            Self::HandCycling => "HandCycling",
+            Self::TrackCycling => "TrackCycling",
+            Self::IndoorRowing => "IndoorRowing",
+            Self::Elliptical => "Elliptical",
+            Self::StairClimbing => "StairClimbing",
+            Self::LapSwimming => "LapSwimming",
+            Self::OpenWater => "OpenWater",
+            Self::FlexibilityTraining => "FlexibilityTraining",
+            Self::StrengthTraining => "StrengthTraining",
+            Self::WarmUp => "WarmUp",
+            Self::Match => "Match",
+            Self::Exercise => "Exercise",
+            Self::Challenge => "Challenge",
+            Self::IndoorSkiing => "IndoorSkiing",
+            Self::CardioTraining => "CardioTraining",
+            Self::IndoorWalking => "IndoorWalking",
+            Self::EBikeFitness => "EBikeFitness",
+            Self::Bmx => "Bmx",
+            Self::CasualWalking => "CasualWalking",
+            Self::SpeedWalking => "SpeedWalking",
+            Self::BikeToRunTransition => "BikeToRunTransition",
+            Self::RunToBikeTransition => "RunToBikeTransition",
+            Self::SwimToBikeTransition => "SwimToBikeTransition",
+            Self::Atv => "Atv",
+            Self::Motocross => "Motocross",
+            Self::Backcountry => "Backcountry",
+            Self::Resort => "Resort",
+            Self::RcDrone => "RcDrone",
+            Self::Wingsuit => "Wingsuit",
+            Self::Whitewater => "Whitewater",
+            Self::SkateSkiing => "SkateSkiing",
+            Self::Yoga => "Yoga",
+            Self::Pilates => "Pilates",
+            Self::IndoorRunning => "IndoorRunning",
+            Self::GravelCycling => "GravelCycling",
+            Self::EBikeMountain => "EBikeMountain",
+            Self::Commuting => "Commuting",
+            Self::MixedSurface => "MixedSurface",
+            Self::Navigate => "Navigate",
+            Self::TrackMe => "TrackMe",
+            Self::Map => "Map",
+            Self::SingleGasDiving => "SingleGasDiving",
+            Self::MultiGasDiving => "MultiGasDiving",
+            Self::GaugeDiving => "GaugeDiving",
+            Self::ApneaDiving => "ApneaDiving",
+            Self::ApneaHunting => "ApneaHunting",
+            Self::VirtualActivity => "VirtualActivity",
+            Self::Obstacle => "Obstacle",
+            Self::Breathing => "Breathing",
+            Self::SailRace => "SailRace",
+            Self::Ultra => "Ultra",
+            Self::IndoorClimbing => "IndoorClimbing",
+            Self::Bouldering => "Bouldering",
+            Self::Hiit => "Hiit",
+            Self::Amrap => "Amrap",
+            Self::Emom => "Emom",
+            Self::Tabata => "Tabata",
+            Self::Pickleball => "Pickleball",
+            Self::Padel => "Padel",
+            Self::IndoorWheelchairWalk => "IndoorWheelchairWalk",
+            Self::IndoorWheelchairRun => "IndoorWheelchairRun",
+            Self::IndoorHandCycling => "IndoorHandCycling",
+            Self::Squash => "Squash",
+            Self::Badminton => "Badminton",
+            Self::Racquetball => "Racquetball",
+            Self::TableTennis => "TableTennis",
+            Self::FlyCanopy => "FlyCanopy",
+            Self::FlyParaglide => "FlyParaglide",
+            Self::FlyParamotor => "FlyParamotor",
+            Self::FlyPressurized => "FlyPressurized",
+            Self::FlyNavigate => "FlyNavigate",
+            Self::FlyTimer => "FlyTimer",
+            Self::FlyAltimeter => "FlyAltimeter",
+            Self::FlyWx => "FlyWx",
+            Self::FlyVfr => "FlyVfr",
+            Self::FlyIfr => "FlyIfr",
+            Self::All => "All",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SportEvent {
     Uncategorized,
     Geocaching,
@@ -2039,6 +3598,107 @@ pub enum SportEvent {
     Touring,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SubSport {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SubSport::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Treadmill" => Ok(Self::Treadmill),
+            "Street" => Ok(Self::Street),
+            "Trail" => Ok(Self::Trail),
+            "Track" => Ok(Self::Track),
+            "Spin" => Ok(Self::Spin),
+            "IndoorCycling" => Ok(Self::IndoorCycling),
+            "Road" => Ok(Self::Road),
+            "Mountain" => Ok(Self::Mountain),
+            "Downhill" => Ok(Self::Downhill),
+            "Recumbent" => Ok(Self::Recumbent),
+            "Cyclocross" => Ok(Self::Cyclocross),
+            "HandCycling" => Ok(Self::HandCycling),
+            "TrackCycling" => Ok(Self::TrackCycling),
+            "IndoorRowing" => Ok(Self::IndoorRowing),
+            "Elliptical" => Ok(Self::Elliptical),
+            "StairClimbing" => Ok(Self::StairClimbing),
+            "LapSwimming" => Ok(Self::LapSwimming),
+            "OpenWater" => Ok(Self::OpenWater),
+            "FlexibilityTraining" => Ok(Self::FlexibilityTraining),
+            "StrengthTraining" => Ok(Self::StrengthTraining),
+            "WarmUp" => Ok(Self::WarmUp),
+            "Match" => Ok(Self::Match),
+            "Exercise" => Ok(Self::Exercise),
+            "Challenge" => Ok(Self::Challenge),
+            "IndoorSkiing" => Ok(Self::IndoorSkiing),
+            "CardioTraining" => Ok(Self::CardioTraining),
+            "IndoorWalking" => Ok(Self::IndoorWalking),
+            "EBikeFitness" => Ok(Self::EBikeFitness),
+            "Bmx" => Ok(Self::Bmx),
+            "CasualWalking" => Ok(Self::CasualWalking),
+            "SpeedWalking" => Ok(Self::SpeedWalking),
+            "BikeToRunTransition" => Ok(Self::BikeToRunTransition),
+            "RunToBikeTransition" => Ok(Self::RunToBikeTransition),
+            "SwimToBikeTransition" => Ok(Self::SwimToBikeTransition),
+            "Atv" => Ok(Self::Atv),
+            "Motocross" => Ok(Self::Motocross),
+            "Backcountry" => Ok(Self::Backcountry),
+            "Resort" => Ok(Self::Resort),
+            "RcDrone" => Ok(Self::RcDrone),
+            "Wingsuit" => Ok(Self::Wingsuit),
+            "Whitewater" => Ok(Self::Whitewater),
+            "SkateSkiing" => Ok(Self::SkateSkiing),
+            "Yoga" => Ok(Self::Yoga),
+            "Pilates" => Ok(Self::Pilates),
+            "IndoorRunning" => Ok(Self::IndoorRunning),
+            "GravelCycling" => Ok(Self::GravelCycling),
+            "EBikeMountain" => Ok(Self::EBikeMountain),
+            "Commuting" => Ok(Self::Commuting),
+            "MixedSurface" => Ok(Self::MixedSurface),
+            "Navigate" => Ok(Self::Navigate),
+            "TrackMe" => Ok(Self::TrackMe),
+            "Map" => Ok(Self::Map),
+            "SingleGasDiving" => Ok(Self::SingleGasDiving),
+            "MultiGasDiving" => Ok(Self::MultiGasDiving),
+            "GaugeDiving" => Ok(Self::GaugeDiving),
+            "ApneaDiving" => Ok(Self::ApneaDiving),
+            "ApneaHunting" => Ok(Self::ApneaHunting),
+            "VirtualActivity" => Ok(Self::VirtualActivity),
+            "Obstacle" => Ok(Self::Obstacle),
+            "Breathing" => Ok(Self::Breathing),
+            "SailRace" => Ok(Self::SailRace),
+            "Ultra" => Ok(Self::Ultra),
+            "IndoorClimbing" => Ok(Self::IndoorClimbing),
+            "Bouldering" => Ok(Self::Bouldering),
+            "Hiit" => Ok(Self::Hiit),
+            "Amrap" => Ok(Self::Amrap),
+            "Emom" => Ok(Self::Emom),
+            "Tabata" => Ok(Self::Tabata),
+            "Pickleball" => Ok(Self::Pickleball),
+            "Padel" => Ok(Self::Padel),
+            "IndoorWheelchairWalk" => Ok(Self::IndoorWheelchairWalk),
+            "IndoorWheelchairRun" => Ok(Self::IndoorWheelchairRun),
+            "IndoorHandCycling" => Ok(Self::IndoorHandCycling),
+            "Squash" => Ok(Self::Squash),
+            "Badminton" => Ok(Self::Badminton),
+            "Racquetball" => Ok(Self::Racquetball),
+            "TableTennis" => Ok(Self::TableTennis),
+            "FlyCanopy" => Ok(Self::FlyCanopy),
+            "FlyParaglide" => Ok(Self::FlyParaglide),
+            "FlyParamotor" => Ok(Self::FlyParamotor),
+            "FlyPressurized" => Ok(Self::FlyPressurized),
+            "FlyNavigate" => Ok(Self::FlyNavigate),
+            "FlyTimer" => Ok(Self::FlyTimer),
+            "FlyAltimeter" => Ok(Self::FlyAltimeter),
+            "FlyWx" => Ok(Self::FlyWx),
+            "FlyVfr" => Ok(Self::FlyVfr),
+            "FlyIfr" => Ok(Self::FlyIfr),
+            "All" => Ok(Self::All),
+            _ => Err(UnknownVariantError::new("SubSport", name)),
+        }
+    }
+}
 impl SportEvent {
     pub fn from(content: u8) -> SportEvent {
         match content {
@@ -2060,22 +3720,76 @@ impl SportEvent {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SportEvent(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SportEvent] {
+        &[
+            SportEvent::Uncategorized,
+            SportEvent::Geocaching,
+            SportEvent::Fitness,
+            SportEvent::Recreation,
+            SportEvent::Race,
+            SportEvent::SpecialEvent,
+            SportEvent::Training,
+            SportEvent::Transportation,
+            SportEvent::Touring,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Uncategorized => "Uncategorized",
+            Self::Geocaching => "Geocaching",
+            Self::Fitness => "Fitness",
+            Self::Recreation => "Recreation",
+            Self::Race => "Race",
+            Self::SpecialEvent => "SpecialEvent",
+            Self::Training => "Training",
+            Self::Transportation => "Transportation",
+            Self::Touring => "Touring",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Activity {
     Manual,
     AutoMultiSport,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SportEvent {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SportEvent::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Uncategorized" => Ok(Self::Uncategorized),
+            "Geocaching" => Ok(Self::Geocaching),
+            "Fitness" => Ok(Self::Fitness),
+            "Recreation" => Ok(Self::Recreation),
+            "Race" => Ok(Self::Race),
+            "SpecialEvent" => Ok(Self::SpecialEvent),
+            "Training" => Ok(Self::Training),
+            "Transportation" => Ok(Self::Transportation),
+            "Touring" => Ok(Self::Touring),
+            _ => Err(UnknownVariantError::new("SportEvent", name)),
+        }
+    }
+}
 impl Activity {
     pub fn from(content: u8) -> Activity {
         match content {
@@ -2090,17 +3804,33 @@ impl Activity {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Activity(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Activity] {
+        &[Activity::Manual, Activity::AutoMultiSport]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::AutoMultiSport => "AutoMultiSport",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Intensity {
     Active,
     Rest,
@@ -2111,6 +3841,20 @@ pub enum Intensity {
     Other,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Activity {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Activity::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Manual" => Ok(Self::Manual),
+            "AutoMultiSport" => Ok(Self::AutoMultiSport),
+            _ => Err(UnknownVariantError::new("Activity", name)),
+        }
+    }
+}
 impl Intensity {
     pub fn from(content: u8) -> Intensity {
         match content {
@@ -2130,17 +3874,46 @@ impl Intensity {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Intensity(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Intensity] {
+        &[
+            Intensity::Active,
+            Intensity::Rest,
+            Intensity::Warmup,
+            Intensity::Cooldown,
+            Intensity::Recovery,
+            Intensity::Interval,
+            Intensity::Other,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "Active",
+            Self::Rest => "Rest",
+            Self::Warmup => "Warmup",
+            Self::Cooldown => "Cooldown",
+            Self::Recovery => "Recovery",
+            Self::Interval => "Interval",
+            Self::Other => "Other",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SessionTrigger {
     ActivityEnd,
     Manual,
@@ -2148,6 +3921,25 @@ pub enum SessionTrigger {
     FitnessEquipment,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Intensity {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Intensity::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Active" => Ok(Self::Active),
+            "Rest" => Ok(Self::Rest),
+            "Warmup" => Ok(Self::Warmup),
+            "Cooldown" => Ok(Self::Cooldown),
+            "Recovery" => Ok(Self::Recovery),
+            "Interval" => Ok(Self::Interval),
+            "Other" => Ok(Self::Other),
+            _ => Err(UnknownVariantError::new("Intensity", name)),
+        }
+    }
+}
 impl SessionTrigger {
     pub fn from(content: u8) -> SessionTrigger {
         match content {
@@ -2164,17 +3956,40 @@ impl SessionTrigger {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SessionTrigger(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SessionTrigger] {
+        &[
+            SessionTrigger::ActivityEnd,
+            SessionTrigger::Manual,
+            SessionTrigger::AutoMultiSport,
+            SessionTrigger::FitnessEquipment,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ActivityEnd => "ActivityEnd",
+            Self::Manual => "Manual",
+            Self::AutoMultiSport => "AutoMultiSport",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LapTrigger {
     Manual,
     Time,
@@ -2187,6 +4002,22 @@ pub enum LapTrigger {
     FitnessEquipment,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SessionTrigger {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SessionTrigger::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "ActivityEnd" => Ok(Self::ActivityEnd),
+            "Manual" => Ok(Self::Manual),
+            "AutoMultiSport" => Ok(Self::AutoMultiSport),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            _ => Err(UnknownVariantError::new("SessionTrigger", name)),
+        }
+    }
+}
 impl LapTrigger {
     pub fn from(content: u8) -> LapTrigger {
         match content {
@@ -2208,17 +4039,50 @@ impl LapTrigger {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LapTrigger(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LapTrigger] {
+        &[
+            LapTrigger::Manual,
+            LapTrigger::Time,
+            LapTrigger::Distance,
+            LapTrigger::PositionStart,
+            LapTrigger::PositionLap,
+            LapTrigger::PositionWaypoint,
+            LapTrigger::PositionMarked,
+            LapTrigger::SessionEnd,
+            LapTrigger::FitnessEquipment,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::Time => "Time",
+            Self::Distance => "Distance",
+            Self::PositionStart => "PositionStart",
+            Self::PositionLap => "PositionLap",
+            Self::PositionWaypoint => "PositionWaypoint",
+            Self::PositionMarked => "PositionMarked",
+            Self::SessionEnd => "SessionEnd",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TimeMode {
     Hour12,
     Hour24,
@@ -2228,10 +4092,31 @@ pub enum TimeMode {
     Utc,
     UnknownVariant(u8),
 }
-impl TimeMode {
-    pub fn from(content: u8) -> TimeMode {
-        match content {
-            0 => TimeMode::Hour12,
+
+impl std::str::FromStr for LapTrigger {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LapTrigger::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Manual" => Ok(Self::Manual),
+            "Time" => Ok(Self::Time),
+            "Distance" => Ok(Self::Distance),
+            "PositionStart" => Ok(Self::PositionStart),
+            "PositionLap" => Ok(Self::PositionLap),
+            "PositionWaypoint" => Ok(Self::PositionWaypoint),
+            "PositionMarked" => Ok(Self::PositionMarked),
+            "SessionEnd" => Ok(Self::SessionEnd),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            _ => Err(UnknownVariantError::new("LapTrigger", name)),
+        }
+    }
+}
+impl TimeMode {
+    pub fn from(content: u8) -> TimeMode {
+        match content {
+            0 => TimeMode::Hour12,
             1 => TimeMode::Hour24,
             2 => TimeMode::Military,
             3 => TimeMode::Hour12WithSeconds,
@@ -2246,17 +4131,44 @@ impl TimeMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::TimeMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [TimeMode] {
+        &[
+            TimeMode::Hour12,
+            TimeMode::Hour24,
+            TimeMode::Military,
+            TimeMode::Hour12WithSeconds,
+            TimeMode::Hour24WithSeconds,
+            TimeMode::Utc,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hour12 => "Hour12",
+            Self::Hour24 => "Hour24",
+            Self::Military => "Military",
+            Self::Hour12WithSeconds => "Hour12WithSeconds",
+            Self::Hour24WithSeconds => "Hour24WithSeconds",
+            Self::Utc => "Utc",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BacklightMode {
     Off,
     Manual,
@@ -2267,6 +4179,24 @@ pub enum BacklightMode {
     KeyAndMessagesAndSmartNotifications,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for TimeMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [TimeMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Hour12" => Ok(Self::Hour12),
+            "Hour24" => Ok(Self::Hour24),
+            "Military" => Ok(Self::Military),
+            "Hour12WithSeconds" => Ok(Self::Hour12WithSeconds),
+            "Hour24WithSeconds" => Ok(Self::Hour24WithSeconds),
+            "Utc" => Ok(Self::Utc),
+            _ => Err(UnknownVariantError::new("TimeMode", name)),
+        }
+    }
+}
 impl BacklightMode {
     pub fn from(content: u8) -> BacklightMode {
         match content {
@@ -2286,22 +4216,70 @@ impl BacklightMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BacklightMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BacklightMode] {
+        &[
+            BacklightMode::Off,
+            BacklightMode::Manual,
+            BacklightMode::KeyAndMessages,
+            BacklightMode::AutoBrightness,
+            BacklightMode::SmartNotifications,
+            BacklightMode::KeyAndMessagesNight,
+            BacklightMode::KeyAndMessagesAndSmartNotifications,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Manual => "Manual",
+            Self::KeyAndMessages => "KeyAndMessages",
+            Self::AutoBrightness => "AutoBrightness",
+            Self::SmartNotifications => "SmartNotifications",
+            Self::KeyAndMessagesNight => "KeyAndMessagesNight",
+            Self::KeyAndMessagesAndSmartNotifications => "KeyAndMessagesAndSmartNotifications",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DateMode {
     DayMonth,
     MonthDay,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for BacklightMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BacklightMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Off" => Ok(Self::Off),
+            "Manual" => Ok(Self::Manual),
+            "KeyAndMessages" => Ok(Self::KeyAndMessages),
+            "AutoBrightness" => Ok(Self::AutoBrightness),
+            "SmartNotifications" => Ok(Self::SmartNotifications),
+            "KeyAndMessagesNight" => Ok(Self::KeyAndMessagesNight),
+            "KeyAndMessagesAndSmartNotifications" => Ok(Self::KeyAndMessagesAndSmartNotifications),
+            _ => Err(UnknownVariantError::new("BacklightMode", name)),
+        }
+    }
+}
 impl DateMode {
     pub fn from(content: u8) -> DateMode {
         match content {
@@ -2316,21 +4294,51 @@ impl DateMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DateMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DateMode] {
+        &[DateMode::DayMonth, DateMode::MonthDay]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DayMonth => "DayMonth",
+            Self::MonthDay => "MonthDay",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BacklightTimeout {
     Infinite,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DateMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DateMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "DayMonth" => Ok(Self::DayMonth),
+            "MonthDay" => Ok(Self::MonthDay),
+            _ => Err(UnknownVariantError::new("DateMode", name)),
+        }
+    }
+}
 impl BacklightTimeout {
     pub fn from(content: u8) -> BacklightTimeout {
         match content {
@@ -2344,17 +4352,32 @@ impl BacklightTimeout {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BacklightTimeout(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BacklightTimeout] {
+        &[BacklightTimeout::Infinite]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Infinite => "Infinite",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Event {
     Timer,
     Workout,
@@ -2404,6 +4427,19 @@ pub enum Event {
     TankPodDisconnected,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for BacklightTimeout {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BacklightTimeout::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Infinite" => Ok(Self::Infinite),
+            _ => Err(UnknownVariantError::new("BacklightTimeout", name)),
+        }
+    }
+}
 impl Event {
     pub fn from(content: u8) -> Event {
         match content {
@@ -2462,17 +4498,124 @@ impl Event {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Event(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Event] {
+        &[
+            Event::Timer,
+            Event::Workout,
+            Event::WorkoutStep,
+            Event::PowerDown,
+            Event::PowerUp,
+            Event::OffCourse,
+            Event::Session,
+            Event::Lap,
+            Event::CoursePoint,
+            Event::Battery,
+            Event::VirtualPartnerPace,
+            Event::HrHighAlert,
+            Event::HrLowAlert,
+            Event::SpeedHighAlert,
+            Event::SpeedLowAlert,
+            Event::CadHighAlert,
+            Event::CadLowAlert,
+            Event::PowerHighAlert,
+            Event::PowerLowAlert,
+            Event::RecoveryHr,
+            Event::BatteryLow,
+            Event::TimeDurationAlert,
+            Event::DistanceDurationAlert,
+            Event::CalorieDurationAlert,
+            Event::Activity,
+            Event::FitnessEquipment,
+            Event::Length,
+            Event::UserMarker,
+            Event::SportPoint,
+            Event::Calibration,
+            Event::FrontGearChange,
+            Event::RearGearChange,
+            Event::RiderPositionChange,
+            Event::ElevHighAlert,
+            Event::ElevLowAlert,
+            Event::CommTimeout,
+            Event::AutoActivityDetect,
+            Event::DiveAlert,
+            Event::DiveGasSwitched,
+            Event::TankPressureReserve,
+            Event::TankPressureCritical,
+            Event::TankLost,
+            Event::RadarThreatAlert,
+            Event::TankBatteryLow,
+            Event::TankPodConnected,
+            Event::TankPodDisconnected,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Timer => "Timer",
+            Self::Workout => "Workout",
+            Self::WorkoutStep => "WorkoutStep",
+            Self::PowerDown => "PowerDown",
+            Self::PowerUp => "PowerUp",
+            Self::OffCourse => "OffCourse",
+            Self::Session => "Session",
+            Self::Lap => "Lap",
+            Self::CoursePoint => "CoursePoint",
+            Self::Battery => "Battery",
+            Self::VirtualPartnerPace => "VirtualPartnerPace",
+            Self::HrHighAlert => "HrHighAlert",
+            Self::HrLowAlert => "HrLowAlert",
+            Self::SpeedHighAlert => "SpeedHighAlert",
+            Self::SpeedLowAlert => "SpeedLowAlert",
+            Self::CadHighAlert => "CadHighAlert",
+            Self::CadLowAlert => "CadLowAlert",
+            Self::PowerHighAlert => "PowerHighAlert",
+            Self::PowerLowAlert => "PowerLowAlert",
+            Self::RecoveryHr => "RecoveryHr",
+            Self::BatteryLow => "BatteryLow",
+            Self::TimeDurationAlert => "TimeDurationAlert",
+            Self::DistanceDurationAlert => "DistanceDurationAlert",
+            Self::CalorieDurationAlert => "CalorieDurationAlert",
+            Self::Activity => "Activity",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::Length => "Length",
+            Self::UserMarker => "UserMarker",
+            Self::SportPoint => "SportPoint",
+            Self::Calibration => "Calibration",
+            Self::FrontGearChange => "FrontGearChange",
+            Self::RearGearChange => "RearGearChange",
+            Self::RiderPositionChange => "RiderPositionChange",
+            Self::ElevHighAlert => "ElevHighAlert",
+            Self::ElevLowAlert => "ElevLowAlert",
+            Self::CommTimeout => "CommTimeout",
+            Self::AutoActivityDetect => "AutoActivityDetect",
+            Self::DiveAlert => "DiveAlert",
+            Self::DiveGasSwitched => "DiveGasSwitched",
+            Self::TankPressureReserve => "TankPressureReserve",
+            Self::TankPressureCritical => "TankPressureCritical",
+            Self::TankLost => "TankLost",
+            Self::RadarThreatAlert => "RadarThreatAlert",
+            Self::TankBatteryLow => "TankBatteryLow",
+            Self::TankPodConnected => "TankPodConnected",
+            Self::TankPodDisconnected => "TankPodDisconnected",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum EventType {
     Start,
     Stop,
@@ -2486,6 +4629,64 @@ pub enum EventType {
     StopDisableAll,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Event {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Event::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Timer" => Ok(Self::Timer),
+            "Workout" => Ok(Self::Workout),
+            "WorkoutStep" => Ok(Self::WorkoutStep),
+            "PowerDown" => Ok(Self::PowerDown),
+            "PowerUp" => Ok(Self::PowerUp),
+            "OffCourse" => Ok(Self::OffCourse),
+            "Session" => Ok(Self::Session),
+            "Lap" => Ok(Self::Lap),
+            "CoursePoint" => Ok(Self::CoursePoint),
+            "Battery" => Ok(Self::Battery),
+            "VirtualPartnerPace" => Ok(Self::VirtualPartnerPace),
+            "HrHighAlert" => Ok(Self::HrHighAlert),
+            "HrLowAlert" => Ok(Self::HrLowAlert),
+            "SpeedHighAlert" => Ok(Self::SpeedHighAlert),
+            "SpeedLowAlert" => Ok(Self::SpeedLowAlert),
+            "CadHighAlert" => Ok(Self::CadHighAlert),
+            "CadLowAlert" => Ok(Self::CadLowAlert),
+            "PowerHighAlert" => Ok(Self::PowerHighAlert),
+            "PowerLowAlert" => Ok(Self::PowerLowAlert),
+            "RecoveryHr" => Ok(Self::RecoveryHr),
+            "BatteryLow" => Ok(Self::BatteryLow),
+            "TimeDurationAlert" => Ok(Self::TimeDurationAlert),
+            "DistanceDurationAlert" => Ok(Self::DistanceDurationAlert),
+            "CalorieDurationAlert" => Ok(Self::CalorieDurationAlert),
+            "Activity" => Ok(Self::Activity),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "Length" => Ok(Self::Length),
+            "UserMarker" => Ok(Self::UserMarker),
+            "SportPoint" => Ok(Self::SportPoint),
+            "Calibration" => Ok(Self::Calibration),
+            "FrontGearChange" => Ok(Self::FrontGearChange),
+            "RearGearChange" => Ok(Self::RearGearChange),
+            "RiderPositionChange" => Ok(Self::RiderPositionChange),
+            "ElevHighAlert" => Ok(Self::ElevHighAlert),
+            "ElevLowAlert" => Ok(Self::ElevLowAlert),
+            "CommTimeout" => Ok(Self::CommTimeout),
+            "AutoActivityDetect" => Ok(Self::AutoActivityDetect),
+            "DiveAlert" => Ok(Self::DiveAlert),
+            "DiveGasSwitched" => Ok(Self::DiveGasSwitched),
+            "TankPressureReserve" => Ok(Self::TankPressureReserve),
+            "TankPressureCritical" => Ok(Self::TankPressureCritical),
+            "TankLost" => Ok(Self::TankLost),
+            "RadarThreatAlert" => Ok(Self::RadarThreatAlert),
+            "TankBatteryLow" => Ok(Self::TankBatteryLow),
+            "TankPodConnected" => Ok(Self::TankPodConnected),
+            "TankPodDisconnected" => Ok(Self::TankPodDisconnected),
+            _ => Err(UnknownVariantError::new("Event", name)),
+        }
+    }
+}
 impl EventType {
     pub fn from(content: u8) -> EventType {
         match content {
@@ -2508,23 +4709,80 @@ impl EventType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::EventType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [EventType] {
+        &[
+            EventType::Start,
+            EventType::Stop,
+            EventType::ConsecutiveDepreciated,
+            EventType::Marker,
+            EventType::StopAll,
+            EventType::BeginDepreciated,
+            EventType::EndDepreciated,
+            EventType::EndAllDepreciated,
+            EventType::StopDisable,
+            EventType::StopDisableAll,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Start => "Start",
+            Self::Stop => "Stop",
+            Self::ConsecutiveDepreciated => "ConsecutiveDepreciated",
+            Self::Marker => "Marker",
+            Self::StopAll => "StopAll",
+            Self::BeginDepreciated => "BeginDepreciated",
+            Self::EndDepreciated => "EndDepreciated",
+            Self::EndAllDepreciated => "EndAllDepreciated",
+            Self::StopDisable => "StopDisable",
+            Self::StopDisableAll => "StopDisableAll",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TimerTrigger {
     Manual,
     Auto,
     FitnessEquipment,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for EventType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [EventType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Start" => Ok(Self::Start),
+            "Stop" => Ok(Self::Stop),
+            "ConsecutiveDepreciated" => Ok(Self::ConsecutiveDepreciated),
+            "Marker" => Ok(Self::Marker),
+            "StopAll" => Ok(Self::StopAll),
+            "BeginDepreciated" => Ok(Self::BeginDepreciated),
+            "EndDepreciated" => Ok(Self::EndDepreciated),
+            "EndAllDepreciated" => Ok(Self::EndAllDepreciated),
+            "StopDisable" => Ok(Self::StopDisable),
+            "StopDisableAll" => Ok(Self::StopDisableAll),
+            _ => Err(UnknownVariantError::new("EventType", name)),
+        }
+    }
+}
 impl TimerTrigger {
     pub fn from(content: u8) -> TimerTrigger {
         match content {
@@ -2540,17 +4798,38 @@ impl TimerTrigger {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::TimerTrigger(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [TimerTrigger] {
+        &[
+            TimerTrigger::Manual,
+            TimerTrigger::Auto,
+            TimerTrigger::FitnessEquipment,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::Auto => "Auto",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum FitnessEquipmentState {
     Ready,
     InUse,
@@ -2558,6 +4837,21 @@ pub enum FitnessEquipmentState {
     Unknown,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for TimerTrigger {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [TimerTrigger::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Manual" => Ok(Self::Manual),
+            "Auto" => Ok(Self::Auto),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            _ => Err(UnknownVariantError::new("TimerTrigger", name)),
+        }
+    }
+}
 impl FitnessEquipmentState {
     pub fn from(content: u8) -> FitnessEquipmentState {
         match content {
@@ -2574,17 +4868,40 @@ impl FitnessEquipmentState {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::FitnessEquipmentState(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [FitnessEquipmentState] {
+        &[
+            FitnessEquipmentState::Ready,
+            FitnessEquipmentState::InUse,
+            FitnessEquipmentState::Paused,
+            FitnessEquipmentState::Unknown,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ready => "Ready",
+            Self::InUse => "InUse",
+            Self::Paused => "Paused",
+            Self::Unknown => "Unknown",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Tone {
     Off,
     Tone,
@@ -2592,6 +4909,22 @@ pub enum Tone {
     ToneAndVibrate,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for FitnessEquipmentState {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [FitnessEquipmentState::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Ready" => Ok(Self::Ready),
+            "InUse" => Ok(Self::InUse),
+            "Paused" => Ok(Self::Paused),
+            "Unknown" => Ok(Self::Unknown),
+            _ => Err(UnknownVariantError::new("FitnessEquipmentState", name)),
+        }
+    }
+}
 impl Tone {
     pub fn from(content: u8) -> Tone {
         match content {
@@ -2608,23 +4941,57 @@ impl Tone {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Tone(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Tone] {
+        &[Tone::Off, Tone::Tone, Tone::Vibrate, Tone::ToneAndVibrate]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Tone => "Tone",
+            Self::Vibrate => "Vibrate",
+            Self::ToneAndVibrate => "ToneAndVibrate",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ActivityClass {
     Level,
     LevelMax,
     Athlete,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Tone {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Tone::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Off" => Ok(Self::Off),
+            "Tone" => Ok(Self::Tone),
+            "Vibrate" => Ok(Self::Vibrate),
+            "ToneAndVibrate" => Ok(Self::ToneAndVibrate),
+            _ => Err(UnknownVariantError::new("Tone", name)),
+        }
+    }
+}
 impl ActivityClass {
     pub fn from(content: u8) -> ActivityClass {
         match content {
@@ -2640,17 +5007,38 @@ impl ActivityClass {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ActivityClass(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ActivityClass] {
+        &[
+            ActivityClass::Level,
+            ActivityClass::LevelMax,
+            ActivityClass::Athlete,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Level => "Level",
+            Self::LevelMax => "LevelMax",
+            Self::Athlete => "Athlete",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum HrZoneCalc {
     Custom,
     PercentMaxHr,
@@ -2658,6 +5046,21 @@ pub enum HrZoneCalc {
     PercentLthr,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ActivityClass {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ActivityClass::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Level" => Ok(Self::Level),
+            "LevelMax" => Ok(Self::LevelMax),
+            "Athlete" => Ok(Self::Athlete),
+            _ => Err(UnknownVariantError::new("ActivityClass", name)),
+        }
+    }
+}
 impl HrZoneCalc {
     pub fn from(content: u8) -> HrZoneCalc {
         match content {
@@ -2674,22 +5077,61 @@ impl HrZoneCalc {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::HrZoneCalc(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [HrZoneCalc] {
+        &[
+            HrZoneCalc::Custom,
+            HrZoneCalc::PercentMaxHr,
+            HrZoneCalc::PercentHrr,
+            HrZoneCalc::PercentLthr,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Custom => "Custom",
+            Self::PercentMaxHr => "PercentMaxHr",
+            Self::PercentHrr => "PercentHrr",
+            Self::PercentLthr => "PercentLthr",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum PwrZoneCalc {
     Custom,
     PercentFtp,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for HrZoneCalc {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [HrZoneCalc::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Custom" => Ok(Self::Custom),
+            "PercentMaxHr" => Ok(Self::PercentMaxHr),
+            "PercentHrr" => Ok(Self::PercentHrr),
+            "PercentLthr" => Ok(Self::PercentLthr),
+            _ => Err(UnknownVariantError::new("HrZoneCalc", name)),
+        }
+    }
+}
 impl PwrZoneCalc {
     pub fn from(content: u8) -> PwrZoneCalc {
         match content {
@@ -2704,17 +5146,33 @@ impl PwrZoneCalc {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::PwrZoneCalc(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [PwrZoneCalc] {
+        &[PwrZoneCalc::Custom, PwrZoneCalc::PercentFtp]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Custom => "Custom",
+            Self::PercentFtp => "PercentFtp",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WktStepDuration {
     Time,
     Distance,
@@ -2749,6 +5207,20 @@ pub enum WktStepDuration {
     TimeOnly,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for PwrZoneCalc {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [PwrZoneCalc::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Custom" => Ok(Self::Custom),
+            "PercentFtp" => Ok(Self::PercentFtp),
+            _ => Err(UnknownVariantError::new("PwrZoneCalc", name)),
+        }
+    }
+}
 impl WktStepDuration {
     pub fn from(content: u8) -> WktStepDuration {
         match content {
@@ -2792,17 +5264,94 @@ impl WktStepDuration {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WktStepDuration(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WktStepDuration] {
+        &[
+            WktStepDuration::Time,
+            WktStepDuration::Distance,
+            WktStepDuration::HrLessThan,
+            WktStepDuration::HrGreaterThan,
+            WktStepDuration::Calories,
+            WktStepDuration::Open,
+            WktStepDuration::RepeatUntilStepsCmplt,
+            WktStepDuration::RepeatUntilTime,
+            WktStepDuration::RepeatUntilDistance,
+            WktStepDuration::RepeatUntilCalories,
+            WktStepDuration::RepeatUntilHrLessThan,
+            WktStepDuration::RepeatUntilHrGreaterThan,
+            WktStepDuration::RepeatUntilPowerLessThan,
+            WktStepDuration::RepeatUntilPowerGreaterThan,
+            WktStepDuration::PowerLessThan,
+            WktStepDuration::PowerGreaterThan,
+            WktStepDuration::TrainingPeaksTss,
+            WktStepDuration::RepeatUntilPowerLastLapLessThan,
+            WktStepDuration::RepeatUntilMaxPowerLastLapLessThan,
+            WktStepDuration::Power3sLessThan,
+            WktStepDuration::Power10sLessThan,
+            WktStepDuration::Power30sLessThan,
+            WktStepDuration::Power3sGreaterThan,
+            WktStepDuration::Power10sGreaterThan,
+            WktStepDuration::Power30sGreaterThan,
+            WktStepDuration::PowerLapLessThan,
+            WktStepDuration::PowerLapGreaterThan,
+            WktStepDuration::RepeatUntilTrainingPeaksTss,
+            WktStepDuration::RepetitionTime,
+            WktStepDuration::Reps,
+            WktStepDuration::TimeOnly,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Time => "Time",
+            Self::Distance => "Distance",
+            Self::HrLessThan => "HrLessThan",
+            Self::HrGreaterThan => "HrGreaterThan",
+            Self::Calories => "Calories",
+            Self::Open => "Open",
+            Self::RepeatUntilStepsCmplt => "RepeatUntilStepsCmplt",
+            Self::RepeatUntilTime => "RepeatUntilTime",
+            Self::RepeatUntilDistance => "RepeatUntilDistance",
+            Self::RepeatUntilCalories => "RepeatUntilCalories",
+            Self::RepeatUntilHrLessThan => "RepeatUntilHrLessThan",
+            Self::RepeatUntilHrGreaterThan => "RepeatUntilHrGreaterThan",
+            Self::RepeatUntilPowerLessThan => "RepeatUntilPowerLessThan",
+            Self::RepeatUntilPowerGreaterThan => "RepeatUntilPowerGreaterThan",
+            Self::PowerLessThan => "PowerLessThan",
+            Self::PowerGreaterThan => "PowerGreaterThan",
+            Self::TrainingPeaksTss => "TrainingPeaksTss",
+            Self::RepeatUntilPowerLastLapLessThan => "RepeatUntilPowerLastLapLessThan",
+            Self::RepeatUntilMaxPowerLastLapLessThan => "RepeatUntilMaxPowerLastLapLessThan",
+            Self::Power3sLessThan => "Power3sLessThan",
+            Self::Power10sLessThan => "Power10sLessThan",
+            Self::Power30sLessThan => "Power30sLessThan",
+            Self::Power3sGreaterThan => "Power3sGreaterThan",
+            Self::Power10sGreaterThan => "Power10sGreaterThan",
+            Self::Power30sGreaterThan => "Power30sGreaterThan",
+            Self::PowerLapLessThan => "PowerLapLessThan",
+            Self::PowerLapGreaterThan => "PowerLapGreaterThan",
+            Self::RepeatUntilTrainingPeaksTss => "RepeatUntilTrainingPeaksTss",
+            Self::RepetitionTime => "RepetitionTime",
+            Self::Reps => "Reps",
+            Self::TimeOnly => "TimeOnly",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WktStepTarget {
     Speed,
     HeartRate,
@@ -2820,6 +5369,49 @@ pub enum WktStepTarget {
     HeartRateLap,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WktStepDuration {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WktStepDuration::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Time" => Ok(Self::Time),
+            "Distance" => Ok(Self::Distance),
+            "HrLessThan" => Ok(Self::HrLessThan),
+            "HrGreaterThan" => Ok(Self::HrGreaterThan),
+            "Calories" => Ok(Self::Calories),
+            "Open" => Ok(Self::Open),
+            "RepeatUntilStepsCmplt" => Ok(Self::RepeatUntilStepsCmplt),
+            "RepeatUntilTime" => Ok(Self::RepeatUntilTime),
+            "RepeatUntilDistance" => Ok(Self::RepeatUntilDistance),
+            "RepeatUntilCalories" => Ok(Self::RepeatUntilCalories),
+            "RepeatUntilHrLessThan" => Ok(Self::RepeatUntilHrLessThan),
+            "RepeatUntilHrGreaterThan" => Ok(Self::RepeatUntilHrGreaterThan),
+            "RepeatUntilPowerLessThan" => Ok(Self::RepeatUntilPowerLessThan),
+            "RepeatUntilPowerGreaterThan" => Ok(Self::RepeatUntilPowerGreaterThan),
+            "PowerLessThan" => Ok(Self::PowerLessThan),
+            "PowerGreaterThan" => Ok(Self::PowerGreaterThan),
+            "TrainingPeaksTss" => Ok(Self::TrainingPeaksTss),
+            "RepeatUntilPowerLastLapLessThan" => Ok(Self::RepeatUntilPowerLastLapLessThan),
+            "RepeatUntilMaxPowerLastLapLessThan" => Ok(Self::RepeatUntilMaxPowerLastLapLessThan),
+            "Power3sLessThan" => Ok(Self::Power3sLessThan),
+            "Power10sLessThan" => Ok(Self::Power10sLessThan),
+            "Power30sLessThan" => Ok(Self::Power30sLessThan),
+            "Power3sGreaterThan" => Ok(Self::Power3sGreaterThan),
+            "Power10sGreaterThan" => Ok(Self::Power10sGreaterThan),
+            "Power30sGreaterThan" => Ok(Self::Power30sGreaterThan),
+            "PowerLapLessThan" => Ok(Self::PowerLapLessThan),
+            "PowerLapGreaterThan" => Ok(Self::PowerLapGreaterThan),
+            "RepeatUntilTrainingPeaksTss" => Ok(Self::RepeatUntilTrainingPeaksTss),
+            "RepetitionTime" => Ok(Self::RepetitionTime),
+            "Reps" => Ok(Self::Reps),
+            "TimeOnly" => Ok(Self::TimeOnly),
+            _ => Err(UnknownVariantError::new("WktStepDuration", name)),
+        }
+    }
+}
 impl WktStepTarget {
     pub fn from(content: u8) -> WktStepTarget {
         match content {
@@ -2846,17 +5438,60 @@ impl WktStepTarget {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WktStepTarget(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WktStepTarget] {
+        &[
+            WktStepTarget::Speed,
+            WktStepTarget::HeartRate,
+            WktStepTarget::Open,
+            WktStepTarget::Cadence,
+            WktStepTarget::Power,
+            WktStepTarget::Grade,
+            WktStepTarget::Resistance,
+            WktStepTarget::Power3s,
+            WktStepTarget::Power10s,
+            WktStepTarget::Power30s,
+            WktStepTarget::PowerLap,
+            WktStepTarget::SwimStroke,
+            WktStepTarget::SpeedLap,
+            WktStepTarget::HeartRateLap,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Speed => "Speed",
+            Self::HeartRate => "HeartRate",
+            Self::Open => "Open",
+            Self::Cadence => "Cadence",
+            Self::Power => "Power",
+            Self::Grade => "Grade",
+            Self::Resistance => "Resistance",
+            Self::Power3s => "Power3s",
+            Self::Power10s => "Power10s",
+            Self::Power30s => "Power30s",
+            Self::PowerLap => "PowerLap",
+            Self::SwimStroke => "SwimStroke",
+            Self::SpeedLap => "SpeedLap",
+            Self::HeartRateLap => "HeartRateLap",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Goal {
     Time,
     Distance,
@@ -2867,6 +5502,32 @@ pub enum Goal {
     ActiveMinutes,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WktStepTarget {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WktStepTarget::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Speed" => Ok(Self::Speed),
+            "HeartRate" => Ok(Self::HeartRate),
+            "Open" => Ok(Self::Open),
+            "Cadence" => Ok(Self::Cadence),
+            "Power" => Ok(Self::Power),
+            "Grade" => Ok(Self::Grade),
+            "Resistance" => Ok(Self::Resistance),
+            "Power3s" => Ok(Self::Power3s),
+            "Power10s" => Ok(Self::Power10s),
+            "Power30s" => Ok(Self::Power30s),
+            "PowerLap" => Ok(Self::PowerLap),
+            "SwimStroke" => Ok(Self::SwimStroke),
+            "SpeedLap" => Ok(Self::SpeedLap),
+            "HeartRateLap" => Ok(Self::HeartRateLap),
+            _ => Err(UnknownVariantError::new("WktStepTarget", name)),
+        }
+    }
+}
 impl Goal {
     pub fn from(content: u8) -> Goal {
         match content {
@@ -2886,17 +5547,46 @@ impl Goal {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Goal(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Goal] {
+        &[
+            Goal::Time,
+            Goal::Distance,
+            Goal::Calories,
+            Goal::Frequency,
+            Goal::Steps,
+            Goal::Ascent,
+            Goal::ActiveMinutes,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Time => "Time",
+            Self::Distance => "Distance",
+            Self::Calories => "Calories",
+            Self::Frequency => "Frequency",
+            Self::Steps => "Steps",
+            Self::Ascent => "Ascent",
+            Self::ActiveMinutes => "ActiveMinutes",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GoalRecurrence {
     Off,
     Daily,
@@ -2906,6 +5596,25 @@ pub enum GoalRecurrence {
     Custom,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Goal {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Goal::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Time" => Ok(Self::Time),
+            "Distance" => Ok(Self::Distance),
+            "Calories" => Ok(Self::Calories),
+            "Frequency" => Ok(Self::Frequency),
+            "Steps" => Ok(Self::Steps),
+            "Ascent" => Ok(Self::Ascent),
+            "ActiveMinutes" => Ok(Self::ActiveMinutes),
+            _ => Err(UnknownVariantError::new("Goal", name)),
+        }
+    }
+}
 impl GoalRecurrence {
     pub fn from(content: u8) -> GoalRecurrence {
         match content {
@@ -2924,23 +5633,68 @@ impl GoalRecurrence {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::GoalRecurrence(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [GoalRecurrence] {
+        &[
+            GoalRecurrence::Off,
+            GoalRecurrence::Daily,
+            GoalRecurrence::Weekly,
+            GoalRecurrence::Monthly,
+            GoalRecurrence::Yearly,
+            GoalRecurrence::Custom,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+            Self::Yearly => "Yearly",
+            Self::Custom => "Custom",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GoalSource {
     Auto,
     Community,
     User,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for GoalRecurrence {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [GoalRecurrence::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Off" => Ok(Self::Off),
+            "Daily" => Ok(Self::Daily),
+            "Weekly" => Ok(Self::Weekly),
+            "Monthly" => Ok(Self::Monthly),
+            "Yearly" => Ok(Self::Yearly),
+            "Custom" => Ok(Self::Custom),
+            _ => Err(UnknownVariantError::new("GoalRecurrence", name)),
+        }
+    }
+}
 impl GoalSource {
     pub fn from(content: u8) -> GoalSource {
         match content {
@@ -2956,22 +5710,54 @@ impl GoalSource {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::GoalSource(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [GoalSource] {
+        &[GoalSource::Auto, GoalSource::Community, GoalSource::User]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::Community => "Community",
+            Self::User => "User",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Schedule {
     Workout,
     Course,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for GoalSource {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [GoalSource::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Auto" => Ok(Self::Auto),
+            "Community" => Ok(Self::Community),
+            "User" => Ok(Self::User),
+            _ => Err(UnknownVariantError::new("GoalSource", name)),
+        }
+    }
+}
 impl Schedule {
     pub fn from(content: u8) -> Schedule {
         match content {
@@ -2986,17 +5772,33 @@ impl Schedule {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Schedule(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Schedule] {
+        &[Schedule::Workout, Schedule::Course]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Workout => "Workout",
+            Self::Course => "Course",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum CoursePoint {
     Generic,
     Summit,
@@ -3053,6 +5855,20 @@ pub enum CoursePoint {
     Info,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Schedule {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Schedule::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Workout" => Ok(Self::Workout),
+            "Course" => Ok(Self::Course),
+            _ => Err(UnknownVariantError::new("Schedule", name)),
+        }
+    }
+}
 impl CoursePoint {
     pub fn from(content: u8) -> CoursePoint {
         match content {
@@ -3118,17 +5934,138 @@ impl CoursePoint {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CoursePoint(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CoursePoint] {
+        &[
+            CoursePoint::Generic,
+            CoursePoint::Summit,
+            CoursePoint::Valley,
+            CoursePoint::Water,
+            CoursePoint::Food,
+            CoursePoint::Danger,
+            CoursePoint::Left,
+            CoursePoint::Right,
+            CoursePoint::Straight,
+            CoursePoint::FirstAid,
+            CoursePoint::FourthCategory,
+            CoursePoint::ThirdCategory,
+            CoursePoint::SecondCategory,
+            CoursePoint::FirstCategory,
+            CoursePoint::HorsCategory,
+            CoursePoint::Sprint,
+            CoursePoint::LeftFork,
+            CoursePoint::RightFork,
+            CoursePoint::MiddleFork,
+            CoursePoint::SlightLeft,
+            CoursePoint::SharpLeft,
+            CoursePoint::SlightRight,
+            CoursePoint::SharpRight,
+            CoursePoint::UTurn,
+            CoursePoint::SegmentStart,
+            CoursePoint::SegmentEnd,
+            CoursePoint::Campsite,
+            CoursePoint::AidStation,
+            CoursePoint::RestArea,
+            CoursePoint::GeneralDistance,
+            CoursePoint::Service,
+            CoursePoint::EnergyGel,
+            CoursePoint::SportsDrink,
+            CoursePoint::MileMarker,
+            CoursePoint::Checkpoint,
+            CoursePoint::Shelter,
+            CoursePoint::MeetingSpot,
+            CoursePoint::Overlook,
+            CoursePoint::Toilet,
+            CoursePoint::Shower,
+            CoursePoint::Gear,
+            CoursePoint::SharpCurve,
+            CoursePoint::SteepIncline,
+            CoursePoint::Tunnel,
+            CoursePoint::Bridge,
+            CoursePoint::Obstacle,
+            CoursePoint::Crossing,
+            CoursePoint::Store,
+            CoursePoint::Transition,
+            CoursePoint::Navaid,
+            CoursePoint::Transport,
+            CoursePoint::Alert,
+            CoursePoint::Info,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Summit => "Summit",
+            Self::Valley => "Valley",
+            Self::Water => "Water",
+            Self::Food => "Food",
+            Self::Danger => "Danger",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Straight => "Straight",
+            Self::FirstAid => "FirstAid",
+            Self::FourthCategory => "FourthCategory",
+            Self::ThirdCategory => "ThirdCategory",
+            Self::SecondCategory => "SecondCategory",
+            Self::FirstCategory => "FirstCategory",
+            Self::HorsCategory => "HorsCategory",
+            Self::Sprint => "Sprint",
+            Self::LeftFork => "LeftFork",
+            Self::RightFork => "RightFork",
+            Self::MiddleFork => "MiddleFork",
+            Self::SlightLeft => "SlightLeft",
+            Self::SharpLeft => "SharpLeft",
+            Self::SlightRight => "SlightRight",
+            Self::SharpRight => "SharpRight",
+            Self::UTurn => "UTurn",
+            Self::SegmentStart => "SegmentStart",
+            Self::SegmentEnd => "SegmentEnd",
+            Self::Campsite => "Campsite",
+            Self::AidStation => "AidStation",
+            Self::RestArea => "RestArea",
+            Self::GeneralDistance => "GeneralDistance",
+            Self::Service => "Service",
+            Self::EnergyGel => "EnergyGel",
+            Self::SportsDrink => "SportsDrink",
+            Self::MileMarker => "MileMarker",
+            Self::Checkpoint => "Checkpoint",
+            Self::Shelter => "Shelter",
+            Self::MeetingSpot => "MeetingSpot",
+            Self::Overlook => "Overlook",
+            Self::Toilet => "Toilet",
+            Self::Shower => "Shower",
+            Self::Gear => "Gear",
+            Self::SharpCurve => "SharpCurve",
+            Self::SteepIncline => "SteepIncline",
+            Self::Tunnel => "Tunnel",
+            Self::Bridge => "Bridge",
+            Self::Obstacle => "Obstacle",
+            Self::Crossing => "Crossing",
+            Self::Store => "Store",
+            Self::Transition => "Transition",
+            Self::Navaid => "Navaid",
+            Self::Transport => "Transport",
+            Self::Alert => "Alert",
+            Self::Info => "Info",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Manufacturer {
     Garmin,
     GarminFr405Antfs,
@@ -3364,6 +6301,71 @@ pub enum Manufacturer {
     Actigraphcorp,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for CoursePoint {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CoursePoint::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Summit" => Ok(Self::Summit),
+            "Valley" => Ok(Self::Valley),
+            "Water" => Ok(Self::Water),
+            "Food" => Ok(Self::Food),
+            "Danger" => Ok(Self::Danger),
+            "Left" => Ok(Self::Left),
+            "Right" => Ok(Self::Right),
+            "Straight" => Ok(Self::Straight),
+            "FirstAid" => Ok(Self::FirstAid),
+            "FourthCategory" => Ok(Self::FourthCategory),
+            "ThirdCategory" => Ok(Self::ThirdCategory),
+            "SecondCategory" => Ok(Self::SecondCategory),
+            "FirstCategory" => Ok(Self::FirstCategory),
+            "HorsCategory" => Ok(Self::HorsCategory),
+            "Sprint" => Ok(Self::Sprint),
+            "LeftFork" => Ok(Self::LeftFork),
+            "RightFork" => Ok(Self::RightFork),
+            "MiddleFork" => Ok(Self::MiddleFork),
+            "SlightLeft" => Ok(Self::SlightLeft),
+            "SharpLeft" => Ok(Self::SharpLeft),
+            "SlightRight" => Ok(Self::SlightRight),
+            "SharpRight" => Ok(Self::SharpRight),
+            "UTurn" => Ok(Self::UTurn),
+            "SegmentStart" => Ok(Self::SegmentStart),
+            "SegmentEnd" => Ok(Self::SegmentEnd),
+            "Campsite" => Ok(Self::Campsite),
+            "AidStation" => Ok(Self::AidStation),
+            "RestArea" => Ok(Self::RestArea),
+            "GeneralDistance" => Ok(Self::GeneralDistance),
+            "Service" => Ok(Self::Service),
+            "EnergyGel" => Ok(Self::EnergyGel),
+            "SportsDrink" => Ok(Self::SportsDrink),
+            "MileMarker" => Ok(Self::MileMarker),
+            "Checkpoint" => Ok(Self::Checkpoint),
+            "Shelter" => Ok(Self::Shelter),
+            "MeetingSpot" => Ok(Self::MeetingSpot),
+            "Overlook" => Ok(Self::Overlook),
+            "Toilet" => Ok(Self::Toilet),
+            "Shower" => Ok(Self::Shower),
+            "Gear" => Ok(Self::Gear),
+            "SharpCurve" => Ok(Self::SharpCurve),
+            "SteepIncline" => Ok(Self::SteepIncline),
+            "Tunnel" => Ok(Self::Tunnel),
+            "Bridge" => Ok(Self::Bridge),
+            "Obstacle" => Ok(Self::Obstacle),
+            "Crossing" => Ok(Self::Crossing),
+            "Store" => Ok(Self::Store),
+            "Transition" => Ok(Self::Transition),
+            "Navaid" => Ok(Self::Navaid),
+            "Transport" => Ok(Self::Transport),
+            "Alert" => Ok(Self::Alert),
+            "Info" => Ok(Self::Info),
+            _ => Err(UnknownVariantError::new("CoursePoint", name)),
+        }
+    }
+}
 impl Manufacturer {
     pub fn from(content: u16) -> Manufacturer {
         match content {
@@ -3608,17 +6610,496 @@ impl Manufacturer {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Manufacturer(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Manufacturer] {
+        &[
+            Manufacturer::Garmin,
+            Manufacturer::GarminFr405Antfs,
+            Manufacturer::Zephyr,
+            Manufacturer::Dayton,
+            Manufacturer::Idt,
+            Manufacturer::Srm,
+            Manufacturer::Quarq,
+            Manufacturer::Ibike,
+            Manufacturer::Saris,
+            Manufacturer::SparkHk,
+            Manufacturer::Tanita,
+            Manufacturer::Echowell,
+            Manufacturer::DynastreamOem,
+            Manufacturer::Nautilus,
+            Manufacturer::Dynastream,
+            Manufacturer::Timex,
+            Manufacturer::Metrigear,
+            Manufacturer::Xelic,
+            Manufacturer::Beurer,
+            Manufacturer::Cardiosport,
+            Manufacturer::AAndD,
+            Manufacturer::Hmm,
+            Manufacturer::Suunto,
+            Manufacturer::ThitaElektronik,
+            Manufacturer::Gpulse,
+            Manufacturer::CleanMobile,
+            Manufacturer::PedalBrain,
+            Manufacturer::Peaksware,
+            Manufacturer::Saxonar,
+            Manufacturer::LemondFitness,
+            Manufacturer::Dexcom,
+            Manufacturer::WahooFitness,
+            Manufacturer::OctaneFitness,
+            Manufacturer::Archinoetics,
+            Manufacturer::TheHurtBox,
+            Manufacturer::CitizenSystems,
+            Manufacturer::Magellan,
+            Manufacturer::Osynce,
+            Manufacturer::Holux,
+            Manufacturer::Concept2,
+            Manufacturer::Shimano,
+            Manufacturer::OneGiantLeap,
+            Manufacturer::AceSensor,
+            Manufacturer::BrimBrothers,
+            Manufacturer::Xplova,
+            Manufacturer::PerceptionDigital,
+            Manufacturer::Bf1systems,
+            Manufacturer::Pioneer,
+            Manufacturer::Spantec,
+            Manufacturer::Metalogics,
+            Manufacturer::Iiiis,
+            Manufacturer::SeikoEpson,
+            Manufacturer::SeikoEpsonOem,
+            Manufacturer::IforPowell,
+            Manufacturer::MaxwellGuider,
+            Manufacturer::StarTrac,
+            Manufacturer::Breakaway,
+            Manufacturer::AlatechTechnologyLtd,
+            Manufacturer::MioTechnologyEurope,
+            Manufacturer::Rotor,
+            Manufacturer::Geonaute,
+            Manufacturer::IdBike,
+            Manufacturer::Specialized,
+            Manufacturer::Wtek,
+            Manufacturer::PhysicalEnterprises,
+            Manufacturer::NorthPoleEngineering,
+            Manufacturer::Bkool,
+            Manufacturer::Cateye,
+            Manufacturer::StagesCycling,
+            Manufacturer::Sigmasport,
+            Manufacturer::Tomtom,
+            Manufacturer::Peripedal,
+            Manufacturer::Wattbike,
+            Manufacturer::Moxy,
+            Manufacturer::Ciclosport,
+            Manufacturer::Powerbahn,
+            Manufacturer::AcornProjectsAps,
+            Manufacturer::Lifebeam,
+            Manufacturer::Bontrager,
+            Manufacturer::Wellgo,
+            Manufacturer::Scosche,
+            Manufacturer::Magura,
+            Manufacturer::Woodway,
+            Manufacturer::Elite,
+            Manufacturer::NielsenKellerman,
+            Manufacturer::DkCity,
+            Manufacturer::Tacx,
+            Manufacturer::DirectionTechnology,
+            Manufacturer::Magtonic,
+            Manufacturer::Partcarbon,
+            Manufacturer::InsideRideTechnologies,
+            Manufacturer::SoundOfMotion,
+            Manufacturer::Stryd,
+            Manufacturer::Icg,
+            Manufacturer::MiPulse,
+            Manufacturer::BsxAthletics,
+            Manufacturer::Look,
+            Manufacturer::CampagnoloSrl,
+            Manufacturer::BodyBikeSmart,
+            Manufacturer::Praxisworks,
+            Manufacturer::LimitsTechnology,
+            Manufacturer::TopactionTechnology,
+            Manufacturer::Cosinuss,
+            Manufacturer::Fitcare,
+            Manufacturer::Magene,
+            Manufacturer::GiantManufacturingCo,
+            Manufacturer::Tigrasport,
+            Manufacturer::Salutron,
+            Manufacturer::Technogym,
+            Manufacturer::BrytonSensors,
+            Manufacturer::LatitudeLimited,
+            Manufacturer::SoaringTechnology,
+            Manufacturer::Igpsport,
+            Manufacturer::Thinkrider,
+            Manufacturer::GopherSport,
+            Manufacturer::Waterrower,
+            Manufacturer::Orangetheory,
+            Manufacturer::Inpeak,
+            Manufacturer::Kinetic,
+            Manufacturer::JohnsonHealthTech,
+            Manufacturer::PolarElectro,
+            Manufacturer::Seesense,
+            Manufacturer::NciTechnology,
+            Manufacturer::Iqsquare,
+            Manufacturer::Leomo,
+            Manufacturer::IfitCom,
+            Manufacturer::CorosByte,
+            Manufacturer::VersaDesign,
+            Manufacturer::Chileaf,
+            Manufacturer::Cycplus,
+            Manufacturer::GravaaByte,
+            Manufacturer::Sigeyi,
+            Manufacturer::Coospo,
+            Manufacturer::Geoid,
+            Manufacturer::Bosch,
+            Manufacturer::Kyto,
+            Manufacturer::KineticSports,
+            Manufacturer::DecathlonByte,
+            Manufacturer::TqSystems,
+            Manufacturer::TagHeuer,
+            Manufacturer::KeiserFitness,
+            Manufacturer::ZwiftByte,
+            Manufacturer::PorscheEp,
+            Manufacturer::Blackbird,
+            Manufacturer::MeilanByte,
+            Manufacturer::Ezon,
+            Manufacturer::Laisi,
+            Manufacturer::Myzone,
+            Manufacturer::Abawo,
+            Manufacturer::Bafang,
+            Manufacturer::LuhongTechnology,
+            Manufacturer::Development,
+            Manufacturer::Healthandlife,
+            Manufacturer::Lezyne,
+            Manufacturer::ScribeLabs,
+            Manufacturer::Zwift,
+            Manufacturer::Watteam,
+            Manufacturer::Recon,
+            Manufacturer::FaveroElectronics,
+            Manufacturer::Dynovelo,
+            Manufacturer::Strava,
+            Manufacturer::Precor,
+            Manufacturer::Bryton,
+            Manufacturer::Sram,
+            Manufacturer::Navman,
+            Manufacturer::Cobi,
+            Manufacturer::Spivi,
+            Manufacturer::MioMagellan,
+            Manufacturer::Evesports,
+            Manufacturer::SensitivusGauge,
+            Manufacturer::Podoon,
+            Manufacturer::LifeTimeFitness,
+            Manufacturer::FalcoEMotors,
+            Manufacturer::Minoura,
+            Manufacturer::Cycliq,
+            Manufacturer::Luxottica,
+            Manufacturer::TrainerRoad,
+            Manufacturer::TheSufferfest,
+            Manufacturer::Fullspeedahead,
+            Manufacturer::Virtualtraining,
+            Manufacturer::Feedbacksports,
+            Manufacturer::Omata,
+            Manufacturer::Vdo,
+            Manufacturer::Magneticdays,
+            Manufacturer::Hammerhead,
+            Manufacturer::KineticByKurt,
+            Manufacturer::Shapelog,
+            Manufacturer::Dabuziduo,
+            Manufacturer::Jetblack,
+            Manufacturer::Coros,
+            Manufacturer::Virtugo,
+            Manufacturer::Velosense,
+            Manufacturer::Cycligentinc,
+            Manufacturer::Trailforks,
+            Manufacturer::MahleEbikemotion,
+            Manufacturer::Nurvv,
+            Manufacturer::Microprogram,
+            Manufacturer::Zone5cloud,
+            Manufacturer::Greenteg,
+            Manufacturer::YamahaMotors,
+            Manufacturer::Whoop,
+            Manufacturer::Gravaa,
+            Manufacturer::Onelap,
+            Manufacturer::MonarkExercise,
+            Manufacturer::Form,
+            Manufacturer::Decathlon,
+            Manufacturer::Syncros,
+            Manufacturer::Heatup,
+            Manufacturer::Cannondale,
+            Manufacturer::TrueFitness,
+            Manufacturer::RGTCycling,
+            Manufacturer::Vasa,
+            Manufacturer::RaceRepublic,
+            Manufacturer::Fazua,
+            Manufacturer::OrekaTraining,
+            Manufacturer::Lsec,
+            Manufacturer::LululemonStudio,
+            Manufacturer::Shanyue,
+            Manufacturer::SpinningMda,
+            Manufacturer::Hilldating,
+            Manufacturer::AeroSensor,
+            Manufacturer::Nike,
+            Manufacturer::Magicshine,
+            Manufacturer::Ictrainer,
+            Manufacturer::AbsoluteCycling,
+            Manufacturer::EoSwimbetter,
+            Manufacturer::Mywhoosh,
+            Manufacturer::Ravemen,
+            Manufacturer::TektroRacingProducts,
+            Manufacturer::DaradInnovationCorporation,
+            Manufacturer::Cycloptim,
+            Manufacturer::Actigraphcorp,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Garmin => "Garmin",
+            Self::GarminFr405Antfs => "GarminFr405Antfs",
+            Self::Zephyr => "Zephyr",
+            Self::Dayton => "Dayton",
+            Self::Idt => "Idt",
+            Self::Srm => "Srm",
+            Self::Quarq => "Quarq",
+            Self::Ibike => "Ibike",
+            Self::Saris => "Saris",
+            Self::SparkHk => "SparkHk",
+            Self::Tanita => "Tanita",
+            Self::Echowell => "Echowell",
+            Self::DynastreamOem => "DynastreamOem",
+            Self::Nautilus => "Nautilus",
+            Self::Dynastream => "Dynastream",
+            Self::Timex => "Timex",
+            Self::Metrigear => "Metrigear",
+            Self::Xelic => "Xelic",
+            Self::Beurer => "Beurer",
+            Self::Cardiosport => "Cardiosport",
+            Self::AAndD => "AAndD",
+            Self::Hmm => "Hmm",
+            Self::Suunto => "Suunto",
+            Self::ThitaElektronik => "ThitaElektronik",
+            Self::Gpulse => "Gpulse",
+            Self::CleanMobile => "CleanMobile",
+            Self::PedalBrain => "PedalBrain",
+            Self::Peaksware => "Peaksware",
+            Self::Saxonar => "Saxonar",
+            Self::LemondFitness => "LemondFitness",
+            Self::Dexcom => "Dexcom",
+            Self::WahooFitness => "WahooFitness",
+            Self::OctaneFitness => "OctaneFitness",
+            Self::Archinoetics => "Archinoetics",
+            Self::TheHurtBox => "TheHurtBox",
+            Self::CitizenSystems => "CitizenSystems",
+            Self::Magellan => "Magellan",
+            Self::Osynce => "Osynce",
+            Self::Holux => "Holux",
+            Self::Concept2 => "Concept2",
+            Self::Shimano => "Shimano",
+            Self::OneGiantLeap => "OneGiantLeap",
+            Self::AceSensor => "AceSensor",
+            Self::BrimBrothers => "BrimBrothers",
+            Self::Xplova => "Xplova",
+            Self::PerceptionDigital => "PerceptionDigital",
+            Self::Bf1systems => "Bf1systems",
+            Self::Pioneer => "Pioneer",
+            Self::Spantec => "Spantec",
+            Self::Metalogics => "Metalogics",
+            Self::Iiiis => "Iiiis",
+            Self::SeikoEpson => "SeikoEpson",
+            Self::SeikoEpsonOem => "SeikoEpsonOem",
+            Self::IforPowell => "IforPowell",
+            Self::MaxwellGuider => "MaxwellGuider",
+            Self::StarTrac => "StarTrac",
+            Self::Breakaway => "Breakaway",
+            Self::AlatechTechnologyLtd => "AlatechTechnologyLtd",
+            Self::MioTechnologyEurope => "MioTechnologyEurope",
+            Self::Rotor => "Rotor",
+            Self::Geonaute => "Geonaute",
+            Self::IdBike => "IdBike",
+            Self::Specialized => "Specialized",
+            Self::Wtek => "Wtek",
+            Self::PhysicalEnterprises => "PhysicalEnterprises",
+            Self::NorthPoleEngineering => "NorthPoleEngineering",
+            Self::Bkool => "Bkool",
+            Self::Cateye => "Cateye",
+            Self::StagesCycling => "StagesCycling",
+            Self::Sigmasport => "Sigmasport",
+            Self::Tomtom => "Tomtom",
+            Self::Peripedal => "Peripedal",
+            Self::Wattbike => "Wattbike",
+            Self::Moxy => "Moxy",
+            Self::Ciclosport => "Ciclosport",
+            Self::Powerbahn => "Powerbahn",
+            Self::AcornProjectsAps => "AcornProjectsAps",
+            Self::Lifebeam => "Lifebeam",
+            Self::Bontrager => "Bontrager",
+            Self::Wellgo => "Wellgo",
+            Self::Scosche => "Scosche",
+            Self::Magura => "Magura",
+            Self::Woodway => "Woodway",
+            Self::Elite => "Elite",
+            Self::NielsenKellerman => "NielsenKellerman",
+            Self::DkCity => "DkCity",
+            Self::Tacx => "Tacx",
+            Self::DirectionTechnology => "DirectionTechnology",
+            Self::Magtonic => "Magtonic",
+            Self::Partcarbon => "Partcarbon",
+            Self::InsideRideTechnologies => "InsideRideTechnologies",
+            Self::SoundOfMotion => "SoundOfMotion",
+            Self::Stryd => "Stryd",
+            Self::Icg => "Icg",
+            Self::MiPulse => "MiPulse",
+            Self::BsxAthletics => "BsxAthletics",
+            Self::Look => "Look",
+            Self::CampagnoloSrl => "CampagnoloSrl",
+            Self::BodyBikeSmart => "BodyBikeSmart",
+            Self::Praxisworks => "Praxisworks",
+            Self::LimitsTechnology => "LimitsTechnology",
+            Self::TopactionTechnology => "TopactionTechnology",
+            Self::Cosinuss => "Cosinuss",
+            Self::Fitcare => "Fitcare",
+            Self::Magene => "Magene",
+            Self::GiantManufacturingCo => "GiantManufacturingCo",
+            Self::Tigrasport => "Tigrasport",
+            Self::Salutron => "Salutron",
+            Self::Technogym => "Technogym",
+            Self::BrytonSensors => "BrytonSensors",
+            Self::LatitudeLimited => "LatitudeLimited",
+            Self::SoaringTechnology => "SoaringTechnology",
+            Self::Igpsport => "Igpsport",
+            Self::Thinkrider => "Thinkrider",
+            Self::GopherSport => "GopherSport",
+            Self::Waterrower => "Waterrower",
+            Self::Orangetheory => "Orangetheory",
+            Self::Inpeak => "Inpeak",
+            Self::Kinetic => "Kinetic",
+            Self::JohnsonHealthTech => "JohnsonHealthTech",
+            Self::PolarElectro => "PolarElectro",
+            Self::Seesense => "Seesense",
+            Self::NciTechnology => "NciTechnology",
+            Self::Iqsquare => "Iqsquare",
+            Self::Leomo => "Leomo",
+            Self::IfitCom => "IfitCom",
+            Self::CorosByte => "CorosByte",
+            Self::VersaDesign => "VersaDesign",
+            Self::Chileaf => "Chileaf",
+            Self::Cycplus => "Cycplus",
+            Self::GravaaByte => "GravaaByte",
+            Self::Sigeyi => "Sigeyi",
+            Self::Coospo => "Coospo",
+            Self::Geoid => "Geoid",
+            Self::Bosch => "Bosch",
+            Self::Kyto => "Kyto",
+            Self::KineticSports => "KineticSports",
+            Self::DecathlonByte => "DecathlonByte",
+            Self::TqSystems => "TqSystems",
+            Self::TagHeuer => "TagHeuer",
+            Self::KeiserFitness => "KeiserFitness",
+            Self::ZwiftByte => "ZwiftByte",
+            Self::PorscheEp => "PorscheEp",
+            Self::Blackbird => "Blackbird",
+            Self::MeilanByte => "MeilanByte",
+            Self::Ezon => "Ezon",
+            Self::Laisi => "Laisi",
+            Self::Myzone => "Myzone",
+            Self::Abawo => "Abawo",
+            Self::Bafang => "Bafang",
+            Self::LuhongTechnology => "LuhongTechnology",
+            Self::Development => "Development",
+            Self::Healthandlife => "Healthandlife",
+            Self::Lezyne => "Lezyne",
+            Self::ScribeLabs => "ScribeLabs",
+            Self::Zwift => "Zwift",
+            Self::Watteam => "Watteam",
+            Self::Recon => "Recon",
+            Self::FaveroElectronics => "FaveroElectronics",
+            Self::Dynovelo => "Dynovelo",
+            Self::Strava => "Strava",
+            Self::Precor => "Precor",
+            Self::Bryton => "Bryton",
+            Self::Sram => "Sram",
+            Self::Navman => "Navman",
+            Self::Cobi => "Cobi",
+            Self::Spivi => "Spivi",
+            Self::MioMagellan => "MioMagellan",
+            Self::Evesports => "Evesports",
+            Self::SensitivusGauge => "SensitivusGauge",
+            Self::Podoon => "Podoon",
+            Self::LifeTimeFitness => "LifeTimeFitness",
+            Self::FalcoEMotors => "FalcoEMotors",
+            Self::Minoura => "Minoura",
+            Self::Cycliq => "Cycliq",
+            Self::Luxottica => "Luxottica",
+            Self::TrainerRoad => "TrainerRoad",
+            Self::TheSufferfest => "TheSufferfest",
+            Self::Fullspeedahead => "Fullspeedahead",
+            Self::Virtualtraining => "Virtualtraining",
+            Self::Feedbacksports => "Feedbacksports",
+            Self::Omata => "Omata",
+            Self::Vdo => "Vdo",
+            Self::Magneticdays => "Magneticdays",
+            Self::Hammerhead => "Hammerhead",
+            Self::KineticByKurt => "KineticByKurt",
+            Self::Shapelog => "Shapelog",
+            Self::Dabuziduo => "Dabuziduo",
+            Self::Jetblack => "Jetblack",
+            Self::Coros => "Coros",
+            Self::Virtugo => "Virtugo",
+            Self::Velosense => "Velosense",
+            Self::Cycligentinc => "Cycligentinc",
+            Self::Trailforks => "Trailforks",
+            Self::MahleEbikemotion => "MahleEbikemotion",
+            Self::Nurvv => "Nurvv",
+            Self::Microprogram => "Microprogram",
+            Self::Zone5cloud => "Zone5cloud",
+            Self::Greenteg => "Greenteg",
+            Self::YamahaMotors => "YamahaMotors",
+            Self::Whoop => "Whoop",
+            Self::Gravaa => "Gravaa",
+            Self::Onelap => "Onelap",
+            Self::MonarkExercise => "MonarkExercise",
+            Self::Form => "Form",
+            Self::Decathlon => "Decathlon",
+            Self::Syncros => "Syncros",
+            Self::Heatup => "Heatup",
+            Self::Cannondale => "Cannondale",
+            Self::TrueFitness => "TrueFitness",
+            Self::RGTCycling => "RGTCycling",
+            Self::Vasa => "Vasa",
+            Self::RaceRepublic => "RaceRepublic",
+            Self::Fazua => "Fazua",
+            Self::OrekaTraining => "OrekaTraining",
+            Self::Lsec => "Lsec",
+            Self::LululemonStudio => "LululemonStudio",
+            Self::Shanyue => "Shanyue",
+            Self::SpinningMda => "SpinningMda",
+            Self::Hilldating => "Hilldating",
+            Self::AeroSensor => "AeroSensor",
+            Self::Nike => "Nike",
+            Self::Magicshine => "Magicshine",
+            Self::Ictrainer => "Ictrainer",
+            Self::AbsoluteCycling => "AbsoluteCycling",
+            Self::EoSwimbetter => "EoSwimbetter",
+            Self::Mywhoosh => "Mywhoosh",
+            Self::Ravemen => "Ravemen",
+            Self::TektroRacingProducts => "TektroRacingProducts",
+            Self::DaradInnovationCorporation => "DaradInnovationCorporation",
+            Self::Cycloptim => "Cycloptim",
+            Self::Actigraphcorp => "Actigraphcorp",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GarminProduct {
     Hrm1,
     Axh01,
@@ -4082,6 +7563,250 @@ pub enum GarminProduct {
     Connect,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for Manufacturer {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Manufacturer::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Garmin" => Ok(Self::Garmin),
+            "GarminFr405Antfs" => Ok(Self::GarminFr405Antfs),
+            "Zephyr" => Ok(Self::Zephyr),
+            "Dayton" => Ok(Self::Dayton),
+            "Idt" => Ok(Self::Idt),
+            "Srm" => Ok(Self::Srm),
+            "Quarq" => Ok(Self::Quarq),
+            "Ibike" => Ok(Self::Ibike),
+            "Saris" => Ok(Self::Saris),
+            "SparkHk" => Ok(Self::SparkHk),
+            "Tanita" => Ok(Self::Tanita),
+            "Echowell" => Ok(Self::Echowell),
+            "DynastreamOem" => Ok(Self::DynastreamOem),
+            "Nautilus" => Ok(Self::Nautilus),
+            "Dynastream" => Ok(Self::Dynastream),
+            "Timex" => Ok(Self::Timex),
+            "Metrigear" => Ok(Self::Metrigear),
+            "Xelic" => Ok(Self::Xelic),
+            "Beurer" => Ok(Self::Beurer),
+            "Cardiosport" => Ok(Self::Cardiosport),
+            "AAndD" => Ok(Self::AAndD),
+            "Hmm" => Ok(Self::Hmm),
+            "Suunto" => Ok(Self::Suunto),
+            "ThitaElektronik" => Ok(Self::ThitaElektronik),
+            "Gpulse" => Ok(Self::Gpulse),
+            "CleanMobile" => Ok(Self::CleanMobile),
+            "PedalBrain" => Ok(Self::PedalBrain),
+            "Peaksware" => Ok(Self::Peaksware),
+            "Saxonar" => Ok(Self::Saxonar),
+            "LemondFitness" => Ok(Self::LemondFitness),
+            "Dexcom" => Ok(Self::Dexcom),
+            "WahooFitness" => Ok(Self::WahooFitness),
+            "OctaneFitness" => Ok(Self::OctaneFitness),
+            "Archinoetics" => Ok(Self::Archinoetics),
+            "TheHurtBox" => Ok(Self::TheHurtBox),
+            "CitizenSystems" => Ok(Self::CitizenSystems),
+            "Magellan" => Ok(Self::Magellan),
+            "Osynce" => Ok(Self::Osynce),
+            "Holux" => Ok(Self::Holux),
+            "Concept2" => Ok(Self::Concept2),
+            "Shimano" => Ok(Self::Shimano),
+            "OneGiantLeap" => Ok(Self::OneGiantLeap),
+            "AceSensor" => Ok(Self::AceSensor),
+            "BrimBrothers" => Ok(Self::BrimBrothers),
+            "Xplova" => Ok(Self::Xplova),
+            "PerceptionDigital" => Ok(Self::PerceptionDigital),
+            "Bf1systems" => Ok(Self::Bf1systems),
+            "Pioneer" => Ok(Self::Pioneer),
+            "Spantec" => Ok(Self::Spantec),
+            "Metalogics" => Ok(Self::Metalogics),
+            "Iiiis" => Ok(Self::Iiiis),
+            "SeikoEpson" => Ok(Self::SeikoEpson),
+            "SeikoEpsonOem" => Ok(Self::SeikoEpsonOem),
+            "IforPowell" => Ok(Self::IforPowell),
+            "MaxwellGuider" => Ok(Self::MaxwellGuider),
+            "StarTrac" => Ok(Self::StarTrac),
+            "Breakaway" => Ok(Self::Breakaway),
+            "AlatechTechnologyLtd" => Ok(Self::AlatechTechnologyLtd),
+            "MioTechnologyEurope" => Ok(Self::MioTechnologyEurope),
+            "Rotor" => Ok(Self::Rotor),
+            "Geonaute" => Ok(Self::Geonaute),
+            "IdBike" => Ok(Self::IdBike),
+            "Specialized" => Ok(Self::Specialized),
+            "Wtek" => Ok(Self::Wtek),
+            "PhysicalEnterprises" => Ok(Self::PhysicalEnterprises),
+            "NorthPoleEngineering" => Ok(Self::NorthPoleEngineering),
+            "Bkool" => Ok(Self::Bkool),
+            "Cateye" => Ok(Self::Cateye),
+            "StagesCycling" => Ok(Self::StagesCycling),
+            "Sigmasport" => Ok(Self::Sigmasport),
+            "Tomtom" => Ok(Self::Tomtom),
+            "Peripedal" => Ok(Self::Peripedal),
+            "Wattbike" => Ok(Self::Wattbike),
+            "Moxy" => Ok(Self::Moxy),
+            "Ciclosport" => Ok(Self::Ciclosport),
+            "Powerbahn" => Ok(Self::Powerbahn),
+            "AcornProjectsAps" => Ok(Self::AcornProjectsAps),
+            "Lifebeam" => Ok(Self::Lifebeam),
+            "Bontrager" => Ok(Self::Bontrager),
+            "Wellgo" => Ok(Self::Wellgo),
+            "Scosche" => Ok(Self::Scosche),
+            "Magura" => Ok(Self::Magura),
+            "Woodway" => Ok(Self::Woodway),
+            "Elite" => Ok(Self::Elite),
+            "NielsenKellerman" => Ok(Self::NielsenKellerman),
+            "DkCity" => Ok(Self::DkCity),
+            "Tacx" => Ok(Self::Tacx),
+            "DirectionTechnology" => Ok(Self::DirectionTechnology),
+            "Magtonic" => Ok(Self::Magtonic),
+            "Partcarbon" => Ok(Self::Partcarbon),
+            "InsideRideTechnologies" => Ok(Self::InsideRideTechnologies),
+            "SoundOfMotion" => Ok(Self::SoundOfMotion),
+            "Stryd" => Ok(Self::Stryd),
+            "Icg" => Ok(Self::Icg),
+            "MiPulse" => Ok(Self::MiPulse),
+            "BsxAthletics" => Ok(Self::BsxAthletics),
+            "Look" => Ok(Self::Look),
+            "CampagnoloSrl" => Ok(Self::CampagnoloSrl),
+            "BodyBikeSmart" => Ok(Self::BodyBikeSmart),
+            "Praxisworks" => Ok(Self::Praxisworks),
+            "LimitsTechnology" => Ok(Self::LimitsTechnology),
+            "TopactionTechnology" => Ok(Self::TopactionTechnology),
+            "Cosinuss" => Ok(Self::Cosinuss),
+            "Fitcare" => Ok(Self::Fitcare),
+            "Magene" => Ok(Self::Magene),
+            "GiantManufacturingCo" => Ok(Self::GiantManufacturingCo),
+            "Tigrasport" => Ok(Self::Tigrasport),
+            "Salutron" => Ok(Self::Salutron),
+            "Technogym" => Ok(Self::Technogym),
+            "BrytonSensors" => Ok(Self::BrytonSensors),
+            "LatitudeLimited" => Ok(Self::LatitudeLimited),
+            "SoaringTechnology" => Ok(Self::SoaringTechnology),
+            "Igpsport" => Ok(Self::Igpsport),
+            "Thinkrider" => Ok(Self::Thinkrider),
+            "GopherSport" => Ok(Self::GopherSport),
+            "Waterrower" => Ok(Self::Waterrower),
+            "Orangetheory" => Ok(Self::Orangetheory),
+            "Inpeak" => Ok(Self::Inpeak),
+            "Kinetic" => Ok(Self::Kinetic),
+            "JohnsonHealthTech" => Ok(Self::JohnsonHealthTech),
+            "PolarElectro" => Ok(Self::PolarElectro),
+            "Seesense" => Ok(Self::Seesense),
+            "NciTechnology" => Ok(Self::NciTechnology),
+            "Iqsquare" => Ok(Self::Iqsquare),
+            "Leomo" => Ok(Self::Leomo),
+            "IfitCom" => Ok(Self::IfitCom),
+            "CorosByte" => Ok(Self::CorosByte),
+            "VersaDesign" => Ok(Self::VersaDesign),
+            "Chileaf" => Ok(Self::Chileaf),
+            "Cycplus" => Ok(Self::Cycplus),
+            "GravaaByte" => Ok(Self::GravaaByte),
+            "Sigeyi" => Ok(Self::Sigeyi),
+            "Coospo" => Ok(Self::Coospo),
+            "Geoid" => Ok(Self::Geoid),
+            "Bosch" => Ok(Self::Bosch),
+            "Kyto" => Ok(Self::Kyto),
+            "KineticSports" => Ok(Self::KineticSports),
+            "DecathlonByte" => Ok(Self::DecathlonByte),
+            "TqSystems" => Ok(Self::TqSystems),
+            "TagHeuer" => Ok(Self::TagHeuer),
+            "KeiserFitness" => Ok(Self::KeiserFitness),
+            "ZwiftByte" => Ok(Self::ZwiftByte),
+            "PorscheEp" => Ok(Self::PorscheEp),
+            "Blackbird" => Ok(Self::Blackbird),
+            "MeilanByte" => Ok(Self::MeilanByte),
+            "Ezon" => Ok(Self::Ezon),
+            "Laisi" => Ok(Self::Laisi),
+            "Myzone" => Ok(Self::Myzone),
+            "Abawo" => Ok(Self::Abawo),
+            "Bafang" => Ok(Self::Bafang),
+            "LuhongTechnology" => Ok(Self::LuhongTechnology),
+            "Development" => Ok(Self::Development),
+            "Healthandlife" => Ok(Self::Healthandlife),
+            "Lezyne" => Ok(Self::Lezyne),
+            "ScribeLabs" => Ok(Self::ScribeLabs),
+            "Zwift" => Ok(Self::Zwift),
+            "Watteam" => Ok(Self::Watteam),
+            "Recon" => Ok(Self::Recon),
+            "FaveroElectronics" => Ok(Self::FaveroElectronics),
+            "Dynovelo" => Ok(Self::Dynovelo),
+            "Strava" => Ok(Self::Strava),
+            "Precor" => Ok(Self::Precor),
+            "Bryton" => Ok(Self::Bryton),
+            "Sram" => Ok(Self::Sram),
+            "Navman" => Ok(Self::Navman),
+            "Cobi" => Ok(Self::Cobi),
+            "Spivi" => Ok(Self::Spivi),
+            "MioMagellan" => Ok(Self::MioMagellan),
+            "Evesports" => Ok(Self::Evesports),
+            "SensitivusGauge" => Ok(Self::SensitivusGauge),
+            "Podoon" => Ok(Self::Podoon),
+            "LifeTimeFitness" => Ok(Self::LifeTimeFitness),
+            "FalcoEMotors" => Ok(Self::FalcoEMotors),
+            "Minoura" => Ok(Self::Minoura),
+            "Cycliq" => Ok(Self::Cycliq),
+            "Luxottica" => Ok(Self::Luxottica),
+            "TrainerRoad" => Ok(Self::TrainerRoad),
+            "TheSufferfest" => Ok(Self::TheSufferfest),
+            "Fullspeedahead" => Ok(Self::Fullspeedahead),
+            "Virtualtraining" => Ok(Self::Virtualtraining),
+            "Feedbacksports" => Ok(Self::Feedbacksports),
+            "Omata" => Ok(Self::Omata),
+            "Vdo" => Ok(Self::Vdo),
+            "Magneticdays" => Ok(Self::Magneticdays),
+            "Hammerhead" => Ok(Self::Hammerhead),
+            "KineticByKurt" => Ok(Self::KineticByKurt),
+            "Shapelog" => Ok(Self::Shapelog),
+            "Dabuziduo" => Ok(Self::Dabuziduo),
+            "Jetblack" => Ok(Self::Jetblack),
+            "Coros" => Ok(Self::Coros),
+            "Virtugo" => Ok(Self::Virtugo),
+            "Velosense" => Ok(Self::Velosense),
+            "Cycligentinc" => Ok(Self::Cycligentinc),
+            "Trailforks" => Ok(Self::Trailforks),
+            "MahleEbikemotion" => Ok(Self::MahleEbikemotion),
+            "Nurvv" => Ok(Self::Nurvv),
+            "Microprogram" => Ok(Self::Microprogram),
+            "Zone5cloud" => Ok(Self::Zone5cloud),
+            "Greenteg" => Ok(Self::Greenteg),
+            "YamahaMotors" => Ok(Self::YamahaMotors),
+            "Whoop" => Ok(Self::Whoop),
+            "Gravaa" => Ok(Self::Gravaa),
+            "Onelap" => Ok(Self::Onelap),
+            "MonarkExercise" => Ok(Self::MonarkExercise),
+            "Form" => Ok(Self::Form),
+            "Decathlon" => Ok(Self::Decathlon),
+            "Syncros" => Ok(Self::Syncros),
+            "Heatup" => Ok(Self::Heatup),
+            "Cannondale" => Ok(Self::Cannondale),
+            "TrueFitness" => Ok(Self::TrueFitness),
+            "RGTCycling" => Ok(Self::RGTCycling),
+            "Vasa" => Ok(Self::Vasa),
+            "RaceRepublic" => Ok(Self::RaceRepublic),
+            "Fazua" => Ok(Self::Fazua),
+            "OrekaTraining" => Ok(Self::OrekaTraining),
+            "Lsec" => Ok(Self::Lsec),
+            "LululemonStudio" => Ok(Self::LululemonStudio),
+            "Shanyue" => Ok(Self::Shanyue),
+            "SpinningMda" => Ok(Self::SpinningMda),
+            "Hilldating" => Ok(Self::Hilldating),
+            "AeroSensor" => Ok(Self::AeroSensor),
+            "Nike" => Ok(Self::Nike),
+            "Magicshine" => Ok(Self::Magicshine),
+            "Ictrainer" => Ok(Self::Ictrainer),
+            "AbsoluteCycling" => Ok(Self::AbsoluteCycling),
+            "EoSwimbetter" => Ok(Self::EoSwimbetter),
+            "Mywhoosh" => Ok(Self::Mywhoosh),
+            "Ravemen" => Ok(Self::Ravemen),
+            "TektroRacingProducts" => Ok(Self::TektroRacingProducts),
+            "DaradInnovationCorporation" => Ok(Self::DaradInnovationCorporation),
+            "Cycloptim" => Ok(Self::Cycloptim),
+            "Actigraphcorp" => Ok(Self::Actigraphcorp),
+            _ => Err(UnknownVariantError::new("Manufacturer", name)),
+        }
+    }
+}
 impl GarminProduct {
     pub fn from(content: u16) -> GarminProduct {
         match content {
@@ -4554,17 +8279,952 @@ impl GarminProduct {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::GarminProduct(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [GarminProduct] {
+        &[
+            GarminProduct::Hrm1,
+            GarminProduct::Axh01,
+            GarminProduct::Axb01,
+            GarminProduct::Axb02,
+            GarminProduct::Hrm2ss,
+            GarminProduct::DsiAlf02,
+            GarminProduct::Hrm3ss,
+            GarminProduct::HrmRunSingleByteProductId,
+            GarminProduct::Bsm,
+            GarminProduct::Bcm,
+            GarminProduct::Axs01,
+            GarminProduct::HrmTriSingleByteProductId,
+            GarminProduct::Hrm4RunSingleByteProductId,
+            GarminProduct::Fr225SingleByteProductId,
+            GarminProduct::Gen3BsmSingleByteProductId,
+            GarminProduct::Gen3BcmSingleByteProductId,
+            GarminProduct::HrmFitSingleByteProductId,
+            GarminProduct::OHR,
+            GarminProduct::Fr301China,
+            GarminProduct::Fr301Japan,
+            GarminProduct::Fr301Korea,
+            GarminProduct::Fr301Taiwan,
+            GarminProduct::Fr405,
+            GarminProduct::Fr50,
+            GarminProduct::Fr405Japan,
+            GarminProduct::Fr60,
+            GarminProduct::DsiAlf01,
+            GarminProduct::Fr310xt,
+            GarminProduct::Edge500,
+            GarminProduct::Fr110,
+            GarminProduct::Edge800,
+            GarminProduct::Edge500Taiwan,
+            GarminProduct::Edge500Japan,
+            GarminProduct::Chirp,
+            GarminProduct::Fr110Japan,
+            GarminProduct::Edge200,
+            GarminProduct::Fr910xt,
+            GarminProduct::Edge800Taiwan,
+            GarminProduct::Edge800Japan,
+            GarminProduct::Alf04,
+            GarminProduct::Fr610,
+            GarminProduct::Fr210Japan,
+            GarminProduct::VectorSs,
+            GarminProduct::VectorCp,
+            GarminProduct::Edge800China,
+            GarminProduct::Edge500China,
+            GarminProduct::ApproachG10,
+            GarminProduct::Fr610Japan,
+            GarminProduct::Edge500Korea,
+            GarminProduct::Fr70,
+            GarminProduct::Fr310xt4t,
+            GarminProduct::Amx,
+            GarminProduct::Fr10,
+            GarminProduct::Edge800Korea,
+            GarminProduct::Swim,
+            GarminProduct::Fr910xtChina,
+            GarminProduct::Fenix,
+            GarminProduct::Edge200Taiwan,
+            GarminProduct::Edge510,
+            GarminProduct::Edge810,
+            GarminProduct::Tempe,
+            GarminProduct::Fr910xtJapan,
+            GarminProduct::Fr620,
+            GarminProduct::Fr220,
+            GarminProduct::Fr910xtKorea,
+            GarminProduct::Fr10Japan,
+            GarminProduct::Edge810Japan,
+            GarminProduct::VirbElite,
+            GarminProduct::EdgeTouring,
+            GarminProduct::Edge510Japan,
+            GarminProduct::HrmTri,
+            GarminProduct::HrmRun,
+            GarminProduct::Fr920xt,
+            GarminProduct::Edge510Asia,
+            GarminProduct::Edge810China,
+            GarminProduct::Edge810Taiwan,
+            GarminProduct::Edge1000,
+            GarminProduct::VivoFit,
+            GarminProduct::VirbRemote,
+            GarminProduct::VivoKi,
+            GarminProduct::Fr15,
+            GarminProduct::VivoActive,
+            GarminProduct::Edge510Korea,
+            GarminProduct::Fr620Japan,
+            GarminProduct::Fr620China,
+            GarminProduct::Fr220Japan,
+            GarminProduct::Fr220China,
+            GarminProduct::ApproachS6,
+            GarminProduct::VivoSmart,
+            GarminProduct::Fenix2,
+            GarminProduct::Epix,
+            GarminProduct::Fenix3,
+            GarminProduct::Edge1000Taiwan,
+            GarminProduct::Edge1000Japan,
+            GarminProduct::Fr15Japan,
+            GarminProduct::Edge520,
+            GarminProduct::Edge1000China,
+            GarminProduct::Fr620Russia,
+            GarminProduct::Fr220Russia,
+            GarminProduct::VectorS,
+            GarminProduct::Edge1000Korea,
+            GarminProduct::Fr920xtTaiwan,
+            GarminProduct::Fr920xtChina,
+            GarminProduct::Fr920xtJapan,
+            GarminProduct::Virbx,
+            GarminProduct::VivoSmartApac,
+            GarminProduct::EtrexTouch,
+            GarminProduct::Edge25,
+            GarminProduct::Fr25,
+            GarminProduct::VivoFit2,
+            GarminProduct::Fr225,
+            GarminProduct::Fr630,
+            GarminProduct::Fr230,
+            GarminProduct::Fr735xt,
+            GarminProduct::VivoActiveApac,
+            GarminProduct::Vector2,
+            GarminProduct::Vector2s,
+            GarminProduct::Virbxe,
+            GarminProduct::Fr620Taiwan,
+            GarminProduct::Fr220Taiwan,
+            GarminProduct::Truswing,
+            GarminProduct::D2airvenu,
+            GarminProduct::Fenix3China,
+            GarminProduct::Fenix3Twn,
+            GarminProduct::VariaHeadlight,
+            GarminProduct::VariaTaillightOld,
+            GarminProduct::EdgeExplore1000,
+            GarminProduct::Fr225Asia,
+            GarminProduct::VariaRadarTaillight,
+            GarminProduct::VariaRadarDisplay,
+            GarminProduct::Edge20,
+            GarminProduct::Edge520Asia,
+            GarminProduct::Edge520Japan,
+            GarminProduct::D2Bravo,
+            GarminProduct::ApproachS20,
+            GarminProduct::VivoSmart2,
+            GarminProduct::Edge1000Thai,
+            GarminProduct::VariaRemote,
+            GarminProduct::Edge25Asia,
+            GarminProduct::Edge25Jpn,
+            GarminProduct::Edge20Asia,
+            GarminProduct::ApproachX40,
+            GarminProduct::Fenix3Japan,
+            GarminProduct::VivoSmartEmea,
+            GarminProduct::Fr630Asia,
+            GarminProduct::Fr630Jpn,
+            GarminProduct::Fr230Jpn,
+            GarminProduct::Hrm4Run,
+            GarminProduct::EpixJapan,
+            GarminProduct::VivoActiveHr,
+            GarminProduct::VivoSmartGpsHr,
+            GarminProduct::VivoSmartHr,
+            GarminProduct::VivoSmartHrAsia,
+            GarminProduct::VivoSmartGpsHrAsia,
+            GarminProduct::VivoMove,
+            GarminProduct::VariaTaillight,
+            GarminProduct::Fr235Asia,
+            GarminProduct::Fr235Japan,
+            GarminProduct::VariaVision,
+            GarminProduct::VivoFit3,
+            GarminProduct::Fenix3Korea,
+            GarminProduct::Fenix3Sea,
+            GarminProduct::Fenix3Hr,
+            GarminProduct::VirbUltra30,
+            GarminProduct::IndexSmartScale,
+            GarminProduct::Fr235,
+            GarminProduct::Fenix3Chronos,
+            GarminProduct::Oregon7xx,
+            GarminProduct::Rino7xx,
+            GarminProduct::EpixKorea,
+            GarminProduct::Fenix3HrChn,
+            GarminProduct::Fenix3HrTwn,
+            GarminProduct::Fenix3HrJpn,
+            GarminProduct::Fenix3HrSea,
+            GarminProduct::Fenix3HrKor,
+            GarminProduct::Nautix,
+            GarminProduct::VivoActiveHrApac,
+            GarminProduct::Fr35,
+            GarminProduct::Oregon7xxWw,
+            GarminProduct::Edge820,
+            GarminProduct::EdgeExplore820,
+            GarminProduct::Fr735xtApac,
+            GarminProduct::Fr735xtJapan,
+            GarminProduct::Fenix5s,
+            GarminProduct::D2BravoTitanium,
+            GarminProduct::VariaUt800,
+            GarminProduct::RunningDynamicsPod,
+            GarminProduct::Edge820China,
+            GarminProduct::Edge820Japan,
+            GarminProduct::Fenix5x,
+            GarminProduct::VivoFitJr,
+            GarminProduct::VivoSmart3,
+            GarminProduct::VivoSport,
+            GarminProduct::Edge820Taiwan,
+            GarminProduct::Edge820Korea,
+            GarminProduct::Edge820Sea,
+            GarminProduct::Fr35Hebrew,
+            GarminProduct::ApproachS60,
+            GarminProduct::Fr35Apac,
+            GarminProduct::Fr35Japan,
+            GarminProduct::Fenix3ChronosAsia,
+            GarminProduct::Virb360,
+            GarminProduct::Fr935,
+            GarminProduct::Fenix5,
+            GarminProduct::Vivoactive3,
+            GarminProduct::Fr235ChinaNfc,
+            GarminProduct::Foretrex601701,
+            GarminProduct::VivoMoveHr,
+            GarminProduct::Edge1030,
+            GarminProduct::Fr35Sea,
+            GarminProduct::Vector3,
+            GarminProduct::Fenix5Asia,
+            GarminProduct::Fenix5sAsia,
+            GarminProduct::Fenix5xAsia,
+            GarminProduct::ApproachZ80,
+            GarminProduct::Fr35Korea,
+            GarminProduct::D2charlie,
+            GarminProduct::VivoSmart3Apac,
+            GarminProduct::VivoSportApac,
+            GarminProduct::Fr935Asia,
+            GarminProduct::Descent,
+            GarminProduct::VivoFit4,
+            GarminProduct::Fr645,
+            GarminProduct::Fr645m,
+            GarminProduct::Fr30,
+            GarminProduct::Fenix5sPlus,
+            GarminProduct::Edge130,
+            GarminProduct::Edge1030Asia,
+            GarminProduct::Vivosmart4,
+            GarminProduct::VivoMoveHrAsia,
+            GarminProduct::ApproachX10,
+            GarminProduct::Fr30Asia,
+            GarminProduct::Vivoactive3mW,
+            GarminProduct::Fr645Asia,
+            GarminProduct::Fr645mAsia,
+            GarminProduct::EdgeExplore,
+            GarminProduct::Gpsmap66,
+            GarminProduct::ApproachS10,
+            GarminProduct::Vivoactive3mL,
+            GarminProduct::Fr245,
+            GarminProduct::Fr245Music,
+            GarminProduct::ApproachG80,
+            GarminProduct::Edge130Asia,
+            GarminProduct::Edge1030Bontrager,
+            GarminProduct::Fenix5Plus,
+            GarminProduct::Fenix5xPlus,
+            GarminProduct::Edge520Plus,
+            GarminProduct::Fr945,
+            GarminProduct::Edge530,
+            GarminProduct::Edge830,
+            GarminProduct::InstinctEsports,
+            GarminProduct::Fenix5sPlusApac,
+            GarminProduct::Fenix5xPlusApac,
+            GarminProduct::Edge520PlusApac,
+            GarminProduct::DescentT1,
+            GarminProduct::Fr235lAsia,
+            GarminProduct::Fr245Asia,
+            GarminProduct::VivoActive3mApac,
+            GarminProduct::Gen3Bsm,
+            GarminProduct::Gen3Bcm,
+            GarminProduct::VivoSmart4Asia,
+            GarminProduct::Vivoactive4Small,
+            GarminProduct::Vivoactive4Large,
+            GarminProduct::Venu,
+            GarminProduct::MarqDriver,
+            GarminProduct::MarqAviator,
+            GarminProduct::MarqCaptain,
+            GarminProduct::MarqCommander,
+            GarminProduct::MarqExpedition,
+            GarminProduct::MarqAthlete,
+            GarminProduct::DescentMk2,
+            GarminProduct::Fr45,
+            GarminProduct::Gpsmap66i,
+            GarminProduct::Fenix6SSport,
+            GarminProduct::Fenix6S,
+            GarminProduct::Fenix6Sport,
+            GarminProduct::Fenix6,
+            GarminProduct::Fenix6x,
+            GarminProduct::HrmDual,
+            GarminProduct::HrmPro,
+            GarminProduct::VivoMove3Premium,
+            GarminProduct::ApproachS40,
+            GarminProduct::Fr245mAsia,
+            GarminProduct::Edge530Apac,
+            GarminProduct::Edge830Apac,
+            GarminProduct::VivoMove3,
+            GarminProduct::VivoActive4SmallAsia,
+            GarminProduct::VivoActive4LargeAsia,
+            GarminProduct::VivoActive4OledAsia,
+            GarminProduct::Swim2,
+            GarminProduct::MarqDriverAsia,
+            GarminProduct::MarqAviatorAsia,
+            GarminProduct::VivoMove3Asia,
+            GarminProduct::Fr945Asia,
+            GarminProduct::VivoActive3tChn,
+            GarminProduct::MarqCaptainAsia,
+            GarminProduct::MarqCommanderAsia,
+            GarminProduct::MarqExpeditionAsia,
+            GarminProduct::MarqAthleteAsia,
+            GarminProduct::IndexSmartScale2,
+            GarminProduct::InstinctSolar,
+            GarminProduct::Fr45Asia,
+            GarminProduct::Vivoactive3Daimler,
+            GarminProduct::LegacyRey,
+            GarminProduct::LegacyDarthVader,
+            GarminProduct::LegacyCaptainMarvel,
+            GarminProduct::LegacyFirstAvenger,
+            GarminProduct::Fenix6sSportAsia,
+            GarminProduct::Fenix6sAsia,
+            GarminProduct::Fenix6SportAsia,
+            GarminProduct::Fenix6Asia,
+            GarminProduct::Fenix6xAsia,
+            GarminProduct::LegacyCaptainMarvelAsia,
+            GarminProduct::LegacyFirstAvengerAsia,
+            GarminProduct::LegacyReyAsia,
+            GarminProduct::LegacyDarthVaderAsia,
+            GarminProduct::DescentMk2s,
+            GarminProduct::Edge130Plus,
+            GarminProduct::Edge1030Plus,
+            GarminProduct::Rally200,
+            GarminProduct::Fr745,
+            GarminProduct::VenusqMusic,
+            GarminProduct::VenusqMusicV2,
+            GarminProduct::Venusq,
+            GarminProduct::Lily,
+            GarminProduct::MarqAdventurer,
+            GarminProduct::Enduro,
+            GarminProduct::Swim2Apac,
+            GarminProduct::MarqAdventurerAsia,
+            GarminProduct::Fr945Lte,
+            GarminProduct::DescentMk2Asia,
+            GarminProduct::Venu2,
+            GarminProduct::Venu2s,
+            GarminProduct::VenuDaimlerAsia,
+            GarminProduct::MarqGolfer,
+            GarminProduct::VenuDaimler,
+            GarminProduct::Fr745Asia,
+            GarminProduct::VariaRct715,
+            GarminProduct::LilyAsia,
+            GarminProduct::Edge1030PlusAsia,
+            GarminProduct::Edge130PlusAsia,
+            GarminProduct::ApproachS12,
+            GarminProduct::EnduroAsia,
+            GarminProduct::VenusqAsia,
+            GarminProduct::Edge1040,
+            GarminProduct::MarqGolferAsia,
+            GarminProduct::Venu2Plus,
+            GarminProduct::Gnss,
+            GarminProduct::Fr55,
+            GarminProduct::Instinct2,
+            GarminProduct::Instinct2s,
+            GarminProduct::Fenix7s,
+            GarminProduct::Fenix7,
+            GarminProduct::Fenix7x,
+            GarminProduct::Fenix7sApac,
+            GarminProduct::Fenix7Apac,
+            GarminProduct::Fenix7xApac,
+            GarminProduct::DescentMk2sAsia,
+            GarminProduct::ApproachS42,
+            GarminProduct::EpixGen2,
+            GarminProduct::EpixGen2Apac,
+            GarminProduct::Venu2sAsia,
+            GarminProduct::Venu2Asia,
+            GarminProduct::Fr945LteAsia,
+            GarminProduct::VivoMoveSport,
+            GarminProduct::VivomoveTrend,
+            GarminProduct::ApproachS12Asia,
+            GarminProduct::Fr255Music,
+            GarminProduct::Fr255SmallMusic,
+            GarminProduct::Fr255,
+            GarminProduct::Fr255Small,
+            GarminProduct::ApproachS42Asia,
+            GarminProduct::DescentG1,
+            GarminProduct::Venu2PlusAsia,
+            GarminProduct::Fr955,
+            GarminProduct::Fr55Asia,
+            GarminProduct::Edge540,
+            GarminProduct::Edge840,
+            GarminProduct::Vivosmart5,
+            GarminProduct::Instinct2Asia,
+            GarminProduct::MarqGen2,
+            GarminProduct::Venusq2,
+            GarminProduct::Venusq2music,
+            GarminProduct::MarqGen2Aviator,
+            GarminProduct::D2AirX10,
+            GarminProduct::HrmProPlus,
+            GarminProduct::DescentG1Asia,
+            GarminProduct::Tactix7,
+            GarminProduct::InstinctCrossover,
+            GarminProduct::EdgeExplore2,
+            GarminProduct::DescentMk3,
+            GarminProduct::DescentMk3i,
+            GarminProduct::ApproachS70,
+            GarminProduct::Fr265Large,
+            GarminProduct::Fr265Small,
+            GarminProduct::Venu3,
+            GarminProduct::Venu3s,
+            GarminProduct::TacxNeoSmart,
+            GarminProduct::TacxNeo2Smart,
+            GarminProduct::TacxNeo2TSmart,
+            GarminProduct::TacxNeoSmartBike,
+            GarminProduct::TacxSatoriSmart,
+            GarminProduct::TacxFlowSmart,
+            GarminProduct::TacxVortexSmart,
+            GarminProduct::TacxBushidoSmart,
+            GarminProduct::TacxGeniusSmart,
+            GarminProduct::TacxFluxFluxSSmart,
+            GarminProduct::TacxFlux2Smart,
+            GarminProduct::TacxMagnum,
+            GarminProduct::Edge1040Asia,
+            GarminProduct::EpixGen2Pro42,
+            GarminProduct::EpixGen2Pro47,
+            GarminProduct::EpixGen2Pro51,
+            GarminProduct::Fr965,
+            GarminProduct::Enduro2,
+            GarminProduct::Fenix7sProSolar,
+            GarminProduct::Fenix7ProSolar,
+            GarminProduct::Fenix7xProSolar,
+            GarminProduct::Lily2,
+            GarminProduct::Instinct2x,
+            GarminProduct::Vivoactive5,
+            GarminProduct::Fr165,
+            GarminProduct::Fr165Music,
+            GarminProduct::Edge1050,
+            GarminProduct::DescentT2,
+            GarminProduct::HrmFit,
+            GarminProduct::MarqGen2Commander,
+            GarminProduct::LilyAthlete,
+            GarminProduct::Fenix8Solar,
+            GarminProduct::Fenix8SolarLarge,
+            GarminProduct::Fenix8Small,
+            GarminProduct::Fenix8,
+            GarminProduct::D2Mach1Pro,
+            GarminProduct::Enduro3,
+            GarminProduct::InstinctE40mm,
+            GarminProduct::InstinctE45mm,
+            GarminProduct::Instinct3Solar45mm,
+            GarminProduct::Instinct3Amoled45mm,
+            GarminProduct::Instinct3Amoled50mm,
+            GarminProduct::DescentG2,
+            GarminProduct::Hrm200,
+            GarminProduct::Vivoactive6,
+            GarminProduct::ApproachS44,
+            GarminProduct::ApproachS50,
+            GarminProduct::FenixE,
+            GarminProduct::Instinct3Solar50mm,
+            GarminProduct::Tactix8Amoled,
+            GarminProduct::Tactix8Solar,
+            GarminProduct::Sdm4,
+            GarminProduct::EdgeRemote,
+            GarminProduct::TacxTrainingAppWin,
+            GarminProduct::TacxTrainingAppMac,
+            GarminProduct::TacxTrainingAppMacCatalyst,
+            GarminProduct::TrainingCenter,
+            GarminProduct::TacxTrainingAppAndroid,
+            GarminProduct::TacxTrainingAppIos,
+            GarminProduct::TacxTrainingAppLegacy,
+            GarminProduct::ConnectiqSimulator,
+            GarminProduct::AndroidAntplusPlugin,
+            GarminProduct::Connect,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hrm1 => "Hrm1",
+            Self::Axh01 => "Axh01",
+            Self::Axb01 => "Axb01",
+            Self::Axb02 => "Axb02",
+            Self::Hrm2ss => "Hrm2ss",
+            Self::DsiAlf02 => "DsiAlf02",
+            Self::Hrm3ss => "Hrm3ss",
+            Self::HrmRunSingleByteProductId => "HrmRunSingleByteProductId",
+            Self::Bsm => "Bsm",
+            Self::Bcm => "Bcm",
+            Self::Axs01 => "Axs01",
+            Self::HrmTriSingleByteProductId => "HrmTriSingleByteProductId",
+            Self::Hrm4RunSingleByteProductId => "Hrm4RunSingleByteProductId",
+            Self::Fr225SingleByteProductId => "Fr225SingleByteProductId",
+            Self::Gen3BsmSingleByteProductId => "Gen3BsmSingleByteProductId",
+            Self::Gen3BcmSingleByteProductId => "Gen3BcmSingleByteProductId",
+            Self::HrmFitSingleByteProductId => "HrmFitSingleByteProductId",
+            Self::OHR => "OHR",
+            Self::Fr301China => "Fr301China",
+            Self::Fr301Japan => "Fr301Japan",
+            Self::Fr301Korea => "Fr301Korea",
+            Self::Fr301Taiwan => "Fr301Taiwan",
+            Self::Fr405 => "Fr405",
+            Self::Fr50 => "Fr50",
+            Self::Fr405Japan => "Fr405Japan",
+            Self::Fr60 => "Fr60",
+            Self::DsiAlf01 => "DsiAlf01",
+            Self::Fr310xt => "Fr310xt",
+            Self::Edge500 => "Edge500",
+            Self::Fr110 => "Fr110",
+            Self::Edge800 => "Edge800",
+            Self::Edge500Taiwan => "Edge500Taiwan",
+            Self::Edge500Japan => "Edge500Japan",
+            Self::Chirp => "Chirp",
+            Self::Fr110Japan => "Fr110Japan",
+            Self::Edge200 => "Edge200",
+            Self::Fr910xt => "Fr910xt",
+            Self::Edge800Taiwan => "Edge800Taiwan",
+            Self::Edge800Japan => "Edge800Japan",
+            Self::Alf04 => "Alf04",
+            Self::Fr610 => "Fr610",
+            Self::Fr210Japan => "Fr210Japan",
+            Self::VectorSs => "VectorSs",
+            Self::VectorCp => "VectorCp",
+            Self::Edge800China => "Edge800China",
+            Self::Edge500China => "Edge500China",
+            Self::ApproachG10 => "ApproachG10",
+            Self::Fr610Japan => "Fr610Japan",
+            Self::Edge500Korea => "Edge500Korea",
+            Self::Fr70 => "Fr70",
+            Self::Fr310xt4t => "Fr310xt4t",
+            Self::Amx => "Amx",
+            Self::Fr10 => "Fr10",
+            Self::Edge800Korea => "Edge800Korea",
+            Self::Swim => "Swim",
+            Self::Fr910xtChina => "Fr910xtChina",
+            Self::Fenix => "Fenix",
+            Self::Edge200Taiwan => "Edge200Taiwan",
+            Self::Edge510 => "Edge510",
+            Self::Edge810 => "Edge810",
+            Self::Tempe => "Tempe",
+            Self::Fr910xtJapan => "Fr910xtJapan",
+            Self::Fr620 => "Fr620",
+            Self::Fr220 => "Fr220",
+            Self::Fr910xtKorea => "Fr910xtKorea",
+            Self::Fr10Japan => "Fr10Japan",
+            Self::Edge810Japan => "Edge810Japan",
+            Self::VirbElite => "VirbElite",
+            Self::EdgeTouring => "EdgeTouring",
+            Self::Edge510Japan => "Edge510Japan",
+            Self::HrmTri => "HrmTri",
+            Self::HrmRun => "HrmRun",
+            Self::Fr920xt => "Fr920xt",
+            Self::Edge510Asia => "Edge510Asia",
+            Self::Edge810China => "Edge810China",
+            Self::Edge810Taiwan => "Edge810Taiwan",
+            Self::Edge1000 => "Edge1000",
+            Self::VivoFit => "VivoFit",
+            Self::VirbRemote => "VirbRemote",
+            Self::VivoKi => "VivoKi",
+            Self::Fr15 => "Fr15",
+            Self::VivoActive => "VivoActive",
+            Self::Edge510Korea => "Edge510Korea",
+            Self::Fr620Japan => "Fr620Japan",
+            Self::Fr620China => "Fr620China",
+            Self::Fr220Japan => "Fr220Japan",
+            Self::Fr220China => "Fr220China",
+            Self::ApproachS6 => "ApproachS6",
+            Self::VivoSmart => "VivoSmart",
+            Self::Fenix2 => "Fenix2",
+            Self::Epix => "Epix",
+            Self::Fenix3 => "Fenix3",
+            Self::Edge1000Taiwan => "Edge1000Taiwan",
+            Self::Edge1000Japan => "Edge1000Japan",
+            Self::Fr15Japan => "Fr15Japan",
+            Self::Edge520 => "Edge520",
+            Self::Edge1000China => "Edge1000China",
+            Self::Fr620Russia => "Fr620Russia",
+            Self::Fr220Russia => "Fr220Russia",
+            Self::VectorS => "VectorS",
+            Self::Edge1000Korea => "Edge1000Korea",
+            Self::Fr920xtTaiwan => "Fr920xtTaiwan",
+            Self::Fr920xtChina => "Fr920xtChina",
+            Self::Fr920xtJapan => "Fr920xtJapan",
+            Self::Virbx => "Virbx",
+            Self::VivoSmartApac => "VivoSmartApac",
+            Self::EtrexTouch => "EtrexTouch",
+            Self::Edge25 => "Edge25",
+            Self::Fr25 => "Fr25",
+            Self::VivoFit2 => "VivoFit2",
+            Self::Fr225 => "Fr225",
+            Self::Fr630 => "Fr630",
+            Self::Fr230 => "Fr230",
+            Self::Fr735xt => "Fr735xt",
+            Self::VivoActiveApac => "VivoActiveApac",
+            Self::Vector2 => "Vector2",
+            Self::Vector2s => "Vector2s",
+            Self::Virbxe => "Virbxe",
+            Self::Fr620Taiwan => "Fr620Taiwan",
+            Self::Fr220Taiwan => "Fr220Taiwan",
+            Self::Truswing => "Truswing",
+            Self::D2airvenu => "D2airvenu",
+            Self::Fenix3China => "Fenix3China",
+            Self::Fenix3Twn => "Fenix3Twn",
+            Self::VariaHeadlight => "VariaHeadlight",
+            Self::VariaTaillightOld => "VariaTaillightOld",
+            Self::EdgeExplore1000 => "EdgeExplore1000",
+            Self::Fr225Asia => "Fr225Asia",
+            Self::VariaRadarTaillight => "VariaRadarTaillight",
+            Self::VariaRadarDisplay => "VariaRadarDisplay",
+            Self::Edge20 => "Edge20",
+            Self::Edge520Asia => "Edge520Asia",
+            Self::Edge520Japan => "Edge520Japan",
+            Self::D2Bravo => "D2Bravo",
+            Self::ApproachS20 => "ApproachS20",
+            Self::VivoSmart2 => "VivoSmart2",
+            Self::Edge1000Thai => "Edge1000Thai",
+            Self::VariaRemote => "VariaRemote",
+            Self::Edge25Asia => "Edge25Asia",
+            Self::Edge25Jpn => "Edge25Jpn",
+            Self::Edge20Asia => "Edge20Asia",
+            Self::ApproachX40 => "ApproachX40",
+            Self::Fenix3Japan => "Fenix3Japan",
+            Self::VivoSmartEmea => "VivoSmartEmea",
+            Self::Fr630Asia => "Fr630Asia",
+            Self::Fr630Jpn => "Fr630Jpn",
+            Self::Fr230Jpn => "Fr230Jpn",
+            Self::Hrm4Run => "Hrm4Run",
+            Self::EpixJapan => "EpixJapan",
+            Self::VivoActiveHr => "VivoActiveHr",
+            Self::VivoSmartGpsHr => "VivoSmartGpsHr",
+            Self::VivoSmartHr => "VivoSmartHr",
+            Self::VivoSmartHrAsia => "VivoSmartHrAsia",
+            Self::VivoSmartGpsHrAsia => "VivoSmartGpsHrAsia",
+            Self::VivoMove => "VivoMove",
+            Self::VariaTaillight => "VariaTaillight",
+            Self::Fr235Asia => "Fr235Asia",
+            Self::Fr235Japan => "Fr235Japan",
+            Self::VariaVision => "VariaVision",
+            Self::VivoFit3 => "VivoFit3",
+            Self::Fenix3Korea => "Fenix3Korea",
+            Self::Fenix3Sea => "Fenix3Sea",
+            Self::Fenix3Hr => "Fenix3Hr",
+            Self::VirbUltra30 => "VirbUltra30",
+            Self::IndexSmartScale => "IndexSmartScale",
+            Self::Fr235 => "Fr235",
+            Self::Fenix3Chronos => "Fenix3Chronos",
+            Self::Oregon7xx => "Oregon7xx",
+            Self::Rino7xx => "Rino7xx",
+            Self::EpixKorea => "EpixKorea",
+            Self::Fenix3HrChn => "Fenix3HrChn",
+            Self::Fenix3HrTwn => "Fenix3HrTwn",
+            Self::Fenix3HrJpn => "Fenix3HrJpn",
+            Self::Fenix3HrSea => "Fenix3HrSea",
+            Self::Fenix3HrKor => "Fenix3HrKor",
+            Self::Nautix => "Nautix",
+            Self::VivoActiveHrApac => "VivoActiveHrApac",
+            Self::Fr35 => "Fr35",
+            Self::Oregon7xxWw => "Oregon7xxWw",
+            Self::Edge820 => "Edge820",
+            Self::EdgeExplore820 => "EdgeExplore820",
+            Self::Fr735xtApac => "Fr735xtApac",
+            Self::Fr735xtJapan => "Fr735xtJapan",
+            Self::Fenix5s => "Fenix5s",
+            Self::D2BravoTitanium => "D2BravoTitanium",
+            Self::VariaUt800 => "VariaUt800",
+            Self::RunningDynamicsPod => "RunningDynamicsPod",
+            Self::Edge820China => "Edge820China",
+            Self::Edge820Japan => "Edge820Japan",
+            Self::Fenix5x => "Fenix5x",
+            Self::VivoFitJr => "VivoFitJr",
+            Self::VivoSmart3 => "VivoSmart3",
+            Self::VivoSport => "VivoSport",
+            Self::Edge820Taiwan => "Edge820Taiwan",
+            Self::Edge820Korea => "Edge820Korea",
+            Self::Edge820Sea => "Edge820Sea",
+            Self::Fr35Hebrew => "Fr35Hebrew",
+            Self::ApproachS60 => "ApproachS60",
+            Self::Fr35Apac => "Fr35Apac",
+            Self::Fr35Japan => "Fr35Japan",
+            Self::Fenix3ChronosAsia => "Fenix3ChronosAsia",
+            Self::Virb360 => "Virb360",
+            Self::Fr935 => "Fr935",
+            Self::Fenix5 => "Fenix5",
+            Self::Vivoactive3 => "Vivoactive3",
+            Self::Fr235ChinaNfc => "Fr235ChinaNfc",
+            Self::Foretrex601701 => "Foretrex601701",
+            Self::VivoMoveHr => "VivoMoveHr",
+            Self::Edge1030 => "Edge1030",
+            Self::Fr35Sea => "Fr35Sea",
+            Self::Vector3 => "Vector3",
+            Self::Fenix5Asia => "Fenix5Asia",
+            Self::Fenix5sAsia => "Fenix5sAsia",
+            Self::Fenix5xAsia => "Fenix5xAsia",
+            Self::ApproachZ80 => "ApproachZ80",
+            Self::Fr35Korea => "Fr35Korea",
+            Self::D2charlie => "D2charlie",
+            Self::VivoSmart3Apac => "VivoSmart3Apac",
+            Self::VivoSportApac => "VivoSportApac",
+            Self::Fr935Asia => "Fr935Asia",
+            Self::Descent => "Descent",
+            Self::VivoFit4 => "VivoFit4",
+            Self::Fr645 => "Fr645",
+            Self::Fr645m => "Fr645m",
+            Self::Fr30 => "Fr30",
+            Self::Fenix5sPlus => "Fenix5sPlus",
+            Self::Edge130 => "Edge130",
+            Self::Edge1030Asia => "Edge1030Asia",
+            Self::Vivosmart4 => "Vivosmart4",
+            Self::VivoMoveHrAsia => "VivoMoveHrAsia",
+            Self::ApproachX10 => "ApproachX10",
+            Self::Fr30Asia => "Fr30Asia",
+            Self::Vivoactive3mW => "Vivoactive3mW",
+            Self::Fr645Asia => "Fr645Asia",
+            Self::Fr645mAsia => "Fr645mAsia",
+            Self::EdgeExplore => "EdgeExplore",
+            Self::Gpsmap66 => "Gpsmap66",
+            Self::ApproachS10 => "ApproachS10",
+            Self::Vivoactive3mL => "Vivoactive3mL",
+            Self::Fr245 => "Fr245",
+            Self::Fr245Music => "Fr245Music",
+            Self::ApproachG80 => "ApproachG80",
+            Self::Edge130Asia => "Edge130Asia",
+            Self::Edge1030Bontrager => "Edge1030Bontrager",
+            Self::Fenix5Plus => "Fenix5Plus",
+            Self::Fenix5xPlus => "Fenix5xPlus",
+            Self::Edge520Plus => "Edge520Plus",
+            Self::Fr945 => "Fr945",
+            Self::Edge530 => "Edge530",
+            Self::Edge830 => "Edge830",
+            Self::InstinctEsports => "InstinctEsports",
+            Self::Fenix5sPlusApac => "Fenix5sPlusApac",
+            Self::Fenix5xPlusApac => "Fenix5xPlusApac",
+            Self::Edge520PlusApac => "Edge520PlusApac",
+            Self::DescentT1 => "DescentT1",
+            Self::Fr235lAsia => "Fr235lAsia",
+            Self::Fr245Asia => "Fr245Asia",
+            Self::VivoActive3mApac => "VivoActive3mApac",
+            Self::Gen3Bsm => "Gen3Bsm",
+            Self::Gen3Bcm => "Gen3Bcm",
+            Self::VivoSmart4Asia => "VivoSmart4Asia",
+            Self::Vivoactive4Small => "Vivoactive4Small",
+            Self::Vivoactive4Large => "Vivoactive4Large",
+            Self::Venu => "Venu",
+            Self::MarqDriver => "MarqDriver",
+            Self::MarqAviator => "MarqAviator",
+            Self::MarqCaptain => "MarqCaptain",
+            Self::MarqCommander => "MarqCommander",
+            Self::MarqExpedition => "MarqExpedition",
+            Self::MarqAthlete => "MarqAthlete",
+            Self::DescentMk2 => "DescentMk2",
+            Self::Fr45 => "Fr45",
+            Self::Gpsmap66i => "Gpsmap66i",
+            Self::Fenix6SSport => "Fenix6SSport",
+            Self::Fenix6S => "Fenix6S",
+            Self::Fenix6Sport => "Fenix6Sport",
+            Self::Fenix6 => "Fenix6",
+            Self::Fenix6x => "Fenix6x",
+            Self::HrmDual => "HrmDual",
+            Self::HrmPro => "HrmPro",
+            Self::VivoMove3Premium => "VivoMove3Premium",
+            Self::ApproachS40 => "ApproachS40",
+            Self::Fr245mAsia => "Fr245mAsia",
+            Self::Edge530Apac => "Edge530Apac",
+            Self::Edge830Apac => "Edge830Apac",
+            Self::VivoMove3 => "VivoMove3",
+            Self::VivoActive4SmallAsia => "VivoActive4SmallAsia",
+            Self::VivoActive4LargeAsia => "VivoActive4LargeAsia",
+            Self::VivoActive4OledAsia => "VivoActive4OledAsia",
+            Self::Swim2 => "Swim2",
+            Self::MarqDriverAsia => "MarqDriverAsia",
+            Self::MarqAviatorAsia => "MarqAviatorAsia",
+            Self::VivoMove3Asia => "VivoMove3Asia",
+            Self::Fr945Asia => "Fr945Asia",
+            Self::VivoActive3tChn => "VivoActive3tChn",
+            Self::MarqCaptainAsia => "MarqCaptainAsia",
+            Self::MarqCommanderAsia => "MarqCommanderAsia",
+            Self::MarqExpeditionAsia => "MarqExpeditionAsia",
+            Self::MarqAthleteAsia => "MarqAthleteAsia",
+            Self::IndexSmartScale2 => "IndexSmartScale2",
+            Self::InstinctSolar => "InstinctSolar",
+            Self::Fr45Asia => "Fr45Asia",
+            Self::Vivoactive3Daimler => "Vivoactive3Daimler",
+            Self::LegacyRey => "LegacyRey",
+            Self::LegacyDarthVader => "LegacyDarthVader",
+            Self::LegacyCaptainMarvel => "LegacyCaptainMarvel",
+            Self::LegacyFirstAvenger => "LegacyFirstAvenger",
+            Self::Fenix6sSportAsia => "Fenix6sSportAsia",
+            Self::Fenix6sAsia => "Fenix6sAsia",
+            Self::Fenix6SportAsia => "Fenix6SportAsia",
+            Self::Fenix6Asia => "Fenix6Asia",
+            Self::Fenix6xAsia => "Fenix6xAsia",
+            Self::LegacyCaptainMarvelAsia => "LegacyCaptainMarvelAsia",
+            Self::LegacyFirstAvengerAsia => "LegacyFirstAvengerAsia",
+            Self::LegacyReyAsia => "LegacyReyAsia",
+            Self::LegacyDarthVaderAsia => "LegacyDarthVaderAsia",
+            Self::DescentMk2s => "DescentMk2s",
+            Self::Edge130Plus => "Edge130Plus",
+            Self::Edge1030Plus => "Edge1030Plus",
+            Self::Rally200 => "Rally200",
+            Self::Fr745 => "Fr745",
+            Self::VenusqMusic => "VenusqMusic",
+            Self::VenusqMusicV2 => "VenusqMusicV2",
+            Self::Venusq => "Venusq",
+            Self::Lily => "Lily",
+            Self::MarqAdventurer => "MarqAdventurer",
+            Self::Enduro => "Enduro",
+            Self::Swim2Apac => "Swim2Apac",
+            Self::MarqAdventurerAsia => "MarqAdventurerAsia",
+            Self::Fr945Lte => "Fr945Lte",
+            Self::DescentMk2Asia => "DescentMk2Asia",
+            Self::Venu2 => "Venu2",
+            Self::Venu2s => "Venu2s",
+            Self::VenuDaimlerAsia => "VenuDaimlerAsia",
+            Self::MarqGolfer => "MarqGolfer",
+            Self::VenuDaimler => "VenuDaimler",
+            Self::Fr745Asia => "Fr745Asia",
+            Self::VariaRct715 => "VariaRct715",
+            Self::LilyAsia => "LilyAsia",
+            Self::Edge1030PlusAsia => "Edge1030PlusAsia",
+            Self::Edge130PlusAsia => "Edge130PlusAsia",
+            Self::ApproachS12 => "ApproachS12",
+            Self::EnduroAsia => "EnduroAsia",
+            Self::VenusqAsia => "VenusqAsia",
+            Self::Edge1040 => "Edge1040",
+            Self::MarqGolferAsia => "MarqGolferAsia",
+            Self::Venu2Plus => "Venu2Plus",
+            Self::Gnss => "Gnss",
+            Self::Fr55 => "Fr55",
+            Self::Instinct2 => "Instinct2",
+            Self::Instinct2s => "Instinct2s",
+            Self::Fenix7s => "Fenix7s",
+            Self::Fenix7 => "Fenix7",
+            Self::Fenix7x => "Fenix7x",
+            Self::Fenix7sApac => "Fenix7sApac",
+            Self::Fenix7Apac => "Fenix7Apac",
+            Self::Fenix7xApac => "Fenix7xApac",
+            Self::DescentMk2sAsia => "DescentMk2sAsia",
+            Self::ApproachS42 => "ApproachS42",
+            Self::EpixGen2 => "EpixGen2",
+            Self::EpixGen2Apac => "EpixGen2Apac",
+            Self::Venu2sAsia => "Venu2sAsia",
+            Self::Venu2Asia => "Venu2Asia",
+            Self::Fr945LteAsia => "Fr945LteAsia",
+            Self::VivoMoveSport => "VivoMoveSport",
+            Self::VivomoveTrend => "VivomoveTrend",
+            Self::ApproachS12Asia => "ApproachS12Asia",
+            Self::Fr255Music => "Fr255Music",
+            Self::Fr255SmallMusic => "Fr255SmallMusic",
+            Self::Fr255 => "Fr255",
+            Self::Fr255Small => "Fr255Small",
+            Self::ApproachS42Asia => "ApproachS42Asia",
+            Self::DescentG1 => "DescentG1",
+            Self::Venu2PlusAsia => "Venu2PlusAsia",
+            Self::Fr955 => "Fr955",
+            Self::Fr55Asia => "Fr55Asia",
+            Self::Edge540 => "Edge540",
+            Self::Edge840 => "Edge840",
+            Self::Vivosmart5 => "Vivosmart5",
+            Self::Instinct2Asia => "Instinct2Asia",
+            Self::MarqGen2 => "MarqGen2",
+            Self::Venusq2 => "Venusq2",
+            Self::Venusq2music => "Venusq2music",
+            Self::MarqGen2Aviator => "MarqGen2Aviator",
+            Self::D2AirX10 => "D2AirX10",
+            Self::HrmProPlus => "HrmProPlus",
+            Self::DescentG1Asia => "DescentG1Asia",
+            Self::Tactix7 => "Tactix7",
+            Self::InstinctCrossover => "InstinctCrossover",
+            Self::EdgeExplore2 => "EdgeExplore2",
+            Self::DescentMk3 => "DescentMk3",
+            Self::DescentMk3i => "DescentMk3i",
+            Self::ApproachS70 => "ApproachS70",
+            Self::Fr265Large => "Fr265Large",
+            Self::Fr265Small => "Fr265Small",
+            Self::Venu3 => "Venu3",
+            Self::Venu3s => "Venu3s",
+            Self::TacxNeoSmart => "TacxNeoSmart",
+            Self::TacxNeo2Smart => "TacxNeo2Smart",
+            Self::TacxNeo2TSmart => "TacxNeo2TSmart",
+            Self::TacxNeoSmartBike => "TacxNeoSmartBike",
+            Self::TacxSatoriSmart => "TacxSatoriSmart",
+            Self::TacxFlowSmart => "TacxFlowSmart",
+            Self::TacxVortexSmart => "TacxVortexSmart",
+            Self::TacxBushidoSmart => "TacxBushidoSmart",
+            Self::TacxGeniusSmart => "TacxGeniusSmart",
+            Self::TacxFluxFluxSSmart => "TacxFluxFluxSSmart",
+            Self::TacxFlux2Smart => "TacxFlux2Smart",
+            Self::TacxMagnum => "TacxMagnum",
+            Self::Edge1040Asia => "Edge1040Asia",
+            Self::EpixGen2Pro42 => "EpixGen2Pro42",
+            Self::EpixGen2Pro47 => "EpixGen2Pro47",
+            Self::EpixGen2Pro51 => "EpixGen2Pro51",
+            Self::Fr965 => "Fr965",
+            Self::Enduro2 => "Enduro2",
+            Self::Fenix7sProSolar => "Fenix7sProSolar",
+            Self::Fenix7ProSolar => "Fenix7ProSolar",
+            Self::Fenix7xProSolar => "Fenix7xProSolar",
+            Self::Lily2 => "Lily2",
+            Self::Instinct2x => "Instinct2x",
+            Self::Vivoactive5 => "Vivoactive5",
+            Self::Fr165 => "Fr165",
+            Self::Fr165Music => "Fr165Music",
+            Self::Edge1050 => "Edge1050",
+            Self::DescentT2 => "DescentT2",
+            Self::HrmFit => "HrmFit",
+            Self::MarqGen2Commander => "MarqGen2Commander",
+            Self::LilyAthlete => "LilyAthlete",
+            Self::Fenix8Solar => "Fenix8Solar",
+            Self::Fenix8SolarLarge => "Fenix8SolarLarge",
+            Self::Fenix8Small => "Fenix8Small",
+            Self::Fenix8 => "Fenix8",
+            Self::D2Mach1Pro => "D2Mach1Pro",
+            Self::Enduro3 => "Enduro3",
+            Self::InstinctE40mm => "InstinctE40mm",
+            Self::InstinctE45mm => "InstinctE45mm",
+            Self::Instinct3Solar45mm => "Instinct3Solar45mm",
+            Self::Instinct3Amoled45mm => "Instinct3Amoled45mm",
+            Self::Instinct3Amoled50mm => "Instinct3Amoled50mm",
+            Self::DescentG2 => "DescentG2",
+            Self::Hrm200 => "Hrm200",
+            Self::Vivoactive6 => "Vivoactive6",
+            Self::ApproachS44 => "ApproachS44",
+            Self::ApproachS50 => "ApproachS50",
+            Self::FenixE => "FenixE",
+            Self::Instinct3Solar50mm => "Instinct3Solar50mm",
+            Self::Tactix8Amoled => "Tactix8Amoled",
+            Self::Tactix8Solar => "Tactix8Solar",
+            Self::Sdm4 => "Sdm4",
+            Self::EdgeRemote => "EdgeRemote",
+            Self::TacxTrainingAppWin => "TacxTrainingAppWin",
+            Self::TacxTrainingAppMac => "TacxTrainingAppMac",
+            Self::TacxTrainingAppMacCatalyst => "TacxTrainingAppMacCatalyst",
+            Self::TrainingCenter => "TrainingCenter",
+            Self::TacxTrainingAppAndroid => "TacxTrainingAppAndroid",
+            Self::TacxTrainingAppIos => "TacxTrainingAppIos",
+            Self::TacxTrainingAppLegacy => "TacxTrainingAppLegacy",
+            Self::ConnectiqSimulator => "ConnectiqSimulator",
+            Self::AndroidAntplusPlugin => "AndroidAntplusPlugin",
+            Self::Connect => "Connect",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AntplusDeviceType {
     Antfs,
     BikePower,
@@ -4593,7 +9253,479 @@ pub enum AntplusDeviceType {
     StrideSpeedDistance,
     UnknownVariant(u8),
 }
-impl AntplusDeviceType {
+
+impl std::str::FromStr for GarminProduct {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [GarminProduct::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Hrm1" => Ok(Self::Hrm1),
+            "Axh01" => Ok(Self::Axh01),
+            "Axb01" => Ok(Self::Axb01),
+            "Axb02" => Ok(Self::Axb02),
+            "Hrm2ss" => Ok(Self::Hrm2ss),
+            "DsiAlf02" => Ok(Self::DsiAlf02),
+            "Hrm3ss" => Ok(Self::Hrm3ss),
+            "HrmRunSingleByteProductId" => Ok(Self::HrmRunSingleByteProductId),
+            "Bsm" => Ok(Self::Bsm),
+            "Bcm" => Ok(Self::Bcm),
+            "Axs01" => Ok(Self::Axs01),
+            "HrmTriSingleByteProductId" => Ok(Self::HrmTriSingleByteProductId),
+            "Hrm4RunSingleByteProductId" => Ok(Self::Hrm4RunSingleByteProductId),
+            "Fr225SingleByteProductId" => Ok(Self::Fr225SingleByteProductId),
+            "Gen3BsmSingleByteProductId" => Ok(Self::Gen3BsmSingleByteProductId),
+            "Gen3BcmSingleByteProductId" => Ok(Self::Gen3BcmSingleByteProductId),
+            "HrmFitSingleByteProductId" => Ok(Self::HrmFitSingleByteProductId),
+            "OHR" => Ok(Self::OHR),
+            "Fr301China" => Ok(Self::Fr301China),
+            "Fr301Japan" => Ok(Self::Fr301Japan),
+            "Fr301Korea" => Ok(Self::Fr301Korea),
+            "Fr301Taiwan" => Ok(Self::Fr301Taiwan),
+            "Fr405" => Ok(Self::Fr405),
+            "Fr50" => Ok(Self::Fr50),
+            "Fr405Japan" => Ok(Self::Fr405Japan),
+            "Fr60" => Ok(Self::Fr60),
+            "DsiAlf01" => Ok(Self::DsiAlf01),
+            "Fr310xt" => Ok(Self::Fr310xt),
+            "Edge500" => Ok(Self::Edge500),
+            "Fr110" => Ok(Self::Fr110),
+            "Edge800" => Ok(Self::Edge800),
+            "Edge500Taiwan" => Ok(Self::Edge500Taiwan),
+            "Edge500Japan" => Ok(Self::Edge500Japan),
+            "Chirp" => Ok(Self::Chirp),
+            "Fr110Japan" => Ok(Self::Fr110Japan),
+            "Edge200" => Ok(Self::Edge200),
+            "Fr910xt" => Ok(Self::Fr910xt),
+            "Edge800Taiwan" => Ok(Self::Edge800Taiwan),
+            "Edge800Japan" => Ok(Self::Edge800Japan),
+            "Alf04" => Ok(Self::Alf04),
+            "Fr610" => Ok(Self::Fr610),
+            "Fr210Japan" => Ok(Self::Fr210Japan),
+            "VectorSs" => Ok(Self::VectorSs),
+            "VectorCp" => Ok(Self::VectorCp),
+            "Edge800China" => Ok(Self::Edge800China),
+            "Edge500China" => Ok(Self::Edge500China),
+            "ApproachG10" => Ok(Self::ApproachG10),
+            "Fr610Japan" => Ok(Self::Fr610Japan),
+            "Edge500Korea" => Ok(Self::Edge500Korea),
+            "Fr70" => Ok(Self::Fr70),
+            "Fr310xt4t" => Ok(Self::Fr310xt4t),
+            "Amx" => Ok(Self::Amx),
+            "Fr10" => Ok(Self::Fr10),
+            "Edge800Korea" => Ok(Self::Edge800Korea),
+            "Swim" => Ok(Self::Swim),
+            "Fr910xtChina" => Ok(Self::Fr910xtChina),
+            "Fenix" => Ok(Self::Fenix),
+            "Edge200Taiwan" => Ok(Self::Edge200Taiwan),
+            "Edge510" => Ok(Self::Edge510),
+            "Edge810" => Ok(Self::Edge810),
+            "Tempe" => Ok(Self::Tempe),
+            "Fr910xtJapan" => Ok(Self::Fr910xtJapan),
+            "Fr620" => Ok(Self::Fr620),
+            "Fr220" => Ok(Self::Fr220),
+            "Fr910xtKorea" => Ok(Self::Fr910xtKorea),
+            "Fr10Japan" => Ok(Self::Fr10Japan),
+            "Edge810Japan" => Ok(Self::Edge810Japan),
+            "VirbElite" => Ok(Self::VirbElite),
+            "EdgeTouring" => Ok(Self::EdgeTouring),
+            "Edge510Japan" => Ok(Self::Edge510Japan),
+            "HrmTri" => Ok(Self::HrmTri),
+            "HrmRun" => Ok(Self::HrmRun),
+            "Fr920xt" => Ok(Self::Fr920xt),
+            "Edge510Asia" => Ok(Self::Edge510Asia),
+            "Edge810China" => Ok(Self::Edge810China),
+            "Edge810Taiwan" => Ok(Self::Edge810Taiwan),
+            "Edge1000" => Ok(Self::Edge1000),
+            "VivoFit" => Ok(Self::VivoFit),
+            "VirbRemote" => Ok(Self::VirbRemote),
+            "VivoKi" => Ok(Self::VivoKi),
+            "Fr15" => Ok(Self::Fr15),
+            "VivoActive" => Ok(Self::VivoActive),
+            "Edge510Korea" => Ok(Self::Edge510Korea),
+            "Fr620Japan" => Ok(Self::Fr620Japan),
+            "Fr620China" => Ok(Self::Fr620China),
+            "Fr220Japan" => Ok(Self::Fr220Japan),
+            "Fr220China" => Ok(Self::Fr220China),
+            "ApproachS6" => Ok(Self::ApproachS6),
+            "VivoSmart" => Ok(Self::VivoSmart),
+            "Fenix2" => Ok(Self::Fenix2),
+            "Epix" => Ok(Self::Epix),
+            "Fenix3" => Ok(Self::Fenix3),
+            "Edge1000Taiwan" => Ok(Self::Edge1000Taiwan),
+            "Edge1000Japan" => Ok(Self::Edge1000Japan),
+            "Fr15Japan" => Ok(Self::Fr15Japan),
+            "Edge520" => Ok(Self::Edge520),
+            "Edge1000China" => Ok(Self::Edge1000China),
+            "Fr620Russia" => Ok(Self::Fr620Russia),
+            "Fr220Russia" => Ok(Self::Fr220Russia),
+            "VectorS" => Ok(Self::VectorS),
+            "Edge1000Korea" => Ok(Self::Edge1000Korea),
+            "Fr920xtTaiwan" => Ok(Self::Fr920xtTaiwan),
+            "Fr920xtChina" => Ok(Self::Fr920xtChina),
+            "Fr920xtJapan" => Ok(Self::Fr920xtJapan),
+            "Virbx" => Ok(Self::Virbx),
+            "VivoSmartApac" => Ok(Self::VivoSmartApac),
+            "EtrexTouch" => Ok(Self::EtrexTouch),
+            "Edge25" => Ok(Self::Edge25),
+            "Fr25" => Ok(Self::Fr25),
+            "VivoFit2" => Ok(Self::VivoFit2),
+            "Fr225" => Ok(Self::Fr225),
+            "Fr630" => Ok(Self::Fr630),
+            "Fr230" => Ok(Self::Fr230),
+            "Fr735xt" => Ok(Self::Fr735xt),
+            "VivoActiveApac" => Ok(Self::VivoActiveApac),
+            "Vector2" => Ok(Self::Vector2),
+            "Vector2s" => Ok(Self::Vector2s),
+            "Virbxe" => Ok(Self::Virbxe),
+            "Fr620Taiwan" => Ok(Self::Fr620Taiwan),
+            "Fr220Taiwan" => Ok(Self::Fr220Taiwan),
+            "Truswing" => Ok(Self::Truswing),
+            "D2airvenu" => Ok(Self::D2airvenu),
+            "Fenix3China" => Ok(Self::Fenix3China),
+            "Fenix3Twn" => Ok(Self::Fenix3Twn),
+            "VariaHeadlight" => Ok(Self::VariaHeadlight),
+            "VariaTaillightOld" => Ok(Self::VariaTaillightOld),
+            "EdgeExplore1000" => Ok(Self::EdgeExplore1000),
+            "Fr225Asia" => Ok(Self::Fr225Asia),
+            "VariaRadarTaillight" => Ok(Self::VariaRadarTaillight),
+            "VariaRadarDisplay" => Ok(Self::VariaRadarDisplay),
+            "Edge20" => Ok(Self::Edge20),
+            "Edge520Asia" => Ok(Self::Edge520Asia),
+            "Edge520Japan" => Ok(Self::Edge520Japan),
+            "D2Bravo" => Ok(Self::D2Bravo),
+            "ApproachS20" => Ok(Self::ApproachS20),
+            "VivoSmart2" => Ok(Self::VivoSmart2),
+            "Edge1000Thai" => Ok(Self::Edge1000Thai),
+            "VariaRemote" => Ok(Self::VariaRemote),
+            "Edge25Asia" => Ok(Self::Edge25Asia),
+            "Edge25Jpn" => Ok(Self::Edge25Jpn),
+            "Edge20Asia" => Ok(Self::Edge20Asia),
+            "ApproachX40" => Ok(Self::ApproachX40),
+            "Fenix3Japan" => Ok(Self::Fenix3Japan),
+            "VivoSmartEmea" => Ok(Self::VivoSmartEmea),
+            "Fr630Asia" => Ok(Self::Fr630Asia),
+            "Fr630Jpn" => Ok(Self::Fr630Jpn),
+            "Fr230Jpn" => Ok(Self::Fr230Jpn),
+            "Hrm4Run" => Ok(Self::Hrm4Run),
+            "EpixJapan" => Ok(Self::EpixJapan),
+            "VivoActiveHr" => Ok(Self::VivoActiveHr),
+            "VivoSmartGpsHr" => Ok(Self::VivoSmartGpsHr),
+            "VivoSmartHr" => Ok(Self::VivoSmartHr),
+            "VivoSmartHrAsia" => Ok(Self::VivoSmartHrAsia),
+            "VivoSmartGpsHrAsia" => Ok(Self::VivoSmartGpsHrAsia),
+            "VivoMove" => Ok(Self::VivoMove),
+            "VariaTaillight" => Ok(Self::VariaTaillight),
+            "Fr235Asia" => Ok(Self::Fr235Asia),
+            "Fr235Japan" => Ok(Self::Fr235Japan),
+            "VariaVision" => Ok(Self::VariaVision),
+            "VivoFit3" => Ok(Self::VivoFit3),
+            "Fenix3Korea" => Ok(Self::Fenix3Korea),
+            "Fenix3Sea" => Ok(Self::Fenix3Sea),
+            "Fenix3Hr" => Ok(Self::Fenix3Hr),
+            "VirbUltra30" => Ok(Self::VirbUltra30),
+            "IndexSmartScale" => Ok(Self::IndexSmartScale),
+            "Fr235" => Ok(Self::Fr235),
+            "Fenix3Chronos" => Ok(Self::Fenix3Chronos),
+            "Oregon7xx" => Ok(Self::Oregon7xx),
+            "Rino7xx" => Ok(Self::Rino7xx),
+            "EpixKorea" => Ok(Self::EpixKorea),
+            "Fenix3HrChn" => Ok(Self::Fenix3HrChn),
+            "Fenix3HrTwn" => Ok(Self::Fenix3HrTwn),
+            "Fenix3HrJpn" => Ok(Self::Fenix3HrJpn),
+            "Fenix3HrSea" => Ok(Self::Fenix3HrSea),
+            "Fenix3HrKor" => Ok(Self::Fenix3HrKor),
+            "Nautix" => Ok(Self::Nautix),
+            "VivoActiveHrApac" => Ok(Self::VivoActiveHrApac),
+            "Fr35" => Ok(Self::Fr35),
+            "Oregon7xxWw" => Ok(Self::Oregon7xxWw),
+            "Edge820" => Ok(Self::Edge820),
+            "EdgeExplore820" => Ok(Self::EdgeExplore820),
+            "Fr735xtApac" => Ok(Self::Fr735xtApac),
+            "Fr735xtJapan" => Ok(Self::Fr735xtJapan),
+            "Fenix5s" => Ok(Self::Fenix5s),
+            "D2BravoTitanium" => Ok(Self::D2BravoTitanium),
+            "VariaUt800" => Ok(Self::VariaUt800),
+            "RunningDynamicsPod" => Ok(Self::RunningDynamicsPod),
+            "Edge820China" => Ok(Self::Edge820China),
+            "Edge820Japan" => Ok(Self::Edge820Japan),
+            "Fenix5x" => Ok(Self::Fenix5x),
+            "VivoFitJr" => Ok(Self::VivoFitJr),
+            "VivoSmart3" => Ok(Self::VivoSmart3),
+            "VivoSport" => Ok(Self::VivoSport),
+            "Edge820Taiwan" => Ok(Self::Edge820Taiwan),
+            "Edge820Korea" => Ok(Self::Edge820Korea),
+            "Edge820Sea" => Ok(Self::Edge820Sea),
+            "Fr35Hebrew" => Ok(Self::Fr35Hebrew),
+            "ApproachS60" => Ok(Self::ApproachS60),
+            "Fr35Apac" => Ok(Self::Fr35Apac),
+            "Fr35Japan" => Ok(Self::Fr35Japan),
+            "Fenix3ChronosAsia" => Ok(Self::Fenix3ChronosAsia),
+            "Virb360" => Ok(Self::Virb360),
+            "Fr935" => Ok(Self::Fr935),
+            "Fenix5" => Ok(Self::Fenix5),
+            "Vivoactive3" => Ok(Self::Vivoactive3),
+            "Fr235ChinaNfc" => Ok(Self::Fr235ChinaNfc),
+            "Foretrex601701" => Ok(Self::Foretrex601701),
+            "VivoMoveHr" => Ok(Self::VivoMoveHr),
+            "Edge1030" => Ok(Self::Edge1030),
+            "Fr35Sea" => Ok(Self::Fr35Sea),
+            "Vector3" => Ok(Self::Vector3),
+            "Fenix5Asia" => Ok(Self::Fenix5Asia),
+            "Fenix5sAsia" => Ok(Self::Fenix5sAsia),
+            "Fenix5xAsia" => Ok(Self::Fenix5xAsia),
+            "ApproachZ80" => Ok(Self::ApproachZ80),
+            "Fr35Korea" => Ok(Self::Fr35Korea),
+            "D2charlie" => Ok(Self::D2charlie),
+            "VivoSmart3Apac" => Ok(Self::VivoSmart3Apac),
+            "VivoSportApac" => Ok(Self::VivoSportApac),
+            "Fr935Asia" => Ok(Self::Fr935Asia),
+            "Descent" => Ok(Self::Descent),
+            "VivoFit4" => Ok(Self::VivoFit4),
+            "Fr645" => Ok(Self::Fr645),
+            "Fr645m" => Ok(Self::Fr645m),
+            "Fr30" => Ok(Self::Fr30),
+            "Fenix5sPlus" => Ok(Self::Fenix5sPlus),
+            "Edge130" => Ok(Self::Edge130),
+            "Edge1030Asia" => Ok(Self::Edge1030Asia),
+            "Vivosmart4" => Ok(Self::Vivosmart4),
+            "VivoMoveHrAsia" => Ok(Self::VivoMoveHrAsia),
+            "ApproachX10" => Ok(Self::ApproachX10),
+            "Fr30Asia" => Ok(Self::Fr30Asia),
+            "Vivoactive3mW" => Ok(Self::Vivoactive3mW),
+            "Fr645Asia" => Ok(Self::Fr645Asia),
+            "Fr645mAsia" => Ok(Self::Fr645mAsia),
+            "EdgeExplore" => Ok(Self::EdgeExplore),
+            "Gpsmap66" => Ok(Self::Gpsmap66),
+            "ApproachS10" => Ok(Self::ApproachS10),
+            "Vivoactive3mL" => Ok(Self::Vivoactive3mL),
+            "Fr245" => Ok(Self::Fr245),
+            "Fr245Music" => Ok(Self::Fr245Music),
+            "ApproachG80" => Ok(Self::ApproachG80),
+            "Edge130Asia" => Ok(Self::Edge130Asia),
+            "Edge1030Bontrager" => Ok(Self::Edge1030Bontrager),
+            "Fenix5Plus" => Ok(Self::Fenix5Plus),
+            "Fenix5xPlus" => Ok(Self::Fenix5xPlus),
+            "Edge520Plus" => Ok(Self::Edge520Plus),
+            "Fr945" => Ok(Self::Fr945),
+            "Edge530" => Ok(Self::Edge530),
+            "Edge830" => Ok(Self::Edge830),
+            "InstinctEsports" => Ok(Self::InstinctEsports),
+            "Fenix5sPlusApac" => Ok(Self::Fenix5sPlusApac),
+            "Fenix5xPlusApac" => Ok(Self::Fenix5xPlusApac),
+            "Edge520PlusApac" => Ok(Self::Edge520PlusApac),
+            "DescentT1" => Ok(Self::DescentT1),
+            "Fr235lAsia" => Ok(Self::Fr235lAsia),
+            "Fr245Asia" => Ok(Self::Fr245Asia),
+            "VivoActive3mApac" => Ok(Self::VivoActive3mApac),
+            "Gen3Bsm" => Ok(Self::Gen3Bsm),
+            "Gen3Bcm" => Ok(Self::Gen3Bcm),
+            "VivoSmart4Asia" => Ok(Self::VivoSmart4Asia),
+            "Vivoactive4Small" => Ok(Self::Vivoactive4Small),
+            "Vivoactive4Large" => Ok(Self::Vivoactive4Large),
+            "Venu" => Ok(Self::Venu),
+            "MarqDriver" => Ok(Self::MarqDriver),
+            "MarqAviator" => Ok(Self::MarqAviator),
+            "MarqCaptain" => Ok(Self::MarqCaptain),
+            "MarqCommander" => Ok(Self::MarqCommander),
+            "MarqExpedition" => Ok(Self::MarqExpedition),
+            "MarqAthlete" => Ok(Self::MarqAthlete),
+            "DescentMk2" => Ok(Self::DescentMk2),
+            "Fr45" => Ok(Self::Fr45),
+            "Gpsmap66i" => Ok(Self::Gpsmap66i),
+            "Fenix6SSport" => Ok(Self::Fenix6SSport),
+            "Fenix6S" => Ok(Self::Fenix6S),
+            "Fenix6Sport" => Ok(Self::Fenix6Sport),
+            "Fenix6" => Ok(Self::Fenix6),
+            "Fenix6x" => Ok(Self::Fenix6x),
+            "HrmDual" => Ok(Self::HrmDual),
+            "HrmPro" => Ok(Self::HrmPro),
+            "VivoMove3Premium" => Ok(Self::VivoMove3Premium),
+            "ApproachS40" => Ok(Self::ApproachS40),
+            "Fr245mAsia" => Ok(Self::Fr245mAsia),
+            "Edge530Apac" => Ok(Self::Edge530Apac),
+            "Edge830Apac" => Ok(Self::Edge830Apac),
+            "VivoMove3" => Ok(Self::VivoMove3),
+            "VivoActive4SmallAsia" => Ok(Self::VivoActive4SmallAsia),
+            "VivoActive4LargeAsia" => Ok(Self::VivoActive4LargeAsia),
+            "VivoActive4OledAsia" => Ok(Self::VivoActive4OledAsia),
+            "Swim2" => Ok(Self::Swim2),
+            "MarqDriverAsia" => Ok(Self::MarqDriverAsia),
+            "MarqAviatorAsia" => Ok(Self::MarqAviatorAsia),
+            "VivoMove3Asia" => Ok(Self::VivoMove3Asia),
+            "Fr945Asia" => Ok(Self::Fr945Asia),
+            "VivoActive3tChn" => Ok(Self::VivoActive3tChn),
+            "MarqCaptainAsia" => Ok(Self::MarqCaptainAsia),
+            "MarqCommanderAsia" => Ok(Self::MarqCommanderAsia),
+            "MarqExpeditionAsia" => Ok(Self::MarqExpeditionAsia),
+            "MarqAthleteAsia" => Ok(Self::MarqAthleteAsia),
+            "IndexSmartScale2" => Ok(Self::IndexSmartScale2),
+            "InstinctSolar" => Ok(Self::InstinctSolar),
+            "Fr45Asia" => Ok(Self::Fr45Asia),
+            "Vivoactive3Daimler" => Ok(Self::Vivoactive3Daimler),
+            "LegacyRey" => Ok(Self::LegacyRey),
+            "LegacyDarthVader" => Ok(Self::LegacyDarthVader),
+            "LegacyCaptainMarvel" => Ok(Self::LegacyCaptainMarvel),
+            "LegacyFirstAvenger" => Ok(Self::LegacyFirstAvenger),
+            "Fenix6sSportAsia" => Ok(Self::Fenix6sSportAsia),
+            "Fenix6sAsia" => Ok(Self::Fenix6sAsia),
+            "Fenix6SportAsia" => Ok(Self::Fenix6SportAsia),
+            "Fenix6Asia" => Ok(Self::Fenix6Asia),
+            "Fenix6xAsia" => Ok(Self::Fenix6xAsia),
+            "LegacyCaptainMarvelAsia" => Ok(Self::LegacyCaptainMarvelAsia),
+            "LegacyFirstAvengerAsia" => Ok(Self::LegacyFirstAvengerAsia),
+            "LegacyReyAsia" => Ok(Self::LegacyReyAsia),
+            "LegacyDarthVaderAsia" => Ok(Self::LegacyDarthVaderAsia),
+            "DescentMk2s" => Ok(Self::DescentMk2s),
+            "Edge130Plus" => Ok(Self::Edge130Plus),
+            "Edge1030Plus" => Ok(Self::Edge1030Plus),
+            "Rally200" => Ok(Self::Rally200),
+            "Fr745" => Ok(Self::Fr745),
+            "VenusqMusic" => Ok(Self::VenusqMusic),
+            "VenusqMusicV2" => Ok(Self::VenusqMusicV2),
+            "Venusq" => Ok(Self::Venusq),
+            "Lily" => Ok(Self::Lily),
+            "MarqAdventurer" => Ok(Self::MarqAdventurer),
+            "Enduro" => Ok(Self::Enduro),
+            "Swim2Apac" => Ok(Self::Swim2Apac),
+            "MarqAdventurerAsia" => Ok(Self::MarqAdventurerAsia),
+            "Fr945Lte" => Ok(Self::Fr945Lte),
+            "DescentMk2Asia" => Ok(Self::DescentMk2Asia),
+            "Venu2" => Ok(Self::Venu2),
+            "Venu2s" => Ok(Self::Venu2s),
+            "VenuDaimlerAsia" => Ok(Self::VenuDaimlerAsia),
+            "MarqGolfer" => Ok(Self::MarqGolfer),
+            "VenuDaimler" => Ok(Self::VenuDaimler),
+            "Fr745Asia" => Ok(Self::Fr745Asia),
+            "VariaRct715" => Ok(Self::VariaRct715),
+            "LilyAsia" => Ok(Self::LilyAsia),
+            "Edge1030PlusAsia" => Ok(Self::Edge1030PlusAsia),
+            "Edge130PlusAsia" => Ok(Self::Edge130PlusAsia),
+            "ApproachS12" => Ok(Self::ApproachS12),
+            "EnduroAsia" => Ok(Self::EnduroAsia),
+            "VenusqAsia" => Ok(Self::VenusqAsia),
+            "Edge1040" => Ok(Self::Edge1040),
+            "MarqGolferAsia" => Ok(Self::MarqGolferAsia),
+            "Venu2Plus" => Ok(Self::Venu2Plus),
+            "Gnss" => Ok(Self::Gnss),
+            "Fr55" => Ok(Self::Fr55),
+            "Instinct2" => Ok(Self::Instinct2),
+            "Instinct2s" => Ok(Self::Instinct2s),
+            "Fenix7s" => Ok(Self::Fenix7s),
+            "Fenix7" => Ok(Self::Fenix7),
+            "Fenix7x" => Ok(Self::Fenix7x),
+            "Fenix7sApac" => Ok(Self::Fenix7sApac),
+            "Fenix7Apac" => Ok(Self::Fenix7Apac),
+            "Fenix7xApac" => Ok(Self::Fenix7xApac),
+            "DescentMk2sAsia" => Ok(Self::DescentMk2sAsia),
+            "ApproachS42" => Ok(Self::ApproachS42),
+            "EpixGen2" => Ok(Self::EpixGen2),
+            "EpixGen2Apac" => Ok(Self::EpixGen2Apac),
+            "Venu2sAsia" => Ok(Self::Venu2sAsia),
+            "Venu2Asia" => Ok(Self::Venu2Asia),
+            "Fr945LteAsia" => Ok(Self::Fr945LteAsia),
+            "VivoMoveSport" => Ok(Self::VivoMoveSport),
+            "VivomoveTrend" => Ok(Self::VivomoveTrend),
+            "ApproachS12Asia" => Ok(Self::ApproachS12Asia),
+            "Fr255Music" => Ok(Self::Fr255Music),
+            "Fr255SmallMusic" => Ok(Self::Fr255SmallMusic),
+            "Fr255" => Ok(Self::Fr255),
+            "Fr255Small" => Ok(Self::Fr255Small),
+            "ApproachS42Asia" => Ok(Self::ApproachS42Asia),
+            "DescentG1" => Ok(Self::DescentG1),
+            "Venu2PlusAsia" => Ok(Self::Venu2PlusAsia),
+            "Fr955" => Ok(Self::Fr955),
+            "Fr55Asia" => Ok(Self::Fr55Asia),
+            "Edge540" => Ok(Self::Edge540),
+            "Edge840" => Ok(Self::Edge840),
+            "Vivosmart5" => Ok(Self::Vivosmart5),
+            "Instinct2Asia" => Ok(Self::Instinct2Asia),
+            "MarqGen2" => Ok(Self::MarqGen2),
+            "Venusq2" => Ok(Self::Venusq2),
+            "Venusq2music" => Ok(Self::Venusq2music),
+            "MarqGen2Aviator" => Ok(Self::MarqGen2Aviator),
+            "D2AirX10" => Ok(Self::D2AirX10),
+            "HrmProPlus" => Ok(Self::HrmProPlus),
+            "DescentG1Asia" => Ok(Self::DescentG1Asia),
+            "Tactix7" => Ok(Self::Tactix7),
+            "InstinctCrossover" => Ok(Self::InstinctCrossover),
+            "EdgeExplore2" => Ok(Self::EdgeExplore2),
+            "DescentMk3" => Ok(Self::DescentMk3),
+            "DescentMk3i" => Ok(Self::DescentMk3i),
+            "ApproachS70" => Ok(Self::ApproachS70),
+            "Fr265Large" => Ok(Self::Fr265Large),
+            "Fr265Small" => Ok(Self::Fr265Small),
+            "Venu3" => Ok(Self::Venu3),
+            "Venu3s" => Ok(Self::Venu3s),
+            "TacxNeoSmart" => Ok(Self::TacxNeoSmart),
+            "TacxNeo2Smart" => Ok(Self::TacxNeo2Smart),
+            "TacxNeo2TSmart" => Ok(Self::TacxNeo2TSmart),
+            "TacxNeoSmartBike" => Ok(Self::TacxNeoSmartBike),
+            "TacxSatoriSmart" => Ok(Self::TacxSatoriSmart),
+            "TacxFlowSmart" => Ok(Self::TacxFlowSmart),
+            "TacxVortexSmart" => Ok(Self::TacxVortexSmart),
+            "TacxBushidoSmart" => Ok(Self::TacxBushidoSmart),
+            "TacxGeniusSmart" => Ok(Self::TacxGeniusSmart),
+            "TacxFluxFluxSSmart" => Ok(Self::TacxFluxFluxSSmart),
+            "TacxFlux2Smart" => Ok(Self::TacxFlux2Smart),
+            "TacxMagnum" => Ok(Self::TacxMagnum),
+            "Edge1040Asia" => Ok(Self::Edge1040Asia),
+            "EpixGen2Pro42" => Ok(Self::EpixGen2Pro42),
+            "EpixGen2Pro47" => Ok(Self::EpixGen2Pro47),
+            "EpixGen2Pro51" => Ok(Self::EpixGen2Pro51),
+            "Fr965" => Ok(Self::Fr965),
+            "Enduro2" => Ok(Self::Enduro2),
+            "Fenix7sProSolar" => Ok(Self::Fenix7sProSolar),
+            "Fenix7ProSolar" => Ok(Self::Fenix7ProSolar),
+            "Fenix7xProSolar" => Ok(Self::Fenix7xProSolar),
+            "Lily2" => Ok(Self::Lily2),
+            "Instinct2x" => Ok(Self::Instinct2x),
+            "Vivoactive5" => Ok(Self::Vivoactive5),
+            "Fr165" => Ok(Self::Fr165),
+            "Fr165Music" => Ok(Self::Fr165Music),
+            "Edge1050" => Ok(Self::Edge1050),
+            "DescentT2" => Ok(Self::DescentT2),
+            "HrmFit" => Ok(Self::HrmFit),
+            "MarqGen2Commander" => Ok(Self::MarqGen2Commander),
+            "LilyAthlete" => Ok(Self::LilyAthlete),
+            "Fenix8Solar" => Ok(Self::Fenix8Solar),
+            "Fenix8SolarLarge" => Ok(Self::Fenix8SolarLarge),
+            "Fenix8Small" => Ok(Self::Fenix8Small),
+            "Fenix8" => Ok(Self::Fenix8),
+            "D2Mach1Pro" => Ok(Self::D2Mach1Pro),
+            "Enduro3" => Ok(Self::Enduro3),
+            "InstinctE40mm" => Ok(Self::InstinctE40mm),
+            "InstinctE45mm" => Ok(Self::InstinctE45mm),
+            "Instinct3Solar45mm" => Ok(Self::Instinct3Solar45mm),
+            "Instinct3Amoled45mm" => Ok(Self::Instinct3Amoled45mm),
+            "Instinct3Amoled50mm" => Ok(Self::Instinct3Amoled50mm),
+            "DescentG2" => Ok(Self::DescentG2),
+            "Hrm200" => Ok(Self::Hrm200),
+            "Vivoactive6" => Ok(Self::Vivoactive6),
+            "ApproachS44" => Ok(Self::ApproachS44),
+            "ApproachS50" => Ok(Self::ApproachS50),
+            "FenixE" => Ok(Self::FenixE),
+            "Instinct3Solar50mm" => Ok(Self::Instinct3Solar50mm),
+            "Tactix8Amoled" => Ok(Self::Tactix8Amoled),
+            "Tactix8Solar" => Ok(Self::Tactix8Solar),
+            "Sdm4" => Ok(Self::Sdm4),
+            "EdgeRemote" => Ok(Self::EdgeRemote),
+            "TacxTrainingAppWin" => Ok(Self::TacxTrainingAppWin),
+            "TacxTrainingAppMac" => Ok(Self::TacxTrainingAppMac),
+            "TacxTrainingAppMacCatalyst" => Ok(Self::TacxTrainingAppMacCatalyst),
+            "TrainingCenter" => Ok(Self::TrainingCenter),
+            "TacxTrainingAppAndroid" => Ok(Self::TacxTrainingAppAndroid),
+            "TacxTrainingAppIos" => Ok(Self::TacxTrainingAppIos),
+            "TacxTrainingAppLegacy" => Ok(Self::TacxTrainingAppLegacy),
+            "ConnectiqSimulator" => Ok(Self::ConnectiqSimulator),
+            "AndroidAntplusPlugin" => Ok(Self::AndroidAntplusPlugin),
+            "Connect" => Ok(Self::Connect),
+            _ => Err(UnknownVariantError::new("GarminProduct", name)),
+        }
+    }
+}
+impl AntplusDeviceType {
     pub fn from(content: u8) -> AntplusDeviceType {
         match content {
             1 => AntplusDeviceType::Antfs,
@@ -4630,17 +9762,82 @@ impl AntplusDeviceType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AntplusDeviceType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AntplusDeviceType] {
+        &[
+            AntplusDeviceType::Antfs,
+            AntplusDeviceType::BikePower,
+            AntplusDeviceType::EnvironmentSensorLegacy,
+            AntplusDeviceType::MultiSportSpeedDistance,
+            AntplusDeviceType::Control,
+            AntplusDeviceType::FitnessEquipment,
+            AntplusDeviceType::BloodPressure,
+            AntplusDeviceType::GeocacheNode,
+            AntplusDeviceType::LightElectricVehicle,
+            AntplusDeviceType::EnvSensor,
+            AntplusDeviceType::Racquet,
+            AntplusDeviceType::ControlHub,
+            AntplusDeviceType::MuscleOxygen,
+            AntplusDeviceType::Shifting,
+            AntplusDeviceType::BikeLightMain,
+            AntplusDeviceType::BikeLightShared,
+            AntplusDeviceType::Exd,
+            AntplusDeviceType::BikeRadar,
+            AntplusDeviceType::BikeAero,
+            AntplusDeviceType::WeightScale,
+            AntplusDeviceType::HeartRate,
+            AntplusDeviceType::BikeSpeedCadence,
+            AntplusDeviceType::BikeCadence,
+            AntplusDeviceType::BikeSpeed,
+            AntplusDeviceType::StrideSpeedDistance,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Antfs => "Antfs",
+            Self::BikePower => "BikePower",
+            Self::EnvironmentSensorLegacy => "EnvironmentSensorLegacy",
+            Self::MultiSportSpeedDistance => "MultiSportSpeedDistance",
+            Self::Control => "Control",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::BloodPressure => "BloodPressure",
+            Self::GeocacheNode => "GeocacheNode",
+            Self::LightElectricVehicle => "LightElectricVehicle",
+            Self::EnvSensor => "EnvSensor",
+            Self::Racquet => "Racquet",
+            Self::ControlHub => "ControlHub",
+            Self::MuscleOxygen => "MuscleOxygen",
+            Self::Shifting => "Shifting",
+            Self::BikeLightMain => "BikeLightMain",
+            Self::BikeLightShared => "BikeLightShared",
+            Self::Exd => "Exd",
+            Self::BikeRadar => "BikeRadar",
+            Self::BikeAero => "BikeAero",
+            Self::WeightScale => "WeightScale",
+            Self::HeartRate => "HeartRate",
+            Self::BikeSpeedCadence => "BikeSpeedCadence",
+            Self::BikeCadence => "BikeCadence",
+            Self::BikeSpeed => "BikeSpeed",
+            Self::StrideSpeedDistance => "StrideSpeedDistance",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AntNetwork {
     Public,
     Antplus,
@@ -4648,6 +9845,43 @@ pub enum AntNetwork {
     Private,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AntplusDeviceType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AntplusDeviceType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Antfs" => Ok(Self::Antfs),
+            "BikePower" => Ok(Self::BikePower),
+            "EnvironmentSensorLegacy" => Ok(Self::EnvironmentSensorLegacy),
+            "MultiSportSpeedDistance" => Ok(Self::MultiSportSpeedDistance),
+            "Control" => Ok(Self::Control),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "BloodPressure" => Ok(Self::BloodPressure),
+            "GeocacheNode" => Ok(Self::GeocacheNode),
+            "LightElectricVehicle" => Ok(Self::LightElectricVehicle),
+            "EnvSensor" => Ok(Self::EnvSensor),
+            "Racquet" => Ok(Self::Racquet),
+            "ControlHub" => Ok(Self::ControlHub),
+            "MuscleOxygen" => Ok(Self::MuscleOxygen),
+            "Shifting" => Ok(Self::Shifting),
+            "BikeLightMain" => Ok(Self::BikeLightMain),
+            "BikeLightShared" => Ok(Self::BikeLightShared),
+            "Exd" => Ok(Self::Exd),
+            "BikeRadar" => Ok(Self::BikeRadar),
+            "BikeAero" => Ok(Self::BikeAero),
+            "WeightScale" => Ok(Self::WeightScale),
+            "HeartRate" => Ok(Self::HeartRate),
+            "BikeSpeedCadence" => Ok(Self::BikeSpeedCadence),
+            "BikeCadence" => Ok(Self::BikeCadence),
+            "BikeSpeed" => Ok(Self::BikeSpeed),
+            "StrideSpeedDistance" => Ok(Self::StrideSpeedDistance),
+            _ => Err(UnknownVariantError::new("AntplusDeviceType", name)),
+        }
+    }
+}
 impl AntNetwork {
     pub fn from(content: u8) -> AntNetwork {
         match content {
@@ -4664,17 +9898,40 @@ impl AntNetwork {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AntNetwork(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AntNetwork] {
+        &[
+            AntNetwork::Public,
+            AntNetwork::Antplus,
+            AntNetwork::Antfs,
+            AntNetwork::Private,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "Public",
+            Self::Antplus => "Antplus",
+            Self::Antfs => "Antfs",
+            Self::Private => "Private",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WorkoutCapabilities {
     Interval,
     Custom,
@@ -4692,6 +9949,22 @@ pub enum WorkoutCapabilities {
     Protected,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for AntNetwork {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AntNetwork::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Public" => Ok(Self::Public),
+            "Antplus" => Ok(Self::Antplus),
+            "Antfs" => Ok(Self::Antfs),
+            "Private" => Ok(Self::Private),
+            _ => Err(UnknownVariantError::new("AntNetwork", name)),
+        }
+    }
+}
 impl WorkoutCapabilities {
     pub fn from(content: u32) -> WorkoutCapabilities {
         match content {
@@ -4718,17 +9991,60 @@ impl WorkoutCapabilities {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WorkoutCapabilities(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WorkoutCapabilities] {
+        &[
+            WorkoutCapabilities::Interval,
+            WorkoutCapabilities::Custom,
+            WorkoutCapabilities::FitnessEquipment,
+            WorkoutCapabilities::Firstbeat,
+            WorkoutCapabilities::NewLeaf,
+            WorkoutCapabilities::Tcx,
+            WorkoutCapabilities::Speed,
+            WorkoutCapabilities::HeartRate,
+            WorkoutCapabilities::Distance,
+            WorkoutCapabilities::Cadence,
+            WorkoutCapabilities::Power,
+            WorkoutCapabilities::Grade,
+            WorkoutCapabilities::Resistance,
+            WorkoutCapabilities::Protected,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Interval => "Interval",
+            Self::Custom => "Custom",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::Firstbeat => "Firstbeat",
+            Self::NewLeaf => "NewLeaf",
+            Self::Tcx => "Tcx",
+            Self::Speed => "Speed",
+            Self::HeartRate => "HeartRate",
+            Self::Distance => "Distance",
+            Self::Cadence => "Cadence",
+            Self::Power => "Power",
+            Self::Grade => "Grade",
+            Self::Resistance => "Resistance",
+            Self::Protected => "Protected",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BatteryStatus {
     New,
     Good,
@@ -4739,6 +10055,32 @@ pub enum BatteryStatus {
     Unknown,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WorkoutCapabilities {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WorkoutCapabilities::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Interval" => Ok(Self::Interval),
+            "Custom" => Ok(Self::Custom),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "Firstbeat" => Ok(Self::Firstbeat),
+            "NewLeaf" => Ok(Self::NewLeaf),
+            "Tcx" => Ok(Self::Tcx),
+            "Speed" => Ok(Self::Speed),
+            "HeartRate" => Ok(Self::HeartRate),
+            "Distance" => Ok(Self::Distance),
+            "Cadence" => Ok(Self::Cadence),
+            "Power" => Ok(Self::Power),
+            "Grade" => Ok(Self::Grade),
+            "Resistance" => Ok(Self::Resistance),
+            "Protected" => Ok(Self::Protected),
+            _ => Err(UnknownVariantError::new("WorkoutCapabilities", name)),
+        }
+    }
+}
 impl BatteryStatus {
     pub fn from(content: u8) -> BatteryStatus {
         match content {
@@ -4758,22 +10100,70 @@ impl BatteryStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BatteryStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BatteryStatus] {
+        &[
+            BatteryStatus::New,
+            BatteryStatus::Good,
+            BatteryStatus::Ok,
+            BatteryStatus::Low,
+            BatteryStatus::Critical,
+            BatteryStatus::Charging,
+            BatteryStatus::Unknown,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::Good => "Good",
+            Self::Ok => "Ok",
+            Self::Low => "Low",
+            Self::Critical => "Critical",
+            Self::Charging => "Charging",
+            Self::Unknown => "Unknown",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum HrType {
     Normal,
     Irregular,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for BatteryStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BatteryStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "New" => Ok(Self::New),
+            "Good" => Ok(Self::Good),
+            "Ok" => Ok(Self::Ok),
+            "Low" => Ok(Self::Low),
+            "Critical" => Ok(Self::Critical),
+            "Charging" => Ok(Self::Charging),
+            "Unknown" => Ok(Self::Unknown),
+            _ => Err(UnknownVariantError::new("BatteryStatus", name)),
+        }
+    }
+}
 impl HrType {
     pub fn from(content: u8) -> HrType {
         match content {
@@ -4788,17 +10178,33 @@ impl HrType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::HrType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [HrType] {
+        &[HrType::Normal, HrType::Irregular]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Irregular => "Irregular",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum CourseCapabilities {
     Processed,
     Valid,
@@ -4814,6 +10220,20 @@ pub enum CourseCapabilities {
     Aviation,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for HrType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [HrType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Normal" => Ok(Self::Normal),
+            "Irregular" => Ok(Self::Irregular),
+            _ => Err(UnknownVariantError::new("HrType", name)),
+        }
+    }
+}
 impl CourseCapabilities {
     pub fn from(content: u32) -> CourseCapabilities {
         match content {
@@ -4838,21 +10258,84 @@ impl CourseCapabilities {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CourseCapabilities(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CourseCapabilities] {
+        &[
+            CourseCapabilities::Processed,
+            CourseCapabilities::Valid,
+            CourseCapabilities::Time,
+            CourseCapabilities::Distance,
+            CourseCapabilities::Position,
+            CourseCapabilities::HeartRate,
+            CourseCapabilities::Power,
+            CourseCapabilities::Cadence,
+            CourseCapabilities::Training,
+            CourseCapabilities::Navigation,
+            CourseCapabilities::Bikeway,
+            CourseCapabilities::Aviation,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "Processed",
+            Self::Valid => "Valid",
+            Self::Time => "Time",
+            Self::Distance => "Distance",
+            Self::Position => "Position",
+            Self::HeartRate => "HeartRate",
+            Self::Power => "Power",
+            Self::Cadence => "Cadence",
+            Self::Training => "Training",
+            Self::Navigation => "Navigation",
+            Self::Bikeway => "Bikeway",
+            Self::Aviation => "Aviation",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Weight {
     Calculating,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for CourseCapabilities {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CourseCapabilities::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Processed" => Ok(Self::Processed),
+            "Valid" => Ok(Self::Valid),
+            "Time" => Ok(Self::Time),
+            "Distance" => Ok(Self::Distance),
+            "Position" => Ok(Self::Position),
+            "HeartRate" => Ok(Self::HeartRate),
+            "Power" => Ok(Self::Power),
+            "Cadence" => Ok(Self::Cadence),
+            "Training" => Ok(Self::Training),
+            "Navigation" => Ok(Self::Navigation),
+            "Bikeway" => Ok(Self::Bikeway),
+            "Aviation" => Ok(Self::Aviation),
+            _ => Err(UnknownVariantError::new("CourseCapabilities", name)),
+        }
+    }
+}
 impl Weight {
     pub fn from(content: u16) -> Weight {
         match content {
@@ -4866,21 +10349,49 @@ impl Weight {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Weight(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Weight] {
+        &[Weight::Calculating]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Calculating => "Calculating",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WorkoutHr {
     BpmOffset,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for Weight {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Weight::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Calculating" => Ok(Self::Calculating),
+            _ => Err(UnknownVariantError::new("Weight", name)),
+        }
+    }
+}
 impl WorkoutHr {
     pub fn from(content: u32) -> WorkoutHr {
         match content {
@@ -4894,21 +10405,49 @@ impl WorkoutHr {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WorkoutHr(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WorkoutHr] {
+        &[WorkoutHr::BpmOffset]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BpmOffset => "BpmOffset",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WorkoutPower {
     WattsOffset,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for WorkoutHr {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WorkoutHr::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "BpmOffset" => Ok(Self::BpmOffset),
+            _ => Err(UnknownVariantError::new("WorkoutHr", name)),
+        }
+    }
+}
 impl WorkoutPower {
     pub fn from(content: u32) -> WorkoutPower {
         match content {
@@ -4922,17 +10461,32 @@ impl WorkoutPower {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WorkoutPower(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WorkoutPower] {
+        &[WorkoutPower::WattsOffset]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WattsOffset => "WattsOffset",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BpStatus {
     NoError,
     ErrorIncompleteData,
@@ -4941,6 +10495,19 @@ pub enum BpStatus {
     ErrorIrregularHeartRate,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WorkoutPower {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WorkoutPower::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "WattsOffset" => Ok(Self::WattsOffset),
+            _ => Err(UnknownVariantError::new("WorkoutPower", name)),
+        }
+    }
+}
 impl BpStatus {
     pub fn from(content: u8) -> BpStatus {
         match content {
@@ -4958,17 +10525,42 @@ impl BpStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BpStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BpStatus] {
+        &[
+            BpStatus::NoError,
+            BpStatus::ErrorIncompleteData,
+            BpStatus::ErrorNoMeasurement,
+            BpStatus::ErrorDataOutOfRange,
+            BpStatus::ErrorIrregularHeartRate,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoError => "NoError",
+            Self::ErrorIncompleteData => "ErrorIncompleteData",
+            Self::ErrorNoMeasurement => "ErrorNoMeasurement",
+            Self::ErrorDataOutOfRange => "ErrorDataOutOfRange",
+            Self::ErrorIrregularHeartRate => "ErrorIrregularHeartRate",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum UserLocalId {
     LocalMin,
     LocalMax,
@@ -4978,6 +10570,23 @@ pub enum UserLocalId {
     PortableMax,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for BpStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BpStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NoError" => Ok(Self::NoError),
+            "ErrorIncompleteData" => Ok(Self::ErrorIncompleteData),
+            "ErrorNoMeasurement" => Ok(Self::ErrorNoMeasurement),
+            "ErrorDataOutOfRange" => Ok(Self::ErrorDataOutOfRange),
+            "ErrorIrregularHeartRate" => Ok(Self::ErrorIrregularHeartRate),
+            _ => Err(UnknownVariantError::new("BpStatus", name)),
+        }
+    }
+}
 impl UserLocalId {
     pub fn from(content: u16) -> UserLocalId {
         match content {
@@ -4996,17 +10605,44 @@ impl UserLocalId {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::UserLocalId(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [UserLocalId] {
+        &[
+            UserLocalId::LocalMin,
+            UserLocalId::LocalMax,
+            UserLocalId::StationaryMin,
+            UserLocalId::StationaryMax,
+            UserLocalId::PortableMin,
+            UserLocalId::PortableMax,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LocalMin => "LocalMin",
+            Self::LocalMax => "LocalMax",
+            Self::StationaryMin => "StationaryMin",
+            Self::StationaryMax => "StationaryMax",
+            Self::PortableMin => "PortableMin",
+            Self::PortableMax => "PortableMax",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SwimStroke {
     Freestyle,
     Backstroke,
@@ -5019,6 +10655,24 @@ pub enum SwimStroke {
     Rimo,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for UserLocalId {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [UserLocalId::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "LocalMin" => Ok(Self::LocalMin),
+            "LocalMax" => Ok(Self::LocalMax),
+            "StationaryMin" => Ok(Self::StationaryMin),
+            "StationaryMax" => Ok(Self::StationaryMax),
+            "PortableMin" => Ok(Self::PortableMin),
+            "PortableMax" => Ok(Self::PortableMax),
+            _ => Err(UnknownVariantError::new("UserLocalId", name)),
+        }
+    }
+}
 impl SwimStroke {
     pub fn from(content: u8) -> SwimStroke {
         match content {
@@ -5040,17 +10694,50 @@ impl SwimStroke {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SwimStroke(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SwimStroke] {
+        &[
+            SwimStroke::Freestyle,
+            SwimStroke::Backstroke,
+            SwimStroke::Breaststroke,
+            SwimStroke::Butterfly,
+            SwimStroke::Drill,
+            SwimStroke::Mixed,
+            SwimStroke::Im,
+            SwimStroke::ImByRound,
+            SwimStroke::Rimo,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Freestyle => "Freestyle",
+            Self::Backstroke => "Backstroke",
+            Self::Breaststroke => "Breaststroke",
+            Self::Butterfly => "Butterfly",
+            Self::Drill => "Drill",
+            Self::Mixed => "Mixed",
+            Self::Im => "Im",
+            Self::ImByRound => "ImByRound",
+            Self::Rimo => "Rimo",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ActivityType {
     Generic,
     Running,
@@ -5063,6 +10750,27 @@ pub enum ActivityType {
     All,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SwimStroke {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SwimStroke::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Freestyle" => Ok(Self::Freestyle),
+            "Backstroke" => Ok(Self::Backstroke),
+            "Breaststroke" => Ok(Self::Breaststroke),
+            "Butterfly" => Ok(Self::Butterfly),
+            "Drill" => Ok(Self::Drill),
+            "Mixed" => Ok(Self::Mixed),
+            "Im" => Ok(Self::Im),
+            "ImByRound" => Ok(Self::ImByRound),
+            "Rimo" => Ok(Self::Rimo),
+            _ => Err(UnknownVariantError::new("SwimStroke", name)),
+        }
+    }
+}
 impl ActivityType {
     pub fn from(content: u8) -> ActivityType {
         match content {
@@ -5084,17 +10792,50 @@ impl ActivityType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ActivityType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ActivityType] {
+        &[
+            ActivityType::Generic,
+            ActivityType::Running,
+            ActivityType::Cycling,
+            ActivityType::Transition,
+            ActivityType::FitnessEquipment,
+            ActivityType::Swimming,
+            ActivityType::Walking,
+            ActivityType::Sedentary,
+            ActivityType::All,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Running => "Running",
+            Self::Cycling => "Cycling",
+            Self::Transition => "Transition",
+            Self::FitnessEquipment => "FitnessEquipment",
+            Self::Swimming => "Swimming",
+            Self::Walking => "Walking",
+            Self::Sedentary => "Sedentary",
+            Self::All => "All",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ActivitySubtype {
     Generic,
     Treadmill,
@@ -5118,6 +10859,27 @@ pub enum ActivitySubtype {
     All,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ActivityType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ActivityType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Running" => Ok(Self::Running),
+            "Cycling" => Ok(Self::Cycling),
+            "Transition" => Ok(Self::Transition),
+            "FitnessEquipment" => Ok(Self::FitnessEquipment),
+            "Swimming" => Ok(Self::Swimming),
+            "Walking" => Ok(Self::Walking),
+            "Sedentary" => Ok(Self::Sedentary),
+            "All" => Ok(Self::All),
+            _ => Err(UnknownVariantError::new("ActivityType", name)),
+        }
+    }
+}
 impl ActivitySubtype {
     pub fn from(content: u8) -> ActivitySubtype {
         match content {
@@ -5150,23 +10912,110 @@ impl ActivitySubtype {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ActivitySubtype(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ActivitySubtype] {
+        &[
+            ActivitySubtype::Generic,
+            ActivitySubtype::Treadmill,
+            ActivitySubtype::Street,
+            ActivitySubtype::Trail,
+            ActivitySubtype::Track,
+            ActivitySubtype::Spin,
+            ActivitySubtype::IndoorCycling,
+            ActivitySubtype::Road,
+            ActivitySubtype::Mountain,
+            ActivitySubtype::Downhill,
+            ActivitySubtype::Recumbent,
+            ActivitySubtype::Cyclocross,
+            ActivitySubtype::HandCycling,
+            ActivitySubtype::TrackCycling,
+            ActivitySubtype::IndoorRowing,
+            ActivitySubtype::Elliptical,
+            ActivitySubtype::StairClimbing,
+            ActivitySubtype::LapSwimming,
+            ActivitySubtype::OpenWater,
+            ActivitySubtype::All,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Treadmill => "Treadmill",
+            Self::Street => "Street",
+            Self::Trail => "Trail",
+            Self::Track => "Track",
+            Self::Spin => "Spin",
+            Self::IndoorCycling => "IndoorCycling",
+            Self::Road => "Road",
+            Self::Mountain => "Mountain",
+            Self::Downhill => "Downhill",
+            Self::Recumbent => "Recumbent",
+            Self::Cyclocross => "Cyclocross",
+            Self::HandCycling => "HandCycling",
+            Self::TrackCycling => "TrackCycling",
+            Self::IndoorRowing => "IndoorRowing",
+            Self::Elliptical => "Elliptical",
+            Self::StairClimbing => "StairClimbing",
+            Self::LapSwimming => "LapSwimming",
+            Self::OpenWater => "OpenWater",
+            Self::All => "All",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ActivityLevel {
     Low,
     Medium,
     High,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ActivitySubtype {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ActivitySubtype::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Treadmill" => Ok(Self::Treadmill),
+            "Street" => Ok(Self::Street),
+            "Trail" => Ok(Self::Trail),
+            "Track" => Ok(Self::Track),
+            "Spin" => Ok(Self::Spin),
+            "IndoorCycling" => Ok(Self::IndoorCycling),
+            "Road" => Ok(Self::Road),
+            "Mountain" => Ok(Self::Mountain),
+            "Downhill" => Ok(Self::Downhill),
+            "Recumbent" => Ok(Self::Recumbent),
+            "Cyclocross" => Ok(Self::Cyclocross),
+            "HandCycling" => Ok(Self::HandCycling),
+            "TrackCycling" => Ok(Self::TrackCycling),
+            "IndoorRowing" => Ok(Self::IndoorRowing),
+            "Elliptical" => Ok(Self::Elliptical),
+            "StairClimbing" => Ok(Self::StairClimbing),
+            "LapSwimming" => Ok(Self::LapSwimming),
+            "OpenWater" => Ok(Self::OpenWater),
+            "All" => Ok(Self::All),
+            _ => Err(UnknownVariantError::new("ActivitySubtype", name)),
+        }
+    }
+}
 impl ActivityLevel {
     pub fn from(content: u8) -> ActivityLevel {
         match content {
@@ -5182,22 +11031,58 @@ impl ActivityLevel {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ActivityLevel(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ActivityLevel] {
+        &[
+            ActivityLevel::Low,
+            ActivityLevel::Medium,
+            ActivityLevel::High,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Side {
     Right,
     Left,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ActivityLevel {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ActivityLevel::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Low" => Ok(Self::Low),
+            "Medium" => Ok(Self::Medium),
+            "High" => Ok(Self::High),
+            _ => Err(UnknownVariantError::new("ActivityLevel", name)),
+        }
+    }
+}
 impl Side {
     pub fn from(content: u8) -> Side {
         match content {
@@ -5212,22 +11097,52 @@ impl Side {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Side(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Side] {
+        &[Side::Right, Side::Left]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Right => "Right",
+            Self::Left => "Left",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LeftRightBalance {
     Mask,
     Right,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Side {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Side::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Right" => Ok(Self::Right),
+            "Left" => Ok(Self::Left),
+            _ => Err(UnknownVariantError::new("Side", name)),
+        }
+    }
+}
 impl LeftRightBalance {
     pub fn from(content: u8) -> LeftRightBalance {
         match content {
@@ -5242,22 +11157,52 @@ impl LeftRightBalance {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LeftRightBalance(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LeftRightBalance] {
+        &[LeftRightBalance::Mask, LeftRightBalance::Right]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mask => "Mask",
+            Self::Right => "Right",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LeftRightBalance100 {
     Mask,
     Right,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for LeftRightBalance {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LeftRightBalance::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Mask" => Ok(Self::Mask),
+            "Right" => Ok(Self::Right),
+            _ => Err(UnknownVariantError::new("LeftRightBalance", name)),
+        }
+    }
+}
 impl LeftRightBalance100 {
     pub fn from(content: u16) -> LeftRightBalance100 {
         match content {
@@ -5272,22 +11217,52 @@ impl LeftRightBalance100 {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LeftRightBalance100(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LeftRightBalance100] {
+        &[LeftRightBalance100::Mask, LeftRightBalance100::Right]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mask => "Mask",
+            Self::Right => "Right",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LengthType {
     Idle,
     Active,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for LeftRightBalance100 {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LeftRightBalance100::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Mask" => Ok(Self::Mask),
+            "Right" => Ok(Self::Right),
+            _ => Err(UnknownVariantError::new("LeftRightBalance100", name)),
+        }
+    }
+}
 impl LengthType {
     pub fn from(content: u8) -> LengthType {
         match content {
@@ -5302,17 +11277,33 @@ impl LengthType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LengthType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LengthType] {
+        &[LengthType::Idle, LengthType::Active]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::Active => "Active",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DayOfWeek {
     Sunday,
     Monday,
@@ -5323,6 +11314,20 @@ pub enum DayOfWeek {
     Saturday,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for LengthType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LengthType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Idle" => Ok(Self::Idle),
+            "Active" => Ok(Self::Active),
+            _ => Err(UnknownVariantError::new("LengthType", name)),
+        }
+    }
+}
 impl DayOfWeek {
     pub fn from(content: u8) -> DayOfWeek {
         match content {
@@ -5342,17 +11347,46 @@ impl DayOfWeek {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DayOfWeek(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DayOfWeek] {
+        &[
+            DayOfWeek::Sunday,
+            DayOfWeek::Monday,
+            DayOfWeek::Tuesday,
+            DayOfWeek::Wednesday,
+            DayOfWeek::Thursday,
+            DayOfWeek::Friday,
+            DayOfWeek::Saturday,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ConnectivityCapabilities {
     Bluetooth,
     BluetoothLe,
@@ -5388,6 +11422,25 @@ pub enum ConnectivityCapabilities {
     InstantInput,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for DayOfWeek {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DayOfWeek::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Sunday" => Ok(Self::Sunday),
+            "Monday" => Ok(Self::Monday),
+            "Tuesday" => Ok(Self::Tuesday),
+            "Wednesday" => Ok(Self::Wednesday),
+            "Thursday" => Ok(Self::Thursday),
+            "Friday" => Ok(Self::Friday),
+            "Saturday" => Ok(Self::Saturday),
+            _ => Err(UnknownVariantError::new("DayOfWeek", name)),
+        }
+    }
+}
 impl ConnectivityCapabilities {
     pub fn from(content: u32) -> ConnectivityCapabilities {
         match content {
@@ -5432,17 +11485,96 @@ impl ConnectivityCapabilities {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ConnectivityCapabilities(
                 Self::from(reader.next_u32(endianness)?),
             )));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ConnectivityCapabilities] {
+        &[
+            ConnectivityCapabilities::Bluetooth,
+            ConnectivityCapabilities::BluetoothLe,
+            ConnectivityCapabilities::Ant,
+            ConnectivityCapabilities::ActivityUpload,
+            ConnectivityCapabilities::CourseDownload,
+            ConnectivityCapabilities::WorkoutDownload,
+            ConnectivityCapabilities::LiveTrack,
+            ConnectivityCapabilities::WeatherConditions,
+            ConnectivityCapabilities::WeatherAlerts,
+            ConnectivityCapabilities::GpsEphemerisDownload,
+            ConnectivityCapabilities::ExplicitArchive,
+            ConnectivityCapabilities::SetupIncomplete,
+            ConnectivityCapabilities::ContinueSyncAfterSoftwareUpdate,
+            ConnectivityCapabilities::ConnectIqAppDownload,
+            ConnectivityCapabilities::GolfCourseDownload,
+            ConnectivityCapabilities::DeviceInitiatesSync,
+            ConnectivityCapabilities::ConnectIqWatchAppDownload,
+            ConnectivityCapabilities::ConnectIqWidgetDownload,
+            ConnectivityCapabilities::ConnectIqWatchFaceDownload,
+            ConnectivityCapabilities::ConnectIqDataFieldDownload,
+            ConnectivityCapabilities::ConnectIqAppManagment,
+            ConnectivityCapabilities::SwingSensor,
+            ConnectivityCapabilities::SwingSensorRemote,
+            ConnectivityCapabilities::IncidentDetection,
+            ConnectivityCapabilities::AudioPrompts,
+            ConnectivityCapabilities::WifiVerification,
+            ConnectivityCapabilities::TrueUp,
+            ConnectivityCapabilities::FindMyWatch,
+            ConnectivityCapabilities::RemoteManualSync,
+            ConnectivityCapabilities::LiveTrackAutoStart,
+            ConnectivityCapabilities::LiveTrackMessaging,
+            ConnectivityCapabilities::InstantInput,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bluetooth => "Bluetooth",
+            Self::BluetoothLe => "BluetoothLe",
+            Self::Ant => "Ant",
+            Self::ActivityUpload => "ActivityUpload",
+            Self::CourseDownload => "CourseDownload",
+            Self::WorkoutDownload => "WorkoutDownload",
+            Self::LiveTrack => "LiveTrack",
+            Self::WeatherConditions => "WeatherConditions",
+            Self::WeatherAlerts => "WeatherAlerts",
+            Self::GpsEphemerisDownload => "GpsEphemerisDownload",
+            Self::ExplicitArchive => "ExplicitArchive",
+            Self::SetupIncomplete => "SetupIncomplete",
+            Self::ContinueSyncAfterSoftwareUpdate => "ContinueSyncAfterSoftwareUpdate",
+            Self::ConnectIqAppDownload => "ConnectIqAppDownload",
+            Self::GolfCourseDownload => "GolfCourseDownload",
+            Self::DeviceInitiatesSync => "DeviceInitiatesSync",
+            Self::ConnectIqWatchAppDownload => "ConnectIqWatchAppDownload",
+            Self::ConnectIqWidgetDownload => "ConnectIqWidgetDownload",
+            Self::ConnectIqWatchFaceDownload => "ConnectIqWatchFaceDownload",
+            Self::ConnectIqDataFieldDownload => "ConnectIqDataFieldDownload",
+            Self::ConnectIqAppManagment => "ConnectIqAppManagment",
+            Self::SwingSensor => "SwingSensor",
+            Self::SwingSensorRemote => "SwingSensorRemote",
+            Self::IncidentDetection => "IncidentDetection",
+            Self::AudioPrompts => "AudioPrompts",
+            Self::WifiVerification => "WifiVerification",
+            Self::TrueUp => "TrueUp",
+            Self::FindMyWatch => "FindMyWatch",
+            Self::RemoteManualSync => "RemoteManualSync",
+            Self::LiveTrackAutoStart => "LiveTrackAutoStart",
+            Self::LiveTrackMessaging => "LiveTrackMessaging",
+            Self::InstantInput => "InstantInput",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WeatherReport {
     Current,
     Forecast,
@@ -5450,7 +11582,51 @@ pub enum WeatherReport {
     DailyForecast,
     UnknownVariant(u8),
 }
-impl WeatherReport {
+
+impl std::str::FromStr for ConnectivityCapabilities {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ConnectivityCapabilities::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Bluetooth" => Ok(Self::Bluetooth),
+            "BluetoothLe" => Ok(Self::BluetoothLe),
+            "Ant" => Ok(Self::Ant),
+            "ActivityUpload" => Ok(Self::ActivityUpload),
+            "CourseDownload" => Ok(Self::CourseDownload),
+            "WorkoutDownload" => Ok(Self::WorkoutDownload),
+            "LiveTrack" => Ok(Self::LiveTrack),
+            "WeatherConditions" => Ok(Self::WeatherConditions),
+            "WeatherAlerts" => Ok(Self::WeatherAlerts),
+            "GpsEphemerisDownload" => Ok(Self::GpsEphemerisDownload),
+            "ExplicitArchive" => Ok(Self::ExplicitArchive),
+            "SetupIncomplete" => Ok(Self::SetupIncomplete),
+            "ContinueSyncAfterSoftwareUpdate" => Ok(Self::ContinueSyncAfterSoftwareUpdate),
+            "ConnectIqAppDownload" => Ok(Self::ConnectIqAppDownload),
+            "GolfCourseDownload" => Ok(Self::GolfCourseDownload),
+            "DeviceInitiatesSync" => Ok(Self::DeviceInitiatesSync),
+            "ConnectIqWatchAppDownload" => Ok(Self::ConnectIqWatchAppDownload),
+            "ConnectIqWidgetDownload" => Ok(Self::ConnectIqWidgetDownload),
+            "ConnectIqWatchFaceDownload" => Ok(Self::ConnectIqWatchFaceDownload),
+            "ConnectIqDataFieldDownload" => Ok(Self::ConnectIqDataFieldDownload),
+            "ConnectIqAppManagment" => Ok(Self::ConnectIqAppManagment),
+            "SwingSensor" => Ok(Self::SwingSensor),
+            "SwingSensorRemote" => Ok(Self::SwingSensorRemote),
+            "IncidentDetection" => Ok(Self::IncidentDetection),
+            "AudioPrompts" => Ok(Self::AudioPrompts),
+            "WifiVerification" => Ok(Self::WifiVerification),
+            "TrueUp" => Ok(Self::TrueUp),
+            "FindMyWatch" => Ok(Self::FindMyWatch),
+            "RemoteManualSync" => Ok(Self::RemoteManualSync),
+            "LiveTrackAutoStart" => Ok(Self::LiveTrackAutoStart),
+            "LiveTrackMessaging" => Ok(Self::LiveTrackMessaging),
+            "InstantInput" => Ok(Self::InstantInput),
+            _ => Err(UnknownVariantError::new("ConnectivityCapabilities", name)),
+        }
+    }
+}
+impl WeatherReport {
     pub fn from(content: u8) -> WeatherReport {
         match content {
             0 => WeatherReport::Current,
@@ -5466,17 +11642,40 @@ impl WeatherReport {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WeatherReport(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WeatherReport] {
+        &[
+            WeatherReport::Current,
+            WeatherReport::Forecast,
+            WeatherReport::HourlyForecast,
+            WeatherReport::DailyForecast,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Current => "Current",
+            Self::Forecast => "Forecast",
+            Self::HourlyForecast => "HourlyForecast",
+            Self::DailyForecast => "DailyForecast",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WeatherStatus {
     Clear,
     PartlyCloudy,
@@ -5501,6 +11700,22 @@ pub enum WeatherStatus {
     Cloudy,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WeatherReport {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WeatherReport::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Current" => Ok(Self::Current),
+            "Forecast" => Ok(Self::Forecast),
+            "HourlyForecast" => Ok(Self::HourlyForecast),
+            "DailyForecast" => Ok(Self::DailyForecast),
+            _ => Err(UnknownVariantError::new("WeatherReport", name)),
+        }
+    }
+}
 impl WeatherStatus {
     pub fn from(content: u8) -> WeatherStatus {
         match content {
@@ -5534,17 +11749,74 @@ impl WeatherStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WeatherStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WeatherStatus] {
+        &[
+            WeatherStatus::Clear,
+            WeatherStatus::PartlyCloudy,
+            WeatherStatus::MostlyCloudy,
+            WeatherStatus::Rain,
+            WeatherStatus::Snow,
+            WeatherStatus::Windy,
+            WeatherStatus::Thunderstorms,
+            WeatherStatus::WintryMix,
+            WeatherStatus::Fog,
+            WeatherStatus::Hazy,
+            WeatherStatus::Hail,
+            WeatherStatus::ScatteredShowers,
+            WeatherStatus::ScatteredThunderstorms,
+            WeatherStatus::UnknownPrecipitation,
+            WeatherStatus::LightRain,
+            WeatherStatus::HeavyRain,
+            WeatherStatus::LightSnow,
+            WeatherStatus::HeavySnow,
+            WeatherStatus::LightRainSnow,
+            WeatherStatus::HeavyRainSnow,
+            WeatherStatus::Cloudy,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Clear => "Clear",
+            Self::PartlyCloudy => "PartlyCloudy",
+            Self::MostlyCloudy => "MostlyCloudy",
+            Self::Rain => "Rain",
+            Self::Snow => "Snow",
+            Self::Windy => "Windy",
+            Self::Thunderstorms => "Thunderstorms",
+            Self::WintryMix => "WintryMix",
+            Self::Fog => "Fog",
+            Self::Hazy => "Hazy",
+            Self::Hail => "Hail",
+            Self::ScatteredShowers => "ScatteredShowers",
+            Self::ScatteredThunderstorms => "ScatteredThunderstorms",
+            Self::UnknownPrecipitation => "UnknownPrecipitation",
+            Self::LightRain => "LightRain",
+            Self::HeavyRain => "HeavyRain",
+            Self::LightSnow => "LightSnow",
+            Self::HeavySnow => "HeavySnow",
+            Self::LightRainSnow => "LightRainSnow",
+            Self::HeavyRainSnow => "HeavyRainSnow",
+            Self::Cloudy => "Cloudy",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WeatherSeverity {
     Unknown,
     Warning,
@@ -5553,6 +11825,39 @@ pub enum WeatherSeverity {
     Statement,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WeatherStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WeatherStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Clear" => Ok(Self::Clear),
+            "PartlyCloudy" => Ok(Self::PartlyCloudy),
+            "MostlyCloudy" => Ok(Self::MostlyCloudy),
+            "Rain" => Ok(Self::Rain),
+            "Snow" => Ok(Self::Snow),
+            "Windy" => Ok(Self::Windy),
+            "Thunderstorms" => Ok(Self::Thunderstorms),
+            "WintryMix" => Ok(Self::WintryMix),
+            "Fog" => Ok(Self::Fog),
+            "Hazy" => Ok(Self::Hazy),
+            "Hail" => Ok(Self::Hail),
+            "ScatteredShowers" => Ok(Self::ScatteredShowers),
+            "ScatteredThunderstorms" => Ok(Self::ScatteredThunderstorms),
+            "UnknownPrecipitation" => Ok(Self::UnknownPrecipitation),
+            "LightRain" => Ok(Self::LightRain),
+            "HeavyRain" => Ok(Self::HeavyRain),
+            "LightSnow" => Ok(Self::LightSnow),
+            "HeavySnow" => Ok(Self::HeavySnow),
+            "LightRainSnow" => Ok(Self::LightRainSnow),
+            "HeavyRainSnow" => Ok(Self::HeavyRainSnow),
+            "Cloudy" => Ok(Self::Cloudy),
+            _ => Err(UnknownVariantError::new("WeatherStatus", name)),
+        }
+    }
+}
 impl WeatherSeverity {
     pub fn from(content: u8) -> WeatherSeverity {
         match content {
@@ -5570,17 +11875,42 @@ impl WeatherSeverity {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WeatherSeverity(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WeatherSeverity] {
+        &[
+            WeatherSeverity::Unknown,
+            WeatherSeverity::Warning,
+            WeatherSeverity::Watch,
+            WeatherSeverity::Advisory,
+            WeatherSeverity::Statement,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Warning => "Warning",
+            Self::Watch => "Watch",
+            Self::Advisory => "Advisory",
+            Self::Statement => "Statement",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WeatherSevereType {
     Unspecified,
     Tornado,
@@ -5669,6 +11999,23 @@ pub enum WeatherSevereType {
     SpecialWeather,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WeatherSeverity {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WeatherSeverity::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Unknown" => Ok(Self::Unknown),
+            "Warning" => Ok(Self::Warning),
+            "Watch" => Ok(Self::Watch),
+            "Advisory" => Ok(Self::Advisory),
+            "Statement" => Ok(Self::Statement),
+            _ => Err(UnknownVariantError::new("WeatherSeverity", name)),
+        }
+    }
+}
 impl WeatherSevereType {
     pub fn from(content: u8) -> WeatherSevereType {
         match content {
@@ -5766,20 +12113,302 @@ impl WeatherSevereType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WeatherSevereType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WeatherSevereType] {
+        &[
+            WeatherSevereType::Unspecified,
+            WeatherSevereType::Tornado,
+            WeatherSevereType::Tsunami,
+            WeatherSevereType::Hurricane,
+            WeatherSevereType::ExtremeWind,
+            WeatherSevereType::Typhoon,
+            WeatherSevereType::InlandHurricane,
+            WeatherSevereType::HurricaneForceWind,
+            WeatherSevereType::Waterspout,
+            WeatherSevereType::SevereThunderstorm,
+            WeatherSevereType::WreckhouseWinds,
+            WeatherSevereType::LesSuetesWind,
+            WeatherSevereType::Avalanche,
+            WeatherSevereType::FlashFlood,
+            WeatherSevereType::TropicalStorm,
+            WeatherSevereType::InlandTropicalStorm,
+            WeatherSevereType::Blizzard,
+            WeatherSevereType::IceStorm,
+            WeatherSevereType::FreezingRain,
+            WeatherSevereType::DebrisFlow,
+            WeatherSevereType::FlashFreeze,
+            WeatherSevereType::DustStorm,
+            WeatherSevereType::HighWind,
+            WeatherSevereType::WinterStorm,
+            WeatherSevereType::HeavyFreezingSpray,
+            WeatherSevereType::ExtremeCold,
+            WeatherSevereType::WindChill,
+            WeatherSevereType::ColdWave,
+            WeatherSevereType::HeavySnowAlert,
+            WeatherSevereType::LakeEffectBlowingSnow,
+            WeatherSevereType::SnowSquall,
+            WeatherSevereType::LakeEffectSnow,
+            WeatherSevereType::WinterWeather,
+            WeatherSevereType::Sleet,
+            WeatherSevereType::Snowfall,
+            WeatherSevereType::SnowAndBlowingSnow,
+            WeatherSevereType::BlowingSnow,
+            WeatherSevereType::SnowAlert,
+            WeatherSevereType::ArcticOutflow,
+            WeatherSevereType::FreezingDrizzle,
+            WeatherSevereType::Storm,
+            WeatherSevereType::StormSurge,
+            WeatherSevereType::Rainfall,
+            WeatherSevereType::ArealFlood,
+            WeatherSevereType::CoastalFlood,
+            WeatherSevereType::LakeshoreFlood,
+            WeatherSevereType::ExcessiveHeat,
+            WeatherSevereType::Heat,
+            WeatherSevereType::Weather,
+            WeatherSevereType::HighHeatAndHumidity,
+            WeatherSevereType::HumidexAndHealth,
+            WeatherSevereType::Humidex,
+            WeatherSevereType::Gale,
+            WeatherSevereType::FreezingSpray,
+            WeatherSevereType::SpecialMarine,
+            WeatherSevereType::Squall,
+            WeatherSevereType::StrongWind,
+            WeatherSevereType::LakeWind,
+            WeatherSevereType::MarineWeather,
+            WeatherSevereType::Wind,
+            WeatherSevereType::SmallCraftHazardousSeas,
+            WeatherSevereType::HazardousSeas,
+            WeatherSevereType::SmallCraft,
+            WeatherSevereType::SmallCraftWinds,
+            WeatherSevereType::SmallCraftRoughBar,
+            WeatherSevereType::HighWaterLevel,
+            WeatherSevereType::Ashfall,
+            WeatherSevereType::FreezingFog,
+            WeatherSevereType::DenseFog,
+            WeatherSevereType::DenseSmoke,
+            WeatherSevereType::BlowingDust,
+            WeatherSevereType::HardFreeze,
+            WeatherSevereType::Freeze,
+            WeatherSevereType::Frost,
+            WeatherSevereType::FireWeather,
+            WeatherSevereType::Flood,
+            WeatherSevereType::RipTide,
+            WeatherSevereType::HighSurf,
+            WeatherSevereType::Smog,
+            WeatherSevereType::AirQuality,
+            WeatherSevereType::BriskWind,
+            WeatherSevereType::AirStagnation,
+            WeatherSevereType::LowWater,
+            WeatherSevereType::Hydrological,
+            WeatherSevereType::SpecialWeather,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "Unspecified",
+            Self::Tornado => "Tornado",
+            Self::Tsunami => "Tsunami",
+            Self::Hurricane => "Hurricane",
+            Self::ExtremeWind => "ExtremeWind",
+            Self::Typhoon => "Typhoon",
+            Self::InlandHurricane => "InlandHurricane",
+            Self::HurricaneForceWind => "HurricaneForceWind",
+            Self::Waterspout => "Waterspout",
+            Self::SevereThunderstorm => "SevereThunderstorm",
+            Self::WreckhouseWinds => "WreckhouseWinds",
+            Self::LesSuetesWind => "LesSuetesWind",
+            Self::Avalanche => "Avalanche",
+            Self::FlashFlood => "FlashFlood",
+            Self::TropicalStorm => "TropicalStorm",
+            Self::InlandTropicalStorm => "InlandTropicalStorm",
+            Self::Blizzard => "Blizzard",
+            Self::IceStorm => "IceStorm",
+            Self::FreezingRain => "FreezingRain",
+            Self::DebrisFlow => "DebrisFlow",
+            Self::FlashFreeze => "FlashFreeze",
+            Self::DustStorm => "DustStorm",
+            Self::HighWind => "HighWind",
+            Self::WinterStorm => "WinterStorm",
+            Self::HeavyFreezingSpray => "HeavyFreezingSpray",
+            Self::ExtremeCold => "ExtremeCold",
+            Self::WindChill => "WindChill",
+            Self::ColdWave => "ColdWave",
+            Self::HeavySnowAlert => "HeavySnowAlert",
+            Self::LakeEffectBlowingSnow => "LakeEffectBlowingSnow",
+            Self::SnowSquall => "SnowSquall",
+            Self::LakeEffectSnow => "LakeEffectSnow",
+            Self::WinterWeather => "WinterWeather",
+            Self::Sleet => "Sleet",
+            Self::Snowfall => "Snowfall",
+            Self::SnowAndBlowingSnow => "SnowAndBlowingSnow",
+            Self::BlowingSnow => "BlowingSnow",
+            Self::SnowAlert => "SnowAlert",
+            Self::ArcticOutflow => "ArcticOutflow",
+            Self::FreezingDrizzle => "FreezingDrizzle",
+            Self::Storm => "Storm",
+            Self::StormSurge => "StormSurge",
+            Self::Rainfall => "Rainfall",
+            Self::ArealFlood => "ArealFlood",
+            Self::CoastalFlood => "CoastalFlood",
+            Self::LakeshoreFlood => "LakeshoreFlood",
+            Self::ExcessiveHeat => "ExcessiveHeat",
+            Self::Heat => "Heat",
+            Self::Weather => "Weather",
+            Self::HighHeatAndHumidity => "HighHeatAndHumidity",
+            Self::HumidexAndHealth => "HumidexAndHealth",
+            Self::Humidex => "Humidex",
+            Self::Gale => "Gale",
+            Self::FreezingSpray => "FreezingSpray",
+            Self::SpecialMarine => "SpecialMarine",
+            Self::Squall => "Squall",
+            Self::StrongWind => "StrongWind",
+            Self::LakeWind => "LakeWind",
+            Self::MarineWeather => "MarineWeather",
+            Self::Wind => "Wind",
+            Self::SmallCraftHazardousSeas => "SmallCraftHazardousSeas",
+            Self::HazardousSeas => "HazardousSeas",
+            Self::SmallCraft => "SmallCraft",
+            Self::SmallCraftWinds => "SmallCraftWinds",
+            Self::SmallCraftRoughBar => "SmallCraftRoughBar",
+            Self::HighWaterLevel => "HighWaterLevel",
+            Self::Ashfall => "Ashfall",
+            Self::FreezingFog => "FreezingFog",
+            Self::DenseFog => "DenseFog",
+            Self::DenseSmoke => "DenseSmoke",
+            Self::BlowingDust => "BlowingDust",
+            Self::HardFreeze => "HardFreeze",
+            Self::Freeze => "Freeze",
+            Self::Frost => "Frost",
+            Self::FireWeather => "FireWeather",
+            Self::Flood => "Flood",
+            Self::RipTide => "RipTide",
+            Self::HighSurf => "HighSurf",
+            Self::Smog => "Smog",
+            Self::AirQuality => "AirQuality",
+            Self::BriskWind => "BriskWind",
+            Self::AirStagnation => "AirStagnation",
+            Self::LowWater => "LowWater",
+            Self::Hydrological => "Hydrological",
+            Self::SpecialWeather => "SpecialWeather",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LocaltimeIntoDay {
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for WeatherSevereType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WeatherSevereType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Unspecified" => Ok(Self::Unspecified),
+            "Tornado" => Ok(Self::Tornado),
+            "Tsunami" => Ok(Self::Tsunami),
+            "Hurricane" => Ok(Self::Hurricane),
+            "ExtremeWind" => Ok(Self::ExtremeWind),
+            "Typhoon" => Ok(Self::Typhoon),
+            "InlandHurricane" => Ok(Self::InlandHurricane),
+            "HurricaneForceWind" => Ok(Self::HurricaneForceWind),
+            "Waterspout" => Ok(Self::Waterspout),
+            "SevereThunderstorm" => Ok(Self::SevereThunderstorm),
+            "WreckhouseWinds" => Ok(Self::WreckhouseWinds),
+            "LesSuetesWind" => Ok(Self::LesSuetesWind),
+            "Avalanche" => Ok(Self::Avalanche),
+            "FlashFlood" => Ok(Self::FlashFlood),
+            "TropicalStorm" => Ok(Self::TropicalStorm),
+            "InlandTropicalStorm" => Ok(Self::InlandTropicalStorm),
+            "Blizzard" => Ok(Self::Blizzard),
+            "IceStorm" => Ok(Self::IceStorm),
+            "FreezingRain" => Ok(Self::FreezingRain),
+            "DebrisFlow" => Ok(Self::DebrisFlow),
+            "FlashFreeze" => Ok(Self::FlashFreeze),
+            "DustStorm" => Ok(Self::DustStorm),
+            "HighWind" => Ok(Self::HighWind),
+            "WinterStorm" => Ok(Self::WinterStorm),
+            "HeavyFreezingSpray" => Ok(Self::HeavyFreezingSpray),
+            "ExtremeCold" => Ok(Self::ExtremeCold),
+            "WindChill" => Ok(Self::WindChill),
+            "ColdWave" => Ok(Self::ColdWave),
+            "HeavySnowAlert" => Ok(Self::HeavySnowAlert),
+            "LakeEffectBlowingSnow" => Ok(Self::LakeEffectBlowingSnow),
+            "SnowSquall" => Ok(Self::SnowSquall),
+            "LakeEffectSnow" => Ok(Self::LakeEffectSnow),
+            "WinterWeather" => Ok(Self::WinterWeather),
+            "Sleet" => Ok(Self::Sleet),
+            "Snowfall" => Ok(Self::Snowfall),
+            "SnowAndBlowingSnow" => Ok(Self::SnowAndBlowingSnow),
+            "BlowingSnow" => Ok(Self::BlowingSnow),
+            "SnowAlert" => Ok(Self::SnowAlert),
+            "ArcticOutflow" => Ok(Self::ArcticOutflow),
+            "FreezingDrizzle" => Ok(Self::FreezingDrizzle),
+            "Storm" => Ok(Self::Storm),
+            "StormSurge" => Ok(Self::StormSurge),
+            "Rainfall" => Ok(Self::Rainfall),
+            "ArealFlood" => Ok(Self::ArealFlood),
+            "CoastalFlood" => Ok(Self::CoastalFlood),
+            "LakeshoreFlood" => Ok(Self::LakeshoreFlood),
+            "ExcessiveHeat" => Ok(Self::ExcessiveHeat),
+            "Heat" => Ok(Self::Heat),
+            "Weather" => Ok(Self::Weather),
+            "HighHeatAndHumidity" => Ok(Self::HighHeatAndHumidity),
+            "HumidexAndHealth" => Ok(Self::HumidexAndHealth),
+            "Humidex" => Ok(Self::Humidex),
+            "Gale" => Ok(Self::Gale),
+            "FreezingSpray" => Ok(Self::FreezingSpray),
+            "SpecialMarine" => Ok(Self::SpecialMarine),
+            "Squall" => Ok(Self::Squall),
+            "StrongWind" => Ok(Self::StrongWind),
+            "LakeWind" => Ok(Self::LakeWind),
+            "MarineWeather" => Ok(Self::MarineWeather),
+            "Wind" => Ok(Self::Wind),
+            "SmallCraftHazardousSeas" => Ok(Self::SmallCraftHazardousSeas),
+            "HazardousSeas" => Ok(Self::HazardousSeas),
+            "SmallCraft" => Ok(Self::SmallCraft),
+            "SmallCraftWinds" => Ok(Self::SmallCraftWinds),
+            "SmallCraftRoughBar" => Ok(Self::SmallCraftRoughBar),
+            "HighWaterLevel" => Ok(Self::HighWaterLevel),
+            "Ashfall" => Ok(Self::Ashfall),
+            "FreezingFog" => Ok(Self::FreezingFog),
+            "DenseFog" => Ok(Self::DenseFog),
+            "DenseSmoke" => Ok(Self::DenseSmoke),
+            "BlowingDust" => Ok(Self::BlowingDust),
+            "HardFreeze" => Ok(Self::HardFreeze),
+            "Freeze" => Ok(Self::Freeze),
+            "Frost" => Ok(Self::Frost),
+            "FireWeather" => Ok(Self::FireWeather),
+            "Flood" => Ok(Self::Flood),
+            "RipTide" => Ok(Self::RipTide),
+            "HighSurf" => Ok(Self::HighSurf),
+            "Smog" => Ok(Self::Smog),
+            "AirQuality" => Ok(Self::AirQuality),
+            "BriskWind" => Ok(Self::BriskWind),
+            "AirStagnation" => Ok(Self::AirStagnation),
+            "LowWater" => Ok(Self::LowWater),
+            "Hydrological" => Ok(Self::Hydrological),
+            "SpecialWeather" => Ok(Self::SpecialWeather),
+            _ => Err(UnknownVariantError::new("WeatherSevereType", name)),
+        }
+    }
+}
 impl LocaltimeIntoDay {
     pub fn from(content: u32) -> LocaltimeIntoDay {
         match content {
@@ -5792,17 +12421,31 @@ impl LocaltimeIntoDay {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LocaltimeIntoDay(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LocaltimeIntoDay] {
+        &[]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum StrokeType {
     NoEvent,
     Other,
@@ -5812,6 +12455,18 @@ pub enum StrokeType {
     Smash,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for LocaltimeIntoDay {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LocaltimeIntoDay::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            _ => Err(UnknownVariantError::new("LocaltimeIntoDay", name)),
+        }
+    }
+}
 impl StrokeType {
     pub fn from(content: u8) -> StrokeType {
         match content {
@@ -5830,17 +12485,44 @@ impl StrokeType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::StrokeType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [StrokeType] {
+        &[
+            StrokeType::NoEvent,
+            StrokeType::Other,
+            StrokeType::Serve,
+            StrokeType::Forehand,
+            StrokeType::Backhand,
+            StrokeType::Smash,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoEvent => "NoEvent",
+            Self::Other => "Other",
+            Self::Serve => "Serve",
+            Self::Forehand => "Forehand",
+            Self::Backhand => "Backhand",
+            Self::Smash => "Smash",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BodyLocation {
     LeftLeg,
     LeftCalf,
@@ -5884,6 +12566,24 @@ pub enum BodyLocation {
     WaistRight,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for StrokeType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [StrokeType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NoEvent" => Ok(Self::NoEvent),
+            "Other" => Ok(Self::Other),
+            "Serve" => Ok(Self::Serve),
+            "Forehand" => Ok(Self::Forehand),
+            "Backhand" => Ok(Self::Backhand),
+            "Smash" => Ok(Self::Smash),
+            _ => Err(UnknownVariantError::new("StrokeType", name)),
+        }
+    }
+}
 impl BodyLocation {
     pub fn from(content: u8) -> BodyLocation {
         match content {
@@ -5936,22 +12636,169 @@ impl BodyLocation {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BodyLocation(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BodyLocation] {
+        &[
+            BodyLocation::LeftLeg,
+            BodyLocation::LeftCalf,
+            BodyLocation::LeftShin,
+            BodyLocation::LeftHamstring,
+            BodyLocation::LeftQuad,
+            BodyLocation::LeftGlute,
+            BodyLocation::RightLeg,
+            BodyLocation::RightCalf,
+            BodyLocation::RightShin,
+            BodyLocation::RightHamstring,
+            BodyLocation::RightQuad,
+            BodyLocation::RightGlute,
+            BodyLocation::TorsoBack,
+            BodyLocation::LeftLowerBack,
+            BodyLocation::LeftUpperBack,
+            BodyLocation::RightLowerBack,
+            BodyLocation::RightUpperBack,
+            BodyLocation::TorsoFront,
+            BodyLocation::LeftAbdomen,
+            BodyLocation::LeftChest,
+            BodyLocation::RightAbdomen,
+            BodyLocation::RightChest,
+            BodyLocation::LeftArm,
+            BodyLocation::LeftShoulder,
+            BodyLocation::LeftBicep,
+            BodyLocation::LeftTricep,
+            BodyLocation::LeftBrachioradialis,
+            BodyLocation::LeftForearmExtensors,
+            BodyLocation::RightArm,
+            BodyLocation::RightShoulder,
+            BodyLocation::RightBicep,
+            BodyLocation::RightTricep,
+            BodyLocation::RightBrachioradialis,
+            BodyLocation::RightForearmExtensors,
+            BodyLocation::Neck,
+            BodyLocation::Throat,
+            BodyLocation::WaistMidBack,
+            BodyLocation::WaistFront,
+            BodyLocation::WaistLeft,
+            BodyLocation::WaistRight,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LeftLeg => "LeftLeg",
+            Self::LeftCalf => "LeftCalf",
+            Self::LeftShin => "LeftShin",
+            Self::LeftHamstring => "LeftHamstring",
+            Self::LeftQuad => "LeftQuad",
+            Self::LeftGlute => "LeftGlute",
+            Self::RightLeg => "RightLeg",
+            Self::RightCalf => "RightCalf",
+            Self::RightShin => "RightShin",
+            Self::RightHamstring => "RightHamstring",
+            Self::RightQuad => "RightQuad",
+            Self::RightGlute => "RightGlute",
+            Self::TorsoBack => "TorsoBack",
+            Self::LeftLowerBack => "LeftLowerBack",
+            Self::LeftUpperBack => "LeftUpperBack",
+            Self::RightLowerBack => "RightLowerBack",
+            Self::RightUpperBack => "RightUpperBack",
+            Self::TorsoFront => "TorsoFront",
+            Self::LeftAbdomen => "LeftAbdomen",
+            Self::LeftChest => "LeftChest",
+            Self::RightAbdomen => "RightAbdomen",
+            Self::RightChest => "RightChest",
+            Self::LeftArm => "LeftArm",
+            Self::LeftShoulder => "LeftShoulder",
+            Self::LeftBicep => "LeftBicep",
+            Self::LeftTricep => "LeftTricep",
+            Self::LeftBrachioradialis => "LeftBrachioradialis",
+            Self::LeftForearmExtensors => "LeftForearmExtensors",
+            Self::RightArm => "RightArm",
+            Self::RightShoulder => "RightShoulder",
+            Self::RightBicep => "RightBicep",
+            Self::RightTricep => "RightTricep",
+            Self::RightBrachioradialis => "RightBrachioradialis",
+            Self::RightForearmExtensors => "RightForearmExtensors",
+            Self::Neck => "Neck",
+            Self::Throat => "Throat",
+            Self::WaistMidBack => "WaistMidBack",
+            Self::WaistFront => "WaistFront",
+            Self::WaistLeft => "WaistLeft",
+            Self::WaistRight => "WaistRight",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SegmentLapStatus {
     End,
     Fail,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for BodyLocation {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BodyLocation::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "LeftLeg" => Ok(Self::LeftLeg),
+            "LeftCalf" => Ok(Self::LeftCalf),
+            "LeftShin" => Ok(Self::LeftShin),
+            "LeftHamstring" => Ok(Self::LeftHamstring),
+            "LeftQuad" => Ok(Self::LeftQuad),
+            "LeftGlute" => Ok(Self::LeftGlute),
+            "RightLeg" => Ok(Self::RightLeg),
+            "RightCalf" => Ok(Self::RightCalf),
+            "RightShin" => Ok(Self::RightShin),
+            "RightHamstring" => Ok(Self::RightHamstring),
+            "RightQuad" => Ok(Self::RightQuad),
+            "RightGlute" => Ok(Self::RightGlute),
+            "TorsoBack" => Ok(Self::TorsoBack),
+            "LeftLowerBack" => Ok(Self::LeftLowerBack),
+            "LeftUpperBack" => Ok(Self::LeftUpperBack),
+            "RightLowerBack" => Ok(Self::RightLowerBack),
+            "RightUpperBack" => Ok(Self::RightUpperBack),
+            "TorsoFront" => Ok(Self::TorsoFront),
+            "LeftAbdomen" => Ok(Self::LeftAbdomen),
+            "LeftChest" => Ok(Self::LeftChest),
+            "RightAbdomen" => Ok(Self::RightAbdomen),
+            "RightChest" => Ok(Self::RightChest),
+            "LeftArm" => Ok(Self::LeftArm),
+            "LeftShoulder" => Ok(Self::LeftShoulder),
+            "LeftBicep" => Ok(Self::LeftBicep),
+            "LeftTricep" => Ok(Self::LeftTricep),
+            "LeftBrachioradialis" => Ok(Self::LeftBrachioradialis),
+            "LeftForearmExtensors" => Ok(Self::LeftForearmExtensors),
+            "RightArm" => Ok(Self::RightArm),
+            "RightShoulder" => Ok(Self::RightShoulder),
+            "RightBicep" => Ok(Self::RightBicep),
+            "RightTricep" => Ok(Self::RightTricep),
+            "RightBrachioradialis" => Ok(Self::RightBrachioradialis),
+            "RightForearmExtensors" => Ok(Self::RightForearmExtensors),
+            "Neck" => Ok(Self::Neck),
+            "Throat" => Ok(Self::Throat),
+            "WaistMidBack" => Ok(Self::WaistMidBack),
+            "WaistFront" => Ok(Self::WaistFront),
+            "WaistLeft" => Ok(Self::WaistLeft),
+            "WaistRight" => Ok(Self::WaistRight),
+            _ => Err(UnknownVariantError::new("BodyLocation", name)),
+        }
+    }
+}
 impl SegmentLapStatus {
     pub fn from(content: u8) -> SegmentLapStatus {
         match content {
@@ -5966,17 +12813,33 @@ impl SegmentLapStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SegmentLapStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SegmentLapStatus] {
+        &[SegmentLapStatus::End, SegmentLapStatus::Fail]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::End => "End",
+            Self::Fail => "Fail",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SegmentLeaderboardType {
     Overall,
     PersonalBest,
@@ -5995,6 +12858,20 @@ pub enum SegmentLeaderboardType {
     CourseRecord,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SegmentLapStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SegmentLapStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "End" => Ok(Self::End),
+            "Fail" => Ok(Self::Fail),
+            _ => Err(UnknownVariantError::new("SegmentLapStatus", name)),
+        }
+    }
+}
 impl SegmentLeaderboardType {
     pub fn from(content: u8) -> SegmentLeaderboardType {
         match content {
@@ -6022,23 +12899,95 @@ impl SegmentLeaderboardType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SegmentLeaderboardType(
                 Self::from(reader.next_u8()?),
             )));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SegmentLeaderboardType] {
+        &[
+            SegmentLeaderboardType::Overall,
+            SegmentLeaderboardType::PersonalBest,
+            SegmentLeaderboardType::Connections,
+            SegmentLeaderboardType::Group,
+            SegmentLeaderboardType::Challenger,
+            SegmentLeaderboardType::Kom,
+            SegmentLeaderboardType::Qom,
+            SegmentLeaderboardType::Pr,
+            SegmentLeaderboardType::Goal,
+            SegmentLeaderboardType::Carrot,
+            SegmentLeaderboardType::ClubLeader,
+            SegmentLeaderboardType::Rival,
+            SegmentLeaderboardType::Last,
+            SegmentLeaderboardType::RecentBest,
+            SegmentLeaderboardType::CourseRecord,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Overall => "Overall",
+            Self::PersonalBest => "PersonalBest",
+            Self::Connections => "Connections",
+            Self::Group => "Group",
+            Self::Challenger => "Challenger",
+            Self::Kom => "Kom",
+            Self::Qom => "Qom",
+            Self::Pr => "Pr",
+            Self::Goal => "Goal",
+            Self::Carrot => "Carrot",
+            Self::ClubLeader => "ClubLeader",
+            Self::Rival => "Rival",
+            Self::Last => "Last",
+            Self::RecentBest => "RecentBest",
+            Self::CourseRecord => "CourseRecord",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SegmentDeleteStatus {
     DoNotDelete,
     DeleteOne,
     DeleteAll,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SegmentLeaderboardType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SegmentLeaderboardType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Overall" => Ok(Self::Overall),
+            "PersonalBest" => Ok(Self::PersonalBest),
+            "Connections" => Ok(Self::Connections),
+            "Group" => Ok(Self::Group),
+            "Challenger" => Ok(Self::Challenger),
+            "Kom" => Ok(Self::Kom),
+            "Qom" => Ok(Self::Qom),
+            "Pr" => Ok(Self::Pr),
+            "Goal" => Ok(Self::Goal),
+            "Carrot" => Ok(Self::Carrot),
+            "ClubLeader" => Ok(Self::ClubLeader),
+            "Rival" => Ok(Self::Rival),
+            "Last" => Ok(Self::Last),
+            "RecentBest" => Ok(Self::RecentBest),
+            "CourseRecord" => Ok(Self::CourseRecord),
+            _ => Err(UnknownVariantError::new("SegmentLeaderboardType", name)),
+        }
+    }
+}
 impl SegmentDeleteStatus {
     pub fn from(content: u8) -> SegmentDeleteStatus {
         match content {
@@ -6054,22 +13003,58 @@ impl SegmentDeleteStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SegmentDeleteStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SegmentDeleteStatus] {
+        &[
+            SegmentDeleteStatus::DoNotDelete,
+            SegmentDeleteStatus::DeleteOne,
+            SegmentDeleteStatus::DeleteAll,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DoNotDelete => "DoNotDelete",
+            Self::DeleteOne => "DeleteOne",
+            Self::DeleteAll => "DeleteAll",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SegmentSelectionType {
     Starred,
     Suggested,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SegmentDeleteStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SegmentDeleteStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "DoNotDelete" => Ok(Self::DoNotDelete),
+            "DeleteOne" => Ok(Self::DeleteOne),
+            "DeleteAll" => Ok(Self::DeleteAll),
+            _ => Err(UnknownVariantError::new("SegmentDeleteStatus", name)),
+        }
+    }
+}
 impl SegmentSelectionType {
     pub fn from(content: u8) -> SegmentSelectionType {
         match content {
@@ -6084,17 +13069,36 @@ impl SegmentSelectionType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SegmentSelectionType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SegmentSelectionType] {
+        &[
+            SegmentSelectionType::Starred,
+            SegmentSelectionType::Suggested,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Starred => "Starred",
+            Self::Suggested => "Suggested",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SourceType {
     Ant,
     Antplus,
@@ -6104,6 +13108,20 @@ pub enum SourceType {
     Local,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SegmentSelectionType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SegmentSelectionType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Starred" => Ok(Self::Starred),
+            "Suggested" => Ok(Self::Suggested),
+            _ => Err(UnknownVariantError::new("SegmentSelectionType", name)),
+        }
+    }
+}
 impl SourceType {
     pub fn from(content: u8) -> SourceType {
         match content {
@@ -6122,17 +13140,44 @@ impl SourceType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SourceType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SourceType] {
+        &[
+            SourceType::Ant,
+            SourceType::Antplus,
+            SourceType::Bluetooth,
+            SourceType::BluetoothLowEnergy,
+            SourceType::Wifi,
+            SourceType::Local,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ant => "Ant",
+            Self::Antplus => "Antplus",
+            Self::Bluetooth => "Bluetooth",
+            Self::BluetoothLowEnergy => "BluetoothLowEnergy",
+            Self::Wifi => "Wifi",
+            Self::Local => "Local",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum LocalDeviceType {
     Gps,
     Glonass,
@@ -6144,6 +13189,24 @@ pub enum LocalDeviceType {
     SensorHub,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SourceType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SourceType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Ant" => Ok(Self::Ant),
+            "Antplus" => Ok(Self::Antplus),
+            "Bluetooth" => Ok(Self::Bluetooth),
+            "BluetoothLowEnergy" => Ok(Self::BluetoothLowEnergy),
+            "Wifi" => Ok(Self::Wifi),
+            "Local" => Ok(Self::Local),
+            _ => Err(UnknownVariantError::new("SourceType", name)),
+        }
+    }
+}
 impl LocalDeviceType {
     pub fn from(content: u8) -> LocalDeviceType {
         match content {
@@ -6164,17 +13227,48 @@ impl LocalDeviceType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::LocalDeviceType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [LocalDeviceType] {
+        &[
+            LocalDeviceType::Gps,
+            LocalDeviceType::Glonass,
+            LocalDeviceType::GpsGlonass,
+            LocalDeviceType::Accelerometer,
+            LocalDeviceType::Barometer,
+            LocalDeviceType::Temperature,
+            LocalDeviceType::Whr,
+            LocalDeviceType::SensorHub,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gps => "Gps",
+            Self::Glonass => "Glonass",
+            Self::GpsGlonass => "GpsGlonass",
+            Self::Accelerometer => "Accelerometer",
+            Self::Barometer => "Barometer",
+            Self::Temperature => "Temperature",
+            Self::Whr => "Whr",
+            Self::SensorHub => "SensorHub",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum BleDeviceType {
     ConnectedGps,
     HeartRate,
@@ -6186,6 +13280,26 @@ pub enum BleDeviceType {
     BikeTrainer,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for LocalDeviceType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [LocalDeviceType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Gps" => Ok(Self::Gps),
+            "Glonass" => Ok(Self::Glonass),
+            "GpsGlonass" => Ok(Self::GpsGlonass),
+            "Accelerometer" => Ok(Self::Accelerometer),
+            "Barometer" => Ok(Self::Barometer),
+            "Temperature" => Ok(Self::Temperature),
+            "Whr" => Ok(Self::Whr),
+            "SensorHub" => Ok(Self::SensorHub),
+            _ => Err(UnknownVariantError::new("LocalDeviceType", name)),
+        }
+    }
+}
 impl BleDeviceType {
     pub fn from(content: u8) -> BleDeviceType {
         match content {
@@ -6206,17 +13320,48 @@ impl BleDeviceType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::BleDeviceType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [BleDeviceType] {
+        &[
+            BleDeviceType::ConnectedGps,
+            BleDeviceType::HeartRate,
+            BleDeviceType::BikePower,
+            BleDeviceType::BikeSpeedCadence,
+            BleDeviceType::BikeSpeed,
+            BleDeviceType::BikeCadence,
+            BleDeviceType::Footpod,
+            BleDeviceType::BikeTrainer,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ConnectedGps => "ConnectedGps",
+            Self::HeartRate => "HeartRate",
+            Self::BikePower => "BikePower",
+            Self::BikeSpeedCadence => "BikeSpeedCadence",
+            Self::BikeSpeed => "BikeSpeed",
+            Self::BikeCadence => "BikeCadence",
+            Self::Footpod => "Footpod",
+            Self::BikeTrainer => "BikeTrainer",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AntChannelId {
     AntExtendedDeviceNumberUpperNibble,
     AntTransmissionTypeLowerNibble,
@@ -6224,6 +13369,26 @@ pub enum AntChannelId {
     AntDeviceNumber,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for BleDeviceType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [BleDeviceType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "ConnectedGps" => Ok(Self::ConnectedGps),
+            "HeartRate" => Ok(Self::HeartRate),
+            "BikePower" => Ok(Self::BikePower),
+            "BikeSpeedCadence" => Ok(Self::BikeSpeedCadence),
+            "BikeSpeed" => Ok(Self::BikeSpeed),
+            "BikeCadence" => Ok(Self::BikeCadence),
+            "Footpod" => Ok(Self::Footpod),
+            "BikeTrainer" => Ok(Self::BikeTrainer),
+            _ => Err(UnknownVariantError::new("BleDeviceType", name)),
+        }
+    }
+}
 impl AntChannelId {
     pub fn from(content: u32) -> AntChannelId {
         match content {
@@ -6240,17 +13405,40 @@ impl AntChannelId {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AntChannelId(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AntChannelId] {
+        &[
+            AntChannelId::AntExtendedDeviceNumberUpperNibble,
+            AntChannelId::AntTransmissionTypeLowerNibble,
+            AntChannelId::AntDeviceType,
+            AntChannelId::AntDeviceNumber,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AntExtendedDeviceNumberUpperNibble => "AntExtendedDeviceNumberUpperNibble",
+            Self::AntTransmissionTypeLowerNibble => "AntTransmissionTypeLowerNibble",
+            Self::AntDeviceType => "AntDeviceType",
+            Self::AntDeviceNumber => "AntDeviceNumber",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DisplayOrientation {
     Auto,
     Portrait,
@@ -6259,6 +13447,22 @@ pub enum DisplayOrientation {
     LandscapeFlipped,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AntChannelId {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AntChannelId::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "AntExtendedDeviceNumberUpperNibble" => Ok(Self::AntExtendedDeviceNumberUpperNibble),
+            "AntTransmissionTypeLowerNibble" => Ok(Self::AntTransmissionTypeLowerNibble),
+            "AntDeviceType" => Ok(Self::AntDeviceType),
+            "AntDeviceNumber" => Ok(Self::AntDeviceNumber),
+            _ => Err(UnknownVariantError::new("AntChannelId", name)),
+        }
+    }
+}
 impl DisplayOrientation {
     pub fn from(content: u8) -> DisplayOrientation {
         match content {
@@ -6276,17 +13480,42 @@ impl DisplayOrientation {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DisplayOrientation(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DisplayOrientation] {
+        &[
+            DisplayOrientation::Auto,
+            DisplayOrientation::Portrait,
+            DisplayOrientation::Landscape,
+            DisplayOrientation::PortraitFlipped,
+            DisplayOrientation::LandscapeFlipped,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::Portrait => "Portrait",
+            Self::Landscape => "Landscape",
+            Self::PortraitFlipped => "PortraitFlipped",
+            Self::LandscapeFlipped => "LandscapeFlipped",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WorkoutEquipment {
     None,
     SwimFins,
@@ -6296,6 +13525,23 @@ pub enum WorkoutEquipment {
     SwimSnorkel,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DisplayOrientation {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DisplayOrientation::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Auto" => Ok(Self::Auto),
+            "Portrait" => Ok(Self::Portrait),
+            "Landscape" => Ok(Self::Landscape),
+            "PortraitFlipped" => Ok(Self::PortraitFlipped),
+            "LandscapeFlipped" => Ok(Self::LandscapeFlipped),
+            _ => Err(UnknownVariantError::new("DisplayOrientation", name)),
+        }
+    }
+}
 impl WorkoutEquipment {
     pub fn from(content: u8) -> WorkoutEquipment {
         match content {
@@ -6314,17 +13560,44 @@ impl WorkoutEquipment {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WorkoutEquipment(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WorkoutEquipment] {
+        &[
+            WorkoutEquipment::None,
+            WorkoutEquipment::SwimFins,
+            WorkoutEquipment::SwimKickboard,
+            WorkoutEquipment::SwimPaddles,
+            WorkoutEquipment::SwimPullBuoy,
+            WorkoutEquipment::SwimSnorkel,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::SwimFins => "SwimFins",
+            Self::SwimKickboard => "SwimKickboard",
+            Self::SwimPaddles => "SwimPaddles",
+            Self::SwimPullBuoy => "SwimPullBuoy",
+            Self::SwimSnorkel => "SwimSnorkel",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WatchfaceMode {
     Digital,
     Analog,
@@ -6332,6 +13605,24 @@ pub enum WatchfaceMode {
     Disabled,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WorkoutEquipment {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WorkoutEquipment::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "None" => Ok(Self::None),
+            "SwimFins" => Ok(Self::SwimFins),
+            "SwimKickboard" => Ok(Self::SwimKickboard),
+            "SwimPaddles" => Ok(Self::SwimPaddles),
+            "SwimPullBuoy" => Ok(Self::SwimPullBuoy),
+            "SwimSnorkel" => Ok(Self::SwimSnorkel),
+            _ => Err(UnknownVariantError::new("WorkoutEquipment", name)),
+        }
+    }
+}
 impl WatchfaceMode {
     pub fn from(content: u8) -> WatchfaceMode {
         match content {
@@ -6348,23 +13639,62 @@ impl WatchfaceMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WatchfaceMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WatchfaceMode] {
+        &[
+            WatchfaceMode::Digital,
+            WatchfaceMode::Analog,
+            WatchfaceMode::ConnectIq,
+            WatchfaceMode::Disabled,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Digital => "Digital",
+            Self::Analog => "Analog",
+            Self::ConnectIq => "ConnectIq",
+            Self::Disabled => "Disabled",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DigitalWatchfaceLayout {
     Traditional,
     Modern,
     Bold,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WatchfaceMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WatchfaceMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Digital" => Ok(Self::Digital),
+            "Analog" => Ok(Self::Analog),
+            "ConnectIq" => Ok(Self::ConnectIq),
+            "Disabled" => Ok(Self::Disabled),
+            _ => Err(UnknownVariantError::new("WatchfaceMode", name)),
+        }
+    }
+}
 impl DigitalWatchfaceLayout {
     pub fn from(content: u8) -> DigitalWatchfaceLayout {
         match content {
@@ -6380,23 +13710,59 @@ impl DigitalWatchfaceLayout {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DigitalWatchfaceLayout(
                 Self::from(reader.next_u8()?),
             )));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DigitalWatchfaceLayout] {
+        &[
+            DigitalWatchfaceLayout::Traditional,
+            DigitalWatchfaceLayout::Modern,
+            DigitalWatchfaceLayout::Bold,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Traditional => "Traditional",
+            Self::Modern => "Modern",
+            Self::Bold => "Bold",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AnalogWatchfaceLayout {
     Minimal,
     Traditional,
     Modern,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DigitalWatchfaceLayout {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DigitalWatchfaceLayout::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Traditional" => Ok(Self::Traditional),
+            "Modern" => Ok(Self::Modern),
+            "Bold" => Ok(Self::Bold),
+            _ => Err(UnknownVariantError::new("DigitalWatchfaceLayout", name)),
+        }
+    }
+}
 impl AnalogWatchfaceLayout {
     pub fn from(content: u8) -> AnalogWatchfaceLayout {
         match content {
@@ -6412,17 +13778,38 @@ impl AnalogWatchfaceLayout {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AnalogWatchfaceLayout(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AnalogWatchfaceLayout] {
+        &[
+            AnalogWatchfaceLayout::Minimal,
+            AnalogWatchfaceLayout::Traditional,
+            AnalogWatchfaceLayout::Modern,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Minimal => "Minimal",
+            Self::Traditional => "Traditional",
+            Self::Modern => "Modern",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum RiderPositionType {
     Seated,
     Standing,
@@ -6430,6 +13817,21 @@ pub enum RiderPositionType {
     TransitionToStanding,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AnalogWatchfaceLayout {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AnalogWatchfaceLayout::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Minimal" => Ok(Self::Minimal),
+            "Traditional" => Ok(Self::Traditional),
+            "Modern" => Ok(Self::Modern),
+            _ => Err(UnknownVariantError::new("AnalogWatchfaceLayout", name)),
+        }
+    }
+}
 impl RiderPositionType {
     pub fn from(content: u8) -> RiderPositionType {
         match content {
@@ -6446,20 +13848,43 @@ impl RiderPositionType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::RiderPositionType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
-pub enum CameraEventType {
-    VideoStart,
-    VideoSplit,
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [RiderPositionType] {
+        &[
+            RiderPositionType::Seated,
+            RiderPositionType::Standing,
+            RiderPositionType::TransitionToSeated,
+            RiderPositionType::TransitionToStanding,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Seated => "Seated",
+            Self::Standing => "Standing",
+            Self::TransitionToSeated => "TransitionToSeated",
+            Self::TransitionToStanding => "TransitionToStanding",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CameraEventType {
+    VideoStart,
+    VideoSplit,
     VideoEnd,
     PhotoTaken,
     VideoSecondStreamStart,
@@ -6473,6 +13898,22 @@ pub enum CameraEventType {
     VideoSecondStreamResume,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for RiderPositionType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [RiderPositionType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Seated" => Ok(Self::Seated),
+            "Standing" => Ok(Self::Standing),
+            "TransitionToSeated" => Ok(Self::TransitionToSeated),
+            "TransitionToStanding" => Ok(Self::TransitionToStanding),
+            _ => Err(UnknownVariantError::new("RiderPositionType", name)),
+        }
+    }
+}
 impl CameraEventType {
     pub fn from(content: u8) -> CameraEventType {
         match content {
@@ -6498,17 +13939,58 @@ impl CameraEventType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CameraEventType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CameraEventType] {
+        &[
+            CameraEventType::VideoStart,
+            CameraEventType::VideoSplit,
+            CameraEventType::VideoEnd,
+            CameraEventType::PhotoTaken,
+            CameraEventType::VideoSecondStreamStart,
+            CameraEventType::VideoSecondStreamSplit,
+            CameraEventType::VideoSecondStreamEnd,
+            CameraEventType::VideoSplitStart,
+            CameraEventType::VideoSecondStreamSplitStart,
+            CameraEventType::VideoPause,
+            CameraEventType::VideoSecondStreamPause,
+            CameraEventType::VideoResume,
+            CameraEventType::VideoSecondStreamResume,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::VideoStart => "VideoStart",
+            Self::VideoSplit => "VideoSplit",
+            Self::VideoEnd => "VideoEnd",
+            Self::PhotoTaken => "PhotoTaken",
+            Self::VideoSecondStreamStart => "VideoSecondStreamStart",
+            Self::VideoSecondStreamSplit => "VideoSecondStreamSplit",
+            Self::VideoSecondStreamEnd => "VideoSecondStreamEnd",
+            Self::VideoSplitStart => "VideoSplitStart",
+            Self::VideoSecondStreamSplitStart => "VideoSecondStreamSplitStart",
+            Self::VideoPause => "VideoPause",
+            Self::VideoSecondStreamPause => "VideoSecondStreamPause",
+            Self::VideoResume => "VideoResume",
+            Self::VideoSecondStreamResume => "VideoSecondStreamResume",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SensorType {
     Accelerometer,
     Gyroscope,
@@ -6516,6 +13998,31 @@ pub enum SensorType {
     Barometer,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for CameraEventType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CameraEventType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "VideoStart" => Ok(Self::VideoStart),
+            "VideoSplit" => Ok(Self::VideoSplit),
+            "VideoEnd" => Ok(Self::VideoEnd),
+            "PhotoTaken" => Ok(Self::PhotoTaken),
+            "VideoSecondStreamStart" => Ok(Self::VideoSecondStreamStart),
+            "VideoSecondStreamSplit" => Ok(Self::VideoSecondStreamSplit),
+            "VideoSecondStreamEnd" => Ok(Self::VideoSecondStreamEnd),
+            "VideoSplitStart" => Ok(Self::VideoSplitStart),
+            "VideoSecondStreamSplitStart" => Ok(Self::VideoSecondStreamSplitStart),
+            "VideoPause" => Ok(Self::VideoPause),
+            "VideoSecondStreamPause" => Ok(Self::VideoSecondStreamPause),
+            "VideoResume" => Ok(Self::VideoResume),
+            "VideoSecondStreamResume" => Ok(Self::VideoSecondStreamResume),
+            _ => Err(UnknownVariantError::new("CameraEventType", name)),
+        }
+    }
+}
 impl SensorType {
     pub fn from(content: u8) -> SensorType {
         match content {
@@ -6532,17 +14039,40 @@ impl SensorType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SensorType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SensorType] {
+        &[
+            SensorType::Accelerometer,
+            SensorType::Gyroscope,
+            SensorType::Compass,
+            SensorType::Barometer,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Accelerometer => "Accelerometer",
+            Self::Gyroscope => "Gyroscope",
+            Self::Compass => "Compass",
+            Self::Barometer => "Barometer",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum CommTimeoutType {
     WildcardPairingTimeout,
     PairingTimeout,
@@ -6550,6 +14080,22 @@ pub enum CommTimeoutType {
     ConnectionTimeout,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for SensorType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SensorType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Accelerometer" => Ok(Self::Accelerometer),
+            "Gyroscope" => Ok(Self::Gyroscope),
+            "Compass" => Ok(Self::Compass),
+            "Barometer" => Ok(Self::Barometer),
+            _ => Err(UnknownVariantError::new("SensorType", name)),
+        }
+    }
+}
 impl CommTimeoutType {
     pub fn from(content: u16) -> CommTimeoutType {
         match content {
@@ -6566,17 +14112,40 @@ impl CommTimeoutType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CommTimeoutType(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CommTimeoutType] {
+        &[
+            CommTimeoutType::WildcardPairingTimeout,
+            CommTimeoutType::PairingTimeout,
+            CommTimeoutType::ConnectionLost,
+            CommTimeoutType::ConnectionTimeout,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WildcardPairingTimeout => "WildcardPairingTimeout",
+            Self::PairingTimeout => "PairingTimeout",
+            Self::ConnectionLost => "ConnectionLost",
+            Self::ConnectionTimeout => "ConnectionTimeout",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum CameraOrientationType {
     CameraOrientation0,
     CameraOrientation90,
@@ -6584,6 +14153,22 @@ pub enum CameraOrientationType {
     CameraOrientation270,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for CommTimeoutType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CommTimeoutType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "WildcardPairingTimeout" => Ok(Self::WildcardPairingTimeout),
+            "PairingTimeout" => Ok(Self::PairingTimeout),
+            "ConnectionLost" => Ok(Self::ConnectionLost),
+            "ConnectionTimeout" => Ok(Self::ConnectionTimeout),
+            _ => Err(UnknownVariantError::new("CommTimeoutType", name)),
+        }
+    }
+}
 impl CameraOrientationType {
     pub fn from(content: u8) -> CameraOrientationType {
         match content {
@@ -6600,17 +14185,40 @@ impl CameraOrientationType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CameraOrientationType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CameraOrientationType] {
+        &[
+            CameraOrientationType::CameraOrientation0,
+            CameraOrientationType::CameraOrientation90,
+            CameraOrientationType::CameraOrientation180,
+            CameraOrientationType::CameraOrientation270,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CameraOrientation0 => "CameraOrientation0",
+            Self::CameraOrientation90 => "CameraOrientation90",
+            Self::CameraOrientation180 => "CameraOrientation180",
+            Self::CameraOrientation270 => "CameraOrientation270",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AttitudeStage {
     Failed,
     Aligning,
@@ -6618,6 +14226,22 @@ pub enum AttitudeStage {
     Valid,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for CameraOrientationType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CameraOrientationType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "CameraOrientation0" => Ok(Self::CameraOrientation0),
+            "CameraOrientation90" => Ok(Self::CameraOrientation90),
+            "CameraOrientation180" => Ok(Self::CameraOrientation180),
+            "CameraOrientation270" => Ok(Self::CameraOrientation270),
+            _ => Err(UnknownVariantError::new("CameraOrientationType", name)),
+        }
+    }
+}
 impl AttitudeStage {
     pub fn from(content: u8) -> AttitudeStage {
         match content {
@@ -6634,17 +14258,40 @@ impl AttitudeStage {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AttitudeStage(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AttitudeStage] {
+        &[
+            AttitudeStage::Failed,
+            AttitudeStage::Aligning,
+            AttitudeStage::Degraded,
+            AttitudeStage::Valid,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Failed => "Failed",
+            Self::Aligning => "Aligning",
+            Self::Degraded => "Degraded",
+            Self::Valid => "Valid",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AttitudeValidity {
     TrackAngleHeadingValid,
     PitchValid,
@@ -6661,6 +14308,22 @@ pub enum AttitudeValidity {
     MagneticHeading,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for AttitudeStage {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AttitudeStage::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Failed" => Ok(Self::Failed),
+            "Aligning" => Ok(Self::Aligning),
+            "Degraded" => Ok(Self::Degraded),
+            "Valid" => Ok(Self::Valid),
+            _ => Err(UnknownVariantError::new("AttitudeStage", name)),
+        }
+    }
+}
 impl AttitudeValidity {
     pub fn from(content: u16) -> AttitudeValidity {
         match content {
@@ -6686,17 +14349,58 @@ impl AttitudeValidity {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AttitudeValidity(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AttitudeValidity] {
+        &[
+            AttitudeValidity::TrackAngleHeadingValid,
+            AttitudeValidity::PitchValid,
+            AttitudeValidity::RollValid,
+            AttitudeValidity::LateralBodyAccelValid,
+            AttitudeValidity::NormalBodyAccelValid,
+            AttitudeValidity::TurnRateValid,
+            AttitudeValidity::HwFail,
+            AttitudeValidity::MagInvalid,
+            AttitudeValidity::NoGps,
+            AttitudeValidity::GpsInvalid,
+            AttitudeValidity::SolutionCoasting,
+            AttitudeValidity::TrueTrackAngle,
+            AttitudeValidity::MagneticHeading,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TrackAngleHeadingValid => "TrackAngleHeadingValid",
+            Self::PitchValid => "PitchValid",
+            Self::RollValid => "RollValid",
+            Self::LateralBodyAccelValid => "LateralBodyAccelValid",
+            Self::NormalBodyAccelValid => "NormalBodyAccelValid",
+            Self::TurnRateValid => "TurnRateValid",
+            Self::HwFail => "HwFail",
+            Self::MagInvalid => "MagInvalid",
+            Self::NoGps => "NoGps",
+            Self::GpsInvalid => "GpsInvalid",
+            Self::SolutionCoasting => "SolutionCoasting",
+            Self::TrueTrackAngle => "TrueTrackAngle",
+            Self::MagneticHeading => "MagneticHeading",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AutoSyncFrequency {
     Never,
     Occasionally,
@@ -6705,6 +14409,31 @@ pub enum AutoSyncFrequency {
     Remote,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AttitudeValidity {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AttitudeValidity::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "TrackAngleHeadingValid" => Ok(Self::TrackAngleHeadingValid),
+            "PitchValid" => Ok(Self::PitchValid),
+            "RollValid" => Ok(Self::RollValid),
+            "LateralBodyAccelValid" => Ok(Self::LateralBodyAccelValid),
+            "NormalBodyAccelValid" => Ok(Self::NormalBodyAccelValid),
+            "TurnRateValid" => Ok(Self::TurnRateValid),
+            "HwFail" => Ok(Self::HwFail),
+            "MagInvalid" => Ok(Self::MagInvalid),
+            "NoGps" => Ok(Self::NoGps),
+            "GpsInvalid" => Ok(Self::GpsInvalid),
+            "SolutionCoasting" => Ok(Self::SolutionCoasting),
+            "TrueTrackAngle" => Ok(Self::TrueTrackAngle),
+            "MagneticHeading" => Ok(Self::MagneticHeading),
+            _ => Err(UnknownVariantError::new("AttitudeValidity", name)),
+        }
+    }
+}
 impl AutoSyncFrequency {
     pub fn from(content: u8) -> AutoSyncFrequency {
         match content {
@@ -6722,17 +14451,42 @@ impl AutoSyncFrequency {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AutoSyncFrequency(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AutoSyncFrequency] {
+        &[
+            AutoSyncFrequency::Never,
+            AutoSyncFrequency::Occasionally,
+            AutoSyncFrequency::Frequent,
+            AutoSyncFrequency::OnceADay,
+            AutoSyncFrequency::Remote,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Never => "Never",
+            Self::Occasionally => "Occasionally",
+            Self::Frequent => "Frequent",
+            Self::OnceADay => "OnceADay",
+            Self::Remote => "Remote",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExdLayout {
     FullScreen,
     HalfVertical,
@@ -6745,6 +14499,23 @@ pub enum ExdLayout {
     Dynamic,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AutoSyncFrequency {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AutoSyncFrequency::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Never" => Ok(Self::Never),
+            "Occasionally" => Ok(Self::Occasionally),
+            "Frequent" => Ok(Self::Frequent),
+            "OnceADay" => Ok(Self::OnceADay),
+            "Remote" => Ok(Self::Remote),
+            _ => Err(UnknownVariantError::new("AutoSyncFrequency", name)),
+        }
+    }
+}
 impl ExdLayout {
     pub fn from(content: u8) -> ExdLayout {
         match content {
@@ -6766,17 +14537,50 @@ impl ExdLayout {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExdLayout(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExdLayout] {
+        &[
+            ExdLayout::FullScreen,
+            ExdLayout::HalfVertical,
+            ExdLayout::HalfHorizontal,
+            ExdLayout::HalfVerticalRightSplit,
+            ExdLayout::HalfHorizontalBottomSplit,
+            ExdLayout::FullQuarterSplit,
+            ExdLayout::HalfVerticalLeftSplit,
+            ExdLayout::HalfHorizontalTopSplit,
+            ExdLayout::Dynamic,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FullScreen => "FullScreen",
+            Self::HalfVertical => "HalfVertical",
+            Self::HalfHorizontal => "HalfHorizontal",
+            Self::HalfVerticalRightSplit => "HalfVerticalRightSplit",
+            Self::HalfHorizontalBottomSplit => "HalfHorizontalBottomSplit",
+            Self::FullQuarterSplit => "FullQuarterSplit",
+            Self::HalfVerticalLeftSplit => "HalfVerticalLeftSplit",
+            Self::HalfHorizontalTopSplit => "HalfHorizontalTopSplit",
+            Self::Dynamic => "Dynamic",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExdDisplayType {
     Numerical,
     Simple,
@@ -6791,6 +14595,27 @@ pub enum ExdDisplayType {
     Gauge,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ExdLayout {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExdLayout::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "FullScreen" => Ok(Self::FullScreen),
+            "HalfVertical" => Ok(Self::HalfVertical),
+            "HalfHorizontal" => Ok(Self::HalfHorizontal),
+            "HalfVerticalRightSplit" => Ok(Self::HalfVerticalRightSplit),
+            "HalfHorizontalBottomSplit" => Ok(Self::HalfHorizontalBottomSplit),
+            "FullQuarterSplit" => Ok(Self::FullQuarterSplit),
+            "HalfVerticalLeftSplit" => Ok(Self::HalfVerticalLeftSplit),
+            "HalfHorizontalTopSplit" => Ok(Self::HalfHorizontalTopSplit),
+            "Dynamic" => Ok(Self::Dynamic),
+            _ => Err(UnknownVariantError::new("ExdLayout", name)),
+        }
+    }
+}
 impl ExdDisplayType {
     pub fn from(content: u8) -> ExdDisplayType {
         match content {
@@ -6814,17 +14639,54 @@ impl ExdDisplayType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExdDisplayType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExdDisplayType] {
+        &[
+            ExdDisplayType::Numerical,
+            ExdDisplayType::Simple,
+            ExdDisplayType::Graph,
+            ExdDisplayType::Bar,
+            ExdDisplayType::CircleGraph,
+            ExdDisplayType::VirtualPartner,
+            ExdDisplayType::Balance,
+            ExdDisplayType::StringList,
+            ExdDisplayType::String,
+            ExdDisplayType::SimpleDynamicIcon,
+            ExdDisplayType::Gauge,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Numerical => "Numerical",
+            Self::Simple => "Simple",
+            Self::Graph => "Graph",
+            Self::Bar => "Bar",
+            Self::CircleGraph => "CircleGraph",
+            Self::VirtualPartner => "VirtualPartner",
+            Self::Balance => "Balance",
+            Self::StringList => "StringList",
+            Self::String => "String",
+            Self::SimpleDynamicIcon => "SimpleDynamicIcon",
+            Self::Gauge => "Gauge",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExdDataUnits {
     NoUnits,
     Laps,
@@ -6878,6 +14740,29 @@ pub enum ExdDataUnits {
     EightCardinal,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ExdDisplayType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExdDisplayType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Numerical" => Ok(Self::Numerical),
+            "Simple" => Ok(Self::Simple),
+            "Graph" => Ok(Self::Graph),
+            "Bar" => Ok(Self::Bar),
+            "CircleGraph" => Ok(Self::CircleGraph),
+            "VirtualPartner" => Ok(Self::VirtualPartner),
+            "Balance" => Ok(Self::Balance),
+            "StringList" => Ok(Self::StringList),
+            "String" => Ok(Self::String),
+            "SimpleDynamicIcon" => Ok(Self::SimpleDynamicIcon),
+            "Gauge" => Ok(Self::Gauge),
+            _ => Err(UnknownVariantError::new("ExdDisplayType", name)),
+        }
+    }
+}
 impl ExdDataUnits {
     pub fn from(content: u8) -> ExdDataUnits {
         match content {
@@ -6940,17 +14825,132 @@ impl ExdDataUnits {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExdDataUnits(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExdDataUnits] {
+        &[
+            ExdDataUnits::NoUnits,
+            ExdDataUnits::Laps,
+            ExdDataUnits::MilesPerHour,
+            ExdDataUnits::KilometersPerHour,
+            ExdDataUnits::FeetPerHour,
+            ExdDataUnits::MetersPerHour,
+            ExdDataUnits::DegreesCelsius,
+            ExdDataUnits::DegreesFarenheit,
+            ExdDataUnits::Zone,
+            ExdDataUnits::Gear,
+            ExdDataUnits::Rpm,
+            ExdDataUnits::Bpm,
+            ExdDataUnits::Degrees,
+            ExdDataUnits::Millimeters,
+            ExdDataUnits::Meters,
+            ExdDataUnits::Kilometers,
+            ExdDataUnits::Feet,
+            ExdDataUnits::Yards,
+            ExdDataUnits::Kilofeet,
+            ExdDataUnits::Miles,
+            ExdDataUnits::Time,
+            ExdDataUnits::EnumTurnType,
+            ExdDataUnits::Percent,
+            ExdDataUnits::Watts,
+            ExdDataUnits::WattsPerKilogram,
+            ExdDataUnits::EnumBatteryStatus,
+            ExdDataUnits::EnumBikeLightBeamAngleMode,
+            ExdDataUnits::EnumBikeLightBatteryStatus,
+            ExdDataUnits::EnumBikeLightNetworkConfigType,
+            ExdDataUnits::Lights,
+            ExdDataUnits::Seconds,
+            ExdDataUnits::Minutes,
+            ExdDataUnits::Hours,
+            ExdDataUnits::Calories,
+            ExdDataUnits::Kilojoules,
+            ExdDataUnits::Milliseconds,
+            ExdDataUnits::SecondPerMile,
+            ExdDataUnits::SecondPerKilometer,
+            ExdDataUnits::Centimeter,
+            ExdDataUnits::EnumCoursePoint,
+            ExdDataUnits::Bradians,
+            ExdDataUnits::EnumSport,
+            ExdDataUnits::InchesHg,
+            ExdDataUnits::MmHg,
+            ExdDataUnits::Mbars,
+            ExdDataUnits::HectoPascals,
+            ExdDataUnits::FeetPerMin,
+            ExdDataUnits::MetersPerMin,
+            ExdDataUnits::MetersPerSec,
+            ExdDataUnits::EightCardinal,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoUnits => "NoUnits",
+            Self::Laps => "Laps",
+            Self::MilesPerHour => "MilesPerHour",
+            Self::KilometersPerHour => "KilometersPerHour",
+            Self::FeetPerHour => "FeetPerHour",
+            Self::MetersPerHour => "MetersPerHour",
+            Self::DegreesCelsius => "DegreesCelsius",
+            Self::DegreesFarenheit => "DegreesFarenheit",
+            Self::Zone => "Zone",
+            Self::Gear => "Gear",
+            Self::Rpm => "Rpm",
+            Self::Bpm => "Bpm",
+            Self::Degrees => "Degrees",
+            Self::Millimeters => "Millimeters",
+            Self::Meters => "Meters",
+            Self::Kilometers => "Kilometers",
+            Self::Feet => "Feet",
+            Self::Yards => "Yards",
+            Self::Kilofeet => "Kilofeet",
+            Self::Miles => "Miles",
+            Self::Time => "Time",
+            Self::EnumTurnType => "EnumTurnType",
+            Self::Percent => "Percent",
+            Self::Watts => "Watts",
+            Self::WattsPerKilogram => "WattsPerKilogram",
+            Self::EnumBatteryStatus => "EnumBatteryStatus",
+            Self::EnumBikeLightBeamAngleMode => "EnumBikeLightBeamAngleMode",
+            Self::EnumBikeLightBatteryStatus => "EnumBikeLightBatteryStatus",
+            Self::EnumBikeLightNetworkConfigType => "EnumBikeLightNetworkConfigType",
+            Self::Lights => "Lights",
+            Self::Seconds => "Seconds",
+            Self::Minutes => "Minutes",
+            Self::Hours => "Hours",
+            Self::Calories => "Calories",
+            Self::Kilojoules => "Kilojoules",
+            Self::Milliseconds => "Milliseconds",
+            Self::SecondPerMile => "SecondPerMile",
+            Self::SecondPerKilometer => "SecondPerKilometer",
+            Self::Centimeter => "Centimeter",
+            Self::EnumCoursePoint => "EnumCoursePoint",
+            Self::Bradians => "Bradians",
+            Self::EnumSport => "EnumSport",
+            Self::InchesHg => "InchesHg",
+            Self::MmHg => "MmHg",
+            Self::Mbars => "Mbars",
+            Self::HectoPascals => "HectoPascals",
+            Self::FeetPerMin => "FeetPerMin",
+            Self::MetersPerMin => "MetersPerMin",
+            Self::MetersPerSec => "MetersPerSec",
+            Self::EightCardinal => "EightCardinal",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExdQualifiers {
     NoQualifier,
     Instantaneous,
@@ -6998,6 +14998,68 @@ pub enum ExdQualifiers {
     Zone1,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ExdDataUnits {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExdDataUnits::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NoUnits" => Ok(Self::NoUnits),
+            "Laps" => Ok(Self::Laps),
+            "MilesPerHour" => Ok(Self::MilesPerHour),
+            "KilometersPerHour" => Ok(Self::KilometersPerHour),
+            "FeetPerHour" => Ok(Self::FeetPerHour),
+            "MetersPerHour" => Ok(Self::MetersPerHour),
+            "DegreesCelsius" => Ok(Self::DegreesCelsius),
+            "DegreesFarenheit" => Ok(Self::DegreesFarenheit),
+            "Zone" => Ok(Self::Zone),
+            "Gear" => Ok(Self::Gear),
+            "Rpm" => Ok(Self::Rpm),
+            "Bpm" => Ok(Self::Bpm),
+            "Degrees" => Ok(Self::Degrees),
+            "Millimeters" => Ok(Self::Millimeters),
+            "Meters" => Ok(Self::Meters),
+            "Kilometers" => Ok(Self::Kilometers),
+            "Feet" => Ok(Self::Feet),
+            "Yards" => Ok(Self::Yards),
+            "Kilofeet" => Ok(Self::Kilofeet),
+            "Miles" => Ok(Self::Miles),
+            "Time" => Ok(Self::Time),
+            "EnumTurnType" => Ok(Self::EnumTurnType),
+            "Percent" => Ok(Self::Percent),
+            "Watts" => Ok(Self::Watts),
+            "WattsPerKilogram" => Ok(Self::WattsPerKilogram),
+            "EnumBatteryStatus" => Ok(Self::EnumBatteryStatus),
+            "EnumBikeLightBeamAngleMode" => Ok(Self::EnumBikeLightBeamAngleMode),
+            "EnumBikeLightBatteryStatus" => Ok(Self::EnumBikeLightBatteryStatus),
+            "EnumBikeLightNetworkConfigType" => Ok(Self::EnumBikeLightNetworkConfigType),
+            "Lights" => Ok(Self::Lights),
+            "Seconds" => Ok(Self::Seconds),
+            "Minutes" => Ok(Self::Minutes),
+            "Hours" => Ok(Self::Hours),
+            "Calories" => Ok(Self::Calories),
+            "Kilojoules" => Ok(Self::Kilojoules),
+            "Milliseconds" => Ok(Self::Milliseconds),
+            "SecondPerMile" => Ok(Self::SecondPerMile),
+            "SecondPerKilometer" => Ok(Self::SecondPerKilometer),
+            "Centimeter" => Ok(Self::Centimeter),
+            "EnumCoursePoint" => Ok(Self::EnumCoursePoint),
+            "Bradians" => Ok(Self::Bradians),
+            "EnumSport" => Ok(Self::EnumSport),
+            "InchesHg" => Ok(Self::InchesHg),
+            "MmHg" => Ok(Self::MmHg),
+            "Mbars" => Ok(Self::Mbars),
+            "HectoPascals" => Ok(Self::HectoPascals),
+            "FeetPerMin" => Ok(Self::FeetPerMin),
+            "MetersPerMin" => Ok(Self::MetersPerMin),
+            "MetersPerSec" => Ok(Self::MetersPerSec),
+            "EightCardinal" => Ok(Self::EightCardinal),
+            _ => Err(UnknownVariantError::new("ExdDataUnits", name)),
+        }
+    }
+}
 impl ExdQualifiers {
     pub fn from(content: u8) -> ExdQualifiers {
         match content {
@@ -7054,17 +15116,120 @@ impl ExdQualifiers {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExdQualifiers(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExdQualifiers] {
+        &[
+            ExdQualifiers::NoQualifier,
+            ExdQualifiers::Instantaneous,
+            ExdQualifiers::Average,
+            ExdQualifiers::Lap,
+            ExdQualifiers::Maximum,
+            ExdQualifiers::MaximumAverage,
+            ExdQualifiers::MaximumLap,
+            ExdQualifiers::LastLap,
+            ExdQualifiers::AverageLap,
+            ExdQualifiers::ToDestination,
+            ExdQualifiers::ToGo,
+            ExdQualifiers::ToNext,
+            ExdQualifiers::NextCoursePoint,
+            ExdQualifiers::Total,
+            ExdQualifiers::ThreeSecondAverage,
+            ExdQualifiers::TenSecondAverage,
+            ExdQualifiers::ThirtySecondAverage,
+            ExdQualifiers::PercentMaximum,
+            ExdQualifiers::PercentMaximumAverage,
+            ExdQualifiers::LapPercentMaximum,
+            ExdQualifiers::Elapsed,
+            ExdQualifiers::Sunrise,
+            ExdQualifiers::Sunset,
+            ExdQualifiers::ComparedToVirtualPartner,
+            ExdQualifiers::Maximum24h,
+            ExdQualifiers::Minimum24h,
+            ExdQualifiers::Minimum,
+            ExdQualifiers::First,
+            ExdQualifiers::Second,
+            ExdQualifiers::Third,
+            ExdQualifiers::Shifter,
+            ExdQualifiers::LastSport,
+            ExdQualifiers::Moving,
+            ExdQualifiers::Stopped,
+            ExdQualifiers::EstimatedTotal,
+            ExdQualifiers::Zone9,
+            ExdQualifiers::Zone8,
+            ExdQualifiers::Zone7,
+            ExdQualifiers::Zone6,
+            ExdQualifiers::Zone5,
+            ExdQualifiers::Zone4,
+            ExdQualifiers::Zone3,
+            ExdQualifiers::Zone2,
+            ExdQualifiers::Zone1,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoQualifier => "NoQualifier",
+            Self::Instantaneous => "Instantaneous",
+            Self::Average => "Average",
+            Self::Lap => "Lap",
+            Self::Maximum => "Maximum",
+            Self::MaximumAverage => "MaximumAverage",
+            Self::MaximumLap => "MaximumLap",
+            Self::LastLap => "LastLap",
+            Self::AverageLap => "AverageLap",
+            Self::ToDestination => "ToDestination",
+            Self::ToGo => "ToGo",
+            Self::ToNext => "ToNext",
+            Self::NextCoursePoint => "NextCoursePoint",
+            Self::Total => "Total",
+            Self::ThreeSecondAverage => "ThreeSecondAverage",
+            Self::TenSecondAverage => "TenSecondAverage",
+            Self::ThirtySecondAverage => "ThirtySecondAverage",
+            Self::PercentMaximum => "PercentMaximum",
+            Self::PercentMaximumAverage => "PercentMaximumAverage",
+            Self::LapPercentMaximum => "LapPercentMaximum",
+            Self::Elapsed => "Elapsed",
+            Self::Sunrise => "Sunrise",
+            Self::Sunset => "Sunset",
+            Self::ComparedToVirtualPartner => "ComparedToVirtualPartner",
+            Self::Maximum24h => "Maximum24h",
+            Self::Minimum24h => "Minimum24h",
+            Self::Minimum => "Minimum",
+            Self::First => "First",
+            Self::Second => "Second",
+            Self::Third => "Third",
+            Self::Shifter => "Shifter",
+            Self::LastSport => "LastSport",
+            Self::Moving => "Moving",
+            Self::Stopped => "Stopped",
+            Self::EstimatedTotal => "EstimatedTotal",
+            Self::Zone9 => "Zone9",
+            Self::Zone8 => "Zone8",
+            Self::Zone7 => "Zone7",
+            Self::Zone6 => "Zone6",
+            Self::Zone5 => "Zone5",
+            Self::Zone4 => "Zone4",
+            Self::Zone3 => "Zone3",
+            Self::Zone2 => "Zone2",
+            Self::Zone1 => "Zone1",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExdDescriptors {
     BikeLightBatteryStatus,
     BeamAngleStatus,
@@ -7165,6 +15330,62 @@ pub enum ExdDescriptors {
     Vam,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ExdQualifiers {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExdQualifiers::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NoQualifier" => Ok(Self::NoQualifier),
+            "Instantaneous" => Ok(Self::Instantaneous),
+            "Average" => Ok(Self::Average),
+            "Lap" => Ok(Self::Lap),
+            "Maximum" => Ok(Self::Maximum),
+            "MaximumAverage" => Ok(Self::MaximumAverage),
+            "MaximumLap" => Ok(Self::MaximumLap),
+            "LastLap" => Ok(Self::LastLap),
+            "AverageLap" => Ok(Self::AverageLap),
+            "ToDestination" => Ok(Self::ToDestination),
+            "ToGo" => Ok(Self::ToGo),
+            "ToNext" => Ok(Self::ToNext),
+            "NextCoursePoint" => Ok(Self::NextCoursePoint),
+            "Total" => Ok(Self::Total),
+            "ThreeSecondAverage" => Ok(Self::ThreeSecondAverage),
+            "TenSecondAverage" => Ok(Self::TenSecondAverage),
+            "ThirtySecondAverage" => Ok(Self::ThirtySecondAverage),
+            "PercentMaximum" => Ok(Self::PercentMaximum),
+            "PercentMaximumAverage" => Ok(Self::PercentMaximumAverage),
+            "LapPercentMaximum" => Ok(Self::LapPercentMaximum),
+            "Elapsed" => Ok(Self::Elapsed),
+            "Sunrise" => Ok(Self::Sunrise),
+            "Sunset" => Ok(Self::Sunset),
+            "ComparedToVirtualPartner" => Ok(Self::ComparedToVirtualPartner),
+            "Maximum24h" => Ok(Self::Maximum24h),
+            "Minimum24h" => Ok(Self::Minimum24h),
+            "Minimum" => Ok(Self::Minimum),
+            "First" => Ok(Self::First),
+            "Second" => Ok(Self::Second),
+            "Third" => Ok(Self::Third),
+            "Shifter" => Ok(Self::Shifter),
+            "LastSport" => Ok(Self::LastSport),
+            "Moving" => Ok(Self::Moving),
+            "Stopped" => Ok(Self::Stopped),
+            "EstimatedTotal" => Ok(Self::EstimatedTotal),
+            "Zone9" => Ok(Self::Zone9),
+            "Zone8" => Ok(Self::Zone8),
+            "Zone7" => Ok(Self::Zone7),
+            "Zone6" => Ok(Self::Zone6),
+            "Zone5" => Ok(Self::Zone5),
+            "Zone4" => Ok(Self::Zone4),
+            "Zone3" => Ok(Self::Zone3),
+            "Zone2" => Ok(Self::Zone2),
+            "Zone1" => Ok(Self::Zone1),
+            _ => Err(UnknownVariantError::new("ExdQualifiers", name)),
+        }
+    }
+}
 impl ExdDescriptors {
     pub fn from(content: u8) -> ExdDescriptors {
         match content {
@@ -7274,17 +15495,226 @@ impl ExdDescriptors {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExdDescriptors(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExdDescriptors] {
+        &[
+            ExdDescriptors::BikeLightBatteryStatus,
+            ExdDescriptors::BeamAngleStatus,
+            ExdDescriptors::BateryLevel,
+            ExdDescriptors::LightNetworkMode,
+            ExdDescriptors::NumberLightsConnected,
+            ExdDescriptors::Cadence,
+            ExdDescriptors::Distance,
+            ExdDescriptors::EstimatedTimeOfArrival,
+            ExdDescriptors::Heading,
+            ExdDescriptors::Time,
+            ExdDescriptors::BatteryLevel,
+            ExdDescriptors::TrainerResistance,
+            ExdDescriptors::TrainerTargetPower,
+            ExdDescriptors::TimeSeated,
+            ExdDescriptors::TimeStanding,
+            ExdDescriptors::Elevation,
+            ExdDescriptors::Grade,
+            ExdDescriptors::Ascent,
+            ExdDescriptors::Descent,
+            ExdDescriptors::VerticalSpeed,
+            ExdDescriptors::Di2BatteryLevel,
+            ExdDescriptors::FrontGear,
+            ExdDescriptors::RearGear,
+            ExdDescriptors::GearRatio,
+            ExdDescriptors::HeartRate,
+            ExdDescriptors::HeartRateZone,
+            ExdDescriptors::TimeInHeartRateZone,
+            ExdDescriptors::HeartRateReserve,
+            ExdDescriptors::Calories,
+            ExdDescriptors::GpsAccuracy,
+            ExdDescriptors::GpsSignalStrength,
+            ExdDescriptors::Temperature,
+            ExdDescriptors::TimeOfDay,
+            ExdDescriptors::Balance,
+            ExdDescriptors::PedalSmoothness,
+            ExdDescriptors::Power,
+            ExdDescriptors::FunctionalThresholdPower,
+            ExdDescriptors::IntensityFactor,
+            ExdDescriptors::Work,
+            ExdDescriptors::PowerRatio,
+            ExdDescriptors::NormalizedPower,
+            ExdDescriptors::TrainingStressScore,
+            ExdDescriptors::TimeOnZone,
+            ExdDescriptors::Speed,
+            ExdDescriptors::Laps,
+            ExdDescriptors::Reps,
+            ExdDescriptors::WorkoutStep,
+            ExdDescriptors::CourseDistance,
+            ExdDescriptors::NavigationDistance,
+            ExdDescriptors::CourseEstimatedTimeOfArrival,
+            ExdDescriptors::NavigationEstimatedTimeOfArrival,
+            ExdDescriptors::CourseTime,
+            ExdDescriptors::NavigationTime,
+            ExdDescriptors::CourseHeading,
+            ExdDescriptors::NavigationHeading,
+            ExdDescriptors::PowerZone,
+            ExdDescriptors::TorqueEffectiveness,
+            ExdDescriptors::TimerTime,
+            ExdDescriptors::PowerWeightRatio,
+            ExdDescriptors::LeftPlatformCenterOffset,
+            ExdDescriptors::RightPlatformCenterOffset,
+            ExdDescriptors::LeftPowerPhaseStartAngle,
+            ExdDescriptors::RightPowerPhaseStartAngle,
+            ExdDescriptors::LeftPowerPhaseFinishAngle,
+            ExdDescriptors::RightPowerPhaseFinishAngle,
+            ExdDescriptors::Gears,
+            ExdDescriptors::Pace,
+            ExdDescriptors::TrainingEffect,
+            ExdDescriptors::VerticalOscillation,
+            ExdDescriptors::VerticalRatio,
+            ExdDescriptors::GroundContactTime,
+            ExdDescriptors::LeftGroundContactTimeBalance,
+            ExdDescriptors::RightGroundContactTimeBalance,
+            ExdDescriptors::StrideLength,
+            ExdDescriptors::RunningCadence,
+            ExdDescriptors::PerformanceCondition,
+            ExdDescriptors::CourseType,
+            ExdDescriptors::TimeInPowerZone,
+            ExdDescriptors::NavigationTurn,
+            ExdDescriptors::CourseLocation,
+            ExdDescriptors::NavigationLocation,
+            ExdDescriptors::Compass,
+            ExdDescriptors::GearCombo,
+            ExdDescriptors::MuscleOxygen,
+            ExdDescriptors::Icon,
+            ExdDescriptors::CompassHeading,
+            ExdDescriptors::GpsHeading,
+            ExdDescriptors::GpsElevation,
+            ExdDescriptors::AnaerobicTrainingEffect,
+            ExdDescriptors::Course,
+            ExdDescriptors::OffCourse,
+            ExdDescriptors::GlideRatio,
+            ExdDescriptors::VerticalDistance,
+            ExdDescriptors::Vmg,
+            ExdDescriptors::AmbientPressure,
+            ExdDescriptors::Pressure,
+            ExdDescriptors::Vam,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BikeLightBatteryStatus => "BikeLightBatteryStatus",
+            Self::BeamAngleStatus => "BeamAngleStatus",
+            Self::BateryLevel => "BateryLevel",
+            Self::LightNetworkMode => "LightNetworkMode",
+            Self::NumberLightsConnected => "NumberLightsConnected",
+            Self::Cadence => "Cadence",
+            Self::Distance => "Distance",
+            Self::EstimatedTimeOfArrival => "EstimatedTimeOfArrival",
+            Self::Heading => "Heading",
+            Self::Time => "Time",
+            Self::BatteryLevel => "BatteryLevel",
+            Self::TrainerResistance => "TrainerResistance",
+            Self::TrainerTargetPower => "TrainerTargetPower",
+            Self::TimeSeated => "TimeSeated",
+            Self::TimeStanding => "TimeStanding",
+            Self::Elevation => "Elevation",
+            Self::Grade => "Grade",
+            Self::Ascent => "Ascent",
+            Self::Descent => "Descent",
+            Self::VerticalSpeed => "VerticalSpeed",
+            Self::Di2BatteryLevel => "Di2BatteryLevel",
+            Self::FrontGear => "FrontGear",
+            Self::RearGear => "RearGear",
+            Self::GearRatio => "GearRatio",
+            Self::HeartRate => "HeartRate",
+            Self::HeartRateZone => "HeartRateZone",
+            Self::TimeInHeartRateZone => "TimeInHeartRateZone",
+            Self::HeartRateReserve => "HeartRateReserve",
+            Self::Calories => "Calories",
+            Self::GpsAccuracy => "GpsAccuracy",
+            Self::GpsSignalStrength => "GpsSignalStrength",
+            Self::Temperature => "Temperature",
+            Self::TimeOfDay => "TimeOfDay",
+            Self::Balance => "Balance",
+            Self::PedalSmoothness => "PedalSmoothness",
+            Self::Power => "Power",
+            Self::FunctionalThresholdPower => "FunctionalThresholdPower",
+            Self::IntensityFactor => "IntensityFactor",
+            Self::Work => "Work",
+            Self::PowerRatio => "PowerRatio",
+            Self::NormalizedPower => "NormalizedPower",
+            Self::TrainingStressScore => "TrainingStressScore",
+            Self::TimeOnZone => "TimeOnZone",
+            Self::Speed => "Speed",
+            Self::Laps => "Laps",
+            Self::Reps => "Reps",
+            Self::WorkoutStep => "WorkoutStep",
+            Self::CourseDistance => "CourseDistance",
+            Self::NavigationDistance => "NavigationDistance",
+            Self::CourseEstimatedTimeOfArrival => "CourseEstimatedTimeOfArrival",
+            Self::NavigationEstimatedTimeOfArrival => "NavigationEstimatedTimeOfArrival",
+            Self::CourseTime => "CourseTime",
+            Self::NavigationTime => "NavigationTime",
+            Self::CourseHeading => "CourseHeading",
+            Self::NavigationHeading => "NavigationHeading",
+            Self::PowerZone => "PowerZone",
+            Self::TorqueEffectiveness => "TorqueEffectiveness",
+            Self::TimerTime => "TimerTime",
+            Self::PowerWeightRatio => "PowerWeightRatio",
+            Self::LeftPlatformCenterOffset => "LeftPlatformCenterOffset",
+            Self::RightPlatformCenterOffset => "RightPlatformCenterOffset",
+            Self::LeftPowerPhaseStartAngle => "LeftPowerPhaseStartAngle",
+            Self::RightPowerPhaseStartAngle => "RightPowerPhaseStartAngle",
+            Self::LeftPowerPhaseFinishAngle => "LeftPowerPhaseFinishAngle",
+            Self::RightPowerPhaseFinishAngle => "RightPowerPhaseFinishAngle",
+            Self::Gears => "Gears",
+            Self::Pace => "Pace",
+            Self::TrainingEffect => "TrainingEffect",
+            Self::VerticalOscillation => "VerticalOscillation",
+            Self::VerticalRatio => "VerticalRatio",
+            Self::GroundContactTime => "GroundContactTime",
+            Self::LeftGroundContactTimeBalance => "LeftGroundContactTimeBalance",
+            Self::RightGroundContactTimeBalance => "RightGroundContactTimeBalance",
+            Self::StrideLength => "StrideLength",
+            Self::RunningCadence => "RunningCadence",
+            Self::PerformanceCondition => "PerformanceCondition",
+            Self::CourseType => "CourseType",
+            Self::TimeInPowerZone => "TimeInPowerZone",
+            Self::NavigationTurn => "NavigationTurn",
+            Self::CourseLocation => "CourseLocation",
+            Self::NavigationLocation => "NavigationLocation",
+            Self::Compass => "Compass",
+            Self::GearCombo => "GearCombo",
+            Self::MuscleOxygen => "MuscleOxygen",
+            Self::Icon => "Icon",
+            Self::CompassHeading => "CompassHeading",
+            Self::GpsHeading => "GpsHeading",
+            Self::GpsElevation => "GpsElevation",
+            Self::AnaerobicTrainingEffect => "AnaerobicTrainingEffect",
+            Self::Course => "Course",
+            Self::OffCourse => "OffCourse",
+            Self::GlideRatio => "GlideRatio",
+            Self::VerticalDistance => "VerticalDistance",
+            Self::Vmg => "Vmg",
+            Self::AmbientPressure => "AmbientPressure",
+            Self::Pressure => "Pressure",
+            Self::Vam => "Vam",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum AutoActivityDetect {
     None,
     Running,
@@ -7295,6 +15725,115 @@ pub enum AutoActivityDetect {
     Sedentary,
     UnknownVariant(u32),
 }
+
+impl std::str::FromStr for ExdDescriptors {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExdDescriptors::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "BikeLightBatteryStatus" => Ok(Self::BikeLightBatteryStatus),
+            "BeamAngleStatus" => Ok(Self::BeamAngleStatus),
+            "BateryLevel" => Ok(Self::BateryLevel),
+            "LightNetworkMode" => Ok(Self::LightNetworkMode),
+            "NumberLightsConnected" => Ok(Self::NumberLightsConnected),
+            "Cadence" => Ok(Self::Cadence),
+            "Distance" => Ok(Self::Distance),
+            "EstimatedTimeOfArrival" => Ok(Self::EstimatedTimeOfArrival),
+            "Heading" => Ok(Self::Heading),
+            "Time" => Ok(Self::Time),
+            "BatteryLevel" => Ok(Self::BatteryLevel),
+            "TrainerResistance" => Ok(Self::TrainerResistance),
+            "TrainerTargetPower" => Ok(Self::TrainerTargetPower),
+            "TimeSeated" => Ok(Self::TimeSeated),
+            "TimeStanding" => Ok(Self::TimeStanding),
+            "Elevation" => Ok(Self::Elevation),
+            "Grade" => Ok(Self::Grade),
+            "Ascent" => Ok(Self::Ascent),
+            "Descent" => Ok(Self::Descent),
+            "VerticalSpeed" => Ok(Self::VerticalSpeed),
+            "Di2BatteryLevel" => Ok(Self::Di2BatteryLevel),
+            "FrontGear" => Ok(Self::FrontGear),
+            "RearGear" => Ok(Self::RearGear),
+            "GearRatio" => Ok(Self::GearRatio),
+            "HeartRate" => Ok(Self::HeartRate),
+            "HeartRateZone" => Ok(Self::HeartRateZone),
+            "TimeInHeartRateZone" => Ok(Self::TimeInHeartRateZone),
+            "HeartRateReserve" => Ok(Self::HeartRateReserve),
+            "Calories" => Ok(Self::Calories),
+            "GpsAccuracy" => Ok(Self::GpsAccuracy),
+            "GpsSignalStrength" => Ok(Self::GpsSignalStrength),
+            "Temperature" => Ok(Self::Temperature),
+            "TimeOfDay" => Ok(Self::TimeOfDay),
+            "Balance" => Ok(Self::Balance),
+            "PedalSmoothness" => Ok(Self::PedalSmoothness),
+            "Power" => Ok(Self::Power),
+            "FunctionalThresholdPower" => Ok(Self::FunctionalThresholdPower),
+            "IntensityFactor" => Ok(Self::IntensityFactor),
+            "Work" => Ok(Self::Work),
+            "PowerRatio" => Ok(Self::PowerRatio),
+            "NormalizedPower" => Ok(Self::NormalizedPower),
+            "TrainingStressScore" => Ok(Self::TrainingStressScore),
+            "TimeOnZone" => Ok(Self::TimeOnZone),
+            "Speed" => Ok(Self::Speed),
+            "Laps" => Ok(Self::Laps),
+            "Reps" => Ok(Self::Reps),
+            "WorkoutStep" => Ok(Self::WorkoutStep),
+            "CourseDistance" => Ok(Self::CourseDistance),
+            "NavigationDistance" => Ok(Self::NavigationDistance),
+            "CourseEstimatedTimeOfArrival" => Ok(Self::CourseEstimatedTimeOfArrival),
+            "NavigationEstimatedTimeOfArrival" => Ok(Self::NavigationEstimatedTimeOfArrival),
+            "CourseTime" => Ok(Self::CourseTime),
+            "NavigationTime" => Ok(Self::NavigationTime),
+            "CourseHeading" => Ok(Self::CourseHeading),
+            "NavigationHeading" => Ok(Self::NavigationHeading),
+            "PowerZone" => Ok(Self::PowerZone),
+            "TorqueEffectiveness" => Ok(Self::TorqueEffectiveness),
+            "TimerTime" => Ok(Self::TimerTime),
+            "PowerWeightRatio" => Ok(Self::PowerWeightRatio),
+            "LeftPlatformCenterOffset" => Ok(Self::LeftPlatformCenterOffset),
+            "RightPlatformCenterOffset" => Ok(Self::RightPlatformCenterOffset),
+            "LeftPowerPhaseStartAngle" => Ok(Self::LeftPowerPhaseStartAngle),
+            "RightPowerPhaseStartAngle" => Ok(Self::RightPowerPhaseStartAngle),
+            "LeftPowerPhaseFinishAngle" => Ok(Self::LeftPowerPhaseFinishAngle),
+            "RightPowerPhaseFinishAngle" => Ok(Self::RightPowerPhaseFinishAngle),
+            "Gears" => Ok(Self::Gears),
+            "Pace" => Ok(Self::Pace),
+            "TrainingEffect" => Ok(Self::TrainingEffect),
+            "VerticalOscillation" => Ok(Self::VerticalOscillation),
+            "VerticalRatio" => Ok(Self::VerticalRatio),
+            "GroundContactTime" => Ok(Self::GroundContactTime),
+            "LeftGroundContactTimeBalance" => Ok(Self::LeftGroundContactTimeBalance),
+            "RightGroundContactTimeBalance" => Ok(Self::RightGroundContactTimeBalance),
+            "StrideLength" => Ok(Self::StrideLength),
+            "RunningCadence" => Ok(Self::RunningCadence),
+            "PerformanceCondition" => Ok(Self::PerformanceCondition),
+            "CourseType" => Ok(Self::CourseType),
+            "TimeInPowerZone" => Ok(Self::TimeInPowerZone),
+            "NavigationTurn" => Ok(Self::NavigationTurn),
+            "CourseLocation" => Ok(Self::CourseLocation),
+            "NavigationLocation" => Ok(Self::NavigationLocation),
+            "Compass" => Ok(Self::Compass),
+            "GearCombo" => Ok(Self::GearCombo),
+            "MuscleOxygen" => Ok(Self::MuscleOxygen),
+            "Icon" => Ok(Self::Icon),
+            "CompassHeading" => Ok(Self::CompassHeading),
+            "GpsHeading" => Ok(Self::GpsHeading),
+            "GpsElevation" => Ok(Self::GpsElevation),
+            "AnaerobicTrainingEffect" => Ok(Self::AnaerobicTrainingEffect),
+            "Course" => Ok(Self::Course),
+            "OffCourse" => Ok(Self::OffCourse),
+            "GlideRatio" => Ok(Self::GlideRatio),
+            "VerticalDistance" => Ok(Self::VerticalDistance),
+            "Vmg" => Ok(Self::Vmg),
+            "AmbientPressure" => Ok(Self::AmbientPressure),
+            "Pressure" => Ok(Self::Pressure),
+            "Vam" => Ok(Self::Vam),
+            _ => Err(UnknownVariantError::new("ExdDescriptors", name)),
+        }
+    }
+}
 impl AutoActivityDetect {
     pub fn from(content: u32) -> AutoActivityDetect {
         match content {
@@ -7314,17 +15853,46 @@ impl AutoActivityDetect {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 4;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 4 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::AutoActivityDetect(Self::from(
                 reader.next_u32(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [AutoActivityDetect] {
+        &[
+            AutoActivityDetect::None,
+            AutoActivityDetect::Running,
+            AutoActivityDetect::Cycling,
+            AutoActivityDetect::Swimming,
+            AutoActivityDetect::Walking,
+            AutoActivityDetect::Elliptical,
+            AutoActivityDetect::Sedentary,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Running => "Running",
+            Self::Cycling => "Cycling",
+            Self::Swimming => "Swimming",
+            Self::Walking => "Walking",
+            Self::Elliptical => "Elliptical",
+            Self::Sedentary => "Sedentary",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum FitBaseType {
     Enum,
     Sint8,
@@ -7345,6 +15913,25 @@ pub enum FitBaseType {
     Uint64z,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for AutoActivityDetect {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [AutoActivityDetect::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "None" => Ok(Self::None),
+            "Running" => Ok(Self::Running),
+            "Cycling" => Ok(Self::Cycling),
+            "Swimming" => Ok(Self::Swimming),
+            "Walking" => Ok(Self::Walking),
+            "Elliptical" => Ok(Self::Elliptical),
+            "Sedentary" => Ok(Self::Sedentary),
+            _ => Err(UnknownVariantError::new("AutoActivityDetect", name)),
+        }
+    }
+}
 impl FitBaseType {
     pub fn from(content: u8) -> FitBaseType {
         match content {
@@ -7374,23 +15961,101 @@ impl FitBaseType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::FitBaseType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [FitBaseType] {
+        &[
+            FitBaseType::Enum,
+            FitBaseType::Sint8,
+            FitBaseType::Uint8,
+            FitBaseType::Sint16,
+            FitBaseType::Uint16,
+            FitBaseType::Sint32,
+            FitBaseType::Uint32,
+            FitBaseType::String,
+            FitBaseType::Float32,
+            FitBaseType::Float64,
+            FitBaseType::Uint8z,
+            FitBaseType::Uint16z,
+            FitBaseType::Uint32z,
+            FitBaseType::Byte,
+            FitBaseType::Sint64,
+            FitBaseType::Uint64,
+            FitBaseType::Uint64z,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enum => "Enum",
+            Self::Sint8 => "Sint8",
+            Self::Uint8 => "Uint8",
+            Self::Sint16 => "Sint16",
+            Self::Uint16 => "Uint16",
+            Self::Sint32 => "Sint32",
+            Self::Uint32 => "Uint32",
+            Self::String => "String",
+            Self::Float32 => "Float32",
+            Self::Float64 => "Float64",
+            Self::Uint8z => "Uint8z",
+            Self::Uint16z => "Uint16z",
+            Self::Uint32z => "Uint32z",
+            Self::Byte => "Byte",
+            Self::Sint64 => "Sint64",
+            Self::Uint64 => "Uint64",
+            Self::Uint64z => "Uint64z",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum FitBaseUnit {
     Other,
     Kilogram,
     Pound,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for FitBaseType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [FitBaseType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Enum" => Ok(Self::Enum),
+            "Sint8" => Ok(Self::Sint8),
+            "Uint8" => Ok(Self::Uint8),
+            "Sint16" => Ok(Self::Sint16),
+            "Uint16" => Ok(Self::Uint16),
+            "Sint32" => Ok(Self::Sint32),
+            "Uint32" => Ok(Self::Uint32),
+            "String" => Ok(Self::String),
+            "Float32" => Ok(Self::Float32),
+            "Float64" => Ok(Self::Float64),
+            "Uint8z" => Ok(Self::Uint8z),
+            "Uint16z" => Ok(Self::Uint16z),
+            "Uint32z" => Ok(Self::Uint32z),
+            "Byte" => Ok(Self::Byte),
+            "Sint64" => Ok(Self::Sint64),
+            "Uint64" => Ok(Self::Uint64),
+            "Uint64z" => Ok(Self::Uint64z),
+            _ => Err(UnknownVariantError::new("FitBaseType", name)),
+        }
+    }
+}
 impl FitBaseUnit {
     pub fn from(content: u16) -> FitBaseUnit {
         match content {
@@ -7406,22 +16071,58 @@ impl FitBaseUnit {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::FitBaseUnit(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [FitBaseUnit] {
+        &[
+            FitBaseUnit::Other,
+            FitBaseUnit::Kilogram,
+            FitBaseUnit::Pound,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Other => "Other",
+            Self::Kilogram => "Kilogram",
+            Self::Pound => "Pound",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SetType {
     Rest,
     Active,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for FitBaseUnit {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [FitBaseUnit::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Other" => Ok(Self::Other),
+            "Kilogram" => Ok(Self::Kilogram),
+            "Pound" => Ok(Self::Pound),
+            _ => Err(UnknownVariantError::new("FitBaseUnit", name)),
+        }
+    }
+}
 impl SetType {
     pub fn from(content: u8) -> SetType {
         match content {
@@ -7436,22 +16137,52 @@ impl SetType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SetType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SetType] {
+        &[SetType::Rest, SetType::Active]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rest => "Rest",
+            Self::Active => "Active",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MaxMetCategory {
     Generic,
     Cycling,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SetType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SetType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Rest" => Ok(Self::Rest),
+            "Active" => Ok(Self::Active),
+            _ => Err(UnknownVariantError::new("SetType", name)),
+        }
+    }
+}
 impl MaxMetCategory {
     pub fn from(content: u8) -> MaxMetCategory {
         match content {
@@ -7466,17 +16197,33 @@ impl MaxMetCategory {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MaxMetCategory(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MaxMetCategory] {
+        &[MaxMetCategory::Generic, MaxMetCategory::Cycling]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generic => "Generic",
+            Self::Cycling => "Cycling",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ExerciseCategory {
     BenchPress,
     CalfRaise,
@@ -7533,6 +16280,20 @@ pub enum ExerciseCategory {
     Unknown,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for MaxMetCategory {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MaxMetCategory::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Generic" => Ok(Self::Generic),
+            "Cycling" => Ok(Self::Cycling),
+            _ => Err(UnknownVariantError::new("MaxMetCategory", name)),
+        }
+    }
+}
 impl ExerciseCategory {
     pub fn from(content: u16) -> ExerciseCategory {
         match content {
@@ -7598,17 +16359,138 @@ impl ExerciseCategory {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ExerciseCategory(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ExerciseCategory] {
+        &[
+            ExerciseCategory::BenchPress,
+            ExerciseCategory::CalfRaise,
+            ExerciseCategory::Cardio,
+            ExerciseCategory::Carry,
+            ExerciseCategory::Chop,
+            ExerciseCategory::Core,
+            ExerciseCategory::Crunch,
+            ExerciseCategory::Curl,
+            ExerciseCategory::Deadlift,
+            ExerciseCategory::Flye,
+            ExerciseCategory::HipRaise,
+            ExerciseCategory::HipStability,
+            ExerciseCategory::HipSwing,
+            ExerciseCategory::Hyperextension,
+            ExerciseCategory::LateralRaise,
+            ExerciseCategory::LegCurl,
+            ExerciseCategory::LegRaise,
+            ExerciseCategory::Lunge,
+            ExerciseCategory::OlympicLift,
+            ExerciseCategory::Plank,
+            ExerciseCategory::Plyo,
+            ExerciseCategory::PullUp,
+            ExerciseCategory::PushUp,
+            ExerciseCategory::Row,
+            ExerciseCategory::ShoulderPress,
+            ExerciseCategory::ShoulderStability,
+            ExerciseCategory::Shrug,
+            ExerciseCategory::SitUp,
+            ExerciseCategory::Squat,
+            ExerciseCategory::TotalBody,
+            ExerciseCategory::TricepsExtension,
+            ExerciseCategory::WarmUp,
+            ExerciseCategory::Run,
+            ExerciseCategory::Bike,
+            ExerciseCategory::CardioSensors,
+            ExerciseCategory::Move,
+            ExerciseCategory::Pose,
+            ExerciseCategory::BandedExercises,
+            ExerciseCategory::BattleRope,
+            ExerciseCategory::Elliptical,
+            ExerciseCategory::FloorClimb,
+            ExerciseCategory::IndoorBike,
+            ExerciseCategory::IndoorRow,
+            ExerciseCategory::Ladder,
+            ExerciseCategory::Sandbag,
+            ExerciseCategory::Sled,
+            ExerciseCategory::SledgeHammer,
+            ExerciseCategory::StairStepper,
+            ExerciseCategory::Suspension,
+            ExerciseCategory::Tire,
+            ExerciseCategory::RunIndoor,
+            ExerciseCategory::BikeOutdoor,
+            ExerciseCategory::Unknown,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BenchPress => "BenchPress",
+            Self::CalfRaise => "CalfRaise",
+            Self::Cardio => "Cardio",
+            Self::Carry => "Carry",
+            Self::Chop => "Chop",
+            Self::Core => "Core",
+            Self::Crunch => "Crunch",
+            Self::Curl => "Curl",
+            Self::Deadlift => "Deadlift",
+            Self::Flye => "Flye",
+            Self::HipRaise => "HipRaise",
+            Self::HipStability => "HipStability",
+            Self::HipSwing => "HipSwing",
+            Self::Hyperextension => "Hyperextension",
+            Self::LateralRaise => "LateralRaise",
+            Self::LegCurl => "LegCurl",
+            Self::LegRaise => "LegRaise",
+            Self::Lunge => "Lunge",
+            Self::OlympicLift => "OlympicLift",
+            Self::Plank => "Plank",
+            Self::Plyo => "Plyo",
+            Self::PullUp => "PullUp",
+            Self::PushUp => "PushUp",
+            Self::Row => "Row",
+            Self::ShoulderPress => "ShoulderPress",
+            Self::ShoulderStability => "ShoulderStability",
+            Self::Shrug => "Shrug",
+            Self::SitUp => "SitUp",
+            Self::Squat => "Squat",
+            Self::TotalBody => "TotalBody",
+            Self::TricepsExtension => "TricepsExtension",
+            Self::WarmUp => "WarmUp",
+            Self::Run => "Run",
+            Self::Bike => "Bike",
+            Self::CardioSensors => "CardioSensors",
+            Self::Move => "Move",
+            Self::Pose => "Pose",
+            Self::BandedExercises => "BandedExercises",
+            Self::BattleRope => "BattleRope",
+            Self::Elliptical => "Elliptical",
+            Self::FloorClimb => "FloorClimb",
+            Self::IndoorBike => "IndoorBike",
+            Self::IndoorRow => "IndoorRow",
+            Self::Ladder => "Ladder",
+            Self::Sandbag => "Sandbag",
+            Self::Sled => "Sled",
+            Self::SledgeHammer => "SledgeHammer",
+            Self::StairStepper => "StairStepper",
+            Self::Suspension => "Suspension",
+            Self::Tire => "Tire",
+            Self::RunIndoor => "RunIndoor",
+            Self::BikeOutdoor => "BikeOutdoor",
+            Self::Unknown => "Unknown",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WaterType {
     Fresh,
     Salt,
@@ -7616,6 +16498,71 @@ pub enum WaterType {
     Custom,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ExerciseCategory {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ExerciseCategory::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "BenchPress" => Ok(Self::BenchPress),
+            "CalfRaise" => Ok(Self::CalfRaise),
+            "Cardio" => Ok(Self::Cardio),
+            "Carry" => Ok(Self::Carry),
+            "Chop" => Ok(Self::Chop),
+            "Core" => Ok(Self::Core),
+            "Crunch" => Ok(Self::Crunch),
+            "Curl" => Ok(Self::Curl),
+            "Deadlift" => Ok(Self::Deadlift),
+            "Flye" => Ok(Self::Flye),
+            "HipRaise" => Ok(Self::HipRaise),
+            "HipStability" => Ok(Self::HipStability),
+            "HipSwing" => Ok(Self::HipSwing),
+            "Hyperextension" => Ok(Self::Hyperextension),
+            "LateralRaise" => Ok(Self::LateralRaise),
+            "LegCurl" => Ok(Self::LegCurl),
+            "LegRaise" => Ok(Self::LegRaise),
+            "Lunge" => Ok(Self::Lunge),
+            "OlympicLift" => Ok(Self::OlympicLift),
+            "Plank" => Ok(Self::Plank),
+            "Plyo" => Ok(Self::Plyo),
+            "PullUp" => Ok(Self::PullUp),
+            "PushUp" => Ok(Self::PushUp),
+            "Row" => Ok(Self::Row),
+            "ShoulderPress" => Ok(Self::ShoulderPress),
+            "ShoulderStability" => Ok(Self::ShoulderStability),
+            "Shrug" => Ok(Self::Shrug),
+            "SitUp" => Ok(Self::SitUp),
+            "Squat" => Ok(Self::Squat),
+            "TotalBody" => Ok(Self::TotalBody),
+            "TricepsExtension" => Ok(Self::TricepsExtension),
+            "WarmUp" => Ok(Self::WarmUp),
+            "Run" => Ok(Self::Run),
+            "Bike" => Ok(Self::Bike),
+            "CardioSensors" => Ok(Self::CardioSensors),
+            "Move" => Ok(Self::Move),
+            "Pose" => Ok(Self::Pose),
+            "BandedExercises" => Ok(Self::BandedExercises),
+            "BattleRope" => Ok(Self::BattleRope),
+            "Elliptical" => Ok(Self::Elliptical),
+            "FloorClimb" => Ok(Self::FloorClimb),
+            "IndoorBike" => Ok(Self::IndoorBike),
+            "IndoorRow" => Ok(Self::IndoorRow),
+            "Ladder" => Ok(Self::Ladder),
+            "Sandbag" => Ok(Self::Sandbag),
+            "Sled" => Ok(Self::Sled),
+            "SledgeHammer" => Ok(Self::SledgeHammer),
+            "StairStepper" => Ok(Self::StairStepper),
+            "Suspension" => Ok(Self::Suspension),
+            "Tire" => Ok(Self::Tire),
+            "RunIndoor" => Ok(Self::RunIndoor),
+            "BikeOutdoor" => Ok(Self::BikeOutdoor),
+            "Unknown" => Ok(Self::Unknown),
+            _ => Err(UnknownVariantError::new("ExerciseCategory", name)),
+        }
+    }
+}
 impl WaterType {
     pub fn from(content: u8) -> WaterType {
         match content {
@@ -7632,21 +16579,60 @@ impl WaterType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::WaterType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [WaterType] {
+        &[
+            WaterType::Fresh,
+            WaterType::Salt,
+            WaterType::En13319,
+            WaterType::Custom,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fresh => "Fresh",
+            Self::Salt => "Salt",
+            Self::En13319 => "En13319",
+            Self::Custom => "Custom",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TissueModelType {
     Zhl16c,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for WaterType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [WaterType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Fresh" => Ok(Self::Fresh),
+            "Salt" => Ok(Self::Salt),
+            "En13319" => Ok(Self::En13319),
+            "Custom" => Ok(Self::Custom),
+            _ => Err(UnknownVariantError::new("WaterType", name)),
+        }
+    }
+}
 impl TissueModelType {
     pub fn from(content: u8) -> TissueModelType {
         match content {
@@ -7660,23 +16646,51 @@ impl TissueModelType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::TissueModelType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [TissueModelType] {
+        &[TissueModelType::Zhl16c]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zhl16c => "Zhl16c",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DiveGasStatus {
     Disabled,
     Enabled,
     BackupOnly,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for TissueModelType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [TissueModelType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Zhl16c" => Ok(Self::Zhl16c),
+            _ => Err(UnknownVariantError::new("TissueModelType", name)),
+        }
+    }
+}
 impl DiveGasStatus {
     pub fn from(content: u8) -> DiveGasStatus {
         match content {
@@ -7692,17 +16706,72 @@ impl DiveGasStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DiveGasStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DiveGasStatus] {
+        &[
+            DiveGasStatus::Disabled,
+            DiveGasStatus::Enabled,
+            DiveGasStatus::BackupOnly,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disabled => "Disabled",
+            Self::Enabled => "Enabled",
+            Self::BackupOnly => "BackupOnly",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+
+    /// Whether the value is `Enabled`.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled)
+    }
+
+    /// `Some(true)` for `Enabled`, `Some(false)` for `Disabled` and `None` for any other
+    /// value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Enabled => Some(true),
+            Self::Disabled => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn from_bool(value: bool) -> Self {
+        if value { Self::Enabled } else { Self::Disabled }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl std::str::FromStr for DiveGasStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DiveGasStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Disabled" => Ok(Self::Disabled),
+            "Enabled" => Ok(Self::Enabled),
+            "BackupOnly" => Ok(Self::BackupOnly),
+            _ => Err(UnknownVariantError::new("DiveGasStatus", name)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DiveAlert {
     NdlReached,
     GasSwitchPrompted,
@@ -7796,23 +16865,167 @@ impl DiveAlert {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DiveAlert(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DiveAlert] {
+        &[
+            DiveAlert::NdlReached,
+            DiveAlert::GasSwitchPrompted,
+            DiveAlert::NearSurface,
+            DiveAlert::ApproachingNdl,
+            DiveAlert::Po2Warn,
+            DiveAlert::Po2CritHigh,
+            DiveAlert::Po2CritLow,
+            DiveAlert::TimeAlert,
+            DiveAlert::DepthAlert,
+            DiveAlert::DecoCeilingBroken,
+            DiveAlert::DecoComplete,
+            DiveAlert::SafetyStopBroken,
+            DiveAlert::SafetyStopComplete,
+            DiveAlert::CnsWarning,
+            DiveAlert::CnsCritical,
+            DiveAlert::OtuWarning,
+            DiveAlert::OtuCritical,
+            DiveAlert::AscentCritical,
+            DiveAlert::AlertDismissedByKey,
+            DiveAlert::AlertDismissedByTimeout,
+            DiveAlert::BatteryLow,
+            DiveAlert::BatteryCritical,
+            DiveAlert::SafetyStopStarted,
+            DiveAlert::ApproachingFirstDecoStop,
+            DiveAlert::SetpointSwitchAutoLow,
+            DiveAlert::SetpointSwitchAutoHigh,
+            DiveAlert::SetpointSwitchManualLow,
+            DiveAlert::SetpointSwitchManualHigh,
+            DiveAlert::AutoSetpointSwitchIgnored,
+            DiveAlert::SwitchedToOpenCircuit,
+            DiveAlert::SwitchedToClosedCircuit,
+            DiveAlert::TankBatteryLow,
+            DiveAlert::Po2CcrDilLow,
+            DiveAlert::DecoStopCleared,
+            DiveAlert::ApneaNeutralBuoyancy,
+            DiveAlert::ApneaTargetDepth,
+            DiveAlert::ApneaSurface,
+            DiveAlert::ApneaHighSpeed,
+            DiveAlert::ApneaLowSpeed,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NdlReached => "NdlReached",
+            Self::GasSwitchPrompted => "GasSwitchPrompted",
+            Self::NearSurface => "NearSurface",
+            Self::ApproachingNdl => "ApproachingNdl",
+            Self::Po2Warn => "Po2Warn",
+            Self::Po2CritHigh => "Po2CritHigh",
+            Self::Po2CritLow => "Po2CritLow",
+            Self::TimeAlert => "TimeAlert",
+            Self::DepthAlert => "DepthAlert",
+            Self::DecoCeilingBroken => "DecoCeilingBroken",
+            Self::DecoComplete => "DecoComplete",
+            Self::SafetyStopBroken => "SafetyStopBroken",
+            Self::SafetyStopComplete => "SafetyStopComplete",
+            Self::CnsWarning => "CnsWarning",
+            Self::CnsCritical => "CnsCritical",
+            Self::OtuWarning => "OtuWarning",
+            Self::OtuCritical => "OtuCritical",
+            Self::AscentCritical => "AscentCritical",
+            Self::AlertDismissedByKey => "AlertDismissedByKey",
+            Self::AlertDismissedByTimeout => "AlertDismissedByTimeout",
+            Self::BatteryLow => "BatteryLow",
+            Self::BatteryCritical => "BatteryCritical",
+            Self::SafetyStopStarted => "SafetyStopStarted",
+            Self::ApproachingFirstDecoStop => "ApproachingFirstDecoStop",
+            Self::SetpointSwitchAutoLow => "SetpointSwitchAutoLow",
+            Self::SetpointSwitchAutoHigh => "SetpointSwitchAutoHigh",
+            Self::SetpointSwitchManualLow => "SetpointSwitchManualLow",
+            Self::SetpointSwitchManualHigh => "SetpointSwitchManualHigh",
+            Self::AutoSetpointSwitchIgnored => "AutoSetpointSwitchIgnored",
+            Self::SwitchedToOpenCircuit => "SwitchedToOpenCircuit",
+            Self::SwitchedToClosedCircuit => "SwitchedToClosedCircuit",
+            Self::TankBatteryLow => "TankBatteryLow",
+            Self::Po2CcrDilLow => "Po2CcrDilLow",
+            Self::DecoStopCleared => "DecoStopCleared",
+            Self::ApneaNeutralBuoyancy => "ApneaNeutralBuoyancy",
+            Self::ApneaTargetDepth => "ApneaTargetDepth",
+            Self::ApneaSurface => "ApneaSurface",
+            Self::ApneaHighSpeed => "ApneaHighSpeed",
+            Self::ApneaLowSpeed => "ApneaLowSpeed",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DiveAlarmType {
     Depth,
     Time,
     Speed,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DiveAlert {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DiveAlert::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "NdlReached" => Ok(Self::NdlReached),
+            "GasSwitchPrompted" => Ok(Self::GasSwitchPrompted),
+            "NearSurface" => Ok(Self::NearSurface),
+            "ApproachingNdl" => Ok(Self::ApproachingNdl),
+            "Po2Warn" => Ok(Self::Po2Warn),
+            "Po2CritHigh" => Ok(Self::Po2CritHigh),
+            "Po2CritLow" => Ok(Self::Po2CritLow),
+            "TimeAlert" => Ok(Self::TimeAlert),
+            "DepthAlert" => Ok(Self::DepthAlert),
+            "DecoCeilingBroken" => Ok(Self::DecoCeilingBroken),
+            "DecoComplete" => Ok(Self::DecoComplete),
+            "SafetyStopBroken" => Ok(Self::SafetyStopBroken),
+            "SafetyStopComplete" => Ok(Self::SafetyStopComplete),
+            "CnsWarning" => Ok(Self::CnsWarning),
+            "CnsCritical" => Ok(Self::CnsCritical),
+            "OtuWarning" => Ok(Self::OtuWarning),
+            "OtuCritical" => Ok(Self::OtuCritical),
+            "AscentCritical" => Ok(Self::AscentCritical),
+            "AlertDismissedByKey" => Ok(Self::AlertDismissedByKey),
+            "AlertDismissedByTimeout" => Ok(Self::AlertDismissedByTimeout),
+            "BatteryLow" => Ok(Self::BatteryLow),
+            "BatteryCritical" => Ok(Self::BatteryCritical),
+            "SafetyStopStarted" => Ok(Self::SafetyStopStarted),
+            "ApproachingFirstDecoStop" => Ok(Self::ApproachingFirstDecoStop),
+            "SetpointSwitchAutoLow" => Ok(Self::SetpointSwitchAutoLow),
+            "SetpointSwitchAutoHigh" => Ok(Self::SetpointSwitchAutoHigh),
+            "SetpointSwitchManualLow" => Ok(Self::SetpointSwitchManualLow),
+            "SetpointSwitchManualHigh" => Ok(Self::SetpointSwitchManualHigh),
+            "AutoSetpointSwitchIgnored" => Ok(Self::AutoSetpointSwitchIgnored),
+            "SwitchedToOpenCircuit" => Ok(Self::SwitchedToOpenCircuit),
+            "SwitchedToClosedCircuit" => Ok(Self::SwitchedToClosedCircuit),
+            "TankBatteryLow" => Ok(Self::TankBatteryLow),
+            "Po2CcrDilLow" => Ok(Self::Po2CcrDilLow),
+            "DecoStopCleared" => Ok(Self::DecoStopCleared),
+            "ApneaNeutralBuoyancy" => Ok(Self::ApneaNeutralBuoyancy),
+            "ApneaTargetDepth" => Ok(Self::ApneaTargetDepth),
+            "ApneaSurface" => Ok(Self::ApneaSurface),
+            "ApneaHighSpeed" => Ok(Self::ApneaHighSpeed),
+            "ApneaLowSpeed" => Ok(Self::ApneaLowSpeed),
+            _ => Err(UnknownVariantError::new("DiveAlert", name)),
+        }
+    }
+}
 impl DiveAlarmType {
     pub fn from(content: u8) -> DiveAlarmType {
         match content {
@@ -7828,22 +17041,58 @@ impl DiveAlarmType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DiveAlarmType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DiveAlarmType] {
+        &[
+            DiveAlarmType::Depth,
+            DiveAlarmType::Time,
+            DiveAlarmType::Speed,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Depth => "Depth",
+            Self::Time => "Time",
+            Self::Speed => "Speed",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DiveBacklightMode {
     AtDepth,
     AlwaysOn,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DiveAlarmType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DiveAlarmType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Depth" => Ok(Self::Depth),
+            "Time" => Ok(Self::Time),
+            "Speed" => Ok(Self::Speed),
+            _ => Err(UnknownVariantError::new("DiveAlarmType", name)),
+        }
+    }
+}
 impl DiveBacklightMode {
     pub fn from(content: u8) -> DiveBacklightMode {
         match content {
@@ -7858,17 +17107,33 @@ impl DiveBacklightMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DiveBacklightMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DiveBacklightMode] {
+        &[DiveBacklightMode::AtDepth, DiveBacklightMode::AlwaysOn]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AtDepth => "AtDepth",
+            Self::AlwaysOn => "AlwaysOn",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SleepLevel {
     Unmeasurable,
     Awake,
@@ -7877,6 +17142,20 @@ pub enum SleepLevel {
     Rem,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DiveBacklightMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DiveBacklightMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "AtDepth" => Ok(Self::AtDepth),
+            "AlwaysOn" => Ok(Self::AlwaysOn),
+            _ => Err(UnknownVariantError::new("DiveBacklightMode", name)),
+        }
+    }
+}
 impl SleepLevel {
     pub fn from(content: u8) -> SleepLevel {
         match content {
@@ -7894,17 +17173,42 @@ impl SleepLevel {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SleepLevel(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SleepLevel] {
+        &[
+            SleepLevel::Unmeasurable,
+            SleepLevel::Awake,
+            SleepLevel::Light,
+            SleepLevel::Deep,
+            SleepLevel::Rem,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unmeasurable => "Unmeasurable",
+            Self::Awake => "Awake",
+            Self::Light => "Light",
+            Self::Deep => "Deep",
+            Self::Rem => "Rem",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Spo2MeasurementType {
     OffWrist,
     SpotCheck,
@@ -7912,6 +17216,23 @@ pub enum Spo2MeasurementType {
     Periodic,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SleepLevel {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SleepLevel::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Unmeasurable" => Ok(Self::Unmeasurable),
+            "Awake" => Ok(Self::Awake),
+            "Light" => Ok(Self::Light),
+            "Deep" => Ok(Self::Deep),
+            "Rem" => Ok(Self::Rem),
+            _ => Err(UnknownVariantError::new("SleepLevel", name)),
+        }
+    }
+}
 impl Spo2MeasurementType {
     pub fn from(content: u8) -> Spo2MeasurementType {
         match content {
@@ -7928,22 +17249,61 @@ impl Spo2MeasurementType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::Spo2MeasurementType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [Spo2MeasurementType] {
+        &[
+            Spo2MeasurementType::OffWrist,
+            Spo2MeasurementType::SpotCheck,
+            Spo2MeasurementType::ContinuousCheck,
+            Spo2MeasurementType::Periodic,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OffWrist => "OffWrist",
+            Self::SpotCheck => "SpotCheck",
+            Self::ContinuousCheck => "ContinuousCheck",
+            Self::Periodic => "Periodic",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum CcrSetpointSwitchMode {
     Manual,
     Automatic,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for Spo2MeasurementType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [Spo2MeasurementType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "OffWrist" => Ok(Self::OffWrist),
+            "SpotCheck" => Ok(Self::SpotCheck),
+            "ContinuousCheck" => Ok(Self::ContinuousCheck),
+            "Periodic" => Ok(Self::Periodic),
+            _ => Err(UnknownVariantError::new("Spo2MeasurementType", name)),
+        }
+    }
+}
 impl CcrSetpointSwitchMode {
     pub fn from(content: u8) -> CcrSetpointSwitchMode {
         match content {
@@ -7958,22 +17318,55 @@ impl CcrSetpointSwitchMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::CcrSetpointSwitchMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [CcrSetpointSwitchMode] {
+        &[
+            CcrSetpointSwitchMode::Manual,
+            CcrSetpointSwitchMode::Automatic,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::Automatic => "Automatic",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum DiveGasMode {
     OpenCircuit,
     ClosedCircuitDiluent,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for CcrSetpointSwitchMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [CcrSetpointSwitchMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Manual" => Ok(Self::Manual),
+            "Automatic" => Ok(Self::Automatic),
+            _ => Err(UnknownVariantError::new("CcrSetpointSwitchMode", name)),
+        }
+    }
+}
 impl DiveGasMode {
     pub fn from(content: u8) -> DiveGasMode {
         match content {
@@ -7988,17 +17381,33 @@ impl DiveGasMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::DiveGasMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [DiveGasMode] {
+        &[DiveGasMode::OpenCircuit, DiveGasMode::ClosedCircuitDiluent]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OpenCircuit => "OpenCircuit",
+            Self::ClosedCircuitDiluent => "ClosedCircuitDiluent",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ProjectileType {
     Arrow,
     RifleCartridge,
@@ -8008,6 +17417,20 @@ pub enum ProjectileType {
     Other,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for DiveGasMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [DiveGasMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "OpenCircuit" => Ok(Self::OpenCircuit),
+            "ClosedCircuitDiluent" => Ok(Self::ClosedCircuitDiluent),
+            _ => Err(UnknownVariantError::new("DiveGasMode", name)),
+        }
+    }
+}
 impl ProjectileType {
     pub fn from(content: u8) -> ProjectileType {
         match content {
@@ -8026,22 +17449,67 @@ impl ProjectileType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ProjectileType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ProjectileType] {
+        &[
+            ProjectileType::Arrow,
+            ProjectileType::RifleCartridge,
+            ProjectileType::PistolCartridge,
+            ProjectileType::Shotshell,
+            ProjectileType::AirRiflePellet,
+            ProjectileType::Other,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Arrow => "Arrow",
+            Self::RifleCartridge => "RifleCartridge",
+            Self::PistolCartridge => "PistolCartridge",
+            Self::Shotshell => "Shotshell",
+            Self::AirRiflePellet => "AirRiflePellet",
+            Self::Other => "Other",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum FaveroProduct {
     AssiomaUno,
     AssiomaDuo,
     UnknownVariant(u16),
 }
+
+impl std::str::FromStr for ProjectileType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ProjectileType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Arrow" => Ok(Self::Arrow),
+            "RifleCartridge" => Ok(Self::RifleCartridge),
+            "PistolCartridge" => Ok(Self::PistolCartridge),
+            "Shotshell" => Ok(Self::Shotshell),
+            "AirRiflePellet" => Ok(Self::AirRiflePellet),
+            "Other" => Ok(Self::Other),
+            _ => Err(UnknownVariantError::new("ProjectileType", name)),
+        }
+    }
+}
 impl FaveroProduct {
     pub fn from(content: u16) -> FaveroProduct {
         match content {
@@ -8056,17 +17524,33 @@ impl FaveroProduct {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 2;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 2 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::FaveroProduct(Self::from(
                 reader.next_u16(endianness)?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [FaveroProduct] {
+        &[FaveroProduct::AssiomaUno, FaveroProduct::AssiomaDuo]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AssiomaUno => "AssiomaUno",
+            Self::AssiomaDuo => "AssiomaDuo",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SplitType {
     AscentSplit,
     DescentSplit,
@@ -8091,6 +17575,20 @@ pub enum SplitType {
     SkiRunSplit,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for FaveroProduct {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [FaveroProduct::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "AssiomaUno" => Ok(Self::AssiomaUno),
+            "AssiomaDuo" => Ok(Self::AssiomaDuo),
+            _ => Err(UnknownVariantError::new("FaveroProduct", name)),
+        }
+    }
+}
 impl SplitType {
     pub fn from(content: u8) -> SplitType {
         match content {
@@ -8124,23 +17622,113 @@ impl SplitType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::SplitType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [SplitType] {
+        &[
+            SplitType::AscentSplit,
+            SplitType::DescentSplit,
+            SplitType::IntervalActive,
+            SplitType::IntervalRest,
+            SplitType::IntervalWarmup,
+            SplitType::IntervalCooldown,
+            SplitType::IntervalRecovery,
+            SplitType::IntervalOther,
+            SplitType::ClimbActive,
+            SplitType::ClimbRest,
+            SplitType::SurfActive,
+            SplitType::RunActive,
+            SplitType::RunRest,
+            SplitType::WorkoutRound,
+            SplitType::RwdRun,
+            SplitType::RwdWalk,
+            SplitType::WindsurfActive,
+            SplitType::RwdStand,
+            SplitType::Transition,
+            SplitType::SkiLiftSplit,
+            SplitType::SkiRunSplit,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AscentSplit => "AscentSplit",
+            Self::DescentSplit => "DescentSplit",
+            Self::IntervalActive => "IntervalActive",
+            Self::IntervalRest => "IntervalRest",
+            Self::IntervalWarmup => "IntervalWarmup",
+            Self::IntervalCooldown => "IntervalCooldown",
+            Self::IntervalRecovery => "IntervalRecovery",
+            Self::IntervalOther => "IntervalOther",
+            Self::ClimbActive => "ClimbActive",
+            Self::ClimbRest => "ClimbRest",
+            Self::SurfActive => "SurfActive",
+            Self::RunActive => "RunActive",
+            Self::RunRest => "RunRest",
+            Self::WorkoutRound => "WorkoutRound",
+            Self::RwdRun => "RwdRun",
+            Self::RwdWalk => "RwdWalk",
+            Self::WindsurfActive => "WindsurfActive",
+            Self::RwdStand => "RwdStand",
+            Self::Transition => "Transition",
+            Self::SkiLiftSplit => "SkiLiftSplit",
+            Self::SkiRunSplit => "SkiRunSplit",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ClimbProEvent {
     Approach,
     Start,
     Complete,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for SplitType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [SplitType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "AscentSplit" => Ok(Self::AscentSplit),
+            "DescentSplit" => Ok(Self::DescentSplit),
+            "IntervalActive" => Ok(Self::IntervalActive),
+            "IntervalRest" => Ok(Self::IntervalRest),
+            "IntervalWarmup" => Ok(Self::IntervalWarmup),
+            "IntervalCooldown" => Ok(Self::IntervalCooldown),
+            "IntervalRecovery" => Ok(Self::IntervalRecovery),
+            "IntervalOther" => Ok(Self::IntervalOther),
+            "ClimbActive" => Ok(Self::ClimbActive),
+            "ClimbRest" => Ok(Self::ClimbRest),
+            "SurfActive" => Ok(Self::SurfActive),
+            "RunActive" => Ok(Self::RunActive),
+            "RunRest" => Ok(Self::RunRest),
+            "WorkoutRound" => Ok(Self::WorkoutRound),
+            "RwdRun" => Ok(Self::RwdRun),
+            "RwdWalk" => Ok(Self::RwdWalk),
+            "WindsurfActive" => Ok(Self::WindsurfActive),
+            "RwdStand" => Ok(Self::RwdStand),
+            "Transition" => Ok(Self::Transition),
+            "SkiLiftSplit" => Ok(Self::SkiLiftSplit),
+            "SkiRunSplit" => Ok(Self::SkiRunSplit),
+            _ => Err(UnknownVariantError::new("SplitType", name)),
+        }
+    }
+}
 impl ClimbProEvent {
     pub fn from(content: u8) -> ClimbProEvent {
         match content {
@@ -8156,23 +17744,59 @@ impl ClimbProEvent {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::ClimbProEvent(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [ClimbProEvent] {
+        &[
+            ClimbProEvent::Approach,
+            ClimbProEvent::Start,
+            ClimbProEvent::Complete,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Approach => "Approach",
+            Self::Start => "Start",
+            Self::Complete => "Complete",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GasConsumptionRateType {
     PressureSac,
     VolumeSac,
     Rmv,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for ClimbProEvent {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [ClimbProEvent::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Approach" => Ok(Self::Approach),
+            "Start" => Ok(Self::Start),
+            "Complete" => Ok(Self::Complete),
+            _ => Err(UnknownVariantError::new("ClimbProEvent", name)),
+        }
+    }
+}
 impl GasConsumptionRateType {
     pub fn from(content: u8) -> GasConsumptionRateType {
         match content {
@@ -8188,23 +17812,59 @@ impl GasConsumptionRateType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::GasConsumptionRateType(
                 Self::from(reader.next_u8()?),
             )));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [GasConsumptionRateType] {
+        &[
+            GasConsumptionRateType::PressureSac,
+            GasConsumptionRateType::VolumeSac,
+            GasConsumptionRateType::Rmv,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PressureSac => "PressureSac",
+            Self::VolumeSac => "VolumeSac",
+            Self::Rmv => "Rmv",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TapSensitivity {
     High,
     Medium,
     Low,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for GasConsumptionRateType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [GasConsumptionRateType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "PressureSac" => Ok(Self::PressureSac),
+            "VolumeSac" => Ok(Self::VolumeSac),
+            "Rmv" => Ok(Self::Rmv),
+            _ => Err(UnknownVariantError::new("GasConsumptionRateType", name)),
+        }
+    }
+}
 impl TapSensitivity {
     pub fn from(content: u8) -> TapSensitivity {
         match content {
@@ -8220,17 +17880,38 @@ impl TapSensitivity {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::TapSensitivity(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [TapSensitivity] {
+        &[
+            TapSensitivity::High,
+            TapSensitivity::Medium,
+            TapSensitivity::Low,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::High => "High",
+            Self::Medium => "Medium",
+            Self::Low => "Low",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum RadarThreatLevelType {
     ThreatUnknown,
     ThreatNone,
@@ -8238,6 +17919,21 @@ pub enum RadarThreatLevelType {
     ThreatApproachingFast,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for TapSensitivity {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [TapSensitivity::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "High" => Ok(Self::High),
+            "Medium" => Ok(Self::Medium),
+            "Low" => Ok(Self::Low),
+            _ => Err(UnknownVariantError::new("TapSensitivity", name)),
+        }
+    }
+}
 impl RadarThreatLevelType {
     pub fn from(content: u8) -> RadarThreatLevelType {
         match content {
@@ -8254,23 +17950,62 @@ impl RadarThreatLevelType {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::RadarThreatLevelType(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [RadarThreatLevelType] {
+        &[
+            RadarThreatLevelType::ThreatUnknown,
+            RadarThreatLevelType::ThreatNone,
+            RadarThreatLevelType::ThreatApproaching,
+            RadarThreatLevelType::ThreatApproachingFast,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ThreatUnknown => "ThreatUnknown",
+            Self::ThreatNone => "ThreatNone",
+            Self::ThreatApproaching => "ThreatApproaching",
+            Self::ThreatApproachingFast => "ThreatApproachingFast",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MaxMetSpeedSource {
     OnboardGps,
     ConnectedGps,
     Cadence,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for RadarThreatLevelType {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [RadarThreatLevelType::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "ThreatUnknown" => Ok(Self::ThreatUnknown),
+            "ThreatNone" => Ok(Self::ThreatNone),
+            "ThreatApproaching" => Ok(Self::ThreatApproaching),
+            "ThreatApproachingFast" => Ok(Self::ThreatApproachingFast),
+            _ => Err(UnknownVariantError::new("RadarThreatLevelType", name)),
+        }
+    }
+}
 impl MaxMetSpeedSource {
     pub fn from(content: u8) -> MaxMetSpeedSource {
         match content {
@@ -8286,22 +18021,58 @@ impl MaxMetSpeedSource {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MaxMetSpeedSource(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MaxMetSpeedSource] {
+        &[
+            MaxMetSpeedSource::OnboardGps,
+            MaxMetSpeedSource::ConnectedGps,
+            MaxMetSpeedSource::Cadence,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OnboardGps => "OnboardGps",
+            Self::ConnectedGps => "ConnectedGps",
+            Self::Cadence => "Cadence",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum MaxMetHeartRateSource {
     Whr,
     Hrm,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for MaxMetSpeedSource {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MaxMetSpeedSource::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "OnboardGps" => Ok(Self::OnboardGps),
+            "ConnectedGps" => Ok(Self::ConnectedGps),
+            "Cadence" => Ok(Self::Cadence),
+            _ => Err(UnknownVariantError::new("MaxMetSpeedSource", name)),
+        }
+    }
+}
 impl MaxMetHeartRateSource {
     pub fn from(content: u8) -> MaxMetHeartRateSource {
         match content {
@@ -8316,17 +18087,33 @@ impl MaxMetHeartRateSource {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::MaxMetHeartRateSource(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [MaxMetHeartRateSource] {
+        &[MaxMetHeartRateSource::Whr, MaxMetHeartRateSource::Hrm]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Whr => "Whr",
+            Self::Hrm => "Hrm",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum HrvStatus {
     None,
     Poor,
@@ -8335,6 +18122,20 @@ pub enum HrvStatus {
     Balanced,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for MaxMetHeartRateSource {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [MaxMetHeartRateSource::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Whr" => Ok(Self::Whr),
+            "Hrm" => Ok(Self::Hrm),
+            _ => Err(UnknownVariantError::new("MaxMetHeartRateSource", name)),
+        }
+    }
+}
 impl HrvStatus {
     pub fn from(content: u8) -> HrvStatus {
         match content {
@@ -8352,22 +18153,64 @@ impl HrvStatus {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::HrvStatus(Self::from(
                 reader.next_u8()?,
             ))));
         }
-        Ok(values)
+        Ok(values)
+    }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [HrvStatus] {
+        &[
+            HrvStatus::None,
+            HrvStatus::Poor,
+            HrvStatus::Low,
+            HrvStatus::Unbalanced,
+            HrvStatus::Balanced,
+        ]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Poor => "Poor",
+            Self::Low => "Low",
+            Self::Unbalanced => "Unbalanced",
+            Self::Balanced => "Balanced",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum NoFlyTimeMode {
     Standard,
     Flat24Hours,
     UnknownVariant(u8),
 }
+
+impl std::str::FromStr for HrvStatus {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [HrvStatus::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "None" => Ok(Self::None),
+            "Poor" => Ok(Self::Poor),
+            "Low" => Ok(Self::Low),
+            "Unbalanced" => Ok(Self::Unbalanced),
+            "Balanced" => Ok(Self::Balanced),
+            _ => Err(UnknownVariantError::new("HrvStatus", name)),
+        }
+    }
+}
 impl NoFlyTimeMode {
     pub fn from(content: u8) -> NoFlyTimeMode {
         match content {
@@ -8382,14 +18225,30 @@ impl NoFlyTimeMode {
         endianness: &Endianness,
         number_of_bytes: u8,
     ) -> Result<Vec<DataValue>, DataTypeError> {
+        let number_of_values = number_of_bytes / 1;
+        check_number_of_values(reader, number_of_values as usize)?;
         let mut values = Vec::new();
-        for _ in 0..number_of_bytes / 1 {
+        for _ in 0..number_of_values {
             values.push(DataValue::Enum(FitEnum::NoFlyTimeMode(Self::from(
                 reader.next_u8()?,
             ))));
         }
         Ok(values)
     }
+
+    /// All the known variants, in the order of the FIT profile.
+    pub fn all() -> &'static [NoFlyTimeMode] {
+        &[NoFlyTimeMode::Standard, NoFlyTimeMode::Flat24Hours]
+    }
+
+    /// Name of the variant, e.g. to build lookup tables. Parsed back with [str::parse].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Flat24Hours => "Flat24Hours",
+            Self::UnknownVariant(_) => "UnknownVariant",
+        }
+    }
 }
 
 impl FitBaseType {
@@ -8419,6 +18278,20 @@ impl FitBaseType {
     }
 }
 
+impl std::str::FromStr for NoFlyTimeMode {
+    type Err = UnknownVariantError;
+
+    /// Variant from its name as returned by [NoFlyTimeMode::as_str], an error for an unknown name
+    /// or `UnknownVariant`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Standard" => Ok(Self::Standard),
+            "Flat24Hours" => Ok(Self::Flat24Hours),
+            _ => Err(UnknownVariantError::new("NoFlyTimeMode", name)),
+        }
+    }
+}
+
 pub type SimpleParseFunction =
     fn(&mut Reader, &Endianness, u8) -> Result<Vec<DataValue>, DataTypeError>;
 pub type DynamicParseFunction = fn(
@@ -8575,6 +18448,149 @@ pub enum FitField {
 pub struct CustomField {
     pub name: Option<String>,
     pub units: Option<String>,
+    /// Name as written in the developer field description, `name` being normalized when
+    /// [crate::ParseOptions::normalize_custom_names] is set.
+    pub original_name: Option<String>,
+}
+
+impl FitField {
+    /// Name of the field, from the FIT profile or from the developer field description for
+    /// [FitField::Custom] fields.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::FileId(field) => field.name(),
+            Self::FileCreator(field) => field.name(),
+            Self::TimestampCorrelation(field) => field.name(),
+            Self::Software(field) => field.name(),
+            Self::SlaveDevice(field) => field.name(),
+            Self::Capabilities(field) => field.name(),
+            Self::FileCapabilities(field) => field.name(),
+            Self::MesgCapabilities(field) => field.name(),
+            Self::FieldCapabilities(field) => field.name(),
+            Self::DeviceSettings(field) => field.name(),
+            Self::UserProfile(field) => field.name(),
+            Self::HrmProfile(field) => field.name(),
+            Self::SdmProfile(field) => field.name(),
+            Self::BikeProfile(field) => field.name(),
+            Self::Connectivity(field) => field.name(),
+            Self::WatchfaceSettings(field) => field.name(),
+            Self::OhrSettings(field) => field.name(),
+            Self::TimeInZone(field) => field.name(),
+            Self::ZonesTarget(field) => field.name(),
+            Self::Sport(field) => field.name(),
+            Self::HrZone(field) => field.name(),
+            Self::SpeedZone(field) => field.name(),
+            Self::CadenceZone(field) => field.name(),
+            Self::PowerZone(field) => field.name(),
+            Self::MetZone(field) => field.name(),
+            Self::TrainingSettings(field) => field.name(),
+            Self::DiveSettings(field) => field.name(),
+            Self::DiveAlarm(field) => field.name(),
+            Self::DiveApneaAlarm(field) => field.name(),
+            Self::DiveGas(field) => field.name(),
+            Self::Goal(field) => field.name(),
+            Self::Activity(field) => field.name(),
+            Self::Session(field) => field.name(),
+            Self::Lap(field) => field.name(),
+            Self::Length(field) => field.name(),
+            Self::Record(field) => field.name(),
+            Self::Event(field) => field.name(),
+            Self::DeviceInfo(field) => field.name(),
+            Self::DeviceAuxBatteryInfo(field) => field.name(),
+            Self::TrainingFile(field) => field.name(),
+            Self::WeatherConditions(field) => field.name(),
+            Self::WeatherAlert(field) => field.name(),
+            Self::GpsMetadata(field) => field.name(),
+            Self::CameraEvent(field) => field.name(),
+            Self::GyroscopeData(field) => field.name(),
+            Self::AccelerometerData(field) => field.name(),
+            Self::MagnetometerData(field) => field.name(),
+            Self::BarometerData(field) => field.name(),
+            Self::ThreeDSensorCalibration(field) => field.name(),
+            Self::OneDSensorCalibration(field) => field.name(),
+            Self::VideoFrame(field) => field.name(),
+            Self::ObdiiData(field) => field.name(),
+            Self::NmeaSentence(field) => field.name(),
+            Self::AviationAttitude(field) => field.name(),
+            Self::Video(field) => field.name(),
+            Self::VideoTitle(field) => field.name(),
+            Self::VideoDescription(field) => field.name(),
+            Self::VideoClip(field) => field.name(),
+            Self::Set(field) => field.name(),
+            Self::Jump(field) => field.name(),
+            Self::Split(field) => field.name(),
+            Self::SplitSummary(field) => field.name(),
+            Self::ClimbPro(field) => field.name(),
+            Self::FieldDescription(field) => field.name(),
+            Self::DeveloperDataId(field) => field.name(),
+            Self::Course(field) => field.name(),
+            Self::CoursePoint(field) => field.name(),
+            Self::SegmentId(field) => field.name(),
+            Self::SegmentLeaderboardEntry(field) => field.name(),
+            Self::SegmentPoint(field) => field.name(),
+            Self::SegmentLap(field) => field.name(),
+            Self::SegmentFile(field) => field.name(),
+            Self::Workout(field) => field.name(),
+            Self::WorkoutSession(field) => field.name(),
+            Self::WorkoutStep(field) => field.name(),
+            Self::ExerciseTitle(field) => field.name(),
+            Self::Schedule(field) => field.name(),
+            Self::Totals(field) => field.name(),
+            Self::WeightScale(field) => field.name(),
+            Self::BloodPressure(field) => field.name(),
+            Self::MonitoringInfo(field) => field.name(),
+            Self::Monitoring(field) => field.name(),
+            Self::MonitoringHrData(field) => field.name(),
+            Self::Spo2Data(field) => field.name(),
+            Self::Hr(field) => field.name(),
+            Self::StressLevel(field) => field.name(),
+            Self::MaxMetData(field) => field.name(),
+            Self::HsaBodyBatteryData(field) => field.name(),
+            Self::HsaEvent(field) => field.name(),
+            Self::HsaAccelerometerData(field) => field.name(),
+            Self::HsaGyroscopeData(field) => field.name(),
+            Self::HsaStepData(field) => field.name(),
+            Self::HsaSpo2Data(field) => field.name(),
+            Self::HsaStressData(field) => field.name(),
+            Self::HsaRespirationData(field) => field.name(),
+            Self::HsaHeartRateData(field) => field.name(),
+            Self::HsaConfigurationData(field) => field.name(),
+            Self::HsaWristTemperatureData(field) => field.name(),
+            Self::MemoGlob(field) => field.name(),
+            Self::SleepLevel(field) => field.name(),
+            Self::AntChannelId(field) => field.name(),
+            Self::AntRx(field) => field.name(),
+            Self::AntTx(field) => field.name(),
+            Self::ExdScreenConfiguration(field) => field.name(),
+            Self::ExdDataFieldConfiguration(field) => field.name(),
+            Self::ExdDataConceptConfiguration(field) => field.name(),
+            Self::DiveSummary(field) => field.name(),
+            Self::AadAccelFeatures(field) => field.name(),
+            Self::Hrv(field) => field.name(),
+            Self::BeatIntervals(field) => field.name(),
+            Self::HrvStatusSummary(field) => field.name(),
+            Self::HrvValue(field) => field.name(),
+            Self::RawBbi(field) => field.name(),
+            Self::RespirationRate(field) => field.name(),
+            Self::ChronoShotSession(field) => field.name(),
+            Self::ChronoShotData(field) => field.name(),
+            Self::TankUpdate(field) => field.name(),
+            Self::TankSummary(field) => field.name(),
+            Self::SleepAssessment(field) => field.name(),
+            Self::SkinTempOvernight(field) => field.name(),
+            Self::Custom(field) => field.name.as_deref().unwrap_or("unknown"),
+            Self::UnknownVariant(_) => "unknown",
+        }
+    }
+
+    /// Units of the field. Only known for [FitField::Custom] fields, from their developer field
+    /// description.
+    pub fn units(&self) -> Option<&str> {
+        match self {
+            Self::Custom(field) => field.units.as_deref(),
+            _ => None,
+        }
+    }
 }
 #[derive(Debug, PartialEq, Clone)]
 pub enum FileIdField {
@@ -8615,6 +18631,7 @@ impl FileIdFieldProductSubfield {
         Ok(DataMessageField {
             kind: FitField::FileId(FileIdField::Product),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -8646,6 +18663,7 @@ impl FileIdFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::FileId(FileIdField::FaveroProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -8687,6 +18705,7 @@ impl FileIdFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::FileId(FileIdField::GarminProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -8696,6 +18715,22 @@ impl FileIdFieldProductSubfield {
     }
 }
 impl FileIdField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Type => "type",
+            Self::Manufacturer => "manufacturer",
+            Self::Product => "product",
+            Self::FaveroProduct => "favero_product",
+            Self::GarminProduct => "garmin_product",
+            Self::SerialNumber => "serial_number",
+            Self::TimeCreated => "time_created",
+            Self::Number => "number",
+            Self::ProductName => "product_name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Type,
@@ -8739,6 +18774,15 @@ pub enum FileCreatorField {
     Unknown,
 }
 impl FileCreatorField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SoftwareVersion => "software_version",
+            Self::HardwareVersion => "hardware_version",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::SoftwareVersion,
@@ -8777,6 +18821,20 @@ pub enum TimestampCorrelationField {
     Unknown,
 }
 impl TimestampCorrelationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::FractionalTimestamp => "fractional_timestamp",
+            Self::SystemTimestamp => "system_timestamp",
+            Self::FractionalSystemTimestamp => "fractional_system_timestamp",
+            Self::LocalTimestamp => "local_timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SystemTimestampMs => "system_timestamp_ms",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -8831,6 +18889,16 @@ pub enum SoftwareField {
     Unknown,
 }
 impl SoftwareField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Version => "version",
+            Self::PartNumber => "part_number",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -8897,6 +18965,7 @@ impl SlaveDeviceFieldProductSubfield {
         Ok(DataMessageField {
             kind: FitField::SlaveDevice(SlaveDeviceField::Product),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -8928,6 +18997,7 @@ impl SlaveDeviceFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::SlaveDevice(SlaveDeviceField::FaveroProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -8969,6 +19039,7 @@ impl SlaveDeviceFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::SlaveDevice(SlaveDeviceField::GarminProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -8978,6 +19049,17 @@ impl SlaveDeviceFieldProductSubfield {
     }
 }
 impl SlaveDeviceField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Manufacturer => "manufacturer",
+            Self::Product => "product",
+            Self::FaveroProduct => "favero_product",
+            Self::GarminProduct => "garmin_product",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Manufacturer,
@@ -9013,6 +19095,17 @@ pub enum CapabilitiesField {
     Unknown,
 }
 impl CapabilitiesField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Languages => "languages",
+            Self::Sports => "sports",
+            Self::WorkoutsSupported => "workouts_supported",
+            Self::ConnectivitySupported => "connectivity_supported",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Languages,
@@ -9054,6 +19147,19 @@ pub enum FileCapabilitiesField {
     Unknown,
 }
 impl FileCapabilitiesField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Type => "type",
+            Self::Flags => "flags",
+            Self::Directory => "directory",
+            Self::MaxCount => "max_count",
+            Self::MaxSize => "max_size",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9127,6 +19233,7 @@ impl MesgCapabilitiesFieldCountSubfield {
         Ok(DataMessageField {
             kind: FitField::MesgCapabilities(MesgCapabilitiesField::Count),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -9158,6 +19265,7 @@ impl MesgCapabilitiesFieldCountSubfield {
                         Ok(DataMessageField {
                             kind: FitField::MesgCapabilities(MesgCapabilitiesField::NumPerFile),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -9185,6 +19293,7 @@ impl MesgCapabilitiesFieldCountSubfield {
                         Ok(DataMessageField {
                             kind: FitField::MesgCapabilities(MesgCapabilitiesField::MaxPerFile),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -9212,6 +19321,7 @@ impl MesgCapabilitiesFieldCountSubfield {
                         Ok(DataMessageField {
                             kind: FitField::MesgCapabilities(MesgCapabilitiesField::MaxPerFileType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -9221,6 +19331,21 @@ impl MesgCapabilitiesFieldCountSubfield {
     }
 }
 impl MesgCapabilitiesField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::File => "file",
+            Self::MesgNum => "mesg_num",
+            Self::CountType => "count_type",
+            Self::Count => "count",
+            Self::NumPerFile => "num_per_file",
+            Self::MaxPerFile => "max_per_file",
+            Self::MaxPerFileType => "max_per_file_type",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9263,6 +19388,18 @@ pub enum FieldCapabilitiesField {
     Unknown,
 }
 impl FieldCapabilitiesField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::File => "file",
+            Self::MesgNum => "mesg_num",
+            Self::FieldNum => "field_num",
+            Self::Count => "count",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9324,6 +19461,37 @@ pub enum DeviceSettingsField {
     Unknown,
 }
 impl DeviceSettingsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ActiveTimeZone => "active_time_zone",
+            Self::UtcOffset => "utc_offset",
+            Self::TimeOffset => "time_offset",
+            Self::TimeMode => "time_mode",
+            Self::TimeZoneOffset => "time_zone_offset",
+            Self::BacklightMode => "backlight_mode",
+            Self::ActivityTrackerEnabled => "activity_tracker_enabled",
+            Self::ClockTime => "clock_time",
+            Self::PagesEnabled => "pages_enabled",
+            Self::MoveAlertEnabled => "move_alert_enabled",
+            Self::DateMode => "date_mode",
+            Self::DisplayOrientation => "display_orientation",
+            Self::MountingSide => "mounting_side",
+            Self::DefaultPage => "default_page",
+            Self::AutosyncMinSteps => "autosync_min_steps",
+            Self::AutosyncMinTime => "autosync_min_time",
+            Self::LactateThresholdAutodetectEnabled => "lactate_threshold_autodetect_enabled",
+            Self::BleAutoUploadEnabled => "ble_auto_upload_enabled",
+            Self::AutoSyncFrequency => "auto_sync_frequency",
+            Self::AutoActivityDetect => "auto_activity_detect",
+            Self::NumberOfScreens => "number_of_screens",
+            Self::SmartNotificationDisplayOrientation => "smart_notification_display_orientation",
+            Self::TapInterface => "tap_interface",
+            Self::TapSensitivity => "tap_sensitivity",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ActiveTimeZone,
@@ -9432,6 +19600,42 @@ pub enum UserProfileField {
     Unknown,
 }
 impl UserProfileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::FriendlyName => "friendly_name",
+            Self::Gender => "gender",
+            Self::Age => "age",
+            Self::Height => "height",
+            Self::Weight => "weight",
+            Self::Language => "language",
+            Self::ElevSetting => "elev_setting",
+            Self::WeightSetting => "weight_setting",
+            Self::RestingHeartRate => "resting_heart_rate",
+            Self::DefaultMaxRunningHeartRate => "default_max_running_heart_rate",
+            Self::DefaultMaxBikingHeartRate => "default_max_biking_heart_rate",
+            Self::DefaultMaxHeartRate => "default_max_heart_rate",
+            Self::HrSetting => "hr_setting",
+            Self::SpeedSetting => "speed_setting",
+            Self::DistSetting => "dist_setting",
+            Self::PowerSetting => "power_setting",
+            Self::ActivityClass => "activity_class",
+            Self::PositionSetting => "position_setting",
+            Self::TemperatureSetting => "temperature_setting",
+            Self::LocalId => "local_id",
+            Self::GlobalId => "global_id",
+            Self::WakeTime => "wake_time",
+            Self::SleepTime => "sleep_time",
+            Self::HeightSetting => "height_setting",
+            Self::UserRunningStepLength => "user_running_step_length",
+            Self::UserWalkingStepLength => "user_walking_step_length",
+            Self::DepthSetting => "depth_setting",
+            Self::DiveCount => "dive_count",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9538,6 +19742,18 @@ pub enum HrmProfileField {
     Unknown,
 }
 impl HrmProfileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Enabled => "enabled",
+            Self::HrmAntId => "hrm_ant_id",
+            Self::LogHrv => "log_hrv",
+            Self::HrmAntIdTransType => "hrm_ant_id_trans_type",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9583,6 +19799,21 @@ pub enum SdmProfileField {
     Unknown,
 }
 impl SdmProfileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Enabled => "enabled",
+            Self::SdmAntId => "sdm_ant_id",
+            Self::SdmCalFactor => "sdm_cal_factor",
+            Self::Odometer => "odometer",
+            Self::SpeedSource => "speed_source",
+            Self::SdmAntIdTransType => "sdm_ant_id_trans_type",
+            Self::OdometerRollover => "odometer_rollover",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9666,6 +19897,45 @@ pub enum BikeProfileField {
     Unknown,
 }
 impl BikeProfileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Name => "name",
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::Odometer => "odometer",
+            Self::BikeSpdAntId => "bike_spd_ant_id",
+            Self::BikeCadAntId => "bike_cad_ant_id",
+            Self::BikeSpdcadAntId => "bike_spdcad_ant_id",
+            Self::BikePowerAntId => "bike_power_ant_id",
+            Self::CustomWheelsize => "custom_wheelsize",
+            Self::AutoWheelsize => "auto_wheelsize",
+            Self::BikeWeight => "bike_weight",
+            Self::PowerCalFactor => "power_cal_factor",
+            Self::AutoWheelCal => "auto_wheel_cal",
+            Self::AutoPowerZero => "auto_power_zero",
+            Self::Id => "id",
+            Self::SpdEnabled => "spd_enabled",
+            Self::CadEnabled => "cad_enabled",
+            Self::SpdcadEnabled => "spdcad_enabled",
+            Self::PowerEnabled => "power_enabled",
+            Self::CrankLength => "crank_length",
+            Self::Enabled => "enabled",
+            Self::BikeSpdAntIdTransType => "bike_spd_ant_id_trans_type",
+            Self::BikeCadAntIdTransType => "bike_cad_ant_id_trans_type",
+            Self::BikeSpdcadAntIdTransType => "bike_spdcad_ant_id_trans_type",
+            Self::BikePowerAntIdTransType => "bike_power_ant_id_trans_type",
+            Self::OdometerRollover => "odometer_rollover",
+            Self::FrontGearNum => "front_gear_num",
+            Self::FrontGear => "front_gear",
+            Self::RearGearNum => "rear_gear_num",
+            Self::RearGear => "rear_gear",
+            Self::ShimanoDi2Enabled => "shimano_di2_enabled",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9794,6 +20064,26 @@ pub enum ConnectivityField {
     Unknown,
 }
 impl ConnectivityField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BluetoothEnabled => "bluetooth_enabled",
+            Self::BluetoothLeEnabled => "bluetooth_le_enabled",
+            Self::AntEnabled => "ant_enabled",
+            Self::Name => "name",
+            Self::LiveTrackingEnabled => "live_tracking_enabled",
+            Self::WeatherConditionsEnabled => "weather_conditions_enabled",
+            Self::WeatherAlertsEnabled => "weather_alerts_enabled",
+            Self::AutoActivityUploadEnabled => "auto_activity_upload_enabled",
+            Self::CourseDownloadEnabled => "course_download_enabled",
+            Self::WorkoutDownloadEnabled => "workout_download_enabled",
+            Self::GpsEphemerisDownloadEnabled => "gps_ephemeris_download_enabled",
+            Self::IncidentDetectionEnabled => "incident_detection_enabled",
+            Self::GrouptrackEnabled => "grouptrack_enabled",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::BluetoothEnabled,
@@ -9877,6 +20167,7 @@ impl WatchfaceSettingsFieldLayoutSubfield {
         Ok(DataMessageField {
             kind: FitField::WatchfaceSettings(WatchfaceSettingsField::Layout),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -9911,6 +20202,7 @@ impl WatchfaceSettingsFieldLayoutSubfield {
                                 WatchfaceSettingsField::DigitalLayout,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -9939,6 +20231,7 @@ impl WatchfaceSettingsFieldLayoutSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WatchfaceSettings(WatchfaceSettingsField::AnalogLayout),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -9948,6 +20241,18 @@ impl WatchfaceSettingsFieldLayoutSubfield {
     }
 }
 impl WatchfaceSettingsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Mode => "mode",
+            Self::Layout => "layout",
+            Self::DigitalLayout => "digital_layout",
+            Self::AnalogLayout => "analog_layout",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -9983,6 +20288,15 @@ pub enum OhrSettingsField {
     Unknown,
 }
 impl OhrSettingsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Enabled => "enabled",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -10031,6 +20345,30 @@ pub enum TimeInZoneField {
     Unknown,
 }
 impl TimeInZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ReferenceMesg => "reference_mesg",
+            Self::ReferenceIndex => "reference_index",
+            Self::TimeInHrZone => "time_in_hr_zone",
+            Self::TimeInSpeedZone => "time_in_speed_zone",
+            Self::TimeInCadenceZone => "time_in_cadence_zone",
+            Self::TimeInPowerZone => "time_in_power_zone",
+            Self::HrZoneHighBoundary => "hr_zone_high_boundary",
+            Self::SpeedZoneHighBoundary => "speed_zone_high_boundary",
+            Self::CadenceZoneHighBondary => "cadence_zone_high_bondary",
+            Self::PowerZoneHighBoundary => "power_zone_high_boundary",
+            Self::HrCalcType => "hr_calc_type",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::RestingHeartRate => "resting_heart_rate",
+            Self::ThresholdHeartRate => "threshold_heart_rate",
+            Self::PwrCalcType => "pwr_calc_type",
+            Self::FunctionalThresholdPower => "functional_threshold_power",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -10117,6 +20455,18 @@ pub enum ZonesTargetField {
     Unknown,
 }
 impl ZonesTargetField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::ThresholdHeartRate => "threshold_heart_rate",
+            Self::FunctionalThresholdPower => "functional_threshold_power",
+            Self::HrCalcType => "hr_calc_type",
+            Self::PwrCalcType => "pwr_calc_type",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             1 => Self::MaxHeartRate,
@@ -10157,6 +20507,16 @@ pub enum SportField {
     Unknown,
 }
 impl SportField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::Name => "name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Sport,
@@ -10193,6 +20553,16 @@ pub enum HrZoneField {
     Unknown,
 }
 impl HrZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HighBpm => "high_bpm",
+            Self::Name => "name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10229,6 +20599,16 @@ pub enum SpeedZoneField {
     Unknown,
 }
 impl SpeedZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HighValue => "high_value",
+            Self::Name => "name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10269,6 +20649,16 @@ pub enum CadenceZoneField {
     Unknown,
 }
 impl CadenceZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HighValue => "high_value",
+            Self::Name => "name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10305,6 +20695,16 @@ pub enum PowerZoneField {
     Unknown,
 }
 impl PowerZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HighValue => "high_value",
+            Self::Name => "name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10342,6 +20742,17 @@ pub enum MetZoneField {
     Unknown,
 }
 impl MetZoneField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HighBpm => "high_bpm",
+            Self::Calories => "calories",
+            Self::FatCalories => "fat_calories",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10389,6 +20800,17 @@ pub enum TrainingSettingsField {
     Unknown,
 }
 impl TrainingSettingsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TargetDistance => "target_distance",
+            Self::TargetSpeed => "target_speed",
+            Self::TargetTime => "target_time",
+            Self::PreciseTargetSpeed => "precise_target_speed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             31 => Self::TargetDistance,
@@ -10499,6 +20921,7 @@ impl DiveSettingsFieldHeartRateSourceSubfield {
         Ok(DataMessageField {
             kind: FitField::DiveSettings(DiveSettingsField::HeartRateSource),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -10532,6 +20955,7 @@ impl DiveSettingsFieldHeartRateSourceSubfield {
                                 DiveSettingsField::HeartRateAntplusDeviceType,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -10561,6 +20985,7 @@ impl DiveSettingsFieldHeartRateSourceSubfield {
                                 DiveSettingsField::HeartRateLocalDeviceType,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -10570,6 +20995,50 @@ impl DiveSettingsFieldHeartRateSourceSubfield {
     }
 }
 impl DiveSettingsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::MessageIndex => "message_index",
+            Self::Name => "name",
+            Self::Model => "model",
+            Self::GfLow => "gf_low",
+            Self::GfHigh => "gf_high",
+            Self::WaterType => "water_type",
+            Self::WaterDensity => "water_density",
+            Self::Po2Warn => "po2_warn",
+            Self::Po2Critical => "po2_critical",
+            Self::Po2Deco => "po2_deco",
+            Self::SafetyStopEnabled => "safety_stop_enabled",
+            Self::BottomDepth => "bottom_depth",
+            Self::BottomTime => "bottom_time",
+            Self::ApneaCountdownEnabled => "apnea_countdown_enabled",
+            Self::ApneaCountdownTime => "apnea_countdown_time",
+            Self::BacklightMode => "backlight_mode",
+            Self::BacklightBrightness => "backlight_brightness",
+            Self::BacklightTimeout => "backlight_timeout",
+            Self::RepeatDiveInterval => "repeat_dive_interval",
+            Self::SafetyStopTime => "safety_stop_time",
+            Self::HeartRateSourceType => "heart_rate_source_type",
+            Self::HeartRateSource => "heart_rate_source",
+            Self::HeartRateAntplusDeviceType => "heart_rate_antplus_device_type",
+            Self::HeartRateLocalDeviceType => "heart_rate_local_device_type",
+            Self::TravelGas => "travel_gas",
+            Self::CcrLowSetpointSwitchMode => "ccr_low_setpoint_switch_mode",
+            Self::CcrLowSetpoint => "ccr_low_setpoint",
+            Self::CcrLowSetpointDepth => "ccr_low_setpoint_depth",
+            Self::CcrHighSetpointSwitchMode => "ccr_high_setpoint_switch_mode",
+            Self::CcrHighSetpoint => "ccr_high_setpoint",
+            Self::CcrHighSetpointDepth => "ccr_high_setpoint_depth",
+            Self::GasConsumptionDisplay => "gas_consumption_display",
+            Self::UpKeyEnabled => "up_key_enabled",
+            Self::DiveSounds => "dive_sounds",
+            Self::LastStopMultiple => "last_stop_multiple",
+            Self::NoFlyTimeMode => "no_fly_time_mode",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -10720,6 +21189,26 @@ pub enum DiveAlarmField {
     Unknown,
 }
 impl DiveAlarmField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Depth => "depth",
+            Self::Time => "time",
+            Self::Enabled => "enabled",
+            Self::AlarmType => "alarm_type",
+            Self::Sound => "sound",
+            Self::DiveTypes => "dive_types",
+            Self::Id => "id",
+            Self::PopupEnabled => "popup_enabled",
+            Self::TriggerOnDescent => "trigger_on_descent",
+            Self::TriggerOnAscent => "trigger_on_ascent",
+            Self::Repeating => "repeating",
+            Self::Speed => "speed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10798,6 +21287,26 @@ pub enum DiveApneaAlarmField {
     Unknown,
 }
 impl DiveApneaAlarmField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Depth => "depth",
+            Self::Time => "time",
+            Self::Enabled => "enabled",
+            Self::AlarmType => "alarm_type",
+            Self::Sound => "sound",
+            Self::DiveTypes => "dive_types",
+            Self::Id => "id",
+            Self::PopupEnabled => "popup_enabled",
+            Self::TriggerOnDescent => "trigger_on_descent",
+            Self::TriggerOnAscent => "trigger_on_ascent",
+            Self::Repeating => "repeating",
+            Self::Speed => "speed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10868,6 +21377,18 @@ pub enum DiveGasField {
     Unknown,
 }
 impl DiveGasField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::HeliumContent => "helium_content",
+            Self::OxygenContent => "oxygen_content",
+            Self::Status => "status",
+            Self::Mode => "mode",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10918,6 +21439,26 @@ pub enum GoalField {
     Unknown,
 }
 impl GoalField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::StartDate => "start_date",
+            Self::EndDate => "end_date",
+            Self::Type => "type",
+            Self::Value => "value",
+            Self::Repeat => "repeat",
+            Self::TargetValue => "target_value",
+            Self::Recurrence => "recurrence",
+            Self::RecurrenceValue => "recurrence_value",
+            Self::Enabled => "enabled",
+            Self::Source => "source",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -10979,6 +21520,21 @@ pub enum ActivityField {
     Unknown,
 }
 impl ActivityField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::NumSessions => "num_sessions",
+            Self::Type => "type",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::LocalTimestamp => "local_timestamp",
+            Self::EventGroup => "event_group",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -11211,6 +21767,7 @@ impl SessionFieldTotalCyclesSubfield {
         Ok(DataMessageField {
             kind: FitField::Session(SessionField::TotalCycles),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -11248,6 +21805,7 @@ impl SessionFieldTotalCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Session(SessionField::TotalStrides),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -11289,6 +21847,7 @@ impl SessionFieldTotalCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Session(SessionField::TotalStrokes),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -11322,6 +21881,7 @@ impl SessionFieldAvgCadenceSubfield {
         Ok(DataMessageField {
             kind: FitField::Session(SessionField::AvgCadence),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -11352,6 +21912,7 @@ impl SessionFieldAvgCadenceSubfield {
                     Ok(DataMessageField {
                         kind: FitField::Session(SessionField::AvgRunningCadence),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -11384,6 +21945,7 @@ impl SessionFieldMaxCadenceSubfield {
         Ok(DataMessageField {
             kind: FitField::Session(SessionField::MaxCadence),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -11414,6 +21976,7 @@ impl SessionFieldMaxCadenceSubfield {
                     Ok(DataMessageField {
                         kind: FitField::Session(SessionField::MaxRunningCadence),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -11422,6 +21985,173 @@ impl SessionFieldMaxCadenceSubfield {
     }
 }
 impl SessionField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::StartTime => "start_time",
+            Self::StartPositionLat => "start_position_lat",
+            Self::StartPositionLong => "start_position_long",
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::TotalElapsedTime => "total_elapsed_time",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalDistance => "total_distance",
+            Self::TotalCycles => "total_cycles",
+            Self::TotalStrides => "total_strides",
+            Self::TotalStrokes => "total_strokes",
+            Self::TotalCalories => "total_calories",
+            Self::TotalFatCalories => "total_fat_calories",
+            Self::AvgSpeed => "avg_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::AvgHeartRate => "avg_heart_rate",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::AvgCadence => "avg_cadence",
+            Self::AvgRunningCadence => "avg_running_cadence",
+            Self::MaxCadence => "max_cadence",
+            Self::MaxRunningCadence => "max_running_cadence",
+            Self::AvgPower => "avg_power",
+            Self::MaxPower => "max_power",
+            Self::TotalAscent => "total_ascent",
+            Self::TotalDescent => "total_descent",
+            Self::TotalTrainingEffect => "total_training_effect",
+            Self::FirstLapIndex => "first_lap_index",
+            Self::NumLaps => "num_laps",
+            Self::EventGroup => "event_group",
+            Self::Trigger => "trigger",
+            Self::NecLat => "nec_lat",
+            Self::NecLong => "nec_long",
+            Self::SwcLat => "swc_lat",
+            Self::SwcLong => "swc_long",
+            Self::NumLengths => "num_lengths",
+            Self::NormalizedPower => "normalized_power",
+            Self::TrainingStressScore => "training_stress_score",
+            Self::IntensityFactor => "intensity_factor",
+            Self::LeftRightBalance => "left_right_balance",
+            Self::EndPositionLat => "end_position_lat",
+            Self::EndPositionLong => "end_position_long",
+            Self::AvgStrokeCount => "avg_stroke_count",
+            Self::AvgStrokeDistance => "avg_stroke_distance",
+            Self::SwimStroke => "swim_stroke",
+            Self::PoolLength => "pool_length",
+            Self::ThresholdPower => "threshold_power",
+            Self::PoolLengthUnit => "pool_length_unit",
+            Self::NumActiveLengths => "num_active_lengths",
+            Self::TotalWork => "total_work",
+            Self::AvgAltitude => "avg_altitude",
+            Self::MaxAltitude => "max_altitude",
+            Self::GpsAccuracy => "gps_accuracy",
+            Self::AvgGrade => "avg_grade",
+            Self::AvgPosGrade => "avg_pos_grade",
+            Self::AvgNegGrade => "avg_neg_grade",
+            Self::MaxPosGrade => "max_pos_grade",
+            Self::MaxNegGrade => "max_neg_grade",
+            Self::AvgTemperature => "avg_temperature",
+            Self::MaxTemperature => "max_temperature",
+            Self::TotalMovingTime => "total_moving_time",
+            Self::AvgPosVerticalSpeed => "avg_pos_vertical_speed",
+            Self::AvgNegVerticalSpeed => "avg_neg_vertical_speed",
+            Self::MaxPosVerticalSpeed => "max_pos_vertical_speed",
+            Self::MaxNegVerticalSpeed => "max_neg_vertical_speed",
+            Self::MinHeartRate => "min_heart_rate",
+            Self::TimeInHrZone => "time_in_hr_zone",
+            Self::TimeInSpeedZone => "time_in_speed_zone",
+            Self::TimeInCadenceZone => "time_in_cadence_zone",
+            Self::TimeInPowerZone => "time_in_power_zone",
+            Self::AvgLapTime => "avg_lap_time",
+            Self::BestLapIndex => "best_lap_index",
+            Self::MinAltitude => "min_altitude",
+            Self::PlayerScore => "player_score",
+            Self::OpponentScore => "opponent_score",
+            Self::OpponentName => "opponent_name",
+            Self::StrokeCount => "stroke_count",
+            Self::ZoneCount => "zone_count",
+            Self::MaxBallSpeed => "max_ball_speed",
+            Self::AvgBallSpeed => "avg_ball_speed",
+            Self::AvgVerticalOscillation => "avg_vertical_oscillation",
+            Self::AvgStanceTimePercent => "avg_stance_time_percent",
+            Self::AvgStanceTime => "avg_stance_time",
+            Self::AvgFractionalCadence => "avg_fractional_cadence",
+            Self::MaxFractionalCadence => "max_fractional_cadence",
+            Self::TotalFractionalCycles => "total_fractional_cycles",
+            Self::AvgTotalHemoglobinConc => "avg_total_hemoglobin_conc",
+            Self::MinTotalHemoglobinConc => "min_total_hemoglobin_conc",
+            Self::MaxTotalHemoglobinConc => "max_total_hemoglobin_conc",
+            Self::AvgSaturatedHemoglobinPercent => "avg_saturated_hemoglobin_percent",
+            Self::MinSaturatedHemoglobinPercent => "min_saturated_hemoglobin_percent",
+            Self::MaxSaturatedHemoglobinPercent => "max_saturated_hemoglobin_percent",
+            Self::AvgLeftTorqueEffectiveness => "avg_left_torque_effectiveness",
+            Self::AvgRightTorqueEffectiveness => "avg_right_torque_effectiveness",
+            Self::AvgLeftPedalSmoothness => "avg_left_pedal_smoothness",
+            Self::AvgRightPedalSmoothness => "avg_right_pedal_smoothness",
+            Self::AvgCombinedPedalSmoothness => "avg_combined_pedal_smoothness",
+            Self::SportProfileName => "sport_profile_name",
+            Self::SportIndex => "sport_index",
+            Self::TimeStanding => "time_standing",
+            Self::StandCount => "stand_count",
+            Self::AvgLeftPco => "avg_left_pco",
+            Self::AvgRightPco => "avg_right_pco",
+            Self::AvgLeftPowerPhase => "avg_left_power_phase",
+            Self::AvgLeftPowerPhasePeak => "avg_left_power_phase_peak",
+            Self::AvgRightPowerPhase => "avg_right_power_phase",
+            Self::AvgRightPowerPhasePeak => "avg_right_power_phase_peak",
+            Self::AvgPowerPosition => "avg_power_position",
+            Self::MaxPowerPosition => "max_power_position",
+            Self::AvgCadencePosition => "avg_cadence_position",
+            Self::MaxCadencePosition => "max_cadence_position",
+            Self::EnhancedAvgSpeed => "enhanced_avg_speed",
+            Self::EnhancedMaxSpeed => "enhanced_max_speed",
+            Self::EnhancedAvgAltitude => "enhanced_avg_altitude",
+            Self::EnhancedMinAltitude => "enhanced_min_altitude",
+            Self::EnhancedMaxAltitude => "enhanced_max_altitude",
+            Self::AvgLevMotorPower => "avg_lev_motor_power",
+            Self::MaxLevMotorPower => "max_lev_motor_power",
+            Self::LevBatteryConsumption => "lev_battery_consumption",
+            Self::AvgVerticalRatio => "avg_vertical_ratio",
+            Self::AvgStanceTimeBalance => "avg_stance_time_balance",
+            Self::AvgStepLength => "avg_step_length",
+            Self::TotalAnaerobicTrainingEffect => "total_anaerobic_training_effect",
+            Self::AvgVam => "avg_vam",
+            Self::AvgDepth => "avg_depth",
+            Self::MaxDepth => "max_depth",
+            Self::SurfaceInterval => "surface_interval",
+            Self::StartCns => "start_cns",
+            Self::EndCns => "end_cns",
+            Self::StartN2 => "start_n2",
+            Self::EndN2 => "end_n2",
+            Self::AvgRespirationRate => "avg_respiration_rate",
+            Self::MaxRespirationRate => "max_respiration_rate",
+            Self::MinRespirationRate => "min_respiration_rate",
+            Self::MinTemperature => "min_temperature",
+            Self::O2Toxicity => "o2_toxicity",
+            Self::DiveNumber => "dive_number",
+            Self::TrainingLoadPeak => "training_load_peak",
+            Self::EnhancedAvgRespirationRate => "enhanced_avg_respiration_rate",
+            Self::EnhancedMaxRespirationRate => "enhanced_max_respiration_rate",
+            Self::EnhancedMinRespirationRate => "enhanced_min_respiration_rate",
+            Self::TotalGrit => "total_grit",
+            Self::TotalFlow => "total_flow",
+            Self::JumpCount => "jump_count",
+            Self::AvgGrit => "avg_grit",
+            Self::AvgFlow => "avg_flow",
+            Self::WorkoutFeel => "workout_feel",
+            Self::WorkoutRpe => "workout_rpe",
+            Self::AvgSpo2 => "avg_spo2",
+            Self::AvgStress => "avg_stress",
+            Self::SdrrHrv => "sdrr_hrv",
+            Self::RmssdHrv => "rmssd_hrv",
+            Self::TotalFractionalAscent => "total_fractional_ascent",
+            Self::TotalFractionalDescent => "total_fractional_descent",
+            Self::AvgCoreTemperature => "avg_core_temperature",
+            Self::MinCoreTemperature => "min_core_temperature",
+            Self::MaxCoreTemperature => "max_core_temperature",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -12233,6 +22963,7 @@ impl LapFieldTotalCyclesSubfield {
         Ok(DataMessageField {
             kind: FitField::Lap(LapField::TotalCycles),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -12270,6 +23001,7 @@ impl LapFieldTotalCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Lap(LapField::TotalStrides),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -12311,6 +23043,7 @@ impl LapFieldTotalCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Lap(LapField::TotalStrokes),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -12344,6 +23077,7 @@ impl LapFieldAvgCadenceSubfield {
         Ok(DataMessageField {
             kind: FitField::Lap(LapField::AvgCadence),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -12374,6 +23108,7 @@ impl LapFieldAvgCadenceSubfield {
                     Ok(DataMessageField {
                         kind: FitField::Lap(LapField::AvgRunningCadence),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -12406,6 +23141,7 @@ impl LapFieldMaxCadenceSubfield {
         Ok(DataMessageField {
             kind: FitField::Lap(LapField::MaxCadence),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -12436,6 +23172,7 @@ impl LapFieldMaxCadenceSubfield {
                     Ok(DataMessageField {
                         kind: FitField::Lap(LapField::MaxRunningCadence),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -12444,6 +23181,140 @@ impl LapFieldMaxCadenceSubfield {
     }
 }
 impl LapField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::StartTime => "start_time",
+            Self::StartPositionLat => "start_position_lat",
+            Self::StartPositionLong => "start_position_long",
+            Self::EndPositionLat => "end_position_lat",
+            Self::EndPositionLong => "end_position_long",
+            Self::TotalElapsedTime => "total_elapsed_time",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalDistance => "total_distance",
+            Self::TotalCycles => "total_cycles",
+            Self::TotalStrides => "total_strides",
+            Self::TotalStrokes => "total_strokes",
+            Self::TotalCalories => "total_calories",
+            Self::TotalFatCalories => "total_fat_calories",
+            Self::AvgSpeed => "avg_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::AvgHeartRate => "avg_heart_rate",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::AvgCadence => "avg_cadence",
+            Self::AvgRunningCadence => "avg_running_cadence",
+            Self::MaxCadence => "max_cadence",
+            Self::MaxRunningCadence => "max_running_cadence",
+            Self::AvgPower => "avg_power",
+            Self::MaxPower => "max_power",
+            Self::TotalAscent => "total_ascent",
+            Self::TotalDescent => "total_descent",
+            Self::Intensity => "intensity",
+            Self::LapTrigger => "lap_trigger",
+            Self::Sport => "sport",
+            Self::EventGroup => "event_group",
+            Self::NumLengths => "num_lengths",
+            Self::NormalizedPower => "normalized_power",
+            Self::LeftRightBalance => "left_right_balance",
+            Self::FirstLengthIndex => "first_length_index",
+            Self::AvgStrokeDistance => "avg_stroke_distance",
+            Self::SwimStroke => "swim_stroke",
+            Self::SubSport => "sub_sport",
+            Self::NumActiveLengths => "num_active_lengths",
+            Self::TotalWork => "total_work",
+            Self::AvgAltitude => "avg_altitude",
+            Self::MaxAltitude => "max_altitude",
+            Self::GpsAccuracy => "gps_accuracy",
+            Self::AvgGrade => "avg_grade",
+            Self::AvgPosGrade => "avg_pos_grade",
+            Self::AvgNegGrade => "avg_neg_grade",
+            Self::MaxPosGrade => "max_pos_grade",
+            Self::MaxNegGrade => "max_neg_grade",
+            Self::AvgTemperature => "avg_temperature",
+            Self::MaxTemperature => "max_temperature",
+            Self::TotalMovingTime => "total_moving_time",
+            Self::AvgPosVerticalSpeed => "avg_pos_vertical_speed",
+            Self::AvgNegVerticalSpeed => "avg_neg_vertical_speed",
+            Self::MaxPosVerticalSpeed => "max_pos_vertical_speed",
+            Self::MaxNegVerticalSpeed => "max_neg_vertical_speed",
+            Self::TimeInHrZone => "time_in_hr_zone",
+            Self::TimeInSpeedZone => "time_in_speed_zone",
+            Self::TimeInCadenceZone => "time_in_cadence_zone",
+            Self::TimeInPowerZone => "time_in_power_zone",
+            Self::RepetitionNum => "repetition_num",
+            Self::MinAltitude => "min_altitude",
+            Self::MinHeartRate => "min_heart_rate",
+            Self::WktStepIndex => "wkt_step_index",
+            Self::OpponentScore => "opponent_score",
+            Self::StrokeCount => "stroke_count",
+            Self::ZoneCount => "zone_count",
+            Self::AvgVerticalOscillation => "avg_vertical_oscillation",
+            Self::AvgStanceTimePercent => "avg_stance_time_percent",
+            Self::AvgStanceTime => "avg_stance_time",
+            Self::AvgFractionalCadence => "avg_fractional_cadence",
+            Self::MaxFractionalCadence => "max_fractional_cadence",
+            Self::TotalFractionalCycles => "total_fractional_cycles",
+            Self::PlayerScore => "player_score",
+            Self::AvgTotalHemoglobinConc => "avg_total_hemoglobin_conc",
+            Self::MinTotalHemoglobinConc => "min_total_hemoglobin_conc",
+            Self::MaxTotalHemoglobinConc => "max_total_hemoglobin_conc",
+            Self::AvgSaturatedHemoglobinPercent => "avg_saturated_hemoglobin_percent",
+            Self::MinSaturatedHemoglobinPercent => "min_saturated_hemoglobin_percent",
+            Self::MaxSaturatedHemoglobinPercent => "max_saturated_hemoglobin_percent",
+            Self::AvgLeftTorqueEffectiveness => "avg_left_torque_effectiveness",
+            Self::AvgRightTorqueEffectiveness => "avg_right_torque_effectiveness",
+            Self::AvgLeftPedalSmoothness => "avg_left_pedal_smoothness",
+            Self::AvgRightPedalSmoothness => "avg_right_pedal_smoothness",
+            Self::AvgCombinedPedalSmoothness => "avg_combined_pedal_smoothness",
+            Self::TimeStanding => "time_standing",
+            Self::StandCount => "stand_count",
+            Self::AvgLeftPco => "avg_left_pco",
+            Self::AvgRightPco => "avg_right_pco",
+            Self::AvgLeftPowerPhase => "avg_left_power_phase",
+            Self::AvgLeftPowerPhasePeak => "avg_left_power_phase_peak",
+            Self::AvgRightPowerPhase => "avg_right_power_phase",
+            Self::AvgRightPowerPhasePeak => "avg_right_power_phase_peak",
+            Self::AvgPowerPosition => "avg_power_position",
+            Self::MaxPowerPosition => "max_power_position",
+            Self::AvgCadencePosition => "avg_cadence_position",
+            Self::MaxCadencePosition => "max_cadence_position",
+            Self::EnhancedAvgSpeed => "enhanced_avg_speed",
+            Self::EnhancedMaxSpeed => "enhanced_max_speed",
+            Self::EnhancedAvgAltitude => "enhanced_avg_altitude",
+            Self::EnhancedMinAltitude => "enhanced_min_altitude",
+            Self::EnhancedMaxAltitude => "enhanced_max_altitude",
+            Self::AvgLevMotorPower => "avg_lev_motor_power",
+            Self::MaxLevMotorPower => "max_lev_motor_power",
+            Self::LevBatteryConsumption => "lev_battery_consumption",
+            Self::AvgVerticalRatio => "avg_vertical_ratio",
+            Self::AvgStanceTimeBalance => "avg_stance_time_balance",
+            Self::AvgStepLength => "avg_step_length",
+            Self::AvgVam => "avg_vam",
+            Self::AvgDepth => "avg_depth",
+            Self::MaxDepth => "max_depth",
+            Self::MinTemperature => "min_temperature",
+            Self::EnhancedAvgRespirationRate => "enhanced_avg_respiration_rate",
+            Self::EnhancedMaxRespirationRate => "enhanced_max_respiration_rate",
+            Self::AvgRespirationRate => "avg_respiration_rate",
+            Self::MaxRespirationRate => "max_respiration_rate",
+            Self::TotalGrit => "total_grit",
+            Self::TotalFlow => "total_flow",
+            Self::JumpCount => "jump_count",
+            Self::AvgGrit => "avg_grit",
+            Self::AvgFlow => "avg_flow",
+            Self::TotalFractionalAscent => "total_fractional_ascent",
+            Self::TotalFractionalDescent => "total_fractional_descent",
+            Self::AvgCoreTemperature => "avg_core_temperature",
+            Self::MinCoreTemperature => "min_core_temperature",
+            Self::MaxCoreTemperature => "max_core_temperature",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -12995,6 +23866,35 @@ pub enum LengthField {
     Unknown,
 }
 impl LengthField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::StartTime => "start_time",
+            Self::TotalElapsedTime => "total_elapsed_time",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalStrokes => "total_strokes",
+            Self::AvgSpeed => "avg_speed",
+            Self::SwimStroke => "swim_stroke",
+            Self::AvgSwimmingCadence => "avg_swimming_cadence",
+            Self::EventGroup => "event_group",
+            Self::TotalCalories => "total_calories",
+            Self::LengthType => "length_type",
+            Self::PlayerScore => "player_score",
+            Self::OpponentScore => "opponent_score",
+            Self::StrokeCount => "stroke_count",
+            Self::ZoneCount => "zone_count",
+            Self::EnhancedAvgRespirationRate => "enhanced_avg_respiration_rate",
+            Self::EnhancedMaxRespirationRate => "enhanced_max_respiration_rate",
+            Self::AvgRespirationRate => "avg_respiration_rate",
+            Self::MaxRespirationRate => "max_respiration_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -13170,6 +24070,97 @@ pub enum RecordField {
     Unknown,
 }
 impl RecordField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::Altitude => "altitude",
+            Self::HeartRate => "heart_rate",
+            Self::Cadence => "cadence",
+            Self::Distance => "distance",
+            Self::Speed => "speed",
+            Self::Power => "power",
+            Self::CompressedSpeedDistance => "compressed_speed_distance",
+            Self::Grade => "grade",
+            Self::Resistance => "resistance",
+            Self::TimeFromCourse => "time_from_course",
+            Self::CycleLength => "cycle_length",
+            Self::Temperature => "temperature",
+            Self::Speed1s => "speed_1s",
+            Self::Cycles => "cycles",
+            Self::TotalCycles => "total_cycles",
+            Self::CompressedAccumulatedPower => "compressed_accumulated_power",
+            Self::AccumulatedPower => "accumulated_power",
+            Self::LeftRightBalance => "left_right_balance",
+            Self::GpsAccuracy => "gps_accuracy",
+            Self::VerticalSpeed => "vertical_speed",
+            Self::Calories => "calories",
+            Self::VerticalOscillation => "vertical_oscillation",
+            Self::StanceTimePercent => "stance_time_percent",
+            Self::StanceTime => "stance_time",
+            Self::ActivityType => "activity_type",
+            Self::LeftTorqueEffectiveness => "left_torque_effectiveness",
+            Self::RightTorqueEffectiveness => "right_torque_effectiveness",
+            Self::LeftPedalSmoothness => "left_pedal_smoothness",
+            Self::RightPedalSmoothness => "right_pedal_smoothness",
+            Self::CombinedPedalSmoothness => "combined_pedal_smoothness",
+            Self::Time128 => "time128",
+            Self::StrokeType => "stroke_type",
+            Self::Zone => "zone",
+            Self::BallSpeed => "ball_speed",
+            Self::Cadence256 => "cadence256",
+            Self::FractionalCadence => "fractional_cadence",
+            Self::TotalHemoglobinConc => "total_hemoglobin_conc",
+            Self::TotalHemoglobinConcMin => "total_hemoglobin_conc_min",
+            Self::TotalHemoglobinConcMax => "total_hemoglobin_conc_max",
+            Self::SaturatedHemoglobinPercent => "saturated_hemoglobin_percent",
+            Self::SaturatedHemoglobinPercentMin => "saturated_hemoglobin_percent_min",
+            Self::SaturatedHemoglobinPercentMax => "saturated_hemoglobin_percent_max",
+            Self::DeviceIndex => "device_index",
+            Self::LeftPco => "left_pco",
+            Self::RightPco => "right_pco",
+            Self::LeftPowerPhase => "left_power_phase",
+            Self::LeftPowerPhasePeak => "left_power_phase_peak",
+            Self::RightPowerPhase => "right_power_phase",
+            Self::RightPowerPhasePeak => "right_power_phase_peak",
+            Self::EnhancedSpeed => "enhanced_speed",
+            Self::EnhancedAltitude => "enhanced_altitude",
+            Self::BatterySoc => "battery_soc",
+            Self::MotorPower => "motor_power",
+            Self::VerticalRatio => "vertical_ratio",
+            Self::StanceTimeBalance => "stance_time_balance",
+            Self::StepLength => "step_length",
+            Self::CycleLength16 => "cycle_length16",
+            Self::AbsolutePressure => "absolute_pressure",
+            Self::Depth => "depth",
+            Self::NextStopDepth => "next_stop_depth",
+            Self::NextStopTime => "next_stop_time",
+            Self::TimeToSurface => "time_to_surface",
+            Self::NdlTime => "ndl_time",
+            Self::CnsLoad => "cns_load",
+            Self::N2Load => "n2_load",
+            Self::RespirationRate => "respiration_rate",
+            Self::EnhancedRespirationRate => "enhanced_respiration_rate",
+            Self::Grit => "grit",
+            Self::Flow => "flow",
+            Self::CurrentStress => "current_stress",
+            Self::EbikeTravelRange => "ebike_travel_range",
+            Self::EbikeBatteryLevel => "ebike_battery_level",
+            Self::EbikeAssistMode => "ebike_assist_mode",
+            Self::EbikeAssistLevelPercent => "ebike_assist_level_percent",
+            Self::AirTimeRemaining => "air_time_remaining",
+            Self::PressureSac => "pressure_sac",
+            Self::VolumeSac => "volume_sac",
+            Self::Rmv => "rmv",
+            Self::AscentRate => "ascent_rate",
+            Self::Po2 => "po2",
+            Self::CoreTemperature => "core_temperature",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -13662,6 +24653,7 @@ impl EventFieldDataSubfield {
         Ok(DataMessageField {
             kind: FitField::Event(EventField::Data),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -13693,6 +24685,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::TimerTrigger),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13720,6 +24713,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::CoursePointIndex),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13752,6 +24746,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::BatteryLevel),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -13784,6 +24779,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::VirtualPartnerSpeed),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -13811,6 +24807,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::HrHighAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13838,6 +24835,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::HrLowAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13870,6 +24868,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::SpeedHighAlert),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -13902,6 +24901,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::SpeedLowAlert),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -13929,6 +24929,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::CadHighAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13956,6 +24957,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::CadLowAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -13983,6 +24985,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::PowerHighAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14010,6 +25013,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::PowerLowAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14042,6 +25046,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::TimeDurationAlert),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -14074,6 +25079,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::DistanceDurationAlert),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -14101,6 +25107,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::CalorieDurationAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14129,6 +25136,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::FitnessEquipmentState),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14156,6 +25164,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::SportPoint),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14189,6 +25198,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::GearChangeData),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14216,6 +25226,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::RiderPosition),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14243,6 +25254,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::CommTimeout),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14270,6 +25282,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::DiveAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14297,6 +25310,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::AutoActivityDetectDuration),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14324,6 +25338,7 @@ impl EventFieldDataSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Event(EventField::RadarThreatAlert),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14357,6 +25372,7 @@ impl EventFieldStartTimestampSubfield {
         Ok(DataMessageField {
             kind: FitField::Event(EventField::StartTimestamp),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -14387,6 +25403,7 @@ impl EventFieldStartTimestampSubfield {
                     Ok(DataMessageField {
                         kind: FitField::Event(EventField::AutoActivityDetectStartTimestamp),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -14395,6 +25412,56 @@ impl EventFieldStartTimestampSubfield {
     }
 }
 impl EventField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::Data16 => "data16",
+            Self::Data => "data",
+            Self::TimerTrigger => "timer_trigger",
+            Self::CoursePointIndex => "course_point_index",
+            Self::BatteryLevel => "battery_level",
+            Self::VirtualPartnerSpeed => "virtual_partner_speed",
+            Self::HrHighAlert => "hr_high_alert",
+            Self::HrLowAlert => "hr_low_alert",
+            Self::SpeedHighAlert => "speed_high_alert",
+            Self::SpeedLowAlert => "speed_low_alert",
+            Self::CadHighAlert => "cad_high_alert",
+            Self::CadLowAlert => "cad_low_alert",
+            Self::PowerHighAlert => "power_high_alert",
+            Self::PowerLowAlert => "power_low_alert",
+            Self::TimeDurationAlert => "time_duration_alert",
+            Self::DistanceDurationAlert => "distance_duration_alert",
+            Self::CalorieDurationAlert => "calorie_duration_alert",
+            Self::FitnessEquipmentState => "fitness_equipment_state",
+            Self::SportPoint => "sport_point",
+            Self::GearChangeData => "gear_change_data",
+            Self::RiderPosition => "rider_position",
+            Self::CommTimeout => "comm_timeout",
+            Self::DiveAlert => "dive_alert",
+            Self::AutoActivityDetectDuration => "auto_activity_detect_duration",
+            Self::RadarThreatAlert => "radar_threat_alert",
+            Self::EventGroup => "event_group",
+            Self::Score => "score",
+            Self::OpponentScore => "opponent_score",
+            Self::FrontGearNum => "front_gear_num",
+            Self::FrontGear => "front_gear",
+            Self::RearGearNum => "rear_gear_num",
+            Self::RearGear => "rear_gear",
+            Self::DeviceIndex => "device_index",
+            Self::ActivityType => "activity_type",
+            Self::StartTimestamp => "start_timestamp",
+            Self::AutoActivityDetectStartTimestamp => "auto_activity_detect_start_timestamp",
+            Self::RadarThreatLevelMax => "radar_threat_level_max",
+            Self::RadarThreatCount => "radar_threat_count",
+            Self::RadarThreatAvgApproachSpeed => "radar_threat_avg_approach_speed",
+            Self::RadarThreatMaxApproachSpeed => "radar_threat_max_approach_speed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -14520,6 +25587,7 @@ impl DeviceInfoFieldDeviceTypeSubfield {
         Ok(DataMessageField {
             kind: FitField::DeviceInfo(DeviceInfoField::DeviceType),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -14551,6 +25619,7 @@ impl DeviceInfoFieldDeviceTypeSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::BleDeviceType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14578,6 +25647,7 @@ impl DeviceInfoFieldDeviceTypeSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::AntplusDeviceType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14605,6 +25675,7 @@ impl DeviceInfoFieldDeviceTypeSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::AntDeviceType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14632,6 +25703,7 @@ impl DeviceInfoFieldDeviceTypeSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::LocalDeviceType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14666,6 +25738,7 @@ impl DeviceInfoFieldProductSubfield {
         Ok(DataMessageField {
             kind: FitField::DeviceInfo(DeviceInfoField::Product),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -14697,6 +25770,7 @@ impl DeviceInfoFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::FaveroProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14738,6 +25812,7 @@ impl DeviceInfoFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::DeviceInfo(DeviceInfoField::GarminProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14747,6 +25822,38 @@ impl DeviceInfoFieldProductSubfield {
     }
 }
 impl DeviceInfoField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::DeviceIndex => "device_index",
+            Self::DeviceType => "device_type",
+            Self::BleDeviceType => "ble_device_type",
+            Self::AntplusDeviceType => "antplus_device_type",
+            Self::AntDeviceType => "ant_device_type",
+            Self::LocalDeviceType => "local_device_type",
+            Self::Manufacturer => "manufacturer",
+            Self::SerialNumber => "serial_number",
+            Self::Product => "product",
+            Self::FaveroProduct => "favero_product",
+            Self::GarminProduct => "garmin_product",
+            Self::SoftwareVersion => "software_version",
+            Self::HardwareVersion => "hardware_version",
+            Self::CumOperatingTime => "cum_operating_time",
+            Self::BatteryVoltage => "battery_voltage",
+            Self::BatteryStatus => "battery_status",
+            Self::SensorPosition => "sensor_position",
+            Self::Descriptor => "descriptor",
+            Self::AntTransmissionType => "ant_transmission_type",
+            Self::AntDeviceNumber => "ant_device_number",
+            Self::AntNetwork => "ant_network",
+            Self::SourceType => "source_type",
+            Self::ProductName => "product_name",
+            Self::BatteryLevel => "battery_level",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -14825,6 +25932,18 @@ pub enum DeviceAuxBatteryInfoField {
     Unknown,
 }
 impl DeviceAuxBatteryInfoField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::DeviceIndex => "device_index",
+            Self::BatteryVoltage => "battery_voltage",
+            Self::BatteryStatus => "battery_status",
+            Self::BatteryIdentifier => "battery_identifier",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -14901,6 +26020,7 @@ impl TrainingFileFieldProductSubfield {
         Ok(DataMessageField {
             kind: FitField::TrainingFile(TrainingFileField::Product),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -14932,6 +26052,7 @@ impl TrainingFileFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::TrainingFile(TrainingFileField::FaveroProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14973,6 +26094,7 @@ impl TrainingFileFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::TrainingFile(TrainingFileField::GarminProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -14982,6 +26104,21 @@ impl TrainingFileFieldProductSubfield {
     }
 }
 impl TrainingFileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Type => "type",
+            Self::Manufacturer => "manufacturer",
+            Self::Product => "product",
+            Self::FaveroProduct => "favero_product",
+            Self::GarminProduct => "garmin_product",
+            Self::SerialNumber => "serial_number",
+            Self::TimeCreated => "time_created",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15037,6 +26174,29 @@ pub enum WeatherConditionsField {
     Unknown,
 }
 impl WeatherConditionsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::WeatherReport => "weather_report",
+            Self::Temperature => "temperature",
+            Self::Condition => "condition",
+            Self::WindDirection => "wind_direction",
+            Self::WindSpeed => "wind_speed",
+            Self::PrecipitationProbability => "precipitation_probability",
+            Self::TemperatureFeelsLike => "temperature_feels_like",
+            Self::RelativeHumidity => "relative_humidity",
+            Self::Location => "location",
+            Self::ObservedAtTime => "observed_at_time",
+            Self::ObservedLocationLat => "observed_location_lat",
+            Self::ObservedLocationLong => "observed_location_long",
+            Self::DayOfWeek => "day_of_week",
+            Self::HighTemperature => "high_temperature",
+            Self::LowTemperature => "low_temperature",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15108,6 +26268,19 @@ pub enum WeatherAlertField {
     Unknown,
 }
 impl WeatherAlertField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ReportId => "report_id",
+            Self::IssueTime => "issue_time",
+            Self::ExpireTime => "expire_time",
+            Self::Severity => "severity",
+            Self::Type => "type",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15156,6 +26329,22 @@ pub enum GpsMetadataField {
     Unknown,
 }
 impl GpsMetadataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::EnhancedAltitude => "enhanced_altitude",
+            Self::EnhancedSpeed => "enhanced_speed",
+            Self::Heading => "heading",
+            Self::UtcTimestamp => "utc_timestamp",
+            Self::Velocity => "velocity",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15222,6 +26411,18 @@ pub enum CameraEventField {
     Unknown,
 }
 impl CameraEventField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::CameraEventType => "camera_event_type",
+            Self::CameraFileUuid => "camera_file_uuid",
+            Self::CameraOrientation => "camera_orientation",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15268,6 +26469,22 @@ pub enum GyroscopeDataField {
     Unknown,
 }
 impl GyroscopeDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SampleTimeOffset => "sample_time_offset",
+            Self::GyroX => "gyro_x",
+            Self::GyroY => "gyro_y",
+            Self::GyroZ => "gyro_z",
+            Self::CalibratedGyroX => "calibrated_gyro_x",
+            Self::CalibratedGyroY => "calibrated_gyro_y",
+            Self::CalibratedGyroZ => "calibrated_gyro_z",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15325,6 +26542,25 @@ pub enum AccelerometerDataField {
     Unknown,
 }
 impl AccelerometerDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SampleTimeOffset => "sample_time_offset",
+            Self::AccelX => "accel_x",
+            Self::AccelY => "accel_y",
+            Self::AccelZ => "accel_z",
+            Self::CalibratedAccelX => "calibrated_accel_x",
+            Self::CalibratedAccelY => "calibrated_accel_y",
+            Self::CalibratedAccelZ => "calibrated_accel_z",
+            Self::CompressedCalibratedAccelX => "compressed_calibrated_accel_x",
+            Self::CompressedCalibratedAccelY => "compressed_calibrated_accel_y",
+            Self::CompressedCalibratedAccelZ => "compressed_calibrated_accel_z",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15387,6 +26623,22 @@ pub enum MagnetometerDataField {
     Unknown,
 }
 impl MagnetometerDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SampleTimeOffset => "sample_time_offset",
+            Self::MagX => "mag_x",
+            Self::MagY => "mag_y",
+            Self::MagZ => "mag_z",
+            Self::CalibratedMagX => "calibrated_mag_x",
+            Self::CalibratedMagY => "calibrated_mag_y",
+            Self::CalibratedMagZ => "calibrated_mag_z",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15436,6 +26688,17 @@ pub enum BarometerDataField {
     Unknown,
 }
 impl BarometerDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SampleTimeOffset => "sample_time_offset",
+            Self::BaroPres => "baro_pres",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15509,6 +26772,7 @@ impl ThreeDSensorCalibrationFieldCalibrationFactorSubfield {
                 ThreeDSensorCalibrationField::CalibrationFactor,
             ),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -15542,6 +26806,7 @@ impl ThreeDSensorCalibrationFieldCalibrationFactorSubfield {
                                 ThreeDSensorCalibrationField::AccelCalFactor,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -15571,6 +26836,7 @@ impl ThreeDSensorCalibrationFieldCalibrationFactorSubfield {
                                 ThreeDSensorCalibrationField::GyroCalFactor,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -15580,6 +26846,22 @@ impl ThreeDSensorCalibrationFieldCalibrationFactorSubfield {
     }
 }
 impl ThreeDSensorCalibrationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::SensorType => "sensor_type",
+            Self::CalibrationFactor => "calibration_factor",
+            Self::AccelCalFactor => "accel_cal_factor",
+            Self::GyroCalFactor => "gyro_cal_factor",
+            Self::CalibrationDivisor => "calibration_divisor",
+            Self::LevelShift => "level_shift",
+            Self::OffsetCal => "offset_cal",
+            Self::OrientationMatrix => "orientation_matrix",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15662,6 +26944,7 @@ impl OneDSensorCalibrationFieldCalibrationFactorSubfield {
         Ok(DataMessageField {
             kind: FitField::OneDSensorCalibration(OneDSensorCalibrationField::CalibrationFactor),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -15694,6 +26977,7 @@ impl OneDSensorCalibrationFieldCalibrationFactorSubfield {
                             OneDSensorCalibrationField::BaroCalFactor,
                         ),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -15702,6 +26986,20 @@ impl OneDSensorCalibrationFieldCalibrationFactorSubfield {
     }
 }
 impl OneDSensorCalibrationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::SensorType => "sensor_type",
+            Self::CalibrationFactor => "calibration_factor",
+            Self::BaroCalFactor => "baro_cal_factor",
+            Self::CalibrationDivisor => "calibration_divisor",
+            Self::LevelShift => "level_shift",
+            Self::OffsetCal => "offset_cal",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15746,6 +27044,16 @@ pub enum VideoFrameField {
     Unknown,
 }
 impl VideoFrameField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::FrameNumber => "frame_number",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15788,6 +27096,22 @@ pub enum ObdiiDataField {
     Unknown,
 }
 impl ObdiiDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::TimeOffset => "time_offset",
+            Self::Pid => "pid",
+            Self::RawData => "raw_data",
+            Self::PidDataSize => "pid_data_size",
+            Self::SystemTime => "system_time",
+            Self::StartTimestamp => "start_timestamp",
+            Self::StartTimestampMs => "start_timestamp_ms",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15836,6 +27160,16 @@ pub enum NmeaSentenceField {
     Unknown,
 }
 impl NmeaSentenceField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::Sentence => "sentence",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15881,6 +27215,25 @@ pub enum AviationAttitudeField {
     Unknown,
 }
 impl AviationAttitudeField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SystemTime => "system_time",
+            Self::Pitch => "pitch",
+            Self::Roll => "roll",
+            Self::AccelLateral => "accel_lateral",
+            Self::AccelNormal => "accel_normal",
+            Self::TurnRate => "turn_rate",
+            Self::Stage => "stage",
+            Self::AttitudeStageComplete => "attitude_stage_complete",
+            Self::Track => "track",
+            Self::Validity => "validity",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -15959,6 +27312,16 @@ pub enum VideoField {
     Unknown,
 }
 impl VideoField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Url => "url",
+            Self::HostingProvider => "hosting_provider",
+            Self::Duration => "duration",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Url,
@@ -15995,6 +27358,16 @@ pub enum VideoTitleField {
     Unknown,
 }
 impl VideoTitleField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::MessageCount => "message_count",
+            Self::Text => "text",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16031,6 +27404,16 @@ pub enum VideoDescriptionField {
     Unknown,
 }
 impl VideoDescriptionField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::MessageCount => "message_count",
+            Self::Text => "text",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16071,6 +27454,20 @@ pub enum VideoClipField {
     Unknown,
 }
 impl VideoClipField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ClipNumber => "clip_number",
+            Self::StartTimestamp => "start_timestamp",
+            Self::StartTimestampMs => "start_timestamp_ms",
+            Self::EndTimestamp => "end_timestamp",
+            Self::EndTimestampMs => "end_timestamp_ms",
+            Self::ClipStart => "clip_start",
+            Self::ClipEnd => "clip_end",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ClipNumber,
@@ -16123,6 +27520,24 @@ pub enum SetField {
     Unknown,
 }
 impl SetField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Duration => "duration",
+            Self::Repetitions => "repetitions",
+            Self::Weight => "weight",
+            Self::SetType => "set_type",
+            Self::StartTime => "start_time",
+            Self::Category => "category",
+            Self::CategorySubtype => "category_subtype",
+            Self::WeightDisplayUnit => "weight_display_unit",
+            Self::MessageIndex => "message_index",
+            Self::WktStepIndex => "wkt_step_index",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::Timestamp,
@@ -16190,6 +27605,23 @@ pub enum JumpField {
     Unknown,
 }
 impl JumpField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Distance => "distance",
+            Self::Height => "height",
+            Self::Rotations => "rotations",
+            Self::HangTime => "hang_time",
+            Self::Score => "score",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::Speed => "speed",
+            Self::EnhancedSpeed => "enhanced_speed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -16264,6 +27696,32 @@ pub enum SplitField {
     Unknown,
 }
 impl SplitField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::SplitType => "split_type",
+            Self::TotalElapsedTime => "total_elapsed_time",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalDistance => "total_distance",
+            Self::AvgSpeed => "avg_speed",
+            Self::StartTime => "start_time",
+            Self::TotalAscent => "total_ascent",
+            Self::TotalDescent => "total_descent",
+            Self::StartPositionLat => "start_position_lat",
+            Self::StartPositionLong => "start_position_long",
+            Self::EndPositionLat => "end_position_lat",
+            Self::EndPositionLong => "end_position_long",
+            Self::MaxSpeed => "max_speed",
+            Self::AvgVertSpeed => "avg_vert_speed",
+            Self::EndTime => "end_time",
+            Self::TotalCalories => "total_calories",
+            Self::StartElevation => "start_elevation",
+            Self::TotalMovingTime => "total_moving_time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16375,6 +27833,27 @@ pub enum SplitSummaryField {
     Unknown,
 }
 impl SplitSummaryField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::SplitType => "split_type",
+            Self::NumSplits => "num_splits",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalDistance => "total_distance",
+            Self::AvgSpeed => "avg_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::TotalAscent => "total_ascent",
+            Self::TotalDescent => "total_descent",
+            Self::AvgHeartRate => "avg_heart_rate",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::AvgVertSpeed => "avg_vert_speed",
+            Self::TotalCalories => "total_calories",
+            Self::TotalMovingTime => "total_moving_time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16461,6 +27940,20 @@ pub enum ClimbProField {
     Unknown,
 }
 impl ClimbProField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::ClimbProEvent => "climb_pro_event",
+            Self::ClimbNumber => "climb_number",
+            Self::ClimbCategory => "climb_category",
+            Self::CurrentDist => "current_dist",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -16516,6 +28009,27 @@ pub enum FieldDescriptionField {
     Unknown,
 }
 impl FieldDescriptionField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DeveloperDataIndex => "developer_data_index",
+            Self::FieldDefinitionNumber => "field_definition_number",
+            Self::FitBaseTypeId => "fit_base_type_id",
+            Self::FieldName => "field_name",
+            Self::Array => "array",
+            Self::Components => "components",
+            Self::Scale => "scale",
+            Self::Offset => "offset",
+            Self::Units => "units",
+            Self::Bits => "bits",
+            Self::Accumulate => "accumulate",
+            Self::FitBaseUnitId => "fit_base_unit_id",
+            Self::NativeMesgNum => "native_mesg_num",
+            Self::NativeFieldNum => "native_field_num",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::DeveloperDataIndex,
@@ -16576,6 +28090,18 @@ pub enum DeveloperDataIdField {
     Unknown,
 }
 impl DeveloperDataIdField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DeveloperId => "developer_id",
+            Self::ApplicationId => "application_id",
+            Self::ManufacturerId => "manufacturer_id",
+            Self::DeveloperDataIndex => "developer_data_index",
+            Self::ApplicationVersion => "application_version",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::DeveloperId,
@@ -16617,6 +28143,17 @@ pub enum CourseField {
     Unknown,
 }
 impl CourseField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sport => "sport",
+            Self::Name => "name",
+            Self::Capabilities => "capabilities",
+            Self::SubSport => "sub_sport",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             4 => Self::Sport,
@@ -16660,6 +28197,21 @@ pub enum CoursePointField {
     Unknown,
 }
 impl CoursePointField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::Distance => "distance",
+            Self::Type => "type",
+            Self::Name => "name",
+            Self::Favorite => "favorite",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16716,6 +28268,22 @@ pub enum SegmentIdField {
     Unknown,
 }
 impl SegmentIdField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Uuid => "uuid",
+            Self::Sport => "sport",
+            Self::Enabled => "enabled",
+            Self::UserProfilePrimaryKey => "user_profile_primary_key",
+            Self::DeviceId => "device_id",
+            Self::DefaultRaceLeader => "default_race_leader",
+            Self::DeleteStatus => "delete_status",
+            Self::SelectionType => "selection_type",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Name,
@@ -16768,6 +28336,20 @@ pub enum SegmentLeaderboardEntryField {
     Unknown,
 }
 impl SegmentLeaderboardEntryField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Name => "name",
+            Self::Type => "type",
+            Self::GroupPrimaryKey => "group_primary_key",
+            Self::ActivityId => "activity_id",
+            Self::SegmentTime => "segment_time",
+            Self::ActivityIdString => "activity_id_string",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16820,6 +28402,20 @@ pub enum SegmentPointField {
     Unknown,
 }
 impl SegmentPointField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::PositionLat => "position_lat",
+            Self::PositionLong => "position_long",
+            Self::Distance => "distance",
+            Self::Altitude => "altitude",
+            Self::LeaderTime => "leader_time",
+            Self::EnhancedAltitude => "enhanced_altitude",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -16997,6 +28593,7 @@ impl SegmentLapFieldTotalCyclesSubfield {
         Ok(DataMessageField {
             kind: FitField::SegmentLap(SegmentLapField::TotalCycles),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -17027,6 +28624,7 @@ impl SegmentLapFieldTotalCyclesSubfield {
                     Ok(DataMessageField {
                         kind: FitField::SegmentLap(SegmentLapField::TotalStrokes),
                         values,
+                        is_scaled: false,
                     })
                 }),
                 None => None,
@@ -17035,6 +28633,109 @@ impl SegmentLapFieldTotalCyclesSubfield {
     }
 }
 impl SegmentLapField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::Event => "event",
+            Self::EventType => "event_type",
+            Self::StartTime => "start_time",
+            Self::StartPositionLat => "start_position_lat",
+            Self::StartPositionLong => "start_position_long",
+            Self::EndPositionLat => "end_position_lat",
+            Self::EndPositionLong => "end_position_long",
+            Self::TotalElapsedTime => "total_elapsed_time",
+            Self::TotalTimerTime => "total_timer_time",
+            Self::TotalDistance => "total_distance",
+            Self::TotalCycles => "total_cycles",
+            Self::TotalStrokes => "total_strokes",
+            Self::TotalCalories => "total_calories",
+            Self::TotalFatCalories => "total_fat_calories",
+            Self::AvgSpeed => "avg_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::AvgHeartRate => "avg_heart_rate",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::AvgCadence => "avg_cadence",
+            Self::MaxCadence => "max_cadence",
+            Self::AvgPower => "avg_power",
+            Self::MaxPower => "max_power",
+            Self::TotalAscent => "total_ascent",
+            Self::TotalDescent => "total_descent",
+            Self::Sport => "sport",
+            Self::EventGroup => "event_group",
+            Self::NecLat => "nec_lat",
+            Self::NecLong => "nec_long",
+            Self::SwcLat => "swc_lat",
+            Self::SwcLong => "swc_long",
+            Self::Name => "name",
+            Self::NormalizedPower => "normalized_power",
+            Self::LeftRightBalance => "left_right_balance",
+            Self::SubSport => "sub_sport",
+            Self::TotalWork => "total_work",
+            Self::AvgAltitude => "avg_altitude",
+            Self::MaxAltitude => "max_altitude",
+            Self::GpsAccuracy => "gps_accuracy",
+            Self::AvgGrade => "avg_grade",
+            Self::AvgPosGrade => "avg_pos_grade",
+            Self::AvgNegGrade => "avg_neg_grade",
+            Self::MaxPosGrade => "max_pos_grade",
+            Self::MaxNegGrade => "max_neg_grade",
+            Self::AvgTemperature => "avg_temperature",
+            Self::MaxTemperature => "max_temperature",
+            Self::TotalMovingTime => "total_moving_time",
+            Self::AvgPosVerticalSpeed => "avg_pos_vertical_speed",
+            Self::AvgNegVerticalSpeed => "avg_neg_vertical_speed",
+            Self::MaxPosVerticalSpeed => "max_pos_vertical_speed",
+            Self::MaxNegVerticalSpeed => "max_neg_vertical_speed",
+            Self::TimeInHrZone => "time_in_hr_zone",
+            Self::TimeInSpeedZone => "time_in_speed_zone",
+            Self::TimeInCadenceZone => "time_in_cadence_zone",
+            Self::TimeInPowerZone => "time_in_power_zone",
+            Self::RepetitionNum => "repetition_num",
+            Self::MinAltitude => "min_altitude",
+            Self::MinHeartRate => "min_heart_rate",
+            Self::ActiveTime => "active_time",
+            Self::WktStepIndex => "wkt_step_index",
+            Self::SportEvent => "sport_event",
+            Self::AvgLeftTorqueEffectiveness => "avg_left_torque_effectiveness",
+            Self::AvgRightTorqueEffectiveness => "avg_right_torque_effectiveness",
+            Self::AvgLeftPedalSmoothness => "avg_left_pedal_smoothness",
+            Self::AvgRightPedalSmoothness => "avg_right_pedal_smoothness",
+            Self::AvgCombinedPedalSmoothness => "avg_combined_pedal_smoothness",
+            Self::Status => "status",
+            Self::Uuid => "uuid",
+            Self::AvgFractionalCadence => "avg_fractional_cadence",
+            Self::MaxFractionalCadence => "max_fractional_cadence",
+            Self::TotalFractionalCycles => "total_fractional_cycles",
+            Self::FrontGearShiftCount => "front_gear_shift_count",
+            Self::RearGearShiftCount => "rear_gear_shift_count",
+            Self::TimeStanding => "time_standing",
+            Self::StandCount => "stand_count",
+            Self::AvgLeftPco => "avg_left_pco",
+            Self::AvgRightPco => "avg_right_pco",
+            Self::AvgLeftPowerPhase => "avg_left_power_phase",
+            Self::AvgLeftPowerPhasePeak => "avg_left_power_phase_peak",
+            Self::AvgRightPowerPhase => "avg_right_power_phase",
+            Self::AvgRightPowerPhasePeak => "avg_right_power_phase_peak",
+            Self::AvgPowerPosition => "avg_power_position",
+            Self::MaxPowerPosition => "max_power_position",
+            Self::AvgCadencePosition => "avg_cadence_position",
+            Self::MaxCadencePosition => "max_cadence_position",
+            Self::Manufacturer => "manufacturer",
+            Self::TotalGrit => "total_grit",
+            Self::TotalFlow => "total_flow",
+            Self::AvgGrit => "avg_grit",
+            Self::AvgFlow => "avg_flow",
+            Self::TotalFractionalAscent => "total_fractional_ascent",
+            Self::TotalFractionalDescent => "total_fractional_descent",
+            Self::EnhancedAvgAltitude => "enhanced_avg_altitude",
+            Self::EnhancedMaxAltitude => "enhanced_max_altitude",
+            Self::EnhancedMinAltitude => "enhanced_min_altitude",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -17425,6 +29126,22 @@ pub enum SegmentFileField {
     Unknown,
 }
 impl SegmentFileField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::FileUuid => "file_uuid",
+            Self::Enabled => "enabled",
+            Self::UserProfilePrimaryKey => "user_profile_primary_key",
+            Self::LeaderType => "leader_type",
+            Self::LeaderGroupPrimaryKey => "leader_group_primary_key",
+            Self::LeaderActivityId => "leader_activity_id",
+            Self::LeaderActivityIdString => "leader_activity_id_string",
+            Self::DefaultRaceLeader => "default_race_leader",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -17479,6 +29196,22 @@ pub enum WorkoutField {
     Unknown,
 }
 impl WorkoutField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Sport => "sport",
+            Self::Capabilities => "capabilities",
+            Self::NumValidSteps => "num_valid_steps",
+            Self::WktName => "wkt_name",
+            Self::SubSport => "sub_sport",
+            Self::PoolLength => "pool_length",
+            Self::PoolLengthUnit => "pool_length_unit",
+            Self::WktDescription => "wkt_description",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -17535,6 +29268,20 @@ pub enum WorkoutSessionField {
     Unknown,
 }
 impl WorkoutSessionField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::NumValidSteps => "num_valid_steps",
+            Self::FirstStepIndex => "first_step_index",
+            Self::PoolLength => "pool_length",
+            Self::PoolLengthUnit => "pool_length_unit",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -17668,6 +29415,7 @@ impl WorkoutStepFieldDurationValueSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::DurationValue),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -17710,6 +29458,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationTime),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -17742,6 +29491,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationDistance),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -17775,6 +29525,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationHr),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -17802,6 +29553,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationCalories),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -17873,6 +29625,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationStep),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -17908,6 +29661,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationPower),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -17935,6 +29689,7 @@ impl WorkoutStepFieldDurationValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::DurationReps),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -17978,6 +29733,7 @@ impl WorkoutStepFieldTargetValueSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::TargetValue),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -18009,6 +29765,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::TargetSpeedZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18036,6 +29793,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::TargetHrZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18063,6 +29821,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::TargetCadenceZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18090,6 +29849,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::TargetPowerZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18119,6 +29879,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatSteps),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18151,6 +29912,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatTime),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -18185,6 +29947,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatDistance),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -18214,6 +29977,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatCalories),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18251,6 +30015,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatHr),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18288,6 +30053,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::RepeatPower),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18315,6 +30081,7 @@ impl WorkoutStepFieldTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::TargetStrokeType),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18353,6 +30120,7 @@ impl WorkoutStepFieldCustomTargetValueLowSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetValueLow),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -18389,6 +30157,7 @@ impl WorkoutStepFieldCustomTargetValueLowSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetSpeedLow),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -18416,6 +30185,7 @@ impl WorkoutStepFieldCustomTargetValueLowSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetHeartRateLow),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18443,6 +30213,7 @@ impl WorkoutStepFieldCustomTargetValueLowSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetCadenceLow),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18470,6 +30241,7 @@ impl WorkoutStepFieldCustomTargetValueLowSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetPowerLow),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18508,6 +30280,7 @@ impl WorkoutStepFieldCustomTargetValueHighSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetValueHigh),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -18544,6 +30317,7 @@ impl WorkoutStepFieldCustomTargetValueHighSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetSpeedHigh),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -18573,6 +30347,7 @@ impl WorkoutStepFieldCustomTargetValueHighSubfield {
                                 WorkoutStepField::CustomTargetHeartRateHigh,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18600,6 +30375,7 @@ impl WorkoutStepFieldCustomTargetValueHighSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetCadenceHigh),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18627,6 +30403,7 @@ impl WorkoutStepFieldCustomTargetValueHighSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::CustomTargetPowerHigh),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18666,6 +30443,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryTargetValue),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -18697,6 +30475,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryTargetSpeedZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18724,6 +30503,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryTargetHrZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18753,6 +30533,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
                                 WorkoutStepField::SecondaryTargetCadenceZone,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18780,6 +30561,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
                         Ok(DataMessageField {
                             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryTargetPowerZone),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18809,6 +30591,7 @@ impl WorkoutStepFieldSecondaryTargetValueSubfield {
                                 WorkoutStepField::SecondaryTargetStrokeType,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18847,6 +30630,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueLowSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryCustomTargetValueLow),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -18885,6 +30669,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueLowSubfield {
                                 WorkoutStepField::SecondaryCustomTargetSpeedLow,
                             ),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -18914,6 +30699,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueLowSubfield {
                                 WorkoutStepField::SecondaryCustomTargetHeartRateLow,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18943,6 +30729,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueLowSubfield {
                                 WorkoutStepField::SecondaryCustomTargetCadenceLow,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -18972,6 +30759,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueLowSubfield {
                                 WorkoutStepField::SecondaryCustomTargetPowerLow,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19010,6 +30798,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
         Ok(DataMessageField {
             kind: FitField::WorkoutStep(WorkoutStepField::SecondaryCustomTargetValueHigh),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -19048,6 +30837,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
                                 WorkoutStepField::SecondaryCustomTargetSpeedHigh,
                             ),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -19077,6 +30867,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
                                 WorkoutStepField::SecondaryCustomTargetHeartRateHigh,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19106,6 +30897,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
                                 WorkoutStepField::SecondaryCustomTargetCadenceHigh,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19135,6 +30927,7 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
                                 WorkoutStepField::SecondaryCustomTargetPowerHigh,
                             ),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19144,6 +30937,71 @@ impl WorkoutStepFieldSecondaryCustomTargetValueHighSubfield {
     }
 }
 impl WorkoutStepField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::WktStepName => "wkt_step_name",
+            Self::DurationType => "duration_type",
+            Self::DurationValue => "duration_value",
+            Self::DurationTime => "duration_time",
+            Self::DurationDistance => "duration_distance",
+            Self::DurationHr => "duration_hr",
+            Self::DurationCalories => "duration_calories",
+            Self::DurationStep => "duration_step",
+            Self::DurationPower => "duration_power",
+            Self::DurationReps => "duration_reps",
+            Self::TargetType => "target_type",
+            Self::TargetValue => "target_value",
+            Self::TargetSpeedZone => "target_speed_zone",
+            Self::TargetHrZone => "target_hr_zone",
+            Self::TargetCadenceZone => "target_cadence_zone",
+            Self::TargetPowerZone => "target_power_zone",
+            Self::RepeatSteps => "repeat_steps",
+            Self::RepeatTime => "repeat_time",
+            Self::RepeatDistance => "repeat_distance",
+            Self::RepeatCalories => "repeat_calories",
+            Self::RepeatHr => "repeat_hr",
+            Self::RepeatPower => "repeat_power",
+            Self::TargetStrokeType => "target_stroke_type",
+            Self::CustomTargetValueLow => "custom_target_value_low",
+            Self::CustomTargetSpeedLow => "custom_target_speed_low",
+            Self::CustomTargetHeartRateLow => "custom_target_heart_rate_low",
+            Self::CustomTargetCadenceLow => "custom_target_cadence_low",
+            Self::CustomTargetPowerLow => "custom_target_power_low",
+            Self::CustomTargetValueHigh => "custom_target_value_high",
+            Self::CustomTargetSpeedHigh => "custom_target_speed_high",
+            Self::CustomTargetHeartRateHigh => "custom_target_heart_rate_high",
+            Self::CustomTargetCadenceHigh => "custom_target_cadence_high",
+            Self::CustomTargetPowerHigh => "custom_target_power_high",
+            Self::Intensity => "intensity",
+            Self::Notes => "notes",
+            Self::Equipment => "equipment",
+            Self::ExerciseCategory => "exercise_category",
+            Self::ExerciseName => "exercise_name",
+            Self::ExerciseWeight => "exercise_weight",
+            Self::WeightDisplayUnit => "weight_display_unit",
+            Self::SecondaryTargetType => "secondary_target_type",
+            Self::SecondaryTargetValue => "secondary_target_value",
+            Self::SecondaryTargetSpeedZone => "secondary_target_speed_zone",
+            Self::SecondaryTargetHrZone => "secondary_target_hr_zone",
+            Self::SecondaryTargetCadenceZone => "secondary_target_cadence_zone",
+            Self::SecondaryTargetPowerZone => "secondary_target_power_zone",
+            Self::SecondaryTargetStrokeType => "secondary_target_stroke_type",
+            Self::SecondaryCustomTargetValueLow => "secondary_custom_target_value_low",
+            Self::SecondaryCustomTargetSpeedLow => "secondary_custom_target_speed_low",
+            Self::SecondaryCustomTargetHeartRateLow => "secondary_custom_target_heart_rate_low",
+            Self::SecondaryCustomTargetCadenceLow => "secondary_custom_target_cadence_low",
+            Self::SecondaryCustomTargetPowerLow => "secondary_custom_target_power_low",
+            Self::SecondaryCustomTargetValueHigh => "secondary_custom_target_value_high",
+            Self::SecondaryCustomTargetSpeedHigh => "secondary_custom_target_speed_high",
+            Self::SecondaryCustomTargetHeartRateHigh => "secondary_custom_target_heart_rate_high",
+            Self::SecondaryCustomTargetCadenceHigh => "secondary_custom_target_cadence_high",
+            Self::SecondaryCustomTargetPowerHigh => "secondary_custom_target_power_high",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -19221,6 +31079,17 @@ pub enum ExerciseTitleField {
     Unknown,
 }
 impl ExerciseTitleField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::ExerciseCategory => "exercise_category",
+            Self::ExerciseName => "exercise_name",
+            Self::WktStepName => "wkt_step_name",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -19290,6 +31159,7 @@ impl ScheduleFieldProductSubfield {
         Ok(DataMessageField {
             kind: FitField::Schedule(ScheduleField::Product),
             values,
+            is_scaled: false,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -19321,6 +31191,7 @@ impl ScheduleFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Schedule(ScheduleField::FaveroProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19362,6 +31233,7 @@ impl ScheduleFieldProductSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Schedule(ScheduleField::GarminProduct),
                             values,
+                            is_scaled: false,
                         })
                     }),
                     None => None,
@@ -19371,6 +31243,22 @@ impl ScheduleFieldProductSubfield {
     }
 }
 impl ScheduleField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Manufacturer => "manufacturer",
+            Self::Product => "product",
+            Self::FaveroProduct => "favero_product",
+            Self::GarminProduct => "garmin_product",
+            Self::SerialNumber => "serial_number",
+            Self::TimeCreated => "time_created",
+            Self::Completed => "completed",
+            Self::Type => "type",
+            Self::ScheduledTime => "scheduled_time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Manufacturer,
@@ -19422,6 +31310,23 @@ pub enum TotalsField {
     Unknown,
 }
 impl TotalsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MessageIndex => "message_index",
+            Self::Timestamp => "timestamp",
+            Self::TimerTime => "timer_time",
+            Self::Distance => "distance",
+            Self::Calories => "calories",
+            Self::Sport => "sport",
+            Self::ElapsedTime => "elapsed_time",
+            Self::Sessions => "sessions",
+            Self::ActiveTime => "active_time",
+            Self::SportIndex => "sport_index",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             254 => Self::MessageIndex,
@@ -19483,6 +31388,27 @@ pub enum WeightScaleField {
     Unknown,
 }
 impl WeightScaleField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Weight => "weight",
+            Self::PercentFat => "percent_fat",
+            Self::PercentHydration => "percent_hydration",
+            Self::VisceralFatMass => "visceral_fat_mass",
+            Self::BoneMass => "bone_mass",
+            Self::MuscleMass => "muscle_mass",
+            Self::BasalMet => "basal_met",
+            Self::PhysiqueRating => "physique_rating",
+            Self::ActiveMet => "active_met",
+            Self::MetabolicAge => "metabolic_age",
+            Self::VisceralFatRating => "visceral_fat_rating",
+            Self::UserProfileIndex => "user_profile_index",
+            Self::Bmi => "bmi",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -19585,6 +31511,24 @@ pub enum BloodPressureField {
     Unknown,
 }
 impl BloodPressureField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::SystolicPressure => "systolic_pressure",
+            Self::DiastolicPressure => "diastolic_pressure",
+            Self::MeanArterialPressure => "mean_arterial_pressure",
+            Self::Map3SampleMean => "map_3_sample_mean",
+            Self::MapMorningValues => "map_morning_values",
+            Self::MapEveningValues => "map_evening_values",
+            Self::HeartRate => "heart_rate",
+            Self::HeartRateType => "heart_rate_type",
+            Self::Status => "status",
+            Self::UserProfileIndex => "user_profile_index",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -19640,6 +31584,19 @@ pub enum MonitoringInfoField {
     Unknown,
 }
 impl MonitoringInfoField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::LocalTimestamp => "local_timestamp",
+            Self::ActivityType => "activity_type",
+            Self::CyclesToDistance => "cycles_to_distance",
+            Self::CyclesToCalories => "cycles_to_calories",
+            Self::RestingMetabolicRate => "resting_metabolic_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -19748,6 +31705,7 @@ impl MonitoringFieldCyclesSubfield {
         Ok(DataMessageField {
             kind: FitField::Monitoring(MonitoringField::Cycles),
             values,
+            is_scaled: true,
         })
     }
     fn subfields_parse_functions() -> Vec<
@@ -19790,6 +31748,7 @@ impl MonitoringFieldCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Monitoring(MonitoringField::Steps),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -19828,6 +31787,7 @@ impl MonitoringFieldCyclesSubfield {
                         Ok(DataMessageField {
                             kind: FitField::Monitoring(MonitoringField::Strokes),
                             values,
+                            is_scaled: true,
                         })
                     }),
                     None => None,
@@ -19837,6 +31797,44 @@ impl MonitoringFieldCyclesSubfield {
     }
 }
 impl MonitoringField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::DeviceIndex => "device_index",
+            Self::Calories => "calories",
+            Self::Distance => "distance",
+            Self::Cycles => "cycles",
+            Self::Steps => "steps",
+            Self::Strokes => "strokes",
+            Self::ActiveTime => "active_time",
+            Self::ActivityType => "activity_type",
+            Self::ActivitySubtype => "activity_subtype",
+            Self::ActivityLevel => "activity_level",
+            Self::Distance16 => "distance_16",
+            Self::Cycles16 => "cycles_16",
+            Self::ActiveTime16 => "active_time_16",
+            Self::LocalTimestamp => "local_timestamp",
+            Self::Temperature => "temperature",
+            Self::TemperatureMin => "temperature_min",
+            Self::TemperatureMax => "temperature_max",
+            Self::ActivityTime => "activity_time",
+            Self::ActiveCalories => "active_calories",
+            Self::CurrentActivityTypeIntensity => "current_activity_type_intensity",
+            Self::TimestampMin8 => "timestamp_min_8",
+            Self::Timestamp16 => "timestamp_16",
+            Self::HeartRate => "heart_rate",
+            Self::Intensity => "intensity",
+            Self::DurationMin => "duration_min",
+            Self::Duration => "duration",
+            Self::Ascent => "ascent",
+            Self::Descent => "descent",
+            Self::ModerateActivityMinutes => "moderate_activity_minutes",
+            Self::VigorousActivityMinutes => "vigorous_activity_minutes",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -19961,6 +31959,16 @@ pub enum MonitoringHrDataField {
     Unknown,
 }
 impl MonitoringHrDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::RestingHeartRate => "resting_heart_rate",
+            Self::CurrentDayRestingHeartRate => "current_day_resting_heart_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -19998,6 +32006,17 @@ pub enum Spo2DataField {
     Unknown,
 }
 impl Spo2DataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ReadingSpo2 => "reading_spo2",
+            Self::ReadingConfidence => "reading_confidence",
+            Self::Mode => "mode",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20047,6 +32066,19 @@ pub enum HrField {
     Unknown,
 }
 impl HrField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::FractionalTimestamp => "fractional_timestamp",
+            Self::Time256 => "time256",
+            Self::FilteredBpm => "filtered_bpm",
+            Self::EventTimestamp => "event_timestamp",
+            Self::EventTimestamp12 => "event_timestamp_12",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20100,6 +32132,15 @@ pub enum StressLevelField {
     Unknown,
 }
 impl StressLevelField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::StressLevelValue => "stress_level_value",
+            Self::StressLevelTime => "stress_level_time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::StressLevelValue,
@@ -20139,6 +32180,21 @@ pub enum MaxMetDataField {
     Unknown,
 }
 impl MaxMetDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UpdateTime => "update_time",
+            Self::Vo2Max => "vo2_max",
+            Self::Sport => "sport",
+            Self::SubSport => "sub_sport",
+            Self::MaxMetCategory => "max_met_category",
+            Self::CalibratedData => "calibrated_data",
+            Self::HrSource => "hr_source",
+            Self::SpeedSource => "speed_source",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::UpdateTime,
@@ -20191,6 +32247,18 @@ pub enum HsaBodyBatteryDataField {
     Unknown,
 }
 impl HsaBodyBatteryDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::Level => "level",
+            Self::Charged => "charged",
+            Self::Uncharged => "uncharged",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20232,6 +32300,15 @@ pub enum HsaEventField {
     Unknown,
 }
 impl HsaEventField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::EventId => "event_id",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20270,6 +32347,20 @@ pub enum HsaAccelerometerDataField {
     Unknown,
 }
 impl HsaAccelerometerDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SamplingInterval => "sampling_interval",
+            Self::AccelX => "accel_x",
+            Self::AccelY => "accel_y",
+            Self::AccelZ => "accel_z",
+            Self::Timestamp32k => "timestamp_32k",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20332,6 +32423,20 @@ pub enum HsaGyroscopeDataField {
     Unknown,
 }
 impl HsaGyroscopeDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::SamplingInterval => "sampling_interval",
+            Self::GyroX => "gyro_x",
+            Self::GyroY => "gyro_y",
+            Self::GyroZ => "gyro_z",
+            Self::Timestamp32k => "timestamp_32k",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20388,6 +32493,16 @@ pub enum HsaStepDataField {
     Unknown,
 }
 impl HsaStepDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::Steps => "steps",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20429,6 +32544,17 @@ pub enum HsaSpo2DataField {
     Unknown,
 }
 impl HsaSpo2DataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::ReadingSpo2 => "reading_spo2",
+            Self::Confidence => "confidence",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20467,6 +32593,16 @@ pub enum HsaStressDataField {
     Unknown,
 }
 impl HsaStressDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::StressLevel => "stress_level",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20507,6 +32643,16 @@ pub enum HsaRespirationDataField {
     Unknown,
 }
 impl HsaRespirationDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::RespirationRate => "respiration_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20550,6 +32696,17 @@ pub enum HsaHeartRateDataField {
     Unknown,
 }
 impl HsaHeartRateDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::Status => "status",
+            Self::HeartRate => "heart_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20592,6 +32749,16 @@ pub enum HsaConfigurationDataField {
     Unknown,
 }
 impl HsaConfigurationDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Data => "data",
+            Self::DataSize => "data_size",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20630,6 +32797,16 @@ pub enum HsaWristTemperatureDataField {
     Unknown,
 }
 impl HsaWristTemperatureDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ProcessingInterval => "processing_interval",
+            Self::Value => "value",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20675,6 +32852,19 @@ pub enum MemoGlobField {
     Unknown,
 }
 impl MemoGlobField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PartIndex => "part_index",
+            Self::Memo => "memo",
+            Self::MesgNum => "mesg_num",
+            Self::ParentIndex => "parent_index",
+            Self::FieldNum => "field_num",
+            Self::Data => "data",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             250 => Self::PartIndex,
@@ -20716,6 +32906,15 @@ pub enum SleepLevelField {
     Unknown,
 }
 impl SleepLevelField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::SleepLevel => "sleep_level",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20752,6 +32951,18 @@ pub enum AntChannelIdField {
     Unknown,
 }
 impl AntChannelIdField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ChannelNumber => "channel_number",
+            Self::DeviceType => "device_type",
+            Self::DeviceNumber => "device_number",
+            Self::TransmissionType => "transmission_type",
+            Self::DeviceIndex => "device_index",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ChannelNumber,
@@ -20795,6 +33006,19 @@ pub enum AntRxField {
     Unknown,
 }
 impl AntRxField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::FractionalTimestamp => "fractional_timestamp",
+            Self::MesgId => "mesg_id",
+            Self::MesgData => "mesg_data",
+            Self::ChannelNumber => "channel_number",
+            Self::Data => "data",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20844,6 +33068,19 @@ pub enum AntTxField {
     Unknown,
 }
 impl AntTxField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::FractionalTimestamp => "fractional_timestamp",
+            Self::MesgId => "mesg_id",
+            Self::MesgData => "mesg_data",
+            Self::ChannelNumber => "channel_number",
+            Self::Data => "data",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -20891,6 +33128,17 @@ pub enum ExdScreenConfigurationField {
     Unknown,
 }
 impl ExdScreenConfigurationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ScreenIndex => "screen_index",
+            Self::FieldCount => "field_count",
+            Self::Layout => "layout",
+            Self::ScreenEnabled => "screen_enabled",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ScreenIndex,
@@ -20932,6 +33180,19 @@ pub enum ExdDataFieldConfigurationField {
     Unknown,
 }
 impl ExdDataFieldConfigurationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ScreenIndex => "screen_index",
+            Self::ConceptField => "concept_field",
+            Self::FieldId => "field_id",
+            Self::ConceptCount => "concept_count",
+            Self::DisplayType => "display_type",
+            Self::Title => "title",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ScreenIndex,
@@ -20982,6 +33243,24 @@ pub enum ExdDataConceptConfigurationField {
     Unknown,
 }
 impl ExdDataConceptConfigurationField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ScreenIndex => "screen_index",
+            Self::ConceptField => "concept_field",
+            Self::FieldId => "field_id",
+            Self::ConceptIndex => "concept_index",
+            Self::DataPage => "data_page",
+            Self::ConceptKey => "concept_key",
+            Self::Scaling => "scaling",
+            Self::DataUnits => "data_units",
+            Self::Qualifier => "qualifier",
+            Self::Descriptor => "descriptor",
+            Self::IsSigned => "is_signed",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::ScreenIndex,
@@ -21054,6 +33333,36 @@ pub enum DiveSummaryField {
     Unknown,
 }
 impl DiveSummaryField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ReferenceMesg => "reference_mesg",
+            Self::ReferenceIndex => "reference_index",
+            Self::AvgDepth => "avg_depth",
+            Self::MaxDepth => "max_depth",
+            Self::SurfaceInterval => "surface_interval",
+            Self::StartCns => "start_cns",
+            Self::EndCns => "end_cns",
+            Self::StartN2 => "start_n2",
+            Self::EndN2 => "end_n2",
+            Self::O2Toxicity => "o2_toxicity",
+            Self::DiveNumber => "dive_number",
+            Self::BottomTime => "bottom_time",
+            Self::AvgPressureSac => "avg_pressure_sac",
+            Self::AvgVolumeSac => "avg_volume_sac",
+            Self::AvgRmv => "avg_rmv",
+            Self::DescentTime => "descent_time",
+            Self::AscentTime => "ascent_time",
+            Self::AvgAscentRate => "avg_ascent_rate",
+            Self::AvgDescentRate => "avg_descent_rate",
+            Self::MaxAscentRate => "max_ascent_rate",
+            Self::MaxDescentRate => "max_descent_rate",
+            Self::HangTime => "hang_time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21205,6 +33514,19 @@ pub enum AadAccelFeaturesField {
     Unknown,
 }
 impl AadAccelFeaturesField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Time => "time",
+            Self::EnergyTotal => "energy_total",
+            Self::ZeroCrossCnt => "zero_cross_cnt",
+            Self::Instance => "instance",
+            Self::TimeAboveThreshold => "time_above_threshold",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21249,6 +33571,14 @@ pub enum HrvField {
     Unknown,
 }
 impl HrvField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Time => "time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::Time,
@@ -21285,6 +33615,16 @@ pub enum BeatIntervalsField {
     Unknown,
 }
 impl BeatIntervalsField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::Time => "time",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21326,6 +33666,21 @@ pub enum HrvStatusSummaryField {
     Unknown,
 }
 impl HrvStatusSummaryField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::WeeklyAverage => "weekly_average",
+            Self::LastNightAverage => "last_night_average",
+            Self::LastNight5MinHigh => "last_night_5_min_high",
+            Self::BaselineLowUpper => "baseline_low_upper",
+            Self::BaselineBalancedLower => "baseline_balanced_lower",
+            Self::BaselineBalancedUpper => "baseline_balanced_upper",
+            Self::Status => "status",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21395,6 +33750,15 @@ pub enum HrvValueField {
     Unknown,
 }
 impl HrvValueField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Value => "value",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21436,6 +33800,19 @@ pub enum RawBbiField {
     Unknown,
 }
 impl RawBbiField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::TimestampMs => "timestamp_ms",
+            Self::Data => "data",
+            Self::Time => "time",
+            Self::Quality => "quality",
+            Self::Gap => "gap",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21477,6 +33854,15 @@ pub enum RespirationRateField {
     Unknown,
 }
 impl RespirationRateField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::RespirationRate => "respiration_rate",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21520,6 +33906,21 @@ pub enum ChronoShotSessionField {
     Unknown,
 }
 impl ChronoShotSessionField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::MinSpeed => "min_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::AvgSpeed => "avg_speed",
+            Self::ShotCount => "shot_count",
+            Self::ProjectileType => "projectile_type",
+            Self::GrainWeight => "grain_weight",
+            Self::StandardDeviation => "standard_deviation",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21588,6 +33989,16 @@ pub enum ChronoShotDataField {
     Unknown,
 }
 impl ChronoShotDataField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::ShotSpeed => "shot_speed",
+            Self::ShotNum => "shot_num",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21628,6 +34039,16 @@ pub enum TankUpdateField {
     Unknown,
 }
 impl TankUpdateField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Sensor => "sensor",
+            Self::Pressure => "pressure",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21670,6 +34091,18 @@ pub enum TankSummaryField {
     Unknown,
 }
 impl TankSummaryField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Sensor => "sensor",
+            Self::StartPressure => "start_pressure",
+            Self::EndPressure => "end_pressure",
+            Self::VolumeUsed => "volume_used",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21733,6 +34166,27 @@ pub enum SleepAssessmentField {
     Unknown,
 }
 impl SleepAssessmentField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CombinedAwakeScore => "combined_awake_score",
+            Self::AwakeTimeScore => "awake_time_score",
+            Self::AwakeningsCountScore => "awakenings_count_score",
+            Self::DeepSleepScore => "deep_sleep_score",
+            Self::SleepDurationScore => "sleep_duration_score",
+            Self::LightSleepScore => "light_sleep_score",
+            Self::OverallSleepScore => "overall_sleep_score",
+            Self::SleepQualityScore => "sleep_quality_score",
+            Self::SleepRecoveryScore => "sleep_recovery_score",
+            Self::RemSleepScore => "rem_sleep_score",
+            Self::SleepRestlessnessScore => "sleep_restlessness_score",
+            Self::AwakeningsCount => "awakenings_count",
+            Self::InterruptionsScore => "interruptions_score",
+            Self::AverageStressDuringSleep => "average_stress_during_sleep",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             0 => Self::CombinedAwakeScore,
@@ -21797,6 +34251,18 @@ pub enum SkinTempOvernightField {
     Unknown,
 }
 impl SkinTempOvernightField {
+    /// Name of the field in the FIT profile.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::LocalTimestamp => "local_timestamp",
+            Self::AverageDeviation => "average_deviation",
+            Self::Average7DayDeviation => "average_7_day_deviation",
+            Self::NightlyValue => "nightly_value",
+            Self::Unknown => "unknown",
+        }
+    }
+
     fn from(definition_field: u8) -> Self {
         match definition_field {
             253 => Self::Timestamp,
@@ -21831,3 +34297,144 @@ impl SkinTempOvernightField {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! assert_str_helpers {
+        ($enum:ident, $count:expr) => {
+            assert_eq!($enum::all().len(), $count, "{}", stringify!($enum));
+            for variant in $enum::all() {
+                assert_eq!(variant.as_str().parse::<$enum>().as_ref(), Ok(variant));
+            }
+        };
+    }
+
+    #[test]
+    fn test_enums_str_helpers() {
+        assert_str_helpers!(File, 18);
+        assert_str_helpers!(MesgNum, 120);
+        assert_str_helpers!(FileFlags, 3);
+        assert_str_helpers!(MesgCount, 3);
+        assert_str_helpers!(MessageIndex, 3);
+        assert_str_helpers!(DeviceIndex, 1);
+        assert_str_helpers!(Gender, 2);
+        assert_str_helpers!(Language, 39);
+        assert_str_helpers!(DisplayMeasure, 3);
+        assert_str_helpers!(DisplayHeart, 3);
+        assert_str_helpers!(DisplayPower, 2);
+        assert_str_helpers!(DisplayPosition, 42);
+        assert_str_helpers!(Switch, 3);
+        assert_str_helpers!(Sport, 69);
+        assert_str_helpers!(SportBits0, 8);
+        assert_str_helpers!(SubSport, 89);
+        assert_str_helpers!(SportEvent, 9);
+        assert_str_helpers!(Activity, 2);
+        assert_str_helpers!(Intensity, 7);
+        assert_str_helpers!(SessionTrigger, 4);
+        assert_str_helpers!(LapTrigger, 9);
+        assert_str_helpers!(TimeMode, 6);
+        assert_str_helpers!(BacklightMode, 7);
+        assert_str_helpers!(DateMode, 2);
+        assert_str_helpers!(BacklightTimeout, 1);
+        assert_str_helpers!(Event, 46);
+        assert_str_helpers!(EventType, 10);
+        assert_str_helpers!(TimerTrigger, 3);
+        assert_str_helpers!(FitnessEquipmentState, 4);
+        assert_str_helpers!(Tone, 4);
+        assert_str_helpers!(ActivityClass, 3);
+        assert_str_helpers!(HrZoneCalc, 4);
+        assert_str_helpers!(PwrZoneCalc, 2);
+        assert_str_helpers!(WktStepDuration, 31);
+        assert_str_helpers!(WktStepTarget, 14);
+        assert_str_helpers!(Goal, 7);
+        assert_str_helpers!(GoalRecurrence, 6);
+        assert_str_helpers!(GoalSource, 3);
+        assert_str_helpers!(Schedule, 2);
+        assert_str_helpers!(CoursePoint, 53);
+        assert_str_helpers!(Manufacturer, 232);
+        assert_str_helpers!(GarminProduct, 460);
+        assert_str_helpers!(AntplusDeviceType, 25);
+        assert_str_helpers!(AntNetwork, 4);
+        assert_str_helpers!(WorkoutCapabilities, 14);
+        assert_str_helpers!(BatteryStatus, 7);
+        assert_str_helpers!(HrType, 2);
+        assert_str_helpers!(CourseCapabilities, 12);
+        assert_str_helpers!(Weight, 1);
+        assert_str_helpers!(WorkoutHr, 1);
+        assert_str_helpers!(WorkoutPower, 1);
+        assert_str_helpers!(BpStatus, 5);
+        assert_str_helpers!(UserLocalId, 6);
+        assert_str_helpers!(SwimStroke, 9);
+        assert_str_helpers!(ActivityType, 9);
+        assert_str_helpers!(ActivitySubtype, 20);
+        assert_str_helpers!(ActivityLevel, 3);
+        assert_str_helpers!(Side, 2);
+        assert_str_helpers!(LeftRightBalance, 2);
+        assert_str_helpers!(LeftRightBalance100, 2);
+        assert_str_helpers!(LengthType, 2);
+        assert_str_helpers!(DayOfWeek, 7);
+        assert_str_helpers!(ConnectivityCapabilities, 32);
+        assert_str_helpers!(WeatherReport, 4);
+        assert_str_helpers!(WeatherStatus, 21);
+        assert_str_helpers!(WeatherSeverity, 5);
+        assert_str_helpers!(WeatherSevereType, 85);
+        assert_str_helpers!(LocaltimeIntoDay, 0);
+        assert_str_helpers!(StrokeType, 6);
+        assert_str_helpers!(BodyLocation, 40);
+        assert_str_helpers!(SegmentLapStatus, 2);
+        assert_str_helpers!(SegmentLeaderboardType, 15);
+        assert_str_helpers!(SegmentDeleteStatus, 3);
+        assert_str_helpers!(SegmentSelectionType, 2);
+        assert_str_helpers!(SourceType, 6);
+        assert_str_helpers!(LocalDeviceType, 8);
+        assert_str_helpers!(BleDeviceType, 8);
+        assert_str_helpers!(AntChannelId, 4);
+        assert_str_helpers!(DisplayOrientation, 5);
+        assert_str_helpers!(WorkoutEquipment, 6);
+        assert_str_helpers!(WatchfaceMode, 4);
+        assert_str_helpers!(DigitalWatchfaceLayout, 3);
+        assert_str_helpers!(AnalogWatchfaceLayout, 3);
+        assert_str_helpers!(RiderPositionType, 4);
+        assert_str_helpers!(CameraEventType, 13);
+        assert_str_helpers!(SensorType, 4);
+        assert_str_helpers!(CommTimeoutType, 4);
+        assert_str_helpers!(CameraOrientationType, 4);
+        assert_str_helpers!(AttitudeStage, 4);
+        assert_str_helpers!(AttitudeValidity, 13);
+        assert_str_helpers!(AutoSyncFrequency, 5);
+        assert_str_helpers!(ExdLayout, 9);
+        assert_str_helpers!(ExdDisplayType, 11);
+        assert_str_helpers!(ExdDataUnits, 50);
+        assert_str_helpers!(ExdQualifiers, 44);
+        assert_str_helpers!(ExdDescriptors, 97);
+        assert_str_helpers!(AutoActivityDetect, 7);
+        assert_str_helpers!(FitBaseType, 17);
+        assert_str_helpers!(FitBaseUnit, 3);
+        assert_str_helpers!(SetType, 2);
+        assert_str_helpers!(MaxMetCategory, 2);
+        assert_str_helpers!(ExerciseCategory, 53);
+        assert_str_helpers!(WaterType, 4);
+        assert_str_helpers!(TissueModelType, 1);
+        assert_str_helpers!(DiveGasStatus, 3);
+        assert_str_helpers!(DiveAlert, 39);
+        assert_str_helpers!(DiveAlarmType, 3);
+        assert_str_helpers!(DiveBacklightMode, 2);
+        assert_str_helpers!(SleepLevel, 5);
+        assert_str_helpers!(Spo2MeasurementType, 4);
+        assert_str_helpers!(CcrSetpointSwitchMode, 2);
+        assert_str_helpers!(DiveGasMode, 2);
+        assert_str_helpers!(ProjectileType, 6);
+        assert_str_helpers!(FaveroProduct, 2);
+        assert_str_helpers!(SplitType, 21);
+        assert_str_helpers!(ClimbProEvent, 3);
+        assert_str_helpers!(GasConsumptionRateType, 3);
+        assert_str_helpers!(TapSensitivity, 3);
+        assert_str_helpers!(RadarThreatLevelType, 4);
+        assert_str_helpers!(MaxMetSpeedSource, 3);
+        assert_str_helpers!(MaxMetHeartRateSource, 2);
+        assert_str_helpers!(HrvStatus, 5);
+        assert_str_helpers!(NoFlyTimeMode, 2);
+    }
+}
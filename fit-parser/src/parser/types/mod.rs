@@ -42,13 +42,62 @@ pub enum DataTypeError {
     InvalidUtf8,
     #[error("Error while trying to read bytes from content")]
     ReaderError(#[from] ReaderError),
+    #[error("Field announces {0} values, more than the maximum of {1}")]
+    TooManyValues(usize, usize),
 }
 
-fn number_of_values(type_size: u8, bytes_to_read: u8) -> Result<u8, DataTypeError> {
+/// Name that is not one of the known variants of a profile enum, returned when parsing one of
+/// the [generated] enums with [str::parse].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown {enum_name} variant: {name}")]
+pub struct UnknownVariantError {
+    enum_name: &'static str,
+    name: String,
+}
+
+impl UnknownVariantError {
+    pub fn new(enum_name: &'static str, name: &str) -> Self {
+        Self {
+            enum_name,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Fail before reading a field announcing more values than allowed by
+/// [Reader::max_field_values].
+pub fn check_number_of_values(
+    content: &Reader,
+    number_of_values: usize,
+) -> Result<(), DataTypeError> {
+    if number_of_values > content.max_field_values() {
+        return Err(DataTypeError::TooManyValues(
+            number_of_values,
+            content.max_field_values(),
+        ));
+    }
+    Ok(())
+}
+
+fn number_of_values(
+    content: &Reader,
+    type_size: u8,
+    bytes_to_read: u8,
+) -> Result<u8, DataTypeError> {
     if !bytes_to_read.is_multiple_of(type_size) {
         return Err(DataTypeError::DataNotAligned(bytes_to_read, type_size));
     }
-    Ok(bytes_to_read / type_size)
+    let number_of_values = bytes_to_read / type_size;
+    check_number_of_values(content, number_of_values as usize)?;
+    Ok(number_of_values)
 }
 
 pub fn parse_uint8(
@@ -56,7 +105,7 @@ pub fn parse_uint8(
     _endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(1, bytes)?;
+    let number_of_values = number_of_values(content, 1, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -71,7 +120,7 @@ pub fn parse_uint16(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(2, bytes)?;
+    let number_of_values = number_of_values(content, 2, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -86,7 +135,7 @@ pub fn parse_uint32(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(4, bytes)?;
+    let number_of_values = number_of_values(content, 4, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -101,7 +150,7 @@ pub fn parse_uint64(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(8, bytes)?;
+    let number_of_values = number_of_values(content, 8, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -116,7 +165,7 @@ pub fn parse_uint8z(
     _endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(1, bytes)?;
+    let number_of_values = number_of_values(content, 1, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -131,7 +180,7 @@ pub fn parse_uint16z(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(2, bytes)?;
+    let number_of_values = number_of_values(content, 2, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -146,7 +195,7 @@ pub fn parse_uint32z(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(4, bytes)?;
+    let number_of_values = number_of_values(content, 4, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -161,7 +210,7 @@ pub fn parse_uint64z(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(8, bytes)?;
+    let number_of_values = number_of_values(content, 8, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -176,7 +225,7 @@ pub fn parse_sint8(
     _endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(1, bytes)?;
+    let number_of_values = number_of_values(content, 1, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -191,7 +240,7 @@ pub fn parse_sint16(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(2, bytes)?;
+    let number_of_values = number_of_values(content, 2, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -209,7 +258,7 @@ pub fn parse_sint32(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(4, bytes)?;
+    let number_of_values = number_of_values(content, 4, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -237,7 +286,7 @@ pub fn parse_sint64(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(8, bytes)?;
+    let number_of_values = number_of_values(content, 8, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -273,7 +322,7 @@ pub fn parse_float32(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(4, bytes)?;
+    let number_of_values = number_of_values(content, 4, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -290,7 +339,7 @@ pub fn parse_float64(
     endianness: &Endianness,
     bytes: u8,
 ) -> Result<Vec<DataValue>, DataTypeError> {
-    let number_of_values = number_of_values(8, bytes)?;
+    let number_of_values = number_of_values(content, 8, bytes)?;
     let mut values = Vec::new();
 
     for _ in 0..number_of_values {
@@ -376,6 +425,10 @@ impl DataValue {
         }
     }
 
+    /// Apply the profile scale and offset to a numeric value. Invalid values, as detected by
+    /// [DataValue::is_invalid] before scaling, are returned unchanged: integer sentinels keep their
+    /// raw value and invalid floats stay NaN, which callers serializing to JSON should map to
+    /// `null`.
     pub fn apply_scale_offset(
         &self,
         scale_offset: &Option<ScaleOffset>,
@@ -391,6 +444,9 @@ impl DataValue {
         if *scale == 0. {
             return Err(RecordError::ScaleByZeroError);
         }
+        if !scale.is_finite() || !offset.is_finite() {
+            return Err(RecordError::InvalidScaleOffset(*scale, *offset));
+        }
 
         match self {
             Self::Sint8(val) => Ok(Self::Float32((*val as f32) / *scale - *offset)),
@@ -429,6 +485,115 @@ impl DataValue {
             val => Ok(val.clone()),
         }
     }
+
+    /// Rescale a numeric value as `value * factor + offset`, e.g. `rescale(3.6, 0.)` to convert a
+    /// speed from m/s to km/h. 64 bits values become [DataValue::Float64] and other numeric values
+    /// [DataValue::Float32]. Invalid values, [DataValue::DateTime] and non-numeric values are
+    /// returned unchanged.
+    pub fn rescale(&self, factor: f64, offset: f64) -> DataValue {
+        if self.is_invalid() {
+            return self.clone();
+        }
+
+        let rescale = |val: f64| val * factor + offset;
+        match self {
+            Self::Sint8(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Sint16(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Sint32(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Uint8(val) | Self::Uint8z(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Uint16(val) | Self::Uint16z(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Uint32(val) | Self::Uint32z(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Float32(val) => Self::Float32(rescale(*val as f64) as f32),
+            Self::Sint64(val) => Self::Float64(rescale(*val as f64)),
+            Self::Uint64(val) | Self::Uint64z(val) => Self::Float64(rescale(*val as f64)),
+            Self::Float64(val) => Self::Float64(rescale(*val)),
+            val => val.clone(),
+        }
+    }
+
+    /// Smallest integer [DataValue] exactly holding an integral float, e.g. `Uint16(300)` for
+    /// `Float32(300.)`. Unsigned types are used for positive values, and a type is skipped when the
+    /// value is its invalid sentinel (e.g. `255` is a `Uint16`). Any other value, including
+    /// non-integral or non-finite floats, is returned unchanged.
+    pub fn to_integral(&self) -> DataValue {
+        let value = match self {
+            Self::Float32(val) => *val as f64,
+            Self::Float64(val) => *val,
+            _ => return self.clone(),
+        };
+        if !value.is_finite() || value.fract() != 0. {
+            return self.clone();
+        }
+
+        if value >= 0. {
+            match value {
+                val if val < u8::MAX as f64 => Self::Uint8(val as u8),
+                val if val < u16::MAX as f64 => Self::Uint16(val as u16),
+                val if val < u32::MAX as f64 => Self::Uint32(val as u32),
+                val if val < u64::MAX as f64 => Self::Uint64(val as u64),
+                _ => self.clone(),
+            }
+        } else {
+            match value {
+                val if val >= i8::MIN as f64 => Self::Sint8(val as i8),
+                val if val >= i16::MIN as f64 => Self::Sint16(val as i16),
+                val if val >= i32::MIN as f64 => Self::Sint32(val as i32),
+                val if val >= i64::MIN as f64 => Self::Sint64(val as i64),
+                _ => self.clone(),
+            }
+        }
+    }
+
+    /// Numeric value of an integer, float or [DataValue::DateTime] (as its raw number of seconds),
+    /// e.g. for charting. Returns `None` for invalid values (see [DataValue::is_invalid]), so that
+    /// sentinels like `0xFFFF` are never mistaken for actual values, and for
+    /// [DataValue::String], [DataValue::Byte], [DataValue::Enum] and [DataValue::Unknown].
+    pub fn as_f64(&self) -> Option<f64> {
+        if self.is_invalid() {
+            return None;
+        }
+
+        match self {
+            Self::Sint8(val) => Some(*val as f64),
+            Self::Sint16(val) => Some(*val as f64),
+            Self::Sint32(val) => Some(*val as f64),
+            Self::Sint64(val) => Some(*val as f64),
+            Self::Uint8(val) | Self::Uint8z(val) => Some(*val as f64),
+            Self::Uint16(val) | Self::Uint16z(val) => Some(*val as f64),
+            Self::Uint32(val) | Self::Uint32z(val) => Some(*val as f64),
+            Self::Uint64(val) | Self::Uint64z(val) => Some(*val as f64),
+            Self::Float32(val) => Some(*val as f64),
+            Self::Float64(val) => Some(*val),
+            Self::DateTime(val) => Some(*val as f64),
+            Self::String(_) | Self::Byte(_) | Self::Enum(_) | Self::Unknown(_) => None,
+        }
+    }
+
+    /// Instant of a [DataValue::DateTime], shifted from the FIT epoch (see
+    /// [crate::utils::FIT_EPOCH_OFFSET]). Returns `None` for the invalid `0xFFFFFFFF` value, for
+    /// system times that are not relative to the FIT epoch (see [crate::utils::FIT_DATETIME_MIN])
+    /// and for any other variant.
+    #[cfg(feature = "chrono")]
+    pub fn as_datetime_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::DateTime(u32::MAX) => None,
+            Self::DateTime(val) => {
+                let timestamp = crate::utils::fit_datetime_to_unix_timestamp(*val)?;
+                chrono::DateTime::from_timestamp(timestamp, 0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Raw bytes behind a [DataValue::Byte], a [DataValue::String] (as its UTF-8 bytes) or a
+    /// [DataValue::Unknown]. Returns `None` for any other variant.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Byte(val) | Self::Unknown(val) => Some(val),
+            Self::String(val) => Some(val.as_bytes()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -439,10 +604,47 @@ pub struct ScaleOffset {
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::types::generated::Activity;
+    use crate::parser::types::generated::{
+        Activity, DiveGasStatus, File, LocalDateTime, MesgNum, Sport, Switch,
+    };
 
     use super::*;
 
+    #[test]
+    fn test_switch_bool_helpers() {
+        assert!(Switch::On.is_on());
+        assert!(!Switch::Off.is_on());
+        assert_eq!(Switch::On.as_bool(), Some(true));
+        assert_eq!(Switch::Off.as_bool(), Some(false));
+        assert_eq!(Switch::Auto.as_bool(), None);
+        assert_eq!(Switch::from_bool(true), Switch::On);
+        assert_eq!(DiveGasStatus::from_bool(false), DiveGasStatus::Disabled);
+    }
+
+    #[test]
+    fn test_sport_str_helpers() {
+        assert_eq!(Sport::all().first(), Some(&Sport::Generic));
+        assert!(Sport::all().contains(&Sport::CrossCountrySkiing));
+        assert!(!Sport::all().contains(&Sport::UnknownVariant(200)));
+        assert_eq!(Sport::CrossCountrySkiing.as_str(), "CrossCountrySkiing");
+        assert_eq!(
+            "CrossCountrySkiing".parse::<Sport>(),
+            Ok(Sport::CrossCountrySkiing)
+        );
+        assert_eq!(Sport::UnknownVariant(200).as_str(), "UnknownVariant");
+        assert_eq!(
+            "UnknownVariant".parse::<Sport>(),
+            Err(UnknownVariantError::new("Sport", "UnknownVariant"))
+        );
+        let err = "cross_country_skiing".parse::<Sport>().unwrap_err();
+        assert_eq!(err.enum_name(), "Sport");
+        assert_eq!(err.name(), "cross_country_skiing");
+        assert_eq!(
+            err.to_string(),
+            "Unknown Sport variant: cross_country_skiing"
+        );
+    }
+
     #[test]
     fn test_data_value_enum_invalid() {
         assert!(!DataValue::Enum(FitEnum::Activity(Activity::AutoMultiSport)).is_invalid());
@@ -530,6 +732,127 @@ mod tests {
         assert!(DataValue::Unknown(vec![]).is_invalid());
     }
 
+    #[test]
+    fn test_data_value_sint_as_f64() {
+        assert_eq!(DataValue::Sint8(-12).as_f64(), Some(-12.));
+        assert_eq!(DataValue::Sint8(0x7F).as_f64(), None);
+
+        assert_eq!(DataValue::Sint16(-12).as_f64(), Some(-12.));
+        assert_eq!(DataValue::Sint16(0x7FFF).as_f64(), None);
+
+        assert_eq!(DataValue::Sint32(-12).as_f64(), Some(-12.));
+        assert_eq!(DataValue::Sint32(0x7FFFFFFF).as_f64(), None);
+
+        assert_eq!(DataValue::Sint64(-12).as_f64(), Some(-12.));
+        assert_eq!(DataValue::Sint64(0x7FFFFFFFFFFFFFFF).as_f64(), None);
+    }
+
+    #[test]
+    fn test_data_value_uint_as_f64() {
+        assert_eq!(DataValue::Uint8(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint8(0xFF).as_f64(), None);
+
+        assert_eq!(DataValue::Uint16(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint16(0xFFFF).as_f64(), None);
+
+        assert_eq!(DataValue::Uint32(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint32(0xFFFFFFFF).as_f64(), None);
+
+        assert_eq!(DataValue::Uint64(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint64(0xFFFFFFFFFFFFFFFF).as_f64(), None);
+    }
+
+    #[test]
+    fn test_data_value_uintz_as_f64() {
+        assert_eq!(DataValue::Uint8z(0xFF).as_f64(), Some(255.));
+        assert_eq!(DataValue::Uint8z(0).as_f64(), None);
+
+        assert_eq!(DataValue::Uint16z(0xFFFF).as_f64(), Some(65535.));
+        assert_eq!(DataValue::Uint16z(0).as_f64(), None);
+
+        assert_eq!(DataValue::Uint32z(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint32z(0).as_f64(), None);
+
+        assert_eq!(DataValue::Uint64z(12).as_f64(), Some(12.));
+        assert_eq!(DataValue::Uint64z(0).as_f64(), None);
+    }
+
+    #[test]
+    fn test_data_value_float_as_f64() {
+        assert_eq!(DataValue::Float32(2.5).as_f64(), Some(2.5));
+        assert_eq!(
+            DataValue::Float32(f32::from_le_bytes([0xFF, 0xFF, 0xFF, 0xFF])).as_f64(),
+            None
+        );
+
+        assert_eq!(DataValue::Float64(2.5).as_f64(), Some(2.5));
+        assert_eq!(
+            DataValue::Float64(f64::from_le_bytes([
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF
+            ]))
+            .as_f64(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_data_value_datetime_as_f64() {
+        assert_eq!(DataValue::DateTime(1_000_000_000).as_f64(), Some(1e9));
+    }
+
+    #[test]
+    fn test_data_value_non_numeric_as_f64() {
+        assert_eq!(DataValue::String("12".to_string()).as_f64(), None);
+        assert_eq!(DataValue::Byte(vec![0x0C]).as_f64(), None);
+        assert_eq!(
+            DataValue::Enum(FitEnum::Activity(Activity::AutoMultiSport)).as_f64(),
+            None
+        );
+        assert_eq!(DataValue::Unknown(vec![0x0C]).as_f64(), None);
+    }
+
+    #[test]
+    fn test_parse_field_with_too_many_values() {
+        let mut content = Reader::new(255, vec![0; 255].into_iter()).with_max_field_values(16);
+
+        assert!(matches!(
+            parse_uint8(&mut content, &Endianness::Little, 255),
+            Err(DataTypeError::TooManyValues(255, 16))
+        ));
+        assert!(matches!(
+            parse_uint32(&mut content, &Endianness::Little, 252),
+            Err(DataTypeError::TooManyValues(63, 16))
+        ));
+        // Rejected before reading, and allocating, any value
+        assert_eq!(content.bytes_left_to_read(), 255);
+
+        assert_eq!(
+            parse_uint8(&mut content, &Endianness::Little, 16)
+                .unwrap()
+                .len(),
+            16
+        );
+    }
+
+    #[test]
+    fn test_generated_parse_field_with_too_many_values() {
+        let mut content = Reader::new(255, vec![0; 255].into_iter()).with_max_field_values(16);
+
+        assert!(matches!(
+            File::parse(&mut content, &Endianness::Little, 200),
+            Err(DataTypeError::TooManyValues(200, 16))
+        ));
+        assert!(matches!(
+            MesgNum::parse(&mut content, &Endianness::Little, 200),
+            Err(DataTypeError::TooManyValues(100, 16))
+        ));
+        assert!(matches!(
+            LocalDateTime::parse(&mut content, &Endianness::Little, 200),
+            Err(DataTypeError::TooManyValues(50, 16))
+        ));
+        assert_eq!(content.bytes_left_to_read(), 255);
+    }
+
     #[test]
     fn test_apply_scale_offset_is_none() {
         let value = DataValue::Sint32(100);
@@ -562,6 +885,108 @@ mod tests {
         assert_eq!(result.unwrap(), DataValue::Float64(17.5));
     }
 
+    #[test]
+    fn test_rescale_speed_to_kmh() {
+        assert_eq!(
+            DataValue::Float32(5.).rescale(3.6, 0.),
+            DataValue::Float32(18.)
+        );
+        assert_eq!(
+            DataValue::Float64(10.).rescale(3.6, 0.),
+            DataValue::Float64(36.)
+        );
+        assert_eq!(
+            DataValue::Uint16(10).rescale(3.6, 0.),
+            DataValue::Float32(36.)
+        );
+        assert_eq!(
+            DataValue::Uint64(10).rescale(3.6, 0.),
+            DataValue::Float64(36.)
+        );
+    }
+
+    #[test]
+    fn test_rescale_with_offset() {
+        assert_eq!(
+            DataValue::Sint8(-40).rescale(1.8, 32.),
+            DataValue::Float32(-40.)
+        );
+    }
+
+    #[test]
+    fn test_rescale_leaves_other_values_untouched() {
+        assert_eq!(
+            DataValue::String("toto".to_string()).rescale(3.6, 0.),
+            DataValue::String("toto".to_string())
+        );
+        assert_eq!(
+            DataValue::DateTime(135).rescale(3.6, 0.),
+            DataValue::DateTime(135)
+        );
+        assert_eq!(
+            DataValue::Uint16(0xFFFF).rescale(3.6, 0.),
+            DataValue::Uint16(0xFFFF)
+        );
+    }
+
+    #[test]
+    fn test_to_integral() {
+        assert_eq!(DataValue::Float32(3.).to_integral(), DataValue::Uint8(3));
+        assert_eq!(
+            DataValue::Float32(255.).to_integral(),
+            DataValue::Uint16(255)
+        );
+        assert_eq!(
+            DataValue::Float64(70000.).to_integral(),
+            DataValue::Uint32(70000)
+        );
+        assert_eq!(
+            DataValue::Float32(-12.).to_integral(),
+            DataValue::Sint8(-12)
+        );
+        assert_eq!(
+            DataValue::Float32(-300.).to_integral(),
+            DataValue::Sint16(-300)
+        );
+
+        assert_eq!(
+            DataValue::Float32(2.5).to_integral(),
+            DataValue::Float32(2.5)
+        );
+        assert!(matches!(
+            DataValue::Float32(f32::NAN).to_integral(),
+            DataValue::Float32(val) if val.is_nan()
+        ));
+        assert_eq!(DataValue::Uint16(3).to_integral(), DataValue::Uint16(3));
+    }
+
+    #[test]
+    fn test_apply_scale_offset_rejects_nan_scale() {
+        let value = DataValue::Uint8(135);
+        let scale = Some(ScaleOffset {
+            scale: f32::NAN,
+            offset: 0.,
+        });
+
+        assert!(matches!(
+            value.apply_scale_offset(&scale),
+            Err(RecordError::InvalidScaleOffset(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_apply_scale_offset_rejects_infinite_scale_or_offset() {
+        let value = DataValue::Uint64(135);
+        for (scale, offset) in [(f32::INFINITY, 0.), (2., f32::NEG_INFINITY)] {
+            let scale = Some(ScaleOffset { scale, offset });
+
+            assert!(matches!(
+                value.apply_scale_offset(&scale),
+                Err(RecordError::InvalidScaleOffset(_, _))
+            ));
+        }
+    }
+
     #[test]
     fn test_apply_scale_offset_datetime() {
         let value = DataValue::DateTime(135);
@@ -651,4 +1076,52 @@ mod tests {
             _ => unreachable!("Should have return an Ok(DataValue::Float32()"),
         }
     }
+
+    #[test]
+    fn test_data_value_as_bytes() {
+        assert_eq!(
+            DataValue::Byte(vec![0x01, 0xFF]).as_bytes(),
+            Some([0x01, 0xFF].as_slice())
+        );
+        assert_eq!(
+            DataValue::String("abc".to_string()).as_bytes(),
+            Some(b"abc".as_slice())
+        );
+        assert_eq!(
+            DataValue::Unknown(vec![0x02]).as_bytes(),
+            Some([0x02].as_slice())
+        );
+        assert_eq!(DataValue::Byte(vec![]).as_bytes(), Some([].as_slice()));
+    }
+
+    #[test]
+    fn test_data_value_as_bytes_none_for_other_variants() {
+        assert_eq!(DataValue::Uint8(1).as_bytes(), None);
+        assert_eq!(DataValue::Float32(1.).as_bytes(), None);
+        assert_eq!(DataValue::DateTime(1).as_bytes(), None);
+        assert_eq!(
+            DataValue::Enum(FitEnum::Activity(Activity::AutoMultiSport)).as_bytes(),
+            None
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_data_value_as_datetime_utc() {
+        use chrono::{TimeZone, Utc};
+
+        // 1_000_000_000 seconds after the FIT epoch
+        assert_eq!(
+            DataValue::DateTime(1_000_000_000).as_datetime_utc(),
+            Some(Utc.with_ymd_and_hms(2021, 9, 8, 1, 46, 40).unwrap())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_data_value_as_datetime_utc_none() {
+        assert_eq!(DataValue::DateTime(0xFFFFFFFF).as_datetime_utc(), None);
+        assert_eq!(DataValue::DateTime(1000).as_datetime_utc(), None);
+        assert_eq!(DataValue::Uint32(1_000_000_000).as_datetime_utc(), None);
+    }
 }
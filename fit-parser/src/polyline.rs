@@ -0,0 +1,193 @@
+//! Encoding of a track into a Google encoded polyline, a compact string representation of a
+//! sequence of positions (see
+//! <https://developers.google.com/maps/documentation/utilities/polylinealgorithm>).
+
+use std::slice;
+
+use crate::{DataMessage, FitField, MesgNum, RecordField, course::extract_position};
+
+/// Encode `points`, given as `(latitude, longitude)` in degrees, into a polyline. `precision` is
+/// the number of decimals kept for each coordinate, 5 being the standard one.
+pub fn encode_polyline(points: &[(f64, f64)], precision: u32) -> String {
+    let factor = 10_f64.powi(precision as i32);
+
+    let mut polyline = String::new();
+    let mut previous = (0_i64, 0_i64);
+    for (latitude, longitude) in points {
+        let current = (
+            (latitude * factor).round() as i64,
+            (longitude * factor).round() as i64,
+        );
+        encode_value(current.0 - previous.0, &mut polyline);
+        encode_value(current.1 - previous.1, &mut polyline);
+        previous = current;
+    }
+    polyline
+}
+
+fn encode_value(value: i64, polyline: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+    while value >= 0x20 {
+        polyline.push(char::from((((value & 0x1F) | 0x20) + 63) as u8));
+        value >>= 5;
+    }
+    polyline.push(char::from((value + 63) as u8));
+}
+
+/// Encode the positions of the `record` messages into a polyline, skipping records without
+/// coordinates. See [encode_polyline] for `precision`.
+pub fn records_to_polyline(messages: &[DataMessage], precision: u32) -> String {
+    let points = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::Record)
+        .filter_map(|message| {
+            extract_position(
+                slice::from_ref(message),
+                &FitField::Record(RecordField::PositionLat),
+                &FitField::Record(RecordField::PositionLong),
+            )
+        })
+        .map(|position| (position.latitude, position.longitude))
+        .collect::<Vec<_>>();
+
+    encode_polyline(&points, precision)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DataMessageField, DataValue};
+
+    use super::*;
+
+    fn decode_polyline(polyline: &str, precision: u32) -> Vec<(f64, f64)> {
+        let factor = 10_f64.powi(precision as i32);
+
+        let mut values = Vec::new();
+        let (mut value, mut shift) = (0_i64, 0);
+        for byte in polyline.bytes() {
+            let chunk = (byte - 63) as i64;
+            value |= (chunk & 0x1F) << shift;
+            shift += 5;
+            if chunk < 0x20 {
+                values.push(if value & 1 == 1 {
+                    !(value >> 1)
+                } else {
+                    value >> 1
+                });
+                (value, shift) = (0, 0);
+            }
+        }
+
+        let mut current = (0_i64, 0_i64);
+        values
+            .chunks(2)
+            .map(|delta| {
+                current = (current.0 + delta[0], current.1 + delta[1]);
+                (current.0 as f64 / factor, current.1 as f64 / factor)
+            })
+            .collect()
+    }
+
+    fn degrees_to_semicircles(degrees: f64) -> i32 {
+        (degrees * (2.0_f64).powi(31) / 180.).round() as i32
+    }
+
+    fn record(position: Option<(f64, f64)>) -> DataMessage {
+        let mut fields = vec![DataMessageField {
+            kind: FitField::Record(RecordField::HeartRate),
+            values: vec![DataValue::Uint8(120)],
+            is_scaled: false,
+        }];
+        if let Some((latitude, longitude)) = position {
+            fields.push(DataMessageField {
+                kind: FitField::Record(RecordField::PositionLat),
+                values: vec![DataValue::Sint32(degrees_to_semicircles(latitude))],
+                is_scaled: false,
+            });
+            fields.push(DataMessageField {
+                kind: FitField::Record(RecordField::PositionLong),
+                values: vec![DataValue::Sint32(degrees_to_semicircles(longitude))],
+                is_scaled: false,
+            });
+        }
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields,
+        }
+    }
+
+    fn assert_points_close(points: &[(f64, f64)], expected: &[(f64, f64)], tolerance: f64) {
+        assert_eq!(points.len(), expected.len());
+        for (point, expected) in points.iter().zip(expected) {
+            assert!(
+                (point.0 - expected.0).abs() <= tolerance,
+                "{point:?} {expected:?}"
+            );
+            assert!(
+                (point.1 - expected.1).abs() <= tolerance,
+                "{point:?} {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_polyline_reference_example() {
+        let points = [(38.5, -120.2), (40.7, -120.95), (43.252, -126.453)];
+
+        assert_eq!(encode_polyline(&points, 5), "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+    }
+
+    #[test]
+    fn test_encode_polyline_empty() {
+        assert_eq!(encode_polyline(&[], 5), "");
+    }
+
+    #[test]
+    fn test_encode_polyline_round_trip_with_precision() {
+        let points = [
+            (45.1234567, 6.7654321),
+            (45.1239, 6.7661),
+            (-12.5, -0.000001),
+        ];
+
+        assert_points_close(
+            &decode_polyline(&encode_polyline(&points, 5), 5),
+            &points,
+            1e-5,
+        );
+        assert_points_close(
+            &decode_polyline(&encode_polyline(&points, 6), 6),
+            &points,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_records_to_polyline_skips_records_without_position() {
+        let messages = vec![
+            record(Some((48.8566, 2.3522))),
+            record(None),
+            DataMessage {
+                local_message_type: 1,
+                message_kind: MesgNum::Lap,
+                fields: vec![],
+            },
+            record(Some((48.8570, 2.3530))),
+            record(Some((48.8581, 2.3545))),
+        ];
+
+        let polyline = records_to_polyline(&messages, 5);
+
+        assert_points_close(
+            &decode_polyline(&polyline, 5),
+            &[(48.8566, 2.3522), (48.8570, 2.3530), (48.8581, 2.3545)],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_records_to_polyline_without_positions() {
+        assert_eq!(records_to_polyline(&[record(None)], 5), "");
+    }
+}
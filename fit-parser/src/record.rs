@@ -0,0 +1,110 @@
+//! Typed access to the fields of `record` messages, the per-sample data of an activity.
+
+use std::slice;
+
+use crate::{DataMessage, FitField, MesgNum, RecordField, course::extract_position};
+
+/// A view over a `record` [DataMessage], combining its raw fields into ready to use values.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordView<'a> {
+    message: &'a DataMessage,
+}
+
+impl<'a> RecordView<'a> {
+    /// `None` if `message` is not a `record` message.
+    pub fn new(message: &'a DataMessage) -> Option<Self> {
+        (message.message_kind == MesgNum::Record).then_some(Self { message })
+    }
+
+    pub fn message(&self) -> &'a DataMessage {
+        self.message
+    }
+
+    /// `(latitude, longitude)` of the record, in degrees, from its `position_lat` and
+    /// `position_long` semicircles fields. `None` if either of them is missing or invalid, or if
+    /// the latitude is not within [-90, 90].
+    pub fn latlng(&self) -> Option<(f64, f64)> {
+        let position = extract_position(
+            slice::from_ref(self.message),
+            &FitField::Record(RecordField::PositionLat),
+            &FitField::Record(RecordField::PositionLong),
+        )?;
+        (-90.0..=90.0)
+            .contains(&position.latitude)
+            .then_some((position.latitude, position.longitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DataMessageField, DataValue};
+
+    use super::*;
+
+    fn record(latitude: Option<i32>, longitude: Option<i32>) -> DataMessage {
+        let mut fields = vec![DataMessageField {
+            kind: FitField::Record(RecordField::HeartRate),
+            values: vec![DataValue::Uint8(120)],
+            is_scaled: false,
+        }];
+        if let Some(latitude) = latitude {
+            fields.push(DataMessageField {
+                kind: FitField::Record(RecordField::PositionLat),
+                values: vec![DataValue::Sint32(latitude)],
+                is_scaled: false,
+            });
+        }
+        if let Some(longitude) = longitude {
+            fields.push(DataMessageField {
+                kind: FitField::Record(RecordField::PositionLong),
+                values: vec![DataValue::Sint32(longitude)],
+                is_scaled: false,
+            });
+        }
+        DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Record,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_latlng() {
+        // 45° and -90° in semicircles
+        let message = record(Some(536870912), Some(-1073741824));
+
+        assert_eq!(
+            RecordView::new(&message).unwrap().latlng(),
+            Some((45., -90.))
+        );
+    }
+
+    #[test]
+    fn test_latlng_half_missing() {
+        let latitude_only = record(Some(536870912), None);
+        let longitude_only = record(None, Some(-1073741824));
+
+        assert_eq!(RecordView::new(&latitude_only).unwrap().latlng(), None);
+        assert_eq!(RecordView::new(&longitude_only).unwrap().latlng(), None);
+    }
+
+    #[test]
+    fn test_latlng_invalid() {
+        let invalid_sentinel = record(Some(0x7FFFFFFF), Some(-1073741824));
+        let out_of_range = record(Some(1610612736), Some(-1073741824));
+
+        assert_eq!(RecordView::new(&invalid_sentinel).unwrap().latlng(), None);
+        assert_eq!(RecordView::new(&out_of_range).unwrap().latlng(), None);
+    }
+
+    #[test]
+    fn test_record_view_only_for_records() {
+        let message = DataMessage {
+            local_message_type: 0,
+            message_kind: MesgNum::Lap,
+            fields: vec![],
+        };
+
+        assert!(RecordView::new(&message).is_none());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{DataMessage, DataValue, FitField};
+use std::collections::HashMap;
+
+use crate::{DataMessage, DataValue, FitField, MesgNum};
 
 pub fn find_field_value_by_kind<'a>(
     messages: &'a [DataMessage],
@@ -53,36 +55,208 @@ pub fn find_field_value_as_uint(
 }
 
 pub fn find_field_value_as_float(messages: &[DataMessage], target_field: &FitField) -> Option<f64> {
-    find_field_value_by_kind(messages, target_field).and_then(|values| {
-        values.iter().find_map(|val| {
-            if val.is_invalid() {
-                return None;
-            }
-            match val {
-                DataValue::Uint8(val) => Some(*val as f64),
-                DataValue::Uint16(val) => Some(*val as f64),
-                DataValue::Uint32(val) => Some(*val as f64),
-                DataValue::Uint64(val) => Some(*val as f64),
-                DataValue::Uint8z(val) => Some(*val as f64),
-                DataValue::Uint16z(val) => Some(*val as f64),
-                DataValue::Uint32z(val) => Some(*val as f64),
-                DataValue::Uint64z(val) => Some(*val as f64),
-                DataValue::Sint8(val) => Some(*val as f64),
-                DataValue::Sint16(val) => Some(*val as f64),
-                DataValue::Sint32(val) => Some(*val as f64),
-                DataValue::Sint64(val) => Some(*val as f64),
-                DataValue::Float32(val) => Some(*val as f64),
-                DataValue::Float64(val) => Some(*val),
-                _ => None,
-            }
+    find_field_value_by_kind(messages, target_field)
+        .and_then(|values| values.iter().find_map(value_as_float))
+}
+
+/// Numeric value of a valid integer or float [DataValue], timestamps being excluded.
+fn value_as_float(value: &DataValue) -> Option<f64> {
+    match value {
+        DataValue::DateTime(_) => None,
+        value => value.as_f64(),
+    }
+}
+
+/// Average, maximum and minimum of a stream of values, see [stream_stats].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StreamStats {
+    pub avg: Option<f64>,
+    pub max: Option<f64>,
+    pub min: Option<f64>,
+}
+
+/// Average, maximum and minimum of a stream of values, e.g. the heart rate of all the records of
+/// an activity. Invalid values (see [DataValue::is_invalid]), NaN and non-numeric values are
+/// skipped, so that neither sentinels nor gaps skew the statistics. All statistics are `None` if
+/// there is no value left.
+pub fn stream_stats(values: &[DataValue]) -> StreamStats {
+    let mut count = 0;
+    let mut sum = 0.;
+    let mut stats = StreamStats::default();
+    for value in values
+        .iter()
+        .filter_map(value_as_float)
+        .filter(|value| !value.is_nan())
+    {
+        count += 1;
+        sum += value;
+        stats.max = Some(stats.max.map_or(value, |max| max.max(value)));
+        stats.min = Some(stats.min.map_or(value, |min| min.min(value)));
+    }
+    if count > 0 {
+        stats.avg = Some(sum / count as f64);
+    }
+    stats
+}
+
+/// Offset, in seconds, between the Unix epoch and the FIT epoch (1989-12-31T00:00:00Z) that
+/// [DataValue::DateTime] values are relative to. Beware of implementations using 1990-01-01.
+pub const FIT_EPOCH_OFFSET: i64 = 631065600;
+
+/// Raw [DataValue::DateTime] values below this one are not relative to the FIT epoch but are a
+/// system time, e.g. seconds since the device was powered on.
+pub const FIT_DATETIME_MIN: u32 = 0x10000000;
+
+/// Convert a raw [DataValue::DateTime] into a Unix timestamp. System times (below
+/// [FIT_DATETIME_MIN]) are not epoch-shifted and `None` is returned, as they cannot be mapped to an
+/// absolute instant.
+pub fn fit_datetime_to_unix_timestamp(value: u32) -> Option<i64> {
+    if value < FIT_DATETIME_MIN {
+        return None;
+    }
+    Some(value as i64 + FIT_EPOCH_OFFSET)
+}
+
+/// Differences between two lists of [DataMessage]s, see [diff_fit].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FitDiff {
+    /// Messages only found in the first list
+    pub removed: Vec<DataMessage>,
+    /// Messages only found in the second list
+    pub added: Vec<DataMessage>,
+    /// Messages found in both lists, but with different fields
+    pub changed: Vec<MessageDiff>,
+}
+
+impl FitDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Fields differing between two versions of the same message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageDiff {
+    pub message_kind: MesgNum,
+    /// Raw timestamp of the message, if it has one
+    pub timestamp: Option<u32>,
+    pub fields: Vec<FieldDiff>,
+}
+
+/// Values of a field in both versions of a message, `None` if the field is missing from one of
+/// them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub field: FitField,
+    pub old: Option<Vec<DataValue>>,
+    pub new: Option<Vec<DataValue>>,
+}
+
+impl FieldDiff {
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+}
+
+/// Timestamp of a message, from the timestamp field of its kind.
+fn message_timestamp(message: &DataMessage) -> Option<u32> {
+    let timestamp_field = message.message_kind.timestamp_field()?;
+    find_field_value_by_kind(std::slice::from_ref(message), &timestamp_field)?
+        .iter()
+        .find_map(|value| match value {
+            DataValue::DateTime(timestamp) => Some(*timestamp),
+            _ => None,
         })
-    })
+}
+
+/// Key matching the same message in two lists: its kind, its timestamp and its rank among the
+/// previous messages with the same kind and timestamp.
+fn message_keys(messages: &[DataMessage]) -> Vec<(MesgNum, Option<u32>, usize)> {
+    let mut occurrences: HashMap<(MesgNum, Option<u32>), usize> = HashMap::new();
+    messages
+        .iter()
+        .map(|message| {
+            let key = (message.message_kind.clone(), message_timestamp(message));
+            let occurrence = occurrences.entry(key.clone()).or_default();
+            *occurrence += 1;
+            (key.0, key.1, *occurrence - 1)
+        })
+        .collect()
+}
+
+fn diff_fields(old: &DataMessage, new: &DataMessage) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+    for field in old.fields.iter() {
+        let new_values = new
+            .fields
+            .iter()
+            .find(|new_field| new_field.kind == field.kind)
+            .map(|new_field| &new_field.values);
+        if new_values != Some(&field.values) {
+            diffs.push(FieldDiff {
+                field: field.kind.clone(),
+                old: Some(field.values.clone()),
+                new: new_values.cloned(),
+            });
+        }
+    }
+    for field in new.fields.iter() {
+        if !old
+            .fields
+            .iter()
+            .any(|old_field| old_field.kind == field.kind)
+        {
+            diffs.push(FieldDiff {
+                field: field.kind.clone(),
+                old: None,
+                new: Some(field.values.clone()),
+            });
+        }
+    }
+    diffs
+}
+
+/// Compare two lists of messages, e.g. a file and the same file re-encoded or recorded by another
+/// firmware. Messages are matched by kind and timestamp (and by order among the ones sharing both),
+/// so that an added or removed message does not shift the comparison of the following ones.
+/// Matched messages are then compared field by field.
+pub fn diff_fit(a: &[DataMessage], b: &[DataMessage]) -> FitDiff {
+    let mut b_indices: HashMap<_, usize> = message_keys(b)
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| (key, index))
+        .collect();
+
+    let mut diff = FitDiff::default();
+    for (message, key) in a.iter().zip(message_keys(a)) {
+        let Some(index) = b_indices.remove(&key) else {
+            diff.removed.push(message.clone());
+            continue;
+        };
+        let fields = diff_fields(message, &b[index]);
+        if !fields.is_empty() {
+            diff.changed.push(MessageDiff {
+                message_kind: key.0,
+                timestamp: key.1,
+                fields,
+            });
+        }
+    }
+
+    let mut added = b_indices.into_values().collect::<Vec<_>>();
+    added.sort();
+    diff.added = added.into_iter().map(|index| b[index].clone()).collect();
+
+    diff
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::{DataMessageField, FitEnum, MesgNum, SessionField, Sport};
+    use crate::{
+        DataMessageField, FitEnum, RecordField, SessionField, Sport, parse_fit_messages,
+        parser::test_utils::{build_fit_file, data_record, definition_record},
+    };
 
     use super::*;
 
@@ -94,6 +268,7 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::Record(crate::RecordField::Speed),
                 values: vec![DataValue::Float32(1.3)],
+                is_scaled: false,
             }],
         }];
 
@@ -115,6 +290,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::Record(crate::RecordField::Speed),
                     values: vec![DataValue::Float32(1.3)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -123,6 +299,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::DeviceInfo(crate::DeviceInfoField::Product),
                     values: vec![DataValue::String("device".to_string())],
+                    is_scaled: false,
                 }],
             },
         ];
@@ -145,6 +322,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::Record(crate::RecordField::Speed),
                     values: vec![DataValue::Float32(1.3)],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -153,6 +331,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::DeviceInfo(crate::DeviceInfoField::Product),
                     values: vec![DataValue::String("device".to_string())],
+                    is_scaled: false,
                 }],
             },
             DataMessage {
@@ -161,6 +340,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::DeviceInfo(crate::DeviceInfoField::Product),
                     values: vec![DataValue::String("another_device".to_string())],
+                    is_scaled: false,
                 }],
             },
         ];
@@ -182,6 +362,7 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::DeviceInfo(crate::DeviceInfoField::Product),
                 values: vec![DataValue::String("device".to_string())],
+                is_scaled: false,
             }],
         }];
 
@@ -202,6 +383,7 @@ mod tests {
             fields: vec![DataMessageField {
                 kind: FitField::DeviceInfo(crate::DeviceInfoField::Product),
                 values: vec![DataValue::Uint32(12)],
+                is_scaled: false,
             }],
         }];
 
@@ -255,6 +437,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::TotalCalories),
                     values: vec![val.clone()],
+                    is_scaled: false,
                 }],
             }];
 
@@ -325,6 +508,7 @@ mod tests {
                 fields: vec![DataMessageField {
                     kind: FitField::Session(SessionField::TotalCalories),
                     values: vec![val.clone()],
+                    is_scaled: false,
                 }],
             }];
 
@@ -339,4 +523,126 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_stream_stats_skips_invalid_values() {
+        let values = vec![
+            DataValue::Uint8(120),
+            DataValue::Uint8(0xFF),
+            DataValue::Uint8(150),
+            DataValue::Unknown(vec![0x01]),
+            DataValue::Uint8(90),
+            DataValue::String("toto".to_string()),
+        ];
+
+        assert_eq!(
+            stream_stats(&values),
+            StreamStats {
+                avg: Some(120.),
+                max: Some(150.),
+                min: Some(90.),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream_stats_mixed_numeric_types() {
+        let values = vec![
+            DataValue::Float32(2.5),
+            DataValue::Float32(f32::from_le_bytes([0xFF, 0xFF, 0xFF, 0xFF])),
+            DataValue::Float32(f32::NAN),
+            DataValue::Uint16(3),
+            DataValue::Sint8(-1),
+        ];
+
+        assert_eq!(
+            stream_stats(&values),
+            StreamStats {
+                avg: Some(1.5),
+                max: Some(3.),
+                min: Some(-1.),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream_stats_only_invalid_values() {
+        assert_eq!(stream_stats(&[]), StreamStats::default());
+        assert_eq!(
+            stream_stats(&[DataValue::Uint16(0xFFFF), DataValue::Uint8z(0)]),
+            StreamStats::default()
+        );
+    }
+
+    #[test]
+    fn test_fit_datetime_to_unix_timestamp() {
+        // 1_000_000_000 seconds after 1989-12-31T00:00:00Z is 2021-09-08T01:46:40Z
+        assert_eq!(
+            fit_datetime_to_unix_timestamp(1_000_000_000),
+            Some(1_631_065_600)
+        );
+    }
+
+    #[test]
+    fn test_fit_datetime_to_unix_timestamp_boundary() {
+        assert_eq!(
+            fit_datetime_to_unix_timestamp(FIT_DATETIME_MIN),
+            Some(268_435_456 + FIT_EPOCH_OFFSET)
+        );
+        assert_eq!(fit_datetime_to_unix_timestamp(FIT_DATETIME_MIN - 1), None);
+        assert_eq!(fit_datetime_to_unix_timestamp(0), None);
+    }
+
+    /// File with a record message (timestamp and heart rate) per heart rate value.
+    fn record_messages(heart_rates: &[(u32, u8)]) -> Vec<DataMessage> {
+        let mut records = definition_record(0, 20, &[(253, 4, 0x86), (3, 1, 0x02)]);
+        for (timestamp, heart_rate) in heart_rates {
+            let mut payload = timestamp.to_le_bytes().to_vec();
+            payload.push(*heart_rate);
+            records.append(&mut data_record(0, &payload));
+        }
+        parse_fit_messages(build_fit_file(&records).into_iter(), true).unwrap()
+    }
+
+    #[test]
+    fn test_diff_fit() {
+        let original = record_messages(&[(1000, 120), (1001, 121), (1002, 122)]);
+        // Heart rate of the second record changed, the first one removed and a last one added
+        let modified = record_messages(&[(1001, 131), (1002, 122), (1003, 123)]);
+
+        let diff = diff_fit(&original, &modified);
+
+        assert_eq!(diff.removed, vec![original[0].clone()]);
+        assert_eq!(diff.added, vec![modified[2].clone()]);
+        assert_eq!(diff.changed.len(), 1);
+        let change = &diff.changed[0];
+        assert_eq!(change.message_kind, MesgNum::Record);
+        assert_eq!(change.timestamp, Some(1001));
+        assert_eq!(change.fields.len(), 1);
+        assert_eq!(change.fields[0].name(), "heart_rate");
+        assert_eq!(change.fields[0].old, Some(vec![DataValue::Uint8(121)]));
+        assert_eq!(change.fields[0].new, Some(vec![DataValue::Uint8(131)]));
+    }
+
+    #[test]
+    fn test_diff_fit_missing_field() {
+        let original = record_messages(&[(1000, 120)]);
+        let mut modified = original.clone();
+        modified[0]
+            .fields
+            .retain(|field| field.kind != FitField::Record(RecordField::HeartRate));
+
+        let diff = diff_fit(&original, &modified);
+
+        assert_eq!(
+            diff.changed[0].fields[0].old,
+            Some(vec![DataValue::Uint8(120)])
+        );
+        assert_eq!(diff.changed[0].fields[0].new, None);
+        assert!(diff_fit(&modified, &modified).is_empty());
+        assert_eq!(
+            diff_fit(&modified, &original).changed[0].fields[0].old,
+            None
+        );
+    }
 }
@@ -0,0 +1,66 @@
+//! Entrypoint to run the parser in a browser through WebAssembly.
+//!
+//! Decoding only works on the bytes it is given, without filesystem or process access, so it
+//! builds for the `wasm32-unknown-unknown` target. [parse_fit_bytes_js] is exported with
+//! `wasm-bindgen` and returns the messages as a JavaScript array, see [messages_to_json]. The
+//! module is built with
+//!
+//! ```sh
+//! cargo rustc -p fit-parser --lib --features wasm --release \
+//!     --target wasm32-unknown-unknown --crate-type cdylib
+//! ```
+//!
+//! and bound with `wasm-bindgen --target web`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{FitDecoder, json::messages_to_json};
+
+/// Parse the content of a .FIT file into the array of its messages. The error is the message
+/// of the parsing failure.
+#[wasm_bindgen]
+pub fn parse_fit_bytes_js(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let json = parse_fit_bytes_json(bytes).map_err(|err| JsValue::from_str(&err))?;
+    js_sys::JSON::parse(&json)
+}
+
+/// Parse the content of a .FIT file into the JSON array of its messages, serialized to a string.
+fn parse_fit_bytes_json(bytes: &[u8]) -> Result<String, String> {
+    let mut decoder = FitDecoder::new(false);
+    decoder.feed(bytes);
+    let messages = decoder.finish().map_err(|err| err.to_string())?;
+    Ok(messages_to_json(&messages).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use crate::parser::test_utils::{build_fit_file, data_record, definition_record};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_fit_bytes_json() {
+        // record: timestamp (253), heart_rate (3)
+        let mut records = definition_record(0, 20, &[(253, 4, 0x86), (3, 1, 0x02)]);
+        let mut payload = 1000_u32.to_le_bytes().to_vec();
+        payload.push(120);
+        records.append(&mut data_record(0, &payload));
+
+        let json = parse_fit_bytes_json(&build_fit_file(&records)).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap(),
+            json!([{
+                "message": "Record",
+                "fields": {"timestamp": 1000, "heart_rate": 120},
+            }])
+        );
+    }
+
+    #[test]
+    fn test_parse_fit_bytes_json_invalid_content() {
+        assert!(parse_fit_bytes_json(&[0, 1, 2]).is_err());
+    }
+}
@@ -0,0 +1,332 @@
+//! Parsing of .FIT workout files ([File::Workout]) into a list of structured steps.
+
+use std::slice;
+
+use thiserror::Error;
+
+use crate::{
+    DataMessage, DataValue, File, FileIdField, FitEnum, FitField, FitParserError, MesgNum, Sport,
+    WktStepDuration, WktStepTarget, WorkoutField, WorkoutStepField, parse_fit_messages,
+    utils::{find_fied_value_as_string, find_field_value_as_float, find_field_value_by_kind},
+};
+
+#[derive(Debug, Error)]
+pub enum WorkoutError {
+    #[error("Unable to parse .FIT content: {0}")]
+    Parser(#[from] FitParserError),
+
+    #[error("File is not a workout file")]
+    NotAWorkout,
+
+    #[error("Repeat step {0} does not reference an earlier step")]
+    InvalidRepeat(usize),
+}
+
+/// Steps `from_step..=to_step` (indexes in [Workout::steps]) are repeated, the condition to stop
+/// repeating being given by the repeat step's duration type, e.g.
+/// [WktStepDuration::RepeatUntilStepsCmplt].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatBlock {
+    pub from_step: usize,
+    pub to_step: usize,
+}
+
+/// A `workout_step` message. Duration and target values are the ones of the subfield matching
+/// the step's duration and target types, with their scale and offset applied (e.g. seconds for a
+/// [WktStepDuration::Time] step, meters for a [WktStepDuration::Distance] step).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkoutStep {
+    pub name: Option<String>,
+    pub duration_type: Option<WktStepDuration>,
+    pub duration_value: Option<f64>,
+    pub target_type: Option<WktStepTarget>,
+    pub target_value: Option<f64>,
+    pub custom_target_low: Option<f64>,
+    pub custom_target_high: Option<f64>,
+    /// Set for repeat steps only, which do not describe an effort by themselves but repeat a
+    /// block of earlier steps.
+    pub repeat: Option<RepeatBlock>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Workout {
+    pub name: Option<String>,
+    pub sport: Option<Sport>,
+    pub steps: Vec<WorkoutStep>,
+}
+
+const DURATION_FIELDS: [WorkoutStepField; 8] = [
+    WorkoutStepField::DurationValue,
+    WorkoutStepField::DurationTime,
+    WorkoutStepField::DurationDistance,
+    WorkoutStepField::DurationHr,
+    WorkoutStepField::DurationCalories,
+    WorkoutStepField::DurationStep,
+    WorkoutStepField::DurationPower,
+    WorkoutStepField::DurationReps,
+];
+
+const TARGET_FIELDS: [WorkoutStepField; 11] = [
+    WorkoutStepField::TargetValue,
+    WorkoutStepField::TargetSpeedZone,
+    WorkoutStepField::TargetHrZone,
+    WorkoutStepField::TargetCadenceZone,
+    WorkoutStepField::TargetPowerZone,
+    WorkoutStepField::RepeatSteps,
+    WorkoutStepField::RepeatTime,
+    WorkoutStepField::RepeatDistance,
+    WorkoutStepField::RepeatCalories,
+    WorkoutStepField::RepeatHr,
+    WorkoutStepField::RepeatPower,
+];
+
+const CUSTOM_TARGET_LOW_FIELDS: [WorkoutStepField; 5] = [
+    WorkoutStepField::CustomTargetValueLow,
+    WorkoutStepField::CustomTargetSpeedLow,
+    WorkoutStepField::CustomTargetHeartRateLow,
+    WorkoutStepField::CustomTargetCadenceLow,
+    WorkoutStepField::CustomTargetPowerLow,
+];
+
+const CUSTOM_TARGET_HIGH_FIELDS: [WorkoutStepField; 5] = [
+    WorkoutStepField::CustomTargetValueHigh,
+    WorkoutStepField::CustomTargetSpeedHigh,
+    WorkoutStepField::CustomTargetHeartRateHigh,
+    WorkoutStepField::CustomTargetCadenceHigh,
+    WorkoutStepField::CustomTargetPowerHigh,
+];
+
+/// Parse the content of a .FIT workout file. Steps are returned in file order, repeat steps
+/// included.
+pub fn parse_workout(bytes: Vec<u8>) -> Result<Workout, WorkoutError> {
+    let messages = parse_fit_messages(bytes.into_iter(), false)?;
+
+    let file_type = messages
+        .iter()
+        .filter(|message| message.message_kind == MesgNum::FileId)
+        .find_map(|message| {
+            find_field_value_by_kind(
+                slice::from_ref(message),
+                &FitField::FileId(FileIdField::Type),
+            )
+        });
+    if !matches!(
+        file_type,
+        Some([DataValue::Enum(FitEnum::File(File::Workout)), ..])
+    ) {
+        return Err(WorkoutError::NotAWorkout);
+    }
+
+    let mut workout = Workout::default();
+    for message in messages.iter() {
+        let message = slice::from_ref(message);
+        match message[0].message_kind {
+            MesgNum::Workout => {
+                workout.name =
+                    find_fied_value_as_string(message, &FitField::Workout(WorkoutField::WktName));
+                workout.sport =
+                    find_field_value_by_kind(message, &FitField::Workout(WorkoutField::Sport))
+                        .and_then(|values| {
+                            values.iter().find_map(|value| match value {
+                                DataValue::Enum(FitEnum::Sport(sport)) => Some(sport.clone()),
+                                _ => None,
+                            })
+                        });
+            }
+            MesgNum::WorkoutStep => {
+                let index = workout.steps.len();
+                workout.steps.push(extract_step(message, index)?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(workout)
+}
+
+fn extract_step(message: &[DataMessage], index: usize) -> Result<WorkoutStep, WorkoutError> {
+    let duration_type = find_field_value_by_kind(
+        message,
+        &FitField::WorkoutStep(WorkoutStepField::DurationType),
+    )
+    .and_then(|values| {
+        values.iter().find_map(|value| match value {
+            DataValue::Enum(FitEnum::WktStepDuration(duration)) => Some(duration.clone()),
+            _ => None,
+        })
+    });
+    let duration_value = find_first_float(message, &DURATION_FIELDS);
+
+    let repeat = match duration_type {
+        Some(
+            WktStepDuration::RepeatUntilStepsCmplt
+            | WktStepDuration::RepeatUntilTime
+            | WktStepDuration::RepeatUntilDistance
+            | WktStepDuration::RepeatUntilCalories
+            | WktStepDuration::RepeatUntilHrLessThan
+            | WktStepDuration::RepeatUntilHrGreaterThan
+            | WktStepDuration::RepeatUntilPowerLessThan
+            | WktStepDuration::RepeatUntilPowerGreaterThan
+            | WktStepDuration::RepeatUntilPowerLastLapLessThan
+            | WktStepDuration::RepeatUntilMaxPowerLastLapLessThan
+            | WktStepDuration::RepeatUntilTrainingPeaksTss,
+        ) => {
+            // For repeat steps, the duration value is the index of the first repeated step
+            let from_step = duration_value
+                .map(|value| value as usize)
+                .filter(|from_step| *from_step < index)
+                .ok_or(WorkoutError::InvalidRepeat(index))?;
+            Some(RepeatBlock {
+                from_step,
+                to_step: index - 1,
+            })
+        }
+        _ => None,
+    };
+
+    Ok(WorkoutStep {
+        name: find_fied_value_as_string(
+            message,
+            &FitField::WorkoutStep(WorkoutStepField::WktStepName),
+        ),
+        duration_type,
+        duration_value,
+        target_type: find_field_value_by_kind(
+            message,
+            &FitField::WorkoutStep(WorkoutStepField::TargetType),
+        )
+        .and_then(|values| {
+            values.iter().find_map(|value| match value {
+                DataValue::Enum(FitEnum::WktStepTarget(target)) => Some(target.clone()),
+                _ => None,
+            })
+        }),
+        target_value: find_first_float(message, &TARGET_FIELDS),
+        custom_target_low: find_first_float(message, &CUSTOM_TARGET_LOW_FIELDS),
+        custom_target_high: find_first_float(message, &CUSTOM_TARGET_HIGH_FIELDS),
+        repeat,
+    })
+}
+
+/// A dynamic field is decoded as one of its subfields, find the first valid value among them.
+fn find_first_float(message: &[DataMessage], fields: &[WorkoutStepField]) -> Option<f64> {
+    fields
+        .iter()
+        .find_map(|field| find_field_value_as_float(message, &FitField::WorkoutStep(field.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::test_utils::{build_fit_file, data_record, definition_record};
+
+    use super::*;
+
+    /// Encode a workout step as (duration_type, duration_value, target_type, target_value).
+    fn step_payload(
+        duration_type: u8,
+        duration_value: u32,
+        target_type: u8,
+        target_value: u32,
+    ) -> Vec<u8> {
+        let mut payload = vec![duration_type];
+        payload.extend_from_slice(&duration_value.to_le_bytes());
+        payload.push(target_type);
+        payload.extend_from_slice(&target_value.to_le_bytes());
+        payload
+    }
+
+    /// Workout with a 10 minutes warmup, then 5 x (1 km at power zone 4, 2 minutes open
+    /// recovery).
+    fn workout_fixture() -> Vec<u8> {
+        // file_id: type (0, enum)
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[5]));
+
+        // workout: sport (4, enum), wkt_name (8, string)
+        records.append(&mut definition_record(
+            1,
+            26,
+            &[(4, 1, 0x00), (8, 10, 0x07)],
+        ));
+        let mut payload = vec![2];
+        payload.extend_from_slice(b"Intervals\0");
+        records.append(&mut data_record(1, &payload));
+
+        // workout_step: duration_type (1), duration_value (2), target_type (3), target_value (4)
+        records.append(&mut definition_record(
+            2,
+            27,
+            &[(1, 1, 0x00), (2, 4, 0x86), (3, 1, 0x00), (4, 4, 0x86)],
+        ));
+        records.append(&mut data_record(2, &step_payload(0, 600_000, 2, 0)));
+        records.append(&mut data_record(2, &step_payload(1, 100_000, 4, 4)));
+        records.append(&mut data_record(2, &step_payload(0, 120_000, 2, 0)));
+        records.append(&mut data_record(2, &step_payload(6, 1, 2, 5)));
+
+        build_fit_file(&records)
+    }
+
+    #[test]
+    fn test_parse_workout() {
+        let workout = parse_workout(workout_fixture()).unwrap();
+
+        assert_eq!(workout.name, Some("Intervals".to_string()));
+        assert_eq!(workout.sport, Some(Sport::Cycling));
+        assert_eq!(workout.steps.len(), 4);
+
+        let warmup = &workout.steps[0];
+        assert_eq!(warmup.duration_type, Some(WktStepDuration::Time));
+        assert_eq!(warmup.duration_value, Some(600.));
+        assert_eq!(warmup.target_type, Some(WktStepTarget::Open));
+        assert_eq!(warmup.repeat, None);
+
+        let interval = &workout.steps[1];
+        assert_eq!(interval.duration_type, Some(WktStepDuration::Distance));
+        assert_eq!(interval.duration_value, Some(1000.));
+        assert_eq!(interval.target_type, Some(WktStepTarget::Power));
+        assert_eq!(interval.target_value, Some(4.));
+
+        let repeat = &workout.steps[3];
+        assert_eq!(
+            repeat.duration_type,
+            Some(WktStepDuration::RepeatUntilStepsCmplt)
+        );
+        assert_eq!(
+            repeat.repeat,
+            Some(RepeatBlock {
+                from_step: 1,
+                to_step: 2
+            })
+        );
+        assert_eq!(repeat.target_value, Some(5.));
+    }
+
+    #[test]
+    fn test_parse_workout_repeat_referencing_later_step() {
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[5]));
+        records.append(&mut definition_record(
+            1,
+            27,
+            &[(1, 1, 0x00), (2, 4, 0x86), (3, 1, 0x00), (4, 4, 0x86)],
+        ));
+        records.append(&mut data_record(1, &step_payload(0, 600_000, 2, 0)));
+        records.append(&mut data_record(1, &step_payload(6, 3, 2, 5)));
+
+        assert!(matches!(
+            parse_workout(build_fit_file(&records)),
+            Err(WorkoutError::InvalidRepeat(1))
+        ));
+    }
+
+    #[test]
+    fn test_parse_workout_rejects_course_file() {
+        let mut records = definition_record(0, 0, &[(0, 1, 0x00)]);
+        records.append(&mut data_record(0, &[6]));
+
+        assert!(matches!(
+            parse_workout(build_fit_file(&records)),
+            Err(WorkoutError::NotAWorkout)
+        ));
+    }
+}